@@ -3,6 +3,7 @@ mod drop_database;
 mod show_databases;
 mod use_database;
 pub mod use_schema;
+mod verify_database;
 
 use derive_more::Display;
 use std::fmt::Debug;
@@ -16,6 +17,7 @@ pub use drop_database::DropDatabase;
 pub use show_databases::ShowDatabases;
 pub use use_database::UseDatabase;
 pub use use_schema::UseSchema;
+pub use verify_database::VerifyDatabase;
 
 /// Commands that can be executed on the database.
 #[derive(Debug, Clone, PartialEq)]
@@ -34,6 +36,9 @@ pub enum DatabaseCommand {
 
     /// Command to show databases.
     ShowDatabases(ShowDatabases),
+
+    /// Command to verify the pages of a database.
+    VerifyDatabase(VerifyDatabase),
 }
 
 impl<const NODE_SIZE: u8> Command<BackendFacade<NODE_SIZE>>
@@ -62,6 +67,9 @@ impl<const NODE_SIZE: u8> Command<BackendFacade<NODE_SIZE>>
             DatabaseCommand::ShowDatabases(command) => command
                 .execute(facade)
                 .map_err(ExecutionError::ShowDatabases),
+            DatabaseCommand::VerifyDatabase(command) => command
+                .execute(facade)
+                .map_err(ExecutionError::VerifyDatabase),
         }
     }
 }
@@ -88,4 +96,8 @@ pub enum ExecutionError {
     /// Show databases error.
     #[display(fmt = "{}", _0)]
     ShowDatabases(show_databases::ExecutionError),
+
+    /// Verify database error.
+    #[display(fmt = "{}", _0)]
+    VerifyDatabase(verify_database::ExecutionError),
 }
@@ -0,0 +1,118 @@
+use backend::schema::database;
+use common::structs::hash_table::MutHashTable as _;
+use derive_more::Display;
+use prettytable::{format, row};
+
+use crate::api::{
+    command::Command, facade::BackendFacade, CommandResultString,
+};
+
+/// Command to verify the checksums of all pages of a database.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VerifyDatabase {
+    /// The name of the database to verify.
+    pub name: database::Name,
+}
+
+impl<const NODE_SIZE: u8> Command<BackendFacade<NODE_SIZE>> for VerifyDatabase {
+    type Ok = CommandResultString;
+    type Err = ExecutionError;
+
+    fn execute(
+        self,
+        backend: &mut BackendFacade<NODE_SIZE>,
+    ) -> Result<Self::Ok, Self::Err> {
+        let Some(database) =
+            backend.database_controllers.get_mut_value(&self.name)
+        else {
+            return Err(ExecutionError::DatabaseNotExists(self.name));
+        };
+
+        let corrupted = database.verify();
+        if corrupted.is_empty() {
+            return Ok(CommandResultString {
+                result: format!(
+                    "Database `{}` has no corrupted pages",
+                    self.name
+                ),
+            });
+        }
+
+        let mut table = prettytable::Table::new();
+        table.set_format(*format::consts::FORMAT_NO_BORDER_LINE_SEPARATOR);
+        table.set_titles(row!["schema", "table", "page"]);
+
+        for (schema, table_name, pages) in corrupted {
+            for page in pages {
+                table.add_row(row![
+                    schema.0.as_str(),
+                    table_name.0.as_str(),
+                    page
+                ]);
+            }
+        }
+
+        Ok(CommandResultString {
+            result: table.to_string(),
+        })
+    }
+}
+
+/// Errors that can occur when executing the [`VerifyDatabase`] command.
+#[derive(Debug, Display)]
+pub enum ExecutionError {
+    /// The database not exists.
+    #[display(fmt = "Database `{}` not exists", _0)]
+    DatabaseNotExists(database::Name),
+}
+
+#[cfg(test)]
+mod tests {
+    use backend::{schema, schema::database};
+
+    use crate::api::command::{
+        gateway::{test::TestBackendFacade, GatewayError},
+        Gateway,
+    };
+
+    use super::{ExecutionError, VerifyDatabase};
+
+    #[test]
+    fn verify_db_without_corrupted_pages() {
+        let name = database::Name::from("test");
+        let mut facade = TestBackendFacade::<4>::new()
+            .with_database(name.clone())
+            .with_schema(name.clone(), schema::Name::from("schema"))
+            .with_table(
+                name.clone(),
+                schema::Name::from("schema"),
+                schema::table::Name::from("table"),
+            )
+            .build();
+        let cmd = VerifyDatabase { name: name.clone() };
+        let result = facade.send(cmd);
+        assert!(result.is_ok());
+
+        let result = result.unwrap();
+        assert_eq!(result.result, "Database `test` has no corrupted pages");
+    }
+
+    #[test]
+    fn verify_db_when_not_exists() {
+        let name = database::Name::from("test");
+        let mut facade = TestBackendFacade::<4>::new().build();
+        let cmd = VerifyDatabase { name: name.clone() };
+        let result = facade.send(cmd);
+
+        match result {
+            Err(GatewayError::CommandError(
+                ExecutionError::DatabaseNotExists(db_name),
+            )) => {
+                assert_eq!(name, db_name)
+            }
+            _ => {
+                panic!("Expected `DatabaseNotExists` error, found {:?}", result)
+            }
+        }
+    }
+}
@@ -1,5 +1,8 @@
 use crate::api::command::{
-    backend_api::DatabaseCommand, database::SchemaCommand, schema::TableCommand,
+    backend_api::DatabaseCommand,
+    database::SchemaCommand,
+    schema::{JoinTables, TableCommand},
+    table::DataCommand,
 };
 
 /// Commands that can be executed on the whole backend.
@@ -13,4 +16,10 @@ pub enum BackendCommand {
 
     /// Command to operate on a table.
     Table(TableCommand),
+
+    /// Command to operate on the data of a table.
+    Data(DataCommand),
+
+    /// Command to join the data of two tables of a schema.
+    Join(Box<JoinTables>),
 }
@@ -159,7 +159,9 @@ pub mod test {
             let table =
                 schema.get_mut_table(&table_name).expect("table exists");
             let column = schema::Column::new(data_type);
-            table.add_column(column_name, column);
+            table
+                .add_column(column_name, column)
+                .expect("column is nullable");
 
             self
         }
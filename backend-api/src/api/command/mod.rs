@@ -10,6 +10,8 @@ pub mod table;
 use crate::Context;
 use std::convert::Infallible;
 
+pub use gateway::GatewayError;
+
 /// Trait for commands.
 pub trait Command<Ctx> {
     /// The success type.
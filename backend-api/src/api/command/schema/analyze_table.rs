@@ -0,0 +1,189 @@
+use backend::{
+    controller,
+    controller::table::TableControllerError,
+    schema,
+    schema::{database, table},
+};
+use derive_more::Display;
+
+use crate::api::{
+    command::{Command, DatabaseCommand, SchemaCommand},
+    CommandResultString,
+};
+
+/// [`Command`] to collect the statistics of a table the planner estimates
+/// the costs of queries by.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnalyzeTable {
+    /// The name of the database of the table.
+    pub database_name: Option<database::Name>,
+
+    /// The name of the schema of the table.
+    pub schema_name: Option<schema::Name>,
+
+    /// The name of the table to analyze.
+    pub name: table::Name,
+}
+
+impl DatabaseCommand for AnalyzeTable {
+    fn get_db_name(&self) -> Option<database::Name> {
+        self.database_name.clone()
+    }
+
+    fn get_db_name_mut(&mut self) -> &mut Option<database::Name> {
+        &mut self.database_name
+    }
+}
+
+impl SchemaCommand for AnalyzeTable {
+    fn get_schema_name(&self) -> Option<schema::Name> {
+        self.schema_name.clone()
+    }
+
+    fn get_schema_name_mut(&mut self) -> &mut Option<schema::Name> {
+        &mut self.schema_name
+    }
+}
+
+impl<const NODE_SIZE: u8> Command<controller::Schema<NODE_SIZE>>
+    for AnalyzeTable
+{
+    type Ok = CommandResultString;
+    type Err = ExecutionError;
+
+    /// Analyzes the table and summarizes the statistics with the number of
+    /// rows, e.g. `100 rows`, followed by a line per column, e.g.
+    /// `user_id: 8 distinct, 20 NULL`.
+    fn execute(
+        self,
+        schema_controller: &mut controller::Schema<NODE_SIZE>,
+    ) -> Result<Self::Ok, Self::Err> {
+        let Some(table) = schema_controller.get_mut_table(&self.name) else {
+            return Err(ExecutionError::TableNotFound(
+                self.database_name.expect("exists"),
+                self.schema_name.expect("exists"),
+                self.name,
+            ));
+        };
+
+        let statistics = table
+            .analyze()
+            .map_err(ExecutionError::TableControllerError)?;
+        let mut lines = vec![format!("{} rows", statistics.get_row_count())];
+        for (name, column) in statistics.get_columns() {
+            lines.push(format!(
+                "{}: {} distinct, {} NULL",
+                name,
+                column.get_distinct_count(),
+                column.get_null_count()
+            ));
+        }
+
+        Ok(CommandResultString {
+            result: lines.join("\n"),
+        })
+    }
+}
+
+/// Errors that can occur during the execution of [`AnalyzeTable`].
+#[derive(Debug, Display)]
+pub enum ExecutionError {
+    /// The table doesn't exist in the schema.
+    #[display(fmt = "Table `{}`.`{}`.`{}` not found", _0, _1, _2)]
+    TableNotFound(database::Name, schema::Name, table::Name),
+
+    /// The table controller error.
+    #[display(fmt = "{}", _0)]
+    TableControllerError(TableControllerError),
+}
+
+#[cfg(test)]
+mod tests {
+    use backend::{
+        data::DataUnit,
+        schema::{
+            column::primary_key::PrimaryKey,
+            database,
+            r#type::{
+                r#enum::{StorageData, StorageDataType},
+                DataRow,
+            },
+            table, Column,
+        },
+    };
+
+    use crate::api::command::{
+        gateway::{test::TestBackendFacade, GatewayError},
+        schema::{analyze_table::AnalyzeTable, create_table::CreateTable},
+        table::Insert,
+        Gateway,
+    };
+
+    use super::ExecutionError;
+
+    #[test]
+    fn analyzes_columns() {
+        let database_name = database::Name::from("db");
+        let schema_name = backend::schema::Name::from("test");
+        let table_name = table::Name::from("users");
+
+        let mut facade = TestBackendFacade::<4>::new()
+            .with_database(database_name.clone())
+            .with_schema(database_name.clone(), schema_name.clone())
+            .with_db_in_context(database_name.clone())
+            .with_schema_in_context(schema_name.clone())
+            .build();
+
+        facade
+            .send(CreateTable {
+                database_name: None,
+                schema_name: None,
+                name: table_name.clone(),
+                columns: vec![
+                    ("id".into(), Column::new(StorageDataType::Integer)),
+                    ("age".into(), Column::new(StorageDataType::Integer)),
+                ],
+                primary_key: PrimaryKey::new("users_pkey".into(), "id".into()),
+                constraints: vec![],
+            })
+            .unwrap();
+        let mut data = DataUnit::new(vec!["id".into(), "age".into()]);
+        data.insert(DataRow(vec![StorageData::from(1), StorageData::from(30)]));
+        data.insert(DataRow(vec![StorageData::from(2), StorageData::from(30)]));
+        data.insert(DataRow(vec![StorageData::from(3), StorageData::Null]));
+        facade
+            .send(Insert {
+                database_name: None,
+                schema_name: None,
+                name: table_name.clone(),
+                data,
+            })
+            .unwrap();
+
+        let result = facade
+            .send(AnalyzeTable {
+                database_name: None,
+                schema_name: None,
+                name: table_name,
+            })
+            .unwrap();
+        assert_eq!(
+            result.result,
+            "3 rows\n\
+             id: 3 distinct, 0 NULL\n\
+             age: 1 distinct, 1 NULL"
+        );
+
+        let result = facade.send(AnalyzeTable {
+            database_name: None,
+            schema_name: None,
+            name: "missing".into(),
+        });
+        match result {
+            Err(GatewayError::CommandError(ExecutionError::TableNotFound(
+                ..,
+            ))) => {}
+            result => panic!("Expected `TableNotFound` found {:?}", result),
+        }
+    }
+}
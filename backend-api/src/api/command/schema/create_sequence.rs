@@ -0,0 +1,139 @@
+use backend::{
+    controller,
+    data::id::Sequence,
+    schema,
+    schema::{database, sequence},
+};
+use derive_more::Display;
+
+use crate::api::{
+    command::{Command, DatabaseCommand, SchemaCommand},
+    CommandResultString,
+};
+
+/// [`Command`] to create a new standalone sequence in a schema.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreateSequence {
+    /// The name of the database where the sequence will be created.
+    pub database_name: Option<database::Name>,
+
+    /// The name of the schema where the sequence will be created.
+    pub schema_name: Option<schema::Name>,
+
+    /// The name of the sequence to create.
+    pub name: sequence::Name,
+
+    /// The first value of the sequence.
+    pub start: i128,
+}
+
+impl DatabaseCommand for CreateSequence {
+    fn get_db_name(&self) -> Option<database::Name> {
+        self.database_name.clone()
+    }
+
+    fn get_db_name_mut(&mut self) -> &mut Option<database::Name> {
+        &mut self.database_name
+    }
+}
+
+impl SchemaCommand for CreateSequence {
+    fn get_schema_name(&self) -> Option<schema::Name> {
+        self.schema_name.clone()
+    }
+
+    fn get_schema_name_mut(&mut self) -> &mut Option<schema::Name> {
+        &mut self.schema_name
+    }
+}
+
+impl<const NODE_SIZE: u8> Command<controller::Schema<NODE_SIZE>>
+    for CreateSequence
+{
+    type Ok = CommandResultString;
+    type Err = ExecutionError;
+
+    fn execute(
+        self,
+        schema_controller: &mut controller::Schema<NODE_SIZE>,
+    ) -> Result<Self::Ok, Self::Err> {
+        if schema_controller
+            .add_sequence(self.name.clone(), Sequence::new(self.start))
+        {
+            Ok(CommandResultString {
+                result: format!(
+                    "Sequence `{}`.`{}`.`{}` created",
+                    self.database_name.expect("exists"),
+                    self.schema_name.expect("exists"),
+                    self.name
+                ),
+            })
+        } else {
+            Err(ExecutionError::SequenceAlreadyExists(
+                self.database_name.expect("exists"),
+                self.schema_name.expect("exists"),
+                self.name,
+            ))
+        }
+    }
+}
+
+/// Errors that can occur during the execution of [`CreateSequence`].
+#[derive(Debug, Display)]
+pub enum ExecutionError {
+    /// The sequence already exists in the schema.
+    #[display(fmt = "Sequence `{}`.`{}`.`{}` already exists", _0, _1, _2)]
+    SequenceAlreadyExists(database::Name, schema::Name, sequence::Name),
+}
+
+#[cfg(test)]
+mod tests {
+    use backend::schema::{self, database, sequence};
+    use common::structs::hash_table::MutHashTable;
+
+    use crate::api::command::{
+        gateway::{test::TestBackendFacade, GatewayError},
+        Gateway,
+    };
+
+    use super::{CreateSequence, ExecutionError};
+
+    #[test]
+    fn create_sequence_when_not_exists() {
+        let database_name = database::Name::from("db");
+        let schema_name = schema::Name::from("test");
+        let sequence_name = sequence::Name::from("seq");
+
+        let mut facade = TestBackendFacade::<4>::new()
+            .with_database(database_name.clone())
+            .with_schema(database_name.clone(), schema_name.clone())
+            .with_db_in_context(database_name.clone())
+            .with_schema_in_context(schema_name.clone())
+            .build();
+
+        let cmd = CreateSequence {
+            database_name: None,
+            schema_name: None,
+            name: sequence_name.clone(),
+            start: 10,
+        };
+        assert!(facade.send(cmd.clone()).is_ok());
+
+        let db = facade
+            .database_controllers
+            .get_mut_value(&database_name)
+            .unwrap();
+        let schema = db.get_mut_schema(&schema_name).unwrap();
+        let sequence = schema.get_mut_sequence(&sequence_name).unwrap();
+        assert_eq!(sequence.next_value(), Some(10));
+
+        match facade.send(cmd) {
+            Err(GatewayError::CommandError(
+                ExecutionError::SequenceAlreadyExists(_, _, name),
+            )) => assert_eq!(name, sequence_name),
+            result => {
+                panic!("Expected `SequenceAlreadyExists` found {:?}", result)
+            }
+        }
+    }
+}
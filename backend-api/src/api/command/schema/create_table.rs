@@ -1,9 +1,10 @@
 use backend::{
     controller,
-    controller::table::TableControllerError,
+    controller::{table::TableControllerError, SchemaControllerError},
     schema,
     schema::{
-        column, column::primary_key::PrimaryKey, database, table, Column,
+        column, column::primary_key::PrimaryKey, database, table,
+        table::constraint::Constraint, Column,
     },
 };
 use derive_more::Display;
@@ -30,6 +31,9 @@ pub struct CreateTable {
 
     /// The primary key of the table.
     pub primary_key: PrimaryKey,
+
+    /// The `UNIQUE`, `CHECK` and `FOREIGN KEY` constraints of the table.
+    pub constraints: Vec<Constraint>,
 }
 
 impl DatabaseCommand for CreateTable {
@@ -72,28 +76,40 @@ impl<const NODE_SIZE: u8> Command<controller::Schema<NODE_SIZE>>
 
         let mut table = controller::Table::new(self.name.clone());
         for (column_name, column) in self.columns {
-            table.add_column(column_name, column);
+            table
+                .add_column(column_name, column)
+                .map_err(ExecutionError::TableControllerError)?;
         }
         table
             .set_primary_key(self.primary_key)
             .map_err(ExecutionError::TableControllerError)?;
 
-        if schema_controller.add_table(table) {
-            Ok(CommandResultString {
-                result: format!(
-                    "Table `{}`.`{}`.`{}` created",
-                    self.database_name.expect("exists"),
-                    self.schema_name.expect("exists"),
-                    self.name
-                ),
-            })
-        } else {
-            Err(ExecutionError::TableAlreadyExists(
+        if !schema_controller.add_table(table) {
+            return Err(ExecutionError::TableAlreadyExists(
                 self.database_name.expect("exists"),
                 self.schema_name.expect("exists"),
                 self.name,
-            ))
+            ));
+        }
+        // Constraints are added through the schema, as a `FOREIGN KEY` may
+        // reference the table itself or other tables.
+        for constraint in self.constraints {
+            if let Err(err) =
+                schema_controller.add_constraint(&self.name, constraint)
+            {
+                let _ = schema_controller.remove_table(&self.name);
+                return Err(ExecutionError::SchemaControllerError(err));
+            }
         }
+
+        Ok(CommandResultString {
+            result: format!(
+                "Table `{}`.`{}`.`{}` created",
+                self.database_name.expect("exists"),
+                self.schema_name.expect("exists"),
+                self.name
+            ),
+        })
     }
 }
 
@@ -105,8 +121,12 @@ pub enum ExecutionError {
     TableAlreadyExists(database::Name, schema::Name, table::Name),
 
     /// The table controller error.
-    #[display(fmt = "{:?}", _0)]
+    #[display(fmt = "{}", _0)]
     TableControllerError(TableControllerError),
+
+    /// The schema controller error.
+    #[display(fmt = "{}", _0)]
+    SchemaControllerError(SchemaControllerError),
 }
 
 #[cfg(test)]
@@ -154,6 +174,7 @@ mod tests {
             name: table_name.clone(),
             columns: vec![(column_name.clone(), column.clone())],
             primary_key: primary_key.clone(),
+            constraints: Vec::new(),
         };
 
         test_cases.push((facade, cmd));
@@ -170,6 +191,7 @@ mod tests {
             name: table_name.clone(),
             columns: vec![(column_name.clone(), column.clone())],
             primary_key: primary_key.clone(),
+            constraints: Vec::new(),
         };
 
         test_cases.push((facade, cmd));
@@ -187,6 +209,7 @@ mod tests {
             name: table_name.clone(),
             columns: vec![(column_name.clone(), column.clone())],
             primary_key: primary_key.clone(),
+            constraints: Vec::new(),
         };
 
         test_cases.push((facade, cmd));
@@ -247,6 +270,7 @@ mod tests {
             name: table_name.clone(),
             columns: vec![(column_name.clone(), column.clone())],
             primary_key: primary_key.clone(),
+            constraints: Vec::new(),
         };
 
         let result = facade.send(cmd);
@@ -285,6 +309,7 @@ mod tests {
             name: table_name.clone(),
             columns: vec![(column_name.clone(), column.clone())],
             primary_key: primary_key.clone(),
+            constraints: Vec::new(),
         };
         let result = facade.send(cmd);
         assert!(result.is_err());
@@ -322,6 +347,7 @@ mod tests {
             name: table_name.clone(),
             columns: vec![(column_name.clone(), column.clone())],
             primary_key: primary_key.clone(),
+            constraints: Vec::new(),
         };
         let result = facade.send(cmd);
         assert!(result.is_err());
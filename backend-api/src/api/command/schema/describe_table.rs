@@ -0,0 +1,207 @@
+use backend::{
+    controller, schema,
+    schema::{database, table},
+};
+use derive_more::Display;
+
+use crate::api::{
+    command::{Command, DatabaseCommand, SchemaCommand},
+    CommandResultString,
+};
+
+/// [`Command`] to describe the columns and constraints of a table.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DescribeTable {
+    /// The name of the database of the table.
+    pub database_name: Option<database::Name>,
+
+    /// The name of the schema of the table.
+    pub schema_name: Option<schema::Name>,
+
+    /// The name of the table to describe.
+    pub name: table::Name,
+}
+
+impl DatabaseCommand for DescribeTable {
+    fn get_db_name(&self) -> Option<database::Name> {
+        self.database_name.clone()
+    }
+
+    fn get_db_name_mut(&mut self) -> &mut Option<database::Name> {
+        &mut self.database_name
+    }
+}
+
+impl SchemaCommand for DescribeTable {
+    fn get_schema_name(&self) -> Option<schema::Name> {
+        self.schema_name.clone()
+    }
+
+    fn get_schema_name_mut(&mut self) -> &mut Option<schema::Name> {
+        &mut self.schema_name
+    }
+}
+
+impl<const NODE_SIZE: u8> Command<controller::Schema<NODE_SIZE>>
+    for DescribeTable
+{
+    type Ok = CommandResultString;
+    type Err = ExecutionError;
+
+    /// Describes the table with a line per column, e.g.
+    /// `id i32 NOT NULL AUTO_INCREMENT`, followed by a line per constraint,
+    /// e.g. `CONSTRAINT users_pkey PRIMARY KEY (id)`.
+    fn execute(
+        self,
+        schema_controller: &mut controller::Schema<NODE_SIZE>,
+    ) -> Result<Self::Ok, Self::Err> {
+        let Some(table) = schema_controller.get_mut_table(&self.name) else {
+            return Err(ExecutionError::TableNotFound(
+                self.database_name.expect("exists"),
+                self.schema_name.expect("exists"),
+                self.name,
+            ));
+        };
+
+        let mut lines = Vec::new();
+        for name in table.get_column_names() {
+            let column = table.get_column(&name).expect("column exists");
+            let mut line = format!("{} {}", name, column.get_type());
+            if column.is_not_null() {
+                line.push_str(" NOT NULL");
+            }
+            if let Some(function) = column.get_default_function() {
+                line.push_str(&format!(" DEFAULT {}", function));
+            } else if let Some(value) = column.get_default() {
+                line.push_str(&format!(" DEFAULT {}", value));
+            }
+            if column.is_auto_increment() {
+                line.push_str(" AUTO_INCREMENT");
+            }
+            lines.push(line);
+        }
+        if let Some(primary_key) = table.get_primary_key() {
+            lines.push(format!(
+                "CONSTRAINT {} PRIMARY KEY ({})",
+                primary_key.get_name(),
+                primary_key.get_column()
+            ));
+        }
+        for constraint in table.get_constraints() {
+            lines.push(constraint.to_string());
+        }
+
+        Ok(CommandResultString {
+            result: lines.join("\n"),
+        })
+    }
+}
+
+/// Errors that can occur during the execution of [`DescribeTable`].
+#[derive(Debug, Display)]
+pub enum ExecutionError {
+    /// The table doesn't exist in the schema.
+    #[display(fmt = "Table `{}`.`{}`.`{}` not found", _0, _1, _2)]
+    TableNotFound(database::Name, schema::Name, table::Name),
+}
+
+#[cfg(test)]
+mod tests {
+    use backend::{
+        expression::{BinaryOperator, Expression},
+        schema,
+        schema::{
+            column::primary_key::PrimaryKey,
+            database,
+            r#type::r#enum::{StorageData, StorageDataType},
+            table,
+            table::constraint::{Check, Constraint, Unique},
+            Column,
+        },
+    };
+
+    use crate::api::command::{
+        gateway::{test::TestBackendFacade, GatewayError},
+        schema::{create_table::CreateTable, describe_table::DescribeTable},
+        Gateway,
+    };
+
+    use super::ExecutionError;
+
+    #[test]
+    fn describes_columns_and_constraints() {
+        let database_name = database::Name::from("db");
+        let schema_name = schema::Name::from("test");
+        let table_name = table::Name::from("users");
+
+        let mut facade = TestBackendFacade::<4>::new()
+            .with_database(database_name.clone())
+            .with_schema(database_name.clone(), schema_name.clone())
+            .with_db_in_context(database_name.clone())
+            .with_schema_in_context(schema_name.clone())
+            .build();
+
+        let mut id = Column::new(StorageDataType::Integer);
+        id.set_not_null(true);
+        id.set_auto_increment(true);
+        let mut age = Column::new(StorageDataType::Byte);
+        age.set_default(Some(StorageData::Byte(18.into())));
+        let check = Check::new(
+            "users_age_check".into(),
+            Expression::binary(
+                Expression::Column("age".into()),
+                BinaryOperator::GreaterThanOrEqual,
+                Expression::Literal(18u8.into()),
+            ),
+        );
+        facade
+            .send(CreateTable {
+                database_name: None,
+                schema_name: None,
+                name: table_name.clone(),
+                columns: vec![
+                    ("id".into(), id),
+                    ("email".into(), Column::new(StorageDataType::Text)),
+                    ("age".into(), age),
+                ],
+                primary_key: PrimaryKey::new("users_pkey".into(), "id".into()),
+                constraints: vec![
+                    Constraint::Unique(Unique::new(
+                        "users_email_key".into(),
+                        vec!["email".into()],
+                    )),
+                    Constraint::Check(check),
+                ],
+            })
+            .unwrap();
+
+        let result = facade
+            .send(DescribeTable {
+                database_name: None,
+                schema_name: None,
+                name: table_name,
+            })
+            .unwrap();
+        assert_eq!(
+            result.result,
+            "id i32 NOT NULL AUTO_INCREMENT\n\
+             email text\n\
+             age u8 DEFAULT 18\n\
+             CONSTRAINT users_pkey PRIMARY KEY (id)\n\
+             CONSTRAINT users_email_key UNIQUE (email)\n\
+             CONSTRAINT users_age_check CHECK (age >= 18)"
+        );
+
+        let result = facade.send(DescribeTable {
+            database_name: None,
+            schema_name: None,
+            name: "missing".into(),
+        });
+        match result {
+            Err(GatewayError::CommandError(ExecutionError::TableNotFound(
+                ..,
+            ))) => {}
+            result => panic!("Expected `TableNotFound` found {:?}", result),
+        }
+    }
+}
@@ -1,9 +1,8 @@
-use std::convert::Infallible;
-
 use backend::{
     controller, schema,
-    schema::{database, table},
+    schema::{database, table, table::constraint},
 };
+use derive_more::Display;
 
 use crate::api::{
     command::{Command, DatabaseCommand, SchemaCommand},
@@ -21,6 +20,11 @@ pub struct DropTable {
 
     /// The name of the table to create.
     pub name: table::Name,
+
+    /// Whether the `FOREIGN KEY` constraints of other tables that reference
+    /// the table are dropped as well. The table isn't dropped if it's
+    /// referenced and this is `false`.
+    pub cascade: bool,
 }
 
 impl DatabaseCommand for DropTable {
@@ -55,6 +59,19 @@ impl<const NODE_SIZE: u8> Command<controller::Schema<NODE_SIZE>> for DropTable {
             return Ok(CommandResultString::default());
         }
 
+        let referencing =
+            schema_controller.get_referencing_foreign_keys(&self.name);
+        if let Some((table, foreign_key)) = referencing.first() {
+            if !self.cascade {
+                return Err(ExecutionError::TableIsReferenced(
+                    self.name,
+                    foreign_key.get_name().clone(),
+                    table.clone(),
+                ));
+            }
+            schema_controller.remove_referencing_foreign_keys(&self.name);
+        }
+
         let _ = schema_controller.remove_table(&self.name);
         Ok(CommandResultString {
             result: format!(
@@ -68,20 +85,42 @@ impl<const NODE_SIZE: u8> Command<controller::Schema<NODE_SIZE>> for DropTable {
 }
 
 /// Errors that can occur during the execution of [`DropTable`].
-pub type ExecutionError = Infallible;
+#[derive(Debug, Display)]
+pub enum ExecutionError {
+    /// A `FOREIGN KEY` constraint of another table references the table.
+    #[display(
+        fmt = "Table `{}` is referenced by foreign key `{}` of table `{}`, \
+               use `CASCADE` to drop the constraint as well",
+        _0,
+        _1,
+        _2
+    )]
+    TableIsReferenced(table::Name, constraint::Name, table::Name),
+}
 
 #[cfg(test)]
 mod tests {
-    use backend::{schema, schema::database};
+    use backend::{
+        schema,
+        schema::{
+            column::primary_key::PrimaryKey,
+            database,
+            r#type::r#enum::StorageDataType,
+            table::constraint::{Constraint, ForeignKey, OnDelete},
+            Column,
+        },
+    };
     use common::structs::hash_table::MutHashTable;
 
     use crate::api::command::{
         database::DropSchema,
         gateway::{test::TestBackendFacade, GatewayError},
-        schema::drop_table::DropTable,
+        schema::{create_table::CreateTable, drop_table::DropTable},
         Gateway,
     };
 
+    use super::ExecutionError;
+
     #[test]
     fn drops_table_when_exists() {
         let database_name = database::Name::from("test");
@@ -102,6 +141,7 @@ mod tests {
             database_name: Some(database_name.clone()),
             schema_name: Some(schema_name.clone()),
             name: table_name.clone(),
+            cascade: false,
         };
         let result = facade.send(cmd);
         assert!(result.is_ok());
@@ -135,6 +175,7 @@ mod tests {
             database_name: None,
             schema_name: Some(schema_name.clone()),
             name: table_name.clone(),
+            cascade: false,
         };
         let result = facade.send(cmd);
         assert!(result.is_ok());
@@ -169,6 +210,7 @@ mod tests {
             database_name: None,
             schema_name: None,
             name: table_name.clone(),
+            cascade: false,
         };
         let result = facade.send(cmd);
         assert!(result.is_ok());
@@ -201,6 +243,7 @@ mod tests {
             database_name: None,
             schema_name: None,
             name: table_name.clone(),
+            cascade: false,
         };
         let result = facade.send(cmd);
         assert!(result.is_err());
@@ -226,8 +269,74 @@ mod tests {
             database_name: Some(database_name.clone()),
             schema_name: Some(schema_name.clone()),
             name: table_name.clone(),
+            cascade: false,
         };
         let result = facade.send(cmd);
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn drops_referenced_table_only_with_cascade() {
+        let database_name = database::Name::from("test");
+        let schema_name = schema::Name::from("schema");
+
+        let mut facade = TestBackendFacade::<4>::new()
+            .with_database(database_name.clone())
+            .with_schema(database_name.clone(), schema_name.clone())
+            .with_db_in_context(database_name.clone())
+            .with_schema_in_context(schema_name.clone())
+            .build();
+
+        let create_table =
+            |name: &str, constraints: Vec<Constraint>| CreateTable {
+                database_name: None,
+                schema_name: None,
+                name: name.into(),
+                columns: vec![
+                    ("id".into(), Column::new(StorageDataType::Integer)),
+                    ("user_id".into(), Column::new(StorageDataType::Integer)),
+                ],
+                primary_key: PrimaryKey::new(
+                    format!("{}_pkey", name).into(),
+                    "id".into(),
+                ),
+                constraints,
+            };
+        facade.send(create_table("users", Vec::new())).unwrap();
+        let foreign_key = Constraint::ForeignKey(ForeignKey::new(
+            "orders_user_id_fkey".into(),
+            "user_id".into(),
+            "users".into(),
+            "id".into(),
+            OnDelete::Restrict,
+        ));
+        facade
+            .send(create_table("orders", vec![foreign_key]))
+            .unwrap();
+
+        let drop_users = |cascade: bool| DropTable {
+            database_name: None,
+            schema_name: None,
+            name: "users".into(),
+            cascade,
+        };
+        match facade.send(drop_users(false)) {
+            Err(GatewayError::CommandError(
+                ExecutionError::TableIsReferenced(..),
+            )) => {}
+            result => {
+                panic!("Expected `TableIsReferenced` found {:?}", result)
+            }
+        }
+        facade.send(drop_users(true)).unwrap();
+
+        let db = facade
+            .database_controllers
+            .get_mut_value(&database_name)
+            .unwrap();
+        let schema = db.get_mut_schema(&schema_name).unwrap();
+        assert!(!schema.has_table(&"users".into()));
+        let orders = schema.get_mut_table(&"orders".into()).unwrap();
+        assert!(orders.get_constraints().is_empty());
+    }
 }
@@ -0,0 +1,149 @@
+use backend::{
+    controller,
+    controller::SchemaControllerError,
+    data::DataUnit,
+    query::{Join, JoinedTable},
+    schema,
+    schema::database,
+};
+use derive_more::Display;
+
+use crate::api::command::{Command, DatabaseCommand, SchemaCommand};
+
+/// [`Command`] to join the rows of two tables of a schema, as
+/// `SELECT * FROM left join_type right ON condition`. A table is joined
+/// with itself by aliasing at least one of the sides.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JoinTables {
+    /// The name of the database where the tables are located.
+    pub database_name: Option<database::Name>,
+
+    /// The name of the schema where the tables are located.
+    pub schema_name: Option<schema::Name>,
+
+    /// The left table.
+    pub left: JoinedTable,
+
+    /// The right table.
+    pub right: JoinedTable,
+
+    /// The type and the `ON` condition of the join, its columns are
+    /// qualified by the names or the aliases of their tables, e.g.
+    /// `users.id`.
+    pub join: Join,
+}
+
+impl DatabaseCommand for JoinTables {
+    fn get_db_name(&self) -> Option<database::Name> {
+        self.database_name.clone()
+    }
+
+    fn get_db_name_mut(&mut self) -> &mut Option<database::Name> {
+        &mut self.database_name
+    }
+}
+
+impl SchemaCommand for JoinTables {
+    fn get_schema_name(&self) -> Option<schema::Name> {
+        self.schema_name.clone()
+    }
+
+    fn get_schema_name_mut(&mut self) -> &mut Option<schema::Name> {
+        &mut self.schema_name
+    }
+}
+
+impl<const NODE_SIZE: u8> Command<controller::Schema<NODE_SIZE>>
+    for JoinTables
+{
+    type Ok = DataUnit;
+    type Err = ExecutionError;
+
+    fn execute(
+        self,
+        schema_controller: &mut controller::Schema<NODE_SIZE>,
+    ) -> Result<Self::Ok, Self::Err> {
+        schema_controller
+            .get_joined_data(&self.left, &self.right, &self.join)
+            .map_err(ExecutionError::SchemaControllerError)
+    }
+}
+
+/// Errors that can occur during the execution of [`JoinTables`].
+#[derive(Debug, Display)]
+pub enum ExecutionError {
+    /// The schema controller error.
+    #[display(fmt = "{}", _0)]
+    SchemaControllerError(SchemaControllerError),
+}
+
+#[cfg(test)]
+mod tests {
+    use backend::{
+        controller::SchemaControllerError,
+        query::{Join, JoinedTable},
+        schema::{
+            self, column::primary_key::PrimaryKey, database,
+            r#type::r#enum::StorageDataType,
+        },
+    };
+
+    use crate::api::command::{
+        gateway::{test::TestBackendFacade, GatewayError},
+        schema::CreateTable,
+        Gateway,
+    };
+
+    use super::{ExecutionError, JoinTables};
+
+    #[test]
+    fn joins_existing_tables() {
+        let database_name = database::Name::from("db");
+        let schema_name = schema::Name::from("test");
+
+        let mut facade = TestBackendFacade::<4>::new()
+            .with_database(database_name.clone())
+            .with_schema(database_name.clone(), schema_name.clone())
+            .with_db_in_context(database_name)
+            .with_schema_in_context(schema_name)
+            .build();
+        let cmd = JoinTables {
+            database_name: None,
+            schema_name: None,
+            left: JoinedTable::from("users"),
+            right: JoinedTable::from("orders"),
+            join: Join::cross(),
+        };
+
+        match facade.send(cmd.clone()) {
+            Err(GatewayError::CommandError(
+                ExecutionError::SchemaControllerError(
+                    SchemaControllerError::TableDoesNotExist(name),
+                ),
+            )) => assert_eq!(name, "users".into()),
+            result => panic!("Expected `TableDoesNotExist` found {:?}", result),
+        }
+
+        for name in ["users", "orders"] {
+            facade
+                .send(CreateTable {
+                    database_name: None,
+                    schema_name: None,
+                    name: name.into(),
+                    columns: vec![(
+                        "id".into(),
+                        schema::Column::new(StorageDataType::Integer),
+                    )],
+                    primary_key: PrimaryKey::new("pk".into(), "id".into()),
+                    constraints: Vec::new(),
+                })
+                .unwrap();
+        }
+        let result = facade.send(cmd).unwrap();
+        assert_eq!(
+            result.get_names(),
+            &["users.id".into(), "orders.id".into()]
+        );
+        assert_eq!(result.len(), 0);
+    }
+}
@@ -1,5 +1,10 @@
+mod analyze_table;
+mod create_sequence;
 mod create_table;
+mod describe_table;
 mod drop_table;
+pub mod join_tables;
+mod next_value;
 
 use backend::{controller, schema, schema::database};
 use derive_more::Display;
@@ -15,8 +20,13 @@ use crate::{
     Context,
 };
 
+pub use analyze_table::AnalyzeTable;
+pub use create_sequence::CreateSequence;
 pub use create_table::CreateTable;
+pub use describe_table::DescribeTable;
 pub use drop_table::DropTable;
+pub use join_tables::JoinTables;
+pub use next_value::NextValue;
 
 /// Commands that can be executed on the schema.
 #[derive(Debug, Clone, PartialEq)]
@@ -26,6 +36,15 @@ pub enum TableCommand {
 
     /// Command to drop a table.
     Drop(DropTable),
+
+    /// Command to create a new standalone sequence.
+    CreateSequence(CreateSequence),
+
+    /// Command to describe the columns and constraints of a table.
+    Describe(DescribeTable),
+
+    /// Command to collect the statistics of a table.
+    Analyze(AnalyzeTable),
 }
 
 impl DatabaseCommand for TableCommand {
@@ -33,6 +52,9 @@ impl DatabaseCommand for TableCommand {
         match self {
             TableCommand::Create(command) => command.get_db_name(),
             TableCommand::Drop(command) => command.get_db_name(),
+            TableCommand::CreateSequence(command) => command.get_db_name(),
+            TableCommand::Describe(command) => command.get_db_name(),
+            TableCommand::Analyze(command) => command.get_db_name(),
         }
     }
 
@@ -40,6 +62,9 @@ impl DatabaseCommand for TableCommand {
         match self {
             TableCommand::Create(command) => command.get_db_name_mut(),
             TableCommand::Drop(command) => command.get_db_name_mut(),
+            TableCommand::CreateSequence(command) => command.get_db_name_mut(),
+            TableCommand::Describe(command) => command.get_db_name_mut(),
+            TableCommand::Analyze(command) => command.get_db_name_mut(),
         }
     }
 }
@@ -49,6 +74,9 @@ impl SchemaCommand for TableCommand {
         match self {
             TableCommand::Create(command) => command.get_schema_name(),
             TableCommand::Drop(command) => command.get_schema_name(),
+            TableCommand::CreateSequence(command) => command.get_schema_name(),
+            TableCommand::Describe(command) => command.get_schema_name(),
+            TableCommand::Analyze(command) => command.get_schema_name(),
         }
     }
 
@@ -56,6 +84,11 @@ impl SchemaCommand for TableCommand {
         match self {
             TableCommand::Create(command) => command.get_schema_name_mut(),
             TableCommand::Drop(command) => command.get_schema_name_mut(),
+            TableCommand::CreateSequence(command) => {
+                command.get_schema_name_mut()
+            }
+            TableCommand::Describe(command) => command.get_schema_name_mut(),
+            TableCommand::Analyze(command) => command.get_schema_name_mut(),
         }
     }
 }
@@ -77,6 +110,15 @@ impl<const NODE_SIZE: u8> Command<controller::Schema<NODE_SIZE>>
             TableCommand::Drop(command) => command
                 .execute(schema_controller)
                 .map_err(ExecutionError::DropTable),
+            TableCommand::CreateSequence(command) => command
+                .execute(schema_controller)
+                .map_err(ExecutionError::CreateSequence),
+            TableCommand::Describe(command) => command
+                .execute(schema_controller)
+                .map_err(ExecutionError::DescribeTable),
+            TableCommand::Analyze(command) => command
+                .execute(schema_controller)
+                .map_err(ExecutionError::AnalyzeTable),
         }
     }
 }
@@ -89,6 +131,15 @@ pub enum ExecutionError {
 
     /// Drop table error.
     DropTable(drop_table::ExecutionError),
+
+    /// Create sequence error.
+    CreateSequence(create_sequence::ExecutionError),
+
+    /// Describe table error.
+    DescribeTable(describe_table::ExecutionError),
+
+    /// Analyze table error.
+    AnalyzeTable(analyze_table::ExecutionError),
 }
 
 /// Errors that can occur when executing the [`SchemaCommand`].
@@ -0,0 +1,128 @@
+use backend::{
+    controller, schema,
+    schema::{database, sequence},
+};
+use derive_more::Display;
+
+use crate::api::command::{Command, DatabaseCommand, SchemaCommand};
+
+/// [`Command`] to get the next value of a standalone sequence, the same as
+/// `nextval()`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NextValue {
+    /// The name of the database where the sequence is located.
+    pub database_name: Option<database::Name>,
+
+    /// The name of the schema where the sequence is located.
+    pub schema_name: Option<schema::Name>,
+
+    /// The name of the sequence.
+    pub name: sequence::Name,
+}
+
+impl DatabaseCommand for NextValue {
+    fn get_db_name(&self) -> Option<database::Name> {
+        self.database_name.clone()
+    }
+
+    fn get_db_name_mut(&mut self) -> &mut Option<database::Name> {
+        &mut self.database_name
+    }
+}
+
+impl SchemaCommand for NextValue {
+    fn get_schema_name(&self) -> Option<schema::Name> {
+        self.schema_name.clone()
+    }
+
+    fn get_schema_name_mut(&mut self) -> &mut Option<schema::Name> {
+        &mut self.schema_name
+    }
+}
+
+impl<const NODE_SIZE: u8> Command<controller::Schema<NODE_SIZE>> for NextValue {
+    type Ok = i128;
+    type Err = ExecutionError;
+
+    fn execute(
+        self,
+        schema_controller: &mut controller::Schema<NODE_SIZE>,
+    ) -> Result<Self::Ok, Self::Err> {
+        let Some(sequence) = schema_controller.get_mut_sequence(&self.name)
+        else {
+            return Err(ExecutionError::SequenceNotFound(self.name));
+        };
+
+        sequence
+            .next_value()
+            .ok_or(ExecutionError::SequenceExhausted(self.name))
+    }
+}
+
+/// Errors that can occur during the execution of [`NextValue`].
+#[derive(Debug, Display)]
+pub enum ExecutionError {
+    /// The sequence doesn't exist in the schema.
+    #[display(fmt = "Sequence `{}` not found", _0)]
+    SequenceNotFound(sequence::Name),
+
+    /// The sequence reached its maximum value.
+    #[display(fmt = "Sequence `{}` is exhausted", _0)]
+    SequenceExhausted(sequence::Name),
+}
+
+#[cfg(test)]
+mod tests {
+    use backend::schema::{self, database, sequence};
+
+    use crate::api::command::{
+        gateway::{test::TestBackendFacade, GatewayError},
+        schema::CreateSequence,
+        Gateway,
+    };
+
+    use super::{ExecutionError, NextValue};
+
+    #[test]
+    fn returns_next_values() {
+        let database_name = database::Name::from("db");
+        let schema_name = schema::Name::from("test");
+        let sequence_name = sequence::Name::from("seq");
+
+        let mut facade = TestBackendFacade::<4>::new()
+            .with_database(database_name.clone())
+            .with_schema(database_name.clone(), schema_name.clone())
+            .with_db_in_context(database_name)
+            .with_schema_in_context(schema_name)
+            .build();
+
+        let cmd = NextValue {
+            database_name: None,
+            schema_name: None,
+            name: sequence_name.clone(),
+        };
+        match facade.send(cmd.clone()) {
+            Err(GatewayError::CommandError(
+                ExecutionError::SequenceNotFound(name),
+            )) => assert_eq!(name, sequence_name),
+            result => panic!("Expected `SequenceNotFound` found {:?}", result),
+        }
+
+        facade
+            .send(CreateSequence {
+                database_name: None,
+                schema_name: None,
+                name: sequence_name.clone(),
+                start: i128::MAX - 1,
+            })
+            .unwrap();
+        assert_eq!(facade.send(cmd.clone()).unwrap(), i128::MAX - 1);
+        assert_eq!(facade.send(cmd.clone()).unwrap(), i128::MAX);
+        assert!(matches!(
+            facade.send(cmd),
+            Err(GatewayError::CommandError(
+                ExecutionError::SequenceExhausted(_)
+            ))
+        ));
+    }
+}
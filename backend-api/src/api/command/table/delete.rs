@@ -0,0 +1,213 @@
+use backend::{
+    controller,
+    controller::{table::selector::SelectorFilter, SchemaControllerError},
+    schema,
+    schema::{database, table},
+};
+use derive_more::Display;
+
+use crate::api::command::{
+    Command, DatabaseCommand, SchemaCommand, TableCommand,
+};
+
+/// [`Command`] to delete data from a table in a database.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Delete {
+    /// The name of the database where the table is located.
+    pub database_name: Option<database::Name>,
+
+    /// The name of the schema where the table is located.
+    pub schema_name: Option<schema::Name>,
+
+    /// The name of the table to delete from.
+    pub name: table::Name,
+
+    /// The filters every deleted row passes, all rows are deleted if empty.
+    pub filters: Vec<SelectorFilter>,
+}
+
+impl DatabaseCommand for Delete {
+    fn get_db_name(&self) -> Option<database::Name> {
+        self.database_name.clone()
+    }
+
+    fn get_db_name_mut(&mut self) -> &mut Option<database::Name> {
+        &mut self.database_name
+    }
+}
+
+impl SchemaCommand for Delete {
+    fn get_schema_name(&self) -> Option<schema::Name> {
+        self.schema_name.clone()
+    }
+
+    fn get_schema_name_mut(&mut self) -> &mut Option<schema::Name> {
+        &mut self.schema_name
+    }
+}
+
+impl TableCommand for Delete {
+    fn get_table_name(&self) -> table::Name {
+        self.name.clone()
+    }
+
+    fn get_table_name_mut(&mut self) -> &mut table::Name {
+        &mut self.name
+    }
+}
+
+impl<const NODE_SIZE: u8> Command<controller::Schema<NODE_SIZE>> for Delete {
+    type Ok = DeleteResult;
+    type Err = ExecutionError;
+
+    /// Deletes the rows through the schema, as the `ON DELETE` actions of
+    /// the `FOREIGN KEY` constraints change the referencing tables.
+    fn execute(
+        self,
+        schema_controller: &mut controller::Schema<NODE_SIZE>,
+    ) -> Result<Self::Ok, Self::Err> {
+        let count = schema_controller
+            .remove_data(&self.name, &self.filters)
+            .map_err(ExecutionError::SchemaControllerError)?;
+
+        Ok(DeleteResult { count })
+    }
+}
+
+/// Result of [`Delete`].
+#[derive(Debug, Display, Clone, PartialEq)]
+#[display(fmt = "{} rows affected", count)]
+pub struct DeleteResult {
+    /// The number of rows deleted from the table, without the ones deleted
+    /// by `ON DELETE CASCADE`.
+    pub count: usize,
+}
+
+/// Errors that can occur during the execution of [`Delete`].
+#[derive(Debug, Display)]
+pub enum ExecutionError {
+    /// The schema controller error.
+    #[display(fmt = "{}", _0)]
+    SchemaControllerError(SchemaControllerError),
+}
+
+#[cfg(test)]
+mod tests {
+    use backend::{
+        controller::{
+            table::selector::{DataSelector, FilterType, SelectorFilter},
+            SchemaControllerError,
+        },
+        data::DataUnit,
+        schema,
+        schema::{
+            column, database,
+            r#type::{
+                r#enum::{StorageData, StorageDataType},
+                DataRow,
+            },
+            table,
+        },
+    };
+
+    use crate::api::command::{
+        gateway::{test::TestBackendFacade, GatewayError},
+        table::{Insert, Select},
+        Gateway,
+    };
+
+    use super::{Delete, ExecutionError};
+
+    #[test]
+    fn delete_with_filter() {
+        let database_name = database::Name::from("db");
+        let schema_name = schema::Name::from("test");
+        let table_name = table::Name::from("table");
+        let column_name = column::Name::from("id");
+
+        let mut facade = TestBackendFacade::<4>::new()
+            .with_database(database_name.clone())
+            .with_schema(database_name.clone(), schema_name.clone())
+            .with_table(
+                database_name.clone(),
+                schema_name.clone(),
+                table_name.clone(),
+            )
+            .with_column(
+                database_name.clone(),
+                schema_name.clone(),
+                table_name.clone(),
+                column_name.clone(),
+                StorageDataType::Integer,
+            )
+            .with_primary_key(
+                database_name.clone(),
+                schema_name.clone(),
+                table_name.clone(),
+                column_name.clone(),
+            )
+            .with_db_in_context(database_name.clone())
+            .with_schema_in_context(schema_name.clone())
+            .build();
+
+        let mut data = DataUnit::new(vec![column_name.clone()]);
+        for id in 1..=3 {
+            data.insert(vec![StorageData::from(id)].into());
+        }
+        let cmd = Insert {
+            database_name: None,
+            schema_name: None,
+            name: table_name.clone(),
+            data,
+        };
+        assert!(facade.send(cmd).is_ok());
+
+        let result = facade
+            .send(Delete {
+                database_name: None,
+                schema_name: None,
+                name: table_name.clone(),
+                filters: vec![SelectorFilter {
+                    column_name: column_name.clone(),
+                    value: StorageData::from(2),
+                    filter_type: FilterType::GreaterThanOrEqual,
+                }],
+            })
+            .unwrap();
+        assert_eq!(result.count, 2);
+        assert_eq!(result.to_string(), "2 rows affected");
+
+        let result = facade
+            .send(Select {
+                database_name: None,
+                schema_name: None,
+                name: table_name,
+                selector: DataSelector {
+                    row_names: None,
+                    filters: None,
+                    ..Default::default()
+                },
+                aggregation: None,
+            })
+            .unwrap();
+        assert_eq!(
+            result.get_values(),
+            vec![DataRow::from(vec![StorageData::from(1)])]
+        );
+
+        let result = facade.send(Delete {
+            database_name: None,
+            schema_name: None,
+            name: "missing".into(),
+            filters: Vec::new(),
+        });
+        match result {
+            Err(GatewayError::CommandError(
+                ExecutionError::SchemaControllerError(
+                    SchemaControllerError::TableDoesNotExist(_),
+                ),
+            )) => {}
+            result => panic!("Expected `TableDoesNotExist` found {:?}", result),
+        }
+    }
+}
@@ -1,21 +1,19 @@
 use backend::{
     controller,
-    controller::table::TableControllerError,
+    controller::SchemaControllerError,
     data::DataUnit,
     schema,
     schema::{
-        column, column::primary_key::PrimaryKey, database, table, Column,
+        column, column::primary_key::PrimaryKey, database,
+        r#type::r#enum::StorageData, table, Column,
     },
 };
 use derive_more::Display;
 
 use crate::{
-    api::{
-        command::{
-            schema::ProvideError, Command, ContextReceiver, DatabaseCommand,
-            OptionalBy, SchemaCommand, TableCommand,
-        },
-        CommandResultString,
+    api::command::{
+        schema::ProvideError, Command, ContextReceiver, DatabaseCommand,
+        OptionalBy, SchemaCommand, TableCommand,
     },
     Context,
 };
@@ -66,32 +64,41 @@ impl TableCommand for Insert {
     }
 }
 
-impl<const NODE_SIZE: u8> Command<controller::Table<NODE_SIZE>> for Insert {
-    type Ok = CommandResultString;
+impl<const NODE_SIZE: u8> Command<controller::Schema<NODE_SIZE>> for Insert {
+    type Ok = InsertResult;
     type Err = ExecutionError;
 
+    /// Inserts the data through the schema, as values of the `FOREIGN KEY`
+    /// columns must be in the referenced tables.
     fn execute(
         self,
-        table_controller: &mut controller::Table<NODE_SIZE>,
+        schema_controller: &mut controller::Schema<NODE_SIZE>,
     ) -> Result<Self::Ok, Self::Err> {
-        let rows_affected = self.data.len();
-
-        table_controller
-            .add_data(self.data)
-            .map_err(ExecutionError::TableControllerError)?;
+        let keys = schema_controller
+            .add_data(&self.name, self.data)
+            .map_err(ExecutionError::SchemaControllerError)?;
 
-        Ok(CommandResultString {
-            result: format!("{} rows affected", rows_affected),
+        Ok(InsertResult {
+            keys: keys.into_iter().map(Into::into).collect(),
         })
     }
 }
 
+/// Result of [`Insert`].
+#[derive(Debug, Display, Clone, PartialEq)]
+#[display(fmt = "{} rows affected", "keys.len()")]
+pub struct InsertResult {
+    /// The primary keys of the inserted rows in the order of the rows,
+    /// including the generated ones, e.g. of `AUTO_INCREMENT` columns.
+    pub keys: Vec<StorageData>,
+}
+
 /// Errors that can occur during the execution of [`Insert`].
 #[derive(Debug, Display)]
 pub enum ExecutionError {
-    /// The table controller error.
-    #[display(fmt = "{:?}", _0)]
-    TableControllerError(TableControllerError),
+    /// The schema controller error.
+    #[display(fmt = "{}", _0)]
+    SchemaControllerError(SchemaControllerError),
 }
 
 #[cfg(test)]
@@ -1,7 +1,97 @@
+mod delete;
 mod insert;
+pub mod select;
 
+use backend::{
+    controller,
+    data::DataUnit,
+    schema,
+    schema::{database, table},
+};
 use derive_more::Display;
 
+use crate::api::command::{
+    Command, DatabaseCommand, SchemaCommand, TableCommand,
+};
+
+pub use delete::Delete;
+pub use insert::Insert;
+pub use select::Select;
+
+/// Commands that can be executed on the data of a table.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DataCommand {
+    /// Command to select rows of a table.
+    Select(Box<Select>),
+}
+
+impl DatabaseCommand for DataCommand {
+    fn get_db_name(&self) -> Option<database::Name> {
+        match self {
+            DataCommand::Select(command) => command.get_db_name(),
+        }
+    }
+
+    fn get_db_name_mut(&mut self) -> &mut Option<database::Name> {
+        match self {
+            DataCommand::Select(command) => command.get_db_name_mut(),
+        }
+    }
+}
+
+impl SchemaCommand for DataCommand {
+    fn get_schema_name(&self) -> Option<schema::Name> {
+        match self {
+            DataCommand::Select(command) => command.get_schema_name(),
+        }
+    }
+
+    fn get_schema_name_mut(&mut self) -> &mut Option<schema::Name> {
+        match self {
+            DataCommand::Select(command) => command.get_schema_name_mut(),
+        }
+    }
+}
+
+impl TableCommand for DataCommand {
+    fn get_table_name(&self) -> table::Name {
+        match self {
+            DataCommand::Select(command) => command.get_table_name(),
+        }
+    }
+
+    fn get_table_name_mut(&mut self) -> &mut table::Name {
+        match self {
+            DataCommand::Select(command) => command.get_table_name_mut(),
+        }
+    }
+}
+
+impl<const NODE_SIZE: u8> Command<controller::Table<NODE_SIZE>>
+    for DataCommand
+{
+    type Ok = DataUnit;
+    type Err = ExecutionError;
+
+    fn execute(
+        self,
+        table_controller: &mut controller::Table<NODE_SIZE>,
+    ) -> Result<Self::Ok, Self::Err> {
+        match self {
+            DataCommand::Select(command) => command
+                .execute(table_controller)
+                .map_err(ExecutionError::Select),
+        }
+    }
+}
+
+/// Errors that can occur during the execution of [`DataCommand`].
+#[derive(Debug, Display)]
+pub enum ExecutionError {
+    /// Select error.
+    Select(select::ExecutionError),
+}
+
 /// Errors that can occur when executing the [`TableCommand`].
 #[derive(Debug, Display)]
 pub enum ProvideError {
@@ -0,0 +1,181 @@
+use backend::{
+    controller,
+    controller::table::{selector::DataSelector, TableControllerError},
+    data::DataUnit,
+    query::Aggregation,
+    schema,
+    schema::{database, table},
+};
+use derive_more::Display;
+
+use crate::api::command::{
+    Command, DatabaseCommand, SchemaCommand, TableCommand,
+};
+
+/// [`Command`] to select data from a table in a database.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Select {
+    /// The name of the database where the table is located.
+    pub database_name: Option<database::Name>,
+
+    /// The name of the schema where the table is located.
+    pub schema_name: Option<schema::Name>,
+
+    /// The name of the table to select from.
+    pub name: table::Name,
+
+    /// The columns and filters of the selection.
+    pub selector: DataSelector,
+
+    /// The aggregation of the selected rows, the filters of the selector are
+    /// applied before and the rest of it after the aggregation.
+    pub aggregation: Option<Aggregation>,
+}
+
+impl DatabaseCommand for Select {
+    fn get_db_name(&self) -> Option<database::Name> {
+        self.database_name.clone()
+    }
+
+    fn get_db_name_mut(&mut self) -> &mut Option<database::Name> {
+        &mut self.database_name
+    }
+}
+
+impl SchemaCommand for Select {
+    fn get_schema_name(&self) -> Option<schema::Name> {
+        self.schema_name.clone()
+    }
+
+    fn get_schema_name_mut(&mut self) -> &mut Option<schema::Name> {
+        &mut self.schema_name
+    }
+}
+
+impl TableCommand for Select {
+    fn get_table_name(&self) -> table::Name {
+        self.name.clone()
+    }
+
+    fn get_table_name_mut(&mut self) -> &mut table::Name {
+        &mut self.name
+    }
+}
+
+impl<const NODE_SIZE: u8> Command<controller::Table<NODE_SIZE>> for Select {
+    type Ok = DataUnit;
+    type Err = ExecutionError;
+
+    fn execute(
+        self,
+        table_controller: &mut controller::Table<NODE_SIZE>,
+    ) -> Result<Self::Ok, Self::Err> {
+        match self.aggregation {
+            Some(aggregation) => table_controller
+                .get_aggregated_data(self.selector, &aggregation),
+            None => table_controller.get_data(self.selector),
+        }
+        .map_err(ExecutionError::TableControllerError)
+    }
+}
+
+/// Errors that can occur during the execution of [`Select`].
+#[derive(Debug, Display)]
+pub enum ExecutionError {
+    /// The table controller error.
+    #[display(fmt = "{}", _0)]
+    TableControllerError(TableControllerError),
+}
+
+#[cfg(test)]
+mod tests {
+    use backend::{
+        controller::table::selector::{
+            DataSelector, FilterType, SelectorFilter,
+        },
+        data::DataUnit,
+        schema,
+        schema::{
+            column, database,
+            r#type::{
+                r#enum::{StorageData, StorageDataType},
+                DataRow,
+            },
+            table,
+        },
+    };
+
+    use crate::api::command::{
+        gateway::test::TestBackendFacade, table::Insert, Gateway,
+    };
+
+    use super::Select;
+
+    #[test]
+    fn select_with_filter() {
+        let database_name = database::Name::from("db");
+        let schema_name = schema::Name::from("test");
+        let table_name = table::Name::from("table");
+        let column_name = column::Name::from("id");
+
+        let mut facade = TestBackendFacade::<4>::new()
+            .with_database(database_name.clone())
+            .with_schema(database_name.clone(), schema_name.clone())
+            .with_table(
+                database_name.clone(),
+                schema_name.clone(),
+                table_name.clone(),
+            )
+            .with_column(
+                database_name.clone(),
+                schema_name.clone(),
+                table_name.clone(),
+                column_name.clone(),
+                StorageDataType::Integer,
+            )
+            .with_primary_key(
+                database_name.clone(),
+                schema_name.clone(),
+                table_name.clone(),
+                column_name.clone(),
+            )
+            .build();
+
+        let mut data = DataUnit::new(vec![column_name.clone()]);
+        for id in 1..=3 {
+            data.insert(vec![StorageData::from(id)].into());
+        }
+        let cmd = Insert {
+            database_name: Some(database_name.clone()),
+            schema_name: Some(schema_name.clone()),
+            name: table_name.clone(),
+            data,
+        };
+        assert!(facade.send(cmd).is_ok());
+
+        let cmd = Select {
+            database_name: Some(database_name),
+            schema_name: Some(schema_name),
+            name: table_name,
+            selector: DataSelector {
+                row_names: None,
+                filters: Some(vec![SelectorFilter {
+                    column_name,
+                    value: StorageData::from(2),
+                    filter_type: FilterType::GreaterThanOrEqual,
+                }]),
+                ..Default::default()
+            },
+            aggregation: None,
+        };
+
+        let result = facade.send(cmd).unwrap();
+        assert_eq!(
+            result.get_values(),
+            vec![
+                DataRow::from(vec![StorageData::from(2)]),
+                DataRow::from(vec![StorageData::from(3)]),
+            ]
+        );
+    }
+}
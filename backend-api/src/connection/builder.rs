@@ -0,0 +1,634 @@
+use backend::{
+    controller::table::selector::{DataSelector, OrderBy, SelectorFilter},
+    data::DataUnit,
+    expression::Expression,
+    query::{Aggregate, Aggregation},
+    schema::{
+        column,
+        column::{primary_key, primary_key::PrimaryKey},
+        r#type::r#enum::{StorageData, StorageDataType},
+        table,
+        table::constraint::{
+            self, Check, Constraint, ForeignKey, OnDelete, Unique,
+        },
+        Column,
+    },
+};
+
+use crate::{
+    api::command::{
+        schema::CreateTable,
+        table::{Delete, Insert, Select},
+    },
+    connection::{mapping::FromRow, rows::Rows, Connection, Error},
+};
+
+/// Builder of a table created by [`Connection::create_table`].
+#[derive(Debug)]
+pub struct CreateTableBuilder<'a, const NODE_SIZE: u8> {
+    /// The connection to create the table with.
+    connection: &'a mut Connection<NODE_SIZE>,
+
+    /// The name of the table.
+    name: table::Name,
+
+    /// The columns of the table in the order they were added.
+    columns: Vec<(column::Name, Column)>,
+
+    /// The name of the primary key column.
+    primary_key: Option<column::Name>,
+
+    /// The `UNIQUE`, `CHECK` and `FOREIGN KEY` constraints of the table.
+    constraints: Vec<Constraint>,
+}
+
+impl<'a, const NODE_SIZE: u8> CreateTableBuilder<'a, NODE_SIZE> {
+    /// Creates a new [`CreateTableBuilder`].
+    pub(super) fn new(
+        connection: &'a mut Connection<NODE_SIZE>,
+        name: table::Name,
+    ) -> Self {
+        Self {
+            connection,
+            name,
+            columns: Vec::new(),
+            primary_key: None,
+            constraints: Vec::new(),
+        }
+    }
+
+    /// Adds a column of the given type.
+    /// # Arguments
+    /// * `name` - The name of the column.
+    /// * `type_` - The type of the column.
+    pub fn column<N: Into<column::Name>>(
+        self,
+        name: N,
+        type_: StorageDataType,
+    ) -> Self {
+        self.column_with(name, Column::new(type_))
+    }
+
+    /// Adds a column with the given definition, e.g. with a default value.
+    /// # Arguments
+    /// * `name` - The name of the column.
+    /// * `column` - The definition of the column.
+    pub fn column_with<N: Into<column::Name>>(
+        mut self,
+        name: N,
+        column: Column,
+    ) -> Self {
+        self.columns.push((name.into(), column));
+        self
+    }
+
+    /// Sets the primary key column.
+    /// # Arguments
+    /// * `name` - The name of the column.
+    pub fn primary_key<N: Into<column::Name>>(mut self, name: N) -> Self {
+        self.primary_key = Some(name.into());
+        self
+    }
+
+    /// Adds a `UNIQUE` constraint named `<table>_<columns>_key`.
+    /// # Arguments
+    /// * `columns` - The names of the columns whose values must be unique.
+    pub fn unique<I, N>(self, columns: I) -> Self
+    where
+        I: IntoIterator<Item = N>,
+        N: Into<column::Name>,
+    {
+        let columns = columns.into_iter().map(Into::into).collect::<Vec<_>>();
+        let names = columns
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join("_");
+        let name = self.constraint_name(format!("{}_{}_key", self.name, names));
+
+        self.constraint(Constraint::Unique(Unique::new(name, columns)))
+    }
+
+    /// Adds a `CHECK` constraint named `<table>_<column>_check` if the
+    /// condition refers to a single column, e.g. `age >= 18`, or
+    /// `<table>_check` otherwise.
+    /// # Arguments
+    /// * `expression` - The condition the rows must satisfy.
+    pub fn check(self, expression: Expression) -> Self {
+        let name = match expression.columns().as_slice() {
+            [column] => format!("{}_{}_check", self.name, column),
+            _ => format!("{}_check", self.name),
+        };
+        let name = self.constraint_name(name);
+
+        self.constraint(Constraint::Check(Check::new(name, expression)))
+    }
+
+    /// Adds a `FOREIGN KEY` constraint named `<table>_<column>_fkey`.
+    /// # Arguments
+    /// * `column` - The name of the referencing column.
+    /// * `table` - The name of the referenced table.
+    /// * `referenced_column` - The name of the referenced column, the primary
+    ///   key or a column with a `UNIQUE` constraint.
+    /// * `on_delete` - The action on deletion of a referenced row.
+    pub fn foreign_key<C, T, R>(
+        self,
+        column: C,
+        table: T,
+        referenced_column: R,
+        on_delete: OnDelete,
+    ) -> Self
+    where
+        C: Into<column::Name>,
+        T: Into<table::Name>,
+        R: Into<column::Name>,
+    {
+        let column = column.into();
+        let name =
+            self.constraint_name(format!("{}_{}_fkey", self.name, column));
+        let foreign_key = ForeignKey::new(
+            name,
+            column,
+            table.into(),
+            referenced_column.into(),
+            on_delete,
+        );
+
+        self.constraint(Constraint::ForeignKey(foreign_key))
+    }
+
+    /// Adds a constraint with any name and condition.
+    /// # Arguments
+    /// * `constraint` - The constraint to add.
+    pub fn constraint(mut self, constraint: Constraint) -> Self {
+        self.constraints.push(constraint);
+        self
+    }
+
+    /// Returns the name, with a number appended if a constraint of the table
+    /// already has it.
+    fn constraint_name(&self, name: String) -> constraint::Name {
+        let is_taken = |name: &str| {
+            self.constraints
+                .iter()
+                .any(|constraint| constraint.get_name().0 == name)
+        };
+        if !is_taken(&name) {
+            return name.into();
+        }
+
+        (1..)
+            .map(|number| format!("{}{}", name, number))
+            .find(|name| !is_taken(name))
+            .expect("exists because the range is unbounded")
+            .into()
+    }
+
+    /// Creates the table.
+    pub fn execute(self) -> Result<(), Error> {
+        let Some(column) = self.primary_key else {
+            return Err(Error::PrimaryKeyNotProvided(self.name));
+        };
+        let key_name = primary_key::Name(format!("{}_pkey", self.name));
+
+        self.connection.send(CreateTable {
+            database_name: None,
+            schema_name: None,
+            name: self.name,
+            columns: self.columns,
+            primary_key: PrimaryKey::new(key_name, column),
+            constraints: self.constraints,
+        })?;
+
+        Ok(())
+    }
+}
+
+/// Builder of rows inserted by [`Connection::insert`].
+#[derive(Debug)]
+pub struct InsertBuilder<'a, const NODE_SIZE: u8> {
+    /// The connection to insert the rows with.
+    connection: &'a mut Connection<NODE_SIZE>,
+
+    /// The name of the table.
+    table: table::Name,
+
+    /// The names of the columns the values of rows belong to.
+    columns: Vec<column::Name>,
+
+    /// The rows to insert.
+    rows: Vec<Vec<StorageData>>,
+}
+
+impl<'a, const NODE_SIZE: u8> InsertBuilder<'a, NODE_SIZE> {
+    /// Creates a new [`InsertBuilder`].
+    pub(super) fn new(
+        connection: &'a mut Connection<NODE_SIZE>,
+        table: table::Name,
+    ) -> Self {
+        Self {
+            connection,
+            table,
+            columns: Vec::new(),
+            rows: Vec::new(),
+        }
+    }
+
+    /// Sets the columns the values of rows belong to. Columns that are not
+    /// set are filled with their default values.
+    /// # Arguments
+    /// * `columns` - The names of the columns.
+    pub fn columns<I, N>(mut self, columns: I) -> Self
+    where
+        I: IntoIterator<Item = N>,
+        N: Into<column::Name>,
+    {
+        self.columns = columns.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Adds a row to insert.
+    /// # Arguments
+    /// * `values` - The values in the order of the columns.
+    pub fn row<I: IntoIterator<Item = StorageData>>(
+        mut self,
+        values: I,
+    ) -> Self {
+        self.rows.push(values.into_iter().collect());
+        self
+    }
+
+    /// Inserts the rows.
+    /// # Returns
+    /// * `Ok(usize)` - The number of inserted rows.
+    /// * `Err(Error)` - If any row can't be inserted.
+    pub fn execute(self) -> Result<usize, Error> {
+        Ok(self.execute_returning_keys()?.len())
+    }
+
+    /// Inserts the rows and returns their primary keys, e.g. to get the
+    /// values generated for an `AUTO_INCREMENT` primary key.
+    /// # Returns
+    /// * `Ok(Vec<StorageData>)` - The primary keys in the order of the rows.
+    /// * `Err(Error)` - If any row can't be inserted.
+    pub fn execute_returning_keys(self) -> Result<Vec<StorageData>, Error> {
+        let mut data = DataUnit::new(self.columns.clone());
+        for row in self.rows {
+            if row.len() != self.columns.len() {
+                return Err(Error::RowLengthMismatch {
+                    expected: self.columns.len(),
+                    got: row.len(),
+                });
+            }
+            data.insert(row.into());
+        }
+
+        let result = self.connection.send(Insert {
+            database_name: None,
+            schema_name: None,
+            name: self.table,
+            data,
+        })?;
+
+        Ok(result.keys)
+    }
+}
+
+/// Builder of a query started by [`Connection::query`].
+#[derive(Debug)]
+pub struct QueryBuilder<'a, const NODE_SIZE: u8> {
+    /// The connection to query with.
+    connection: &'a mut Connection<NODE_SIZE>,
+
+    /// The name of the table.
+    table: table::Name,
+
+    /// The names of the selected columns, `None` means all columns.
+    columns: Option<Vec<column::Name>>,
+
+    /// The filters every returned row passes.
+    filters: Vec<SelectorFilter>,
+
+    /// The `ORDER BY` items of the query.
+    order_by: Vec<OrderBy>,
+
+    /// The maximum number of returned rows.
+    limit: Option<usize>,
+
+    /// The number of skipped rows.
+    offset: usize,
+
+    /// The `GROUP BY` columns, aggregates and `HAVING` of the query.
+    aggregation: Aggregation,
+}
+
+impl<'a, const NODE_SIZE: u8> QueryBuilder<'a, NODE_SIZE> {
+    /// Creates a new [`QueryBuilder`].
+    pub(super) fn new(
+        connection: &'a mut Connection<NODE_SIZE>,
+        table: table::Name,
+    ) -> Self {
+        Self {
+            connection,
+            table,
+            columns: None,
+            filters: Vec::new(),
+            order_by: Vec::new(),
+            limit: None,
+            offset: 0,
+            aggregation: Aggregation::default(),
+        }
+    }
+
+    /// Selects only the given columns.
+    /// # Arguments
+    /// * `columns` - The names of the columns.
+    pub fn columns<I, N>(mut self, columns: I) -> Self
+    where
+        I: IntoIterator<Item = N>,
+        N: Into<column::Name>,
+    {
+        self.columns = Some(columns.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Adds a filter, built with [`column`]. Rows must pass all filters.
+    ///
+    /// [`column`]: crate::connection::column
+    pub fn filter(mut self, filter: SelectorFilter) -> Self {
+        self.filters.push(filter);
+        self
+    }
+
+    /// Adds an `ORDER BY` item, built with [`column`] or [`OrderBy`]. Rows
+    /// are sorted by the first item, then by the second and so on.
+    ///
+    /// [`column`]: crate::connection::column
+    pub fn order_by(mut self, order_by: OrderBy) -> Self {
+        self.order_by.push(order_by);
+        self
+    }
+
+    /// Groups rows by the column. The query returns a row for every group
+    /// with the `GROUP BY` columns followed by the aggregates.
+    pub fn group_by<N: Into<column::Name>>(mut self, column: N) -> Self {
+        self.aggregation
+            .group_by
+            .push(Expression::Column(column.into()));
+        self
+    }
+
+    /// Adds an aggregate, built with [`column`] or [`Aggregate`]. Without
+    /// [`QueryBuilder::group_by`] all rows form a single group.
+    ///
+    /// [`column`]: crate::connection::column
+    pub fn aggregate(mut self, aggregate: Aggregate) -> Self {
+        self.aggregation.aggregates.push(aggregate);
+        self
+    }
+
+    /// Keeps only groups that pass the condition. Aggregates are referred
+    /// to by their names, e.g. `COUNT(*)`.
+    pub fn having(mut self, condition: Expression) -> Self {
+        self.aggregation.having = Some(condition);
+        self
+    }
+
+    /// Returns at most `limit` rows.
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Skips the first `offset` rows.
+    pub fn offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Executes the query and maps the rows to the type.
+    pub fn fetch_as<T: FromRow>(self) -> Result<Vec<T>, Error> {
+        let rows = self.fetch()?;
+
+        rows.iter()
+            .map(|row| T::from_row(row).map_err(Error::Row))
+            .collect()
+    }
+
+    /// Executes the query.
+    pub fn fetch(self) -> Result<Rows, Error> {
+        let filters = if self.filters.is_empty() {
+            None
+        } else {
+            Some(self.filters)
+        };
+
+        let data = self.connection.send(Select {
+            database_name: None,
+            schema_name: None,
+            name: self.table,
+            selector: DataSelector {
+                row_names: self.columns,
+                filters,
+                order_by: self.order_by,
+                limit: self.limit,
+                offset: self.offset,
+                ..Default::default()
+            },
+            aggregation: (self.aggregation != Aggregation::default())
+                .then_some(self.aggregation),
+        })?;
+
+        Ok(data.into())
+    }
+}
+
+/// Builder of a deletion started by [`Connection::delete`].
+#[derive(Debug)]
+pub struct DeleteBuilder<'a, const NODE_SIZE: u8> {
+    /// The connection to delete with.
+    connection: &'a mut Connection<NODE_SIZE>,
+
+    /// The name of the table.
+    table: table::Name,
+
+    /// The filters every deleted row passes.
+    filters: Vec<SelectorFilter>,
+}
+
+impl<'a, const NODE_SIZE: u8> DeleteBuilder<'a, NODE_SIZE> {
+    /// Creates a new [`DeleteBuilder`].
+    pub(super) fn new(
+        connection: &'a mut Connection<NODE_SIZE>,
+        table: table::Name,
+    ) -> Self {
+        Self {
+            connection,
+            table,
+            filters: Vec::new(),
+        }
+    }
+
+    /// Adds a filter, built with [`column`]. Rows must pass all filters,
+    /// all rows are deleted without filters.
+    ///
+    /// [`column`]: crate::connection::column
+    pub fn filter(mut self, filter: SelectorFilter) -> Self {
+        self.filters.push(filter);
+        self
+    }
+
+    /// Deletes the rows and applies the `ON DELETE` actions of the
+    /// `FOREIGN KEY` constraints that reference them.
+    /// # Returns
+    /// * `Ok(usize)` - The number of rows deleted from the table.
+    /// * `Err(Error)` - If a `RESTRICT` constraint references a row, in which
+    ///   case no rows are deleted.
+    pub fn execute(self) -> Result<usize, Error> {
+        let result = self.connection.send(Delete {
+            database_name: None,
+            schema_name: None,
+            name: self.table,
+            filters: self.filters,
+        })?;
+
+        Ok(result.count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use backend::schema::{
+        r#type::{
+            data_types::VarChar,
+            r#enum::{StorageData, StorageDataType},
+        },
+        Column,
+    };
+
+    use crate::connection::{column, Connection, Error};
+
+    fn connection() -> Connection<4> {
+        let mut connection = Connection::<4>::open("db").unwrap();
+        let mut active = Column::new(StorageDataType::Bool);
+        active.set_default(Some(true.into()));
+
+        connection
+            .create_table("users")
+            .column("id", StorageDataType::Integer)
+            .column("name", StorageDataType::VarChar(16))
+            .column_with("active", active)
+            .primary_key("id")
+            .execute()
+            .unwrap();
+
+        connection
+    }
+
+    fn name(value: &str) -> StorageData {
+        VarChar::new(value.to_string()).unwrap().into()
+    }
+
+    #[test]
+    fn test_create_table_without_primary_key() {
+        let mut connection = Connection::<4>::open("db").unwrap();
+
+        let result = connection
+            .create_table("users")
+            .column("id", StorageDataType::Integer)
+            .execute();
+
+        assert_eq!(result, Err(Error::PrimaryKeyNotProvided("users".into())));
+    }
+
+    #[test]
+    fn test_create_table_twice() {
+        let mut connection = connection();
+
+        let result = connection
+            .create_table("users")
+            .column("id", StorageDataType::Integer)
+            .primary_key("id")
+            .execute();
+
+        assert!(matches!(result, Err(Error::Command(_))));
+    }
+
+    #[test]
+    fn test_insert_and_query() {
+        let mut connection = connection();
+
+        let inserted = connection
+            .insert("users")
+            .columns(["name", "id"])
+            .row([name("ann"), 1.into()])
+            .row([name("bob"), 2.into()])
+            .row([name("carol"), 3.into()])
+            .execute()
+            .unwrap();
+        assert_eq!(inserted, 3);
+
+        let rows = connection
+            .query("users")
+            .columns(["name", "active"])
+            .filter(column("id").gt(1))
+            .filter(column("name").ne(name("carol")))
+            .fetch()
+            .unwrap();
+
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows.columns(), &["name".into(), "active".into()]);
+        let row = rows.get(0).unwrap();
+        assert_eq!(row.get("name"), Some(&name("bob")));
+        assert_eq!(row.get("active"), Some(&true.into()));
+        assert_eq!(row.get("id"), None);
+    }
+
+    #[test]
+    fn test_query_all_columns() {
+        let mut connection = connection();
+        connection
+            .insert("users")
+            .columns(["id", "name", "active"])
+            .row([1.into(), name("ann"), false.into()])
+            .execute()
+            .unwrap();
+
+        let rows = connection.query("users").fetch().unwrap();
+
+        let values = rows.iter().map(|row| row.values()).collect::<Vec<_>>();
+        assert_eq!(values, vec![&[1.into(), name("ann"), false.into()][..]]);
+    }
+
+    #[test]
+    fn test_insert_errors() {
+        let mut connection = connection();
+
+        let result = connection
+            .insert("users")
+            .columns(["id", "name"])
+            .row([1.into()])
+            .execute();
+        assert_eq!(
+            result,
+            Err(Error::RowLengthMismatch {
+                expected: 2,
+                got: 1
+            })
+        );
+
+        let result = connection
+            .insert("users")
+            .columns(["id"])
+            .row([1.into()])
+            .execute();
+        assert!(matches!(result, Err(Error::Command(_))));
+
+        let result = connection
+            .insert("missing")
+            .columns(["id"])
+            .row([1.into()])
+            .execute();
+        assert!(matches!(result, Err(Error::Command(_))));
+    }
+}
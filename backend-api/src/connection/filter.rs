@@ -0,0 +1,117 @@
+use backend::{
+    controller::table::selector::{FilterType, OrderBy, SelectorFilter},
+    expression::{BinaryOperator, Expression},
+    query::{Aggregate, AggregateFunction},
+    schema::{column, r#type::r#enum::StorageData},
+};
+
+/// Starts building a [`SelectorFilter`], an [`OrderBy`] or an [`Aggregate`]
+/// for a column.
+/// # Arguments
+/// * `name` - The name of the column.
+pub fn column<N: Into<column::Name>>(name: N) -> ColumnFilter {
+    ColumnFilter { name: name.into() }
+}
+
+/// Builder of a [`SelectorFilter`], an [`OrderBy`] or an [`Aggregate`] for a
+/// column.
+#[derive(Debug, Clone)]
+pub struct ColumnFilter {
+    /// The name of the filtered column.
+    name: column::Name,
+}
+
+impl ColumnFilter {
+    /// Keeps rows where the column is equal to the value.
+    pub fn eq<V: Into<StorageData>>(self, value: V) -> SelectorFilter {
+        self.build(FilterType::Equal, value)
+    }
+
+    /// Keeps rows where the column is not equal to the value.
+    pub fn ne<V: Into<StorageData>>(self, value: V) -> SelectorFilter {
+        self.build(FilterType::NotEqual, value)
+    }
+
+    /// Keeps rows where the column is greater than the value.
+    pub fn gt<V: Into<StorageData>>(self, value: V) -> SelectorFilter {
+        self.build(FilterType::GreaterThan, value)
+    }
+
+    /// Keeps rows where the column is less than the value.
+    pub fn lt<V: Into<StorageData>>(self, value: V) -> SelectorFilter {
+        self.build(FilterType::LessThan, value)
+    }
+
+    /// Keeps rows where the column is greater than or equal to the value.
+    pub fn ge<V: Into<StorageData>>(self, value: V) -> SelectorFilter {
+        self.build(FilterType::GreaterThanOrEqual, value)
+    }
+
+    /// Keeps rows where the column is less than or equal to the value.
+    pub fn le<V: Into<StorageData>>(self, value: V) -> SelectorFilter {
+        self.build(FilterType::LessThanOrEqual, value)
+    }
+
+    /// Sorts rows by the column in ascending order.
+    pub fn asc(self) -> OrderBy {
+        OrderBy::asc(Expression::Column(self.name))
+    }
+
+    /// Sorts rows by the column in descending order.
+    pub fn desc(self) -> OrderBy {
+        OrderBy::desc(Expression::Column(self.name))
+    }
+
+    /// Counts the non-`NULL` values of the column.
+    pub fn count(self) -> Aggregate {
+        self.aggregate(AggregateFunction::Count)
+    }
+
+    /// Sums the values of the column.
+    pub fn sum(self) -> Aggregate {
+        self.aggregate(AggregateFunction::Sum)
+    }
+
+    /// Averages the values of the column.
+    pub fn avg(self) -> Aggregate {
+        self.aggregate(AggregateFunction::Avg)
+    }
+
+    /// Returns the smallest value of the column.
+    pub fn min(self) -> Aggregate {
+        self.aggregate(AggregateFunction::Min)
+    }
+
+    /// Returns the largest value of the column.
+    pub fn max(self) -> Aggregate {
+        self.aggregate(AggregateFunction::Max)
+    }
+
+    /// Builds the condition that the column is equal to the other column,
+    /// e.g. the `ON` condition of a join.
+    pub fn eq_column<N: Into<column::Name>>(self, other: N) -> Expression {
+        Expression::binary(
+            Expression::Column(self.name),
+            BinaryOperator::Equal,
+            Expression::Column(other.into()),
+        )
+    }
+
+    /// Builds the [`Aggregate`] of the column.
+    fn aggregate(self, function: AggregateFunction) -> Aggregate {
+        Aggregate::new(function, Expression::Column(self.name))
+    }
+
+    /// Builds the [`SelectorFilter`].
+    fn build<V: Into<StorageData>>(
+        self,
+        filter_type: FilterType,
+        value: V,
+    ) -> SelectorFilter {
+        SelectorFilter {
+            column_name: self.name,
+            value: value.into(),
+            filter_type,
+        }
+    }
+}
@@ -0,0 +1,478 @@
+//! Traits to map Rust structs to table rows.
+//!
+//! The traits are usually derived with [`FromRow`], [`IntoRow`] and
+//! [`TableDefinition`] derives, configured by `#[row(...)]` attributes:
+//!
+//! * `#[row(table = "name")]` on the struct sets the table name, which is the
+//!   snake case struct name by default.
+//! * `#[row(rename = "name")]` on a field sets the column name.
+//! * `#[row(primary_key)]` on a field marks the primary key column.
+//! * `#[row(length = 32)]` on a `String` field sets the `VarChar` length,
+//!   which is 255 by default.
+//!
+//! `Option<T>` fields are stored in nullable columns, other fields are
+//! `NOT NULL`.
+//!
+//! [`FromRow`]: macro@crate::connection::FromRow
+//! [`IntoRow`]: macro@crate::connection::IntoRow
+//! [`TableDefinition`]: macro@crate::connection::TableDefinition
+
+use backend::schema::{
+    column,
+    column::primary_key::{self, PrimaryKey},
+    r#type::{
+        data_types::{
+            Blob, Bool, Byte, Date, Double, Float, Integer, Interval, Long,
+            Short, Time, Timestamp, UInteger, ULong, UShort, Uuid, VarChar,
+        },
+        r#enum::{StorageData, StorageDataType},
+    },
+    table, Column,
+};
+use derive_more::Display;
+
+use crate::{api::command::schema::CreateTable, connection::rows::Row};
+
+/// Length of `VarChar` columns of `String` fields without `length`.
+pub const DEFAULT_VARCHAR_LENGTH: usize = 255;
+
+/// Rust type that is stored in a single column.
+pub trait ColumnValue: Sized {
+    /// Returns the definition of the column.
+    /// # Arguments
+    /// * `length` - The length set with `#[row(length = ...)]`.
+    fn column(length: Option<usize>) -> Column;
+
+    /// Converts the value into [`StorageData`].
+    fn into_value(self) -> Result<StorageData, RowError>;
+
+    /// Converts [`StorageData`] into the value.
+    /// # Returns
+    /// * `Option<Self>` - The value or `None` if the data has another type.
+    fn from_value(value: StorageData) -> Option<Self>;
+}
+
+macro_rules! impl_column_value {
+    ($($primitive:ty => $variant:ident),* $(,)?) => {
+        $(
+            impl ColumnValue for $primitive {
+                fn column(_: Option<usize>) -> Column {
+                    let mut column = Column::new(StorageDataType::$variant);
+                    column.set_not_null(true);
+                    column
+                }
+
+                fn into_value(self) -> Result<StorageData, RowError> {
+                    Ok(StorageData::$variant($variant(self)))
+                }
+
+                fn from_value(value: StorageData) -> Option<Self> {
+                    match value {
+                        StorageData::$variant(value) => Some(value.0),
+                        _ => None,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_column_value!(
+    bool => Bool,
+    u8 => Byte,
+    i16 => Short,
+    i32 => Integer,
+    i128 => Long,
+    u16 => UShort,
+    u32 => UInteger,
+    u128 => ULong,
+    f32 => Float,
+    f64 => Double,
+);
+
+macro_rules! impl_column_value_data_type {
+    ($($data_type:ident),* $(,)?) => {
+        $(
+            impl ColumnValue for $data_type {
+                fn column(_: Option<usize>) -> Column {
+                    let mut column = Column::new(StorageDataType::$data_type);
+                    column.set_not_null(true);
+                    column
+                }
+
+                fn into_value(self) -> Result<StorageData, RowError> {
+                    Ok(StorageData::$data_type(self))
+                }
+
+                fn from_value(value: StorageData) -> Option<Self> {
+                    match value {
+                        StorageData::$data_type(value) => Some(value),
+                        _ => None,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_column_value_data_type!(Date, Time, Timestamp, Interval, Uuid);
+
+impl ColumnValue for String {
+    fn column(length: Option<usize>) -> Column {
+        let length = length.unwrap_or(DEFAULT_VARCHAR_LENGTH);
+        let mut column = Column::new(StorageDataType::VarChar(length));
+        column.set_not_null(true);
+        column
+    }
+
+    fn into_value(self) -> Result<StorageData, RowError> {
+        let len = self.len();
+        VarChar::new(self)
+            .map(StorageData::VarChar)
+            .map_err(|_| RowError::ValueTooLong(len))
+    }
+
+    fn from_value(value: StorageData) -> Option<Self> {
+        match value {
+            StorageData::VarChar(value) => Some(value.value),
+            StorageData::Text(value) => Some(value.0),
+            _ => None,
+        }
+    }
+}
+
+impl ColumnValue for Vec<u8> {
+    fn column(_: Option<usize>) -> Column {
+        let mut column = Column::new(StorageDataType::Blob);
+        column.set_not_null(true);
+        column
+    }
+
+    fn into_value(self) -> Result<StorageData, RowError> {
+        Ok(StorageData::Blob(Blob(self)))
+    }
+
+    fn from_value(value: StorageData) -> Option<Self> {
+        match value {
+            StorageData::Blob(value) => Some(value.0),
+            _ => None,
+        }
+    }
+}
+
+impl<T: ColumnValue> ColumnValue for Option<T> {
+    fn column(length: Option<usize>) -> Column {
+        let mut column = T::column(length);
+        column.set_not_null(false);
+        column
+    }
+
+    fn into_value(self) -> Result<StorageData, RowError> {
+        match self {
+            Some(value) => value.into_value(),
+            None => Ok(StorageData::Null),
+        }
+    }
+
+    fn from_value(value: StorageData) -> Option<Self> {
+        match value {
+            StorageData::Null => Some(None),
+            value => T::from_value(value).map(Some),
+        }
+    }
+}
+
+/// Type that is converted into a table row.
+pub trait IntoRow {
+    /// Returns the names of the columns in the order of the row values.
+    fn column_names() -> Vec<column::Name>;
+
+    /// Converts the value into the row values.
+    fn into_row(self) -> Result<Vec<StorageData>, RowError>;
+}
+
+/// Type that is built from a table row.
+pub trait FromRow: Sized {
+    /// Builds the value from the row.
+    /// # Arguments
+    /// * `row` - The row, that must contain all mapped columns.
+    fn from_row(row: Row<'_>) -> Result<Self, RowError>;
+}
+
+/// Type that defines a table.
+pub trait TableDefinition {
+    /// Returns the name of the table.
+    fn table_name() -> table::Name;
+
+    /// Returns the columns of the table in the order of the fields.
+    fn column_definitions() -> Vec<(column::Name, Column)>;
+
+    /// Returns the name of the primary key column.
+    fn primary_key() -> column::Name;
+
+    /// Returns the [`CreateTable`] command that creates the table in the
+    /// current schema.
+    fn create_table() -> CreateTable {
+        let name = Self::table_name();
+        let key_name = primary_key::Name(format!("{}_pkey", name));
+
+        CreateTable {
+            database_name: None,
+            schema_name: None,
+            name,
+            columns: Self::column_definitions(),
+            primary_key: PrimaryKey::new(key_name, Self::primary_key()),
+            constraints: Vec::new(),
+        }
+    }
+}
+
+/// Errors that can occur when mapping a row.
+#[derive(Debug, Display, PartialEq)]
+pub enum RowError {
+    /// The row doesn't contain the column.
+    #[display(fmt = "Column `{}` not found in the row", _0)]
+    ColumnNotFound(column::Name),
+
+    /// The column value can't be converted into the field type.
+    #[display(fmt = "Column `{}` can't be converted into `{}`", _0, _1)]
+    TypeMismatch(column::Name, &'static str),
+
+    /// The string is longer than the maximum `VarChar` length.
+    #[display(fmt = "String of {} bytes is too long for a column", _0)]
+    ValueTooLong(usize),
+}
+
+#[cfg(test)]
+mod tests {
+    use backend::schema::{
+        r#type::{
+            data_types::{Date, Interval, Timestamp, Uuid, VarChar},
+            r#enum::{StorageData, StorageDataType},
+        },
+        Column,
+    };
+
+    use crate::connection::{
+        column, Connection, FromRow, IntoRow, RowError, TableDefinition,
+    };
+
+    #[derive(Debug, Clone, PartialEq, FromRow, IntoRow, TableDefinition)]
+    #[row(table = "users")]
+    struct User {
+        #[row(primary_key)]
+        id: i32,
+        #[row(rename = "user_name", length = 16)]
+        name: String,
+        age: Option<u8>,
+    }
+
+    #[derive(Debug, PartialEq, FromRow, IntoRow, TableDefinition)]
+    struct AuditEntry {
+        #[row(primary_key)]
+        id: u32,
+        active: bool,
+    }
+
+    #[derive(Debug, Clone, PartialEq, FromRow, IntoRow, TableDefinition)]
+    struct Attachment {
+        #[row(primary_key)]
+        id: u32,
+        data: Vec<u8>,
+        thumbnail: Option<Vec<u8>>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, FromRow, IntoRow, TableDefinition)]
+    struct Event {
+        #[row(primary_key)]
+        id: u32,
+        day: Date,
+        at: Timestamp,
+        duration: Option<Interval>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, FromRow, IntoRow, TableDefinition)]
+    struct Session {
+        #[row(primary_key)]
+        id: Uuid,
+        user_id: i32,
+    }
+
+    fn user(id: i32, name: &str, age: Option<u8>) -> User {
+        User {
+            id,
+            name: name.to_string(),
+            age,
+        }
+    }
+
+    #[test]
+    fn test_table_definition() {
+        let mut age = Column::new(StorageDataType::Byte);
+        age.set_not_null(false);
+        let mut name = Column::new(StorageDataType::VarChar(16));
+        name.set_not_null(true);
+
+        let cmd = User::create_table();
+        assert_eq!(cmd.name, "users".into());
+        assert_eq!(cmd.columns[1], ("user_name".into(), name));
+        assert_eq!(cmd.columns[2], ("age".into(), age));
+        assert_eq!(cmd.primary_key.get_column(), &"id".into());
+
+        assert_eq!(AuditEntry::table_name(), "audit_entry".into());
+    }
+
+    #[test]
+    fn test_into_row() {
+        assert_eq!(
+            User::column_names(),
+            vec!["id".into(), "user_name".into(), "age".into()]
+        );
+        assert_eq!(
+            user(1, "ann", None).into_row().unwrap(),
+            vec![
+                StorageData::from(1),
+                VarChar::new("ann".to_string()).unwrap().into(),
+                StorageData::Null,
+            ]
+        );
+        assert_eq!(
+            user(1, &"a".repeat(300), None).into_row(),
+            Err(RowError::ValueTooLong(300))
+        );
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let mut connection = Connection::<4>::open("db").unwrap();
+        connection.create_table_for::<User>().unwrap();
+
+        let users = vec![
+            user(1, "ann", Some(30)),
+            user(2, "bob", None),
+            user(3, "carol", Some(17)),
+        ];
+        assert_eq!(connection.insert_all(users.clone()).unwrap(), 3);
+
+        let all: Vec<User> = connection.query("users").fetch_as().unwrap();
+        assert_eq!(all, users);
+
+        let adults: Vec<User> = connection
+            .query("users")
+            .filter(column("age").ge(18u8))
+            .fetch_as()
+            .unwrap();
+        assert_eq!(adults, vec![user(1, "ann", Some(30))]);
+
+        // A batch with a duplicate primary key is inserted all or none.
+        assert!(connection
+            .insert_all([user(4, "dan", None), user(1, "eve", None)])
+            .is_err());
+        let all: Vec<User> = connection.query("users").fetch_as().unwrap();
+        assert_eq!(all, users);
+    }
+
+    #[test]
+    fn test_blob_roundtrip() {
+        let mut connection = Connection::<4>::open("db").unwrap();
+        connection.create_table_for::<Attachment>().unwrap();
+        assert_eq!(
+            Attachment::create_table().columns[1].1.get_type(),
+            StorageDataType::Blob
+        );
+
+        let attachments = vec![
+            Attachment {
+                id: 1,
+                data: vec![7; 5_000],
+                thumbnail: Some(vec![0xde, 0xad]),
+            },
+            Attachment {
+                id: 2,
+                data: vec![],
+                thumbnail: None,
+            },
+        ];
+        connection.insert_all(attachments.clone()).unwrap();
+
+        let all: Vec<Attachment> =
+            connection.query("attachment").fetch_as().unwrap();
+        assert_eq!(all, attachments);
+    }
+
+    #[test]
+    fn test_temporal_roundtrip() {
+        let mut connection = Connection::<4>::open("db").unwrap();
+        connection.create_table_for::<Event>().unwrap();
+        assert_eq!(
+            Event::create_table().columns[2].1.get_type(),
+            StorageDataType::Timestamp
+        );
+
+        let start = Timestamp(1_700_000_000_000_000);
+        let events = vec![
+            Event {
+                id: 1,
+                day: Date(19_675),
+                at: start,
+                duration: Some(Interval {
+                    months: 0,
+                    days: 1,
+                    micros: 0,
+                }),
+            },
+            Event {
+                id: 2,
+                day: Date(19_676),
+                at: Timestamp(start.0 + 1),
+                duration: None,
+            },
+        ];
+        connection.insert_all(events.clone()).unwrap();
+
+        let later: Vec<Event> = connection
+            .query("event")
+            .filter(column("at").gt(start))
+            .fetch_as()
+            .unwrap();
+        assert_eq!(later, vec![events[1].clone()]);
+    }
+
+    #[test]
+    fn test_uuid_roundtrip() {
+        let mut connection = Connection::<4>::open("db").unwrap();
+        connection.create_table_for::<Session>().unwrap();
+
+        let sessions = vec![
+            Session {
+                id: Uuid::new_v4(),
+                user_id: 1,
+            },
+            Session {
+                id: Uuid::new_v4(),
+                user_id: 2,
+            },
+        ];
+        connection.insert_all(sessions.clone()).unwrap();
+
+        let found: Vec<Session> = connection
+            .query("session")
+            .filter(column("id").eq(sessions[1].id))
+            .fetch_as()
+            .unwrap();
+        assert_eq!(found, vec![sessions[1].clone()]);
+    }
+
+    #[test]
+    fn test_from_row_errors() {
+        let mut connection = Connection::<4>::open("db").unwrap();
+        connection.create_table_for::<User>().unwrap();
+        connection.insert_all([user(1, "ann", None)]).unwrap();
+
+        let result = connection
+            .query("users")
+            .columns(["id", "age"])
+            .fetch_as::<User>();
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,722 @@
+//! Embedded API to use nosqlite from Rust without SQL text.
+//!
+//! ```
+//! use backend::schema::r#type::r#enum::StorageDataType;
+//! use backend_api::connection::{column, Connection};
+//!
+//! let mut connection = Connection::<4>::open("app").unwrap();
+//! connection
+//!     .create_table("users")
+//!     .column("id", StorageDataType::Integer)
+//!     .column("age", StorageDataType::Byte)
+//!     .primary_key("id")
+//!     .execute()
+//!     .unwrap();
+//! connection
+//!     .insert("users")
+//!     .columns(["id", "age"])
+//!     .row([1.into(), 30u8.into()])
+//!     .row([2.into(), 17u8.into()])
+//!     .execute()
+//!     .unwrap();
+//!
+//! let rows = connection
+//!     .query("users")
+//!     .filter(column("age").ge(18u8))
+//!     .fetch()
+//!     .unwrap();
+//! assert_eq!(rows.len(), 1);
+//! ```
+
+mod builder;
+mod filter;
+pub mod mapping;
+mod rows;
+
+use std::fmt::Display;
+
+use backend::{
+    query::{Join, JoinedTable},
+    schema::{self, database, sequence, table},
+};
+use derive_more::Display;
+
+use crate::api::{
+    command::{
+        backend_api::{CreateDatabase, UseSchema},
+        database::CreateSchema,
+        schema::{
+            AnalyzeTable, CreateSequence, DescribeTable, JoinTables, NextValue,
+        },
+        Command, Gateway,
+    },
+    facade::BackendFacade,
+};
+
+pub use builder::{
+    CreateTableBuilder, DeleteBuilder, InsertBuilder, QueryBuilder,
+};
+pub use filter::{column, ColumnFilter};
+pub use mapping::{ColumnValue, FromRow, IntoRow, RowError, TableDefinition};
+pub use rows::{Row, Rows};
+pub use ser_storage_derive::{FromRow, IntoRow, TableDefinition};
+
+/// Name of the schema created by [`Connection::open`].
+pub const DEFAULT_SCHEMA: &str = "public";
+
+/// Connection to an embedded in-memory database.
+///
+/// Commands are executed in the current database and schema of the
+/// connection.
+#[derive(Debug)]
+pub struct Connection<const NODE_SIZE: u8> {
+    /// Backend that executes commands.
+    facade: BackendFacade<NODE_SIZE>,
+}
+
+impl<const NODE_SIZE: u8> Connection<NODE_SIZE> {
+    /// Opens a new database with the [`DEFAULT_SCHEMA`] and uses it.
+    /// # Arguments
+    /// * `database` - The name of the database.
+    /// # Returns
+    /// * `Ok(Connection)` - The connection to the database.
+    /// * `Err(Error)` - If the database can't be created.
+    pub fn open<N: Into<database::Name>>(database: N) -> Result<Self, Error> {
+        let mut connection = Self {
+            facade: BackendFacade::default(),
+        };
+        let name = database.into();
+
+        connection.send(CreateDatabase { name: name.clone() })?;
+        connection.facade.context.set_current_db(name);
+        connection.create_schema(DEFAULT_SCHEMA)?;
+        connection.use_schema(DEFAULT_SCHEMA)?;
+
+        Ok(connection)
+    }
+
+    /// Returns the [`BackendFacade`] of the connection to send commands
+    /// that are not covered by the embedded API.
+    pub fn facade(&mut self) -> &mut BackendFacade<NODE_SIZE> {
+        &mut self.facade
+    }
+
+    /// Creates a new schema in the current database.
+    /// # Arguments
+    /// * `name` - The name of the schema.
+    pub fn create_schema<N: Into<schema::Name>>(
+        &mut self,
+        name: N,
+    ) -> Result<(), Error> {
+        self.send(CreateSchema {
+            database_name: None,
+            name: name.into(),
+        })?;
+
+        Ok(())
+    }
+
+    /// Makes the schema of the current database current.
+    /// # Arguments
+    /// * `name` - The name of the schema.
+    pub fn use_schema<N: Into<schema::Name>>(
+        &mut self,
+        name: N,
+    ) -> Result<(), Error> {
+        self.send(UseSchema {
+            database_name: None,
+            name: name.into(),
+        })?;
+
+        Ok(())
+    }
+
+    /// Starts building a table in the current schema.
+    /// # Arguments
+    /// * `name` - The name of the table.
+    pub fn create_table<N: Into<table::Name>>(
+        &mut self,
+        name: N,
+    ) -> CreateTableBuilder<'_, NODE_SIZE> {
+        CreateTableBuilder::new(self, name.into())
+    }
+
+    /// Creates the table defined by the type in the current schema.
+    pub fn create_table_for<T: TableDefinition>(
+        &mut self,
+    ) -> Result<(), Error> {
+        self.send(T::create_table())?;
+
+        Ok(())
+    }
+
+    /// Describes the columns and constraints of a table in the current
+    /// schema, a line for each.
+    /// # Arguments
+    /// * `name` - The name of the table.
+    pub fn describe_table<N: Into<table::Name>>(
+        &mut self,
+        name: N,
+    ) -> Result<String, Error> {
+        let result = self.send(DescribeTable {
+            database_name: None,
+            schema_name: None,
+            name: name.into(),
+        })?;
+
+        Ok(result.result)
+    }
+
+    /// Collects the statistics of a table in the current schema the planner
+    /// estimates the costs of queries by, see [`AnalyzeTable`].
+    /// # Arguments
+    /// * `name` - The name of the table.
+    pub fn analyze_table<N: Into<table::Name>>(
+        &mut self,
+        name: N,
+    ) -> Result<String, Error> {
+        let result = self.send(AnalyzeTable {
+            database_name: None,
+            schema_name: None,
+            name: name.into(),
+        })?;
+
+        Ok(result.result)
+    }
+
+    /// Creates a standalone sequence in the current schema.
+    /// # Arguments
+    /// * `name` - The name of the sequence.
+    /// * `start` - The first value of the sequence.
+    pub fn create_sequence<N: Into<sequence::Name>>(
+        &mut self,
+        name: N,
+        start: i128,
+    ) -> Result<(), Error> {
+        self.send(CreateSequence {
+            database_name: None,
+            schema_name: None,
+            name: name.into(),
+            start,
+        })?;
+
+        Ok(())
+    }
+
+    /// Returns the next value of a sequence of the current schema, the same
+    /// as `nextval()`.
+    /// # Arguments
+    /// * `name` - The name of the sequence.
+    pub fn next_value<N: Into<sequence::Name>>(
+        &mut self,
+        name: N,
+    ) -> Result<i128, Error> {
+        self.send(NextValue {
+            database_name: None,
+            schema_name: None,
+            name: name.into(),
+        })
+    }
+
+    /// Inserts values into the table defined by their type.
+    /// # Arguments
+    /// * `values` - The values to insert.
+    /// # Returns
+    /// * `Ok(usize)` - The number of inserted rows.
+    /// * `Err(Error)` - If any value can't be inserted, in which case no
+    ///   values are inserted.
+    pub fn insert_all<T, I>(&mut self, values: I) -> Result<usize, Error>
+    where
+        T: IntoRow + TableDefinition,
+        I: IntoIterator<Item = T>,
+    {
+        let mut builder =
+            self.insert(T::table_name()).columns(T::column_names());
+        for value in values {
+            builder = builder.row(value.into_row().map_err(Error::Row)?);
+        }
+
+        builder.execute()
+    }
+
+    /// Starts building rows to insert into a table of the current schema.
+    /// # Arguments
+    /// * `table` - The name of the table.
+    pub fn insert<N: Into<table::Name>>(
+        &mut self,
+        table: N,
+    ) -> InsertBuilder<'_, NODE_SIZE> {
+        InsertBuilder::new(self, table.into())
+    }
+
+    /// Starts building a query to a table of the current schema.
+    /// # Arguments
+    /// * `table` - The name of the table.
+    pub fn query<N: Into<table::Name>>(
+        &mut self,
+        table: N,
+    ) -> QueryBuilder<'_, NODE_SIZE> {
+        QueryBuilder::new(self, table.into())
+    }
+
+    /// Joins the rows of two tables of the current schema. Columns are
+    /// qualified by the names or the aliases of their tables, e.g.
+    /// `users.id`, both in the `ON` condition and in the returned rows.
+    /// # Arguments
+    /// * `left` - The name of the left table or a [`JoinedTable`] with an
+    ///   alias.
+    /// * `right` - The name of the right table or a [`JoinedTable`] with an
+    ///   alias.
+    /// * `join` - The type and the `ON` condition of the join.
+    pub fn join<L: Into<JoinedTable>, R: Into<JoinedTable>>(
+        &mut self,
+        left: L,
+        right: R,
+        join: Join,
+    ) -> Result<Rows, Error> {
+        let data = self.send(JoinTables {
+            database_name: None,
+            schema_name: None,
+            left: left.into(),
+            right: right.into(),
+            join,
+        })?;
+
+        Ok(data.into())
+    }
+
+    /// Starts building a deletion from a table of the current schema.
+    /// # Arguments
+    /// * `table` - The name of the table.
+    pub fn delete<N: Into<table::Name>>(
+        &mut self,
+        table: N,
+    ) -> DeleteBuilder<'_, NODE_SIZE> {
+        DeleteBuilder::new(self, table.into())
+    }
+
+    /// Sends a command to the backend.
+    fn send<Cmd, Ctx>(
+        &mut self,
+        cmd: Cmd,
+    ) -> Result<<Cmd as Command<Ctx>>::Ok, Error>
+    where
+        Cmd: Command<Ctx>,
+        BackendFacade<NODE_SIZE>:
+            Gateway<Cmd, Ctx, Ok = <Cmd as Command<Ctx>>::Ok>,
+        <BackendFacade<NODE_SIZE> as Gateway<Cmd, Ctx>>::Err: Display,
+    {
+        self.facade
+            .send(cmd)
+            .map_err(|err| Error::Command(err.to_string()))
+    }
+}
+
+/// Errors that can occur when using a [`Connection`].
+#[derive(Debug, Display, PartialEq)]
+pub enum Error {
+    /// The backend failed to execute the command.
+    #[display(fmt = "{}", _0)]
+    Command(String),
+
+    /// The table was built without a primary key.
+    #[display(fmt = "Primary key not provided for `{}` table", _0)]
+    PrimaryKeyNotProvided(table::Name),
+
+    /// The row can't be mapped to or from a Rust type.
+    #[display(fmt = "{}", _0)]
+    Row(RowError),
+
+    /// The row has a different number of values than the columns.
+    #[display(fmt = "Expected {} values in a row, got {}", expected, got)]
+    RowLengthMismatch {
+        /// The number of columns.
+        expected: usize,
+
+        /// The number of values in the row.
+        got: usize,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use backend::{
+        expression::{BinaryOperator, Expression},
+        query::{Aggregate, Join, JoinType, JoinedTable},
+        schema::{
+            r#type::{
+                data_types::Text,
+                r#enum::{StorageData, StorageDataType},
+            },
+            table::constraint::OnDelete,
+            Column,
+        },
+    };
+
+    use super::{column, Connection, Error, Rows};
+
+    #[test]
+    fn test_open() {
+        let mut connection = Connection::<4>::open("db").unwrap();
+
+        let context = &connection.facade().context;
+        assert_eq!(context.current_db(), Some(&"db".into()));
+        assert_eq!(context.current_schema(), Some(&"public".into()));
+    }
+
+    #[test]
+    fn test_use_schema() {
+        let mut connection = Connection::<4>::open("db").unwrap();
+
+        assert!(matches!(
+            connection.use_schema("missing"),
+            Err(Error::Command(_))
+        ));
+
+        connection.create_schema("other").unwrap();
+        connection.use_schema("other").unwrap();
+        connection
+            .create_table("users")
+            .column("id", StorageDataType::Integer)
+            .primary_key("id")
+            .execute()
+            .unwrap();
+
+        connection.use_schema("public").unwrap();
+        assert!(connection.query("users").fetch().is_err());
+    }
+
+    #[test]
+    fn test_auto_increment() {
+        let mut connection = Connection::<4>::open("db").unwrap();
+        let mut id = Column::new(StorageDataType::Integer);
+        id.set_auto_increment(true);
+        connection
+            .create_table("users")
+            .column_with("id", id)
+            .column("age", StorageDataType::Byte)
+            .primary_key("id")
+            .execute()
+            .unwrap();
+
+        let keys = connection
+            .insert("users")
+            .columns(["age"])
+            .row([30u8.into()])
+            .row([17u8.into()])
+            .execute_returning_keys()
+            .unwrap();
+        assert_eq!(keys, vec![StorageData::from(1), StorageData::from(2)]);
+        assert_eq!(connection.query("users").fetch().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_constraints() {
+        let mut connection = Connection::<4>::open("db").unwrap();
+        connection
+            .create_table("users")
+            .column("id", StorageDataType::Integer)
+            .column("email", StorageDataType::Text)
+            .column("age", StorageDataType::Byte)
+            .primary_key("id")
+            .unique(["email"])
+            .check(Expression::binary(
+                Expression::Column("age".into()),
+                BinaryOperator::GreaterThanOrEqual,
+                Expression::Literal(18u8.into()),
+            ))
+            .check(Expression::binary(
+                Expression::Column("age".into()),
+                BinaryOperator::LessThan,
+                Expression::Literal(150u8.into()),
+            ))
+            .execute()
+            .unwrap();
+
+        let email = |email: &str| -> StorageData { Text(email.into()).into() };
+        let insert = |connection: &mut Connection<4>, id: i32, age: u8| {
+            connection
+                .insert("users")
+                .columns(["id", "email", "age"])
+                .row([id.into(), email("a@b.c"), age.into()])
+                .execute()
+        };
+        insert(&mut connection, 1, 30).unwrap();
+        assert_eq!(
+            insert(&mut connection, 2, 30).unwrap_err().to_string(),
+            "Duplicate value violates unique constraint `users_email_key`"
+        );
+        assert_eq!(connection.query("users").fetch().unwrap().len(), 1);
+
+        let err = connection
+            .insert("users")
+            .columns(["id", "email", "age"])
+            .row([3.into(), email("d@e.f"), 16u8.into()])
+            .execute()
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Row violates check constraint `users_age_check`"
+        );
+
+        assert_eq!(
+            connection.describe_table("users").unwrap(),
+            "id i32\n\
+             email text\n\
+             age u8\n\
+             CONSTRAINT users_pkey PRIMARY KEY (id)\n\
+             CONSTRAINT users_email_key UNIQUE (email)\n\
+             CONSTRAINT users_age_check CHECK (age >= 18)\n\
+             CONSTRAINT users_age_check1 CHECK (age < 150)"
+        );
+    }
+
+    #[test]
+    fn test_foreign_keys() {
+        let mut connection = Connection::<4>::open("db").unwrap();
+        connection
+            .create_table("users")
+            .column("id", StorageDataType::Integer)
+            .primary_key("id")
+            .execute()
+            .unwrap();
+        let create_table = |connection: &mut Connection<4>,
+                            name: &str,
+                            on_delete: OnDelete| {
+            connection
+                .create_table(name)
+                .column("id", StorageDataType::Integer)
+                .column("user_id", StorageDataType::Integer)
+                .primary_key("id")
+                .foreign_key("user_id", "users", "id", on_delete)
+                .execute()
+                .unwrap();
+        };
+        create_table(&mut connection, "orders", OnDelete::Cascade);
+        create_table(&mut connection, "reviews", OnDelete::SetNull);
+
+        let insert = |connection: &mut Connection<4>, table: &str, id: i32| {
+            connection
+                .insert(table)
+                .columns(["id", "user_id"])
+                .row([id.into(), 1.into()])
+                .execute()
+        };
+        assert_eq!(
+            insert(&mut connection, "orders", 1)
+                .unwrap_err()
+                .to_string(),
+            "Row violates foreign key constraint `orders_user_id_fkey`"
+        );
+        connection
+            .insert("users")
+            .columns(["id"])
+            .row([1.into()])
+            .row([2.into()])
+            .execute()
+            .unwrap();
+        insert(&mut connection, "orders", 1).unwrap();
+        insert(&mut connection, "reviews", 1).unwrap();
+
+        let deleted = connection
+            .delete("users")
+            .filter(column("id").eq(1))
+            .execute()
+            .unwrap();
+        assert_eq!(deleted, 1);
+        assert_eq!(connection.query("users").fetch().unwrap().len(), 1);
+        assert!(connection.query("orders").fetch().unwrap().is_empty());
+        let reviews = connection.query("reviews").fetch().unwrap();
+        assert_eq!(
+            reviews.get(0).unwrap().get("user_id"),
+            Some(&StorageData::Null)
+        );
+
+        assert_eq!(
+            connection.describe_table("orders").unwrap(),
+            "id i32\n\
+             user_id i32\n\
+             CONSTRAINT orders_pkey PRIMARY KEY (id)\n\
+             CONSTRAINT orders_user_id_fkey FOREIGN KEY (user_id) \
+             REFERENCES users (id) ON DELETE CASCADE"
+        );
+    }
+
+    #[test]
+    fn test_sequence() {
+        let mut connection = Connection::<4>::open("db").unwrap();
+        assert!(connection.next_value("ids").is_err());
+
+        connection.create_sequence("ids", 100).unwrap();
+        assert!(connection.create_sequence("ids", 1).is_err());
+        assert_eq!(connection.next_value("ids"), Ok(100));
+        assert_eq!(connection.next_value("ids"), Ok(101));
+    }
+
+    #[test]
+    fn test_order_by_limit_offset() {
+        let mut connection = Connection::<4>::open("db").unwrap();
+        connection
+            .create_table("users")
+            .column("id", StorageDataType::Integer)
+            .column_with("age", Column::new(StorageDataType::Byte))
+            .primary_key("id")
+            .execute()
+            .unwrap();
+        connection
+            .insert("users")
+            .columns(["id", "age"])
+            .row([1.into(), 30u8.into()])
+            .row([2.into(), StorageData::Null])
+            .row([3.into(), 17u8.into()])
+            .row([4.into(), 30u8.into()])
+            .execute()
+            .unwrap();
+
+        let ids = |rows: Rows| {
+            rows.iter()
+                .map(|row| row.get("id").unwrap().clone())
+                .collect::<Vec<_>>()
+        };
+        let rows = connection
+            .query("users")
+            .order_by(column("age").desc().nulls_last())
+            .order_by(column("id").desc())
+            .fetch()
+            .unwrap();
+        assert_eq!(ids(rows), [4, 1, 3, 2].map(StorageData::from));
+
+        let rows = connection
+            .query("users")
+            .order_by(column("age").asc())
+            .offset(1)
+            .limit(2)
+            .fetch()
+            .unwrap();
+        assert_eq!(ids(rows), [1, 4].map(StorageData::from));
+    }
+
+    #[test]
+    fn test_group_by() {
+        let mut connection = Connection::<4>::open("db").unwrap();
+        connection
+            .create_table("orders")
+            .column("id", StorageDataType::Integer)
+            .column("user_id", StorageDataType::Integer)
+            .column("amount", StorageDataType::Integer)
+            .primary_key("id")
+            .execute()
+            .unwrap();
+        let mut insert = connection
+            .insert("orders")
+            .columns(["id", "user_id", "amount"]);
+        for (id, user_id, amount) in
+            [(1, 1, 10), (2, 2, 5), (3, 1, 7), (4, 3, 1)]
+        {
+            insert = insert.row([id.into(), user_id.into(), amount.into()]);
+        }
+        insert.execute().unwrap();
+
+        let rows = connection
+            .query("orders")
+            .filter(column("amount").gt(1))
+            .group_by("user_id")
+            .aggregate(Aggregate::count_all())
+            .aggregate(column("amount").sum().alias("total"))
+            .having(Expression::binary(
+                Expression::Column("total".into()),
+                BinaryOperator::GreaterThan,
+                Expression::Literal(6.into()),
+            ))
+            .order_by(column("total").desc())
+            .fetch()
+            .unwrap();
+        assert_eq!(rows.len(), 1);
+        let row = rows.get(0).unwrap();
+        assert_eq!(row.get("user_id"), Some(&StorageData::from(1)));
+        assert_eq!(row.get("COUNT(*)"), Some(&StorageData::from(2i128)));
+        assert_eq!(row.get("total"), Some(&StorageData::from(17i128)));
+
+        let rows = connection
+            .query("orders")
+            .aggregate(column("amount").max())
+            .fetch()
+            .unwrap();
+        assert_eq!(
+            rows.get(0).unwrap().get("MAX(amount)"),
+            Some(&StorageData::from(10))
+        );
+    }
+
+    #[test]
+    fn test_join() {
+        let mut connection = Connection::<4>::open("db").unwrap();
+        for (table, column) in [("users", "name"), ("orders", "user_id")] {
+            let type_ = if column == "name" {
+                StorageDataType::Text
+            } else {
+                StorageDataType::Integer
+            };
+            connection
+                .create_table(table)
+                .column("id", StorageDataType::Integer)
+                .column(column, type_)
+                .primary_key("id")
+                .execute()
+                .unwrap();
+        }
+        connection
+            .insert("users")
+            .columns(["id", "name"])
+            .row([1.into(), Text("ann".into()).into()])
+            .row([2.into(), Text("bob".into()).into()])
+            .execute()
+            .unwrap();
+        connection
+            .insert("orders")
+            .columns(["id", "user_id"])
+            .row([10.into(), 1.into()])
+            .row([11.into(), 1.into()])
+            .row([12.into(), 3.into()])
+            .execute()
+            .unwrap();
+        let on = || column("orders.user_id").eq_column("users.id");
+
+        let rows = connection
+            .join("orders", "users", Join::new(JoinType::Left, on()))
+            .unwrap();
+        let names = rows
+            .iter()
+            .map(|row| row.get("users.name").unwrap().clone())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            vec![
+                Text("ann".into()).into(),
+                Text("ann".into()).into(),
+                StorageData::Null
+            ]
+        );
+
+        let count = |connection: &mut Connection<4>, join: Join| {
+            connection.join("users", "orders", join).unwrap().len()
+        };
+        assert_eq!(count(&mut connection, Join::new(JoinType::Inner, on())), 2);
+        assert_eq!(count(&mut connection, Join::new(JoinType::Right, on())), 3);
+        assert_eq!(count(&mut connection, Join::new(JoinType::Full, on())), 4);
+        assert_eq!(count(&mut connection, Join::cross()), 6);
+        assert!(connection.join("users", "users", Join::cross()).is_err());
+        let others = JoinedTable::aliased("users".into(), "others".into());
+        let rows = connection
+            .join(
+                "users",
+                others,
+                Join::new(
+                    JoinType::Inner,
+                    column("users.id").eq_column("others.id"),
+                ),
+            )
+            .unwrap();
+        assert_eq!(rows.len(), 2);
+    }
+}
@@ -0,0 +1,106 @@
+use backend::{
+    data::DataUnit,
+    schema::{
+        column,
+        r#type::{r#enum::StorageData, DataRow},
+    },
+};
+
+use crate::connection::mapping::{ColumnValue, RowError};
+
+/// Rows returned by a query.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rows {
+    /// The names of the selected columns.
+    columns: Vec<column::Name>,
+
+    /// The selected rows.
+    rows: Vec<DataRow>,
+}
+
+impl From<DataUnit> for Rows {
+    fn from(data: DataUnit) -> Self {
+        Self {
+            columns: data.get_names().to_vec(),
+            rows: data.get_values(),
+        }
+    }
+}
+
+impl Rows {
+    /// Returns the names of the selected columns.
+    pub fn columns(&self) -> &[column::Name] {
+        &self.columns
+    }
+
+    /// Returns the number of rows.
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Returns `true` if there are no rows.
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// Returns the row at the index.
+    /// # Arguments
+    /// * `index` - The index of the row.
+    pub fn get(&self, index: usize) -> Option<Row<'_>> {
+        self.rows.get(index).map(|row| Row {
+            columns: &self.columns,
+            values: &row.0,
+        })
+    }
+
+    /// Returns an iterator over the rows.
+    pub fn iter(&self) -> impl Iterator<Item = Row<'_>> {
+        self.rows.iter().map(|row| Row {
+            columns: &self.columns,
+            values: &row.0,
+        })
+    }
+}
+
+/// Single row of [`Rows`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Row<'a> {
+    /// The names of the selected columns.
+    columns: &'a [column::Name],
+
+    /// The values in the order of the columns.
+    values: &'a [StorageData],
+}
+
+impl<'a> Row<'a> {
+    /// Returns the value of the column.
+    /// # Arguments
+    /// * `name` - The name of the column.
+    /// # Returns
+    /// * `Option<&StorageData>` - The value or `None` if the column wasn't
+    ///   selected.
+    pub fn get(&self, name: &str) -> Option<&'a StorageData> {
+        self.columns
+            .iter()
+            .position(|column| column.0 == name)
+            .map(|index| &self.values[index])
+    }
+
+    /// Returns the values in the order of the columns.
+    pub fn values(&self) -> &'a [StorageData] {
+        self.values
+    }
+
+    /// Converts the value of the column into the field type.
+    /// # Arguments
+    /// * `name` - The name of the column.
+    pub fn decode<T: ColumnValue>(&self, name: &str) -> Result<T, RowError> {
+        let value = self
+            .get(name)
+            .ok_or_else(|| RowError::ColumnNotFound(name.into()))?;
+
+        T::from_value(value.clone()).ok_or_else(|| {
+            RowError::TypeMismatch(name.into(), std::any::type_name::<T>())
+        })
+    }
+}
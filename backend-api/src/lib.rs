@@ -1,4 +1,8 @@
+extern crate self as backend_api;
+
 pub mod api;
+pub mod connection;
 pub mod context;
 
+pub use connection::Connection;
 pub use context::Context;
@@ -0,0 +1,85 @@
+//! Compares rows encoded with fixed width integers to rows encoded in compact
+//! mode, by their size and by the time spent in `DataStorage::add_data`.
+
+use backend::{
+    data::data_storage::DataStorage,
+    schema::r#type::{
+        data_types::VarChar,
+        r#enum::{StorageData, StorageDataType},
+        DataRow,
+    },
+};
+use criterion::{
+    criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion,
+    Throughput,
+};
+
+/// Number of rows added to a storage in one iteration.
+const ROWS: u32 = 50;
+
+fn storage(compact: bool) -> DataStorage {
+    let mut storage = DataStorage::default();
+    storage.set_compact(compact);
+    storage.set_data_type(vec![
+        StorageDataType::Integer,
+        StorageDataType::Long,
+        StorageDataType::UShort,
+        StorageDataType::ULong,
+        StorageDataType::VarChar(16),
+    ]);
+
+    storage
+}
+
+/// Returns a row with small integers, as ids and counters usually are.
+fn row(i: u32) -> DataRow {
+    DataRow(vec![
+        StorageData::Integer((i as i32).into()),
+        StorageData::Long((-(i as i128)).into()),
+        StorageData::UShort((i as u16 % 100).into()),
+        StorageData::ULong((i as u128 * 1000).into()),
+        StorageData::VarChar(VarChar::new(format!("name {}", i)).unwrap()),
+    ])
+}
+
+/// Returns the number of bytes taken by [`ROWS`] rows.
+fn rows_size(compact: bool) -> u64 {
+    let mut storage = storage(compact);
+    for i in 0..ROWS {
+        storage.add_data(row(i)).unwrap();
+    }
+
+    storage.allocated_size()
+}
+
+fn add_data(c: &mut Criterion) {
+    let mut group = c.benchmark_group("add_data");
+
+    for (name, compact) in [("fixed", false), ("compact", true)] {
+        let size = rows_size(compact);
+        println!("{}: {} bytes per {} rows", name, size, ROWS);
+
+        group.throughput(Throughput::Bytes(size));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(name),
+            &compact,
+            |b, &compact| {
+                b.iter_batched(
+                    || storage(compact),
+                    |mut storage| {
+                        for i in 0..ROWS {
+                            storage.add_data(row(i)).unwrap();
+                        }
+                        storage
+                    },
+                    BatchSize::SmallInput,
+                )
+            },
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, add_data);
+criterion_main!(benches);
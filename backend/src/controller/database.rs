@@ -3,7 +3,10 @@ use common::structs::hash_table::{
     VecFunctions as _,
 };
 
-use crate::{controller, schema, schema as info, schema::database};
+use crate::{
+    controller, schema, schema as info,
+    schema::{database, table},
+};
 
 /// Controller for a single table.
 /// Is used to change the table's schema and data.
@@ -92,6 +95,21 @@ impl<const NODE_SIZE: u8> Database<NODE_SIZE> {
     ) -> Option<&mut controller::Schema<NODE_SIZE>> {
         self.schemas.get_mut_value(name)
     }
+
+    /// Checks the pages of all tables in the database.
+    /// # Returns
+    /// * `Vec<(schema::Name, table::Name, Vec<usize>)>` - Names of the
+    ///   schemas and tables with corrupted pages and indexes of these pages.
+    pub fn verify(&mut self) -> Vec<(schema::Name, table::Name, Vec<usize>)> {
+        let mut corrupted = Vec::new();
+        for mut schema in self.schemas.get_values() {
+            for (table, pages) in schema.verify() {
+                corrupted.push((schema.get_name().clone(), table, pages));
+            }
+        }
+
+        corrupted
+    }
 }
 
 impl<const NODE_SIZE: u8> PartialEq for Database<NODE_SIZE> {
@@ -105,7 +123,10 @@ mod tests {
     use common::structs::hash_table::HashTable as _;
 
     use super::Database;
-    use crate::{controller::Schema, schema};
+    use crate::{
+        controller::{Schema, Table},
+        schema,
+    };
 
     #[test]
     fn test_database_new() {
@@ -151,4 +172,25 @@ mod tests {
             Some(&mut schema)
         );
     }
+
+    #[test]
+    fn test_database_verify() {
+        let mut database = Database::<4>::new("test".into());
+        let mut schema = Schema::<4>::new("schema".into());
+        schema.add_table(Table::<4>::new("table".into()));
+        database.add_schema(schema);
+        assert!(database.verify().is_empty());
+
+        database
+            .get_mut_schema(&"schema".into())
+            .unwrap()
+            .get_mut_table(&"table".into())
+            .unwrap()
+            .corrupt_page(0);
+
+        assert_eq!(
+            database.verify(),
+            vec![("schema".into(), "table".into(), vec![0])]
+        );
+    }
 }
@@ -3,5 +3,5 @@ mod schema;
 pub mod table;
 
 pub use database::Database;
-pub use schema::Schema;
+pub use schema::{Schema, SchemaControllerError};
 pub use table::Table;
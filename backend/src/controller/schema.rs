@@ -1,7 +1,32 @@
-use crate::{controller, schema as info, schema, schema::table};
+use derive_more::Display;
+
+use crate::{
+    controller,
+    controller::table::{
+        selector::SelectorFilter, ReferenceLookup, TableControllerError,
+    },
+    data::{id::Sequence, DataUnit},
+    query::{
+        cost,
+        cost::{JoinInput, JoinPlan},
+        executor,
+        executor::{Operator, Project, Scan},
+        join,
+        key::{normalize, HashKey},
+        Join, JoinAlgorithm, JoinedTable,
+    },
+    schema as info, schema,
+    schema::{
+        column,
+        column::primary_key,
+        r#type::r#enum::{StorageData, StorageDataType},
+        sequence, table,
+        table::constraint::{self, Constraint, ForeignKey, OnDelete},
+    },
+};
 use common::structs::hash_table::{
     scalable::ScalableHashTable, HashTable as _, MutHashTable,
-    VecFunctions as _,
+    RefHashTable as _, VecFunctions as _,
 };
 
 /// Controller for a single table.
@@ -13,6 +38,9 @@ pub struct Schema<const NODE_SIZE: u8> {
 
     /// The tables in the schema.
     tables: ScalableHashTable<table::Name, controller::Table<NODE_SIZE>>,
+
+    /// The standalone sequences in the schema.
+    sequences: ScalableHashTable<sequence::Name, Sequence>,
 }
 
 impl<const NODE_SIZE: u8> Schema<NODE_SIZE> {
@@ -25,6 +53,7 @@ impl<const NODE_SIZE: u8> Schema<NODE_SIZE> {
         Schema {
             info: info::Schema::new(name),
             tables: ScalableHashTable::default(),
+            sequences: ScalableHashTable::default(),
         }
     }
 
@@ -100,6 +129,567 @@ impl<const NODE_SIZE: u8> Schema<NODE_SIZE> {
     pub fn has_table(&mut self, name: &table::Name) -> bool {
         self.tables.contains_key(name)
     }
+
+    /// Adds a constraint to a table of the schema. The column referenced by
+    /// a `FOREIGN KEY` must be the primary key or have a `UNIQUE` constraint
+    /// of its own, and be of the same type as the referencing column.
+    /// # Arguments
+    /// * `name` - The name of the table.
+    /// * `constraint` - The constraint to add.
+    /// # Returns
+    /// * `Result<(), SchemaControllerError>` - The result of the operation.
+    pub fn add_constraint(
+        &mut self,
+        name: &table::Name,
+        constraint: Constraint,
+    ) -> Result<(), SchemaControllerError> {
+        let mut foreign_keys = self.get_table_foreign_keys(name)?;
+        if let Constraint::ForeignKey(foreign_key) = &constraint {
+            self.check_foreign_key(name, foreign_key)?;
+            foreign_keys.push(foreign_key.clone());
+        }
+        self.check_referenced_tables(&foreign_keys)?;
+
+        self.change_referencing_table(name, |table, references| {
+            table.add_referencing_constraint(constraint, references)
+        })
+    }
+
+    /// Checks if a `FOREIGN KEY` constraint can be added to a table.
+    /// # Arguments
+    /// * `name` - The name of the table.
+    /// * `foreign_key` - The constraint to check.
+    /// # Returns
+    /// * `Result<(), SchemaControllerError>` - The result of the check.
+    fn check_foreign_key(
+        &mut self,
+        name: &table::Name,
+        foreign_key: &ForeignKey,
+    ) -> Result<(), SchemaControllerError> {
+        let column = self
+            .tables
+            .get_mut_value(name)
+            .ok_or(SchemaControllerError::TableDoesNotExist(name.clone()))?
+            .get_column(foreign_key.get_column())
+            .ok_or(SchemaControllerError::Table(
+                TableControllerError::ColumnDoesNotExist,
+            ))?;
+        let referenced = self
+            .tables
+            .get_mut_value(foreign_key.get_table())
+            .ok_or(SchemaControllerError::ReferencedTableDoesNotExist(
+                foreign_key.get_table().clone(),
+            ))?;
+        let referenced_name = foreign_key.get_referenced_column();
+        let referenced_column = referenced.get_column(referenced_name).ok_or(
+            SchemaControllerError::Table(
+                TableControllerError::ColumnDoesNotExist,
+            ),
+        )?;
+
+        let is_primary_key =
+            referenced
+                .get_primary_key()
+                .as_ref()
+                .is_some_and(|primary_key| {
+                    primary_key.get_column() == referenced_name
+                });
+        let is_unique = referenced.get_constraints().iter().any(|constraint| {
+            let Constraint::Unique(unique) = constraint else {
+                return false;
+            };
+            unique.get_columns().as_slice() == [referenced_name.clone()]
+        });
+        if !is_primary_key && !is_unique {
+            return Err(SchemaControllerError::ReferencedColumnNotUnique(
+                foreign_key.get_name().clone(),
+            ));
+        }
+        if column.get_type() != referenced_column.get_type() {
+            return Err(SchemaControllerError::Table(
+                TableControllerError::WrongTypeForConstraint(
+                    foreign_key.get_name().clone(),
+                ),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Returns the `FOREIGN KEY` constraints of a table of the schema.
+    /// # Arguments
+    /// * `name` - The name of the table.
+    /// # Returns
+    /// * `Result<Vec<ForeignKey>, SchemaControllerError>` - The constraints.
+    fn get_table_foreign_keys(
+        &mut self,
+        name: &table::Name,
+    ) -> Result<Vec<ForeignKey>, SchemaControllerError> {
+        let table = self
+            .tables
+            .get_mut_value(name)
+            .ok_or(SchemaControllerError::TableDoesNotExist(name.clone()))?;
+
+        Ok(table
+            .get_constraints()
+            .iter()
+            .filter_map(|constraint| match constraint {
+                Constraint::ForeignKey(foreign_key) => {
+                    Some(foreign_key.clone())
+                }
+                _ => None,
+            })
+            .collect())
+    }
+
+    /// Returns the `FOREIGN KEY` constraints of all tables of the schema.
+    /// # Returns
+    /// * `Vec<(table::Name, ForeignKey)>` - The constraints with the names of
+    ///   their tables.
+    pub fn get_foreign_keys(&mut self) -> Vec<(table::Name, ForeignKey)> {
+        let mut foreign_keys = Vec::new();
+        for name in self.tables.get_keys() {
+            let table_foreign_keys = self
+                .get_table_foreign_keys(&name)
+                .expect("table exists because listed");
+            foreign_keys.extend(
+                table_foreign_keys
+                    .into_iter()
+                    .map(|foreign_key| (name.clone(), foreign_key)),
+            );
+        }
+
+        foreign_keys
+    }
+
+    /// Returns the `FOREIGN KEY` constraints of other tables that reference
+    /// a table of the schema.
+    /// # Arguments
+    /// * `name` - The name of the referenced table.
+    /// # Returns
+    /// * `Vec<(table::Name, ForeignKey)>` - The constraints with the names of
+    ///   their tables.
+    pub fn get_referencing_foreign_keys(
+        &mut self,
+        name: &table::Name,
+    ) -> Vec<(table::Name, ForeignKey)> {
+        self.get_foreign_keys()
+            .into_iter()
+            .filter(|(table, foreign_key)| {
+                table != name && foreign_key.get_table() == name
+            })
+            .collect()
+    }
+
+    /// Removes the `FOREIGN KEY` constraints of other tables that reference
+    /// a table of the schema, e.g. to drop it.
+    /// # Arguments
+    /// * `name` - The name of the referenced table.
+    pub fn remove_referencing_foreign_keys(&mut self, name: &table::Name) {
+        for (table, foreign_key) in self.get_referencing_foreign_keys(name) {
+            self.tables
+                .get_mut_value(&table)
+                .expect("table exists because listed")
+                .remove_constraint(foreign_key.get_name());
+        }
+    }
+
+    /// Checks if the tables referenced by `FOREIGN KEY` constraints exist.
+    /// # Arguments
+    /// * `foreign_keys` - The constraints.
+    /// # Returns
+    /// * `Result<(), SchemaControllerError>` - The result of the check.
+    fn check_referenced_tables(
+        &mut self,
+        foreign_keys: &[ForeignKey],
+    ) -> Result<(), SchemaControllerError> {
+        for foreign_key in foreign_keys {
+            if !self.tables.contains_key(foreign_key.get_table()) {
+                return Err(
+                    SchemaControllerError::ReferencedTableDoesNotExist(
+                        foreign_key.get_table().clone(),
+                    ),
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Changes a table of the schema that looks up the values referenced by
+    /// its `FOREIGN KEY` constraints in the other tables. Every value is
+    /// looked up by [`controller::Table::contains_value`], i.e. by the
+    /// primary key or `UNIQUE` index of the referenced column. The table is
+    /// taken out of the schema for the time of the change, so that the other
+    /// tables can be read meanwhile.
+    /// # Arguments
+    /// * `name` - The name of the table.
+    /// * `change` - The change of the table.
+    /// # Returns
+    /// * `Result<T, SchemaControllerError>` - The result of the change.
+    fn change_referencing_table<T>(
+        &mut self,
+        name: &table::Name,
+        change: impl FnOnce(
+            &mut controller::Table<NODE_SIZE>,
+            &ReferenceLookup,
+        ) -> Result<T, TableControllerError>,
+    ) -> Result<T, SchemaControllerError> {
+        let table = self
+            .tables
+            .get_mut_value(name)
+            .ok_or(SchemaControllerError::TableDoesNotExist(name.clone()))?;
+        let mut table =
+            std::mem::replace(table, controller::Table::new(name.clone()));
+
+        let tables = &self.tables;
+        let references = |foreign_key: &ForeignKey, value: &StorageData| {
+            tables.get_value(foreign_key.get_table()).map_or(
+                Ok(false),
+                |referenced| {
+                    referenced.contains_value(
+                        foreign_key.get_referenced_column(),
+                        value,
+                    )
+                },
+            )
+        };
+        let result = change(&mut table, &references);
+
+        *self
+            .tables
+            .get_mut_value(name)
+            .expect("table exists because checked") = table;
+        result.map_err(SchemaControllerError::Table)
+    }
+
+    /// Adds a [`DataUnit`] to a table of the schema. Values of the
+    /// `FOREIGN KEY` columns, except for `NULL`, must be in the referenced
+    /// tables.
+    /// # Arguments
+    /// * `name` - The name of the table.
+    /// * `data` - The data to add.
+    /// # Returns
+    /// * `Result<Vec<primary_key::Data>, SchemaControllerError>` - The
+    ///   primary keys of the added rows.
+    pub fn add_data(
+        &mut self,
+        name: &table::Name,
+        data: DataUnit,
+    ) -> Result<Vec<primary_key::Data>, SchemaControllerError> {
+        let foreign_keys = self.get_table_foreign_keys(name)?;
+        self.check_referenced_tables(&foreign_keys)?;
+
+        self.change_referencing_table(name, |table, references| {
+            table.add_referencing_data(data, references)
+        })
+    }
+
+    /// Removes the rows that pass all filters from a table of the schema
+    /// and applies the `ON DELETE` actions of the `FOREIGN KEY` constraints
+    /// that reference them. The whole removal is planned by
+    /// [`Schema::plan_removal`] before any row is changed, so nothing is
+    /// removed if a `RESTRICT` constraint still references a removed row.
+    /// # Arguments
+    /// * `name` - The name of the table.
+    /// * `filters` - The filters to apply.
+    /// # Returns
+    /// * `Result<usize, SchemaControllerError>` - The number of rows removed
+    ///   from the table, without the cascaded ones.
+    pub fn remove_data(
+        &mut self,
+        name: &table::Name,
+        filters: &[SelectorFilter],
+    ) -> Result<usize, SchemaControllerError> {
+        let keys = self
+            .tables
+            .get_mut_value(name)
+            .ok_or(SchemaControllerError::TableDoesNotExist(name.clone()))?
+            .get_keys(filters)
+            .map_err(SchemaControllerError::Table)?;
+        let count = keys.len();
+
+        let removal = self.plan_removal(name, keys)?;
+        for (table, keys) in removal.removed {
+            self.tables
+                .get_mut_value(&table)
+                .expect("table exists because listed")
+                .remove_data(&keys)
+                .map_err(SchemaControllerError::Table)?;
+        }
+        for (table, column, keys) in removal.set_null {
+            self.tables
+                .get_mut_value(&table)
+                .expect("table exists because listed")
+                .set_null(&column, &keys)
+                .map_err(SchemaControllerError::Table)?;
+        }
+
+        Ok(count)
+    }
+
+    /// Plans the removal of rows of a table of the schema: the rows removed
+    /// with them by `ON DELETE CASCADE` constraints, transitively, and the
+    /// columns set to `NULL` by `ON DELETE SET NULL` ones. The values of the
+    /// removed rows are found by their primary keys and put into hash
+    /// tables, so every referencing table is scanned once per step of the
+    /// cascade.
+    /// # Arguments
+    /// * `name` - The name of the table.
+    /// * `keys` - The primary keys of the removed rows.
+    /// # Returns
+    /// * `Result<Removal, SchemaControllerError>` - The removal or
+    ///   [`SchemaControllerError::RestrictViolation`] if a `RESTRICT`
+    ///   constraint references a removed row.
+    fn plan_removal(
+        &mut self,
+        name: &table::Name,
+        keys: Vec<primary_key::Data>,
+    ) -> Result<Removal, SchemaControllerError> {
+        let foreign_keys = self.get_foreign_keys();
+        let mut removal = Removal::default();
+        removal.remove(name, keys);
+        let mut next = 0;
+        while next < removal.removed.len() {
+            let (referenced, keys) = removal.removed[next].clone();
+            next += 1;
+
+            for (referencing, foreign_key) in
+                foreign_keys.iter().filter(|(_, foreign_key)| {
+                    foreign_key.get_table() == &referenced
+                })
+            {
+                let mut values = ScalableHashTable::<HashKey, ()>::default();
+                for value in self
+                    .tables
+                    .get_value(&referenced)
+                    .expect("table exists because listed")
+                    .get_values_by_keys(
+                        foreign_key.get_referenced_column(),
+                        &keys,
+                    )
+                    .map_err(SchemaControllerError::Table)?
+                {
+                    if value != StorageData::Null {
+                        values.insert(HashKey(vec![normalize(value)]), ());
+                    }
+                }
+                if values.is_empty() {
+                    continue;
+                }
+                let referencing_keys = self
+                    .get_keyed_values(referencing, foreign_key.get_column())?
+                    .into_iter()
+                    .filter(|(key, value)| {
+                        values
+                            .get(&HashKey(vec![normalize(value.clone())]))
+                            .is_some()
+                            && !removal.is_removed(referencing, key)
+                    })
+                    .map(|(key, _)| key)
+                    .collect::<Vec<_>>();
+                if referencing_keys.is_empty() {
+                    continue;
+                }
+
+                match foreign_key.get_on_delete() {
+                    OnDelete::Restrict => {
+                        return Err(SchemaControllerError::RestrictViolation(
+                            foreign_key.get_name().clone(),
+                        ))
+                    }
+                    OnDelete::Cascade => {
+                        removal.remove(referencing, referencing_keys)
+                    }
+                    OnDelete::SetNull => removal.set_null.push((
+                        referencing.clone(),
+                        foreign_key.get_column().clone(),
+                        referencing_keys,
+                    )),
+                }
+            }
+        }
+
+        Ok(removal)
+    }
+
+    /// Returns the values of a column of a table of the schema with the
+    /// primary keys of their rows.
+    fn get_keyed_values(
+        &mut self,
+        name: &table::Name,
+        column: &column::Name,
+    ) -> Result<Vec<(primary_key::Data, StorageData)>, SchemaControllerError>
+    {
+        self.tables
+            .get_mut_value(name)
+            .ok_or(SchemaControllerError::TableDoesNotExist(name.clone()))?
+            .get_keyed_values(column)
+            .map_err(SchemaControllerError::Table)
+    }
+
+    /// Returns the plan [`Schema::get_joined_data`] joins two tables of the
+    /// schema by, the cheapest one by [`cost::plan_join`] for the estimated
+    /// sizes of the tables.
+    /// # Arguments
+    /// * `left` - The left table.
+    /// * `right` - The right table.
+    /// * `join` - The join.
+    /// # Returns
+    /// * `Result<JoinPlan, SchemaControllerError>` - The plan.
+    pub fn get_join_plan(
+        &self,
+        left: &JoinedTable,
+        right: &JoinedTable,
+        join: &Join,
+    ) -> Result<JoinPlan, SchemaControllerError> {
+        let left_input = self.get_join_input(left)?;
+        let right_input = self.get_join_input(right)?;
+        if left.qualifier() == right.qualifier() {
+            return Err(SchemaControllerError::DuplicateQualifier(
+                left.qualifier().clone(),
+            ));
+        }
+
+        Ok(cost::plan_join(join, &left_input, &right_input))
+    }
+
+    /// Joins the rows of two tables of the schema. Columns are qualified by
+    /// the names or the aliases of their tables, e.g. `users.id`, both in
+    /// the `ON` condition and in the result. A table is joined with itself
+    /// if at least one of the sides has an alias. The rows are joined by
+    /// [`executor::Join`] over [`Scan`]s of the tables, as planned by
+    /// [`Schema::get_join_plan`].
+    /// # Arguments
+    /// * `left` - The left table.
+    /// * `right` - The right table, it can't be qualified as the left one.
+    /// * `join` - The join.
+    /// # Returns
+    /// * `Result<DataUnit, SchemaControllerError>` - The joined rows with the
+    ///   left columns followed by the right columns.
+    pub fn get_joined_data(
+        &self,
+        left: &JoinedTable,
+        right: &JoinedTable,
+        join: &Join,
+    ) -> Result<DataUnit, SchemaControllerError> {
+        let plan = self.get_join_plan(left, right, join)?;
+        let left_table = self
+            .tables
+            .get_value(&left.name)
+            .expect("table exists because checked");
+        let right_table = self
+            .tables
+            .get_value(&right.name)
+            .expect("table exists because checked");
+
+        join_tables(
+            (left_table, left.qualifier()),
+            (right_table, right.qualifier()),
+            plan,
+            join,
+        )
+        .map_err(SchemaControllerError::Table)
+    }
+
+    /// Returns the estimated size of a scan of a table of the schema as an
+    /// input of a join, with the column names qualified by the name or the
+    /// alias of the table.
+    fn get_join_input(
+        &self,
+        joined: &JoinedTable,
+    ) -> Result<JoinInput, SchemaControllerError> {
+        let table = self.tables.get_value(&joined.name).ok_or(
+            SchemaControllerError::TableDoesNotExist(joined.name.clone()),
+        )?;
+
+        let qualifier = joined.qualifier();
+        let names = table.get_column_names();
+        Ok(JoinInput {
+            column_names: names
+                .iter()
+                .map(|column| join::qualified_name(qualifier, column))
+                .collect(),
+            distinct_counts: names
+                .iter()
+                .map(|column| table.estimate_distinct(column))
+                .collect(),
+            rows: table.estimate_rows(&[]),
+            primary_key: table.get_primary_key().as_ref().map(|primary_key| {
+                join::qualified_name(qualifier, primary_key.get_column())
+            }),
+        })
+    }
+
+    /// Adds a standalone sequence to the schema.
+    /// # Arguments
+    /// * `name` - The name of the sequence.
+    /// * `sequence` - The sequence to add.
+    /// # Returns
+    /// * `bool` - Whether the sequence was added.
+    pub fn add_sequence(
+        &mut self,
+        name: sequence::Name,
+        sequence: Sequence,
+    ) -> bool {
+        if self.sequences.contains_key(&name) {
+            false
+        } else {
+            self.sequences.insert(name, sequence);
+            true
+        }
+    }
+
+    /// Gets a standalone sequence from the schema.
+    /// # Arguments
+    /// * `name` - The name of the sequence to get.
+    /// # Returns
+    /// * `Option<&mut Sequence>` - The sequence with the given name.
+    pub fn get_mut_sequence(
+        &mut self,
+        name: &sequence::Name,
+    ) -> Option<&mut Sequence> {
+        self.sequences.get_mut_value(name)
+    }
+
+    /// Takes the next value of a standalone sequence of the schema, e.g. for
+    /// the `NEXTVAL` calls of an [`Expression`].
+    /// # Arguments
+    /// * `name` - The name of the sequence.
+    /// # Returns
+    /// * `Ok(StorageData)` - The next value as a `Long`.
+    /// * `Err(SchemaControllerError)` - If the sequence doesn't exist or is
+    ///   exhausted.
+    ///
+    /// [`Expression`]: crate::expression::Expression
+    pub fn next_sequence_value(
+        &mut self,
+        name: &sequence::Name,
+    ) -> Result<StorageData, SchemaControllerError> {
+        let sequence = self.get_mut_sequence(name).ok_or_else(|| {
+            SchemaControllerError::SequenceDoesNotExist(name.clone())
+        })?;
+        let value = sequence.next_value().ok_or_else(|| {
+            SchemaControllerError::SequenceExhausted(name.clone())
+        })?;
+
+        Ok(StorageDataType::Long
+            .integer_value(value)
+            .expect("`Long` holds any value"))
+    }
+
+    /// Checks the pages of all tables in the schema.
+    /// # Returns
+    /// * `Vec<(table::Name, Vec<usize>)>` - Names of the tables with corrupted
+    ///   pages and indexes of these pages.
+    pub fn verify(&mut self) -> Vec<(table::Name, Vec<usize>)> {
+        self.tables
+            .get_values()
+            .into_iter()
+            .map(|table| (table.get_name().clone(), table.verify_pages()))
+            .filter(|(_, pages)| !pages.is_empty())
+            .collect()
+    }
 }
 
 impl<const NODE_SIZE: u8> PartialEq for Schema<NODE_SIZE> {
@@ -108,6 +698,141 @@ impl<const NODE_SIZE: u8> PartialEq for Schema<NODE_SIZE> {
     }
 }
 
+/// Joins the rows of the tables by the plan. If the plan swaps the
+/// tables, the columns of the joined rows are put back into the order of
+/// the join.
+/// # Arguments
+/// * `left` - The left table and the name its columns are qualified by.
+/// * `right` - The right table and the name its columns are qualified by.
+/// * `plan` - The plan, see [`cost::plan_join`].
+/// * `join` - The join.
+/// # Returns
+/// * `Result<DataUnit, TableControllerError>` - The joined rows.
+fn join_tables<const NODE_SIZE: u8>(
+    (left, left_qualifier): (&controller::Table<NODE_SIZE>, &table::Name),
+    (right, right_qualifier): (&controller::Table<NODE_SIZE>, &table::Name),
+    plan: JoinPlan,
+    join: &Join,
+) -> Result<DataUnit, TableControllerError> {
+    let left_scan = Scan::new(left, Vec::new())?.aliased(left_qualifier);
+    let right_scan = Scan::new(right, Vec::new())?.aliased(right_qualifier);
+    let column_names = left_scan
+        .column_names()
+        .iter()
+        .chain(right_scan.column_names())
+        .cloned()
+        .collect::<Vec<_>>();
+
+    let (outer, inner, join) = if plan.swapped {
+        (right_scan, left_scan, join.swapped())
+    } else {
+        (left_scan, right_scan, join.clone())
+    };
+    let inner_table = if plan.swapped { left } else { right };
+    let joined: Box<dyn Operator> = match plan.algorithm {
+        JoinAlgorithm::NestedLoop => Box::new(executor::Join::nested_loop(
+            Box::new(outer),
+            Box::new(inner),
+            join,
+        )?),
+        JoinAlgorithm::Hash => Box::new(executor::Join::hash(
+            Box::new(outer),
+            Box::new(inner),
+            join,
+        )?),
+        JoinAlgorithm::IndexNestedLoop(key) => {
+            Box::new(executor::Join::index_nested_loop(
+                Box::new(outer),
+                inner.column_names().to_vec(),
+                inner.column_types().to_vec(),
+                join,
+                key,
+                Box::new(|value| inner_table.get_row_by_key(value)),
+            )?)
+        }
+    };
+
+    if plan.swapped {
+        Project::columns(joined, column_names)?.collect()
+    } else {
+        let mut joined = joined;
+        joined.collect()
+    }
+}
+
+/// Rows changed by the removal of rows of a table, as planned by
+/// [`Schema::plan_removal`].
+#[derive(Debug, Default)]
+struct Removal {
+    /// The primary keys of the removed rows, by table and in the order of
+    /// the cascade.
+    removed: Vec<(table::Name, Vec<primary_key::Data>)>,
+
+    /// The primary keys of the removed rows by table, to check if a row is
+    /// removed already.
+    removed_keys: Vec<(table::Name, ScalableHashTable<primary_key::Data, ()>)>,
+
+    /// The primary keys of the rows whose column is set to `NULL`, with the
+    /// names of their tables and of the columns.
+    set_null: Vec<(table::Name, column::Name, Vec<primary_key::Data>)>,
+}
+
+impl Removal {
+    /// Adds rows of a table to the removed ones.
+    fn remove(&mut self, name: &table::Name, keys: Vec<primary_key::Data>) {
+        let position = self
+            .removed_keys
+            .iter()
+            .position(|(table, _)| table == name)
+            .unwrap_or_else(|| {
+                self.removed_keys
+                    .push((name.clone(), ScalableHashTable::default()));
+                self.removed_keys.len() - 1
+            });
+        for key in &keys {
+            self.removed_keys[position].1.insert(key.clone(), ());
+        }
+        self.removed.push((name.clone(), keys));
+    }
+
+    /// Checks if a row of a table is removed already.
+    fn is_removed(&self, name: &table::Name, key: &primary_key::Data) -> bool {
+        self.removed_keys
+            .iter()
+            .find(|(table, _)| table == name)
+            .is_some_and(|(_, keys)| keys.get(key).is_some())
+    }
+}
+
+/// Errors that can occur when changing the tables of a [`Schema`].
+#[derive(Debug, Display, PartialEq)]
+pub enum SchemaControllerError {
+    #[display(fmt = "Table `{}` does not exist", _0)]
+    TableDoesNotExist(table::Name),
+    #[display(fmt = "Referenced table `{}` does not exist", _0)]
+    ReferencedTableDoesNotExist(table::Name),
+    #[display(
+        fmt = "Column referenced by foreign key `{}` is neither the primary \
+               key nor unique",
+        _0
+    )]
+    ReferencedColumnNotUnique(constraint::Name),
+    #[display(fmt = "Row is still referenced by foreign key `{}`", _0)]
+    RestrictViolation(constraint::Name),
+    #[display(
+        fmt = "Table name `{}` is used by both sides of the join, alias one \
+               of them",
+        _0
+    )]
+    DuplicateQualifier(table::Name),
+    #[display(fmt = "Sequence `{}` does not exist", _0)]
+    SequenceDoesNotExist(sequence::Name),
+    #[display(fmt = "Sequence `{}` is exhausted", _0)]
+    SequenceExhausted(sequence::Name),
+    #[display(fmt = "{}", _0)]
+    Table(TableControllerError),
+}
+
 #[cfg(test)]
 mod tests {
     use std::sync::{Arc, Mutex};
@@ -116,11 +841,105 @@ mod tests {
 
     use crate::{
         controller,
-        controller::schema::Schema,
-        data::{data_storage::DataStorage, id},
+        controller::{
+            schema::{Schema, SchemaControllerError},
+            table::{
+                selector::{FilterType, SelectorFilter},
+                TableControllerError,
+            },
+        },
+        data::{data_storage::DataStorage, id, id::Sequence, DataUnit},
+        expression::{BinaryOperator, Expression, Function},
         page::page_controller::PageController,
+        query::{Join, JoinAlgorithm, JoinType, JoinedTable},
+        schema::{
+            column::primary_key::{self, PrimaryKey},
+            r#type::{
+                data_types::Text,
+                r#enum::{StorageData, StorageDataType},
+            },
+            table::constraint::{Constraint, ForeignKey, OnDelete, Unique},
+            Column,
+        },
     };
 
+    fn int(value: i32) -> StorageData {
+        StorageData::Integer(value.into())
+    }
+
+    fn key(value: i32) -> primary_key::Data {
+        primary_key::Data::Integer(value.into())
+    }
+
+    /// Creates a table with an `id` primary key and a `ref_id` column.
+    fn table(name: &str) -> controller::Table<4> {
+        let mut table = controller::Table::<4>::new(name.into());
+        table
+            .add_column("id".into(), Column::new(StorageDataType::Integer))
+            .unwrap();
+        table
+            .add_column("ref_id".into(), Column::new(StorageDataType::Integer))
+            .unwrap();
+        table
+            .set_primary_key(PrimaryKey::new("pk".into(), "id".into()))
+            .unwrap();
+        table
+    }
+
+    fn insert(
+        schema: &mut Schema<4>,
+        table: &str,
+        rows: &[(i32, StorageData)],
+    ) -> Result<Vec<primary_key::Data>, SchemaControllerError> {
+        let mut data = DataUnit::new(vec!["id".into(), "ref_id".into()]);
+        for (id, ref_id) in rows {
+            data.insert(vec![int(*id), ref_id.clone()].into());
+        }
+        schema.add_data(&table.into(), data)
+    }
+
+    fn foreign_key(table: &str, on_delete: OnDelete) -> Constraint {
+        Constraint::ForeignKey(ForeignKey::new(
+            format!("{}_ref_id_fkey", table).into(),
+            "ref_id".into(),
+            "users".into(),
+            "id".into(),
+            on_delete,
+        ))
+    }
+
+    /// Creates `users` with the ids 1 and 2 and a table referencing them.
+    fn referenced_schema(on_delete: OnDelete) -> Schema<4> {
+        let mut schema = Schema::<4>::new("test".into());
+        schema.add_table(table("users"));
+        schema.add_table(table("orders"));
+        schema
+            .add_constraint(&"orders".into(), foreign_key("orders", on_delete))
+            .unwrap();
+        insert(
+            &mut schema,
+            "users",
+            &[(1, StorageData::Null), (2, StorageData::Null)],
+        )
+        .unwrap();
+        insert(&mut schema, "orders", &[(10, int(1)), (11, int(2))]).unwrap();
+        schema
+    }
+
+    fn remove_user(
+        schema: &mut Schema<4>,
+        id: i32,
+    ) -> Result<usize, SchemaControllerError> {
+        schema.remove_data(
+            &"users".into(),
+            &[SelectorFilter {
+                column_name: "id".into(),
+                value: int(id),
+                filter_type: FilterType::Equal,
+            }],
+        )
+    }
+
     #[test]
     fn test_schema_new() {
         let schema = Schema::<4>::new("test".into());
@@ -176,4 +995,444 @@ mod tests {
         let table = table.unwrap();
         assert_eq!(table.get_pages().len(), 1);
     }
+
+    #[test]
+    fn test_schema_sequence() {
+        let mut schema = Schema::<4>::new("test".into());
+        assert!(schema.add_sequence("seq".into(), Sequence::new(5)));
+        assert!(!schema.add_sequence("seq".into(), Sequence::default()));
+
+        let sequence = schema.get_mut_sequence(&"seq".into()).unwrap();
+        assert_eq!(sequence.next_value(), Some(5));
+        let sequence = schema.get_mut_sequence(&"seq".into()).unwrap();
+        assert_eq!(sequence.next_value(), Some(6));
+
+        assert!(schema.get_mut_sequence(&"other".into()).is_none());
+    }
+
+    #[test]
+    fn test_schema_next_sequence_value() {
+        let mut schema = Schema::<4>::new("test".into());
+        schema.add_sequence("seq".into(), Sequence::new(5).with_max(6));
+
+        let next_value = Expression::Function {
+            function: Function::NextVal,
+            arguments: vec![Expression::Literal(Text("seq".into()).into())],
+        };
+        let expression = Expression::binary(
+            next_value.clone(),
+            BinaryOperator::Add,
+            next_value.clone(),
+        );
+        let expression = expression
+            .with_next_values(&mut |name| schema.next_sequence_value(name))
+            .unwrap();
+        assert_eq!(
+            expression,
+            Expression::binary(
+                Expression::Literal(
+                    StorageDataType::Long.integer_value(5).unwrap()
+                ),
+                BinaryOperator::Add,
+                Expression::Literal(
+                    StorageDataType::Long.integer_value(6).unwrap()
+                ),
+            )
+        );
+        assert_eq!(
+            next_value
+                .with_next_values(&mut |name| schema.next_sequence_value(name)),
+            Err(SchemaControllerError::SequenceExhausted("seq".into()))
+        );
+
+        let other = Expression::Function {
+            function: Function::NextVal,
+            arguments: vec![Expression::Literal(Text("other".into()).into())],
+        };
+        assert_eq!(
+            other
+                .with_next_values(&mut |name| schema.next_sequence_value(name)),
+            Err(SchemaControllerError::SequenceDoesNotExist("other".into()))
+        );
+    }
+
+    #[test]
+    fn test_schema_verify() {
+        let mut schema = Schema::<4>::new("test".into());
+        schema.add_table(controller::Table::<4>::new("table".into()));
+        schema.add_table(controller::Table::<4>::new("other".into()));
+        assert!(schema.verify().is_empty());
+
+        schema
+            .get_mut_table(&"table".into())
+            .unwrap()
+            .corrupt_page(0);
+
+        assert_eq!(schema.verify(), vec![("table".into(), vec![0])]);
+    }
+
+    #[test]
+    fn test_schema_foreign_key_add_data() {
+        let mut schema = referenced_schema(OnDelete::Restrict);
+
+        assert_eq!(
+            insert(&mut schema, "orders", &[(12, int(3))]),
+            Err(SchemaControllerError::Table(
+                TableControllerError::ForeignKeyViolation(
+                    "orders_ref_id_fkey".into()
+                )
+            ))
+        );
+        assert_eq!(
+            insert(&mut schema, "orders", &[(12, StorageData::Null)]),
+            Ok(vec![key(12)])
+        );
+    }
+
+    #[test]
+    fn test_schema_add_foreign_key() {
+        let mut schema = referenced_schema(OnDelete::Restrict);
+        schema.add_table(table("items"));
+        insert(&mut schema, "items", &[(1, int(3))]).unwrap();
+
+        assert_eq!(
+            schema.add_constraint(
+                &"items".into(),
+                foreign_key("items", OnDelete::Restrict)
+            ),
+            Err(SchemaControllerError::Table(
+                TableControllerError::ForeignKeyViolation(
+                    "items_ref_id_fkey".into()
+                )
+            ))
+        );
+        assert!(schema
+            .get_mut_table(&"items".into())
+            .is_some_and(|table| table.get_constraints().is_empty()));
+
+        let to_ref_id = Constraint::ForeignKey(ForeignKey::new(
+            "items_id_fkey".into(),
+            "id".into(),
+            "users".into(),
+            "ref_id".into(),
+            OnDelete::Restrict,
+        ));
+        assert_eq!(
+            schema.add_constraint(&"items".into(), to_ref_id.clone()),
+            Err(SchemaControllerError::ReferencedColumnNotUnique(
+                "items_id_fkey".into()
+            ))
+        );
+        schema
+            .add_constraint(
+                &"users".into(),
+                Constraint::Unique(Unique::new(
+                    "users_ref_id_key".into(),
+                    vec!["ref_id".into()],
+                )),
+            )
+            .unwrap();
+        insert(&mut schema, "users", &[(3, int(1))]).unwrap();
+        schema.add_constraint(&"items".into(), to_ref_id).unwrap();
+        assert_eq!(
+            insert(&mut schema, "items", &[(2, StorageData::Null)]),
+            Err(SchemaControllerError::Table(
+                TableControllerError::ForeignKeyViolation(
+                    "items_id_fkey".into()
+                )
+            ))
+        );
+
+        let to_missing = Constraint::ForeignKey(ForeignKey::new(
+            "items_missing_fkey".into(),
+            "ref_id".into(),
+            "missing".into(),
+            "id".into(),
+            OnDelete::Restrict,
+        ));
+        assert_eq!(
+            schema.add_constraint(&"items".into(), to_missing),
+            Err(SchemaControllerError::ReferencedTableDoesNotExist(
+                "missing".into()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_schema_remove_data_restrict() {
+        let mut schema = referenced_schema(OnDelete::Restrict);
+
+        assert_eq!(
+            remove_user(&mut schema, 1),
+            Err(SchemaControllerError::RestrictViolation(
+                "orders_ref_id_fkey".into()
+            ))
+        );
+        let users = schema.get_mut_table(&"users".into()).unwrap();
+        assert_eq!(users.get_keys(&[]), Ok(vec![key(1), key(2)]));
+    }
+
+    #[test]
+    fn test_schema_remove_data_cascade() {
+        let mut schema = referenced_schema(OnDelete::Cascade);
+        schema.add_table(table("items"));
+        schema
+            .add_constraint(
+                &"items".into(),
+                Constraint::ForeignKey(ForeignKey::new(
+                    "items_ref_id_fkey".into(),
+                    "ref_id".into(),
+                    "orders".into(),
+                    "id".into(),
+                    OnDelete::Cascade,
+                )),
+            )
+            .unwrap();
+        insert(&mut schema, "items", &[(100, int(10)), (101, int(11))])
+            .unwrap();
+
+        assert_eq!(remove_user(&mut schema, 1), Ok(1));
+        let orders = schema.get_mut_table(&"orders".into()).unwrap();
+        assert_eq!(orders.get_keys(&[]), Ok(vec![key(11)]));
+        let items = schema.get_mut_table(&"items".into()).unwrap();
+        assert_eq!(items.get_keys(&[]), Ok(vec![key(101)]));
+    }
+
+    #[test]
+    fn test_schema_remove_data_cascade_restrict() {
+        let mut schema = referenced_schema(OnDelete::Cascade);
+        schema.add_table(table("items"));
+        schema
+            .add_constraint(
+                &"items".into(),
+                Constraint::ForeignKey(ForeignKey::new(
+                    "items_ref_id_fkey".into(),
+                    "ref_id".into(),
+                    "orders".into(),
+                    "id".into(),
+                    OnDelete::Restrict,
+                )),
+            )
+            .unwrap();
+        insert(&mut schema, "items", &[(100, int(11))]).unwrap();
+
+        assert_eq!(remove_user(&mut schema, 1), Ok(1));
+        assert_eq!(
+            remove_user(&mut schema, 2),
+            Err(SchemaControllerError::RestrictViolation(
+                "items_ref_id_fkey".into()
+            ))
+        );
+        let users = schema.get_mut_table(&"users".into()).unwrap();
+        assert_eq!(users.get_keys(&[]), Ok(vec![key(2)]));
+        let orders = schema.get_mut_table(&"orders".into()).unwrap();
+        assert_eq!(orders.get_keys(&[]), Ok(vec![key(11)]));
+    }
+
+    #[test]
+    fn test_schema_remove_data_set_null() {
+        let mut schema = referenced_schema(OnDelete::SetNull);
+
+        assert_eq!(remove_user(&mut schema, 2), Ok(1));
+        let orders = schema.get_mut_table(&"orders".into()).unwrap();
+        assert_eq!(
+            orders.get_keyed_values(&"ref_id".into()),
+            Ok(vec![(key(10), int(1)), (key(11), StorageData::Null)])
+        );
+    }
+
+    #[test]
+    fn test_schema_self_referencing_foreign_key() {
+        let mut schema = Schema::<4>::new("test".into());
+        schema.add_table(table("users"));
+        schema
+            .add_constraint(
+                &"users".into(),
+                foreign_key("users", OnDelete::Cascade),
+            )
+            .unwrap();
+        insert(
+            &mut schema,
+            "users",
+            &[
+                (1, StorageData::Null),
+                (2, int(1)),
+                (3, int(2)),
+                (4, int(4)),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(remove_user(&mut schema, 1), Ok(1));
+        let users = schema.get_mut_table(&"users".into()).unwrap();
+        assert_eq!(users.get_keys(&[]), Ok(vec![key(4)]));
+    }
+
+    #[test]
+    fn test_schema_referencing_foreign_keys() {
+        let mut schema = referenced_schema(OnDelete::Restrict);
+
+        assert_eq!(
+            schema
+                .get_referencing_foreign_keys(&"users".into())
+                .into_iter()
+                .map(|(table, _)| table)
+                .collect::<Vec<_>>(),
+            vec!["orders".into()]
+        );
+        schema.remove_referencing_foreign_keys(&"users".into());
+        assert!(schema
+            .get_referencing_foreign_keys(&"users".into())
+            .is_empty());
+        assert_eq!(remove_user(&mut schema, 1), Ok(1));
+    }
+
+    #[test]
+    fn test_schema_get_joined_data() {
+        let mut schema = referenced_schema(OnDelete::Restrict);
+        insert(&mut schema, "orders", &[(12, StorageData::Null)]).unwrap();
+        let others = (3..=20)
+            .map(|id| (id, StorageData::Null))
+            .collect::<Vec<_>>();
+        insert(&mut schema, "users", &others).unwrap();
+        let (users, orders): (JoinedTable, JoinedTable) =
+            ("users".into(), "orders".into());
+        let on = || {
+            Expression::binary(
+                Expression::Column("users.id".into()),
+                BinaryOperator::Equal,
+                Expression::Column("orders.ref_id".into()),
+            )
+        };
+
+        let join = Join::new(JoinType::Left, on());
+        let plan = schema.get_join_plan(&orders, &users, &join).unwrap();
+        assert_eq!(
+            plan.algorithm,
+            JoinAlgorithm::IndexNestedLoop(Expression::Column(
+                "orders.ref_id".into()
+            ))
+        );
+        assert!(!plan.swapped);
+        let result = schema.get_joined_data(&orders, &users, &join).unwrap();
+        assert_eq!(
+            result.get_names(),
+            &[
+                "orders.id".into(),
+                "orders.ref_id".into(),
+                "users.id".into(),
+                "users.ref_id".into()
+            ]
+        );
+        assert_eq!(
+            result
+                .get_values()
+                .into_iter()
+                .map(|row| (row.0[0].clone(), row.0[2].clone()))
+                .collect::<Vec<_>>(),
+            vec![
+                (int(10), int(1)),
+                (int(11), int(2)),
+                (int(12), StorageData::Null)
+            ]
+        );
+
+        let join = Join::new(JoinType::Right, on());
+        let plan = schema.get_join_plan(&users, &orders, &join).unwrap();
+        assert!(plan.swapped);
+        assert_eq!(
+            plan.algorithm,
+            JoinAlgorithm::IndexNestedLoop(Expression::Column(
+                "orders.ref_id".into()
+            ))
+        );
+        let result = schema.get_joined_data(&users, &orders, &join).unwrap();
+        assert_eq!(
+            result.get_names(),
+            &[
+                "users.id".into(),
+                "users.ref_id".into(),
+                "orders.id".into(),
+                "orders.ref_id".into()
+            ]
+        );
+        assert_eq!(
+            result
+                .get_values()
+                .into_iter()
+                .map(|row| (row.0[0].clone(), row.0[2].clone()))
+                .collect::<Vec<_>>(),
+            vec![
+                (int(1), int(10)),
+                (int(2), int(11)),
+                (StorageData::Null, int(12))
+            ]
+        );
+
+        let join = Join::cross();
+        assert_eq!(
+            schema
+                .get_join_plan(&users, &orders, &join)
+                .unwrap()
+                .algorithm,
+            JoinAlgorithm::NestedLoop
+        );
+        assert_eq!(
+            schema
+                .get_joined_data(&users, &orders, &join)
+                .unwrap()
+                .len(),
+            60
+        );
+
+        assert_eq!(
+            schema.get_joined_data(&users, &users, &join),
+            Err(SchemaControllerError::DuplicateQualifier("users".into()))
+        );
+        assert_eq!(
+            schema.get_joined_data(&users, &"items".into(), &join),
+            Err(SchemaControllerError::TableDoesNotExist("items".into()))
+        );
+    }
+
+    #[test]
+    fn test_schema_get_self_joined_data() {
+        let mut schema = Schema::<4>::new("test".into());
+        schema.add_table(table("users"));
+        insert(
+            &mut schema,
+            "users",
+            &[(1, StorageData::Null), (2, int(1)), (3, int(1))],
+        )
+        .unwrap();
+        let users = JoinedTable::from("users");
+        let managers = JoinedTable::aliased("users".into(), "m".into());
+        let join = Join::new(
+            JoinType::Inner,
+            Expression::binary(
+                Expression::Column("users.ref_id".into()),
+                BinaryOperator::Equal,
+                Expression::Column("m.id".into()),
+            ),
+        );
+
+        let result = schema.get_joined_data(&users, &managers, &join).unwrap();
+        assert_eq!(
+            result.get_names(),
+            &[
+                "users.id".into(),
+                "users.ref_id".into(),
+                "m.id".into(),
+                "m.ref_id".into()
+            ]
+        );
+        assert_eq!(
+            result
+                .get_values()
+                .into_iter()
+                .map(|row| (row.0[0].clone(), row.0[2].clone()))
+                .collect::<Vec<_>>(),
+            vec![(int(2), int(1)), (int(3), int(1))]
+        );
+    }
 }
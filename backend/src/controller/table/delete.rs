@@ -0,0 +1,251 @@
+use common::structs::hash_table::HashTable;
+
+use crate::{
+    controller::{table::TableControllerError, Table},
+    schema::{
+        column,
+        column::primary_key,
+        r#type::{r#enum::StorageData, DataRow},
+    },
+};
+
+impl<const NODE_SIZE: u8> Table<NODE_SIZE> {
+    /// Removes the rows with the given primary keys from the table. Keys
+    /// that are not in the table are skipped.
+    /// # Arguments
+    /// * `keys` - The primary keys of the rows to remove.
+    /// # Returns
+    /// * `Result<usize, TableControllerError>` - The number of removed rows.
+    pub fn remove_data(
+        &mut self,
+        keys: &[primary_key::Data],
+    ) -> Result<usize, TableControllerError> {
+        let mut removed = 0;
+        let mut result = Ok(());
+        let mut data_storage = self.data_storage.lock().unwrap();
+        for key in keys {
+            let Some(id) = self.keys.remove(key) else {
+                continue;
+            };
+            let row = if self.unique.is_empty() {
+                Ok(None)
+            } else {
+                data_storage.get_data(id).map(Some)
+            };
+            let Ok(row) = row.and_then(|row| {
+                data_storage.remove_data(id)?;
+                Ok(row)
+            }) else {
+                self.keys.insert(key.clone(), id);
+                result = Err(TableControllerError::DataStorageError);
+                break;
+            };
+            if let Some(row) = row {
+                for index in &mut self.unique {
+                    index.remove(&row.0);
+                }
+            }
+            removed += 1;
+        }
+        // The index drops the removed rows in one pass, also if removing a
+        // row failed.
+        if removed > 0 {
+            let keys = &self.keys;
+            self.index.retain(|key_id| keys.get(&key_id.key).is_some());
+        }
+
+        result.map(|_| removed)
+    }
+
+    /// Sets a column of the rows with the given primary keys to `NULL`.
+    /// Keys that are not in the table are skipped.
+    /// # Arguments
+    /// * `name` - The name of the column, it can't be the primary key.
+    /// * `keys` - The primary keys of the rows to update.
+    /// # Returns
+    /// * `Result<(), TableControllerError>` - The result of the operation.
+    pub fn set_null(
+        &mut self,
+        name: &column::Name,
+        keys: &[primary_key::Data],
+    ) -> Result<(), TableControllerError> {
+        let position = self.column_positions(std::slice::from_ref(name))?[0];
+        let mut data_storage = self.data_storage.lock().unwrap();
+        let mut updated = false;
+        for key in keys {
+            let Some(id) = self.keys.get(key) else {
+                continue;
+            };
+            let mut row = data_storage
+                .get_data(id)
+                .map_err(|_| TableControllerError::DataStorageError)?;
+            for index in &mut self.unique {
+                if index.positions.contains(&position) {
+                    index.remove(&row.0);
+                }
+            }
+            row.0[position] = StorageData::Null;
+            data_storage
+                .remove_data(id)
+                .map_err(|_| TableControllerError::DataStorageError)?;
+            let id = data_storage
+                .add_data(DataRow::from(row.0))
+                .map_err(|_| TableControllerError::DataStorageError)?;
+            self.keys.insert(key.clone(), id);
+            updated = true;
+        }
+        // Rows keep their places in the index with the ids they were
+        // stored again with.
+        if updated {
+            let keys = &self.keys;
+            for key_id in &mut self.index {
+                if let Some(id) = keys.get(&key_id.key) {
+                    key_id.id = id;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        controller::{table::TableControllerError, Table},
+        data::DataUnit,
+        schema,
+        schema::{
+            column::primary_key,
+            r#type::r#enum::{StorageData, StorageDataType},
+            table::constraint::{Constraint, Unique},
+        },
+    };
+
+    fn orders_table() -> Table<16> {
+        let mut table = Table::<16>::new("orders".into());
+        table
+            .add_column(
+                "id".into(),
+                schema::Column::new(StorageDataType::Integer),
+            )
+            .unwrap();
+        table
+            .add_column(
+                "user_id".into(),
+                schema::Column::new(StorageDataType::Integer),
+            )
+            .unwrap();
+        table
+            .set_primary_key(primary_key::PrimaryKey::new(
+                "pk".into(),
+                "id".into(),
+            ))
+            .expect("Failed to set primary key");
+
+        let mut data = DataUnit::new(vec!["id".into(), "user_id".into()]);
+        for (id, user_id) in [(1, 10), (2, 20), (3, 10)] {
+            data.insert(
+                vec![
+                    StorageData::Integer(id.into()),
+                    StorageData::Integer(user_id.into()),
+                ]
+                .into(),
+            );
+        }
+        table.add_data(data).expect("is ok");
+
+        table
+    }
+
+    fn key(id: i32) -> primary_key::Data {
+        primary_key::Data::Integer(id.into())
+    }
+
+    #[test]
+    fn test_remove_data() {
+        let mut table = orders_table();
+
+        assert_eq!(table.remove_data(&[key(1), key(4)]), Ok(1));
+        assert_eq!(table.get_keys(&[]), Ok(vec![key(2), key(3)]));
+        assert_eq!(table.remove_data(&[key(1)]), Ok(0));
+        assert_eq!(table.get_row_by_key(&StorageData::from(1)), Ok(None));
+        assert!(table
+            .get_row_by_key(&StorageData::from(3))
+            .unwrap()
+            .is_some());
+
+        let mut data = DataUnit::new(vec!["id".into(), "user_id".into()]);
+        data.insert(vec![StorageData::from(1), StorageData::from(30)].into());
+        table.add_data(data).unwrap();
+        assert_eq!(table.get_keys(&[]), Ok(vec![key(2), key(3), key(1)]));
+    }
+
+    #[test]
+    fn test_set_null() {
+        let mut table = orders_table();
+
+        table
+            .set_null(&"user_id".into(), &[key(1), key(3)])
+            .unwrap();
+        assert_eq!(
+            table.get_keyed_values(&"user_id".into()),
+            Ok(vec![
+                (key(1), StorageData::Null),
+                (key(2), StorageData::Integer(20.into())),
+                (key(3), StorageData::Null),
+            ])
+        );
+        assert_eq!(
+            table.get_row_by_key(&StorageData::from(3)),
+            Ok(Some(vec![StorageData::from(3), StorageData::Null].into()))
+        );
+        assert_eq!(
+            table.set_null(&"name".into(), &[key(1)]),
+            Err(TableControllerError::ColumnDoesNotExist)
+        );
+    }
+
+    #[test]
+    fn test_remove_data_unique() {
+        let mut table = orders_table();
+        let unique = Constraint::Unique(Unique::new(
+            "user_id_key".into(),
+            vec!["user_id".into()],
+        ));
+        table.remove_data(&[key(3)]).unwrap();
+        table.add_constraint(unique).unwrap();
+        let insert = |table: &mut Table<16>, id: i32, user_id: i32| {
+            let mut data = DataUnit::new(vec!["id".into(), "user_id".into()]);
+            data.insert(
+                vec![StorageData::from(id), StorageData::from(user_id)].into(),
+            );
+            table.add_data(data).map(|_| ())
+        };
+
+        assert_eq!(
+            insert(&mut table, 4, 10),
+            Err(TableControllerError::UniqueViolation("user_id_key".into()))
+        );
+        assert_eq!(
+            table.contains_value(&"user_id".into(), &StorageData::from(10)),
+            Ok(true)
+        );
+        table.remove_data(&[key(1)]).unwrap();
+        assert_eq!(
+            table.contains_value(&"user_id".into(), &StorageData::from(10)),
+            Ok(false)
+        );
+        assert_eq!(insert(&mut table, 4, 10), Ok(()));
+
+        table.set_null(&"user_id".into(), &[key(2)]).unwrap();
+        assert_eq!(insert(&mut table, 5, 20), Ok(()));
+        assert_eq!(
+            insert(&mut table, 6, 20),
+            Err(TableControllerError::UniqueViolation("user_id_key".into()))
+        );
+
+        table.remove_constraint(&"user_id_key".into());
+        assert_eq!(insert(&mut table, 6, 20), Ok(()));
+    }
+}
@@ -2,19 +2,44 @@
 //!
 //! [`controller::Table`]: Table
 
+mod delete;
 mod key_id;
 mod select;
-mod selector;
+pub mod selector;
+pub(crate) mod sort;
+mod statistics;
+mod unique;
 
 use std::sync::{Arc, Mutex};
 
-use common::structs::tree::object::tree::Tree as _;
+use common::structs::hash_table::{scalable::ScalableHashTable, HashTable};
+use derive_more::Display;
 
 use crate::{
-    controller::table::key_id::KeyId,
-    data::{data_storage::DataStorage, DataUnit},
+    controller::table::{key_id::KeyId, unique::UniqueIndex},
+    data::{
+        data_storage::DataStorage,
+        id::{NumericId, Sequence},
+        DataUnit,
+    },
+    expression::EvaluationError,
+    query::{AggregationError, JoinError},
     schema,
-    schema::{column, column::primary_key::PrimaryKey, table::Name},
+    schema::{
+        column,
+        column::{
+            default::DefaultFunction,
+            primary_key::{self, PrimaryKey},
+        },
+        r#type::{
+            r#enum::{StorageData, StorageDataType},
+            DataRow,
+        },
+        table::{
+            constraint::{self, Constraint, ForeignKey, OnDelete},
+            Name,
+        },
+    },
 };
 
 /// Controller for a single table.
@@ -23,9 +48,14 @@ use crate::{
 pub struct Table<const NODE_SIZE: u8> {
     /// Information about the table.
     info: schema::Table,
-    /// B-Tree to store primary key indexes.
-    //index: BTree<KeyId, NODE_SIZE>,
+    /// Primary keys and ids of the rows in the order they were added, scans
+    /// read the rows in this order.
     index: Vec<KeyId>,
+    /// Hashed primary key index that maps the keys to the ids of their
+    /// rows, so a row is found by its key without a scan.
+    keys: ScalableHashTable<primary_key::Data, NumericId>,
+    /// Hashed indexes of the `UNIQUE` constraints of the table.
+    unique: Vec<UniqueIndex>,
     /// Vector of page indexes that store the table's data.
     table_pages: Vec<usize>,
     /// The data storage to use.
@@ -47,8 +77,9 @@ impl<const NODE_SIZE: u8> Table<NODE_SIZE> {
     pub fn new(name: Name) -> Self {
         Table {
             info: schema::Table::new(name),
-            //index: BTree::default(),
             index: Vec::new(),
+            keys: ScalableHashTable::default(),
+            unique: Vec::new(),
             table_pages: Vec::new(),
             data_storage: Arc::new(Mutex::new(DataStorage::default())),
         }
@@ -61,18 +92,40 @@ impl<const NODE_SIZE: u8> Table<NODE_SIZE> {
         self.info.get_name()
     }
 
-    /// Adds a column to the table.
+    /// Adds a column to the table. A sequence is created for the column in
+    /// the table's schema if it is `AUTO_INCREMENT`. Rows already in the
+    /// table get the column's default value, so a `NOT NULL` column can only
+    /// be added to them with a default value.
     /// # Arguments
     /// * `name` - The name of the column.
     /// * `column` - The column to add.
-    pub fn add_column(&mut self, name: column::Name, column: schema::Column) {
+    /// # Returns
+    /// * `Result<(), TableControllerError>` - The result of the operation.
+    pub fn add_column(
+        &mut self,
+        name: column::Name,
+        column: schema::Column,
+    ) -> Result<(), TableControllerError> {
+        if column.is_not_null()
+            && column.get_default().is_none()
+            && !self.index.is_empty()
+        {
+            return Err(TableControllerError::NotNullWithoutDefault(name));
+        }
+
         {
             let mut data_storage = self.data_storage.lock().unwrap();
-            data_storage.append_data_type(column.get_type());
+            if column.is_not_null() {
+                data_storage.append_data_type(column.get_type());
+            } else {
+                data_storage.append_nullable_data_type(column.get_type());
+            }
+            let index = data_storage.get_data_type().len() - 1;
+            data_storage.set_default(index, column.get_default().cloned());
         }
         self.info.add_column(name, column);
 
-        //todo!("Add data update when new column added")
+        Ok(())
     }
 
     /// Sets the primary key of the table.
@@ -100,6 +153,242 @@ impl<const NODE_SIZE: u8> Table<NODE_SIZE> {
         self.info.get_primary_key()
     }
 
+    /// Adds a constraint to the table. Rows already in the table must
+    /// satisfy it, so a `FOREIGN KEY` can only be added to an empty table or
+    /// with [`Table::add_referencing_constraint`].
+    /// # Arguments
+    /// * `constraint` - The constraint to add.
+    /// # Returns
+    /// * `Result<(), TableControllerError>` - The result of the operation.
+    pub fn add_constraint(
+        &mut self,
+        constraint: Constraint,
+    ) -> Result<(), TableControllerError> {
+        self.add_referencing_constraint(constraint, &|_, _| Ok(false))
+    }
+
+    /// Adds a constraint to the table. Rows already in the table must
+    /// satisfy it.
+    /// # Arguments
+    /// * `constraint` - The constraint to add.
+    /// * `references` - The lookup of the values referenced by the
+    ///   `FOREIGN KEY` constraints of the table in other tables, including
+    ///   the added one.
+    /// # Returns
+    /// * `Result<(), TableControllerError>` - The result of the operation.
+    pub fn add_referencing_constraint(
+        &mut self,
+        constraint: Constraint,
+        references: &ReferenceLookup,
+    ) -> Result<(), TableControllerError> {
+        let name = constraint.get_name();
+        let exists = self
+            .info
+            .get_constraints()
+            .iter()
+            .any(|constraint| constraint.get_name() == name);
+        if exists {
+            return Err(TableControllerError::ConstraintAlreadyExists(
+                name.clone(),
+            ));
+        }
+        if !self.info.has_columns(&constraint.get_columns()) {
+            return Err(TableControllerError::ColumnDoesNotExist);
+        }
+        if let Constraint::Check(check) = &constraint {
+            let column_types = constraint
+                .get_columns()
+                .into_iter()
+                .map(|name| {
+                    let column = self
+                        .info
+                        .get_column(&name)
+                        .expect("column exists because checked");
+                    (name, column.get_type())
+                })
+                .collect::<Vec<_>>();
+            let data_type = check
+                .get_expression()
+                .data_type(|name| {
+                    column_types
+                        .iter()
+                        .find(|(column, _)| column == name)
+                        .map(|(_, data_type)| *data_type)
+                })
+                .map_err(TableControllerError::EvaluationError)?;
+            if !matches!(data_type, Some(StorageDataType::Bool) | None) {
+                return Err(TableControllerError::WrongTypeForConstraint(
+                    name.clone(),
+                ));
+            }
+        }
+        if let Constraint::ForeignKey(foreign_key) = &constraint {
+            let column = self
+                .info
+                .get_column(foreign_key.get_column())
+                .expect("column exists because checked");
+            if foreign_key.get_on_delete() == OnDelete::SetNull
+                && column.is_not_null()
+            {
+                return Err(TableControllerError::SetNullOnNotNullColumn(
+                    name.clone(),
+                ));
+            }
+        }
+
+        // Rows are checked against the added constraint only, as they
+        // satisfy the others. A `UNIQUE` constraint is checked by building
+        // its index.
+        let mut unique = match &constraint {
+            Constraint::Unique(unique) => Some(self.new_unique_index(unique)?),
+            _ => None,
+        };
+        for key_id in &self.index {
+            let row = { self.data_storage.lock().unwrap().get_data(key_id.id) }
+                .map_err(|_| TableControllerError::DataStorageError)?
+                .0;
+            let Some(index) = &mut unique else {
+                self.check_constraint(&constraint, &row, references)?;
+                continue;
+            };
+            let Some(unique_key) = index.key(&row) else {
+                continue;
+            };
+            if index.keys.get(&unique_key).is_some() {
+                return Err(TableControllerError::UniqueViolation(
+                    name.clone(),
+                ));
+            }
+            index.keys.insert(unique_key, key_id.key.clone());
+        }
+        self.info.add_constraint(constraint);
+        self.unique.extend(unique);
+
+        Ok(())
+    }
+
+    /// Removes the constraint with the given name from the table.
+    /// # Arguments
+    /// * `name` - The name of the constraint.
+    /// # Returns
+    /// * `Option<Constraint>` - The removed constraint.
+    pub fn remove_constraint(
+        &mut self,
+        name: &constraint::Name,
+    ) -> Option<Constraint> {
+        self.unique.retain(|index| &index.name != name);
+        self.info.remove_constraint(name)
+    }
+
+    /// Returns the `UNIQUE`, `CHECK` and `FOREIGN KEY` constraints of the
+    /// table.
+    /// # Returns
+    /// * `&Vec<Constraint>` - The constraints of the table.
+    pub fn get_constraints(&self) -> &Vec<Constraint> {
+        self.info.get_constraints()
+    }
+
+    /// Checks if a row satisfies the constraints of the table.
+    /// # Arguments
+    /// * `row` - The values of the row in the order of the table's columns.
+    /// * `references` - The lookup of the values referenced by the
+    ///   `FOREIGN KEY` constraints of the table in other tables.
+    /// # Returns
+    /// * `Result<(), TableControllerError>` - The violation of the first
+    ///   constraint the row doesn't satisfy.
+    fn check_constraints(
+        &self,
+        row: &[StorageData],
+        references: &ReferenceLookup,
+    ) -> Result<(), TableControllerError> {
+        for constraint in self.info.get_constraints() {
+            self.check_constraint(constraint, row, references)?;
+        }
+
+        Ok(())
+    }
+
+    /// Checks if a row that is not in the table yet satisfies a constraint.
+    /// `UNIQUE` constraints are checked by their indexes, and rows of the
+    /// table referenced by its own foreign keys are found by
+    /// [`Table::contains_value`].
+    /// # Arguments
+    /// * `constraint` - The constraint.
+    /// * `row` - The values of the row in the order of the table's columns.
+    /// * `references` - The lookup of the values referenced by the
+    ///   `FOREIGN KEY` constraints of the table in other tables.
+    /// # Returns
+    /// * `Result<(), TableControllerError>` - The violation of the
+    ///   constraint if the row doesn't satisfy it.
+    fn check_constraint(
+        &self,
+        constraint: &Constraint,
+        row: &[StorageData],
+        references: &ReferenceLookup,
+    ) -> Result<(), TableControllerError> {
+        let column_names = self.info.get_column_names();
+        let value = |name: &column::Name| {
+            column_names
+                .iter()
+                .position(|column| column == name)
+                .and_then(|position| row.get(position))
+        };
+
+        match constraint {
+            Constraint::Check(check) => {
+                let is_satisfied = check
+                    .is_satisfied(value)
+                    .map_err(TableControllerError::EvaluationError)?;
+                if !is_satisfied {
+                    return Err(TableControllerError::CheckViolation(
+                        check.get_name().clone(),
+                    ));
+                }
+            }
+            Constraint::Unique(unique) => {
+                let index = self
+                    .unique
+                    .iter()
+                    .find(|index| &index.name == unique.get_name());
+                let is_duplicate = index.is_some_and(|index| {
+                    index
+                        .key(row)
+                        .is_some_and(|key| index.keys.get(&key).is_some())
+                });
+                if is_duplicate {
+                    return Err(TableControllerError::UniqueViolation(
+                        unique.get_name().clone(),
+                    ));
+                }
+            }
+            Constraint::ForeignKey(foreign_key) => {
+                let value = value(foreign_key.get_column())
+                    .ok_or(TableControllerError::ColumnDoesNotExist)?;
+                if value == &StorageData::Null {
+                    return Ok(());
+                }
+                let is_self_referencing =
+                    foreign_key.get_table() == self.get_name();
+                let is_referenced =
+                    !is_self_referencing && references(foreign_key, value)?;
+                let is_self_referenced = is_self_referencing && {
+                    let referenced = foreign_key.get_referenced_column();
+                    let position = self
+                        .column_positions(std::slice::from_ref(referenced))?[0];
+                    &row[position] == value
+                        || self.contains_value(referenced, value)?
+                };
+                if !is_referenced && !is_self_referenced {
+                    return Err(TableControllerError::ForeignKeyViolation(
+                        foreign_key.get_name().clone(),
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     /// Returns the column with the given name.
     /// # Arguments
     /// * `name` - The name of the column.
@@ -112,46 +401,201 @@ impl<const NODE_SIZE: u8> Table<NODE_SIZE> {
         self.info.get_column(name)
     }
 
-    /// Adds a [`DataUnit`] to the table.
+    /// Returns the column names of the table in the order they were added.
+    /// # Returns
+    /// * `Vec<column::Name>` - The column names of the table.
+    pub fn get_column_names(&self) -> Vec<column::Name> {
+        self.info.get_column_names()
+    }
+
+    /// Returns the column types of the table in the order the columns were
+    /// added.
+    /// # Returns
+    /// * `Vec<StorageDataType>` - The column types of the table.
+    pub fn get_column_types(&self) -> Vec<StorageDataType> {
+        self.info.get_column_types()
+    }
+
+    /// Adds a [`DataUnit`] to the table. Values of the `FOREIGN KEY`
+    /// columns, except for `NULL`, must reference rows of the same table, use
+    /// [`Table::add_referencing_data`] to reference other tables.
     /// # Arguments
     /// * `data` - The data to add.
     /// # Returns
-    /// * `Result<(), TableControllerError>` - The result of the operation.
+    /// * `Result<Vec<primary_key::Data>, TableControllerError>` - The primary
+    ///   keys of the added rows, including the generated ones.
     pub fn add_data(
+        &mut self,
+        data: DataUnit,
+    ) -> Result<Vec<primary_key::Data>, TableControllerError> {
+        self.add_referencing_data(data, &|_, _| Ok(false))
+    }
+
+    /// Adds a [`DataUnit`] to the table.
+    ///
+    /// Rows are reordered to match the table's columns, and columns that are
+    /// not provided are filled with their default values. Default functions
+    /// are called for every row, so the primary key may be omitted if its
+    /// column has one, e.g. `gen_random_uuid()`, or is `AUTO_INCREMENT`.
+    /// Values provided for `AUTO_INCREMENT` columns advance their sequences,
+    /// so they are never generated later. If a row's primary key is already
+    /// in the table, or a row violates a constraint of the table, no rows
+    /// are added.
+    /// # Arguments
+    /// * `data` - The data to add.
+    /// * `references` - The lookup of the values referenced by the
+    ///   `FOREIGN KEY` constraints of the table in other tables.
+    /// # Returns
+    /// * `Result<Vec<primary_key::Data>, TableControllerError>` - The primary
+    ///   keys of the added rows, including the generated ones.
+    pub fn add_referencing_data(
         &mut self,
         mut data: DataUnit,
-    ) -> Result<(), TableControllerError> {
+        references: &ReferenceLookup,
+    ) -> Result<Vec<primary_key::Data>, TableControllerError> {
         let Some(primary_key) = self.get_primary_key() else {
             return Err(TableControllerError::PrimaryKeyDoesNotExist);
         };
+        let key_name = primary_key.get_column().clone();
 
-        let key_name = primary_key.get_column();
-        let key_index = if let Some(index) = data.get_index(key_name) {
-            index
-        } else {
-            return Err(TableControllerError::ColumnNotProvided);
-        };
+        if !self.info.has_columns(&data.get_names().to_vec()) {
+            return Err(TableControllerError::ColumnDoesNotExist);
+        }
 
-        for row in data.get_values() {
-            let key = row.0.get(key_index).expect("exists").clone();
+        let column_names = self.info.get_column_names();
+        let mut sources = Vec::with_capacity(column_names.len());
+        let mut observed = Vec::new();
+        for (column_index, name) in column_names.iter().enumerate() {
+            let sequence = self.info.get_mut_sequence(name).is_some();
+            let source = match data.get_index(name) {
+                Some(index) => {
+                    if sequence {
+                        observed.push((column_index, name));
+                    }
+                    ValueSource::Index(index)
+                }
+                None => {
+                    let column = self
+                        .info
+                        .get_column(name)
+                        .ok_or(TableControllerError::ColumnNotProvided)?;
+                    if sequence {
+                        ValueSource::Sequence(name.clone(), column.get_type())
+                    } else if let Some(function) = column.get_default_function()
+                    {
+                        ValueSource::Function(function)
+                    } else if let Some(value) = column.get_default() {
+                        ValueSource::Default(value.clone())
+                    } else {
+                        return Err(TableControllerError::ColumnNotProvided);
+                    }
+                }
+            };
+            sources.push(source);
+        }
+        let key_index = column_names
+            .iter()
+            .position(|name| name == &key_name)
+            .expect("primary key column exists");
 
-            let id = {
-                let mut data_storage = self.data_storage.lock().unwrap();
-                let Ok(id) = data_storage.add_data(row) else {
-                    return Err(TableControllerError::DataStorageError);
+        let mut rows = Vec::with_capacity(data.len());
+        for row in data.get_values() {
+            let mut values = Vec::with_capacity(sources.len());
+            for source in &sources {
+                let value = match source {
+                    ValueSource::Index(index) => row
+                        .0
+                        .get(*index)
+                        .cloned()
+                        .ok_or(TableControllerError::ColumnNotProvided)?,
+                    ValueSource::Default(value) => value.clone(),
+                    ValueSource::Function(function) => function.call(),
+                    ValueSource::Sequence(name, data_type) => self
+                        .info
+                        .get_mut_sequence(name)
+                        .and_then(Sequence::next_value)
+                        .and_then(|value| data_type.integer_value(value))
+                        .ok_or(TableControllerError::SequenceExhausted)?,
                 };
-                id
-            };
+                values.push(value);
+            }
+            for (column_index, name) in &observed {
+                if let (Some(value), Some(sequence)) = (
+                    values[*column_index].as_integer(),
+                    self.info.get_mut_sequence(name),
+                ) {
+                    sequence.observe(value);
+                }
+            }
+            rows.push(values.into());
+        }
+
+        let mut keys = Vec::with_capacity(rows.len());
+        for row in rows {
+            match self.add_row(row, key_index, references) {
+                Ok(key) => keys.push(key),
+                Err(error) => {
+                    // The rows are added all or none, so the rows added
+                    // before are removed.
+                    self.remove_data(&keys)?;
+                    return Err(error);
+                }
+            }
+        }
 
-            let key_id = KeyId {
-                id,
-                key: key.try_into().unwrap(),
+        Ok(keys)
+    }
+
+    /// Adds a row whose values are in the order of the table's columns.
+    /// # Arguments
+    /// * `row` - The row to add.
+    /// * `key_index` - The index of the primary key column.
+    /// * `references` - The lookup of the values referenced by the
+    ///   `FOREIGN KEY` constraints of the table in other tables.
+    /// # Returns
+    /// * `Result<primary_key::Data, TableControllerError>` - The primary key
+    ///   of the added row.
+    fn add_row(
+        &mut self,
+        mut row: DataRow,
+        key_index: usize,
+        references: &ReferenceLookup,
+    ) -> Result<primary_key::Data, TableControllerError> {
+        self.data_storage.lock().unwrap().fit_row(&mut row.0);
+        let key = match row.0[key_index].clone() {
+            StorageData::Null => {
+                return Err(TableControllerError::PrimaryKeyIsNull)
+            }
+            key => key
+                .try_into()
+                .map_err(|_| TableControllerError::DataStorageError)?,
+        };
+        if self.keys.get(&key).is_some() {
+            return Err(TableControllerError::DuplicatePrimaryKey);
+        }
+        self.check_constraints(&row.0, references)?;
+        let unique_row = (!self.unique.is_empty()).then(|| row.0.clone());
+
+        let id = {
+            let mut data_storage = self.data_storage.lock().unwrap();
+            let Ok(id) = data_storage.add_data(row) else {
+                return Err(TableControllerError::DataStorageError);
             };
+            id
+        };
 
-            self.index.push(key_id);
+        if let Some(row) = unique_row {
+            for index in &mut self.unique {
+                index.insert(&row, &key);
+            }
         }
+        self.keys.insert(key.clone(), id);
+        self.index.push(KeyId {
+            id,
+            key: key.clone(),
+        });
 
-        Ok(())
+        Ok(key)
     }
 
     /// Adds a page to the table.
@@ -167,17 +611,104 @@ impl<const NODE_SIZE: u8> Table<NODE_SIZE> {
     pub fn get_pages(&self) -> &Vec<usize> {
         &self.table_pages
     }
+
+    /// Checks the checksums of the pages that store the table's data.
+    /// # Returns
+    /// * `Vec<usize>` - Indexes of the corrupted pages.
+    pub fn verify_pages(&self) -> Vec<usize> {
+        self.data_storage.lock().unwrap().verify_pages()
+    }
+
+    /// Corrupts the page with the given index of the table's data.
+    #[cfg(test)]
+    pub(crate) fn corrupt_page(&mut self, index: usize) {
+        self.data_storage.lock().unwrap().corrupt_page(index);
+    }
 }
 
-#[derive(Debug, PartialEq)]
+/// Lookup of a value of a `FOREIGN KEY` column in the table the
+/// constraint references, it returns whether a row of that table has the
+/// value, e.g. by [`Table::contains_value`].
+pub type ReferenceLookup<'a> = dyn 'a
+    + Fn(&ForeignKey, &StorageData) -> Result<bool, TableControllerError>;
+
+/// Source of a column value of an inserted row.
+enum ValueSource {
+    /// Value at the index of the provided row.
+    Index(usize),
+
+    /// Default value of the column.
+    Default(StorageData),
+
+    /// Function that computes the default value of the column.
+    Function(DefaultFunction),
+
+    /// Name of an `AUTO_INCREMENT` column, whose sequence generates the
+    /// value, and the column's type.
+    Sequence(column::Name, StorageDataType),
+}
+
+#[derive(Debug, Display, PartialEq)]
 pub enum TableControllerError {
+    #[display(fmt = "Value of a column without a default is not provided")]
     ColumnNotProvided,
+    #[display(fmt = "Column does not exist")]
     ColumnDoesNotExist,
+    #[display(
+        fmt = "Column `{}` can't be NOT NULL without a default value, as \
+               the table has rows",
+        _0
+    )]
+    NotNullWithoutDefault(column::Name),
+    #[display(fmt = "Column type can't be used for the primary key")]
     WrongTypeForPrimaryKey,
+    #[display(fmt = "Table has no primary key")]
     PrimaryKeyDoesNotExist,
+    #[display(fmt = "Primary key can't be NULL")]
+    PrimaryKeyIsNull,
+    #[display(fmt = "Duplicate primary key")]
+    DuplicatePrimaryKey,
+    #[display(fmt = "Sequence of an `AUTO_INCREMENT` column is exhausted")]
+    SequenceExhausted,
+    #[display(fmt = "Constraint `{}` already exists", _0)]
+    ConstraintAlreadyExists(constraint::Name),
+    #[display(fmt = "Constraint `{}` compares values of another type", _0)]
+    WrongTypeForConstraint(constraint::Name),
+    #[display(fmt = "Duplicate value violates unique constraint `{}`", _0)]
+    UniqueViolation(constraint::Name),
+    #[display(fmt = "Row violates check constraint `{}`", _0)]
+    CheckViolation(constraint::Name),
+    #[display(fmt = "Row violates foreign key constraint `{}`", _0)]
+    ForeignKeyViolation(constraint::Name),
+    #[display(
+        fmt = "Foreign key `{}` can't set a NOT NULL column to NULL",
+        _0
+    )]
+    SetNullOnNotNullColumn(constraint::Name),
+    #[display(fmt = "Values of column `{}` are of different types", _0)]
+    MixedColumnTypes(column::Name),
+    #[display(fmt = "{}", _0)]
+    EvaluationError(EvaluationError),
+    #[display(fmt = "{}", _0)]
+    AggregationError(AggregationError),
+    #[display(fmt = "{}", _0)]
+    JoinError(JoinError),
+    #[display(fmt = "Data storage error")]
     DataStorageError,
 }
 
+impl From<AggregationError> for TableControllerError {
+    fn from(error: AggregationError) -> Self {
+        TableControllerError::AggregationError(error)
+    }
+}
+
+impl From<JoinError> for TableControllerError {
+    fn from(error: JoinError) -> Self {
+        TableControllerError::JoinError(error)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use common::structs::tree::object::tree::Tree as _;
@@ -188,11 +719,17 @@ mod tests {
             table::{KeyId, Table, TableControllerError},
         },
         data::{data_storage::DataStorage, id, id::NumericId, DataUnit},
+        expression::{BinaryOperator, EvaluationError, Expression},
         page::page_controller::PageController,
         schema,
         schema::{
-            column::primary_key,
-            r#type::r#enum::{StorageData, StorageDataType},
+            column::{default::DefaultFunction, primary_key},
+            r#type::{
+                data_types::{Decimal, Uuid, VarChar},
+                r#enum::{StorageData, StorageDataType},
+                DataRow,
+            },
+            table::constraint::{Check, Constraint, Unique},
         },
     };
     use std::sync::{Arc, Mutex};
@@ -209,10 +746,12 @@ mod tests {
     fn test_add_data() {
         let name: table::Name = "table".into();
         let mut table = Table::<16>::new(name.clone());
-        table.add_column(
-            "id".into(),
-            schema::Column::new(StorageDataType::Integer),
-        );
+        table
+            .add_column(
+                "id".into(),
+                schema::Column::new(StorageDataType::Integer),
+            )
+            .unwrap();
 
         let primary_key =
             primary_key::PrimaryKey::new("pk".into(), "id".into());
@@ -230,14 +769,351 @@ mod tests {
         assert_eq!(index.len(), 1);
     }
 
+    #[test]
+    fn test_add_data_decimal_key() {
+        let mut table = Table::<16>::new("table".into());
+        table
+            .add_column(
+                "price".into(),
+                schema::Column::new(StorageDataType::Decimal(6, 2)),
+            )
+            .unwrap();
+        table
+            .set_primary_key(primary_key::PrimaryKey::new(
+                "pk".into(),
+                "price".into(),
+            ))
+            .expect("Failed to set primary key");
+
+        let decimal = |value: &str| value.parse::<Decimal>().unwrap();
+        let mut data = DataUnit::new(vec!["price".into()]);
+        data.insert(vec![decimal("10.005").into()].into());
+        data.insert(vec![decimal("-2").into()].into());
+        table.add_data(data).unwrap();
+
+        let keys = table
+            .index
+            .iter()
+            .map(|key_id| key_id.key.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            keys,
+            vec![
+                primary_key::Data::Decimal(decimal("10.01")),
+                primary_key::Data::Decimal(decimal("-2.00")),
+            ]
+        );
+        assert!(keys[1] < keys[0]);
+
+        let mut data = DataUnit::new(vec!["price".into()]);
+        data.insert(vec![decimal("10000").into()].into());
+        assert_eq!(
+            table.add_data(data),
+            Err(TableControllerError::DataStorageError)
+        );
+
+        let mut data = DataUnit::new(vec!["price".into()]);
+        data.insert(vec![decimal("10.0100").into()].into());
+        assert_eq!(
+            table.add_data(data),
+            Err(TableControllerError::DuplicatePrimaryKey)
+        );
+        assert!(table
+            .get_row_by_key(&decimal("-2.0").into())
+            .unwrap()
+            .is_some());
+    }
+
+    #[test]
+    fn test_add_data_uuid_key() {
+        let mut table = Table::<16>::new("table".into());
+        let mut column = schema::Column::new(StorageDataType::Uuid);
+        column.set_not_null(true);
+        column.set_default_function(Some(DefaultFunction::GenRandomUuid));
+        table.add_column("id".into(), column).unwrap();
+        table
+            .add_column(
+                "value".into(),
+                schema::Column::new(StorageDataType::Integer),
+            )
+            .unwrap();
+        table
+            .set_primary_key(primary_key::PrimaryKey::new(
+                "pk".into(),
+                "id".into(),
+            ))
+            .expect("Failed to set primary key");
+
+        let uuid = "67e55044-10b1-426f-9247-bb680e5fe0c8"
+            .parse::<Uuid>()
+            .unwrap();
+        let mut data = DataUnit::new(vec!["id".into(), "value".into()]);
+        data.insert(vec![uuid.into(), StorageData::Integer(0.into())].into());
+        table.add_data(data).unwrap();
+
+        let mut data = DataUnit::new(vec!["value".into()]);
+        data.insert(vec![StorageData::Integer(1.into())].into());
+        data.insert(vec![StorageData::Integer(2.into())].into());
+        table.add_data(data).unwrap();
+
+        let keys = table
+            .index
+            .iter()
+            .map(|key_id| key_id.key.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(keys.len(), 3);
+        assert_eq!(keys[0], primary_key::Data::Uuid(uuid));
+        assert!(matches!(keys[1], primary_key::Data::Uuid(_)));
+        assert_ne!(keys[1], keys[2]);
+    }
+
+    #[test]
+    fn test_add_data_varchar_key() {
+        let mut table = Table::<16>::new("table".into());
+        table
+            .add_column(
+                "sku".into(),
+                schema::Column::new(StorageDataType::VarChar(16)),
+            )
+            .unwrap();
+        table
+            .set_primary_key(primary_key::PrimaryKey::new(
+                "pk".into(),
+                "sku".into(),
+            ))
+            .expect("Failed to set primary key");
+
+        let sku = |value: &str| -> StorageData {
+            VarChar::new(value.to_string()).unwrap().into()
+        };
+        let mut data = DataUnit::new(vec!["sku".into()]);
+        data.insert(vec![sku("sku-10")].into());
+        data.insert(vec![sku("sku-1")].into());
+        data.insert(vec![sku("SKU-1")].into());
+        table.add_data(data).unwrap();
+
+        let mut keys = table
+            .index
+            .iter()
+            .map(|key_id| key_id.key.clone())
+            .collect::<Vec<_>>();
+        keys.sort();
+        assert_eq!(
+            keys,
+            ["SKU-1", "sku-1", "sku-10"]
+                .map(|value| sku(value).try_into().unwrap())
+                .to_vec()
+        );
+
+        let mut data = DataUnit::new(vec!["sku".into()]);
+        data.insert(vec![sku("sku-2")].into());
+        data.insert(vec![sku("sku-1")].into());
+        assert_eq!(
+            table.add_data(data),
+            Err(TableControllerError::DuplicatePrimaryKey)
+        );
+        assert_eq!(table.index.len(), 3);
+        let key: primary_key::Data = sku("sku-2").try_into().unwrap();
+        assert!(table.index.iter().all(|key_id| key_id.key != key));
+    }
+
+    #[test]
+    fn test_add_data_auto_increment() {
+        let mut table = Table::<16>::new("table".into());
+        let mut column = schema::Column::new(StorageDataType::Byte);
+        assert!(column.set_auto_increment(true));
+        table.add_column("id".into(), column).unwrap();
+        table
+            .add_column(
+                "value".into(),
+                schema::Column::new(StorageDataType::Integer),
+            )
+            .unwrap();
+        table
+            .set_primary_key(primary_key::PrimaryKey::new(
+                "pk".into(),
+                "id".into(),
+            ))
+            .expect("Failed to set primary key");
+
+        let mut data = DataUnit::new(vec!["value".into()]);
+        data.insert(vec![StorageData::Integer(0.into())].into());
+        data.insert(vec![StorageData::Integer(1.into())].into());
+        assert_eq!(
+            table.add_data(data),
+            Ok(vec![
+                primary_key::Data::Byte(1.into()),
+                primary_key::Data::Byte(2.into()),
+            ])
+        );
+
+        let mut data = DataUnit::new(vec!["id".into(), "value".into()]);
+        data.insert(
+            vec![
+                StorageData::Byte(254.into()),
+                StorageData::Integer(2.into()),
+            ]
+            .into(),
+        );
+        table.add_data(data).unwrap();
+
+        let mut data = DataUnit::new(vec!["value".into()]);
+        data.insert(vec![StorageData::Integer(3.into())].into());
+        data.insert(vec![StorageData::Integer(4.into())].into());
+        assert_eq!(
+            table.add_data(data),
+            Err(TableControllerError::SequenceExhausted)
+        );
+        assert_eq!(
+            table.index.last().map(|key_id| key_id.key.clone()),
+            Some(primary_key::Data::Byte(254.into()))
+        );
+    }
+
+    #[test]
+    fn test_add_data_constraints() {
+        let mut table = Table::<16>::new("table".into());
+        table
+            .add_column(
+                "id".into(),
+                schema::Column::new(StorageDataType::Integer),
+            )
+            .unwrap();
+        table
+            .add_column(
+                "email".into(),
+                schema::Column::new(StorageDataType::VarChar(16)),
+            )
+            .unwrap();
+        table
+            .add_column(
+                "age".into(),
+                schema::Column::new(StorageDataType::Byte),
+            )
+            .unwrap();
+        table
+            .set_primary_key(primary_key::PrimaryKey::new(
+                "pk".into(),
+                "id".into(),
+            ))
+            .expect("Failed to set primary key");
+        table
+            .add_constraint(Constraint::Unique(Unique::new(
+                "email_key".into(),
+                vec!["email".into()],
+            )))
+            .unwrap();
+        let check = Check::new(
+            "age_check".into(),
+            Expression::binary(
+                Expression::Column("age".into()),
+                BinaryOperator::GreaterThanOrEqual,
+                Expression::Literal(18u8.into()),
+            ),
+        );
+        table
+            .add_constraint(Constraint::Check(check.clone()))
+            .unwrap();
+        assert_eq!(
+            table.add_constraint(Constraint::Check(check)),
+            Err(TableControllerError::ConstraintAlreadyExists(
+                "age_check".into()
+            ))
+        );
+
+        let row = |id: i32, email: Option<&str>, age: u8| -> DataRow {
+            let email = email.map_or(StorageData::Null, |email| {
+                VarChar::new(email.to_string()).unwrap().into()
+            });
+            vec![id.into(), email, age.into()].into()
+        };
+        let columns = vec!["id".into(), "email".into(), "age".into()];
+
+        let mut data = DataUnit::new(columns.clone());
+        data.insert(row(1, Some("a@b.c"), 20));
+        data.insert(row(2, None, 30));
+        data.insert(row(3, None, 20));
+        assert!(table.add_data(data).is_ok());
+
+        let mut data = DataUnit::new(columns.clone());
+        data.insert(row(4, Some("d@e.f"), 20));
+        data.insert(row(5, Some("d@e.f"), 20));
+        let err = table.add_data(data).unwrap_err();
+        assert_eq!(
+            err,
+            TableControllerError::UniqueViolation("email_key".into())
+        );
+        assert_eq!(
+            err.to_string(),
+            "Duplicate value violates unique constraint `email_key`"
+        );
+
+        let mut data = DataUnit::new(columns.clone());
+        data.insert(row(6, Some("g@h.i"), 17));
+        assert_eq!(
+            table.add_data(data),
+            Err(TableControllerError::CheckViolation("age_check".into()))
+        );
+        assert_eq!(table.index.len(), 3);
+
+        // The rows of a rejected batch are removed from the unique indexes.
+        let mut data = DataUnit::new(columns);
+        data.insert(row(4, Some("d@e.f"), 20));
+        assert!(table.add_data(data).is_ok());
+
+        assert_eq!(
+            table.add_constraint(Constraint::Unique(Unique::new(
+                "age_key".into(),
+                vec!["age".into()],
+            ))),
+            Err(TableControllerError::UniqueViolation("age_key".into()))
+        );
+        assert_eq!(table.get_constraints().len(), 2);
+
+        let check = Check::new(
+            "email_check".into(),
+            Expression::binary(
+                Expression::Column("email".into()),
+                BinaryOperator::NotEqual,
+                Expression::Literal(1.into()),
+            ),
+        );
+        assert_eq!(
+            table.add_constraint(Constraint::Check(check)),
+            Err(TableControllerError::EvaluationError(
+                EvaluationError::UndefinedBinaryOperator(
+                    BinaryOperator::NotEqual,
+                    StorageDataType::VarChar(16),
+                    StorageDataType::Integer
+                )
+            ))
+        );
+        let check = Check::new(
+            "age_check2".into(),
+            Expression::binary(
+                Expression::Column("age".into()),
+                BinaryOperator::Add,
+                Expression::Literal(1.into()),
+            ),
+        );
+        assert_eq!(
+            table.add_constraint(Constraint::Check(check)),
+            Err(TableControllerError::WrongTypeForConstraint(
+                "age_check2".into()
+            ))
+        );
+    }
+
     #[test]
     fn test_add_data_multiple() {
         let name: table::Name = "table".into();
         let mut table = Table::<16>::new(name.clone());
-        table.add_column(
-            "id".into(),
-            schema::Column::new(StorageDataType::Integer),
-        );
+        table
+            .add_column(
+                "id".into(),
+                schema::Column::new(StorageDataType::Integer),
+            )
+            .unwrap();
 
         let primary_key =
             primary_key::PrimaryKey::new("pk".into(), "id".into());
@@ -275,10 +1151,12 @@ mod tests {
     fn test_set_primary_key() {
         let name: table::Name = "table".into();
         let mut table = Table::<16>::new(name.clone());
-        table.add_column(
-            "id".into(),
-            schema::Column::new(StorageDataType::Integer),
-        );
+        table
+            .add_column(
+                "id".into(),
+                schema::Column::new(StorageDataType::Integer),
+            )
+            .unwrap();
 
         let primary_key =
             primary_key::PrimaryKey::new("pk".into(), "id".into());
@@ -292,10 +1170,12 @@ mod tests {
     fn test_set_primary_key_wrong_type() {
         let name: table::Name = "table".into();
         let mut table = Table::<16>::new(name.clone());
-        table.add_column(
-            "id".into(),
-            schema::Column::new(StorageDataType::VarChar(40)),
-        );
+        table
+            .add_column(
+                "id".into(),
+                schema::Column::new(StorageDataType::Double),
+            )
+            .unwrap();
 
         let primary_key =
             primary_key::PrimaryKey::new("pk".into(), "id".into());
@@ -322,7 +1202,7 @@ mod tests {
         let mut table = Table::<16>::new(name.clone());
 
         let column = schema::Column::new(StorageDataType::Integer);
-        table.add_column("column".into(), column.clone());
+        table.add_column("column".into(), column.clone()).unwrap();
 
         assert_eq!(table.get_column(&"column".into()), Some(column));
     }
@@ -333,7 +1213,7 @@ mod tests {
         let mut table = Table::<16>::new(name.clone());
 
         let column = schema::Column::new(StorageDataType::Integer);
-        table.add_column("column".into(), column.clone());
+        table.add_column("column".into(), column.clone()).unwrap();
 
         assert_eq!(table.get_column(&"column".into()), Some(column));
 
@@ -352,10 +1232,10 @@ mod tests {
         let mut table = Table::<16>::new(name.clone());
 
         let column = schema::Column::new(StorageDataType::Integer);
-        table.add_column("column".into(), column.clone());
+        table.add_column("column".into(), column.clone()).unwrap();
 
         let column = schema::Column::new(StorageDataType::Integer);
-        table.add_column("column2".into(), column.clone());
+        table.add_column("column2".into(), column.clone()).unwrap();
 
         assert_eq!(
             table.get_column(&"column".into()),
@@ -367,6 +1247,47 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_add_column_not_null_with_rows() {
+        let mut table = Table::<16>::new("table".into());
+        table
+            .add_column(
+                "id".into(),
+                schema::Column::new(StorageDataType::Integer),
+            )
+            .unwrap();
+
+        let mut column = schema::Column::new(StorageDataType::Integer);
+        column.set_not_null(true);
+        table.add_column("age".into(), column.clone()).unwrap();
+        table
+            .set_primary_key(primary_key::PrimaryKey::new(
+                "pk".into(),
+                "id".into(),
+            ))
+            .expect("Failed to set primary key");
+
+        let mut data = DataUnit::new(vec!["id".into(), "age".into()]);
+        data.insert(
+            vec![
+                StorageData::Integer(0.into()),
+                StorageData::Integer(18.into()),
+            ]
+            .into(),
+        );
+        table.add_data(data).unwrap();
+
+        assert_eq!(
+            table.add_column("score".into(), column.clone()),
+            Err(TableControllerError::NotNullWithoutDefault("score".into()))
+        );
+        assert_eq!(table.get_column(&"score".into()), None);
+
+        column.set_default(Some(StorageData::Integer(0.into())));
+        table.add_column("score".into(), column.clone()).unwrap();
+        assert_eq!(table.get_column(&"score".into()), Some(column));
+    }
+
     #[test]
     fn test_add_page() {
         let name: table::Name = "table".into();
@@ -376,4 +1297,113 @@ mod tests {
 
         assert_eq!(table.table_pages.len(), 1);
     }
+
+    #[test]
+    fn test_add_data_reorders_columns() {
+        let name: table::Name = "table".into();
+        let mut table = Table::<16>::new(name.clone());
+        table
+            .add_column(
+                "id".into(),
+                schema::Column::new(StorageDataType::Integer),
+            )
+            .unwrap();
+        let mut column = schema::Column::new(StorageDataType::Byte);
+        column.set_default(Some(StorageData::Byte(7.into())));
+        table.add_column("flag".into(), column).unwrap();
+        table
+            .add_column(
+                "value".into(),
+                schema::Column::new(StorageDataType::Integer),
+            )
+            .unwrap();
+        table
+            .set_primary_key(primary_key::PrimaryKey::new(
+                "pk".into(),
+                "id".into(),
+            ))
+            .expect("Failed to set primary key");
+
+        let mut data = DataUnit::new(vec!["value".into(), "id".into()]);
+        data.insert(
+            vec![
+                StorageData::Integer(5.into()),
+                StorageData::Integer(1.into()),
+            ]
+            .into(),
+        );
+        assert!(table.add_data(data).is_ok());
+
+        let id = table.index[0].id;
+        let row = table.data_storage.lock().unwrap().get_data(id).unwrap();
+        assert_eq!(
+            row.0,
+            vec![
+                StorageData::Integer(1.into()),
+                StorageData::Byte(7.into()),
+                StorageData::Integer(5.into()),
+            ]
+        );
+
+        let mut data = DataUnit::new(vec!["id".into(), "flag".into()]);
+        data.insert(
+            vec![StorageData::Integer(2.into()), StorageData::Byte(0.into())]
+                .into(),
+        );
+        assert_eq!(
+            table.add_data(data),
+            Err(TableControllerError::ColumnNotProvided)
+        );
+
+        let mut data = DataUnit::new(vec!["id".into(), "missing".into()]);
+        data.insert(
+            vec![StorageData::Integer(2.into()), StorageData::Byte(0.into())]
+                .into(),
+        );
+        assert_eq!(
+            table.add_data(data),
+            Err(TableControllerError::ColumnDoesNotExist)
+        );
+    }
+
+    #[test]
+    fn test_add_data_nullable() {
+        let name: table::Name = "table".into();
+        let mut table = Table::<16>::new(name.clone());
+        let mut column = schema::Column::new(StorageDataType::Integer);
+        column.set_not_null(true);
+        table.add_column("id".into(), column).unwrap();
+        table
+            .add_column(
+                "value".into(),
+                schema::Column::new(StorageDataType::Integer),
+            )
+            .unwrap();
+        table
+            .set_primary_key(primary_key::PrimaryKey::new(
+                "pk".into(),
+                "id".into(),
+            ))
+            .expect("Failed to set primary key");
+
+        let mut data = DataUnit::new(vec!["id".into(), "value".into()]);
+        data.insert(
+            vec![StorageData::Integer(1.into()), StorageData::Null].into(),
+        );
+        assert!(table.add_data(data).is_ok());
+
+        let id = table.index[0].id;
+        let row = table.data_storage.lock().unwrap().get_data(id).unwrap();
+        assert_eq!(
+            row.0,
+            vec![StorageData::Integer(1.into()), StorageData::Null]
+        );
+
+        let mut data = DataUnit::new(vec!["id".into(), "value".into()]);
+        data.insert(vec![StorageData::Null, StorageData::Null].into());
+        assert_eq!(
+            table.add_data(data),
+            Err(TableControllerError::PrimaryKeyIsNull)
+        );
+    }
 }
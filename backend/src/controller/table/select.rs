@@ -1,59 +1,365 @@
+use common::structs::hash_table::HashTable;
+
 use crate::{
     controller::{
-        table::{selector::DataSelector, TableControllerError},
+        table::{
+            selector::{DataSelector, FilterType, SelectorFilter},
+            TableControllerError,
+        },
         Table,
     },
     data::DataUnit,
-    schema::column,
+    expression,
+    expression::Expression,
+    query::{
+        cost,
+        cost::AccessPath,
+        executor,
+        executor::{Aggregate, Filter, IndexScan, Operator, Scan},
+        key::{normalize, HashKey},
+        Aggregation,
+    },
+    schema::{
+        column,
+        column::primary_key,
+        r#type::{r#enum::StorageData, DataRow},
+    },
 };
 
 impl<const NODE_SIZE: u8> Table<NODE_SIZE> {
-    fn select_without_filters(
+    /// Returns the positions of the columns in the table's rows.
+    /// # Arguments
+    /// * `names` - The names of the columns.
+    /// # Returns
+    /// * `Result<Vec<usize>, TableControllerError>` - The positions or
+    ///   [`TableControllerError::ColumnDoesNotExist`].
+    pub(super) fn column_positions(
+        &self,
+        names: &[column::Name],
+    ) -> Result<Vec<usize>, TableControllerError> {
+        positions(&self.info.get_column_names(), names)
+    }
+
+    /// Gets rows by [`DataSelector`] from the table. The rows are read by
+    /// the [`AccessPath`] chosen by [`Table::get_access_path`] and checked
+    /// against the condition, the rest of the selector is applied by
+    /// [`executor::select`].
+    /// # Arguments
+    /// * `selector` - The selector to use.
+    /// # Returns
+    /// * `Result<DataUnit, TableControllerError>` - The result of the
+    ///   operation.
+    pub fn get_data(
         &mut self,
-        rows: Option<Vec<column::Name>>,
+        mut selector: DataSelector,
     ) -> Result<DataUnit, TableControllerError> {
-        if rows.is_some() {
-            todo!()
-        } else {
-            let mut data_unit = DataUnit::new(self.info.get_column_names());
-            for id in self.index.iter().map(|v| v.id) {
-                let row = { self.data_storage.lock().unwrap().get_data(id) }
-                    .map_err(|_| TableControllerError::DataStorageError)?;
-
-                data_unit.insert(row);
+        let filters = selector.filters.take().unwrap_or_default();
+        let scan: Box<dyn Operator + '_> = match self.get_access_path(&filters)
+        {
+            AccessPath::IndexScan(key) => {
+                Box::new(IndexScan::new(self, vec![key], filters)?)
             }
+            AccessPath::Scan => Box::new(Scan::new(self, filters)?),
+        };
+        let rows = with_condition(scan, selector.condition.take())?;
 
-            Ok(data_unit)
-        }
+        executor::select(rows, selector)?.collect()
     }
 
-    /// Gets rows by [`DataSelector`] from the table.
+    /// Gets rows of the table aggregated by [`Aggregation`]. The filters and
+    /// the condition of the selector are applied to the rows of the table,
+    /// its columns, `ORDER BY` items, `OFFSET` and `LIMIT` to the aggregated
+    /// rows, whose columns are named by [`Aggregation::column_names`].
     /// # Arguments
     /// * `selector` - The selector to use.
+    /// * `aggregation` - The aggregation to use.
     /// # Returns
     /// * `Result<DataUnit, TableControllerError>` - The result of the
     ///   operation.
-    pub fn get_data(
+    pub fn get_aggregated_data(
         &mut self,
-        selector: DataSelector,
+        mut selector: DataSelector,
+        aggregation: &Aggregation,
     ) -> Result<DataUnit, TableControllerError> {
-        if selector.filters.is_none() {
-            return self.select_without_filters(selector.row_names);
+        let filters = selector.filters.take().unwrap_or_default();
+        let scan = Box::new(Scan::new(self, filters)?);
+        let rows = with_condition(scan, selector.condition.take())?;
+        let aggregate = Box::new(Aggregate::new(rows, aggregation.clone()));
+
+        executor::select(aggregate, selector)?.collect()
+    }
+
+    /// Chooses the cheapest way to read the rows that pass all filters by
+    /// [`cost`]: an [`AccessPath::IndexScan`] is possible if a filter
+    /// compares the primary key for equality, an [`AccessPath::Scan`]
+    /// always.
+    /// # Arguments
+    /// * `filters` - The filters.
+    /// # Returns
+    /// * `AccessPath` - The access path.
+    pub fn get_access_path(&self, filters: &[SelectorFilter]) -> AccessPath {
+        let table_rows = self.index.len() as f64;
+        let rows = self.estimate_rows(filters);
+        let scan = cost::scan_cost(table_rows, filters.len(), rows);
+
+        match self.key_filter(filters) {
+            Some(key) if cost::index_scan_cost(1.0, filters.len()) < scan => {
+                AccessPath::IndexScan(key)
+            }
+            _ => AccessPath::Scan,
         }
+    }
 
-        todo!()
+    /// Returns the value of the first filter that compares the primary key
+    /// for equality, such filter selects at most one row.
+    fn key_filter(&self, filters: &[SelectorFilter]) -> Option<StorageData> {
+        let primary_key = self.info.get_primary_key().as_ref()?;
+        filters
+            .iter()
+            .find(|filter| {
+                filter.filter_type == FilterType::Equal
+                    && filter.column_name == *primary_key.get_column()
+            })
+            .map(|filter| filter.value.clone())
+    }
+
+    /// Returns the primary keys of the rows that pass all filters.
+    /// # Arguments
+    /// * `filters` - The filters to apply.
+    /// # Returns
+    /// * `Result<Vec<primary_key::Data>, TableControllerError>` - The primary
+    ///   keys in the order of the table's index.
+    pub fn get_keys(
+        &self,
+        filters: &[SelectorFilter],
+    ) -> Result<Vec<primary_key::Data>, TableControllerError> {
+        let filter_names = filters
+            .iter()
+            .map(|filter| filter.column_name.clone())
+            .collect::<Vec<_>>();
+        let filter_positions = self.column_positions(&filter_names)?;
+
+        let mut keys = Vec::new();
+        for key_id in &self.index {
+            let row = { self.data_storage.lock().unwrap().get_data(key_id.id) }
+                .map_err(|_| TableControllerError::DataStorageError)?;
+
+            let matches = filters
+                .iter()
+                .zip(filter_positions.iter())
+                .all(|(filter, position)| filter.matches(&row.0[*position]));
+            if matches {
+                keys.push(key_id.key.clone());
+            }
+        }
+
+        Ok(keys)
+    }
+
+    /// Returns the values of a column with the primary keys of their rows.
+    /// # Arguments
+    /// * `name` - The name of the column.
+    /// # Returns
+    /// * `Result<Vec<(primary_key::Data, StorageData)>, TableControllerError>`
+    ///   - The primary keys and values in the order of the table's index.
+    pub fn get_keyed_values(
+        &self,
+        name: &column::Name,
+    ) -> Result<Vec<(primary_key::Data, StorageData)>, TableControllerError>
+    {
+        let position = self.column_positions(std::slice::from_ref(name))?[0];
+
+        let mut values = Vec::with_capacity(self.index.len());
+        for key_id in &self.index {
+            let mut row =
+                { self.data_storage.lock().unwrap().get_data(key_id.id) }
+                    .map_err(|_| TableControllerError::DataStorageError)?;
+            values.push((key_id.key.clone(), row.0.swap_remove(position)));
+        }
+
+        Ok(values)
+    }
+
+    /// Returns the values of a column of the rows with the given primary
+    /// keys, found by the hashed primary key index. Keys that are not in
+    /// the table are skipped.
+    /// # Arguments
+    /// * `name` - The name of the column.
+    /// * `keys` - The primary keys of the rows.
+    /// # Returns
+    /// * `Result<Vec<StorageData>, TableControllerError>` - The values in the
+    ///   order of the keys.
+    pub fn get_values_by_keys(
+        &self,
+        name: &column::Name,
+        keys: &[primary_key::Data],
+    ) -> Result<Vec<StorageData>, TableControllerError> {
+        let position = self.column_positions(std::slice::from_ref(name))?[0];
+
+        let mut data_storage = self.data_storage.lock().unwrap();
+        let mut values = Vec::with_capacity(keys.len());
+        for key in keys {
+            let Some(id) = self.keys.get(key) else {
+                continue;
+            };
+            let mut row = data_storage
+                .get_data(id)
+                .map_err(|_| TableControllerError::DataStorageError)?;
+            values.push(row.0.swap_remove(position));
+        }
+
+        Ok(values)
+    }
+
+    /// Checks if a row of the table has the value in a column, e.g. a value
+    /// referenced by a `FOREIGN KEY`. The primary key and the columns of
+    /// single column `UNIQUE` constraints are looked up by their indexes,
+    /// other columns are scanned.
+    /// # Arguments
+    /// * `name` - The name of the column.
+    /// * `value` - The value to look up.
+    /// # Returns
+    /// * `Result<bool, TableControllerError>` - Whether a row has the value.
+    pub fn contains_value(
+        &self,
+        name: &column::Name,
+        value: &StorageData,
+    ) -> Result<bool, TableControllerError> {
+        let is_primary_key = self
+            .info
+            .get_primary_key()
+            .as_ref()
+            .is_some_and(|primary_key| primary_key.get_column() == name);
+        if is_primary_key {
+            return Ok(self.get_row_by_key(value)?.is_some());
+        }
+        let position = self.column_positions(std::slice::from_ref(name))?[0];
+        let index = self
+            .unique
+            .iter()
+            .find(|index| index.positions.as_slice() == [position]);
+        if let Some(index) = index {
+            let key = HashKey(vec![normalize(value.clone())]);
+            return Ok(index.keys.get(&key).is_some());
+        }
+
+        Ok(self
+            .get_keyed_values(name)?
+            .into_iter()
+            .any(|(_, other)| &other == value))
+    }
+
+    /// Returns the row whose primary key is equal to the value, found by the
+    /// hashed primary key index. The value is cast to the type of the
+    /// primary key column first, so that e.g. an `i16` can be looked up in
+    /// an `i32` key.
+    /// # Arguments
+    /// * `value` - The value of the primary key.
+    /// # Returns
+    /// * `Result<Option<DataRow>, TableControllerError>` - The row or `None`
+    ///   if no row has the key or the value can't be a key.
+    pub fn get_row_by_key(
+        &self,
+        value: &StorageData,
+    ) -> Result<Option<DataRow>, TableControllerError> {
+        let Some(primary_key) = self.info.get_primary_key() else {
+            return Err(TableControllerError::PrimaryKeyDoesNotExist);
+        };
+        let position = self
+            .column_positions(std::slice::from_ref(primary_key.get_column()))?
+            [0];
+        let data_type =
+            self.data_storage.lock().unwrap().get_data_type()[position];
+        let key = expression::cast(value.clone(), &data_type)
+            .ok()
+            .and_then(|value| primary_key::Data::try_from(value).ok());
+        let Some(key) = key else {
+            return Ok(None);
+        };
+
+        let Some(id) = self.keys.get(&key) else {
+            return Ok(None);
+        };
+        let row = { self.data_storage.lock().unwrap().get_data(id) }
+            .map_err(|_| TableControllerError::DataStorageError)?;
+
+        Ok(Some(row))
+    }
+
+    /// Returns the row at the position in the table's index.
+    /// # Arguments
+    /// * `position` - The position of the row.
+    /// # Returns
+    /// * `Option<Result<DataRow, TableControllerError>>` - The row or `None`
+    ///   if the table has fewer rows.
+    pub(crate) fn get_row_at(
+        &self,
+        position: usize,
+    ) -> Option<Result<DataRow, TableControllerError>> {
+        let key_id = self.index.get(position)?;
+        let row = { self.data_storage.lock().unwrap().get_data(key_id.id) };
+
+        Some(row.map_err(|_| TableControllerError::DataStorageError))
+    }
+}
+
+/// Returns the positions of the columns.
+/// # Arguments
+/// * `column_names` - The names of all columns.
+/// * `names` - The names of the columns to find.
+/// # Returns
+/// * `Result<Vec<usize>, TableControllerError>` - The positions or
+///   [`TableControllerError::ColumnDoesNotExist`].
+fn positions(
+    column_names: &[column::Name],
+    names: &[column::Name],
+) -> Result<Vec<usize>, TableControllerError> {
+    names
+        .iter()
+        .map(|name| {
+            column_names
+                .iter()
+                .position(|column| column == name)
+                .ok_or(TableControllerError::ColumnDoesNotExist)
+        })
+        .collect()
+}
+
+/// Stacks a [`Filter`] for the condition on the input, if there is one.
+/// # Arguments
+/// * `input` - The input operator.
+/// * `condition` - The `WHERE` condition.
+/// # Returns
+/// * `Result<Box<dyn Operator + 'a>, TableControllerError>` - The top
+///   operator or [`TableControllerError::ColumnDoesNotExist`].
+fn with_condition<'a>(
+    input: Box<dyn Operator + 'a>,
+    condition: Option<Expression>,
+) -> Result<Box<dyn Operator + 'a>, TableControllerError> {
+    match condition {
+        Some(condition) => Ok(Box::new(Filter::new(input, condition)?)),
+        None => Ok(input),
     }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::{
-        controller::{table, table::selector::DataSelector},
+        controller::{
+            table,
+            table::{
+                selector::{DataSelector, FilterType, OrderBy, SelectorFilter},
+                TableControllerError,
+            },
+        },
         data::DataUnit,
+        expression::{BinaryOperator, Expression},
+        query::{cost::AccessPath, Aggregate, Aggregation},
         schema,
         schema::{
             column::primary_key,
             r#type::{
+                data_types::VarChar,
                 r#enum::{StorageData, StorageDataType},
                 DataRow,
             },
@@ -64,10 +370,12 @@ mod tests {
     fn test_table_get_data() {
         let name: table::Name = "table".into();
         let mut table = crate::controller::Table::<16>::new(name.clone());
-        table.add_column(
-            "id".into(),
-            schema::Column::new(StorageDataType::Integer),
-        );
+        table
+            .add_column(
+                "id".into(),
+                schema::Column::new(StorageDataType::Integer),
+            )
+            .unwrap();
 
         let primary_key =
             primary_key::PrimaryKey::new("pk".into(), "id".into());
@@ -85,6 +393,7 @@ mod tests {
         let selector = DataSelector {
             row_names: None,
             filters: None,
+            ..Default::default()
         };
 
         let result = table.get_data(selector);
@@ -106,4 +415,348 @@ mod tests {
             &DataRow::from(vec![StorageData::Integer(2.into())])
         );
     }
+
+    fn users_table() -> crate::controller::Table<16> {
+        let mut table = crate::controller::Table::<16>::new("users".into());
+        table
+            .add_column(
+                "id".into(),
+                schema::Column::new(StorageDataType::Integer),
+            )
+            .unwrap();
+        table
+            .add_column(
+                "name".into(),
+                schema::Column::new(StorageDataType::VarChar(8)),
+            )
+            .unwrap();
+        table
+            .set_primary_key(primary_key::PrimaryKey::new(
+                "pk".into(),
+                "id".into(),
+            ))
+            .expect("Failed to set primary key");
+
+        let mut data = DataUnit::new(vec!["id".into(), "name".into()]);
+        for (id, name) in [(1, "ann"), (2, "bob"), (3, "carol")] {
+            data.insert(
+                vec![
+                    StorageData::Integer(id.into()),
+                    VarChar::new(name.to_string()).unwrap().into(),
+                ]
+                .into(),
+            );
+        }
+        table.add_data(data).expect("is ok");
+
+        table
+    }
+
+    #[test]
+    fn test_table_get_data_with_filters() {
+        let mut table = users_table();
+
+        let selector = DataSelector {
+            row_names: Some(vec!["name".into()]),
+            filters: Some(vec![
+                SelectorFilter {
+                    column_name: "id".into(),
+                    value: StorageData::Integer(1.into()),
+                    filter_type: FilterType::GreaterThan,
+                },
+                SelectorFilter {
+                    column_name: "name".into(),
+                    value: VarChar::new("carol".to_string()).unwrap().into(),
+                    filter_type: FilterType::NotEqual,
+                },
+            ]),
+            ..Default::default()
+        };
+
+        let result = table.get_data(selector).unwrap();
+        assert_eq!(result.get_names(), &["name".into()]);
+        assert_eq!(
+            result.get_values(),
+            vec![DataRow::from(vec![StorageData::VarChar(
+                VarChar::new("bob".to_string()).unwrap()
+            )])]
+        );
+    }
+
+    #[test]
+    fn test_table_get_data_unknown_column() {
+        let mut table = users_table();
+
+        let selector = DataSelector {
+            row_names: Some(vec!["age".into()]),
+            filters: None,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            table.get_data(selector),
+            Err(TableControllerError::ColumnDoesNotExist)
+        );
+    }
+
+    #[test]
+    fn test_table_get_data_ordered() {
+        let mut table = users_table();
+        let name = || Expression::Column("name".into());
+        let mut ids = |selector: DataSelector| {
+            table
+                .get_data(selector)
+                .unwrap()
+                .get_values()
+                .into_iter()
+                .map(|row| row.0[0].clone())
+                .collect::<Vec<_>>()
+        };
+
+        let selector = DataSelector {
+            row_names: Some(vec!["id".into()]),
+            order_by: vec![OrderBy::desc(name())],
+            ..Default::default()
+        };
+        assert_eq!(ids(selector), [3, 2, 1].map(StorageData::from));
+
+        let selector = DataSelector {
+            row_names: Some(vec!["id".into()]),
+            order_by: vec![OrderBy::desc(name())],
+            limit: Some(1),
+            offset: 1,
+            ..Default::default()
+        };
+        assert_eq!(ids(selector), [StorageData::from(2)]);
+
+        let selector = DataSelector {
+            row_names: Some(vec!["id".into()]),
+            limit: Some(5),
+            offset: 2,
+            ..Default::default()
+        };
+        assert_eq!(ids(selector), [StorageData::from(3)]);
+
+        let selector = DataSelector {
+            order_by: vec![OrderBy::asc(Expression::Column("age".into()))],
+            ..Default::default()
+        };
+        assert_eq!(
+            table.get_data(selector),
+            Err(TableControllerError::ColumnDoesNotExist)
+        );
+    }
+
+    #[test]
+    fn test_table_get_data_condition() {
+        let mut table = users_table();
+        let id = || Expression::Column("id".into());
+        let condition = Expression::binary(
+            id(),
+            BinaryOperator::GreaterThan,
+            Expression::Literal(StorageData::from(1)),
+        );
+        let tens = Expression::binary(
+            id(),
+            BinaryOperator::Multiply,
+            Expression::Literal(StorageData::from(10)),
+        );
+
+        let selector = DataSelector {
+            row_names: Some(vec!["name".into()]),
+            columns: Some(vec![(tens, "tens".into())]),
+            condition: Some(condition),
+            order_by: vec![OrderBy::desc(id())],
+            ..Default::default()
+        };
+        let result = table.get_data(selector).unwrap();
+        assert_eq!(result.get_names(), &["tens".into()]);
+        assert_eq!(
+            result.get_values(),
+            vec![
+                DataRow::from(vec![StorageData::from(30)]),
+                DataRow::from(vec![StorageData::from(20)])
+            ]
+        );
+
+        let selector = DataSelector {
+            condition: Some(Expression::Column("age".into())),
+            ..Default::default()
+        };
+        assert_eq!(
+            table.get_data(selector),
+            Err(TableControllerError::ColumnDoesNotExist)
+        );
+    }
+
+    #[test]
+    fn test_table_get_aggregated_data() {
+        let mut table = users_table();
+        let aggregation = Aggregation {
+            group_by: vec![Expression::Column("name".into())],
+            aggregates: vec![Aggregate::count_all()],
+            having: None,
+        };
+
+        let selector = DataSelector {
+            filters: Some(vec![SelectorFilter {
+                column_name: "id".into(),
+                value: StorageData::Integer(1.into()),
+                filter_type: FilterType::GreaterThan,
+            }]),
+            order_by: vec![OrderBy::desc(Expression::Column("name".into()))],
+            limit: Some(1),
+            ..Default::default()
+        };
+        let result = table.get_aggregated_data(selector, &aggregation).unwrap();
+        assert_eq!(result.get_names(), &["name".into(), "COUNT(*)".into()]);
+        assert_eq!(
+            result.get_values(),
+            vec![DataRow::from(vec![
+                VarChar::new("carol".to_string()).unwrap().into(),
+                StorageData::from(1i128)
+            ])]
+        );
+
+        let selector = DataSelector {
+            row_names: Some(vec!["id".into()]),
+            ..Default::default()
+        };
+        assert_eq!(
+            table.get_aggregated_data(selector, &aggregation),
+            Err(TableControllerError::ColumnDoesNotExist)
+        );
+    }
+
+    #[test]
+    fn test_table_get_access_path() {
+        let mut table = users_table();
+        let filter = |column: &str| SelectorFilter {
+            column_name: column.into(),
+            value: StorageData::Integer(2.into()),
+            filter_type: FilterType::Equal,
+        };
+
+        assert_eq!(
+            table.get_access_path(&[filter("name"), filter("id")]),
+            AccessPath::IndexScan(StorageData::Integer(2.into()))
+        );
+        assert_eq!(table.get_access_path(&[filter("name")]), AccessPath::Scan);
+
+        let selector = DataSelector {
+            row_names: Some(vec!["name".into()]),
+            filters: Some(vec![filter("id")]),
+            ..Default::default()
+        };
+        assert_eq!(
+            table.get_data(selector).unwrap().get_values(),
+            vec![DataRow::from(vec![StorageData::VarChar(
+                VarChar::new("bob".to_string()).unwrap()
+            )])]
+        );
+
+        let mut table = crate::controller::Table::<16>::new("one".into());
+        table
+            .add_column(
+                "id".into(),
+                schema::Column::new(StorageDataType::Integer),
+            )
+            .unwrap();
+        table
+            .set_primary_key(primary_key::PrimaryKey::new(
+                "pk".into(),
+                "id".into(),
+            ))
+            .expect("Failed to set primary key");
+        let mut data = DataUnit::new(vec!["id".into()]);
+        data.insert(vec![StorageData::Integer(2.into())].into());
+        table.add_data(data).expect("is ok");
+        assert_eq!(table.get_access_path(&[filter("id")]), AccessPath::Scan);
+    }
+
+    #[test]
+    fn test_table_get_keys_and_values() {
+        let table = users_table();
+        let key = |id: i32| primary_key::Data::Integer(id.into());
+
+        let filter = SelectorFilter {
+            column_name: "id".into(),
+            value: StorageData::Integer(1.into()),
+            filter_type: FilterType::GreaterThan,
+        };
+        assert_eq!(table.get_keys(&[filter]), Ok(vec![key(2), key(3)]));
+        assert_eq!(table.get_keys(&[]).unwrap().len(), 3);
+
+        let values = table.get_keyed_values(&"name".into()).unwrap();
+        assert_eq!(
+            values[1],
+            (key(2), VarChar::new("bob".to_string()).unwrap().into())
+        );
+        assert_eq!(
+            table.get_keyed_values(&"age".into()),
+            Err(TableControllerError::ColumnDoesNotExist)
+        );
+
+        let values = table
+            .get_values_by_keys(&"name".into(), &[key(2), key(4), key(1)])
+            .unwrap();
+        assert_eq!(values.len(), 2);
+        assert_eq!(values[0], VarChar::new("bob".to_string()).unwrap().into());
+    }
+
+    #[test]
+    fn test_table_get_data_after_add_column() {
+        let mut table = users_table();
+
+        let mut age = schema::Column::new(StorageDataType::Short);
+        age.set_not_null(true);
+        age.set_default(Some(StorageData::Short(18.into())));
+        table.add_column("age".into(), age).unwrap();
+        table
+            .add_column(
+                "email".into(),
+                schema::Column::new(StorageDataType::VarChar(16)),
+            )
+            .unwrap();
+
+        let mut data = DataUnit::new(vec![
+            "id".into(),
+            "name".into(),
+            "age".into(),
+            "email".into(),
+        ]);
+        data.insert(
+            vec![
+                StorageData::Integer(4.into()),
+                StorageData::Null,
+                StorageData::Short(30.into()),
+                StorageData::Null,
+            ]
+            .into(),
+        );
+        table.add_data(data).expect("is ok");
+
+        let selector = DataSelector {
+            row_names: Some(vec!["age".into(), "email".into()]),
+            filters: None,
+            ..Default::default()
+        };
+        let values = table.get_data(selector).unwrap().get_values();
+
+        assert_eq!(values.len(), 4);
+        assert_eq!(
+            values.first().unwrap(),
+            &DataRow::from(vec![
+                StorageData::Short(18.into()),
+                StorageData::Null
+            ])
+        );
+        assert_eq!(
+            values.last().unwrap(),
+            &DataRow::from(vec![
+                StorageData::Short(30.into()),
+                StorageData::Null
+            ])
+        );
+    }
 }
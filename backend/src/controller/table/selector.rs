@@ -1,48 +1,298 @@
 //! Data selector definitions.
 
-use crate::schema::{column, r#type::r#enum::StorageDataType};
+use std::{cmp::Ordering, fmt::Display};
+
+use derive_more::Display;
+
+use crate::{
+    expression::Expression,
+    schema::{column, r#type::r#enum::StorageData},
+};
 
 /// Represents a data selector.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct DataSelector {
     /// The column names to select. None means all columns.
     pub row_names: Option<Vec<column::Name>>,
 
+    /// The expressions of the result columns and their names, as the list of
+    /// `SELECT`. If set, they are selected instead of `row_names`.
+    pub columns: Option<Vec<(Expression, column::Name)>>,
+
     /// The filters to apply.
     pub filters: Option<Vec<SelectorFilter>>,
+
+    /// The `WHERE` condition, rows for which it's `FALSE` or `NULL` are
+    /// skipped. It's checked after the filters.
+    pub condition: Option<Expression>,
+
+    /// The `ORDER BY` items, rows are sorted by the first item, then by the
+    /// second and so on. Empty means the rows come in the order of the
+    /// table's index.
+    pub order_by: Vec<OrderBy>,
+
+    /// The maximum number of rows to return, `None` means all rows.
+    pub limit: Option<usize>,
+
+    /// The number of rows to skip before returning any.
+    pub offset: usize,
+}
+
+/// Represents an item of `ORDER BY`, e.g. `price * amount DESC NULLS LAST`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrderBy {
+    /// The expression to sort by, it can use columns that aren't selected.
+    pub expression: Expression,
+
+    /// The direction of the sort.
+    pub direction: SortDirection,
+
+    /// The position of `NULL`s, `None` means `NULL`s are sorted as if they
+    /// were larger than any value, last in ascending and first in
+    /// descending order.
+    pub nulls: Option<NullsOrder>,
+}
+
+impl OrderBy {
+    /// Creates an [`OrderBy`] in ascending order.
+    /// # Arguments
+    /// * `expression` - The expression to sort by.
+    pub fn asc(expression: Expression) -> Self {
+        Self {
+            expression,
+            direction: SortDirection::Ascending,
+            nulls: None,
+        }
+    }
+
+    /// Creates an [`OrderBy`] in descending order.
+    /// # Arguments
+    /// * `expression` - The expression to sort by.
+    pub fn desc(expression: Expression) -> Self {
+        Self {
+            expression,
+            direction: SortDirection::Descending,
+            nulls: None,
+        }
+    }
+
+    /// Sorts `NULL`s before other values.
+    pub fn nulls_first(mut self) -> Self {
+        self.nulls = Some(NullsOrder::First);
+        self
+    }
+
+    /// Sorts `NULL`s after other values.
+    pub fn nulls_last(mut self) -> Self {
+        self.nulls = Some(NullsOrder::Last);
+        self
+    }
+
+    /// Checks if `NULL`s are sorted before other values.
+    pub fn is_nulls_first(&self) -> bool {
+        match self.nulls {
+            Some(nulls) => nulls == NullsOrder::First,
+            None => self.direction == SortDirection::Descending,
+        }
+    }
+}
+
+impl Display for OrderBy {
+    /// Formats the item as in SQL, e.g. `name DESC NULLS LAST`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.expression, self.direction)?;
+        if let Some(nulls) = self.nulls {
+            write!(f, " {}", nulls)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Represents the direction of an [`OrderBy`].
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq)]
+pub enum SortDirection {
+    /// Smaller values first.
+    #[display(fmt = "ASC")]
+    Ascending,
+
+    /// Larger values first.
+    #[display(fmt = "DESC")]
+    Descending,
+}
+
+/// Represents the position of `NULL`s in an [`OrderBy`].
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq)]
+pub enum NullsOrder {
+    /// `NULL`s come before other values.
+    #[display(fmt = "NULLS FIRST")]
+    First,
+
+    /// `NULL`s come after other values.
+    #[display(fmt = "NULLS LAST")]
+    Last,
 }
 
 /// Represents a filter for a column.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct SelectorFilter {
     /// The column name.
     pub column_name: column::Name,
 
     /// The value to compare.
-    pub value: StorageDataType,
+    pub value: StorageData,
 
     /// The filter type.
     pub filter_type: FilterType,
 }
 
+impl SelectorFilter {
+    /// Checks if the column value passes the filter.
+    /// # Arguments
+    /// * `value` - The value of the filtered column.
+    /// # Returns
+    /// * `bool` - True if the value passes the filter. Values of a different
+    ///   type never pass it.
+    pub fn matches(&self, value: &StorageData) -> bool {
+        if std::mem::discriminant(value) != std::mem::discriminant(&self.value)
+        {
+            return false;
+        }
+
+        value
+            .partial_cmp(&self.value)
+            .is_some_and(|ordering| self.filter_type.accepts(ordering))
+    }
+}
+
+impl Display for SelectorFilter {
+    /// Formats the filter as an SQL comparison, e.g. `age >= 18`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {} {}",
+            self.column_name, self.filter_type, self.value
+        )
+    }
+}
+
 /// Represents a [`SelectorFilter`] type for a column.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Display, Clone, PartialEq)]
 pub enum FilterType {
     /// Represents an equal filter.
+    #[display(fmt = "=")]
     Equal,
 
     /// Represents a not equal filter.
+    #[display(fmt = "<>")]
     NotEqual,
 
     /// Represents a greater than filter.
+    #[display(fmt = ">")]
     GreaterThan,
 
     /// Represents a less than filter.
+    #[display(fmt = "<")]
     LessThan,
 
     /// Represents a greater than or equal filter.
+    #[display(fmt = ">=")]
     GreaterThanOrEqual,
 
     /// Represents a less than or equal filter.
+    #[display(fmt = "<=")]
     LessThanOrEqual,
 }
+
+impl FilterType {
+    /// Checks if the result of comparing the column value with the filter
+    /// value passes the filter.
+    /// # Arguments
+    /// * `ordering` - The ordering of the column value relative to the
+    ///   filter value.
+    fn accepts(&self, ordering: Ordering) -> bool {
+        match self {
+            FilterType::Equal => ordering == Ordering::Equal,
+            FilterType::NotEqual => ordering != Ordering::Equal,
+            FilterType::GreaterThan => ordering == Ordering::Greater,
+            FilterType::LessThan => ordering == Ordering::Less,
+            FilterType::GreaterThanOrEqual => ordering != Ordering::Less,
+            FilterType::LessThanOrEqual => ordering != Ordering::Greater,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        controller::table::selector::{FilterType, OrderBy, SelectorFilter},
+        expression::Expression,
+        schema::r#type::{
+            data_types::{Integer, Long, Timestamp},
+            r#enum::StorageData,
+        },
+    };
+
+    fn filter(filter_type: FilterType) -> SelectorFilter {
+        SelectorFilter {
+            column_name: "id".into(),
+            value: StorageData::Integer(Integer(5)),
+            filter_type,
+        }
+    }
+
+    #[test]
+    fn test_matches() {
+        let less = StorageData::Integer(Integer(4));
+        let equal = StorageData::Integer(Integer(5));
+
+        assert!(filter(FilterType::Equal).matches(&equal));
+        assert!(!filter(FilterType::NotEqual).matches(&equal));
+        assert!(filter(FilterType::LessThan).matches(&less));
+        assert!(!filter(FilterType::GreaterThan).matches(&less));
+        assert!(filter(FilterType::GreaterThanOrEqual).matches(&equal));
+        assert!(filter(FilterType::LessThanOrEqual).matches(&less));
+    }
+
+    #[test]
+    fn test_matches_other_type() {
+        let value = StorageData::Long(Long(5));
+
+        assert!(!filter(FilterType::Equal).matches(&value));
+        assert!(!filter(FilterType::NotEqual).matches(&value));
+    }
+
+    #[test]
+    fn test_matches_timestamp() {
+        let timestamp = |value: &str| {
+            StorageData::Timestamp(value.parse::<Timestamp>().unwrap())
+        };
+        let filter = SelectorFilter {
+            column_name: "created".into(),
+            value: timestamp("2024-01-01T00:00:00"),
+            filter_type: FilterType::GreaterThanOrEqual,
+        };
+
+        assert!(filter.matches(&timestamp("2024-01-01T00:00:00")));
+        assert!(filter.matches(&timestamp("2024-03-01T12:30:00")));
+        assert!(!filter.matches(&timestamp("2023-12-31T23:59:59.999999")));
+    }
+
+    #[test]
+    fn test_order_by() {
+        let price = || Expression::Column("price".into());
+
+        let order_by = OrderBy::asc(price());
+        assert!(!order_by.is_nulls_first());
+        assert_eq!(order_by.to_string(), "price ASC");
+
+        let order_by = OrderBy::desc(price());
+        assert!(order_by.is_nulls_first());
+        assert_eq!(order_by.to_string(), "price DESC");
+
+        let order_by = OrderBy::desc(price()).nulls_last();
+        assert!(!order_by.is_nulls_first());
+        assert_eq!(order_by.to_string(), "price DESC NULLS LAST");
+        assert!(OrderBy::asc(price()).nulls_first().is_nulls_first());
+    }
+}
@@ -0,0 +1,654 @@
+//! Sorting of selected rows by `ORDER BY`.
+//!
+//! Rows are sorted in runs of [`RUN_SIZE`] rows. If all rows fit into one run
+//! they are sorted in memory, otherwise every sorted run is spilled to the
+//! pages of a temporary [`DataStorage`] of its own, typed by the values of
+//! the run, and the runs are merged while the rows are read back. If only
+//! the first rows are needed because of `LIMIT` and they fit into a run,
+//! only they are kept in a heap (top-N sort).
+
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::BinaryHeap,
+    vec,
+};
+
+use crate::{
+    controller::table::{
+        selector::{OrderBy, SortDirection},
+        TableControllerError,
+    },
+    data::{data_storage::DataStorage, id::NumericId},
+    expression,
+    schema::{
+        column,
+        r#type::{
+            decimal::MAX_PRECISION,
+            r#enum::{StorageData, StorageDataType},
+            DataRow,
+        },
+    },
+};
+
+/// The number of rows sorted in memory at once.
+pub(crate) const RUN_SIZE: usize = 4096;
+
+/// Sorts rows by [`OrderBy`] items. Rows with equal keys keep their order.
+#[derive(Debug, Clone)]
+pub(crate) struct Sorter {
+    /// The items to sort by.
+    order_by: Vec<OrderBy>,
+
+    /// The names of the columns of the rows.
+    column_names: Vec<column::Name>,
+
+    /// The number of rows sorted in memory at once.
+    run_size: usize,
+}
+
+impl Sorter {
+    /// Creates a new [`Sorter`].
+    /// # Arguments
+    /// * `order_by` - The items to sort by.
+    /// * `column_names` - The names of the columns of the rows.
+    pub(crate) fn new(
+        order_by: Vec<OrderBy>,
+        column_names: Vec<column::Name>,
+    ) -> Self {
+        Self {
+            order_by,
+            column_names,
+            run_size: RUN_SIZE,
+        }
+    }
+
+    /// Sets the number of rows sorted in memory at once.
+    #[cfg(test)]
+    pub(crate) fn with_run_size(mut self, run_size: usize) -> Self {
+        self.run_size = run_size;
+        self
+    }
+
+    /// Sorts the rows.
+    /// # Arguments
+    /// * `rows` - The rows to sort.
+    /// * `limit` - The number of first sorted rows that are needed, `None`
+    ///   means all rows.
+    /// # Returns
+    /// * `Result<SortedRows, TableControllerError>` - The sorted rows, at
+    ///   most `limit` of them.
+    pub(crate) fn sort<I>(
+        self,
+        rows: I,
+        limit: Option<usize>,
+    ) -> Result<SortedRows, TableControllerError>
+    where
+        I: IntoIterator<Item = Result<Vec<StorageData>, TableControllerError>>,
+    {
+        match limit {
+            Some(limit) if limit <= self.run_size => self.top_n(rows, limit),
+            _ => self.merge_sort(rows),
+        }
+    }
+
+    /// Keeps the `limit` smallest rows in a heap and sorts them.
+    fn top_n<I>(
+        self,
+        rows: I,
+        limit: usize,
+    ) -> Result<SortedRows, TableControllerError>
+    where
+        I: IntoIterator<Item = Result<Vec<StorageData>, TableControllerError>>,
+    {
+        let mut heap = BinaryHeap::with_capacity(limit);
+        for (position, row) in rows.into_iter().enumerate() {
+            let row = self.sort_row(row?, position)?;
+            if heap.len() < limit {
+                heap.push(row);
+            } else if let Some(mut largest) = heap.peek_mut() {
+                if row < *largest {
+                    *largest = row;
+                }
+            }
+        }
+
+        Ok(SortedRows::Memory(heap.into_sorted_vec().into_iter()))
+    }
+
+    /// Sorts the rows in memory if they fit into a run, otherwise spills
+    /// sorted runs and merges them.
+    fn merge_sort<I>(self, rows: I) -> Result<SortedRows, TableControllerError>
+    where
+        I: IntoIterator<Item = Result<Vec<StorageData>, TableControllerError>>,
+    {
+        let mut run = Vec::new();
+        let mut spill = None;
+        for (position, row) in rows.into_iter().enumerate() {
+            run.push(self.sort_row(row?, position)?);
+            if run.len() == self.run_size {
+                spill
+                    .get_or_insert_with(Spill::default)
+                    .write_run(std::mem::take(&mut run), &self.column_names)?;
+            }
+        }
+
+        match spill {
+            None => {
+                run.sort();
+                Ok(SortedRows::Memory(run.into_iter()))
+            }
+            Some(mut spill) => {
+                if !run.is_empty() {
+                    spill.write_run(run, &self.column_names)?;
+                }
+                Ok(SortedRows::Merge(Box::new(Merge::new(self, spill)?)))
+            }
+        }
+    }
+
+    /// Evaluates the sort keys of the row.
+    /// # Arguments
+    /// * `row` - The row.
+    /// * `position` - The position of the row, rows with equal keys are
+    ///   sorted by it.
+    fn sort_row(
+        &self,
+        row: Vec<StorageData>,
+        position: usize,
+    ) -> Result<SortRow, TableControllerError> {
+        let keys = self
+            .order_by
+            .iter()
+            .map(|order_by| {
+                let value = order_by
+                    .expression
+                    .evaluate(|name| {
+                        self.column_names
+                            .iter()
+                            .position(|column| column == name)
+                            .map(|position| &row[position])
+                    })
+                    .map_err(TableControllerError::EvaluationError)?;
+
+                Ok(SortKey {
+                    value,
+                    descending: order_by.direction == SortDirection::Descending,
+                    nulls_first: order_by.is_nulls_first(),
+                })
+            })
+            .collect::<Result<_, TableControllerError>>()?;
+
+        Ok(SortRow {
+            keys,
+            position,
+            row,
+        })
+    }
+}
+
+/// Rows sorted by a [`Sorter`].
+#[derive(Debug)]
+pub(crate) enum SortedRows {
+    /// Rows sorted in memory.
+    Memory(vec::IntoIter<SortRow>),
+
+    /// Spilled runs that are merged while they are read.
+    Merge(Box<Merge>),
+}
+
+impl Iterator for SortedRows {
+    type Item = Result<Vec<StorageData>, TableControllerError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            SortedRows::Memory(rows) => rows.next().map(|row| Ok(row.row)),
+            SortedRows::Merge(merge) => merge.next(),
+        }
+    }
+}
+
+/// Value of an [`OrderBy`] item for a row.
+#[derive(Debug)]
+struct SortKey {
+    /// The value of the expression.
+    value: StorageData,
+
+    /// Whether larger values come first.
+    descending: bool,
+
+    /// Whether `NULL`s come first.
+    nulls_first: bool,
+}
+
+impl Ord for SortKey {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (
+            self.value == StorageData::Null,
+            other.value == StorageData::Null,
+        ) {
+            (true, true) => Ordering::Equal,
+            (true, false) if self.nulls_first => Ordering::Less,
+            (true, false) => Ordering::Greater,
+            (false, true) if self.nulls_first => Ordering::Greater,
+            (false, true) => Ordering::Less,
+            (false, false) => {
+                let ordering = expression::compare(&self.value, &other.value)
+                    .unwrap_or(Ordering::Equal);
+                if self.descending {
+                    ordering.reverse()
+                } else {
+                    ordering
+                }
+            }
+        }
+    }
+}
+
+impl PartialOrd for SortKey {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for SortKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for SortKey {}
+
+/// A row with the values of its [`SortKey`]s.
+#[derive(Debug)]
+pub(crate) struct SortRow {
+    /// The sort keys.
+    keys: Vec<SortKey>,
+
+    /// The position of the row in the input, or the index of its run when
+    /// the runs are merged.
+    position: usize,
+
+    /// The values of the row.
+    row: Vec<StorageData>,
+}
+
+impl Ord for SortRow {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.keys
+            .cmp(&other.keys)
+            .then(self.position.cmp(&other.position))
+    }
+}
+
+impl PartialOrd for SortRow {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for SortRow {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for SortRow {}
+
+/// Sorted runs spilled to the pages of temporary [`DataStorage`]s.
+#[derive(Debug, Default)]
+struct Spill {
+    /// The storage of every run and the ids of its rows in sorted order, the
+    /// storages are dropped with the runs.
+    runs: Vec<(DataStorage, Vec<NumericId>)>,
+}
+
+impl Spill {
+    /// Sorts the run and writes it to a storage of its own, whose columns
+    /// are of the types of the run's values.
+    /// # Arguments
+    /// * `run` - The rows of the run.
+    /// * `column_names` - The names of the columns of the rows.
+    fn write_run(
+        &mut self,
+        mut run: Vec<SortRow>,
+        column_names: &[column::Name],
+    ) -> Result<(), TableControllerError> {
+        run.sort();
+        let rows = run
+            .into_iter()
+            .map(|row| DataRow(row.row))
+            .collect::<Vec<_>>();
+        let mut storage = DataStorage::default();
+        for (position, name) in column_names.iter().enumerate() {
+            let data_type = result_type(&rows, position).ok_or_else(|| {
+                TableControllerError::MixedColumnTypes(name.clone())
+            })?;
+            storage.append_nullable_data_type(data_type);
+        }
+
+        let ids = rows
+            .into_iter()
+            .map(|row| storage.add_data(row))
+            .collect::<Result<_, _>>()
+            .map_err(|_| TableControllerError::DataStorageError)?;
+        self.runs.push((storage, ids));
+
+        Ok(())
+    }
+}
+
+/// Returns the type of a column of the rows, used to store them. It's the
+/// type of the column's values, the longest one for `VARCHAR`s, or any type
+/// if all values are `NULL`. `DECIMAL`s get the largest scale of the values
+/// and room for the most digits before the decimal point, so that they are
+/// stored without rounding.
+/// # Arguments
+/// * `rows` - The rows.
+/// * `position` - The position of the column in the rows.
+/// # Returns
+/// * `Some(StorageDataType)` - The type of the column.
+/// * `None` - If the values are of different types or the `DECIMAL`s need
+///   more than [`MAX_PRECISION`] digits.
+pub(crate) fn result_type(
+    rows: &[DataRow],
+    position: usize,
+) -> Option<StorageDataType> {
+    let result = rows
+        .iter()
+        .filter_map(|row| row.0[position].data_type())
+        .try_fold(None, |result, data_type| {
+            Some(Some(match (result, data_type) {
+                (None, data_type) => data_type,
+                (
+                    Some(StorageDataType::VarChar(a)),
+                    StorageDataType::VarChar(b),
+                ) => StorageDataType::VarChar(a.max(b)),
+                (
+                    Some(StorageDataType::Decimal(p, s)),
+                    StorageDataType::Decimal(q, t),
+                ) => {
+                    let scale = s.max(t);
+                    StorageDataType::Decimal((p - s).max(q - t) + scale, scale)
+                }
+                (Some(result), data_type) if result == data_type => result,
+                _ => return None,
+            }))
+        })?;
+
+    match result {
+        Some(StorageDataType::Decimal(precision, _))
+            if precision > MAX_PRECISION =>
+        {
+            None
+        }
+        result => Some(result.unwrap_or(StorageDataType::Bool)),
+    }
+}
+
+/// Merges spilled runs, holding only the next row of every run in memory.
+#[derive(Debug)]
+pub(crate) struct Merge {
+    /// The sorter of the runs, used to evaluate the keys of read rows.
+    sorter: Sorter,
+
+    /// The storage of every run and the ids of its rows that are not read
+    /// yet.
+    runs: Vec<(DataStorage, vec::IntoIter<NumericId>)>,
+
+    /// The next row of every run that is not exhausted.
+    heads: BinaryHeap<Reverse<SortRow>>,
+}
+
+impl Merge {
+    /// Creates a new [`Merge`] and reads the first row of every run.
+    fn new(sorter: Sorter, spill: Spill) -> Result<Self, TableControllerError> {
+        let mut merge = Self {
+            sorter,
+            heads: BinaryHeap::with_capacity(spill.runs.len()),
+            runs: spill
+                .runs
+                .into_iter()
+                .map(|(storage, ids)| (storage, ids.into_iter()))
+                .collect(),
+        };
+        for run in 0..merge.runs.len() {
+            merge.read_head(run)?;
+        }
+
+        Ok(merge)
+    }
+
+    /// Reads the next row of the run into the heads.
+    fn read_head(&mut self, run: usize) -> Result<(), TableControllerError> {
+        let (storage, ids) = &mut self.runs[run];
+        if let Some(id) = ids.next() {
+            let row = storage
+                .get_data(id)
+                .map_err(|_| TableControllerError::DataStorageError)?;
+            self.heads.push(Reverse(self.sorter.sort_row(row.0, run)?));
+        }
+
+        Ok(())
+    }
+}
+
+impl Iterator for Merge {
+    type Item = Result<Vec<StorageData>, TableControllerError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Reverse(head) = self.heads.pop()?;
+        if let Err(error) = self.read_head(head.position) {
+            return Some(Err(error));
+        }
+
+        Some(Ok(head.row))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        controller::table::{
+            selector::OrderBy,
+            sort::{SortedRows, Sorter},
+            TableControllerError,
+        },
+        expression::{BinaryOperator, Expression},
+        schema::{
+            column,
+            r#type::{data_types::Decimal, r#enum::StorageData},
+        },
+    };
+
+    fn names() -> Vec<column::Name> {
+        vec!["id".into(), "score".into()]
+    }
+
+    /// Rows with ids `0..count` and scores `id % 7`, every tenth score is
+    /// `NULL`.
+    fn rows(
+        count: i32,
+    ) -> impl Iterator<Item = Result<Vec<StorageData>, TableControllerError>>
+    {
+        (0..count).map(|id| {
+            let score = if id % 10 == 0 {
+                StorageData::Null
+            } else {
+                StorageData::from(id % 7)
+            };
+            Ok(vec![StorageData::from(id), score])
+        })
+    }
+
+    /// Sorts by score and returns the ids.
+    fn sorted_ids(
+        order_by: OrderBy,
+        run_size: usize,
+        limit: Option<usize>,
+        count: i32,
+    ) -> (Vec<StorageData>, bool) {
+        let sorted = Sorter::new(vec![order_by], names())
+            .with_run_size(run_size)
+            .sort(rows(count), limit)
+            .unwrap();
+        let merged = matches!(sorted, SortedRows::Merge(_));
+        let ids = sorted.map(|row| row.unwrap()[0].clone()).collect();
+
+        (ids, merged)
+    }
+
+    /// Sorts the same rows in memory.
+    fn expected_ids(order_by: OrderBy, count: i32) -> Vec<StorageData> {
+        sorted_ids(order_by, usize::MAX, None, count).0
+    }
+
+    fn score() -> Expression {
+        Expression::Column("score".into())
+    }
+
+    #[test]
+    fn test_sort_in_memory() {
+        let (ids, merged) = sorted_ids(OrderBy::asc(score()), 64, None, 21);
+        assert!(!merged);
+
+        let ids = ids
+            .into_iter()
+            .map(|id| match id {
+                StorageData::Integer(id) => id.0,
+                _ => panic!("id is an integer"),
+            })
+            .collect::<Vec<_>>();
+        // Equal scores keep the order of the rows, NULLs come last.
+        assert_eq!(
+            ids,
+            vec![
+                7, 14, 1, 8, 15, 2, 9, 16, 3, 17, 4, 11, 18, 5, 12, 19, 6, 13,
+                0, 10, 20
+            ]
+        );
+    }
+
+    #[test]
+    fn test_sort_nulls_and_direction() {
+        let (ids, _) = sorted_ids(OrderBy::desc(score()), 64, None, 21);
+        assert_eq!(
+            &ids[..4],
+            &[
+                StorageData::from(0),
+                StorageData::from(10),
+                StorageData::from(20),
+                StorageData::from(6)
+            ]
+        );
+
+        let (ids, _) =
+            sorted_ids(OrderBy::desc(score()).nulls_last(), 64, None, 21);
+        assert_eq!(ids[0], StorageData::from(6));
+        assert_eq!(ids[20], StorageData::from(20));
+
+        let (ids, _) =
+            sorted_ids(OrderBy::asc(score()).nulls_first(), 64, None, 21);
+        assert_eq!(ids[2], StorageData::from(20));
+        assert_eq!(ids[3], StorageData::from(7));
+    }
+
+    #[test]
+    fn test_external_merge_sort() {
+        for order_by in
+            [OrderBy::asc(score()), OrderBy::desc(score()).nulls_last()]
+        {
+            let (ids, merged) = sorted_ids(order_by.clone(), 16, None, 250);
+            assert!(merged);
+            assert_eq!(ids, expected_ids(order_by, 250));
+        }
+    }
+
+    #[test]
+    fn test_external_merge_sort_mixed_decimals() {
+        // Every run has decimals of different scales, none may be rounded.
+        let decimals = ["1", "-3.5", "22.125", "0.25", "-7", "1.75", "-0.001"];
+        let rows = (0..decimals.len() * 4).map(|id| {
+            let value = decimals[id % decimals.len()].parse::<Decimal>();
+            Ok(vec![StorageData::from(id as i32), value.unwrap().into()])
+        });
+        let sorted = Sorter::new(vec![OrderBy::asc(score())], names())
+            .with_run_size(3)
+            .sort(rows, None)
+            .unwrap();
+        assert!(matches!(sorted, SortedRows::Merge(_)));
+
+        let scores = sorted
+            .map(|row| row.unwrap().swap_remove(1))
+            .collect::<Vec<_>>();
+        let mut expected = decimals
+            .iter()
+            .flat_map(|value| [value.parse::<Decimal>().unwrap(); 4])
+            .collect::<Vec<_>>();
+        expected.sort();
+        assert_eq!(
+            scores,
+            expected
+                .into_iter()
+                .map(StorageData::from)
+                .collect::<Vec<_>>()
+        );
+
+        let rows = (0..8).map(|id| {
+            let score = if id == 4 {
+                StorageData::from(id)
+            } else {
+                "1.5".parse::<Decimal>().unwrap().into()
+            };
+            Ok(vec![StorageData::from(id), score])
+        });
+        assert_eq!(
+            Sorter::new(vec![OrderBy::asc(score())], names())
+                .with_run_size(3)
+                .sort(rows, None)
+                .err(),
+            Some(TableControllerError::MixedColumnTypes("score".into()))
+        );
+    }
+
+    #[test]
+    fn test_top_n() {
+        let order_by = OrderBy::desc(score());
+        let expected = expected_ids(order_by.clone(), 250);
+
+        let (ids, merged) = sorted_ids(order_by.clone(), 16, Some(12), 250);
+        assert!(!merged);
+        assert_eq!(ids, expected[..12]);
+
+        let (ids, merged) = sorted_ids(order_by.clone(), 16, Some(40), 250);
+        assert!(merged);
+        assert_eq!(ids.len(), 250);
+        assert_eq!(ids[..40], expected[..40]);
+
+        assert!(sorted_ids(order_by, 16, Some(0), 250).0.is_empty());
+    }
+
+    #[test]
+    fn test_sort_by_expression() {
+        let order_by = vec![OrderBy::desc(Expression::binary(
+            Expression::Column("id".into()),
+            BinaryOperator::Modulo,
+            Expression::Literal(StorageData::from(3)),
+        ))];
+        let ids = Sorter::new(order_by, names())
+            .sort(rows(5), None)
+            .unwrap()
+            .map(|row| row.unwrap().swap_remove(0))
+            .collect::<Vec<_>>();
+        assert_eq!(ids, [2, 1, 4, 0, 3].map(StorageData::from).to_vec());
+
+        let order_by = vec![OrderBy::asc(Expression::binary(
+            Expression::Column("id".into()),
+            BinaryOperator::And,
+            Expression::Literal(StorageData::from(3)),
+        ))];
+        assert!(matches!(
+            Sorter::new(order_by, names()).sort(rows(5), Some(1)),
+            Err(TableControllerError::EvaluationError(_))
+        ));
+    }
+}
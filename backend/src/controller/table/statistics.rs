@@ -0,0 +1,180 @@
+use crate::{
+    controller::{
+        table::{selector::SelectorFilter, TableControllerError},
+        Table,
+    },
+    query::executor::{Operator, Scan},
+    schema::{
+        column,
+        table::statistics::{
+            default_selectivity, ColumnStatistics, TableStatistics,
+        },
+    },
+};
+
+impl<const NODE_SIZE: u8> Table<NODE_SIZE> {
+    /// Collects the statistics of the table's rows, as `ANALYZE`, and keeps
+    /// them in the table's info until the table is analyzed again.
+    /// # Returns
+    /// * `Result<TableStatistics, TableControllerError>` - The collected
+    ///   statistics.
+    pub fn analyze(&mut self) -> Result<TableStatistics, TableControllerError> {
+        let names = self.get_column_names();
+        let mut columns =
+            vec![Vec::with_capacity(self.index.len()); names.len()];
+        let mut scan = Scan::new(self, Vec::new())?;
+        while let Some(row) = scan.next()? {
+            for (values, value) in columns.iter_mut().zip(row.0) {
+                values.push(value);
+            }
+        }
+
+        let columns = names
+            .into_iter()
+            .zip(columns.into_iter().map(ColumnStatistics::collect))
+            .collect();
+        let statistics = TableStatistics::new(self.index.len(), columns);
+        self.info.set_statistics(statistics.clone());
+
+        Ok(statistics)
+    }
+
+    /// Returns the statistics collected by [`Table::analyze`].
+    /// # Returns
+    /// * `Option<&TableStatistics>` - The statistics or `None` if the table
+    ///   wasn't analyzed.
+    pub fn get_statistics(&self) -> Option<&TableStatistics> {
+        self.info.get_statistics()
+    }
+
+    /// Returns the number of rows of the table.
+    pub fn get_row_count(&self) -> usize {
+        self.index.len()
+    }
+
+    /// Estimates the number of rows that pass all filters. The filters are
+    /// assumed to be independent, the fraction of rows that pass one is
+    /// estimated by the statistics of its column, or by a default one if
+    /// the table wasn't analyzed.
+    /// # Arguments
+    /// * `filters` - The filters.
+    /// # Returns
+    /// * `f64` - The estimated number of rows.
+    pub fn estimate_rows(&self, filters: &[SelectorFilter]) -> f64 {
+        let statistics = self.info.get_statistics();
+        let selectivity = filters
+            .iter()
+            .map(|filter| match statistics {
+                Some(statistics) => statistics.selectivity(filter),
+                None => default_selectivity(&filter.filter_type),
+            })
+            .product::<f64>();
+
+        self.index.len() as f64 * selectivity
+    }
+
+    /// Estimates the number of distinct values of a column. The values of
+    /// the primary key are distinct, the number of other ones is scaled by
+    /// the number of rows added or removed since the table was analyzed.
+    /// All values are assumed to be distinct without statistics.
+    /// # Arguments
+    /// * `name` - The name of the column.
+    /// # Returns
+    /// * `f64` - The estimated number of distinct values.
+    pub fn estimate_distinct(&self, name: &column::Name) -> f64 {
+        let rows = self.index.len() as f64;
+        let is_key = self
+            .get_primary_key()
+            .as_ref()
+            .is_some_and(|primary_key| primary_key.get_column() == name);
+        let statistics = self
+            .info
+            .get_statistics()
+            .filter(|statistics| statistics.get_row_count() > 0);
+
+        match (is_key, statistics) {
+            (false, Some(statistics)) => match statistics.get_column(name) {
+                Some(column) => {
+                    let scale = rows / statistics.get_row_count() as f64;
+                    (column.get_distinct_count() as f64 * scale).min(rows)
+                }
+                None => rows,
+            },
+            _ => rows,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        controller,
+        controller::table::selector::{FilterType, SelectorFilter},
+        data::DataUnit,
+        schema,
+        schema::{
+            column::primary_key::PrimaryKey,
+            r#type::{
+                r#enum::{StorageData, StorageDataType},
+                DataRow,
+            },
+        },
+    };
+
+    /// Orders 1 to 100 of 10 users, the user of every fifth order is `NULL`.
+    fn orders() -> controller::Table<16> {
+        let mut table = controller::Table::<16>::new("orders".into());
+        table
+            .add_column(
+                "id".into(),
+                schema::Column::new(StorageDataType::Integer),
+            )
+            .unwrap();
+        table
+            .add_column(
+                "user_id".into(),
+                schema::Column::new(StorageDataType::Integer),
+            )
+            .unwrap();
+        table
+            .set_primary_key(PrimaryKey::new("pk".into(), "id".into()))
+            .expect("Failed to set primary key");
+
+        let mut data = DataUnit::new(vec!["id".into(), "user_id".into()]);
+        for id in 1..=100 {
+            let user_id = match id % 5 {
+                0 => StorageData::Null,
+                _ => StorageData::from(id % 10),
+            };
+            data.insert(DataRow(vec![StorageData::from(id), user_id]));
+        }
+        table.add_data(data).expect("is ok");
+
+        table
+    }
+
+    #[test]
+    fn test_table_analyze() {
+        let mut table = orders();
+        assert_eq!(table.get_statistics(), None);
+        assert_eq!(table.estimate_distinct(&"user_id".into()), 100.0);
+
+        let statistics = table.analyze().unwrap();
+        assert_eq!(table.get_statistics(), Some(&statistics));
+        assert_eq!(statistics.get_row_count(), 100);
+
+        let user_id = statistics.get_column(&"user_id".into()).unwrap();
+        assert_eq!(user_id.get_null_count(), 20);
+        assert_eq!(user_id.get_distinct_count(), 8);
+        assert_eq!(table.estimate_distinct(&"user_id".into()), 8.0);
+        assert_eq!(table.estimate_distinct(&"id".into()), 100.0);
+
+        let filter = SelectorFilter {
+            column_name: "user_id".into(),
+            value: StorageData::from(3),
+            filter_type: FilterType::Equal,
+        };
+        assert_eq!(table.estimate_rows(&[filter]), 10.0);
+        assert_eq!(table.estimate_rows(&[]), 100.0);
+    }
+}
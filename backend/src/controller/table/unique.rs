@@ -0,0 +1,84 @@
+//! Contains the [`UniqueIndex`] type definition.
+
+use common::structs::hash_table::{scalable::ScalableHashTable, HashTable};
+
+use crate::{
+    controller::{table::TableControllerError, Table},
+    query::key::{normalize, HashKey},
+    schema::{
+        column::primary_key,
+        r#type::r#enum::StorageData,
+        table::constraint::{self, Unique},
+    },
+};
+
+/// Hashed index of the values of the columns of a `UNIQUE` constraint, so
+/// that a duplicate is found without a scan of the table. Rows with a
+/// `NULL` in one of the columns are not in the index, as they never
+/// violate the constraint.
+#[derive(Debug, Clone)]
+pub struct UniqueIndex {
+    /// The name of the constraint.
+    pub name: constraint::Name,
+
+    /// The positions of the constraint's columns in the table's rows.
+    pub positions: Vec<usize>,
+
+    /// The values of the constraint's columns mapped to the primary keys of
+    /// their rows.
+    pub keys: ScalableHashTable<HashKey, primary_key::Data>,
+}
+
+impl UniqueIndex {
+    /// Returns the values of the constraint's columns of the row, or `None`
+    /// if one of them is `NULL`.
+    /// # Arguments
+    /// * `row` - The values of the row in the order of the table's columns.
+    pub fn key(&self, row: &[StorageData]) -> Option<HashKey> {
+        self.positions
+            .iter()
+            .map(|position| match &row[*position] {
+                StorageData::Null => None,
+                value => Some(normalize(value.clone())),
+            })
+            .collect::<Option<Vec<_>>>()
+            .map(HashKey)
+    }
+
+    /// Adds a row to the index.
+    /// # Arguments
+    /// * `row` - The values of the row in the order of the table's columns.
+    /// * `key` - The primary key of the row.
+    pub fn insert(&mut self, row: &[StorageData], key: &primary_key::Data) {
+        if let Some(unique_key) = self.key(row) {
+            self.keys.insert(unique_key, key.clone());
+        }
+    }
+
+    /// Removes a row from the index.
+    /// # Arguments
+    /// * `row` - The values of the row in the order of the table's columns.
+    pub fn remove(&mut self, row: &[StorageData]) {
+        if let Some(unique_key) = self.key(row) {
+            self.keys.remove(&unique_key);
+        }
+    }
+}
+
+impl<const NODE_SIZE: u8> Table<NODE_SIZE> {
+    /// Creates the index of a `UNIQUE` constraint of the table.
+    /// # Arguments
+    /// * `unique` - The constraint.
+    /// # Returns
+    /// * `Result<UniqueIndex, TableControllerError>` - The empty index.
+    pub(super) fn new_unique_index(
+        &self,
+        unique: &Unique,
+    ) -> Result<UniqueIndex, TableControllerError> {
+        Ok(UniqueIndex {
+            name: unique.get_name().clone(),
+            positions: self.column_positions(unique.get_columns())?,
+            keys: ScalableHashTable::default(),
+        })
+    }
+}
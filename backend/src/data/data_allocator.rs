@@ -25,6 +25,9 @@ pub struct DataAllocator {
 
     /// Link to the first free space in memory.
     tail_link: PageLink,
+
+    /// Indexes of whole pages that were freed and can be allocated again.
+    free_pages: Vec<usize>,
 }
 
 impl Default for DataAllocator {
@@ -36,6 +39,7 @@ impl Default for DataAllocator {
             empty_link_registry:
                 BestFitEmptyLinkRegistryFactory::create_empty_link_registry(),
             tail_link: PageLink::new(0, 0, PAGE_SIZE),
+            free_pages: Vec::new(),
         }
     }
 }
@@ -70,7 +74,19 @@ impl DataAllocator {
         }
     }
 
-    /// Allocates memory for data by given size.
+    /// Moves the tail to the start of the next page. The rest of the current
+    /// page is marked as empty.
+    fn skip_to_next_page(&mut self) {
+        if self.tail_link.len > 0 {
+            let rest = self.tail_link;
+            self.remove(rest);
+        }
+        self.tail_link =
+            PageLink::new(self.tail_link.page_index + 1, 0, PAGE_SIZE);
+    }
+
+    /// Allocates memory for data by given size. Data is never split between
+    /// pages, so the size must not exceed [`PAGE_SIZE`].
     /// # Arguments
     /// * `size` - Size of data that should be allocated.
     /// # Returns
@@ -82,8 +98,12 @@ impl DataAllocator {
 
         if let Some(link) = link {
             advisor.apply_place(&link, size);
-            link
+            // The rest of the place is registered as empty by the advisor.
+            PageLink::new(link.page_index, link.start, size)
         } else {
+            if size > self.tail_link.len {
+                self.skip_to_next_page();
+            }
             let link =
                 PageLink::new_from_raw(self.tail_link.get_raw_index(), size);
 
@@ -101,6 +121,29 @@ impl DataAllocator {
         self.get_empty_link_registry().add_link(link);
     }
 
+    /// Allocates a whole page, used for the overflow pages of large rows.
+    /// # Returns
+    /// * `usize` - Index of the allocated page.
+    pub fn allocate_page(&mut self) -> usize {
+        if let Some(index) = self.free_pages.pop() {
+            return index;
+        }
+        if self.tail_link.start != 0 {
+            self.skip_to_next_page();
+        }
+        let index = self.tail_link.page_index;
+        self.tail_link = PageLink::new(index + 1, 0, PAGE_SIZE);
+
+        index
+    }
+
+    /// Marks a page allocated by [`DataAllocator::allocate_page`] as free.
+    /// # Arguments
+    /// * `index` - Index of the page.
+    pub fn remove_page(&mut self, index: usize) {
+        self.free_pages.push(index);
+    }
+
     /// Gets the size of allocated memory.
     /// # Returns
     /// * `u64` - Size of allocated memory.
@@ -153,11 +196,12 @@ mod tests {
 
         let link = data_allocator.allocate(20);
 
-        assert_eq!(link, PageLink::new(0, PAGE_SIZE - 10, 20));
+        assert_eq!(link, PageLink::new(1, 0, 20));
         assert_eq!(
             data_allocator.tail_link,
-            PageLink::new(1, 10, PAGE_SIZE - 10)
+            PageLink::new(1, 20, PAGE_SIZE - 20)
         );
+        assert_eq!(data_allocator.empty_link_registry.len(), 1);
     }
 
     #[test]
@@ -211,4 +255,35 @@ mod tests {
         );
         assert_eq!(data_allocator.empty_link_registry.len(), 0);
     }
+
+    #[test]
+    fn test_data_allocator_add_smaller_after_remove() {
+        let mut data_allocator = DataAllocator::default();
+
+        let link = data_allocator.allocate(10);
+        data_allocator.remove(link);
+
+        let link = data_allocator.allocate(4);
+
+        assert_eq!(link, PageLink::new(0, 0, 4));
+        assert_eq!(data_allocator.empty_link_registry.len(), 1);
+        assert_eq!(data_allocator.allocated_size(), 10);
+    }
+
+    #[test]
+    fn test_data_allocator_allocate_page() {
+        let mut data_allocator = DataAllocator::default();
+
+        assert_eq!(data_allocator.allocate_page(), 0);
+        assert_eq!(data_allocator.allocated_size(), PAGE_SIZE as u64);
+
+        data_allocator.allocate(10);
+        assert_eq!(data_allocator.allocate_page(), 2);
+        assert_eq!(data_allocator.empty_link_registry.len(), 1);
+        assert_eq!(data_allocator.tail_link, PageLink::new(3, 0, PAGE_SIZE));
+
+        data_allocator.remove_page(0);
+        assert_eq!(data_allocator.allocate_page(), 0);
+        assert_eq!(data_allocator.allocate_page(), 3);
+    }
 }
@@ -1,7 +1,22 @@
 use crate::{
-    data::{data_allocator::DataAllocator, id, row_type::RowType},
-    page::page_controller::PageController,
-    schema::r#type::r#enum::{StorageData, StorageDataType},
+    data::{
+        data_allocator::DataAllocator,
+        id,
+        overflow::{
+            OverflowPointer, RecordKind, MAX_INLINE_SIZE, OVERFLOW_HEADER_SIZE,
+            OVERFLOW_PAGE_CAPACITY,
+        },
+        row_type::RowType,
+        schema_version::{SchemaCatalog, SchemaVersion},
+    },
+    page::{
+        link_struct::PageLink, page_controller::PageController,
+        page_struct::Page,
+    },
+    schema::r#type::{
+        data_types::{Text, VarChar},
+        r#enum::{StorageData, StorageDataType},
+    },
 };
 
 use crate::schema::r#type::DataRow;
@@ -29,6 +44,22 @@ pub struct DataStorage {
 
     /// [`RowType`] is used to describe storable data types.
     data_type: RowType,
+
+    /// Marks columns that can store [`StorageData::Null`]. If any column is
+    /// nullable, rows are prefixed with a bitmap of their `NULL` values.
+    nullable: Vec<bool>,
+
+    /// Whether rows are encoded in compact mode, where integers wider than a
+    /// byte are encoded as varints.
+    compact: bool,
+
+    /// Default values of columns, used to fill columns added after a row
+    /// was stored.
+    defaults: Vec<Option<StorageData>>,
+
+    /// [`SchemaCatalog`] of the row formats of stored rows. Rows are
+    /// prefixed with the id of their [`SchemaVersion`].
+    versions: SchemaCatalog,
 }
 
 impl DataStorage {
@@ -40,15 +71,176 @@ impl DataStorage {
         }
 
         for (i, d) in data.iter().enumerate() {
-            if d.data_type() != self.data_type.0[i] {
-                return false;
+            match (d.data_type(), self.data_type.0[i]) {
+                (None, _) if !self.nullable[i] => return false,
+                (
+                    Some(StorageDataType::VarChar(len)),
+                    StorageDataType::VarChar(max),
+                ) if len <= max => {}
+                (
+                    Some(StorageDataType::Decimal(precision, scale)),
+                    StorageDataType::Decimal(max_precision, max_scale),
+                ) if precision <= max_precision && scale == max_scale => {}
+                (Some(data_type), expected) if data_type != expected => {
+                    return false
+                }
+                _ => {}
             }
         }
 
         true
     }
 
-    /// Adds data to the [`DataStorage`].
+    /// Returns the [`SchemaVersion`] of rows added with the current data
+    /// type. [`VarChar`]s are encoded as texts, see
+    /// [`DataStorage::encode_varchars`].
+    ///
+    /// [`VarChar`]: crate::schema::r#type::data_types::VarChar
+    fn current_version(&self) -> SchemaVersion {
+        let row_type = RowType(
+            self.data_type
+                .0
+                .iter()
+                .map(|data_type| match data_type {
+                    StorageDataType::VarChar(_) => StorageDataType::Text,
+                    data_type => *data_type,
+                })
+                .collect(),
+        );
+        let mut descriptor: OutputDescriptor = row_type.into();
+        if self.compact {
+            descriptor = descriptor.compact();
+        }
+
+        SchemaVersion::new(descriptor, self.nullable.clone())
+    }
+
+    /// Replaces [`StorageData::Null`]s of the row with zero values of their
+    /// columns, as rows are encoded using fixed column sizes.
+    /// # Returns
+    /// * `Vec<u8>` - The bitmap of replaced values.
+    fn take_nulls(
+        &self,
+        data: &mut [StorageData],
+        bitmap_len: usize,
+    ) -> Vec<u8> {
+        let mut bitmap = vec![0; bitmap_len];
+        for (i, (d, data_type)) in
+            data.iter_mut().zip(self.data_type.0.iter()).enumerate()
+        {
+            if *d == StorageData::Null {
+                bitmap[i / 8] |= 1 << (i % 8);
+                *d = data_type.zero_value();
+            }
+        }
+
+        bitmap
+    }
+
+    /// Restores [`StorageData::Null`]s replaced by
+    /// [`DataStorage::take_nulls`].
+    fn restore_nulls(bitmap: &[u8], data: &mut [StorageData]) {
+        for (i, d) in data.iter_mut().enumerate() {
+            if bitmap[i / 8] & (1 << (i % 8)) != 0 {
+                *d = StorageData::Null;
+            }
+        }
+    }
+
+    /// Rounds [`Decimal`]s of the row to the scale of their columns.
+    /// Decimals that don't fit their columns are kept, so that the row fails
+    /// the type check.
+    ///
+    /// [`Decimal`]: crate::schema::r#type::data_types::Decimal
+    pub(crate) fn fit_row(&self, data: &mut [StorageData]) {
+        for (d, data_type) in data.iter_mut().zip(self.data_type.0.iter()) {
+            if let (
+                StorageData::Decimal(value),
+                StorageDataType::Decimal(precision, scale),
+            ) = (d, data_type)
+            {
+                if let Some(fitted) = value.fit(*precision, *scale) {
+                    *value = fitted;
+                }
+            }
+        }
+    }
+
+    /// Replaces [`VarChar`]s of the row with texts, which are encoded with
+    /// their length, so the values are read back exactly.
+    ///
+    /// [`VarChar`]: crate::schema::r#type::data_types::VarChar
+    fn encode_varchars(data: &mut [StorageData]) {
+        for d in data.iter_mut() {
+            if let StorageData::VarChar(value) = d {
+                *d = Text(std::mem::take(&mut value.value)).into();
+            }
+        }
+    }
+
+    /// Fills columns added after the row was stored with their default values
+    /// or `NULL`.
+    /// # Returns
+    /// * `Result<(), DataStorageError>` - Result of the operation.
+    /// # Errors
+    /// * `DataStorageError::TypeMismatch` - Stored columns don't match the
+    ///   current data type.
+    /// * `DataStorageError::NoDefaultValue` - An added column is `NOT NULL`
+    ///   without a default value.
+    fn upcast_row(
+        &self,
+        data: &mut Vec<StorageData>,
+    ) -> Result<(), DataStorageError> {
+        if data.len() > self.data_type.len() {
+            return Err(DataStorageError::TypeMismatch);
+        }
+
+        for i in data.len()..self.data_type.len() {
+            let value = match &self.defaults[i] {
+                Some(default) => default.clone(),
+                None if self.nullable[i] => StorageData::Null,
+                None => return Err(DataStorageError::NoDefaultValue),
+            };
+            data.push(value);
+        }
+
+        if !self.check_data_type(data) {
+            return Err(DataStorageError::TypeMismatch);
+        }
+
+        Ok(())
+    }
+
+    /// Restores [`VarChar`]s replaced by [`DataStorage::encode_varchars`].
+    /// # Returns
+    /// * `Result<(), DataStorageError>` - Result of the operation.
+    /// # Errors
+    /// * `DataStorageError::DataDecodeError` - A text doesn't fit the
+    ///   `VarChar` of its column.
+    ///
+    /// [`VarChar`]: crate::schema::r#type::data_types::VarChar
+    fn decode_varchars(
+        &self,
+        data: &mut [StorageData],
+    ) -> Result<(), DataStorageError> {
+        for (d, data_type) in data.iter_mut().zip(self.data_type.0.iter()) {
+            if let (StorageData::Text(value), StorageDataType::VarChar(len)) =
+                (&mut *d, data_type)
+            {
+                if value.0.len() > *len {
+                    return Err(DataStorageError::DataDecodeError);
+                }
+                *d = VarChar::try_from(std::mem::take(&mut value.0))
+                    .map_err(|_| DataStorageError::DataDecodeError)?
+                    .into();
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Adds data to the [`DataStorage`]. The row is prefixed with the id of
+    /// the current [`SchemaVersion`].
     /// # Arguments
     /// * `data` - Data to add.
     /// # Returns
@@ -57,38 +249,194 @@ impl DataStorage {
         &mut self,
         data: DataRow,
     ) -> Result<id::NumericId, DataStorageError> {
-        let mut encoder = StorageEncoder::new();
-        let data = data.0;
+        let mut encoder = if self.compact {
+            StorageEncoder::compact()
+        } else {
+            StorageEncoder::new()
+        };
+        let mut data = data.0;
 
+        self.fit_row(&mut data);
         if !self.check_data_type(&data) {
             return Err(DataStorageError::TypeMismatch);
         }
+        let version = self.current_version();
+        let bitmap = self.take_nulls(&mut data, version.null_bitmap_len());
+        Self::encode_varchars(&mut data);
+
+        let mut bytes =
+            SchemaCatalog::encode_id(self.versions.register(version));
+        bytes.extend(bitmap);
 
         let _ = data
             .iter()
             .map(|d| encoder.emit::<StorageData>(d.clone()))
             .collect::<Vec<_>>();
-        let bytes = encoder.output.get_bytes();
-        let len = bytes.len();
+        bytes.extend(encoder.output.get_bytes());
 
-        let link = self.data_allocator.allocate(len as u16);
+        let link = self.write_record(bytes);
         let id = self.id_registry.add_link(link);
 
+        Ok(id)
+    }
+
+    /// Writes the record of the row to a page. Rows larger than
+    /// [`MAX_INLINE_SIZE`] are written to overflow pages and the record
+    /// points to them.
+    /// # Arguments
+    /// * `row` - The encoded row.
+    /// # Returns
+    /// * `PageLink` - [`PageLink`] of the record.
+    fn write_record(&mut self, row: Vec<u8>) -> PageLink {
+        let mut record = Vec::with_capacity(1 + row.len());
+        if row.len() <= MAX_INLINE_SIZE {
+            record.push(RecordKind::Inline as u8);
+            record.extend(row);
+        } else {
+            let pointer = self.write_overflow(&row);
+            record.push(RecordKind::Overflow as u8);
+            record.extend(<[u8; OverflowPointer::SIZE]>::from(pointer));
+        }
+
+        let link = self.data_allocator.allocate(record.len() as u16);
+        let page = self.page_controller.get_or_add_page(link.page_index);
+        let _ = page.update_data(&record, link);
+
+        link
+    }
+
+    /// Writes the row to a chain of overflow pages.
+    /// # Arguments
+    /// * `row` - The encoded row.
+    /// # Returns
+    /// * `OverflowPointer` - Pointer to the chain.
+    fn write_overflow(&mut self, row: &[u8]) -> OverflowPointer {
+        let chunks = row.chunks(OVERFLOW_PAGE_CAPACITY).collect::<Vec<_>>();
+        let pages = chunks
+            .iter()
+            .map(|_| self.data_allocator.allocate_page())
+            .collect::<Vec<_>>();
+
+        for (i, chunk) in chunks.iter().enumerate() {
+            let next = pages.get(i + 1).copied().unwrap_or(0);
+            let mut bytes = next.to_be_bytes().to_vec();
+            bytes.extend_from_slice(chunk);
+
+            let link = PageLink::new(pages[i], 0, bytes.len() as u16);
+            let page = self.page_controller.get_or_add_page(pages[i]);
+            let _ = page.update_data(&bytes, link);
+        }
+
+        OverflowPointer {
+            len: row.len() as u32,
+            first_page: pages[0],
+        }
+    }
+
+    /// Gets the page with the given index and checks its checksum.
+    /// # Errors
+    /// * `DataStorageError::PageNotFound` - The page doesn't exist.
+    /// * `DataStorageError::Corruption` - The page doesn't match its
+    ///   checksum.
+    fn get_verified_page(
+        &mut self,
+        index: usize,
+    ) -> Result<&Page, DataStorageError> {
+        if index >= self.page_controller.get_page_count() {
+            return Err(DataStorageError::PageNotFound);
+        }
+        let page = self.page_controller.get_page(index);
+        if !page.verify() {
+            return Err(DataStorageError::Corruption(index));
+        }
+
+        Ok(page)
+    }
+
+    /// Reads the [`OverflowPointer`] of the record, if the row of the record
+    /// is stored in overflow pages.
+    /// # Errors
+    /// * `DataStorageError::DataDecodeError` - The record is malformed.
+    fn read_overflow_pointer(
+        record: &[u8],
+    ) -> Result<Option<OverflowPointer>, DataStorageError> {
+        let (kind, record) = record
+            .split_first()
+            .ok_or(DataStorageError::DataDecodeError)?;
+
+        match RecordKind::try_from(*kind)
+            .map_err(|_| DataStorageError::DataDecodeError)?
         {
-            let page = self.page_controller.get_page(link.page_index);
-            let _ = page.update_data(bytes.as_ref(), link);
+            RecordKind::Inline => Ok(None),
+            RecordKind::Overflow => {
+                let bytes: [u8; OverflowPointer::SIZE] = record
+                    .try_into()
+                    .map_err(|_| DataStorageError::DataDecodeError)?;
+                Ok(Some(OverflowPointer::from(bytes)))
+            }
+        }
+    }
+
+    /// Returns the indexes of the pages of the overflow chain.
+    fn get_overflow_pages(
+        &mut self,
+        pointer: OverflowPointer,
+    ) -> Result<Vec<usize>, DataStorageError> {
+        let mut pages = Vec::with_capacity(pointer.page_count());
+        let mut index = pointer.first_page;
+        for _ in 0..pointer.page_count() {
+            pages.push(index);
+            let page = self.get_verified_page(index)?;
+            let link = PageLink::new(index, 0, OVERFLOW_HEADER_SIZE as u16);
+            index = usize::from_be_bytes(
+                page.get_by_link(link).try_into().expect("header size"),
+            );
+        }
+
+        Ok(pages)
+    }
+
+    /// Reads the encoded row of the record at the link, reassembling it from
+    /// overflow pages if needed.
+    /// # Errors
+    /// * `DataStorageError::PageNotFound` - A page of the row doesn't exist.
+    /// * `DataStorageError::Corruption` - A page of the row doesn't match its
+    ///   checksum.
+    /// * `DataStorageError::DataDecodeError` - The record is malformed.
+    fn read_record(
+        &mut self,
+        link: PageLink,
+    ) -> Result<Vec<u8>, DataStorageError> {
+        let record = self.get_verified_page(link.page_index)?.get_by_link(link);
+        let Some(pointer) = Self::read_overflow_pointer(record)? else {
+            return Ok(record[1..].to_vec());
         };
 
-        Ok(id)
+        let mut row = Vec::with_capacity(pointer.len as usize);
+        for index in self.get_overflow_pages(pointer)? {
+            let len =
+                (pointer.len as usize - row.len()).min(OVERFLOW_PAGE_CAPACITY);
+            let link =
+                PageLink::new(index, 0, (OVERFLOW_HEADER_SIZE + len) as u16);
+            let page = self.get_verified_page(index)?;
+            row.extend_from_slice(
+                &page.get_by_link(link)[OVERFLOW_HEADER_SIZE..],
+            );
+        }
+
+        Ok(row)
     }
 
-    /// Removes data from the [`DataStorage`].
+    /// Removes data from the [`DataStorage`]. Overflow pages of the row are
+    /// freed.
     /// # Arguments
     /// * `id` - [`NumericId`] of the data.
     /// # Returns
     /// * `Result<(), DataStorageError>` - Result of the operation.
     /// # Errors
     /// * `DataStorageError::IdNotFound` - [`NumericId`] was not found.
+    /// * `DataStorageError::Corruption` - A page of the row doesn't match its
+    ///   checksum.
     pub fn remove_data(
         &mut self,
         id: id::NumericId,
@@ -96,6 +444,13 @@ impl DataStorage {
         let link = self.id_registry.get_link(id);
 
         if let Some(link) = link {
+            let record =
+                self.get_verified_page(link.page_index)?.get_by_link(link);
+            if let Some(pointer) = Self::read_overflow_pointer(record)? {
+                for index in self.get_overflow_pages(pointer)? {
+                    self.data_allocator.remove_page(index);
+                }
+            }
             self.data_allocator.remove(link);
             let res = self.id_registry.remove_id(id);
             if res.is_err() {
@@ -128,6 +483,20 @@ impl DataStorage {
         Ok(())
     }
 
+    /// Gets data from the [`DataStorage`]. Rows stored in overflow pages are
+    /// reassembled, rows stored with an older [`SchemaVersion`] are decoded
+    /// with it and upcast to the current columns.
+    /// # Arguments
+    /// * `id` - [`NumericId`] of the data.
+    /// # Returns
+    /// * `Result<DataRow, DataStorageError>` - The data.
+    /// # Errors
+    /// * `DataStorageError::LinkNotFound` - [`NumericId`] was not found.
+    /// * `DataStorageError::Corruption` - A page of the row doesn't match its
+    ///   checksum.
+    /// * `DataStorageError::UnknownSchemaVersion` - The row's version isn't in
+    ///   the catalog.
+    /// * `DataStorageError::DataDecodeError` - The row can't be decoded.
     pub fn get_data(
         &mut self,
         id: id::NumericId,
@@ -135,17 +504,30 @@ impl DataStorage {
         let link = self.id_registry.get_link(id);
 
         if let Some(link) = link {
-            let mut page = self.page_controller.get_page(link.page_index);
-            let descriptor: OutputDescriptor = self.data_type.clone().into();
-            let data = page.get_by_link(link);
+            let data = self.read_record(link)?;
+
+            let (version, len) = SchemaCatalog::decode_id(&data)
+                .ok_or(DataStorageError::DataDecodeError)?;
+            let version = self
+                .versions
+                .get(version)
+                .ok_or(DataStorageError::UnknownSchemaVersion)?;
+            let (bitmap, data) = data[len..]
+                .split_at_checked(version.null_bitmap_len())
+                .ok_or(DataStorageError::DataDecodeError)?;
 
             let mut decoder = StorageDecoder;
-            let data = decoder
+            let mut data = decoder
                 .emit_with_descriptor::<DataRow>(
                     data.to_vec(),
-                    descriptor.get_descriptor_bytes(),
+                    version.get_descriptor().get_descriptor_bytes(),
                 )
                 .map_err(|_| DataStorageError::DataDecodeError)?;
+            self.decode_varchars(&mut data.0)?;
+            if !bitmap.is_empty() {
+                Self::restore_nulls(bitmap, &mut data.0);
+            }
+            self.upcast_row(&mut data.0)?;
 
             Ok(data)
         } else {
@@ -164,14 +546,78 @@ impl DataStorage {
     /// # Arguments
     /// * `data_type` - Data type to set.
     pub fn set_data_type(&mut self, data_type: Vec<StorageDataType>) {
+        self.nullable = vec![false; data_type.len()];
+        self.defaults = vec![None; data_type.len()];
         self.data_type = RowType(data_type);
     }
 
+    /// Sets whether rows of the [`DataStorage`] are encoded in compact mode,
+    /// where small integers take less bytes. It must be set before any data
+    /// is added, as stored rows are not re-encoded.
+    /// # Arguments
+    /// * `compact` - Whether to use compact mode.
+    pub fn set_compact(&mut self, compact: bool) {
+        self.compact = compact;
+    }
+
+    /// Returns true if rows are encoded in compact mode.
+    pub fn is_compact(&self) -> bool {
+        self.compact
+    }
+
+    /// Sets the default value of the column, that is used for rows stored
+    /// before the column was added.
+    /// # Arguments
+    /// * `column` - Index of the column.
+    /// * `default` - Default value of the column.
+    pub fn set_default(&mut self, column: usize, default: Option<StorageData>) {
+        if let Some(value) = self.defaults.get_mut(column) {
+            *value = default;
+        }
+    }
+
+    /// Returns the number of [`SchemaVersion`]s of stored rows.
+    pub fn get_schema_version_count(&self) -> usize {
+        self.versions.len()
+    }
+
+    /// Checks the checksums of all pages of the [`DataStorage`].
+    /// # Returns
+    /// * `Vec<usize>` - Indexes of the corrupted pages.
+    pub fn verify_pages(&self) -> Vec<usize> {
+        self.page_controller.verify()
+    }
+
+    /// Corrupts a byte of the page with the given index.
+    #[cfg(test)]
+    pub(crate) fn corrupt_page(&mut self, index: usize) {
+        self.page_controller.get_page(index).corrupt(0);
+    }
+
+    /// Gets the size of the memory allocated for rows.
+    /// # Returns
+    /// * `u64` - Size of allocated memory.
+    pub fn allocated_size(&self) -> u64 {
+        self.data_allocator.allocated_size()
+    }
+
     /// Appends data type to the [`DataStorage`].
     /// # Arguments
     /// * `data_type` - Data type to append.
     pub fn append_data_type(&mut self, data_type: StorageDataType) {
         self.data_type.0.push(data_type);
+        self.nullable.push(false);
+        self.defaults.push(None);
+    }
+
+    /// Appends data type of a column that can store [`StorageData::Null`]
+    /// to the [`DataStorage`].
+    /// # Arguments
+    /// * `data_type` - Data type to append.
+    pub fn append_nullable_data_type(&mut self, data_type: StorageDataType) {
+        self.data_type.0.push(data_type);
+        self.nullable.push(true);
+        self.defaults.push(None);
     }
 }
 
@@ -183,15 +629,24 @@ pub enum DataStorageError {
     DataLengthMismatch,
     TypeMismatch,
     DataDecodeError,
+    UnknownSchemaVersion,
+    /// A `NOT NULL` column added after a row was stored has no default
+    /// value.
+    NoDefaultValue,
+    /// The page with the given index doesn't match its checksum.
+    Corruption(usize),
 }
 
 #[cfg(test)]
 mod tests {
     use crate::{
-        data::{data_storage::DataStorage, id},
+        data::{
+            data_storage::{DataStorage, DataStorageError},
+            id,
+        },
         page::page_controller::PageController,
         schema::r#type::{
-            data_types::Integer,
+            data_types::{Blob, Decimal, Integer, Long, Text, VarChar},
             r#enum::{StorageData, StorageDataType},
             DataRow,
         },
@@ -269,7 +724,7 @@ mod tests {
         assert!(id.is_ok());
         let id = id.unwrap();
         assert_eq!(data_storage.page_controller.get_page_count(), 1);
-        assert_eq!(data_storage.data_allocator.allocated_size(), 4);
+        assert_eq!(data_storage.data_allocator.allocated_size(), 6);
         assert_eq!(data_storage.id_registry.get_id_count(), 1);
         assert_eq!(id.0, 1);
     }
@@ -290,7 +745,7 @@ mod tests {
         let res = data_storage.remove_data(id);
 
         assert_eq!(data_storage.page_controller.get_page_count(), 1);
-        assert_eq!(data_storage.data_allocator.allocated_size(), 4);
+        assert_eq!(data_storage.data_allocator.allocated_size(), 6);
         assert_eq!(data_storage.id_registry.get_id_count(), 0);
         assert!(res.is_ok());
     }
@@ -312,7 +767,7 @@ mod tests {
         let res = data_storage.remove_data(id);
 
         assert_eq!(data_storage.page_controller.get_page_count(), 1);
-        assert_eq!(data_storage.data_allocator.allocated_size(), 4);
+        assert_eq!(data_storage.data_allocator.allocated_size(), 6);
         assert_eq!(data_storage.id_registry.get_id_count(), 0);
         assert!(res.is_err());
     }
@@ -333,7 +788,7 @@ mod tests {
         let res = data_storage.remove_data(id);
 
         assert_eq!(data_storage.page_controller.get_page_count(), 1);
-        assert_eq!(data_storage.data_allocator.allocated_size(), 4);
+        assert_eq!(data_storage.data_allocator.allocated_size(), 6);
         assert_eq!(data_storage.id_registry.get_id_count(), 0);
         assert!(res.is_ok());
 
@@ -343,7 +798,7 @@ mod tests {
         assert!(id.is_ok());
         let id = id.unwrap();
         assert_eq!(data_storage.page_controller.get_page_count(), 1);
-        assert_eq!(data_storage.data_allocator.allocated_size(), 4);
+        assert_eq!(data_storage.data_allocator.allocated_size(), 6);
         assert_eq!(data_storage.id_registry.get_id_count(), 1);
         assert_eq!(id.0, 1);
     }
@@ -366,7 +821,7 @@ mod tests {
             data_storage.update_data(id, DataRow(vec![updated_data.into()]));
 
         assert_eq!(data_storage.page_controller.get_page_count(), 1);
-        assert_eq!(data_storage.data_allocator.allocated_size(), 4);
+        assert_eq!(data_storage.data_allocator.allocated_size(), 6);
         assert_eq!(data_storage.id_registry.get_id_count(), 1);
         assert!(res.is_ok());
     }
@@ -401,4 +856,269 @@ mod tests {
         assert_eq!(data.0[1], StorageData::Byte(1.into()));
         assert_eq!(data.0[2], StorageData::UInteger(321.into()));
     }
+
+    #[test]
+    fn test_data_storage_get_data_varchar_and_long() {
+        let mut data_storage = DataStorage::default();
+        data_storage.set_data_type(vec![
+            StorageDataType::VarChar(10),
+            StorageDataType::Long,
+        ]);
+
+        let data_row = DataRow(vec![
+            StorageData::VarChar(VarChar::new("test".to_string()).unwrap()),
+            StorageData::Long(Long(-5)),
+        ]);
+        let id = data_storage.add_data(data_row.clone()).unwrap();
+
+        assert_eq!(data_storage.get_data(id).unwrap(), data_row);
+
+        // Trailing `NUL`s are kept, as the length is stored with the value.
+        let data_row = DataRow(vec![
+            StorageData::VarChar(VarChar::new("a\0".to_string()).unwrap()),
+            StorageData::Long(Long(0)),
+        ]);
+        let id = data_storage.add_data(data_row.clone()).unwrap();
+
+        assert_eq!(data_storage.get_data(id).unwrap(), data_row);
+    }
+
+    #[test]
+    fn test_data_storage_add_data_varchar_too_long() {
+        let mut data_storage = DataStorage::default();
+        data_storage.set_data_type(vec![StorageDataType::VarChar(2)]);
+
+        let data_row = DataRow(vec![StorageData::VarChar(
+            VarChar::new("test".to_string()).unwrap(),
+        )]);
+
+        assert!(matches!(
+            data_storage.add_data(data_row),
+            Err(DataStorageError::TypeMismatch)
+        ));
+    }
+
+    #[test]
+    fn test_data_storage_get_data_with_nulls() {
+        let mut data_storage = DataStorage::default();
+        data_storage.append_data_type(StorageDataType::Integer);
+        data_storage.append_nullable_data_type(StorageDataType::VarChar(4));
+        data_storage.append_nullable_data_type(StorageDataType::Long);
+
+        let data_row = DataRow(vec![
+            StorageData::Integer(1.into()),
+            StorageData::Null,
+            StorageData::Long(Long(7)),
+        ]);
+        let id = data_storage.add_data(data_row.clone()).unwrap();
+        assert_eq!(data_storage.get_data(id).unwrap(), data_row);
+
+        let data_row = DataRow(vec![
+            StorageData::Null,
+            StorageData::Null,
+            StorageData::Null,
+        ]);
+        assert!(matches!(
+            data_storage.add_data(data_row),
+            Err(DataStorageError::TypeMismatch)
+        ));
+    }
+
+    #[test]
+    fn test_data_storage_compact() {
+        let mut data_storage = DataStorage::default();
+        data_storage.set_compact(true);
+        data_storage.set_data_type(vec![
+            StorageDataType::Long,
+            StorageDataType::VarChar(4),
+        ]);
+        data_storage.append_nullable_data_type(StorageDataType::UInteger);
+        assert!(data_storage.is_compact());
+
+        let data_row = DataRow(vec![
+            StorageData::Long((-3).into()),
+            StorageData::VarChar(VarChar::new("ab".to_string()).unwrap()),
+            StorageData::Null,
+        ]);
+        let id = data_storage.add_data(data_row.clone()).unwrap();
+
+        // Record kind, version, bitmap, `Long`, length-prefixed `VarChar`
+        // and zero `UInteger`.
+        assert_eq!(data_storage.allocated_size(), 1 + 1 + 1 + 1 + 1 + 2 + 1);
+
+        let large = DataRow(vec![
+            StorageData::Long(i128::MAX.into()),
+            StorageData::VarChar(VarChar::new("abcd".to_string()).unwrap()),
+            StorageData::UInteger(u32::MAX.into()),
+        ]);
+        let large_id = data_storage.add_data(large.clone()).unwrap();
+
+        assert_eq!(data_storage.get_data(id).unwrap(), data_row);
+        assert_eq!(data_storage.get_data(large_id).unwrap(), large);
+
+        data_storage.remove_data(large_id).unwrap();
+        let id = data_storage.add_data(data_row.clone()).unwrap();
+        assert_eq!(data_storage.get_data(id).unwrap(), data_row);
+    }
+
+    #[test]
+    fn test_data_storage_schema_versions() {
+        let mut data_storage = DataStorage::default();
+        data_storage.set_data_type(vec![StorageDataType::Integer]);
+
+        let old = data_storage
+            .add_data(DataRow(vec![Integer(1).into()]))
+            .unwrap();
+        data_storage
+            .add_data(DataRow(vec![Integer(2).into()]))
+            .unwrap();
+        assert_eq!(data_storage.get_schema_version_count(), 1);
+
+        data_storage.append_nullable_data_type(StorageDataType::VarChar(4));
+        data_storage.append_data_type(StorageDataType::Long);
+        data_storage.append_data_type(StorageDataType::Integer);
+        data_storage.set_default(3, Some(Integer(7).into()));
+
+        let new_row = DataRow(vec![
+            Integer(3).into(),
+            StorageData::Null,
+            Long(4).into(),
+            Integer(5).into(),
+        ]);
+        let new = data_storage.add_data(new_row.clone()).unwrap();
+        assert_eq!(data_storage.get_schema_version_count(), 2);
+
+        assert!(matches!(
+            data_storage.get_data(old),
+            Err(DataStorageError::NoDefaultValue)
+        ));
+        data_storage.set_default(2, Some(Long(0).into()));
+        assert_eq!(
+            data_storage.get_data(old).unwrap(),
+            DataRow(vec![
+                Integer(1).into(),
+                StorageData::Null,
+                Long(0).into(),
+                Integer(7).into(),
+            ])
+        );
+        assert_eq!(data_storage.get_data(new).unwrap(), new_row);
+    }
+
+    #[test]
+    fn test_data_storage_schema_version_mismatch() {
+        let mut data_storage = DataStorage::default();
+        data_storage.set_data_type(vec![StorageDataType::Integer]);
+        let id = data_storage
+            .add_data(DataRow(vec![Integer(1).into()]))
+            .unwrap();
+
+        data_storage.set_data_type(vec![StorageDataType::Long]);
+
+        let res = data_storage.get_data(id);
+        assert!(matches!(res, Err(DataStorageError::TypeMismatch)));
+    }
+
+    #[test]
+    fn test_data_storage_corruption() {
+        let mut data_storage = DataStorage::default();
+        data_storage.set_data_type(vec![StorageDataType::Integer]);
+        let id = data_storage
+            .add_data(DataRow(vec![Integer(1).into()]))
+            .unwrap();
+        assert!(data_storage.verify_pages().is_empty());
+
+        data_storage.corrupt_page(0);
+
+        assert_eq!(data_storage.verify_pages(), vec![0]);
+        let res = data_storage.get_data(id);
+        assert!(matches!(res, Err(DataStorageError::Corruption(0))));
+    }
+
+    fn large_row(columns: usize, value: char) -> DataRow {
+        let value = std::iter::repeat_n(value, 255).collect::<String>();
+        DataRow(vec![
+            StorageData::VarChar(VarChar::new(value).unwrap());
+            columns
+        ])
+    }
+
+    #[test]
+    fn test_data_storage_overflow() {
+        let mut data_storage = DataStorage::default();
+        data_storage.set_data_type(vec![StorageDataType::VarChar(255); 20]);
+
+        let first = data_storage.add_data(large_row(20, 'a')).unwrap();
+        let second = data_storage.add_data(large_row(20, 'b')).unwrap();
+
+        // Both rows take two overflow pages, their records share page 2.
+        assert_eq!(data_storage.page_controller.get_page_count(), 5);
+        assert_eq!(data_storage.get_data(first).unwrap(), large_row(20, 'a'));
+        assert_eq!(data_storage.get_data(second).unwrap(), large_row(20, 'b'));
+
+        data_storage.remove_data(first).unwrap();
+        let id = data_storage.add_data(large_row(20, 'c')).unwrap();
+
+        assert_eq!(data_storage.page_controller.get_page_count(), 5);
+        assert_eq!(data_storage.get_data(id).unwrap(), large_row(20, 'c'));
+        assert_eq!(data_storage.get_data(second).unwrap(), large_row(20, 'b'));
+    }
+
+    #[test]
+    fn test_data_storage_overflow_corruption() {
+        let mut data_storage = DataStorage::default();
+        data_storage.set_data_type(vec![StorageDataType::VarChar(255); 10]);
+        let id = data_storage.add_data(large_row(10, 'a')).unwrap();
+
+        data_storage.corrupt_page(0);
+
+        assert_eq!(data_storage.verify_pages(), vec![0]);
+        let res = data_storage.get_data(id);
+        assert!(matches!(res, Err(DataStorageError::Corruption(0))));
+    }
+
+    #[test]
+    fn test_data_storage_text_and_blob() {
+        let mut data_storage = DataStorage::default();
+        data_storage
+            .set_data_type(vec![StorageDataType::Text, StorageDataType::Blob]);
+        data_storage.append_nullable_data_type(StorageDataType::Text);
+
+        let short = DataRow(vec![
+            Text("hi".to_string()).into(),
+            Blob(vec![0xde, 0xad, 0xbe, 0xef]).into(),
+            StorageData::Null,
+        ]);
+        let long = DataRow(vec![
+            Text("a".repeat(10_000)).into(),
+            Blob(vec![]).into(),
+            Text("b".to_string()).into(),
+        ]);
+        let short_id = data_storage.add_data(short.clone()).unwrap();
+        let long_id = data_storage.add_data(long.clone()).unwrap();
+
+        // The long row takes three overflow pages.
+        assert_eq!(data_storage.page_controller.get_page_count(), 4);
+        assert_eq!(data_storage.get_data(short_id).unwrap(), short);
+        assert_eq!(data_storage.get_data(long_id).unwrap(), long);
+    }
+
+    #[test]
+    fn test_data_storage_decimal() {
+        let mut data_storage = DataStorage::default();
+        data_storage.set_data_type(vec![StorageDataType::Decimal(5, 2)]);
+
+        let decimal = |value: &str| value.parse::<Decimal>().unwrap();
+        let id = data_storage
+            .add_data(DataRow(vec![decimal("-123.456").into()]))
+            .unwrap();
+        let data = data_storage.get_data(id).unwrap();
+        let StorageData::Decimal(value) = data.0[0] else {
+            panic!("decimal column holds decimals");
+        };
+        assert_eq!(value.to_string(), "-123.46");
+
+        let res = data_storage.add_data(DataRow(vec![decimal("1000").into()]));
+        assert!(matches!(res, Err(DataStorageError::TypeMismatch)));
+    }
 }
@@ -1,9 +1,11 @@
 mod link_storage;
 pub mod numeric_id_generator;
 pub mod registry;
+pub mod sequence;
 
 pub use numeric_id_generator::NumericId;
 pub use registry::Registry;
+pub use sequence::Sequence;
 
 pub trait IdGenerator<Id> {
     /// Creates a new [`IdGenerator`].
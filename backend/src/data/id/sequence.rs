@@ -0,0 +1,136 @@
+//! Contains the [`Sequence`] type definition.
+
+/// Monotonic generator of integer values for `AUTO_INCREMENT` columns and
+/// standalone sequences.
+///
+/// Unlike [`NumericIdGenerator`], a [`Sequence`] never reuses values, even
+/// if the rows they were generated for are removed.
+///
+/// [`NumericIdGenerator`]: super::numeric_id_generator::NumericIdGenerator
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sequence {
+    /// The first value of the sequence.
+    start: i128,
+
+    /// The largest value of the sequence, e.g. the largest value of the
+    /// type of its `AUTO_INCREMENT` column.
+    max: i128,
+
+    /// The last generated or observed value, `None` before the first one.
+    last: Option<i128>,
+}
+
+impl Default for Sequence {
+    fn default() -> Self {
+        Sequence::new(1)
+    }
+}
+
+impl Sequence {
+    /// Creates a new [`Sequence`].
+    /// # Arguments
+    /// * `start` - The first value of the sequence.
+    /// # Returns
+    /// A new [`Sequence`] that starts with the given value.
+    pub fn new(start: i128) -> Self {
+        Sequence {
+            start,
+            max: i128::MAX,
+            last: None,
+        }
+    }
+
+    /// Sets the largest value of the sequence, it's exhausted after it.
+    /// # Arguments
+    /// * `max` - The largest value.
+    /// # Returns
+    /// The sequence that never generates values larger than `max`.
+    pub fn with_max(mut self, max: i128) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// Generates the next value, the same as `nextval()`.
+    /// # Returns
+    /// * `Option<i128>` - The next value, or `None` if the sequence is
+    ///   exhausted, in which case it's not advanced.
+    pub fn next_value(&mut self) -> Option<i128> {
+        let value = match self.last {
+            Some(last) => last.checked_add(1)?,
+            None => self.start,
+        };
+        if value > self.max {
+            return None;
+        }
+        self.last = Some(value);
+
+        Some(value)
+    }
+
+    /// Returns the last generated value, the same as `currval()`.
+    /// # Returns
+    /// * `Option<i128>` - The last value, or `None` if no value was generated
+    ///   yet.
+    pub fn current_value(&self) -> Option<i128> {
+        self.last
+    }
+
+    /// Observes a value that was provided instead of a generated one, so the
+    /// sequence continues after it and never generates it later.
+    /// # Arguments
+    /// * `value` - The provided value.
+    pub fn observe(&mut self, value: i128) {
+        let is_ahead = match self.last {
+            Some(last) => value > last,
+            None => value >= self.start,
+        };
+        if is_ahead {
+            self.last = Some(value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::data::id::sequence::Sequence;
+
+    #[test]
+    fn test_next_value() {
+        let mut sequence = Sequence::default();
+        assert_eq!(sequence.current_value(), None);
+
+        assert_eq!(sequence.next_value(), Some(1));
+        assert_eq!(sequence.next_value(), Some(2));
+        assert_eq!(sequence.current_value(), Some(2));
+
+        let mut sequence = Sequence::new(i128::MAX);
+        assert_eq!(sequence.next_value(), Some(i128::MAX));
+        assert_eq!(sequence.next_value(), None);
+        assert_eq!(sequence.current_value(), Some(i128::MAX));
+    }
+
+    #[test]
+    fn test_next_value_with_max() {
+        let mut sequence = Sequence::new(254).with_max(255);
+        assert_eq!(sequence.next_value(), Some(254));
+        assert_eq!(sequence.next_value(), Some(255));
+        assert_eq!(sequence.next_value(), None);
+        assert_eq!(sequence.next_value(), None);
+        assert_eq!(sequence.current_value(), Some(255));
+
+        let mut sequence = Sequence::new(10).with_max(5);
+        assert_eq!(sequence.next_value(), None);
+        assert_eq!(sequence.current_value(), None);
+    }
+
+    #[test]
+    fn test_observe() {
+        let mut sequence = Sequence::new(10);
+        sequence.observe(5);
+        assert_eq!(sequence.next_value(), Some(10));
+
+        sequence.observe(20);
+        sequence.observe(15);
+        assert_eq!(sequence.next_value(), Some(21));
+    }
+}
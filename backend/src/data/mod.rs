@@ -2,14 +2,16 @@ pub mod advisors;
 pub mod data_allocator;
 pub mod data_storage;
 pub mod id;
+pub mod overflow;
 pub mod row_type;
+pub mod schema_version;
 
 use crate::schema::{column, r#type::DataRow};
 use common::structs::hash_table::{
     r#static::StaticHashTable, HashTable, VecFunctions,
 };
 
-use crate::schema::r#type::r#enum::StorageData;
+use crate::schema::r#type::r#enum::StorageDataType;
 
 /// Type of data unit. It represents a column row data using a hash table.
 /// Key is the name of the column and value is the data.
@@ -18,6 +20,13 @@ pub struct DataUnit {
     /// Hash table to map column name to data column index.
     indexes: StaticHashTable<column::Name, usize>,
 
+    /// Column names in the order of [`DataRow`] values.
+    names: Vec<column::Name>,
+
+    /// Column types in the order of [`DataRow`] values, `None` if the type
+    /// of a column is unknown, e.g. of a `NULL` literal.
+    types: Vec<Option<StorageDataType>>,
+
     /// Data storage.
     data: Vec<DataRow>,
 }
@@ -42,10 +51,23 @@ impl DataUnit {
 
         Self {
             indexes,
+            types: vec![None; names.len()],
+            names,
             data: vec![],
         }
     }
 
+    /// Sets the types of the columns.
+    /// # Arguments
+    /// * `types` - The column types in the order of the names, `None` if the
+    ///   type of a column is unknown.
+    /// # Returns
+    /// * `DataUnit` - The data unit with the column types.
+    pub fn with_types(mut self, types: Vec<Option<StorageDataType>>) -> Self {
+        self.types = types;
+        self
+    }
+
     /// Inserts a new data row to the hash table.
     /// # Arguments
     /// * `row` - The data row to insert.
@@ -64,6 +86,21 @@ impl DataUnit {
         self.indexes.get(key)
     }
 
+    /// Returns the column names of the data unit.
+    /// # Returns
+    /// * `&[column::Name]` - The column names in the order of values.
+    pub fn get_names(&self) -> &[column::Name] {
+        &self.names
+    }
+
+    /// Returns the column types of the data unit.
+    /// # Returns
+    /// * `&[Option<StorageDataType>]` - The column types in the order of
+    ///   values, `None` if the type of a column is unknown.
+    pub fn get_types(&self) -> &[Option<StorageDataType>] {
+        &self.types
+    }
+
     /// Returns the values of the data unit.
     /// # Returns
     /// * `Vec<DataRow>` - The values of the data unit.
@@ -102,6 +139,8 @@ mod tests {
 
         let res = data_unit.get_index(&"test2".into());
         assert!(res.is_none());
+        assert_eq!(data_unit.get_names(), &["test".into()]);
+        assert_eq!(data_unit.get_types(), &[None]);
 
         let vals = data_unit.get_values();
         assert_eq!(vals.len(), 1);
@@ -0,0 +1,120 @@
+//! Defines the layout of rows that don't fit into a page. The row is written
+//! to a chain of overflow pages and the page of the row stores an
+//! [`OverflowPointer`] to the chain instead.
+//!
+//! Every overflow page starts with the index of the next page of the chain,
+//! the rest of the page is a chunk of the row. The last page doesn't have a
+//! next page, so its index is zero.
+
+use common::base::cast::usize::USIZE_SIZE;
+
+use crate::page::page_struct::PAGE_SIZE;
+
+/// Maximum size of a row that is stored in the page of the row, larger rows
+/// are moved to overflow pages.
+pub const MAX_INLINE_SIZE: usize = PAGE_SIZE as usize / 4;
+
+/// Size of the header of an overflow page.
+pub const OVERFLOW_HEADER_SIZE: usize = USIZE_SIZE;
+
+/// Number of bytes of a row stored in a single overflow page.
+pub const OVERFLOW_PAGE_CAPACITY: usize =
+    PAGE_SIZE as usize - OVERFLOW_HEADER_SIZE;
+
+/// Kind of a record stored in the page of a row.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(u8)]
+pub enum RecordKind {
+    /// The record is the row.
+    Inline = 0,
+
+    /// The record is an [`OverflowPointer`] to the row.
+    Overflow = 1,
+}
+
+impl TryFrom<u8> for RecordKind {
+    type Error = ();
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(RecordKind::Inline),
+            1 => Ok(RecordKind::Overflow),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Pointer to a chain of overflow pages that store a row.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OverflowPointer {
+    /// Length of the row.
+    pub len: u32,
+
+    /// Index of the first page of the chain.
+    pub first_page: usize,
+}
+
+impl OverflowPointer {
+    /// Size of the encoded pointer.
+    pub const SIZE: usize = 4 + USIZE_SIZE;
+
+    /// Returns the number of pages of the chain.
+    pub fn page_count(&self) -> usize {
+        (self.len as usize).div_ceil(OVERFLOW_PAGE_CAPACITY)
+    }
+}
+
+impl From<[u8; OverflowPointer::SIZE]> for OverflowPointer {
+    fn from(bytes: [u8; OverflowPointer::SIZE]) -> Self {
+        let len = u32::from_be_bytes(bytes[0..4].try_into().unwrap());
+        let first_page = usize::from_be_bytes(
+            bytes[4..OverflowPointer::SIZE].try_into().unwrap(),
+        );
+        OverflowPointer { len, first_page }
+    }
+}
+
+impl From<OverflowPointer> for [u8; OverflowPointer::SIZE] {
+    fn from(val: OverflowPointer) -> Self {
+        let mut bytes = [0; OverflowPointer::SIZE];
+        bytes[0..4].copy_from_slice(&val.len.to_be_bytes());
+        bytes[4..OverflowPointer::SIZE]
+            .copy_from_slice(&val.first_page.to_be_bytes());
+        bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{OverflowPointer, RecordKind, OVERFLOW_PAGE_CAPACITY};
+
+    #[test]
+    fn test_overflow_pointer_bytes() {
+        let pointer = OverflowPointer {
+            len: 10_000,
+            first_page: 3,
+        };
+        let bytes: [u8; OverflowPointer::SIZE] = pointer.into();
+
+        assert_eq!(OverflowPointer::from(bytes), pointer);
+    }
+
+    #[test]
+    fn test_overflow_pointer_page_count() {
+        let pointer = |len: usize| OverflowPointer {
+            len: len as u32,
+            first_page: 0,
+        };
+
+        assert_eq!(pointer(1).page_count(), 1);
+        assert_eq!(pointer(OVERFLOW_PAGE_CAPACITY).page_count(), 1);
+        assert_eq!(pointer(OVERFLOW_PAGE_CAPACITY + 1).page_count(), 2);
+    }
+
+    #[test]
+    fn test_record_kind() {
+        assert_eq!(RecordKind::try_from(0), Ok(RecordKind::Inline));
+        assert_eq!(RecordKind::try_from(1), Ok(RecordKind::Overflow));
+        assert_eq!(RecordKind::try_from(2), Err(()));
+    }
+}
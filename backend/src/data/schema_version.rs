@@ -0,0 +1,144 @@
+//! Defines [`SchemaCatalog`], the catalog of the row formats used by a
+//! [`DataStorage`] over time.
+//!
+//! [`DataStorage`]: crate::data::data_storage::DataStorage
+
+use serde_storage::{ser::encoder::OutputDescriptor, varint};
+
+/// Row format of a version of the [`DataStorage`] schema.
+///
+/// [`DataStorage`]: crate::data::data_storage::DataStorage
+#[derive(Debug, Clone)]
+pub struct SchemaVersion {
+    /// Descriptor of the encoded columns.
+    descriptor: OutputDescriptor,
+
+    /// Marks columns that can store `NULL`, they define the length of the
+    /// bitmap that prefixes rows.
+    nullable: Vec<bool>,
+}
+
+impl SchemaVersion {
+    /// Creates a new [`SchemaVersion`].
+    /// # Arguments
+    /// * `descriptor` - Descriptor of the encoded columns.
+    /// * `nullable` - Marks columns that can store `NULL`.
+    pub fn new(descriptor: OutputDescriptor, nullable: Vec<bool>) -> Self {
+        Self {
+            descriptor,
+            nullable,
+        }
+    }
+
+    /// Returns the descriptor of the encoded columns.
+    pub fn get_descriptor(&self) -> &OutputDescriptor {
+        &self.descriptor
+    }
+
+    /// Returns the length of the `NULL` bitmap that prefixes rows.
+    pub fn null_bitmap_len(&self) -> usize {
+        if self.nullable.contains(&true) {
+            self.nullable.len().div_ceil(8)
+        } else {
+            0
+        }
+    }
+}
+
+impl PartialEq for SchemaVersion {
+    fn eq(&self, other: &Self) -> bool {
+        self.nullable == other.nullable
+            && self.descriptor.get_descriptor_bytes()
+                == other.descriptor.get_descriptor_bytes()
+    }
+}
+
+/// Catalog of [`SchemaVersion`]s, where a version id is the index of the
+/// version. Rows are prefixed with the id of their version, so they can be
+/// decoded after the schema changes.
+#[derive(Debug, Clone, Default)]
+pub struct SchemaCatalog {
+    /// Registered versions.
+    versions: Vec<SchemaVersion>,
+}
+
+impl SchemaCatalog {
+    /// Registers the version if it differs from the latest one.
+    /// # Arguments
+    /// * `version` - The current version of the schema.
+    /// # Returns
+    /// * `u32` - Id of the version.
+    pub fn register(&mut self, version: SchemaVersion) -> u32 {
+        if self.versions.last() != Some(&version) {
+            self.versions.push(version);
+        }
+
+        (self.versions.len() - 1) as u32
+    }
+
+    /// Returns the version with the given id.
+    pub fn get(&self, id: u32) -> Option<&SchemaVersion> {
+        self.versions.get(id as usize)
+    }
+
+    /// Returns the number of registered versions.
+    pub fn len(&self) -> usize {
+        self.versions.len()
+    }
+
+    /// Returns true if no version is registered.
+    pub fn is_empty(&self) -> bool {
+        self.versions.is_empty()
+    }
+
+    /// Returns the bytes of the version id that prefix a row.
+    pub fn encode_id(id: u32) -> Vec<u8> {
+        varint::encode_unsigned(id as u128)
+    }
+
+    /// Reads the version id that prefixes the row.
+    /// # Returns
+    /// * `Some((u32, usize))` - The id and the number of its bytes.
+    /// * `None` - If the row doesn't start with a valid id.
+    pub fn decode_id(row: &[u8]) -> Option<(u32, usize)> {
+        let (id, len) = varint::decode_unsigned(row)?;
+
+        Some((id.try_into().ok()?, len))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_storage::ser::encoder::output_descriptor::DescriptorBytes;
+
+    fn version(bytes: Vec<u8>, nullable: Vec<bool>) -> SchemaVersion {
+        let descriptor = DescriptorBytes(bytes).try_into().unwrap();
+        SchemaVersion::new(descriptor, nullable)
+    }
+
+    #[test]
+    fn test_register() {
+        let mut catalog = SchemaCatalog::default();
+        assert!(catalog.is_empty());
+
+        assert_eq!(catalog.register(version(vec![13], vec![false])), 0);
+        assert_eq!(catalog.register(version(vec![13], vec![false])), 0);
+        assert_eq!(catalog.register(version(vec![13], vec![true])), 1);
+        assert_eq!(catalog.register(version(vec![13, 3], vec![true; 2])), 2);
+
+        assert_eq!(catalog.len(), 3);
+        assert_eq!(catalog.get(1).unwrap().null_bitmap_len(), 1);
+        assert_eq!(catalog.get(0).unwrap().null_bitmap_len(), 0);
+        assert!(catalog.get(3).is_none());
+    }
+
+    #[test]
+    fn test_encode_id() {
+        let bytes = SchemaCatalog::encode_id(300);
+        assert_eq!(bytes.len(), 2);
+        assert_eq!(SchemaCatalog::decode_id(&bytes), Some((300, 2)));
+        assert_eq!(SchemaCatalog::encode_id(0), vec![0]);
+        assert_eq!(SchemaCatalog::decode_id(&[]), None);
+    }
+}
@@ -0,0 +1,1593 @@
+//! Evaluation of [`Expression`]s and the type rules it follows. Integer
+//! operands of different types are promoted to a type holding both, e.g.
+//! `u8 + i32` is `i32`, integers with decimals are decimals and anything
+//! with floats is a float. Operators and functions of a `NULL` operand
+//! return `NULL`, except for `AND`, `OR`, `IS NULL` and `COALESCE`.
+
+use std::cmp::Ordering;
+
+use derive_more::Display;
+
+use crate::{
+    expression::{BinaryOperator, Expression, Function, UnaryOperator},
+    schema::{
+        column,
+        r#type::{
+            data_types::{
+                Blob, Date, Decimal, Interval, Text, Time, Timestamp, Uuid,
+                VarChar,
+            },
+            decimal::{Rounding, MAX_PRECISION},
+            r#enum::{StorageData, StorageDataType},
+            temporal::MICROS_PER_DAY,
+        },
+    },
+};
+
+/// The smallest scale of a decimal quotient.
+const DIVISION_SCALE: u8 = 6;
+
+impl Expression {
+    /// Evaluates the expression over the values of a row.
+    /// # Arguments
+    /// * `value` - Returns the row's value of the column with the given name.
+    /// # Returns
+    /// * `Ok(StorageData)` - The value of the expression.
+    /// * `Err(EvaluationError)` - If an operator or a function isn't defined
+    ///   for the types of its operands, or a value is out of range.
+    pub fn evaluate<'a, F>(
+        &self,
+        value: F,
+    ) -> Result<StorageData, EvaluationError>
+    where
+        F: Fn(&column::Name) -> Option<&'a StorageData>,
+    {
+        self.evaluate_with(&value)
+    }
+
+    /// Evaluates the expression as a condition, e.g. of `WHERE`.
+    /// # Arguments
+    /// * `value` - Returns the row's value of the column with the given name.
+    /// # Returns
+    /// * `Ok(bool)` - True if the expression is `TRUE`, false if it is
+    ///   `FALSE` or `NULL`.
+    /// * `Err(EvaluationError)` - If the evaluation fails or the expression
+    ///   isn't a `bool`.
+    pub fn matches<'a, F>(&self, value: F) -> Result<bool, EvaluationError>
+    where
+        F: Fn(&column::Name) -> Option<&'a StorageData>,
+    {
+        Ok(truth_value(self.evaluate_with(&value)?)? == Some(true))
+    }
+
+    /// Returns the data type of the expression's values without evaluating
+    /// it, e.g. to check the expression before any row is read.
+    /// # Arguments
+    /// * `column_type` - Returns the data type of the column with the given
+    ///   name.
+    /// # Returns
+    /// * `Ok(Some(StorageDataType))` - The data type of the values.
+    /// * `Ok(None)` - If the type is unknown as the expression is `NULL`.
+    /// * `Err(EvaluationError)` - If an operator or a function isn't defined
+    ///   for the types of its operands.
+    pub fn data_type<F>(
+        &self,
+        column_type: F,
+    ) -> Result<Option<StorageDataType>, EvaluationError>
+    where
+        F: Fn(&column::Name) -> Option<StorageDataType>,
+    {
+        self.data_type_with(&column_type)
+    }
+
+    fn evaluate_with<'a, F>(
+        &self,
+        value: &F,
+    ) -> Result<StorageData, EvaluationError>
+    where
+        F: Fn(&column::Name) -> Option<&'a StorageData>,
+    {
+        match self {
+            Expression::Literal(literal) => Ok(literal.clone()),
+            Expression::Column(name) => value(name).cloned().ok_or_else(|| {
+                EvaluationError::ColumnDoesNotExist(name.clone())
+            }),
+            Expression::Unary { operator, operand } => {
+                unary(*operator, operand.evaluate_with(value)?)
+            }
+            Expression::Binary {
+                left,
+                operator: operator @ (BinaryOperator::And | BinaryOperator::Or),
+                right,
+            } => {
+                // The value that decides the result regardless of the other
+                // operand, `FALSE` for `AND` and `TRUE` for `OR`.
+                let decisive = *operator == BinaryOperator::Or;
+                let left = truth_value(left.evaluate_with(value)?)?;
+                if left == Some(decisive) {
+                    return Ok(decisive.into());
+                }
+
+                let right = truth_value(right.evaluate_with(value)?)?;
+                Ok(match (left, right) {
+                    (_, Some(right)) if right == decisive => decisive.into(),
+                    (Some(_), Some(_)) => (!decisive).into(),
+                    _ => StorageData::Null,
+                })
+            }
+            Expression::Binary {
+                left,
+                operator,
+                right,
+            } => binary(
+                *operator,
+                left.evaluate_with(value)?,
+                right.evaluate_with(value)?,
+            ),
+            Expression::IsNull { operand, negated } => {
+                let is_null =
+                    operand.evaluate_with(value)? == StorageData::Null;
+                Ok((is_null != *negated).into())
+            }
+            Expression::Function {
+                function,
+                arguments,
+            } => call(*function, arguments, value),
+            Expression::Case {
+                operand,
+                branches,
+                otherwise,
+            } => {
+                let operand = operand
+                    .as_ref()
+                    .map(|operand| operand.evaluate_with(value))
+                    .transpose()?;
+                for (condition, result) in branches {
+                    let condition = condition.evaluate_with(value)?;
+                    let is_match = match &operand {
+                        Some(operand) => {
+                            let equal = binary(
+                                BinaryOperator::Equal,
+                                operand.clone(),
+                                condition,
+                            )?;
+                            truth_value(equal)?
+                        }
+                        None => truth_value(condition)?,
+                    };
+                    if is_match == Some(true) {
+                        return result.evaluate_with(value);
+                    }
+                }
+
+                otherwise
+                    .as_ref()
+                    .map_or(Ok(StorageData::Null), |otherwise| {
+                        otherwise.evaluate_with(value)
+                    })
+            }
+            Expression::Cast { operand, data_type } => {
+                cast(operand.evaluate_with(value)?, data_type)
+            }
+        }
+    }
+
+    fn data_type_with<F>(
+        &self,
+        column_type: &F,
+    ) -> Result<Option<StorageDataType>, EvaluationError>
+    where
+        F: Fn(&column::Name) -> Option<StorageDataType>,
+    {
+        match self {
+            Expression::Literal(literal) => Ok(literal.data_type()),
+            Expression::Column(name) => {
+                column_type(name).map(Some).ok_or_else(|| {
+                    EvaluationError::ColumnDoesNotExist(name.clone())
+                })
+            }
+            Expression::Unary { operator, operand } => operand
+                .data_type_with(column_type)?
+                .map(|data_type| unary_type(*operator, &data_type))
+                .transpose(),
+            Expression::Binary {
+                left,
+                operator,
+                right,
+            } => {
+                let left = left.data_type_with(column_type)?;
+                let right = right.data_type_with(column_type)?;
+                match (left, right) {
+                    (Some(left), Some(right)) => {
+                        binary_type(*operator, &left, &right).map(Some)
+                    }
+                    (Some(data_type), None) | (None, Some(data_type)) => {
+                        binary_type(*operator, &data_type, &data_type).map(Some)
+                    }
+                    (None, None) => Ok((operator.is_comparison()
+                        || matches!(
+                            operator,
+                            BinaryOperator::And | BinaryOperator::Or
+                        ))
+                    .then_some(StorageDataType::Bool)),
+                }
+            }
+            Expression::IsNull { operand, .. } => {
+                operand.data_type_with(column_type)?;
+                Ok(Some(StorageDataType::Bool))
+            }
+            Expression::Function {
+                function,
+                arguments,
+            } => {
+                let arguments = arguments
+                    .iter()
+                    .map(|argument| argument.data_type_with(column_type))
+                    .collect::<Result<Vec<_>, _>>()?;
+                function_type(*function, &arguments)
+            }
+            Expression::Case {
+                operand,
+                branches,
+                otherwise,
+            } => {
+                let operand = match operand {
+                    Some(operand) => operand.data_type_with(column_type)?,
+                    None => None,
+                };
+                let mut result_type = None;
+                for (condition, result) in branches {
+                    let condition = condition.data_type_with(column_type)?;
+                    match (&operand, condition) {
+                        (Some(operand), Some(condition)) => {
+                            binary_type(
+                                BinaryOperator::Equal,
+                                operand,
+                                &condition,
+                            )?;
+                        }
+                        (None, Some(condition))
+                            if condition != StorageDataType::Bool =>
+                        {
+                            return Err(EvaluationError::NotBoolean(condition));
+                        }
+                        _ => {}
+                    }
+                    let result = result.data_type_with(column_type)?;
+                    result_type = common_type(result_type, result)?;
+                }
+                if let Some(otherwise) = otherwise {
+                    let otherwise = otherwise.data_type_with(column_type)?;
+                    result_type = common_type(result_type, otherwise)?;
+                }
+
+                Ok(result_type)
+            }
+            Expression::Cast { operand, data_type } => {
+                if let Some(from) = operand.data_type_with(column_type)? {
+                    cast_type(&from, data_type)?;
+                }
+                Ok(Some(*data_type))
+            }
+        }
+    }
+}
+
+/// Errors that can occur during the evaluation of an [`Expression`].
+#[derive(Debug, Display, Clone, PartialEq)]
+pub enum EvaluationError {
+    /// The column doesn't exist.
+    #[display(fmt = "Column `{}` does not exist", _0)]
+    ColumnDoesNotExist(column::Name),
+
+    /// The unary operator isn't defined for the type of its operand.
+    #[display(fmt = "Operator `{}` is not defined for `{}`", _0, _1)]
+    UndefinedUnaryOperator(UnaryOperator, StorageDataType),
+
+    /// The binary operator isn't defined for the types of its operands.
+    #[display(
+        fmt = "Operator `{}` is not defined for `{}` and `{}`",
+        _0,
+        _1,
+        _2
+    )]
+    UndefinedBinaryOperator(BinaryOperator, StorageDataType, StorageDataType),
+
+    /// A condition or an operand of `AND`, `OR` or `NOT` isn't a `bool`.
+    #[display(fmt = "Expected `bool`, found `{}`", _0)]
+    NotBoolean(StorageDataType),
+
+    /// The function isn't defined for the type of its argument.
+    #[display(fmt = "Function `{}` is not defined for `{}`", _0, _1)]
+    UndefinedFunction(Function, StorageDataType),
+
+    /// The function can't take the number of arguments.
+    #[display(fmt = "Function `{}` can't take {} arguments", _0, _1)]
+    WrongArgumentCount(Function, usize),
+
+    /// Results of `CASE` or arguments of `COALESCE` have types without a
+    /// common one.
+    #[display(fmt = "Types `{}` and `{}` can't be matched", _0, _1)]
+    IncompatibleTypes(StorageDataType, StorageDataType),
+
+    /// Values of the type can never be cast to the other type.
+    #[display(fmt = "`{}` can't be cast to `{}`", _0, _1)]
+    UndefinedCast(StorageDataType, StorageDataType),
+
+    /// The value can't be cast to the type, e.g. `'abc'` to `i32`.
+    #[display(fmt = "Value {} can't be cast to `{}`", _0, _1)]
+    InvalidCast(StorageData, StorageDataType),
+
+    /// The result is out of the range of its type.
+    #[display(fmt = "Value is out of range")]
+    Overflow,
+
+    /// The divisor of `/` or `%` is zero.
+    #[display(fmt = "Division by zero")]
+    DivisionByZero,
+
+    /// `NEXTVAL` is evaluated without the sequences, its argument isn't a
+    /// literal name or it's used where no sequences are provided, see
+    /// [`Expression::with_next_values`].
+    #[display(fmt = "Sequence {} is not available here", _0)]
+    SequenceNotAvailable(Expression),
+}
+
+/// A numeric value of any numeric type.
+#[derive(Debug, Clone, Copy)]
+enum Number {
+    /// A value of an integer type.
+    Integer(i128),
+
+    /// A value of `f32` or `f64`.
+    Float(f64),
+
+    /// A decimal value.
+    Decimal(Decimal),
+}
+
+impl Number {
+    /// Returns the numeric value of the data, if it is numeric.
+    fn from_data(value: &StorageData) -> Option<Self> {
+        match value {
+            StorageData::Float(value) => Some(Number::Float(value.0.into())),
+            StorageData::Double(value) => Some(Number::Float(value.0)),
+            StorageData::Decimal(value) => Some(Number::Decimal(*value)),
+            _ => value.as_integer().map(Number::Integer),
+        }
+    }
+
+    fn to_f64(self) -> f64 {
+        match self {
+            Number::Integer(value) => value as f64,
+            Number::Float(value) => value,
+            Number::Decimal(value) => {
+                value.value() as f64 / 10f64.powi(value.scale().into())
+            }
+        }
+    }
+
+    fn to_decimal(self) -> Option<Decimal> {
+        match self {
+            Number::Integer(value) => Decimal::new(value, 0),
+            Number::Float(value) => value.to_string().parse().ok(),
+            Number::Decimal(value) => Some(value),
+        }
+    }
+
+    fn compare(self, other: Number) -> Option<Ordering> {
+        match (self, other) {
+            (Number::Integer(left), Number::Integer(right)) => {
+                Some(left.cmp(&right))
+            }
+            (Number::Float(_), _) | (_, Number::Float(_)) => {
+                self.to_f64().partial_cmp(&other.to_f64())
+            }
+            _ => match (self.to_decimal(), other.to_decimal()) {
+                (Some(left), Some(right)) => Some(left.cmp(&right)),
+                _ => self.to_f64().partial_cmp(&other.to_f64()),
+            },
+        }
+    }
+}
+
+fn is_numeric(data_type: &StorageDataType) -> bool {
+    data_type.is_integer()
+        || matches!(
+            data_type,
+            StorageDataType::Float
+                | StorageDataType::Double
+                | StorageDataType::Decimal(_, _)
+        )
+}
+
+fn is_string(data_type: &StorageDataType) -> bool {
+    matches!(
+        data_type,
+        StorageDataType::VarChar(_) | StorageDataType::Text
+    )
+}
+
+fn as_str(value: &StorageData) -> Option<&str> {
+    match value {
+        StorageData::VarChar(value) => Some(&value.value),
+        StorageData::Text(value) => Some(&value.0),
+        _ => None,
+    }
+}
+
+/// Returns the truth value of a condition, `None` for `NULL`.
+fn truth_value(value: StorageData) -> Result<Option<bool>, EvaluationError> {
+    match value {
+        StorageData::Bool(value) => Ok(Some(value.0)),
+        StorageData::Null => Ok(None),
+        value => Err(EvaluationError::NotBoolean(
+            value.data_type().expect("isn't null"),
+        )),
+    }
+}
+
+/// Returns the signedness and the number of bits of an integer type.
+fn integer_bits(data_type: &StorageDataType) -> (bool, u8) {
+    match data_type {
+        StorageDataType::Byte => (false, 8),
+        StorageDataType::UShort => (false, 16),
+        StorageDataType::UInteger => (false, 32),
+        StorageDataType::ULong => (false, 128),
+        StorageDataType::Short => (true, 16),
+        StorageDataType::Integer => (true, 32),
+        _ => (true, 128),
+    }
+}
+
+/// Returns the smallest integer type holding values of both integer types.
+/// Only `i128` holds `u128` values and `i128` ones, so it is used for them
+/// and the results out of its range overflow.
+fn common_integer_type(
+    left: &StorageDataType,
+    right: &StorageDataType,
+) -> StorageDataType {
+    let (left_signed, left_bits) = integer_bits(left);
+    let (right_signed, right_bits) = integer_bits(right);
+    if left_signed == right_signed {
+        return if left_bits >= right_bits {
+            *left
+        } else {
+            *right
+        };
+    }
+
+    let (signed_bits, unsigned_bits) = if left_signed {
+        (left_bits, right_bits)
+    } else {
+        (right_bits, left_bits)
+    };
+    [StorageDataType::Short, StorageDataType::Integer]
+        .into_iter()
+        .find(|data_type| {
+            let bits = integer_bits(data_type).1;
+            bits > unsigned_bits && bits >= signed_bits
+        })
+        .unwrap_or(StorageDataType::Long)
+}
+
+/// Returns the scale of the result of decimal arithmetic.
+fn decimal_scale(operator: BinaryOperator, left: u8, right: u8) -> u8 {
+    match operator {
+        BinaryOperator::Multiply => left.saturating_add(right),
+        BinaryOperator::Divide => left.max(right).max(DIVISION_SCALE),
+        _ => left.max(right),
+    }
+    .min(MAX_PRECISION)
+}
+
+/// Returns the type of arithmetic on numbers of the types.
+fn numeric_type(
+    operator: BinaryOperator,
+    left: &StorageDataType,
+    right: &StorageDataType,
+) -> Option<StorageDataType> {
+    if !is_numeric(left) || !is_numeric(right) {
+        return None;
+    }
+
+    let data_type = match (left, right) {
+        _ if left.is_integer() && right.is_integer() => {
+            common_integer_type(left, right)
+        }
+        (StorageDataType::Float, StorageDataType::Float) => {
+            StorageDataType::Float
+        }
+        (StorageDataType::Float | StorageDataType::Double, _)
+        | (_, StorageDataType::Float | StorageDataType::Double) => {
+            StorageDataType::Double
+        }
+        (
+            StorageDataType::Decimal(_, left_scale),
+            StorageDataType::Decimal(_, right_scale),
+        ) => StorageDataType::Decimal(
+            MAX_PRECISION,
+            decimal_scale(operator, *left_scale, *right_scale),
+        ),
+        (StorageDataType::Decimal(_, scale), _) => StorageDataType::Decimal(
+            MAX_PRECISION,
+            decimal_scale(operator, *scale, 0),
+        ),
+        (_, StorageDataType::Decimal(_, scale)) => StorageDataType::Decimal(
+            MAX_PRECISION,
+            decimal_scale(operator, 0, *scale),
+        ),
+        _ => return None,
+    };
+
+    Some(data_type)
+}
+
+/// Returns the type of arithmetic on temporal values of the types.
+fn temporal_type(
+    operator: BinaryOperator,
+    left: &StorageDataType,
+    right: &StorageDataType,
+) -> Option<StorageDataType> {
+    use BinaryOperator::{Add, Subtract};
+    use StorageDataType::{Date, Interval, Time, Timestamp};
+
+    let data_type = match (operator, left, right) {
+        (Add, Date | Timestamp, Interval)
+        | (Add, Interval, Date | Timestamp)
+        | (Subtract, Date | Timestamp, Interval) => Timestamp,
+        (Add, Time, Interval)
+        | (Add, Interval, Time)
+        | (Subtract, Time, Interval) => Time,
+        (Subtract, Date, Date)
+        | (Subtract, Timestamp, Timestamp)
+        | (Subtract, Time, Time)
+        | (Add | Subtract, Interval, Interval) => Interval,
+        _ => return None,
+    };
+
+    Some(data_type)
+}
+
+/// Checks if values of the types can be compared.
+fn is_comparable(left: &StorageDataType, right: &StorageDataType) -> bool {
+    (is_numeric(left) && is_numeric(right))
+        || (is_string(left) && is_string(right))
+        || std::mem::discriminant(left) == std::mem::discriminant(right)
+}
+
+/// Returns the type of a binary operator's result.
+pub(crate) fn binary_type(
+    operator: BinaryOperator,
+    left: &StorageDataType,
+    right: &StorageDataType,
+) -> Result<StorageDataType, EvaluationError> {
+    let undefined =
+        || EvaluationError::UndefinedBinaryOperator(operator, *left, *right);
+
+    match operator {
+        BinaryOperator::And | BinaryOperator::Or => {
+            match [left, right]
+                .into_iter()
+                .find(|data_type| **data_type != StorageDataType::Bool)
+            {
+                Some(data_type) => Err(EvaluationError::NotBoolean(*data_type)),
+                None => Ok(StorageDataType::Bool),
+            }
+        }
+        _ if operator.is_comparison() => is_comparable(left, right)
+            .then_some(StorageDataType::Bool)
+            .ok_or_else(undefined),
+        BinaryOperator::Concat => (is_string(left) || is_string(right))
+            .then_some(StorageDataType::Text)
+            .ok_or_else(undefined),
+        _ => numeric_type(operator, left, right)
+            .or_else(|| temporal_type(operator, left, right))
+            .ok_or_else(undefined),
+    }
+}
+
+/// Returns the type of a unary operator's result. Negated unsigned integers
+/// are signed.
+fn unary_type(
+    operator: UnaryOperator,
+    data_type: &StorageDataType,
+) -> Result<StorageDataType, EvaluationError> {
+    match (operator, data_type) {
+        (UnaryOperator::Not, StorageDataType::Bool) => Ok(*data_type),
+        (UnaryOperator::Not, _) => Err(EvaluationError::NotBoolean(*data_type)),
+        (UnaryOperator::Minus, _) if data_type.is_integer() => {
+            Ok(common_integer_type(data_type, &StorageDataType::Short))
+        }
+        (
+            UnaryOperator::Minus,
+            StorageDataType::Float
+            | StorageDataType::Double
+            | StorageDataType::Decimal(_, _)
+            | StorageDataType::Interval,
+        ) => Ok(*data_type),
+        (UnaryOperator::Minus, _) => Err(
+            EvaluationError::UndefinedUnaryOperator(operator, *data_type),
+        ),
+    }
+}
+
+/// Returns the type both types are converted to if they are alternative
+/// results, e.g. of `CASE`.
+fn common_type(
+    left: Option<StorageDataType>,
+    right: Option<StorageDataType>,
+) -> Result<Option<StorageDataType>, EvaluationError> {
+    let (Some(left), Some(right)) = (left, right) else {
+        return Ok(left.or(right));
+    };
+
+    if left == right {
+        Ok(Some(left))
+    } else if let Some(data_type) =
+        numeric_type(BinaryOperator::Add, &left, &right)
+    {
+        Ok(Some(data_type))
+    } else if is_string(&left) && is_string(&right) {
+        Ok(Some(StorageDataType::Text))
+    } else {
+        Err(EvaluationError::IncompatibleTypes(left, right))
+    }
+}
+
+/// Returns the type of a function's result.
+fn function_type(
+    function: Function,
+    arguments: &[Option<StorageDataType>],
+) -> Result<Option<StorageDataType>, EvaluationError> {
+    if !function.accepts_arguments(arguments.len()) {
+        return Err(EvaluationError::WrongArgumentCount(
+            function,
+            arguments.len(),
+        ));
+    }
+
+    match function {
+        Function::Coalesce => {
+            arguments.iter().try_fold(None, |data_type, argument| {
+                common_type(data_type, *argument)
+            })
+        }
+        Function::NullIf => {
+            if let [Some(left), Some(right)] = arguments {
+                binary_type(BinaryOperator::Equal, left, right)?;
+            }
+            Ok(arguments[0])
+        }
+        _ => {
+            let Some(data_type) = arguments[0] else {
+                return Ok(None);
+            };
+            let result = match function {
+                Function::Abs if is_numeric(&data_type) => data_type,
+                Function::Length if is_string(&data_type) => {
+                    StorageDataType::Integer
+                }
+                Function::Lower | Function::Upper | Function::Trim
+                    if is_string(&data_type) =>
+                {
+                    StorageDataType::Text
+                }
+                Function::NextVal if is_string(&data_type) => {
+                    StorageDataType::Long
+                }
+                _ => {
+                    return Err(EvaluationError::UndefinedFunction(
+                        function, data_type,
+                    ))
+                }
+            };
+            Ok(Some(result))
+        }
+    }
+}
+
+/// Checks if values of the type may be cast to the other type.
+fn cast_type(
+    from: &StorageDataType,
+    to: &StorageDataType,
+) -> Result<(), EvaluationError> {
+    let is_defined = from == to
+        || is_string(from)
+        || is_string(to)
+        || (is_numeric(from) && is_numeric(to))
+        || (*from == StorageDataType::Bool && to.is_integer())
+        || (from.is_integer() && *to == StorageDataType::Bool)
+        || matches!(
+            (from, to),
+            (StorageDataType::Date, StorageDataType::Timestamp)
+                | (StorageDataType::Timestamp, StorageDataType::Date)
+        );
+
+    if is_defined {
+        Ok(())
+    } else {
+        Err(EvaluationError::UndefinedCast(*from, *to))
+    }
+}
+
+fn unary(
+    operator: UnaryOperator,
+    operand: StorageData,
+) -> Result<StorageData, EvaluationError> {
+    let Some(data_type) = operand.data_type() else {
+        return Ok(StorageData::Null);
+    };
+    let result_type = unary_type(operator, &data_type)?;
+
+    match operand {
+        StorageData::Bool(value) => Ok((!value.0).into()),
+        StorageData::Float(value) => Ok((-value.0).into()),
+        StorageData::Double(value) => Ok((-value.0).into()),
+        StorageData::Decimal(value) => value
+            .checked_neg()
+            .map(Into::into)
+            .ok_or(EvaluationError::Overflow),
+        StorageData::Interval(value) => value
+            .checked_neg()
+            .map(Into::into)
+            .ok_or(EvaluationError::Overflow),
+        operand => operand
+            .as_integer()
+            .and_then(i128::checked_neg)
+            .and_then(|value| result_type.integer_value(value))
+            .ok_or(EvaluationError::Overflow),
+    }
+}
+
+/// Applies the operator to the values, as `left operator right`.
+pub(crate) fn binary(
+    operator: BinaryOperator,
+    left: StorageData,
+    right: StorageData,
+) -> Result<StorageData, EvaluationError> {
+    let (Some(left_type), Some(right_type)) =
+        (left.data_type(), right.data_type())
+    else {
+        return Ok(StorageData::Null);
+    };
+    let result_type = binary_type(operator, &left_type, &right_type)?;
+
+    if operator.is_comparison() {
+        let ordering = compare(&left, &right);
+        let result = match (operator, ordering) {
+            (BinaryOperator::NotEqual, ordering) => {
+                ordering != Some(Ordering::Equal)
+            }
+            (_, None) => false,
+            (BinaryOperator::Equal, Some(ordering)) => ordering.is_eq(),
+            (BinaryOperator::LessThan, Some(ordering)) => ordering.is_lt(),
+            (BinaryOperator::LessThanOrEqual, Some(ordering)) => {
+                ordering.is_le()
+            }
+            (BinaryOperator::GreaterThan, Some(ordering)) => ordering.is_gt(),
+            (_, Some(ordering)) => ordering.is_ge(),
+        };
+        return Ok(result.into());
+    }
+
+    match operator {
+        BinaryOperator::Concat => Ok(StorageData::Text(Text(format!(
+            "{}{}",
+            to_text(&left),
+            to_text(&right)
+        )))),
+        _ if is_numeric(&result_type) => {
+            arithmetic(operator, &result_type, &left, &right)
+        }
+        _ => temporal_arithmetic(operator, &left, &right)
+            .ok_or(EvaluationError::Overflow),
+    }
+}
+
+/// Compares values of comparable types, numbers of different types are
+/// compared by their values.
+pub(crate) fn compare(
+    left: &StorageData,
+    right: &StorageData,
+) -> Option<Ordering> {
+    if let (Some(left), Some(right)) =
+        (Number::from_data(left), Number::from_data(right))
+    {
+        return left.compare(right);
+    }
+    if let (Some(left), Some(right)) = (as_str(left), as_str(right)) {
+        return Some(left.cmp(right));
+    }
+
+    left.partial_cmp(right)
+}
+
+fn arithmetic(
+    operator: BinaryOperator,
+    result_type: &StorageDataType,
+    left: &StorageData,
+    right: &StorageData,
+) -> Result<StorageData, EvaluationError> {
+    let (Some(left), Some(right)) =
+        (Number::from_data(left), Number::from_data(right))
+    else {
+        return Err(EvaluationError::Overflow);
+    };
+    let is_division =
+        matches!(operator, BinaryOperator::Divide | BinaryOperator::Modulo);
+
+    match result_type {
+        StorageDataType::Float | StorageDataType::Double => {
+            let (left, right) = (left.to_f64(), right.to_f64());
+            if is_division && right == 0.0 {
+                return Err(EvaluationError::DivisionByZero);
+            }
+            let value = match operator {
+                BinaryOperator::Add => left + right,
+                BinaryOperator::Subtract => left - right,
+                BinaryOperator::Multiply => left * right,
+                BinaryOperator::Divide => left / right,
+                _ => left % right,
+            };
+
+            if *result_type == StorageDataType::Float {
+                Ok((value as f32).into())
+            } else {
+                Ok(value.into())
+            }
+        }
+        StorageDataType::Decimal(_, scale) => {
+            let (Some(left), Some(right)) =
+                (left.to_decimal(), right.to_decimal())
+            else {
+                return Err(EvaluationError::Overflow);
+            };
+            if is_division && right.value() == 0 {
+                return Err(EvaluationError::DivisionByZero);
+            }
+            let value = match operator {
+                BinaryOperator::Add => left.checked_add(right),
+                BinaryOperator::Subtract => left.checked_sub(right),
+                BinaryOperator::Multiply => left.checked_mul(right),
+                BinaryOperator::Divide => {
+                    left.checked_div(right, *scale, Rounding::default())
+                }
+                _ => left
+                    .checked_div(right, 0, Rounding::Down)
+                    .and_then(|quotient| quotient.checked_mul(right))
+                    .and_then(|product| left.checked_sub(product)),
+            };
+
+            value.map(Into::into).ok_or(EvaluationError::Overflow)
+        }
+        _ => {
+            let (Number::Integer(left), Number::Integer(right)) = (left, right)
+            else {
+                return Err(EvaluationError::Overflow);
+            };
+            if is_division && right == 0 {
+                return Err(EvaluationError::DivisionByZero);
+            }
+            let value = match operator {
+                BinaryOperator::Add => left.checked_add(right),
+                BinaryOperator::Subtract => left.checked_sub(right),
+                BinaryOperator::Multiply => left.checked_mul(right),
+                BinaryOperator::Divide => left.checked_div(right),
+                _ => left.checked_rem(right),
+            };
+
+            value
+                .and_then(|value| result_type.integer_value(value))
+                .ok_or(EvaluationError::Overflow)
+        }
+    }
+}
+
+/// Adds or subtracts temporal values, `None` if the result is out of range.
+fn temporal_arithmetic(
+    operator: BinaryOperator,
+    left: &StorageData,
+    right: &StorageData,
+) -> Option<StorageData> {
+    use BinaryOperator::{Add, Subtract};
+
+    let value = match (operator, left, right) {
+        (Add, StorageData::Date(date), StorageData::Interval(interval))
+        | (Add, StorageData::Interval(interval), StorageData::Date(date)) => {
+            date.checked_add(*interval)?.into()
+        }
+        (
+            Subtract,
+            StorageData::Date(date),
+            StorageData::Interval(interval),
+        ) => date.checked_sub(*interval)?.into(),
+        (Subtract, StorageData::Date(left), StorageData::Date(right)) => {
+            left.since(*right)?.into()
+        }
+        (
+            Add,
+            StorageData::Timestamp(timestamp),
+            StorageData::Interval(interval),
+        )
+        | (
+            Add,
+            StorageData::Interval(interval),
+            StorageData::Timestamp(timestamp),
+        ) => timestamp.checked_add(*interval)?.into(),
+        (
+            Subtract,
+            StorageData::Timestamp(timestamp),
+            StorageData::Interval(interval),
+        ) => timestamp.checked_sub(*interval)?.into(),
+        (
+            Subtract,
+            StorageData::Timestamp(left),
+            StorageData::Timestamp(right),
+        ) => left.since(*right)?.into(),
+        (Add, StorageData::Time(time), StorageData::Interval(interval))
+        | (Add, StorageData::Interval(interval), StorageData::Time(time)) => {
+            time.wrapping_add(*interval).into()
+        }
+        (
+            Subtract,
+            StorageData::Time(time),
+            StorageData::Interval(interval),
+        ) => time.wrapping_sub(*interval).into(),
+        (Subtract, StorageData::Time(left), StorageData::Time(right)) => {
+            left.since(*right)?.into()
+        }
+        (Add, StorageData::Interval(left), StorageData::Interval(right)) => {
+            left.checked_add(*right)?.into()
+        }
+        (
+            Subtract,
+            StorageData::Interval(left),
+            StorageData::Interval(right),
+        ) => left.checked_sub(*right)?.into(),
+        _ => return None,
+    };
+
+    Some(value)
+}
+
+fn call<'a, F>(
+    function: Function,
+    arguments: &[Expression],
+    value: &F,
+) -> Result<StorageData, EvaluationError>
+where
+    F: Fn(&column::Name) -> Option<&'a StorageData>,
+{
+    if !function.accepts_arguments(arguments.len()) {
+        return Err(EvaluationError::WrongArgumentCount(
+            function,
+            arguments.len(),
+        ));
+    }
+
+    match function {
+        Function::Coalesce => {
+            for argument in arguments {
+                let argument = argument.evaluate_with(value)?;
+                if argument != StorageData::Null {
+                    return Ok(argument);
+                }
+            }
+            Ok(StorageData::Null)
+        }
+        Function::NullIf => {
+            let left = arguments[0].evaluate_with(value)?;
+            let right = arguments[1].evaluate_with(value)?;
+            let equal = binary(BinaryOperator::Equal, left.clone(), right)?;
+            if truth_value(equal)? == Some(true) {
+                Ok(StorageData::Null)
+            } else {
+                Ok(left)
+            }
+        }
+        Function::NextVal => {
+            Err(EvaluationError::SequenceNotAvailable(arguments[0].clone()))
+        }
+        _ => {
+            let argument = arguments[0].evaluate_with(value)?;
+            let Some(data_type) = argument.data_type() else {
+                return Ok(StorageData::Null);
+            };
+            function_type(function, &[Some(data_type)])?;
+
+            match (function, argument) {
+                (Function::Abs, StorageData::Float(value)) => {
+                    Ok(value.0.abs().into())
+                }
+                (Function::Abs, StorageData::Double(value)) => {
+                    Ok(value.0.abs().into())
+                }
+                (Function::Abs, StorageData::Decimal(value)) => {
+                    if value.value() < 0 {
+                        value
+                            .checked_neg()
+                            .map(Into::into)
+                            .ok_or(EvaluationError::Overflow)
+                    } else {
+                        Ok(value.into())
+                    }
+                }
+                (Function::Abs, argument) => argument
+                    .as_integer()
+                    .and_then(i128::checked_abs)
+                    .and_then(|value| data_type.integer_value(value))
+                    .ok_or(EvaluationError::Overflow),
+                (function, argument) => {
+                    let text = as_str(&argument).expect("checked by type");
+                    Ok(match function {
+                        Function::Length => i32::try_from(text.chars().count())
+                            .map_err(|_| EvaluationError::Overflow)?
+                            .into(),
+                        Function::Lower => Text(text.to_lowercase()).into(),
+                        Function::Upper => Text(text.to_uppercase()).into(),
+                        _ => Text(text.trim().to_string()).into(),
+                    })
+                }
+            }
+        }
+    }
+}
+
+/// Returns the text form of a value, e.g. of `||` operands.
+fn to_text(value: &StorageData) -> String {
+    match value {
+        StorageData::VarChar(value) => value.value.clone(),
+        StorageData::Text(value) => value.0.clone(),
+        StorageData::Bool(value) => value.0.to_string(),
+        StorageData::Blob(value) => format!("\\x{}", value.to_hex()),
+        StorageData::Date(value) => value.to_string(),
+        StorageData::Time(value) => value.to_string(),
+        StorageData::Timestamp(value) => value.to_string(),
+        StorageData::Interval(value) => value.to_string(),
+        StorageData::Uuid(value) => value.to_string(),
+        value => value.to_string(),
+    }
+}
+
+/// Parses the text form of a value of the type.
+fn parse_text(text: &str, data_type: &StorageDataType) -> Option<StorageData> {
+    let text = text.trim();
+    let value = match data_type {
+        _ if data_type.is_integer() => {
+            return data_type.integer_value(text.parse().ok()?)
+        }
+        StorageDataType::Bool => match text.to_lowercase().as_str() {
+            "true" | "t" => true.into(),
+            "false" | "f" => false.into(),
+            _ => return None,
+        },
+        StorageDataType::Float => text.parse::<f32>().ok()?.into(),
+        StorageDataType::Double => text.parse::<f64>().ok()?.into(),
+        StorageDataType::Decimal(precision, scale) => text
+            .parse::<Decimal>()
+            .ok()?
+            .fit(*precision, *scale)?
+            .into(),
+        StorageDataType::Blob => {
+            Blob::from_hex(text.strip_prefix("\\x").unwrap_or(text))?.into()
+        }
+        StorageDataType::Date => text.parse::<Date>().ok()?.into(),
+        StorageDataType::Time => text.parse::<Time>().ok()?.into(),
+        StorageDataType::Timestamp => text.parse::<Timestamp>().ok()?.into(),
+        StorageDataType::Interval => text.parse::<Interval>().ok()?.into(),
+        StorageDataType::Uuid => text.parse::<Uuid>().ok()?.into(),
+        _ => return None,
+    };
+
+    Some(value)
+}
+
+/// Rounds a float to the nearest integer, `None` if it is out of range.
+fn float_to_integer(value: f64) -> Option<i128> {
+    let value = value.round();
+    (value.is_finite() && value >= i128::MIN as f64 && value < i128::MAX as f64)
+        .then_some(value as i128)
+}
+
+/// Converts a non-null value of one type into the other, `None` if the
+/// value doesn't fit.
+fn convert(
+    value: &StorageData,
+    from: &StorageDataType,
+    to: &StorageDataType,
+) -> Option<StorageData> {
+    let converted = match to {
+        StorageDataType::Text => Text(to_text(value)).into(),
+        StorageDataType::VarChar(size) => {
+            let text = to_text(value);
+            if text.chars().count() > *size {
+                return None;
+            }
+            VarChar::new(text).ok()?.into()
+        }
+        _ if is_string(from) => {
+            return parse_text(as_str(value).expect("is a string"), to)
+        }
+        _ if to.is_integer() => {
+            let value = match value {
+                StorageData::Bool(value) => value.0.into(),
+                value => match Number::from_data(value)? {
+                    Number::Integer(value) => value,
+                    Number::Float(value) => float_to_integer(value)?,
+                    Number::Decimal(value) => {
+                        value.rescale(0, Rounding::default())?.value()
+                    }
+                },
+            };
+            return to.integer_value(value);
+        }
+        StorageDataType::Float => {
+            (Number::from_data(value)?.to_f64() as f32).into()
+        }
+        StorageDataType::Double => Number::from_data(value)?.to_f64().into(),
+        StorageDataType::Decimal(precision, scale) => Number::from_data(value)?
+            .to_decimal()?
+            .fit(*precision, *scale)?
+            .into(),
+        StorageDataType::Bool => (value.as_integer()? != 0).into(),
+        StorageDataType::Timestamp => match value {
+            StorageData::Date(date) => Timestamp::try_from(*date).ok()?.into(),
+            _ => return None,
+        },
+        StorageDataType::Date => match value {
+            StorageData::Timestamp(timestamp) => {
+                Date(timestamp.0.div_euclid(MICROS_PER_DAY).try_into().ok()?)
+                    .into()
+            }
+            _ => return None,
+        },
+        _ => return None,
+    };
+
+    Some(converted)
+}
+
+/// Casts the value to the type, as `CAST(value AS data_type)`.
+pub(crate) fn cast(
+    value: StorageData,
+    data_type: &StorageDataType,
+) -> Result<StorageData, EvaluationError> {
+    let Some(from) = value.data_type() else {
+        return Ok(StorageData::Null);
+    };
+    cast_type(&from, data_type)?;
+    if from == *data_type {
+        return Ok(value);
+    }
+
+    convert(&value, &from, data_type)
+        .ok_or(EvaluationError::InvalidCast(value, *data_type))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        expression::{
+            BinaryOperator, EvaluationError, Expression, Function,
+            UnaryOperator,
+        },
+        schema::{
+            column,
+            r#type::{
+                data_types::{Date, Decimal, Interval, Text, Timestamp},
+                r#enum::{StorageData, StorageDataType},
+            },
+        },
+    };
+
+    fn column(name: &str) -> Expression {
+        Expression::Column(name.into())
+    }
+
+    fn literal<T: Into<StorageData>>(value: T) -> Expression {
+        Expression::Literal(value.into())
+    }
+
+    fn text(value: &str) -> StorageData {
+        Text(value.to_string()).into()
+    }
+
+    fn binary(
+        left: Expression,
+        operator: BinaryOperator,
+        right: Expression,
+    ) -> Expression {
+        Expression::binary(left, operator, right)
+    }
+
+    fn evaluate(
+        expression: &Expression,
+    ) -> Result<StorageData, EvaluationError> {
+        let row: Vec<(column::Name, StorageData)> = vec![
+            ("age".into(), StorageData::Byte(30.into())),
+            ("name".into(), text("Ann")),
+            ("price".into(), Decimal::new(1050, 2).unwrap().into()),
+            ("missing".into(), StorageData::Null),
+        ];
+        expression.evaluate(|name: &column::Name| {
+            row.iter()
+                .find(|(column, _)| column == name)
+                .map(|(_, value)| value)
+        })
+    }
+
+    fn column_type(name: &column::Name) -> Option<StorageDataType> {
+        match name.to_string().as_str() {
+            "age" => Some(StorageDataType::Byte),
+            "name" => Some(StorageDataType::Text),
+            "price" => Some(StorageDataType::Decimal(10, 2)),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_integer_arithmetic() {
+        let sum = binary(column("age"), BinaryOperator::Add, literal(5));
+        assert_eq!(evaluate(&sum), Ok(StorageData::Integer(35.into())));
+        assert_eq!(
+            sum.data_type(column_type),
+            Ok(Some(StorageDataType::Integer))
+        );
+
+        let quotient = binary(literal(7), BinaryOperator::Divide, literal(-2));
+        assert_eq!(evaluate(&quotient), Ok(StorageData::from(-3)));
+        let remainder = binary(literal(7), BinaryOperator::Modulo, literal(-2));
+        assert_eq!(evaluate(&remainder), Ok(StorageData::from(1)));
+
+        let product =
+            binary(literal(i32::MAX), BinaryOperator::Multiply, literal(2));
+        assert_eq!(evaluate(&product), Err(EvaluationError::Overflow));
+        let division =
+            binary(column("age"), BinaryOperator::Divide, literal(0));
+        assert_eq!(evaluate(&division), Err(EvaluationError::DivisionByZero));
+
+        let negation = Expression::unary(UnaryOperator::Minus, column("age"));
+        assert_eq!(evaluate(&negation), Ok(StorageData::Short((-30).into())));
+    }
+
+    #[test]
+    fn test_mixed_arithmetic() {
+        let sum = binary(column("price"), BinaryOperator::Add, literal(1));
+        assert_eq!(evaluate(&sum), Ok(Decimal::new(1150, 2).unwrap().into()));
+
+        let quotient =
+            binary(column("price"), BinaryOperator::Divide, literal(4));
+        assert_eq!(
+            evaluate(&quotient),
+            Ok(Decimal::new(2_625_000, 6).unwrap().into())
+        );
+        assert_eq!(
+            quotient.data_type(column_type),
+            Ok(Some(StorageDataType::Decimal(38, 6)))
+        );
+
+        let product =
+            binary(column("age"), BinaryOperator::Multiply, literal(0.5));
+        assert_eq!(evaluate(&product), Ok(StorageData::from(15.0)));
+    }
+
+    #[test]
+    fn test_temporal_arithmetic() {
+        let day = Interval {
+            days: 1,
+            ..Default::default()
+        };
+        let sum = binary(literal(Date(0)), BinaryOperator::Add, literal(day));
+        assert_eq!(evaluate(&sum), Ok(Timestamp(86_400_000_000).into()));
+
+        let difference = binary(
+            literal(Date(3)),
+            BinaryOperator::Subtract,
+            literal(Date(1)),
+        );
+        assert_eq!(
+            evaluate(&difference),
+            Ok(Interval {
+                days: 2,
+                ..Default::default()
+            }
+            .into())
+        );
+
+        let sum = binary(literal(Date(0)), BinaryOperator::Add, literal(1));
+        assert_eq!(
+            evaluate(&sum),
+            Err(EvaluationError::UndefinedBinaryOperator(
+                BinaryOperator::Add,
+                StorageDataType::Date,
+                StorageDataType::Integer
+            ))
+        );
+    }
+
+    #[test]
+    fn test_comparison() {
+        let adult = binary(
+            column("age"),
+            BinaryOperator::GreaterThanOrEqual,
+            literal(18),
+        );
+        assert_eq!(evaluate(&adult), Ok(true.into()));
+        assert_eq!(
+            adult.data_type(column_type),
+            Ok(Some(StorageDataType::Bool))
+        );
+
+        let cheap =
+            binary(column("price"), BinaryOperator::LessThan, literal(10.6));
+        assert_eq!(evaluate(&cheap), Ok(true.into()));
+
+        let name = binary(
+            column("name"),
+            BinaryOperator::NotEqual,
+            Expression::Literal(text("Bob")),
+        );
+        assert_eq!(evaluate(&name), Ok(true.into()));
+
+        let unknown =
+            binary(column("missing"), BinaryOperator::Equal, literal(1));
+        assert_eq!(evaluate(&unknown), Ok(StorageData::Null));
+
+        let mismatch =
+            binary(column("name"), BinaryOperator::Equal, literal(1));
+        assert_eq!(
+            evaluate(&mismatch),
+            Err(EvaluationError::UndefinedBinaryOperator(
+                BinaryOperator::Equal,
+                StorageDataType::Text,
+                StorageDataType::Integer
+            ))
+        );
+        assert!(mismatch.data_type(column_type).is_err());
+    }
+
+    #[test]
+    fn test_three_valued_logic() {
+        let null = || literal(StorageData::Null);
+        for (left, operator, right, expected) in [
+            (null(), BinaryOperator::And, literal(false), false.into()),
+            (
+                null(),
+                BinaryOperator::And,
+                literal(true),
+                StorageData::Null,
+            ),
+            (null(), BinaryOperator::Or, literal(true), true.into()),
+            (
+                null(),
+                BinaryOperator::Or,
+                literal(false),
+                StorageData::Null,
+            ),
+            (
+                literal(true),
+                BinaryOperator::And,
+                literal(true),
+                true.into(),
+            ),
+            (
+                literal(false),
+                BinaryOperator::Or,
+                literal(false),
+                false.into(),
+            ),
+        ] {
+            assert_eq!(evaluate(&binary(left, operator, right)), Ok(expected));
+        }
+
+        let not = Expression::unary(UnaryOperator::Not, null());
+        assert_eq!(evaluate(&not), Ok(StorageData::Null));
+        assert_eq!(not.matches(|_| None), Ok(false));
+
+        // The right operand isn't evaluated if the left one decides.
+        let short_circuit = binary(
+            literal(false),
+            BinaryOperator::And,
+            binary(literal(1), BinaryOperator::Divide, literal(0)),
+        );
+        assert_eq!(evaluate(&short_circuit), Ok(false.into()));
+
+        let is_null = Expression::IsNull {
+            operand: Box::new(column("missing")),
+            negated: false,
+        };
+        assert_eq!(evaluate(&is_null), Ok(true.into()));
+
+        let not_bool = binary(literal(1), BinaryOperator::Or, literal(true));
+        assert_eq!(
+            evaluate(&not_bool),
+            Err(EvaluationError::NotBoolean(StorageDataType::Integer))
+        );
+        assert_eq!(
+            column("age").matches(|_| Some(&StorageData::Null)),
+            Ok(false)
+        );
+    }
+
+    #[test]
+    fn test_concat_and_functions() {
+        let greeting = binary(
+            Expression::Literal(text("Hi ")),
+            BinaryOperator::Concat,
+            binary(column("name"), BinaryOperator::Concat, column("age")),
+        );
+        assert_eq!(evaluate(&greeting), Ok(text("Hi Ann30")));
+
+        let call = |function: Function, arguments: Vec<Expression>| {
+            Expression::Function {
+                function,
+                arguments,
+            }
+        };
+        assert_eq!(
+            evaluate(&call(Function::Upper, vec![column("name")])),
+            Ok(text("ANN"))
+        );
+        assert_eq!(
+            evaluate(&call(Function::Length, vec![column("name")])),
+            Ok(StorageData::from(3))
+        );
+        assert_eq!(
+            evaluate(&call(
+                Function::Coalesce,
+                vec![column("missing"), literal(2)]
+            )),
+            Ok(StorageData::from(2))
+        );
+        assert_eq!(
+            evaluate(&call(Function::NullIf, vec![column("age"), literal(30)])),
+            Ok(StorageData::Null)
+        );
+        assert_eq!(
+            evaluate(&call(Function::Abs, vec![literal(-4)])),
+            Ok(StorageData::from(4))
+        );
+        assert_eq!(
+            evaluate(&call(Function::Lower, vec![column("age")])),
+            Err(EvaluationError::UndefinedFunction(
+                Function::Lower,
+                StorageDataType::Byte
+            ))
+        );
+        assert_eq!(
+            evaluate(&call(Function::Abs, Vec::new())),
+            Err(EvaluationError::WrongArgumentCount(Function::Abs, 0))
+        );
+
+        let next_value =
+            call(Function::NextVal, vec![Expression::Literal(text("seq"))]);
+        assert_eq!(
+            next_value.data_type(column_type),
+            Ok(Some(StorageDataType::Long))
+        );
+        assert_eq!(
+            evaluate(&next_value),
+            Err(EvaluationError::SequenceNotAvailable(Expression::Literal(
+                text("seq")
+            )))
+        );
+    }
+
+    #[test]
+    fn test_case() {
+        let searched = Expression::Case {
+            operand: None,
+            branches: vec![
+                (
+                    binary(
+                        column("age"),
+                        BinaryOperator::LessThan,
+                        literal(18),
+                    ),
+                    Expression::Literal(text("minor")),
+                ),
+                (
+                    binary(
+                        column("age"),
+                        BinaryOperator::LessThan,
+                        literal(65),
+                    ),
+                    Expression::Literal(text("adult")),
+                ),
+            ],
+            otherwise: None,
+        };
+        assert_eq!(evaluate(&searched), Ok(text("adult")));
+        assert_eq!(
+            searched.data_type(column_type),
+            Ok(Some(StorageDataType::Text))
+        );
+
+        let simple = Expression::Case {
+            operand: Some(Box::new(column("name"))),
+            branches: vec![(Expression::Literal(text("Bob")), literal(1))],
+            otherwise: Some(Box::new(literal(2))),
+        };
+        assert_eq!(evaluate(&simple), Ok(StorageData::from(2)));
+
+        let mixed = Expression::Case {
+            operand: None,
+            branches: vec![(literal(true), literal(1))],
+            otherwise: Some(Box::new(column("name"))),
+        };
+        assert_eq!(
+            mixed.data_type(column_type),
+            Err(EvaluationError::IncompatibleTypes(
+                StorageDataType::Integer,
+                StorageDataType::Text
+            ))
+        );
+    }
+
+    #[test]
+    fn test_cast() {
+        let cast = |operand: Expression, data_type: StorageDataType| {
+            Expression::Cast {
+                operand: Box::new(operand),
+                data_type,
+            }
+        };
+        assert_eq!(
+            evaluate(&cast(
+                Expression::Literal(text(" 42 ")),
+                StorageDataType::Long
+            )),
+            Ok(StorageData::from(42i128))
+        );
+        assert_eq!(
+            evaluate(&cast(column("price"), StorageDataType::Integer)),
+            Ok(StorageData::from(11))
+        );
+        assert_eq!(
+            evaluate(&cast(column("age"), StorageDataType::Text)),
+            Ok(text("30"))
+        );
+        assert_eq!(
+            evaluate(&cast(literal(2.5), StorageDataType::Decimal(3, 1))),
+            Ok(Decimal::new(25, 1).unwrap().into())
+        );
+        assert_eq!(
+            evaluate(&cast(
+                Expression::Literal(text("2024-01-31")),
+                StorageDataType::Date
+            )),
+            Ok("2024-01-31".parse::<Date>().unwrap().into())
+        );
+        assert_eq!(
+            evaluate(&cast(literal(300), StorageDataType::Byte)),
+            Err(EvaluationError::InvalidCast(
+                StorageData::from(300),
+                StorageDataType::Byte
+            ))
+        );
+        assert_eq!(
+            evaluate(&cast(literal(Date(0)), StorageDataType::Integer)),
+            Err(EvaluationError::UndefinedCast(
+                StorageDataType::Date,
+                StorageDataType::Integer
+            ))
+        );
+        assert_eq!(
+            evaluate(&cast(column("missing"), StorageDataType::Integer)),
+            Ok(StorageData::Null)
+        );
+    }
+
+    #[test]
+    fn test_unknown_column() {
+        assert_eq!(
+            evaluate(&column("other")),
+            Err(EvaluationError::ColumnDoesNotExist("other".into()))
+        );
+        assert_eq!(
+            column("other").data_type(column_type),
+            Err(EvaluationError::ColumnDoesNotExist("other".into()))
+        );
+    }
+}
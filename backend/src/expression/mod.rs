@@ -0,0 +1,678 @@
+//! Scalar SQL expressions, e.g. `price * 2 > 10 AND name <> 'root'`, shared
+//! by `WHERE` conditions, `CHECK` constraints and `SELECT` lists. An
+//! [`Expression`] is evaluated over the values of a row into
+//! [`StorageData`].
+
+mod evaluate;
+
+use std::{fmt::Display, str::FromStr};
+
+use derive_more::Display;
+
+pub use evaluate::EvaluationError;
+pub(crate) use evaluate::{binary, binary_type, cast, compare};
+
+use crate::schema::{
+    column,
+    r#type::r#enum::{StorageData, StorageDataType},
+    sequence,
+};
+
+/// A scalar SQL expression.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expression {
+    /// A constant value, e.g. `5`, `'text'` or `NULL`.
+    Literal(StorageData),
+
+    /// The value of a column of the row.
+    Column(column::Name),
+
+    /// An operator applied to one operand, e.g. `-price` or `NOT active`.
+    Unary {
+        /// The operator.
+        operator: UnaryOperator,
+
+        /// The operand.
+        operand: Box<Expression>,
+    },
+
+    /// An operator applied to two operands, e.g. `price * 2`.
+    Binary {
+        /// The left operand.
+        left: Box<Expression>,
+
+        /// The operator.
+        operator: BinaryOperator,
+
+        /// The right operand.
+        right: Box<Expression>,
+    },
+
+    /// `IS NULL` or `IS NOT NULL` test of the operand.
+    IsNull {
+        /// The tested operand.
+        operand: Box<Expression>,
+
+        /// True for `IS NOT NULL`.
+        negated: bool,
+    },
+
+    /// A call of a built-in function, e.g. `LOWER(name)`.
+    Function {
+        /// The called function.
+        function: Function,
+
+        /// The arguments of the call.
+        arguments: Vec<Expression>,
+    },
+
+    /// `CASE [operand] WHEN .. THEN .. [ELSE ..] END`. Without the operand
+    /// the `WHEN` expressions are conditions, with it they are compared to
+    /// the operand.
+    Case {
+        /// The operand compared to the `WHEN` expressions.
+        operand: Option<Box<Expression>>,
+
+        /// The `WHEN` and `THEN` expressions in the order they are checked.
+        branches: Vec<(Expression, Expression)>,
+
+        /// The `ELSE` expression, the result is `NULL` without it.
+        otherwise: Option<Box<Expression>>,
+    },
+
+    /// `CAST(operand AS data_type)`.
+    Cast {
+        /// The converted operand.
+        operand: Box<Expression>,
+
+        /// The data type to convert to.
+        data_type: StorageDataType,
+    },
+}
+
+impl Expression {
+    /// Creates an [`Expression::Unary`] with the given parameters.
+    /// # Arguments
+    /// * `operator` - The operator.
+    /// * `operand` - The operand.
+    /// # Returns
+    /// A new [`Expression::Unary`].
+    pub fn unary(operator: UnaryOperator, operand: Expression) -> Self {
+        Expression::Unary {
+            operator,
+            operand: Box::new(operand),
+        }
+    }
+
+    /// Creates an [`Expression::Binary`] with the given parameters.
+    /// # Arguments
+    /// * `left` - The left operand.
+    /// * `operator` - The operator.
+    /// * `right` - The right operand.
+    /// # Returns
+    /// A new [`Expression::Binary`].
+    pub fn binary(
+        left: Expression,
+        operator: BinaryOperator,
+        right: Expression,
+    ) -> Self {
+        Expression::Binary {
+            left: Box::new(left),
+            operator,
+            right: Box::new(right),
+        }
+    }
+
+    /// Returns the names of the columns the expression refers to, in the
+    /// order they appear and without duplicates.
+    /// # Returns
+    /// * `Vec<column::Name>` - The names of the columns.
+    pub fn columns(&self) -> Vec<column::Name> {
+        let mut columns = Vec::new();
+        self.collect_columns(&mut columns);
+        columns
+    }
+
+    /// Adds the names of the columns the expression refers to.
+    fn collect_columns(&self, columns: &mut Vec<column::Name>) {
+        match self {
+            Expression::Literal(_) => {}
+            Expression::Column(name) => {
+                if !columns.contains(name) {
+                    columns.push(name.clone());
+                }
+            }
+            Expression::Unary { operand, .. }
+            | Expression::IsNull { operand, .. }
+            | Expression::Cast { operand, .. } => {
+                operand.collect_columns(columns)
+            }
+            Expression::Binary { left, right, .. } => {
+                left.collect_columns(columns);
+                right.collect_columns(columns);
+            }
+            Expression::Function { arguments, .. } => {
+                for argument in arguments {
+                    argument.collect_columns(columns);
+                }
+            }
+            Expression::Case {
+                operand,
+                branches,
+                otherwise,
+            } => {
+                if let Some(operand) = operand {
+                    operand.collect_columns(columns);
+                }
+                for (condition, result) in branches {
+                    condition.collect_columns(columns);
+                    result.collect_columns(columns);
+                }
+                if let Some(otherwise) = otherwise {
+                    otherwise.collect_columns(columns);
+                }
+            }
+        }
+    }
+
+    /// Returns the expression with the `NEXTVAL('name')` calls replaced by
+    /// the next values of their sequences, as the evaluation has no access
+    /// to them. Every call takes a new value, so it's done for every row.
+    /// # Arguments
+    /// * `next_value` - Takes the next value of the sequence with the given
+    ///   name.
+    /// # Returns
+    /// * `Ok(Expression)` - The expression without `NEXTVAL` calls of a
+    ///   literal name.
+    /// * `Err(E)` - The error of `next_value`.
+    pub fn with_next_values<F, E>(
+        &self,
+        next_value: &mut F,
+    ) -> Result<Expression, E>
+    where
+        F: FnMut(&sequence::Name) -> Result<StorageData, E>,
+    {
+        Ok(match self {
+            Expression::Function {
+                function: Function::NextVal,
+                arguments,
+            } => match arguments.as_slice() {
+                [Expression::Literal(StorageData::Text(name))] => {
+                    Expression::Literal(next_value(&name.0.as_str().into())?)
+                }
+                [Expression::Literal(StorageData::VarChar(name))] => {
+                    Expression::Literal(next_value(
+                        &name.value.as_str().into(),
+                    )?)
+                }
+                _ => self.clone(),
+            },
+            Expression::Literal(_) | Expression::Column(_) => self.clone(),
+            Expression::Unary { operator, operand } => Expression::Unary {
+                operator: *operator,
+                operand: Box::new(operand.with_next_values(next_value)?),
+            },
+            Expression::Binary {
+                left,
+                operator,
+                right,
+            } => Expression::Binary {
+                left: Box::new(left.with_next_values(next_value)?),
+                operator: *operator,
+                right: Box::new(right.with_next_values(next_value)?),
+            },
+            Expression::IsNull { operand, negated } => Expression::IsNull {
+                operand: Box::new(operand.with_next_values(next_value)?),
+                negated: *negated,
+            },
+            Expression::Function {
+                function,
+                arguments,
+            } => Expression::Function {
+                function: *function,
+                arguments: arguments
+                    .iter()
+                    .map(|argument| argument.with_next_values(next_value))
+                    .collect::<Result<_, _>>()?,
+            },
+            Expression::Case {
+                operand,
+                branches,
+                otherwise,
+            } => Expression::Case {
+                operand: match operand {
+                    Some(operand) => {
+                        Some(Box::new(operand.with_next_values(next_value)?))
+                    }
+                    None => None,
+                },
+                branches: branches
+                    .iter()
+                    .map(|(condition, result)| {
+                        Ok((
+                            condition.with_next_values(next_value)?,
+                            result.with_next_values(next_value)?,
+                        ))
+                    })
+                    .collect::<Result<_, _>>()?,
+                otherwise: match otherwise {
+                    Some(otherwise) => {
+                        Some(Box::new(otherwise.with_next_values(next_value)?))
+                    }
+                    None => None,
+                },
+            },
+            Expression::Cast { operand, data_type } => Expression::Cast {
+                operand: Box::new(operand.with_next_values(next_value)?),
+                data_type: *data_type,
+            },
+        })
+    }
+
+    /// Returns how tightly the expression binds its operands, expressions
+    /// of a lower precedence are put in parentheses when they are operands.
+    fn precedence(&self) -> u8 {
+        match self {
+            Expression::Unary { operator, .. } => operator.precedence(),
+            Expression::Binary { operator, .. } => operator.precedence(),
+            Expression::IsNull { .. } => IS_PRECEDENCE,
+            _ => u8::MAX,
+        }
+    }
+
+    /// Formats the operand, in parentheses if it binds looser than the
+    /// given precedence.
+    fn fmt_operand(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        precedence: u8,
+    ) -> std::fmt::Result {
+        if self.precedence() < precedence {
+            write!(f, "({})", self)
+        } else {
+            write!(f, "{}", self)
+        }
+    }
+}
+
+/// Precedence of `IS [NOT] NULL`, which binds tighter than comparisons and
+/// looser than `||`.
+pub const IS_PRECEDENCE: u8 = 5;
+
+impl Display for Expression {
+    /// Formats the expression as SQL with only the necessary parentheses,
+    /// e.g. `(a + b) * 2`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Expression::Literal(value) => write!(f, "{}", value),
+            Expression::Column(name) => write!(f, "{}", name),
+            Expression::Unary {
+                operator: UnaryOperator::Not,
+                operand,
+            } => {
+                write!(f, "NOT ")?;
+                operand.fmt_operand(f, UnaryOperator::Not.precedence())
+            }
+            Expression::Unary {
+                operator: UnaryOperator::Minus,
+                operand,
+            } => {
+                // `--` starts a comment, so negative operands are put in
+                // parentheses.
+                let operand_text = operand.to_string();
+                if operand.precedence() < UnaryOperator::Minus.precedence()
+                    || operand_text.starts_with('-')
+                {
+                    write!(f, "-({})", operand_text)
+                } else {
+                    write!(f, "-{}", operand_text)
+                }
+            }
+            Expression::Binary {
+                left,
+                operator,
+                right,
+            } => {
+                left.fmt_operand(f, operator.precedence())?;
+                write!(f, " {} ", operator)?;
+                right.fmt_operand(f, operator.precedence() + 1)
+            }
+            Expression::IsNull { operand, negated } => {
+                operand.fmt_operand(f, IS_PRECEDENCE + 1)?;
+                if *negated {
+                    write!(f, " IS NOT NULL")
+                } else {
+                    write!(f, " IS NULL")
+                }
+            }
+            Expression::Function {
+                function,
+                arguments,
+            } => {
+                let arguments = arguments
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                write!(f, "{}({})", function, arguments)
+            }
+            Expression::Case {
+                operand,
+                branches,
+                otherwise,
+            } => {
+                write!(f, "CASE")?;
+                if let Some(operand) = operand {
+                    write!(f, " {}", operand)?;
+                }
+                for (condition, result) in branches {
+                    write!(f, " WHEN {} THEN {}", condition, result)?;
+                }
+                if let Some(otherwise) = otherwise {
+                    write!(f, " ELSE {}", otherwise)?;
+                }
+                write!(f, " END")
+            }
+            Expression::Cast { operand, data_type } => {
+                write!(f, "CAST({} AS {})", operand, data_type)
+            }
+        }
+    }
+}
+
+/// An operator of an [`Expression::Unary`].
+#[derive(Debug, Display, Clone, Copy, PartialEq)]
+pub enum UnaryOperator {
+    /// Arithmetic negation.
+    #[display(fmt = "-")]
+    Minus,
+
+    /// Logical negation.
+    #[display(fmt = "NOT")]
+    Not,
+}
+
+impl UnaryOperator {
+    /// Returns how tightly the operator binds its operand.
+    pub fn precedence(&self) -> u8 {
+        match self {
+            UnaryOperator::Not => 3,
+            UnaryOperator::Minus => 9,
+        }
+    }
+}
+
+/// An operator of an [`Expression::Binary`].
+#[derive(Debug, Display, Clone, Copy, PartialEq)]
+pub enum BinaryOperator {
+    /// Addition.
+    #[display(fmt = "+")]
+    Add,
+
+    /// Subtraction.
+    #[display(fmt = "-")]
+    Subtract,
+
+    /// Multiplication.
+    #[display(fmt = "*")]
+    Multiply,
+
+    /// Division, integers are divided with truncation.
+    #[display(fmt = "/")]
+    Divide,
+
+    /// Remainder of the division.
+    #[display(fmt = "%")]
+    Modulo,
+
+    /// String concatenation.
+    #[display(fmt = "||")]
+    Concat,
+
+    /// Equality comparison.
+    #[display(fmt = "=")]
+    Equal,
+
+    /// Inequality comparison.
+    #[display(fmt = "<>")]
+    NotEqual,
+
+    /// Less than comparison.
+    #[display(fmt = "<")]
+    LessThan,
+
+    /// Less than or equal comparison.
+    #[display(fmt = "<=")]
+    LessThanOrEqual,
+
+    /// Greater than comparison.
+    #[display(fmt = ">")]
+    GreaterThan,
+
+    /// Greater than or equal comparison.
+    #[display(fmt = ">=")]
+    GreaterThanOrEqual,
+
+    /// Logical conjunction.
+    #[display(fmt = "AND")]
+    And,
+
+    /// Logical disjunction.
+    #[display(fmt = "OR")]
+    Or,
+}
+
+impl BinaryOperator {
+    /// Returns how tightly the operator binds its operands. All binary
+    /// operators are left-associative.
+    pub fn precedence(&self) -> u8 {
+        match self {
+            BinaryOperator::Or => 1,
+            BinaryOperator::And => 2,
+            BinaryOperator::Equal
+            | BinaryOperator::NotEqual
+            | BinaryOperator::LessThan
+            | BinaryOperator::LessThanOrEqual
+            | BinaryOperator::GreaterThan
+            | BinaryOperator::GreaterThanOrEqual => 4,
+            BinaryOperator::Concat => 6,
+            BinaryOperator::Add | BinaryOperator::Subtract => 7,
+            BinaryOperator::Multiply
+            | BinaryOperator::Divide
+            | BinaryOperator::Modulo => 8,
+        }
+    }
+
+    /// Checks if the operator compares its operands.
+    /// # Returns
+    /// * `bool` - True for `=`, `<>`, `<`, `<=`, `>` and `>=`.
+    pub fn is_comparison(&self) -> bool {
+        self.precedence() == 4
+    }
+}
+
+/// A built-in function of an [`Expression::Function`].
+#[derive(Debug, Display, Clone, Copy, PartialEq)]
+pub enum Function {
+    /// `ABS(number)`, the absolute value.
+    #[display(fmt = "ABS")]
+    Abs,
+
+    /// `COALESCE(value, ...)`, the first argument that isn't `NULL`.
+    #[display(fmt = "COALESCE")]
+    Coalesce,
+
+    /// `NULLIF(value, other)`, `NULL` if the arguments are equal, the first
+    /// one otherwise.
+    #[display(fmt = "NULLIF")]
+    NullIf,
+
+    /// `LENGTH(string)`, the number of characters.
+    #[display(fmt = "LENGTH")]
+    Length,
+
+    /// `LOWER(string)`, the string in lowercase.
+    #[display(fmt = "LOWER")]
+    Lower,
+
+    /// `UPPER(string)`, the string in uppercase.
+    #[display(fmt = "UPPER")]
+    Upper,
+
+    /// `TRIM(string)`, the string without leading and trailing whitespace.
+    #[display(fmt = "TRIM")]
+    Trim,
+
+    /// `NEXTVAL(name)`, the next value of the standalone sequence, see
+    /// [`Expression::with_next_values`].
+    #[display(fmt = "NEXTVAL")]
+    NextVal,
+}
+
+impl Function {
+    /// Checks if the function takes the given number of arguments.
+    /// # Arguments
+    /// * `count` - The number of arguments.
+    /// # Returns
+    /// * `bool` - True if the function can be called with them.
+    pub fn accepts_arguments(&self, count: usize) -> bool {
+        match self {
+            Function::Coalesce => count >= 1,
+            Function::NullIf => count == 2,
+            _ => count == 1,
+        }
+    }
+}
+
+impl FromStr for Function {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "abs" => Ok(Function::Abs),
+            "coalesce" => Ok(Function::Coalesce),
+            "nullif" => Ok(Function::NullIf),
+            "length" | "char_length" => Ok(Function::Length),
+            "lower" => Ok(Function::Lower),
+            "upper" => Ok(Function::Upper),
+            "trim" => Ok(Function::Trim),
+            "nextval" => Ok(Function::NextVal),
+            _ => Err(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        expression::{BinaryOperator, Expression, Function, UnaryOperator},
+        schema::r#type::{
+            data_types::Text,
+            r#enum::{StorageData, StorageDataType},
+        },
+    };
+
+    fn column(name: &str) -> Expression {
+        Expression::Column(name.into())
+    }
+
+    fn literal<T: Into<StorageData>>(value: T) -> Expression {
+        Expression::Literal(value.into())
+    }
+
+    #[test]
+    fn test_display_parentheses() {
+        let sum =
+            Expression::binary(column("a"), BinaryOperator::Add, column("b"));
+        let product = Expression::binary(
+            sum.clone(),
+            BinaryOperator::Multiply,
+            literal(2),
+        );
+        assert_eq!(product.to_string(), "(a + b) * 2");
+
+        let difference = Expression::binary(
+            column("a"),
+            BinaryOperator::Subtract,
+            sum.clone(),
+        );
+        assert_eq!(difference.to_string(), "a - (a + b)");
+
+        let condition = Expression::binary(
+            Expression::binary(sum, BinaryOperator::GreaterThan, literal(1)),
+            BinaryOperator::And,
+            Expression::unary(
+                UnaryOperator::Not,
+                Expression::IsNull {
+                    operand: Box::new(column("c")),
+                    negated: true,
+                },
+            ),
+        );
+        assert_eq!(condition.to_string(), "a + b > 1 AND NOT c IS NOT NULL");
+
+        let negation = Expression::unary(
+            UnaryOperator::Minus,
+            Expression::binary(column("a"), BinaryOperator::Or, column("b")),
+        );
+        assert_eq!(negation.to_string(), "-(a OR b)");
+    }
+
+    #[test]
+    fn test_display_case_function_cast() {
+        let case = Expression::Case {
+            operand: None,
+            branches: vec![(
+                Expression::binary(
+                    column("age"),
+                    BinaryOperator::LessThan,
+                    literal(18),
+                ),
+                literal(Text("minor".to_string())),
+            )],
+            otherwise: Some(Box::new(Expression::Function {
+                function: Function::Upper,
+                arguments: vec![column("name")],
+            })),
+        };
+        assert_eq!(
+            case.to_string(),
+            "CASE WHEN age < 18 THEN 'minor' ELSE UPPER(name) END"
+        );
+
+        let cast = Expression::Cast {
+            operand: Box::new(column("age")),
+            data_type: StorageDataType::Long,
+        };
+        assert_eq!(cast.to_string(), "CAST(age AS i128)");
+    }
+
+    #[test]
+    fn test_columns() {
+        let expression = Expression::Case {
+            operand: Some(Box::new(column("a"))),
+            branches: vec![(literal(1), column("b"))],
+            otherwise: Some(Box::new(Expression::binary(
+                column("a"),
+                BinaryOperator::Add,
+                column("c"),
+            ))),
+        };
+        assert_eq!(
+            expression.columns(),
+            vec!["a".into(), "b".into(), "c".into()]
+        );
+        assert!(literal(1).columns().is_empty());
+    }
+
+    #[test]
+    fn test_function_from_str() {
+        assert_eq!("lower".parse(), Ok(Function::Lower));
+        assert_eq!("COALESCE".parse(), Ok(Function::Coalesce));
+        assert_eq!("missing".parse::<Function>(), Err(()));
+
+        assert!(Function::Coalesce.accepts_arguments(3));
+        assert!(!Function::NullIf.accepts_arguments(1));
+        assert!(!Function::Abs.accepts_arguments(0));
+    }
+}
@@ -1,4 +1,6 @@
 pub mod controller;
 pub mod data;
+pub mod expression;
 pub mod page;
+pub mod query;
 pub mod schema;
@@ -32,9 +32,33 @@ impl PageController {
         &mut self.pages[index]
     }
 
+    /// Gets the page with the given index, adding pages until it exists.
+    /// # Arguments
+    /// * `index` - The index of the page.
+    /// # Returns
+    /// * `&mut Page` - The page.
+    pub fn get_or_add_page(&mut self, index: usize) -> &mut Page {
+        while self.page_count <= index {
+            self.add_page();
+        }
+
+        &mut self.pages[index]
+    }
+
     pub fn get_last_page(&mut self) -> &mut Page {
         &mut self.pages[self.page_count - 1]
     }
+
+    /// Checks the checksums of all pages.
+    /// # Returns
+    /// * `Vec<usize>` - Indexes of the corrupted pages.
+    pub fn verify(&self) -> Vec<usize> {
+        self.pages
+            .iter()
+            .filter(|page| !page.verify())
+            .map(|page| page.get_index())
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -71,4 +95,26 @@ mod test {
         let page = controller.get_last_page();
         assert_eq!(page.get_index(), 1);
     }
+
+    #[test]
+    fn test_page_controller_get_or_add_page() {
+        let mut controller = PageController::default();
+        let page = controller.get_or_add_page(2);
+        assert_eq!(page.get_index(), 2);
+        assert_eq!(controller.get_page_count(), 3);
+
+        controller.get_or_add_page(1);
+        assert_eq!(controller.get_page_count(), 3);
+    }
+
+    #[test]
+    fn test_page_controller_verify() {
+        let mut controller = PageController::default();
+        controller.add_page();
+        controller.add_page();
+        assert!(controller.verify().is_empty());
+
+        controller.get_page(1).corrupt(0);
+        assert_eq!(controller.verify(), vec![1]);
+    }
 }
@@ -5,6 +5,10 @@ use crate::page::link_struct::PageLink;
 /// The size of a page.
 pub const PAGE_SIZE: u16 = 4096;
 
+/// The size of a page written by [`Page::to_bytes`]: the [`PageInfo`], the
+/// checksum and the data of the page.
+pub const PAGE_BYTES_SIZE: usize = 2 + USIZE_SIZE + 4 + PAGE_SIZE as usize;
+
 /// Represents the information of a page. Contains the index of the page and the
 /// amount of free space in the page.
 #[derive(Clone, Copy, Debug)]
@@ -66,8 +70,8 @@ impl From<PageInfo> for [u8; 2 + USIZE_SIZE] {
     }
 }
 
-/// Represents a page in the storage. Contains the information of the page and
-/// the data of the page.
+/// Represents a page in the storage. Contains the information of the page,
+/// the data of the page and its checksum.
 #[derive(Clone, Copy, Debug)]
 pub struct Page {
     /// The information of the page.
@@ -75,6 +79,10 @@ pub struct Page {
 
     /// The data of the page.
     data: [u8; PAGE_SIZE as usize],
+
+    /// CRC32C checksum of the information and the data of the page. It is
+    /// updated on every write and checked when the page is read.
+    checksum: u32,
 }
 
 impl Page {
@@ -85,10 +93,87 @@ impl Page {
     /// A new `Page` with the given index and the amount of free space set to
     /// the size of the page.
     pub fn new(index: usize) -> Page {
-        Page {
+        let mut page = Page {
             info: PageInfo::new(index),
             data: [0; 4096],
+            checksum: 0,
+        };
+        page.update_checksum();
+
+        page
+    }
+
+    /// Computes the checksum of the information and the data of the page.
+    /// # Returns
+    /// The CRC32C checksum of the page.
+    fn compute_checksum(&self) -> u32 {
+        let info: [u8; 2 + USIZE_SIZE] = self.info.into();
+        let checksum = crc32c::crc32c(&info);
+
+        crc32c::crc32c_append(checksum, &self.data)
+    }
+
+    /// Updates the checksum after the page was written.
+    fn update_checksum(&mut self) {
+        self.checksum = self.compute_checksum();
+    }
+
+    /// Gets the checksum of the page.
+    /// # Returns
+    /// The stored checksum of the page.
+    pub fn get_checksum(&self) -> u32 {
+        self.checksum
+    }
+
+    /// Checks that the content of the page matches its checksum.
+    /// # Returns
+    /// `true` if the page is intact, `false` if it is corrupted.
+    pub fn verify(&self) -> bool {
+        self.checksum == self.compute_checksum()
+    }
+
+    /// Writes the page to bytes, so it can be flushed to a storage.
+    /// # Returns
+    /// The information, the checksum and the data of the page.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let info: [u8; 2 + USIZE_SIZE] = self.info.into();
+        let mut bytes = Vec::with_capacity(PAGE_BYTES_SIZE);
+        bytes.extend_from_slice(&info);
+        bytes.extend_from_slice(&self.checksum.to_be_bytes());
+        bytes.extend_from_slice(&self.data);
+
+        bytes
+    }
+
+    /// Loads the page from bytes written by [`Page::to_bytes`] and verifies
+    /// its checksum.
+    /// # Arguments
+    /// * `bytes` - The bytes of the page.
+    /// # Returns
+    /// The loaded page.
+    /// # Errors
+    /// * `PageError::LengthMismatch` - The length of the bytes is not
+    ///   [`PAGE_BYTES_SIZE`].
+    /// * `PageError::ChecksumMismatch` - The page is corrupted.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Page, PageError> {
+        if bytes.len() != PAGE_BYTES_SIZE {
+            return Err(PageError::LengthMismatch);
+        }
+        let (info, bytes) = bytes.split_at(2 + USIZE_SIZE);
+        let (checksum, data) = bytes.split_at(4);
+
+        let page = Page {
+            info: PageInfo::from(
+                <[u8; 2 + USIZE_SIZE]>::try_from(info).unwrap(),
+            ),
+            data: data.try_into().unwrap(),
+            checksum: u32::from_be_bytes(checksum.try_into().unwrap()),
+        };
+        if !page.verify() {
+            return Err(PageError::ChecksumMismatch(page.get_index()));
         }
+
+        Ok(page)
     }
 
     /// Gets the data of the page without the free space.
@@ -139,6 +224,7 @@ impl Page {
             self.info.free -= 1;
             i += 1;
         }
+        self.update_checksum();
     }
 
     /// Updates the data of the page with the given data.
@@ -161,6 +247,7 @@ impl Page {
             self.data[link.start as usize + i] = data[i];
             i += 1;
         }
+        self.update_checksum();
         let res_link = PageLink::new(self.info.index, link.start, link.len);
         Ok(res_link)
     }
@@ -175,6 +262,7 @@ impl Page {
             self.data[link.start as usize + i] = 0;
             i += 1;
         }
+        self.update_checksum();
     }
 
     /// Gets the data of the page with the given link.
@@ -185,6 +273,24 @@ impl Page {
     pub fn get_by_link(&self, link: PageLink) -> &[u8] {
         &self.data[link.start as usize..link.start as usize + link.len as usize]
     }
+
+    /// Overwrites a byte of the page without updating the checksum, as a
+    /// failing disk would.
+    #[cfg(test)]
+    pub(crate) fn corrupt(&mut self, index: usize) {
+        self.data[index] ^= 0xff;
+    }
+}
+
+/// Errors of loading a [`Page`].
+#[derive(Debug, PartialEq)]
+pub enum PageError {
+    /// The bytes don't have the size of a page.
+    LengthMismatch,
+
+    /// The checksum of the page with the given index doesn't match its
+    /// content.
+    ChecksumMismatch(usize),
 }
 
 #[cfg(test)]
@@ -306,4 +412,48 @@ mod tests {
 
         assert_eq!(page.get_by_link(link), &[1, 2, 3, 4, 5]);
     }
+
+    #[test]
+    fn test_page_checksum() {
+        let mut page = super::Page::new(0);
+        assert!(page.verify());
+
+        let checksum = page.get_checksum();
+        page.attach_data(&[1, 2, 3]);
+        assert_ne!(page.get_checksum(), checksum);
+        assert!(page.verify());
+
+        page.update_data(&[4, 5], super::PageLink::new(0, 1, 2))
+            .unwrap();
+        assert!(page.verify());
+        page.erase_data(super::PageLink::new(0, 0, 1));
+        assert!(page.verify());
+
+        page.corrupt(2);
+        assert!(!page.verify());
+    }
+
+    #[test]
+    fn test_page_to_from_bytes() {
+        let mut page = super::Page::new(3);
+        page.attach_data(&[1, 2, 3]);
+
+        let bytes = page.to_bytes();
+        assert_eq!(bytes.len(), super::PAGE_BYTES_SIZE);
+        let loaded = super::Page::from_bytes(&bytes).unwrap();
+        assert_eq!(loaded.get_index(), 3);
+        assert_eq!(loaded.get_data(), &[1, 2, 3]);
+        assert_eq!(loaded.get_checksum(), page.get_checksum());
+
+        let mut corrupted = bytes.clone();
+        corrupted[super::PAGE_BYTES_SIZE - 1] = 1;
+        assert_eq!(
+            super::Page::from_bytes(&corrupted).unwrap_err(),
+            super::PageError::ChecksumMismatch(3)
+        );
+        assert_eq!(
+            super::Page::from_bytes(&bytes[1..]).unwrap_err(),
+            super::PageError::LengthMismatch
+        );
+    }
 }
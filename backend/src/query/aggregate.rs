@@ -0,0 +1,792 @@
+//! Hash aggregation with `GROUP BY` and `HAVING`.
+//!
+//! Rows are put into groups by the values of the `GROUP BY` expressions,
+//! the groups are kept in a [`ScalableHashTable`] that maps the values to
+//! the state of the group's aggregates. `NULL`s are ignored by all
+//! aggregates except `COUNT(*)`, and all `NULL` keys form a single group.
+
+use std::{fmt::Display, str::FromStr};
+
+use common::structs::hash_table::{scalable::ScalableHashTable, HashTable};
+use derive_more::Display;
+
+use crate::{
+    data::DataUnit,
+    expression::{self, BinaryOperator, EvaluationError, Expression},
+    query::{
+        executor,
+        key::{normalize, HashKey},
+    },
+    schema::{
+        column,
+        r#type::{
+            decimal::MAX_PRECISION,
+            r#enum::{StorageData, StorageDataType},
+            DataRow,
+        },
+    },
+};
+
+/// An aggregate function.
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq)]
+pub enum AggregateFunction {
+    /// Counts the rows or the non-`NULL` values.
+    #[display(fmt = "COUNT")]
+    Count,
+
+    /// Sums the values. Integers are summed as `i128` and floats as `f64`.
+    #[display(fmt = "SUM")]
+    Sum,
+
+    /// Averages the values. The average of integers is a decimal.
+    #[display(fmt = "AVG")]
+    Avg,
+
+    /// Returns the smallest value.
+    #[display(fmt = "MIN")]
+    Min,
+
+    /// Returns the largest value.
+    #[display(fmt = "MAX")]
+    Max,
+}
+
+impl FromStr for AggregateFunction {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "count" => Ok(AggregateFunction::Count),
+            "sum" => Ok(AggregateFunction::Sum),
+            "avg" => Ok(AggregateFunction::Avg),
+            "min" => Ok(AggregateFunction::Min),
+            "max" => Ok(AggregateFunction::Max),
+            _ => Err(()),
+        }
+    }
+}
+
+/// An aggregate of a query, e.g. `COUNT(DISTINCT city)`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Aggregate {
+    /// The function.
+    pub function: AggregateFunction,
+
+    /// The aggregated expression, `None` means `*`, that is allowed only
+    /// for `COUNT`.
+    pub argument: Option<Expression>,
+
+    /// Whether only distinct values are aggregated.
+    pub distinct: bool,
+
+    /// The name of the result column, `None` means the aggregate as it's
+    /// written in SQL.
+    pub alias: Option<column::Name>,
+}
+
+impl Aggregate {
+    /// Creates an aggregate of the expression.
+    /// # Arguments
+    /// * `function` - The function.
+    /// * `argument` - The aggregated expression.
+    pub fn new(function: AggregateFunction, argument: Expression) -> Self {
+        Self {
+            function,
+            argument: Some(argument),
+            distinct: false,
+            alias: None,
+        }
+    }
+
+    /// Creates `COUNT(*)`.
+    pub fn count_all() -> Self {
+        Self {
+            function: AggregateFunction::Count,
+            argument: None,
+            distinct: false,
+            alias: None,
+        }
+    }
+
+    /// Aggregates only distinct values.
+    pub fn distinct(mut self) -> Self {
+        self.distinct = true;
+        self
+    }
+
+    /// Sets the name of the result column, as `AS alias`.
+    pub fn alias<N: Into<column::Name>>(mut self, alias: N) -> Self {
+        self.alias = Some(alias.into());
+        self
+    }
+
+    /// Returns the name of the result column.
+    pub fn name(&self) -> column::Name {
+        match &self.alias {
+            Some(alias) => alias.clone(),
+            None => self.to_string().as_str().into(),
+        }
+    }
+
+    /// Returns the type of the result column without aggregating any rows.
+    /// # Arguments
+    /// * `column_type` - Returns the data type of the column with the given
+    ///   name.
+    /// # Returns
+    /// * `Option<StorageDataType>` - The type, or `None` if it's unknown,
+    ///   e.g. the argument is `NULL` or the function isn't defined for it.
+    pub fn data_type<F>(&self, column_type: F) -> Option<StorageDataType>
+    where
+        F: Fn(&column::Name) -> Option<StorageDataType>,
+    {
+        if self.function == AggregateFunction::Count {
+            return Some(StorageDataType::Long);
+        }
+
+        let data_type =
+            self.argument.as_ref()?.data_type(column_type).ok()??;
+        match self.function {
+            AggregateFunction::Sum => Some(widened_type(data_type)),
+            AggregateFunction::Avg => {
+                let sum = if data_type.is_integer() {
+                    StorageDataType::Decimal(MAX_PRECISION, 0)
+                } else {
+                    widened_type(data_type)
+                };
+                expression::binary_type(
+                    BinaryOperator::Divide,
+                    &sum,
+                    &StorageDataType::Long,
+                )
+                .ok()
+            }
+            _ => Some(data_type),
+        }
+    }
+}
+
+impl Display for Aggregate {
+    /// Formats the aggregate as in SQL, e.g. `SUM(DISTINCT price)`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}(", self.function)?;
+        if self.distinct {
+            write!(f, "DISTINCT ")?;
+        }
+        match &self.argument {
+            Some(argument) => write!(f, "{})", argument),
+            None => write!(f, "*)"),
+        }
+    }
+}
+
+/// Aggregation of rows, as `SELECT group_by..., aggregates... GROUP BY
+/// group_by... HAVING having`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Aggregation {
+    /// The expressions rows are grouped by. Empty means all rows form a
+    /// single group, that exists even if there are no rows.
+    pub group_by: Vec<Expression>,
+
+    /// The aggregates computed for every group.
+    pub aggregates: Vec<Aggregate>,
+
+    /// The condition groups must pass. It's evaluated over the result
+    /// columns, so aggregates are referred to by their names, e.g.
+    /// `"COUNT(*)" > 1`.
+    pub having: Option<Expression>,
+}
+
+impl Aggregation {
+    /// Returns the names of the result columns: the `GROUP BY` expressions
+    /// as they are written in SQL followed by the names of the aggregates.
+    pub fn column_names(&self) -> Vec<column::Name> {
+        self.group_by
+            .iter()
+            .map(|expression| expression.to_string().as_str().into())
+            .chain(self.aggregates.iter().map(Aggregate::name))
+            .collect()
+    }
+
+    /// Returns the types of the result columns in the order of
+    /// [`Aggregation::column_names`], `None` if the type of a column is
+    /// unknown.
+    /// # Arguments
+    /// * `column_type` - Returns the data type of the column of the rows
+    ///   with the given name.
+    pub fn column_types<F>(
+        &self,
+        column_type: F,
+    ) -> Vec<Option<StorageDataType>>
+    where
+        F: Fn(&column::Name) -> Option<StorageDataType>,
+    {
+        self.group_by
+            .iter()
+            .map(|expression| expression.data_type(&column_type).ok().flatten())
+            .chain(
+                self.aggregates
+                    .iter()
+                    .map(|aggregate| aggregate.data_type(&column_type)),
+            )
+            .collect()
+    }
+
+    /// Aggregates the rows.
+    /// # Arguments
+    /// * `rows` - The rows, e.g. from [`controller::Table::get_data`]. They
+    ///   must have all columns the expressions use.
+    /// # Returns
+    /// * `Result<DataUnit, AggregationError>` - A row for every group that
+    ///   passes `HAVING`, in the order of the groups' first rows.
+    ///
+    /// [`controller::Table::get_data`]: crate::controller::Table::get_data
+    pub fn aggregate(
+        &self,
+        rows: DataUnit,
+    ) -> Result<DataUnit, AggregationError> {
+        let names = rows.get_names().to_vec();
+        let rows_types = rows.get_types().to_vec();
+        let rows = rows.get_values().into_iter().map(Ok);
+        let rows = self.aggregate_rows::<_, AggregationError>(&names, rows)?;
+
+        let types = self.column_types(|name| {
+            executor::column_type(&names, &rows_types, name)
+        });
+        let mut data_unit =
+            DataUnit::new(self.column_names()).with_types(types);
+        for row in rows {
+            data_unit.insert(row);
+        }
+
+        Ok(data_unit)
+    }
+
+    /// Aggregates the rows while they are read, only the groups are kept in
+    /// memory.
+    /// # Arguments
+    /// * `names` - The names of the columns of the rows.
+    /// * `rows` - The rows or the errors of reading them.
+    /// # Returns
+    /// * `Result<Vec<DataRow>, E>` - A row for every group that passes
+    ///   `HAVING`, in the order of the groups' first rows, with the columns
+    ///   named by [`Aggregation::column_names`].
+    pub fn aggregate_rows<I, E>(
+        &self,
+        names: &[column::Name],
+        rows: I,
+    ) -> Result<Vec<DataRow>, E>
+    where
+        I: IntoIterator<Item = Result<DataRow, E>>,
+        E: From<AggregationError>,
+    {
+        for aggregate in &self.aggregates {
+            if aggregate.argument.is_none()
+                && aggregate.function != AggregateFunction::Count
+            {
+                return Err(AggregationError::MissingArgument(
+                    aggregate.function,
+                )
+                .into());
+            }
+        }
+
+        let mut indexes = ScalableHashTable::<HashKey, usize>::default();
+        let mut groups = Vec::new();
+        if self.group_by.is_empty() {
+            groups.push(self.new_group(Vec::new()));
+            indexes.insert(HashKey(Vec::new()), 0);
+        }
+
+        for row in rows {
+            let row = row?;
+            let value = |name: &column::Name| {
+                names
+                    .iter()
+                    .position(|column| column == name)
+                    .map(|position| &row.0[position])
+            };
+
+            let key = self
+                .group_by
+                .iter()
+                .map(|expression| expression.evaluate(value).map(normalize))
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(AggregationError::from)?;
+            let key = HashKey(key);
+            let index = match indexes.get(&key) {
+                Some(index) => index,
+                None => {
+                    indexes.insert(key.clone(), groups.len());
+                    groups.push(self.new_group(key.0));
+                    groups.len() - 1
+                }
+            };
+
+            let group: &mut Group = &mut groups[index];
+            for (aggregate, state) in
+                self.aggregates.iter().zip(group.states.iter_mut())
+            {
+                let value = match &aggregate.argument {
+                    Some(argument) => argument
+                        .evaluate(value)
+                        .map_err(AggregationError::from)?,
+                    None => StorageData::Bool(true.into()),
+                };
+                state.update(value)?;
+            }
+        }
+
+        let names = self.column_names();
+        let mut result = Vec::new();
+        for group in groups {
+            let mut row = group.key;
+            for state in group.states {
+                row.push(state.finish().map_err(AggregationError::from)?);
+            }
+
+            if let Some(having) = &self.having {
+                let passes = having
+                    .matches(|name| {
+                        names
+                            .iter()
+                            .position(|column| column == name)
+                            .map(|position| &row[position])
+                    })
+                    .map_err(AggregationError::from)?;
+                if !passes {
+                    continue;
+                }
+            }
+            result.push(DataRow(row));
+        }
+
+        Ok(result)
+    }
+
+    /// Creates a group with empty states of the aggregates.
+    fn new_group(&self, key: Vec<StorageData>) -> Group {
+        Group {
+            key,
+            states: self
+                .aggregates
+                .iter()
+                .map(|aggregate| AggregateState {
+                    function: aggregate.function,
+                    accumulator: Accumulator::default(),
+                    seen: aggregate.distinct.then(ScalableHashTable::default),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// A group of rows.
+#[derive(Debug)]
+struct Group {
+    /// The values of the `GROUP BY` expressions.
+    key: Vec<StorageData>,
+
+    /// The states of the aggregates.
+    states: Vec<AggregateState>,
+}
+
+/// State of an [`Aggregate`] for a group.
+#[derive(Debug)]
+struct AggregateState {
+    /// The function of the aggregate.
+    function: AggregateFunction,
+
+    /// The values aggregated so far.
+    accumulator: Accumulator,
+
+    /// The values seen by a `DISTINCT` aggregate.
+    seen: Option<ScalableHashTable<HashKey, ()>>,
+}
+
+/// Values aggregated so far.
+#[derive(Debug)]
+struct Accumulator {
+    /// The number of aggregated values.
+    count: i128,
+
+    /// The sum, the smallest or the largest value, `NULL` if there were no
+    /// values.
+    value: StorageData,
+}
+
+impl Default for Accumulator {
+    fn default() -> Self {
+        Self {
+            count: 0,
+            value: StorageData::Null,
+        }
+    }
+}
+
+impl AggregateState {
+    /// Aggregates the value, `NULL`s are skipped.
+    fn update(&mut self, value: StorageData) -> Result<(), AggregationError> {
+        if value == StorageData::Null {
+            return Ok(());
+        }
+        let value = normalize(value);
+        if !self.accepts(&data_type(&value)) {
+            return Err(AggregationError::UndefinedAggregate(
+                self.function,
+                data_type(&value),
+            ));
+        }
+        if let Some(seen) = &mut self.seen {
+            let key = HashKey(vec![value.clone()]);
+            if seen.get(&key).is_some() {
+                return Ok(());
+            }
+            seen.insert(key, ());
+        }
+
+        let accumulator = &mut self.accumulator;
+        accumulator.count += 1;
+        let current =
+            std::mem::replace(&mut accumulator.value, StorageData::Null);
+        accumulator.value = match self.function {
+            AggregateFunction::Count => current,
+            AggregateFunction::Sum | AggregateFunction::Avg => {
+                let value = widen(value)?;
+                if current == StorageData::Null {
+                    value
+                } else {
+                    expression::binary(BinaryOperator::Add, current, value)?
+                }
+            }
+            AggregateFunction::Min | AggregateFunction::Max => {
+                let replace = current == StorageData::Null
+                    || match expression::compare(&value, &current) {
+                        Some(ordering) => {
+                            if self.function == AggregateFunction::Min {
+                                ordering.is_lt()
+                            } else {
+                                ordering.is_gt()
+                            }
+                        }
+                        None => {
+                            return Err(EvaluationError::IncompatibleTypes(
+                                data_type(&current),
+                                data_type(&value),
+                            )
+                            .into())
+                        }
+                    };
+                if replace {
+                    value
+                } else {
+                    current
+                }
+            }
+        };
+
+        Ok(())
+    }
+
+    /// Checks if the function can aggregate values of the type. `SUM` and
+    /// `AVG` need numbers, `SUM` also sums intervals.
+    fn accepts(&self, data_type: &StorageDataType) -> bool {
+        match self.function {
+            AggregateFunction::Sum | AggregateFunction::Avg => {
+                data_type.is_integer()
+                    || matches!(
+                        data_type,
+                        StorageDataType::Float
+                            | StorageDataType::Double
+                            | StorageDataType::Decimal(_, _)
+                    )
+                    || (self.function == AggregateFunction::Sum
+                        && *data_type == StorageDataType::Interval)
+            }
+            _ => true,
+        }
+    }
+
+    /// Returns the result of the aggregate.
+    fn finish(self) -> Result<StorageData, EvaluationError> {
+        let Accumulator { count, value } = self.accumulator;
+        match self.function {
+            AggregateFunction::Count => Ok(count.into()),
+            AggregateFunction::Avg if count > 0 => {
+                let sum = if data_type(&value).is_integer() {
+                    expression::cast(
+                        value,
+                        &StorageDataType::Decimal(MAX_PRECISION, 0),
+                    )?
+                } else {
+                    value
+                };
+                expression::binary(BinaryOperator::Divide, sum, count.into())
+            }
+            _ => Ok(value),
+        }
+    }
+}
+
+/// Returns the type of a value that isn't `NULL`.
+fn data_type(value: &StorageData) -> StorageDataType {
+    value
+        .data_type()
+        .expect("only values that aren't NULL are aggregated")
+}
+
+/// Widens the value to the type of sums, see [`widened_type`].
+fn widen(value: StorageData) -> Result<StorageData, EvaluationError> {
+    let widened = widened_type(data_type(&value));
+    if widened == data_type(&value) {
+        Ok(value)
+    } else {
+        expression::cast(value, &widened)
+    }
+}
+
+/// Returns the type of sums of values of the type, integers are summed as
+/// `i128` and floats as `f64`, so that sums don't overflow the type of the
+/// values.
+fn widened_type(data_type: StorageDataType) -> StorageDataType {
+    match data_type {
+        StorageDataType::Long | StorageDataType::ULong => data_type,
+        _ if data_type.is_integer() => StorageDataType::Long,
+        StorageDataType::Float => StorageDataType::Double,
+        _ => data_type,
+    }
+}
+
+/// Errors that can occur during [`Aggregation::aggregate`].
+#[derive(Debug, Display, Clone, PartialEq)]
+pub enum AggregationError {
+    /// The aggregate has no argument, only `COUNT(*)` can have it.
+    #[display(fmt = "`{}(*)` is not allowed, only `COUNT(*)` is", _0)]
+    MissingArgument(AggregateFunction),
+
+    /// The aggregate function is not defined for the type of its argument.
+    #[display(fmt = "Aggregate `{}` is not defined for `{}`", _0, _1)]
+    UndefinedAggregate(AggregateFunction, StorageDataType),
+
+    /// An expression can't be evaluated.
+    #[display(fmt = "{}", _0)]
+    EvaluationError(EvaluationError),
+}
+
+impl From<EvaluationError> for AggregationError {
+    fn from(error: EvaluationError) -> Self {
+        AggregationError::EvaluationError(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        data::DataUnit,
+        expression::{BinaryOperator, EvaluationError, Expression},
+        query::aggregate::{
+            Aggregate, AggregateFunction, Aggregation, AggregationError,
+        },
+        schema::r#type::{
+            data_types::{Decimal, Text},
+            r#enum::{StorageData, StorageDataType},
+            DataRow,
+        },
+    };
+
+    fn column(name: &str) -> Expression {
+        Expression::Column(name.into())
+    }
+
+    fn text(value: &str) -> StorageData {
+        Text(value.into()).into()
+    }
+
+    /// Orders with a city, an amount and a price, the price of one order is
+    /// `NULL`.
+    fn orders() -> DataUnit {
+        let mut data =
+            DataUnit::new(vec!["city".into(), "amount".into(), "price".into()]);
+        for (city, amount, price) in [
+            ("Oslo", 2u8, Some(10)),
+            ("Rome", 1, Some(7)),
+            ("Oslo", 2, None),
+            ("Oslo", 5, Some(3)),
+            ("Rome", 3, Some(7)),
+        ] {
+            let price = price.map_or(StorageData::Null, StorageData::from);
+            data.insert(DataRow(vec![text(city), amount.into(), price]));
+        }
+
+        data
+    }
+
+    #[test]
+    fn test_aggregate_without_group_by() {
+        let aggregation = Aggregation {
+            aggregates: vec![
+                Aggregate::count_all(),
+                Aggregate::new(AggregateFunction::Count, column("price")),
+                Aggregate::new(AggregateFunction::Count, column("price"))
+                    .distinct(),
+                Aggregate::new(AggregateFunction::Sum, column("amount")),
+                Aggregate::new(AggregateFunction::Avg, column("price")),
+                Aggregate::new(AggregateFunction::Min, column("city")),
+                Aggregate::new(AggregateFunction::Max, column("price"))
+                    .alias("top"),
+            ],
+            ..Default::default()
+        };
+
+        let result = aggregation.aggregate(orders()).unwrap();
+        assert_eq!(
+            result.get_names(),
+            &[
+                "COUNT(*)".into(),
+                "COUNT(price)".into(),
+                "COUNT(DISTINCT price)".into(),
+                "SUM(amount)".into(),
+                "AVG(price)".into(),
+                "MIN(city)".into(),
+                "top".into(),
+            ]
+        );
+        assert_eq!(
+            result.get_values(),
+            vec![DataRow(vec![
+                5i128.into(),
+                4i128.into(),
+                3i128.into(),
+                13i128.into(),
+                "6.750000".parse::<Decimal>().unwrap().into(),
+                text("Oslo"),
+                10.into(),
+            ])]
+        );
+
+        let aggregation = Aggregation {
+            aggregates: vec![
+                Aggregate::count_all(),
+                Aggregate::new(AggregateFunction::Sum, column("price")),
+            ],
+            ..Default::default()
+        };
+        let empty = DataUnit::new(vec!["price".into()]);
+        assert_eq!(
+            aggregation.aggregate(empty).unwrap().get_values(),
+            vec![DataRow(vec![0i128.into(), StorageData::Null])]
+        );
+    }
+
+    #[test]
+    fn test_group_by_having() {
+        let aggregation = Aggregation {
+            group_by: vec![column("city")],
+            aggregates: vec![
+                Aggregate::count_all(),
+                Aggregate::new(
+                    AggregateFunction::Sum,
+                    Expression::binary(
+                        column("amount"),
+                        BinaryOperator::Multiply,
+                        column("price"),
+                    ),
+                )
+                .alias("total"),
+            ],
+            having: None,
+        };
+
+        let result = aggregation.aggregate(orders()).unwrap();
+        assert_eq!(
+            result.get_names(),
+            &["city".into(), "COUNT(*)".into(), "total".into()]
+        );
+        assert_eq!(
+            result.get_values(),
+            vec![
+                DataRow(vec![text("Oslo"), 3i128.into(), 35i128.into()]),
+                DataRow(vec![text("Rome"), 2i128.into(), 28i128.into()]),
+            ]
+        );
+
+        let aggregation = Aggregation {
+            having: Some(Expression::binary(
+                column("COUNT(*)"),
+                BinaryOperator::LessThan,
+                Expression::Literal(3.into()),
+            )),
+            ..aggregation
+        };
+        let result = aggregation.aggregate(orders()).unwrap();
+        assert_eq!(
+            result.get_values(),
+            vec![DataRow(vec![text("Rome"), 2i128.into(), 28i128.into()])]
+        );
+
+        let aggregation = Aggregation {
+            group_by: vec![column("price")],
+            aggregates: vec![Aggregate::new(
+                AggregateFunction::Sum,
+                column("amount"),
+            )],
+            having: None,
+        };
+        let result = aggregation.aggregate(orders()).unwrap();
+        assert_eq!(result.len(), 4);
+        assert_eq!(
+            result.get_values()[2],
+            DataRow(vec![StorageData::Null, 2i128.into()])
+        );
+    }
+
+    #[test]
+    fn test_aggregate_errors() {
+        let aggregation = Aggregation {
+            aggregates: vec![Aggregate {
+                argument: None,
+                ..Aggregate::new(AggregateFunction::Sum, column("amount"))
+            }],
+            ..Default::default()
+        };
+        assert_eq!(
+            aggregation.aggregate(orders()),
+            Err(AggregationError::MissingArgument(AggregateFunction::Sum))
+        );
+
+        let aggregation = Aggregation {
+            aggregates: vec![Aggregate::new(
+                AggregateFunction::Sum,
+                column("city"),
+            )],
+            ..Default::default()
+        };
+        assert_eq!(
+            aggregation.aggregate(orders()),
+            Err(AggregationError::UndefinedAggregate(
+                AggregateFunction::Sum,
+                StorageDataType::Text
+            ))
+        );
+
+        let aggregation = Aggregation {
+            group_by: vec![column("country")],
+            ..Default::default()
+        };
+        assert_eq!(
+            aggregation.aggregate(orders()),
+            Err(AggregationError::EvaluationError(
+                EvaluationError::ColumnDoesNotExist("country".into())
+            ))
+        );
+    }
+
+    #[test]
+    fn test_aggregate_function_from_str() {
+        assert_eq!("count".parse(), Ok(AggregateFunction::Count));
+        assert_eq!("SUM".parse(), Ok(AggregateFunction::Sum));
+        assert_eq!("Avg".parse(), Ok(AggregateFunction::Avg));
+        assert_eq!("min".parse(), Ok(AggregateFunction::Min));
+        assert_eq!("MAX".parse(), Ok(AggregateFunction::Max));
+        assert_eq!("lower".parse::<AggregateFunction>(), Err(()));
+    }
+}
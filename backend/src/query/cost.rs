@@ -0,0 +1,318 @@
+//! The cost model the planner chooses access paths, join algorithms and
+//! join orders by.
+//!
+//! Costs are in units of reading a row from the pages of a table, the CPU
+//! work of operators is a fraction of it. The numbers of rows are estimated
+//! by the [`TableStatistics`] collected by `ANALYZE`, or by default
+//! selectivities for tables that weren't analyzed.
+//!
+//! [`TableStatistics`]: crate::schema::table::statistics::TableStatistics
+
+use crate::{
+    expression::Expression,
+    query::join::{Join, JoinAlgorithm, JoinType},
+    schema::{column, r#type::r#enum::StorageData},
+};
+
+/// The cost of reading a row from the pages of a table.
+pub const ROW_READ_COST: f64 = 1.0;
+
+/// The cost of checking a filter or a condition for a row.
+pub const OPERATOR_COST: f64 = 0.0025;
+
+/// The cost of putting a row into a hash table or of probing it, also of
+/// looking a row up in the hashed primary key index of a table.
+pub const HASH_COST: f64 = 0.005;
+
+/// The cost of returning or keeping a row in an operator.
+pub const ROW_COST: f64 = 0.01;
+
+/// The fraction of pairs of rows estimated to match a join condition that
+/// compares no columns for equality.
+pub const DEFAULT_JOIN_SELECTIVITY: f64 = 1.0 / 3.0;
+
+/// A way to read the rows of a table.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AccessPath {
+    /// Reads all rows of the table.
+    Scan,
+
+    /// Looks up the row with the primary key.
+    IndexScan(StorageData),
+}
+
+/// Returns the cost of an [`AccessPath::Scan`].
+/// # Arguments
+/// * `table_rows` - The number of rows of the table.
+/// * `filters` - The number of filters checked for every row.
+/// * `rows` - The estimated number of rows that pass the filters.
+pub fn scan_cost(table_rows: f64, filters: usize, rows: f64) -> f64 {
+    table_rows * (ROW_READ_COST + filters as f64 * OPERATOR_COST)
+        + rows * ROW_COST
+}
+
+/// Returns the cost of an [`AccessPath::IndexScan`]. A key is looked up by
+/// a probe of the table's hashed primary key index, so the cost doesn't
+/// depend on the number of rows of the table.
+/// # Arguments
+/// * `keys` - The number of looked up keys.
+/// * `filters` - The number of filters checked for every found row.
+pub fn index_scan_cost(keys: f64, filters: usize) -> f64 {
+    keys * (HASH_COST
+        + ROW_READ_COST
+        + filters as f64 * OPERATOR_COST
+        + ROW_COST)
+}
+
+/// The estimated size of an input of a join, a scan of a table.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JoinInput {
+    /// The qualified names of the columns.
+    pub column_names: Vec<column::Name>,
+
+    /// The estimated numbers of distinct values of the columns.
+    pub distinct_counts: Vec<f64>,
+
+    /// The estimated number of rows.
+    pub rows: f64,
+
+    /// The qualified name of the primary key column, if the table has one.
+    pub primary_key: Option<column::Name>,
+}
+
+impl JoinInput {
+    /// Returns the estimated number of distinct values of the expression,
+    /// the number of rows if it isn't a column.
+    fn distinct_count(&self, expression: &Expression) -> f64 {
+        let Expression::Column(name) = expression else {
+            return self.rows;
+        };
+
+        self.column_names
+            .iter()
+            .position(|column| column == name)
+            .map_or(self.rows, |position| self.distinct_counts[position])
+            .max(1.0)
+    }
+}
+
+/// A plan of a join chosen by [`plan_join`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct JoinPlan {
+    /// The algorithm of the join.
+    pub algorithm: JoinAlgorithm,
+
+    /// Whether the right input is joined with the left one by
+    /// [`Join::swapped`], e.g. because it's smaller.
+    pub swapped: bool,
+
+    /// The estimated number of joined rows.
+    pub rows: f64,
+
+    /// The estimated cost of the join.
+    pub cost: f64,
+}
+
+/// Chooses the cheapest algorithm and order of a join, from the nested
+/// loop join, the hash join if the condition compares columns for equality
+/// and the index nested loop join if the primary key of the right input is
+/// one of them, for both orders of the inputs.
+/// # Arguments
+/// * `join` - The join.
+/// * `left` - The left input.
+/// * `right` - The right input.
+/// # Returns
+/// * `JoinPlan` - The cheapest plan, the first one of the same cost in the
+///   order above with the inputs as they are.
+pub fn plan_join(join: &Join, left: &JoinInput, right: &JoinInput) -> JoinPlan {
+    let rows = join_rows(join, left, right);
+
+    let mut best: Option<JoinPlan> = None;
+    for swapped in [false, true] {
+        let (join, left, right) = if swapped {
+            (join.swapped(), right, left)
+        } else {
+            (join.clone(), left, right)
+        };
+
+        let keys = join.equi_keys(&left.column_names, &right.column_names);
+        let mut algorithms = vec![JoinAlgorithm::NestedLoop];
+        if !keys.is_empty() {
+            algorithms.push(JoinAlgorithm::Hash);
+        }
+        let algorithm = join.algorithm(
+            &left.column_names,
+            &right.column_names,
+            right.primary_key.as_ref(),
+        );
+        if let JoinAlgorithm::IndexNestedLoop(_) = algorithm {
+            algorithms.push(algorithm);
+        }
+
+        for algorithm in algorithms {
+            let cost = join_cost(&algorithm, left, right, rows);
+            if best.as_ref().is_none_or(|best| cost < best.cost) {
+                best = Some(JoinPlan {
+                    algorithm,
+                    swapped,
+                    rows,
+                    cost,
+                });
+            }
+        }
+    }
+
+    best.expect("nested loop join is always possible")
+}
+
+/// Estimates the number of joined rows. Equal values of the compared
+/// expressions are assumed to be spread evenly, so a value of the side
+/// with fewer distinct values matches the rows of one value of the other.
+fn join_rows(join: &Join, left: &JoinInput, right: &JoinInput) -> f64 {
+    let pairs = left.rows * right.rows;
+    let matched = match join.join_type {
+        JoinType::Cross => pairs,
+        _ => {
+            let keys = join.equi_keys(&left.column_names, &right.column_names);
+            if keys.is_empty() {
+                pairs * DEFAULT_JOIN_SELECTIVITY
+            } else {
+                keys.iter().fold(pairs, |rows, (left_key, right_key)| {
+                    rows / left
+                        .distinct_count(left_key)
+                        .max(right.distinct_count(right_key))
+                })
+            }
+        }
+    };
+
+    match join.join_type {
+        JoinType::Left => matched.max(left.rows),
+        JoinType::Right => matched.max(right.rows),
+        JoinType::Full => matched.max(left.rows).max(right.rows),
+        JoinType::Inner | JoinType::Cross => matched,
+    }
+}
+
+/// Returns the cost of joining the inputs with the algorithm, including
+/// the scans of the inputs.
+fn join_cost(
+    algorithm: &JoinAlgorithm,
+    left: &JoinInput,
+    right: &JoinInput,
+    rows: f64,
+) -> f64 {
+    let output = rows * ROW_COST;
+    let left_scan = scan_cost(left.rows, 0, left.rows);
+    match algorithm {
+        JoinAlgorithm::NestedLoop => {
+            left_scan
+                + scan_cost(right.rows, 0, right.rows)
+                + left.rows * right.rows * OPERATOR_COST
+                + output
+        }
+        JoinAlgorithm::Hash => {
+            left_scan
+                + scan_cost(right.rows, 0, right.rows)
+                + (left.rows + right.rows) * HASH_COST
+                + rows * OPERATOR_COST
+                + output
+        }
+        JoinAlgorithm::IndexNestedLoop(_) => {
+            left_scan
+                + index_scan_cost(left.rows, 0)
+                + rows * OPERATOR_COST
+                + output
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        expression::{BinaryOperator, Expression},
+        query::{
+            cost::{
+                index_scan_cost, plan_join, scan_cost, JoinInput, JoinPlan,
+            },
+            Join, JoinAlgorithm, JoinType,
+        },
+    };
+
+    /// A table with the columns `id` and `ref_id` and the number of rows,
+    /// the ids are unique and there are 10 distinct `ref_id`s.
+    fn input(table: &str, rows: f64) -> JoinInput {
+        JoinInput {
+            column_names: vec![
+                format!("{}.id", table).as_str().into(),
+                format!("{}.ref_id", table).as_str().into(),
+            ],
+            distinct_counts: vec![rows, 10.0_f64.min(rows)],
+            rows,
+            primary_key: Some(format!("{}.id", table).as_str().into()),
+        }
+    }
+
+    fn on(left: &str, right: &str) -> Expression {
+        Expression::binary(
+            Expression::Column(left.into()),
+            BinaryOperator::Equal,
+            Expression::Column(right.into()),
+        )
+    }
+
+    fn plan(join: Join, left: &JoinInput, right: &JoinInput) -> JoinPlan {
+        plan_join(&join, left, right)
+    }
+
+    #[test]
+    fn test_access_path_cost() {
+        assert!(index_scan_cost(1.0, 1) < scan_cost(1000.0, 1, 1.0));
+        assert!(index_scan_cost(1.0, 1) < scan_cost(10.0, 1, 1.0));
+        assert!(index_scan_cost(1000.0, 0) > scan_cost(1000.0, 0, 1000.0));
+    }
+
+    #[test]
+    fn test_plan_join() {
+        let (users, orders) = (input("users", 1000.0), input("orders", 5.0));
+        let join =
+            || Join::new(JoinType::Inner, on("users.id", "orders.ref_id"));
+
+        let small_outer = plan(join(), &orders, &users);
+        assert_eq!(
+            small_outer.algorithm,
+            JoinAlgorithm::IndexNestedLoop(Expression::Column(
+                "orders.ref_id".into()
+            ))
+        );
+        assert!(!small_outer.swapped);
+        assert_eq!(small_outer.rows, 5.0);
+
+        let swapped = plan(join(), &users, &orders);
+        assert!(swapped.swapped);
+        assert_eq!(swapped.algorithm, small_outer.algorithm);
+        assert_eq!(swapped.cost, small_outer.cost);
+
+        let orders = input("orders", 800.0);
+        let lookups = plan(join(), &users, &orders);
+        assert!(matches!(
+            lookups.algorithm,
+            JoinAlgorithm::IndexNestedLoop(_)
+        ));
+        assert!(lookups.swapped);
+
+        let orders = input("orders", 2000.0);
+        let hash = plan(join(), &users, &orders);
+        assert_eq!(hash.algorithm, JoinAlgorithm::Hash);
+        assert_eq!(hash.rows, 2000.0);
+
+        let join = Join::new(JoinType::Full, on("users.id", "orders.ref_id"));
+        let full = plan(join, &users, &orders);
+        assert_eq!(full.algorithm, JoinAlgorithm::Hash);
+        assert_eq!(full.rows, 2000.0);
+
+        let cross = plan(Join::cross(), &orders, &users);
+        assert_eq!(cross.algorithm, JoinAlgorithm::NestedLoop);
+        assert_eq!(cross.rows, 2_000_000.0);
+    }
+}
@@ -0,0 +1,78 @@
+use std::vec;
+
+use crate::{
+    controller::table::TableControllerError,
+    query::{
+        executor::{column_type, rows, Operator},
+        Aggregation,
+    },
+    schema::{
+        column,
+        r#type::{r#enum::StorageDataType, DataRow},
+    },
+};
+
+/// Aggregates the rows of the input by [`Aggregation`]. All rows of the
+/// input are read when the first row is pulled, only the groups are kept in
+/// memory.
+pub struct Aggregate<'a> {
+    /// The input operator, `None` once its rows are read.
+    input: Option<Box<dyn Operator + 'a>>,
+
+    /// The aggregation.
+    aggregation: Aggregation,
+
+    /// The names of the result columns, see [`Aggregation::column_names`].
+    column_names: Vec<column::Name>,
+
+    /// The types of the result columns, see [`Aggregation::column_types`].
+    column_types: Vec<Option<StorageDataType>>,
+
+    /// The aggregated rows that are not returned yet.
+    rows: vec::IntoIter<DataRow>,
+}
+
+impl<'a> Aggregate<'a> {
+    /// Creates a new [`Aggregate`].
+    /// # Arguments
+    /// * `input` - The input operator.
+    /// * `aggregation` - The aggregation over the columns of the input.
+    pub fn new(
+        input: Box<dyn Operator + 'a>,
+        aggregation: Aggregation,
+    ) -> Self {
+        let column_types = aggregation.column_types(|name| {
+            column_type(input.column_names(), input.column_types(), name)
+        });
+
+        Self {
+            input: Some(input),
+            column_names: aggregation.column_names(),
+            column_types,
+            aggregation,
+            rows: Vec::new().into_iter(),
+        }
+    }
+}
+
+impl Operator for Aggregate<'_> {
+    fn column_names(&self) -> &[column::Name] {
+        &self.column_names
+    }
+
+    fn column_types(&self) -> &[Option<StorageDataType>] {
+        &self.column_types
+    }
+
+    fn next(&mut self) -> Result<Option<DataRow>, TableControllerError> {
+        if let Some(mut input) = self.input.take() {
+            let names = input.column_names().to_vec();
+            self.rows = self
+                .aggregation
+                .aggregate_rows(&names, rows(&mut input))?
+                .into_iter();
+        }
+
+        Ok(self.rows.next())
+    }
+}
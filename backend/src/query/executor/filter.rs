@@ -0,0 +1,62 @@
+use crate::{
+    controller::table::TableControllerError,
+    expression::Expression,
+    query::executor::{check_columns, column_value, Operator},
+    schema::{
+        column,
+        r#type::{r#enum::StorageDataType, DataRow},
+    },
+};
+
+/// Returns the rows of the input that match a condition, as `WHERE`.
+pub struct Filter<'a> {
+    /// The input operator.
+    input: Box<dyn Operator + 'a>,
+
+    /// The condition, rows for which it's `FALSE` or `NULL` are skipped.
+    condition: Expression,
+}
+
+impl<'a> Filter<'a> {
+    /// Creates a new [`Filter`].
+    /// # Arguments
+    /// * `input` - The input operator.
+    /// * `condition` - The condition over the columns of the input.
+    /// # Returns
+    /// * `Result<Self, TableControllerError>` - The filter or
+    ///   [`TableControllerError::ColumnDoesNotExist`] if the condition uses
+    ///   a column the input doesn't have.
+    pub fn new(
+        input: Box<dyn Operator + 'a>,
+        condition: Expression,
+    ) -> Result<Self, TableControllerError> {
+        check_columns(input.column_names(), [&condition])?;
+
+        Ok(Self { input, condition })
+    }
+}
+
+impl Operator for Filter<'_> {
+    fn column_names(&self) -> &[column::Name] {
+        self.input.column_names()
+    }
+
+    fn column_types(&self) -> &[Option<StorageDataType>] {
+        self.input.column_types()
+    }
+
+    fn next(&mut self) -> Result<Option<DataRow>, TableControllerError> {
+        while let Some(row) = self.input.next()? {
+            let names = self.input.column_names();
+            let matches = self
+                .condition
+                .matches(|name| column_value(names, &row.0, name))
+                .map_err(TableControllerError::EvaluationError)?;
+            if matches {
+                return Ok(Some(row));
+            }
+        }
+
+        Ok(None)
+    }
+}
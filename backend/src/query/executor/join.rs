@@ -0,0 +1,625 @@
+use std::{collections::VecDeque, iter};
+
+use common::structs::hash_table::{scalable::ScalableHashTable, HashTable};
+
+use crate::{
+    controller::table::TableControllerError,
+    expression::Expression,
+    query,
+    query::{
+        executor::{column_value, Operator},
+        join::{hash_key, JoinError, JoinType},
+        key::HashKey,
+    },
+    schema::{
+        column,
+        r#type::{
+            r#enum::{StorageData, StorageDataType},
+            DataRow,
+        },
+    },
+};
+
+/// Looks up the right row by the value of the primary key for
+/// [`Join::index_nested_loop`].
+pub type Lookup<'a> = Box<
+    dyn FnMut(&StorageData) -> Result<Option<DataRow>, TableControllerError>
+        + 'a,
+>;
+
+/// Joins the rows of two inputs by [`query::Join`] with one of the
+/// [`JoinAlgorithm`]s. The left rows are streamed, the right rows are read
+/// into memory when the first row is pulled, except for the index nested
+/// loop join that looks them up one by one. Joined rows have the left
+/// columns followed by the right columns.
+///
+/// [`JoinAlgorithm`]: query::JoinAlgorithm
+pub struct Join<'a> {
+    /// The join.
+    join: query::Join,
+
+    /// The left input.
+    left: Box<dyn Operator + 'a>,
+
+    /// The right side.
+    right: Right<'a>,
+
+    /// The names of the joined columns.
+    column_names: Vec<column::Name>,
+
+    /// The types of the joined columns.
+    column_types: Vec<Option<StorageDataType>>,
+
+    /// The number of left columns.
+    left_width: usize,
+
+    /// The number of right columns.
+    right_width: usize,
+
+    /// The joined rows of the current left row that are not returned yet.
+    pending: VecDeque<DataRow>,
+
+    /// The position of the next right row to check for a match once all
+    /// left rows are joined, `None` until then.
+    unmatched: Option<usize>,
+}
+
+/// The right side of a [`Join`].
+enum Right<'a> {
+    /// The right rows are read into memory.
+    Rows(Build<'a>),
+
+    /// The right rows are looked up by the value of the key expression over
+    /// the left row.
+    Lookup { key: Expression, lookup: Lookup<'a> },
+}
+
+/// The right rows of a nested loop or hash join.
+struct Build<'a> {
+    /// The right input, `None` once its rows are read.
+    input: Option<Box<dyn Operator + 'a>>,
+
+    /// The expressions over the left and the right columns the rows are
+    /// hashed by, `None` for a nested loop join.
+    keys: Option<(Vec<Expression>, Vec<Expression>)>,
+
+    /// The right rows.
+    rows: Vec<DataRow>,
+
+    /// Whether the right rows matched a left row.
+    matched: Vec<bool>,
+
+    /// The positions of the buckets of the hash keys.
+    indexes: ScalableHashTable<HashKey, usize>,
+
+    /// The positions of the right rows with the same hash key.
+    buckets: Vec<Vec<usize>>,
+}
+
+impl Build<'_> {
+    /// Reads the right rows and hashes them if they aren't read yet. Rows
+    /// with `NULL` keys are never equal, so they aren't hashed.
+    fn build(&mut self) -> Result<(), TableControllerError> {
+        let Some(mut input) = self.input.take() else {
+            return Ok(());
+        };
+
+        while let Some(row) = input.next()? {
+            if let Some((_, right_keys)) = &self.keys {
+                let key = hash_key(right_keys, input.column_names(), &row)
+                    .map_err(JoinError::from)?;
+                if let Some(key) = key {
+                    match self.indexes.get(&key) {
+                        Some(index) => {
+                            self.buckets[index].push(self.rows.len())
+                        }
+                        None => {
+                            self.indexes.insert(key, self.buckets.len());
+                            self.buckets.push(vec![self.rows.len()]);
+                        }
+                    }
+                }
+            }
+            self.rows.push(row);
+        }
+        self.matched = vec![false; self.rows.len()];
+
+        Ok(())
+    }
+}
+
+impl<'a> Join<'a> {
+    /// Creates a nested loop join that checks the condition for every pair
+    /// of rows.
+    /// # Arguments
+    /// * `left` - The left input with qualified column names.
+    /// * `right` - The right input with qualified column names.
+    /// * `join` - The join.
+    /// # Returns
+    /// * `Result<Self, TableControllerError>` - The join or
+    ///   [`TableControllerError::JoinError`] if only `CROSS JOIN` has no
+    ///   condition isn't true.
+    pub fn nested_loop(
+        left: Box<dyn Operator + 'a>,
+        right: Box<dyn Operator + 'a>,
+        join: query::Join,
+    ) -> Result<Self, TableControllerError> {
+        Self::with_build(left, right, join, None)
+    }
+
+    /// Creates a hash join that puts the right rows into a hash table by
+    /// the values of [`query::Join::equi_keys`] and checks the condition
+    /// only for the rows in the bucket of the left row. Numbers are hashed
+    /// as `f64` and strings as `TEXT`, so that values of different types
+    /// that are equal get to the same bucket. It's a nested loop join if
+    /// the condition compares nothing for equality.
+    /// # Arguments
+    /// * `left` - The left input with qualified column names.
+    /// * `right` - The right input with qualified column names.
+    /// * `join` - The join.
+    /// # Returns
+    /// * `Result<Self, TableControllerError>` - The join or
+    ///   [`TableControllerError::JoinError`] if only `CROSS JOIN` has no
+    ///   condition isn't true.
+    pub fn hash(
+        left: Box<dyn Operator + 'a>,
+        right: Box<dyn Operator + 'a>,
+        join: query::Join,
+    ) -> Result<Self, TableControllerError> {
+        let keys = join.equi_keys(left.column_names(), right.column_names());
+        let keys = (!keys.is_empty()).then(|| keys.into_iter().unzip());
+
+        Self::with_build(left, right, join, keys)
+    }
+
+    /// Creates an index nested loop join that looks up the right row for
+    /// every left row.
+    /// # Arguments
+    /// * `left` - The left input with qualified column names.
+    /// * `right_names` - The qualified names of the right columns.
+    /// * `right_types` - The types of the right columns.
+    /// * `join` - The `INNER` or `LEFT JOIN`.
+    /// * `key` - The expression over the left columns whose value is looked
+    ///   up, see [`query::JoinAlgorithm::IndexNestedLoop`].
+    /// * `lookup` - Returns the right row with the primary key equal to the
+    ///   value, that is never `NULL`.
+    /// # Returns
+    /// * `Result<Self, TableControllerError>` - The join or
+    ///   [`TableControllerError::JoinError`] if the join has another type
+    ///   or no condition.
+    pub fn index_nested_loop(
+        left: Box<dyn Operator + 'a>,
+        right_names: Vec<column::Name>,
+        right_types: Vec<Option<StorageDataType>>,
+        join: query::Join,
+        key: Expression,
+        lookup: Lookup<'a>,
+    ) -> Result<Self, TableControllerError> {
+        if !matches!(join.join_type, JoinType::Inner | JoinType::Left) {
+            return Err(JoinError::UnsupportedJoinType(join.join_type).into());
+        }
+
+        let right_columns = (right_names, right_types);
+        Self::new(left, right_columns, join, Right::Lookup { key, lookup })
+    }
+
+    /// Creates a nested loop join or a hash join by the keys.
+    fn with_build(
+        left: Box<dyn Operator + 'a>,
+        right: Box<dyn Operator + 'a>,
+        join: query::Join,
+        keys: Option<(Vec<Expression>, Vec<Expression>)>,
+    ) -> Result<Self, TableControllerError> {
+        let right_columns =
+            (right.column_names().to_vec(), right.column_types().to_vec());
+        let build = Build {
+            input: Some(right),
+            keys,
+            rows: Vec::new(),
+            matched: Vec::new(),
+            indexes: ScalableHashTable::default(),
+            buckets: Vec::new(),
+        };
+
+        Self::new(left, right_columns, join, Right::Rows(build))
+    }
+
+    fn new(
+        left: Box<dyn Operator + 'a>,
+        (right_names, right_types): (
+            Vec<column::Name>,
+            Vec<Option<StorageDataType>>,
+        ),
+        join: query::Join,
+        right: Right<'a>,
+    ) -> Result<Self, TableControllerError> {
+        join.check()?;
+
+        let left_width = left.column_names().len();
+        let right_width = right_names.len();
+        let column_names = left
+            .column_names()
+            .iter()
+            .cloned()
+            .chain(right_names)
+            .collect();
+        let column_types = left
+            .column_types()
+            .iter()
+            .copied()
+            .chain(right_types)
+            .collect();
+
+        Ok(Self {
+            join,
+            left,
+            right,
+            column_names,
+            column_types,
+            left_width,
+            right_width,
+            pending: VecDeque::new(),
+            unmatched: None,
+        })
+    }
+
+    /// Joins the left row with the right rows that match the condition, or
+    /// with `NULL`s if there are none and the join type keeps it.
+    fn join_row(&mut self, row: DataRow) -> Result<(), TableControllerError> {
+        let left_names = self.left.column_names();
+        let mut matched = false;
+        match &mut self.right {
+            Right::Rows(build) => {
+                let positions: Box<dyn Iterator<Item = usize>> =
+                    match &build.keys {
+                        Some((left_keys, _)) => {
+                            let key = hash_key(left_keys, left_names, &row)
+                                .map_err(JoinError::from)?;
+                            match key.and_then(|key| build.indexes.get(&key)) {
+                                Some(index) => Box::new(
+                                    build.buckets[index].iter().copied(),
+                                ),
+                                None => Box::new(iter::empty()),
+                            }
+                        }
+                        None => Box::new(0..build.rows.len()),
+                    };
+
+                let mut hits = Vec::new();
+                for position in positions {
+                    let joined = concat(&row, &build.rows[position].0);
+                    if self.join.matches(&self.column_names, &joined)? {
+                        self.pending.push_back(joined);
+                        hits.push(position);
+                    }
+                }
+                for position in &hits {
+                    build.matched[*position] = true;
+                }
+                matched = !hits.is_empty();
+            }
+            Right::Lookup { key, lookup } => {
+                let value = key
+                    .evaluate(|name| column_value(left_names, &row.0, name))
+                    .map_err(JoinError::from)?;
+                let right = match value {
+                    StorageData::Null => None,
+                    value => lookup(&value)?,
+                };
+                if let Some(right) = right {
+                    let joined = concat(&row, &right.0);
+                    if self.join.matches(&self.column_names, &joined)? {
+                        self.pending.push_back(joined);
+                        matched = true;
+                    }
+                }
+            }
+        }
+
+        if !matched && self.join.join_type.keeps_left() {
+            let nulls = vec![StorageData::Null; self.right_width];
+            self.pending.push_back(concat(&row, &nulls));
+        }
+
+        Ok(())
+    }
+
+    /// Returns the next right row without a match with `NULL`s for the
+    /// left columns, if the join type keeps them.
+    fn next_unmatched(&mut self) -> Option<DataRow> {
+        let Right::Rows(build) = &self.right else {
+            return None;
+        };
+        if !self.join.join_type.keeps_right() {
+            return None;
+        }
+
+        let start = self.unmatched?;
+        let position = (start..build.rows.len())
+            .find(|position| !build.matched[*position])?;
+        self.unmatched = Some(position + 1);
+
+        let values = iter::repeat_n(StorageData::Null, self.left_width)
+            .chain(build.rows[position].0.iter().cloned())
+            .collect();
+        Some(DataRow(values))
+    }
+}
+
+impl Operator for Join<'_> {
+    fn column_names(&self) -> &[column::Name] {
+        &self.column_names
+    }
+
+    fn column_types(&self) -> &[Option<StorageDataType>] {
+        &self.column_types
+    }
+
+    fn next(&mut self) -> Result<Option<DataRow>, TableControllerError> {
+        loop {
+            if let Some(row) = self.pending.pop_front() {
+                return Ok(Some(row));
+            }
+            if self.unmatched.is_some() {
+                return Ok(self.next_unmatched());
+            }
+
+            if let Right::Rows(build) = &mut self.right {
+                build.build()?;
+            }
+            match self.left.next()? {
+                Some(row) => self.join_row(row)?,
+                None => self.unmatched = Some(0),
+            }
+        }
+    }
+}
+
+/// Returns the row followed by the values.
+fn concat(row: &DataRow, values: &[StorageData]) -> DataRow {
+    DataRow(row.0.iter().chain(values).cloned().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+
+    use crate::{
+        controller::table::TableControllerError,
+        data::DataUnit,
+        expression::{compare, BinaryOperator, Expression},
+        query,
+        query::{
+            executor::{Join, Operator, Values},
+            join::{qualify, JoinError, JoinType},
+        },
+        schema::r#type::{
+            data_types::Text,
+            r#enum::{StorageData, StorageDataType},
+            DataRow,
+        },
+    };
+
+    fn column(name: &str) -> Expression {
+        Expression::Column(name.into())
+    }
+
+    fn text(value: &str) -> StorageData {
+        Text(value.into()).into()
+    }
+
+    fn equal(left: &str, right: &str) -> Expression {
+        Expression::binary(column(left), BinaryOperator::Equal, column(right))
+    }
+
+    /// Users 1 `ann`, 2 `bob` and 3 `carol`.
+    fn users() -> DataUnit {
+        let mut data = DataUnit::new(vec!["id".into(), "name".into()]);
+        for (id, name) in [(1, "ann"), (2, "bob"), (3, "carol")] {
+            data.insert(DataRow(vec![StorageData::from(id), text(name)]));
+        }
+        qualify(&"users".into(), data)
+    }
+
+    /// Orders 10 and 11 of user 1, 12 of user 4 and 13 without a user, the
+    /// user ids are `i16`s.
+    fn orders() -> DataUnit {
+        let mut data = DataUnit::new(vec!["id".into(), "user_id".into()]);
+        for (id, user_id) in [(10, Some(1i16)), (11, Some(1)), (12, Some(4))]
+            .into_iter()
+            .chain([(13, None)])
+        {
+            let user_id = user_id.map_or(StorageData::Null, StorageData::from);
+            data.insert(DataRow(vec![StorageData::from(id), user_id]));
+        }
+        qualify(&"orders".into(), data)
+    }
+
+    fn nested_loop(
+        join: &query::Join,
+    ) -> Result<DataUnit, TableControllerError> {
+        Join::nested_loop(
+            Box::new(Values::from(users())),
+            Box::new(Values::from(orders())),
+            join.clone(),
+        )?
+        .collect()
+    }
+
+    fn hash(join: &query::Join) -> Result<DataUnit, TableControllerError> {
+        Join::hash(
+            Box::new(Values::from(users())),
+            Box::new(Values::from(orders())),
+            join.clone(),
+        )?
+        .collect()
+    }
+
+    /// Returns the user names and order ids of the joined rows.
+    fn pairs(data: DataUnit) -> Vec<(StorageData, StorageData)> {
+        data.get_values()
+            .into_iter()
+            .map(|row| (row.0[1].clone(), row.0[2].clone()))
+            .collect()
+    }
+
+    #[test]
+    fn test_join_types() {
+        let on = || equal("users.id", "orders.user_id");
+        let order = |id: i32| StorageData::from(id);
+        let null = StorageData::Null;
+
+        for join in [
+            query::Join::new(JoinType::Inner, on()),
+            query::Join::cross(),
+        ] {
+            let result = nested_loop(&join).unwrap();
+            assert_eq!(
+                result.get_names(),
+                &[
+                    "users.id".into(),
+                    "users.name".into(),
+                    "orders.id".into(),
+                    "orders.user_id".into()
+                ]
+            );
+            let expected = if join.join_type == JoinType::Cross {
+                12
+            } else {
+                2
+            };
+            assert_eq!(result.len(), expected);
+        }
+
+        let join = query::Join::new(JoinType::Left, on());
+        assert_eq!(
+            pairs(nested_loop(&join).unwrap()),
+            vec![
+                (text("ann"), order(10)),
+                (text("ann"), order(11)),
+                (text("bob"), null.clone()),
+                (text("carol"), null.clone()),
+            ]
+        );
+
+        let join = query::Join::new(JoinType::Right, on());
+        assert_eq!(
+            pairs(nested_loop(&join).unwrap()),
+            vec![
+                (text("ann"), order(10)),
+                (text("ann"), order(11)),
+                (null.clone(), order(12)),
+                (null.clone(), order(13)),
+            ]
+        );
+
+        let join = query::Join::new(JoinType::Full, on());
+        assert_eq!(nested_loop(&join).unwrap().len(), 6);
+
+        let join = query::Join {
+            join_type: JoinType::Inner,
+            condition: None,
+        };
+        assert_eq!(
+            nested_loop(&join),
+            Err(TableControllerError::JoinError(
+                JoinError::MissingCondition(JoinType::Inner)
+            ))
+        );
+    }
+
+    #[test]
+    fn test_hash_join() {
+        let on = Expression::binary(
+            equal("orders.user_id", "users.id"),
+            BinaryOperator::And,
+            Expression::binary(
+                column("orders.id"),
+                BinaryOperator::LessThan,
+                Expression::Literal(StorageData::from(11)),
+            ),
+        );
+
+        for join_type in [
+            JoinType::Inner,
+            JoinType::Left,
+            JoinType::Right,
+            JoinType::Full,
+        ] {
+            let join = query::Join::new(join_type, on.clone());
+            assert_eq!(hash(&join), nested_loop(&join), "{}", join_type);
+        }
+
+        let join = query::Join::new(JoinType::Inner, on);
+        assert_eq!(
+            join.equi_keys(users().get_names(), orders().get_names()),
+            vec![(column("users.id"), column("orders.user_id"))]
+        );
+        assert_eq!(
+            pairs(hash(&join).unwrap()),
+            vec![(text("ann"), StorageData::from(10))]
+        );
+    }
+
+    #[test]
+    fn test_index_nested_loop_join() {
+        let join = query::Join::new(
+            JoinType::Left,
+            equal("users.id", "orders.user_id"),
+        );
+        let users = users().get_values();
+        let lookup = |value: &StorageData| {
+            Ok(users
+                .iter()
+                .find(|row| {
+                    compare(&row.0[0], value).is_some_and(Ordering::is_eq)
+                })
+                .cloned())
+        };
+
+        let result = Join::index_nested_loop(
+            Box::new(Values::from(orders())),
+            vec!["users.id".into(), "users.name".into()],
+            vec![Some(StorageDataType::Integer), Some(StorageDataType::Text)],
+            join,
+            column("orders.user_id"),
+            Box::new(lookup),
+        )
+        .unwrap()
+        .collect()
+        .unwrap();
+        let names = result
+            .get_values()
+            .into_iter()
+            .map(|row| row.0[3].clone())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            vec![
+                text("ann"),
+                text("ann"),
+                StorageData::Null,
+                StorageData::Null
+            ]
+        );
+
+        let join = query::Join::new(
+            JoinType::Right,
+            equal("users.id", "orders.user_id"),
+        );
+        let result = Join::index_nested_loop(
+            Box::new(Values::from(orders())),
+            Vec::new(),
+            Vec::new(),
+            join,
+            column("x"),
+            Box::new(lookup),
+        );
+        assert!(matches!(
+            result,
+            Err(TableControllerError::JoinError(
+                JoinError::UnsupportedJoinType(JoinType::Right)
+            ))
+        ));
+    }
+}
@@ -0,0 +1,74 @@
+use crate::{
+    controller::table::TableControllerError,
+    query::executor::Operator,
+    schema::{
+        column,
+        r#type::{r#enum::StorageDataType, DataRow},
+    },
+};
+
+/// Skips the first rows of the input and returns at most a number of the
+/// rest, as `OFFSET` and `LIMIT`. The input isn't pulled once the limit is
+/// reached.
+pub struct Limit<'a> {
+    /// The input operator.
+    input: Box<dyn Operator + 'a>,
+
+    /// The maximum number of returned rows, `None` means all rows.
+    limit: Option<usize>,
+
+    /// The number of rows that are still to be skipped.
+    offset: usize,
+
+    /// The number of returned rows.
+    returned: usize,
+}
+
+impl<'a> Limit<'a> {
+    /// Creates a new [`Limit`].
+    /// # Arguments
+    /// * `input` - The input operator.
+    /// * `limit` - The maximum number of returned rows, `None` means all.
+    /// * `offset` - The number of skipped rows.
+    pub fn new(
+        input: Box<dyn Operator + 'a>,
+        limit: Option<usize>,
+        offset: usize,
+    ) -> Self {
+        Self {
+            input,
+            limit,
+            offset,
+            returned: 0,
+        }
+    }
+}
+
+impl Operator for Limit<'_> {
+    fn column_names(&self) -> &[column::Name] {
+        self.input.column_names()
+    }
+
+    fn column_types(&self) -> &[Option<StorageDataType>] {
+        self.input.column_types()
+    }
+
+    fn next(&mut self) -> Result<Option<DataRow>, TableControllerError> {
+        if self.limit == Some(self.returned) {
+            return Ok(None);
+        }
+        while self.offset > 0 {
+            if self.input.next()?.is_none() {
+                return Ok(None);
+            }
+            self.offset -= 1;
+        }
+
+        let row = self.input.next()?;
+        if row.is_some() {
+            self.returned += 1;
+        }
+
+        Ok(row)
+    }
+}
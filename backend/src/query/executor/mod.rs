@@ -0,0 +1,500 @@
+//! Pull-based (volcano) execution of queries.
+//!
+//! A query is a tree of [`Operator`]s. Every operator pulls rows from its
+//! inputs with [`Operator::next`] and returns its own rows one at a time, so
+//! rows stream through the tree and only the operators that need all of
+//! their input, [`Sort`], [`Aggregate`] and the build side of [`Join`], keep
+//! rows in memory. Features compose by stacking operators, e.g.
+//! `Limit(Sort(Filter(Scan)))`.
+
+mod aggregate;
+mod filter;
+mod join;
+mod limit;
+mod project;
+mod scan;
+mod sort;
+
+use std::{iter, vec};
+
+pub use aggregate::Aggregate;
+pub use filter::Filter;
+pub use join::Join;
+pub use limit::Limit;
+pub use project::Project;
+pub use scan::{IndexScan, Scan};
+pub use sort::Sort;
+
+use crate::{
+    controller::table::{selector::DataSelector, TableControllerError},
+    data::DataUnit,
+    expression::Expression,
+    schema::{
+        column,
+        r#type::{
+            r#enum::{StorageData, StorageDataType},
+            DataRow,
+        },
+    },
+};
+
+/// A node of a query tree that returns rows one at a time.
+pub trait Operator {
+    /// Returns the names of the columns of the rows.
+    fn column_names(&self) -> &[column::Name];
+
+    /// Returns the types of the columns of the rows, `None` if the type of a
+    /// column is unknown, e.g. of a `NULL` literal.
+    fn column_types(&self) -> &[Option<StorageDataType>];
+
+    /// Returns the next row.
+    /// # Returns
+    /// * `Ok(Some(DataRow))` - The next row.
+    /// * `Ok(None)` - If there are no more rows.
+    /// * `Err(TableControllerError)` - If a row can't be read or computed.
+    fn next(&mut self) -> Result<Option<DataRow>, TableControllerError>;
+
+    /// Reads all remaining rows.
+    /// # Returns
+    /// * `Result<DataUnit, TableControllerError>` - The rows.
+    fn collect(&mut self) -> Result<DataUnit, TableControllerError> {
+        let mut data_unit = DataUnit::new(self.column_names().to_vec())
+            .with_types(self.column_types().to_vec());
+        while let Some(row) = self.next()? {
+            data_unit.insert(row);
+        }
+
+        Ok(data_unit)
+    }
+}
+
+impl<T: Operator + ?Sized> Operator for Box<T> {
+    fn column_names(&self) -> &[column::Name] {
+        (**self).column_names()
+    }
+
+    fn column_types(&self) -> &[Option<StorageDataType>] {
+        (**self).column_types()
+    }
+
+    fn next(&mut self) -> Result<Option<DataRow>, TableControllerError> {
+        (**self).next()
+    }
+}
+
+/// Returns rows that are already in memory.
+#[derive(Debug)]
+pub struct Values {
+    /// The names of the columns of the rows.
+    column_names: Vec<column::Name>,
+
+    /// The types of the columns of the rows.
+    column_types: Vec<Option<StorageDataType>>,
+
+    /// The rows that are not returned yet.
+    rows: vec::IntoIter<DataRow>,
+}
+
+impl From<DataUnit> for Values {
+    fn from(data_unit: DataUnit) -> Self {
+        Self {
+            column_names: data_unit.get_names().to_vec(),
+            column_types: data_unit.get_types().to_vec(),
+            rows: data_unit.get_values().into_iter(),
+        }
+    }
+}
+
+impl Operator for Values {
+    fn column_names(&self) -> &[column::Name] {
+        &self.column_names
+    }
+
+    fn column_types(&self) -> &[Option<StorageDataType>] {
+        &self.column_types
+    }
+
+    fn next(&mut self) -> Result<Option<DataRow>, TableControllerError> {
+        Ok(self.rows.next())
+    }
+}
+
+/// Stacks the operators of a [`DataSelector`] on the input: [`Sort`] for
+/// its `ORDER BY` items, [`Limit`] for its `OFFSET` and `LIMIT` and
+/// [`Project`] for its columns. Its filters and condition are ignored, they
+/// are applied to the rows of tables before they are aggregated.
+/// # Arguments
+/// * `input` - The input operator.
+/// * `selector` - The selector.
+/// # Returns
+/// * `Result<Box<dyn Operator + 'a>, TableControllerError>` - The top
+///   operator or [`TableControllerError::ColumnDoesNotExist`] if the
+///   selector uses a column the input doesn't have.
+pub fn select<'a>(
+    input: Box<dyn Operator + 'a>,
+    selector: DataSelector,
+) -> Result<Box<dyn Operator + 'a>, TableControllerError> {
+    let mut operator = input;
+    if !selector.order_by.is_empty() {
+        let needed = selector
+            .limit
+            .map(|limit| limit.saturating_add(selector.offset));
+        operator = Box::new(Sort::new(operator, selector.order_by, needed)?);
+    }
+    if selector.limit.is_some() || selector.offset > 0 {
+        operator =
+            Box::new(Limit::new(operator, selector.limit, selector.offset));
+    }
+    if let Some(columns) = selector.columns {
+        operator = Box::new(Project::new(operator, columns)?);
+    } else if let Some(names) = selector.row_names {
+        operator = Box::new(Project::columns(operator, names)?);
+    }
+
+    Ok(operator)
+}
+
+/// Returns the rows of the operator as an iterator.
+fn rows<'a>(
+    operator: &'a mut dyn Operator,
+) -> impl Iterator<Item = Result<DataRow, TableControllerError>> + 'a {
+    iter::from_fn(move || operator.next().transpose())
+}
+
+/// Returns the row's value of the column with the given name.
+pub(crate) fn column_value<'a>(
+    names: &[column::Name],
+    row: &'a [StorageData],
+    name: &column::Name,
+) -> Option<&'a StorageData> {
+    names
+        .iter()
+        .position(|column| column == name)
+        .map(|position| &row[position])
+}
+
+/// Returns the types of the expressions' values over the columns, `None`
+/// for an expression whose type is unknown.
+/// # Arguments
+/// * `names` - The names of the columns.
+/// * `types` - The types of the columns.
+/// * `expressions` - The expressions.
+fn expression_types<'e, I>(
+    names: &[column::Name],
+    types: &[Option<StorageDataType>],
+    expressions: I,
+) -> Vec<Option<StorageDataType>>
+where
+    I: IntoIterator<Item = &'e Expression>,
+{
+    expressions
+        .into_iter()
+        .map(|expression| {
+            expression
+                .data_type(|name| column_type(names, types, name))
+                .ok()
+                .flatten()
+        })
+        .collect()
+}
+
+/// Returns the type of the column with the given name, `None` if the column
+/// doesn't exist or its type is unknown.
+pub(crate) fn column_type(
+    names: &[column::Name],
+    types: &[Option<StorageDataType>],
+    name: &column::Name,
+) -> Option<StorageDataType> {
+    names
+        .iter()
+        .position(|column| column == name)
+        .and_then(|position| types[position])
+}
+
+/// Checks that the columns the expressions refer to exist.
+/// # Arguments
+/// * `names` - The names of the columns.
+/// * `expressions` - The expressions.
+/// # Returns
+/// * `Result<(), TableControllerError>` - `Ok` or
+///   [`TableControllerError::ColumnDoesNotExist`].
+fn check_columns<'e, I>(
+    names: &[column::Name],
+    expressions: I,
+) -> Result<(), TableControllerError>
+where
+    I: IntoIterator<Item = &'e Expression>,
+{
+    let exists = expressions
+        .into_iter()
+        .flat_map(Expression::columns)
+        .all(|column| names.contains(&column));
+    if exists {
+        Ok(())
+    } else {
+        Err(TableControllerError::ColumnDoesNotExist)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        controller,
+        controller::table::{
+            selector::{DataSelector, FilterType, OrderBy, SelectorFilter},
+            TableControllerError,
+        },
+        data::DataUnit,
+        expression::{BinaryOperator, Expression},
+        query::{
+            executor::{
+                select, Aggregate, Filter, IndexScan, Limit, Operator, Project,
+                Scan, Values,
+            },
+            AggregateFunction, Aggregation,
+        },
+        schema,
+        schema::{
+            column,
+            column::primary_key,
+            r#type::{
+                data_types::Text,
+                r#enum::{StorageData, StorageDataType},
+                DataRow,
+            },
+        },
+    };
+
+    fn column(name: &str) -> Expression {
+        Expression::Column(name.into())
+    }
+
+    fn text(value: &str) -> StorageData {
+        Text(value.into()).into()
+    }
+
+    /// Users 1 to 5 with the ages 30, 20, 40, 20 and 50.
+    fn users() -> controller::Table<16> {
+        let mut table = controller::Table::<16>::new("users".into());
+        table
+            .add_column(
+                "id".into(),
+                schema::Column::new(StorageDataType::Integer),
+            )
+            .unwrap();
+        table
+            .add_column(
+                "age".into(),
+                schema::Column::new(StorageDataType::Integer),
+            )
+            .unwrap();
+        table
+            .set_primary_key(primary_key::PrimaryKey::new(
+                "pk".into(),
+                "id".into(),
+            ))
+            .expect("Failed to set primary key");
+
+        let mut data = DataUnit::new(vec!["id".into(), "age".into()]);
+        for (id, age) in [(1, 30), (2, 20), (3, 40), (4, 20), (5, 50)] {
+            data.insert(DataRow(vec![
+                StorageData::from(id),
+                StorageData::from(age),
+            ]));
+        }
+        table.add_data(data).expect("is ok");
+
+        table
+    }
+
+    /// Returns the first values of the rows.
+    fn firsts(data: DataUnit) -> Vec<StorageData> {
+        data.get_values()
+            .into_iter()
+            .map(|row| row.0[0].clone())
+            .collect()
+    }
+
+    /// Counts the rows pulled from the input.
+    struct Counted<'a> {
+        input: Box<dyn Operator + 'a>,
+        pulled: &'a mut usize,
+    }
+
+    impl Operator for Counted<'_> {
+        fn column_names(&self) -> &[column::Name] {
+            self.input.column_names()
+        }
+
+        fn column_types(&self) -> &[Option<StorageDataType>] {
+            self.input.column_types()
+        }
+
+        fn next(&mut self) -> Result<Option<DataRow>, TableControllerError> {
+            let row = self.input.next()?;
+            *self.pulled += usize::from(row.is_some());
+            Ok(row)
+        }
+    }
+
+    #[test]
+    fn test_operators_compose() {
+        let table = users();
+        let scan = Box::new(Scan::new(&table, Vec::new()).unwrap());
+        let older = Expression::binary(
+            column("age"),
+            BinaryOperator::GreaterThan,
+            Expression::Literal(StorageData::from(20)),
+        );
+        let filter = Box::new(Filter::new(scan, older).unwrap());
+        let selector = DataSelector {
+            order_by: vec![OrderBy::desc(column("age"))],
+            limit: Some(2),
+            ..Default::default()
+        };
+        let limited = select(filter, selector).unwrap();
+        let doubled = Expression::binary(
+            column("id"),
+            BinaryOperator::Multiply,
+            Expression::Literal(StorageData::from(2)),
+        );
+        let mut project =
+            Project::new(limited, vec![(doubled, "double_id".into())]).unwrap();
+
+        assert_eq!(project.column_names(), &["double_id".into()]);
+        assert_eq!(
+            firsts(project.collect().unwrap()),
+            [10, 6].map(StorageData::from)
+        );
+
+        let scan = Box::new(Scan::new(&table, Vec::new()).unwrap());
+        assert!(matches!(
+            Filter::new(scan, column("name")),
+            Err(TableControllerError::ColumnDoesNotExist)
+        ));
+    }
+
+    #[test]
+    fn test_index_scan() {
+        let table = users();
+        let filter = SelectorFilter {
+            column_name: "age".into(),
+            value: StorageData::from(20),
+            filter_type: FilterType::NotEqual,
+        };
+        let keys = vec![
+            StorageData::from(3),
+            StorageData::from(9),
+            StorageData::from(2i16),
+            StorageData::from(1),
+        ];
+
+        let mut scan = IndexScan::new(&table, keys, vec![filter]).unwrap();
+        assert_eq!(
+            firsts(scan.collect().unwrap()),
+            [3, 1].map(StorageData::from)
+        );
+
+        let mut table = controller::Table::<16>::new("items".into());
+        table
+            .add_column(
+                "id".into(),
+                schema::Column::new(StorageDataType::Integer),
+            )
+            .unwrap();
+        assert!(matches!(
+            IndexScan::new(&table, Vec::new(), Vec::new()),
+            Err(TableControllerError::PrimaryKeyDoesNotExist)
+        ));
+    }
+
+    #[test]
+    fn test_limit_stops_pulling() {
+        let table = users();
+        let mut pulled = 0;
+        let scan = Box::new(Scan::new(&table, Vec::new()).unwrap());
+        let counted = Box::new(Counted {
+            input: scan,
+            pulled: &mut pulled,
+        });
+
+        let mut limit = Limit::new(counted, Some(2), 1);
+        assert_eq!(
+            firsts(limit.collect().unwrap()),
+            [2, 3].map(StorageData::from)
+        );
+        drop(limit);
+        assert_eq!(pulled, 3);
+    }
+
+    #[test]
+    fn test_aggregate_operator() {
+        let mut data = DataUnit::new(vec!["name".into(), "age".into()]);
+        for (name, age) in [("ann", 30), ("bob", 20), ("ann", 40)] {
+            data.insert(DataRow(vec![text(name), StorageData::from(age)]));
+        }
+        let aggregation = Aggregation {
+            group_by: vec![column("name")],
+            aggregates: vec![crate::query::Aggregate::count_all()],
+            having: None,
+        };
+
+        let mut aggregate =
+            Aggregate::new(Box::new(Values::from(data)), aggregation);
+        assert_eq!(
+            aggregate.column_names(),
+            &["name".into(), "COUNT(*)".into()]
+        );
+        assert_eq!(
+            aggregate.collect().unwrap().get_values(),
+            vec![
+                DataRow(vec![text("ann"), StorageData::from(2i128)]),
+                DataRow(vec![text("bob"), StorageData::from(1i128)]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_column_types() {
+        let table = users();
+        let scan = Box::new(Scan::new(&table, Vec::new()).unwrap());
+        assert_eq!(
+            scan.column_types(),
+            &[
+                Some(StorageDataType::Integer),
+                Some(StorageDataType::Integer)
+            ]
+        );
+
+        let aggregation = Aggregation {
+            group_by: vec![column("age")],
+            aggregates: vec![
+                crate::query::Aggregate::count_all(),
+                crate::query::Aggregate::new(
+                    AggregateFunction::Sum,
+                    column("id"),
+                ),
+            ],
+            having: None,
+        };
+        let aggregate = Box::new(Aggregate::new(scan, aggregation));
+        assert_eq!(
+            aggregate.column_types(),
+            &[
+                Some(StorageDataType::Integer),
+                Some(StorageDataType::Long),
+                Some(StorageDataType::Long),
+            ]
+        );
+
+        let columns = vec![
+            (column("age"), "age".into()),
+            (Expression::Literal(StorageData::Null), "nothing".into()),
+        ];
+        let mut project = Project::new(aggregate, columns).unwrap();
+        assert_eq!(
+            project.collect().unwrap().get_types(),
+            &[Some(StorageDataType::Integer), None]
+        );
+    }
+}
@@ -0,0 +1,103 @@
+use crate::{
+    controller::table::TableControllerError,
+    expression::Expression,
+    query::executor::{
+        check_columns, column_value, expression_types, Operator,
+    },
+    schema::{
+        column,
+        r#type::{r#enum::StorageDataType, DataRow},
+    },
+};
+
+/// Computes the columns of the result from the rows of the input, as the
+/// list of `SELECT`.
+pub struct Project<'a> {
+    /// The input operator.
+    input: Box<dyn Operator + 'a>,
+
+    /// The expressions of the result columns.
+    expressions: Vec<Expression>,
+
+    /// The names of the result columns.
+    column_names: Vec<column::Name>,
+
+    /// The types of the result columns.
+    column_types: Vec<Option<StorageDataType>>,
+}
+
+impl<'a> Project<'a> {
+    /// Creates a new [`Project`].
+    /// # Arguments
+    /// * `input` - The input operator.
+    /// * `columns` - The expressions of the result columns over the columns
+    ///   of the input and their names.
+    /// # Returns
+    /// * `Result<Self, TableControllerError>` - The projection or
+    ///   [`TableControllerError::ColumnDoesNotExist`] if an expression uses
+    ///   a column the input doesn't have.
+    pub fn new(
+        input: Box<dyn Operator + 'a>,
+        columns: Vec<(Expression, column::Name)>,
+    ) -> Result<Self, TableControllerError> {
+        let (expressions, column_names): (Vec<_>, Vec<_>) =
+            columns.into_iter().unzip();
+        check_columns(input.column_names(), &expressions)?;
+        let column_types = expression_types(
+            input.column_names(),
+            input.column_types(),
+            &expressions,
+        );
+
+        Ok(Self {
+            input,
+            expressions,
+            column_names,
+            column_types,
+        })
+    }
+
+    /// Creates a [`Project`] that keeps only the columns with the names.
+    /// # Arguments
+    /// * `input` - The input operator.
+    /// * `names` - The names of the kept columns.
+    pub fn columns(
+        input: Box<dyn Operator + 'a>,
+        names: Vec<column::Name>,
+    ) -> Result<Self, TableControllerError> {
+        let columns = names
+            .into_iter()
+            .map(|name| (Expression::Column(name.clone()), name))
+            .collect();
+
+        Self::new(input, columns)
+    }
+}
+
+impl Operator for Project<'_> {
+    fn column_names(&self) -> &[column::Name] {
+        &self.column_names
+    }
+
+    fn column_types(&self) -> &[Option<StorageDataType>] {
+        &self.column_types
+    }
+
+    fn next(&mut self) -> Result<Option<DataRow>, TableControllerError> {
+        let Some(row) = self.input.next()? else {
+            return Ok(None);
+        };
+
+        let names = self.input.column_names();
+        let values = self
+            .expressions
+            .iter()
+            .map(|expression| {
+                expression.evaluate(|name| column_value(names, &row.0, name))
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(TableControllerError::EvaluationError)?;
+
+        Ok(Some(DataRow(values)))
+    }
+}
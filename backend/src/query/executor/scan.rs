@@ -0,0 +1,228 @@
+use std::vec;
+
+use crate::{
+    controller,
+    controller::table::{selector::SelectorFilter, TableControllerError},
+    query::{executor::Operator, join},
+    schema::{
+        column,
+        r#type::{
+            r#enum::{StorageData, StorageDataType},
+            DataRow,
+        },
+        table,
+    },
+};
+
+/// Reads all rows of a table in the order of its index and returns those
+/// that pass all filters.
+#[derive(Debug)]
+pub struct Scan<'a, const NODE_SIZE: u8> {
+    /// The scanned table.
+    table: &'a controller::Table<NODE_SIZE>,
+
+    /// The names of the columns of the rows.
+    column_names: Vec<column::Name>,
+
+    /// The types of the columns of the rows.
+    column_types: Vec<Option<StorageDataType>>,
+
+    /// The filters of the rows and the positions of their columns.
+    filters: Vec<(SelectorFilter, usize)>,
+
+    /// The position of the next row in the table's index.
+    position: usize,
+}
+
+impl<'a, const NODE_SIZE: u8> Scan<'a, NODE_SIZE> {
+    /// Creates a new [`Scan`].
+    /// # Arguments
+    /// * `table` - The table to scan.
+    /// * `filters` - The filters the returned rows pass.
+    /// # Returns
+    /// * `Result<Self, TableControllerError>` - The scan or
+    ///   [`TableControllerError::ColumnDoesNotExist`] if a filtered column
+    ///   doesn't exist.
+    pub fn new(
+        table: &'a controller::Table<NODE_SIZE>,
+        filters: Vec<SelectorFilter>,
+    ) -> Result<Self, TableControllerError> {
+        Ok(Self {
+            table,
+            column_names: table.get_column_names(),
+            column_types: column_types(table),
+            filters: filter_positions(table, filters)?,
+            position: 0,
+        })
+    }
+
+    /// Qualifies the names of the columns by the name of the table, e.g.
+    /// `users.id`, as the inputs of a [`Join`] need.
+    ///
+    /// [`Join`]: crate::query::executor::Join
+    pub fn qualified(mut self) -> Self {
+        self.column_names = qualified_names(self.table);
+        self
+    }
+
+    /// Qualifies the names of the columns by the alias of the table, e.g.
+    /// `u.id` for `users AS u`, as the inputs of a [`Join`] of a table with
+    /// itself need.
+    ///
+    /// [`Join`]: crate::query::executor::Join
+    pub fn aliased(mut self, alias: &table::Name) -> Self {
+        self.column_names = self
+            .table
+            .get_column_names()
+            .iter()
+            .map(|name| join::qualified_name(alias, name))
+            .collect();
+        self
+    }
+}
+
+impl<const NODE_SIZE: u8> Operator for Scan<'_, NODE_SIZE> {
+    fn column_names(&self) -> &[column::Name] {
+        &self.column_names
+    }
+
+    fn column_types(&self) -> &[Option<StorageDataType>] {
+        &self.column_types
+    }
+
+    fn next(&mut self) -> Result<Option<DataRow>, TableControllerError> {
+        while let Some(row) = self.table.get_row_at(self.position) {
+            self.position += 1;
+            let row = row?;
+            if passes(&self.filters, &row) {
+                return Ok(Some(row));
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+/// Looks up rows of a table by their primary keys and returns those that
+/// pass all filters, e.g. for `WHERE id = 5`.
+#[derive(Debug)]
+pub struct IndexScan<'a, const NODE_SIZE: u8> {
+    /// The scanned table.
+    table: &'a controller::Table<NODE_SIZE>,
+
+    /// The names of the columns of the rows.
+    column_names: Vec<column::Name>,
+
+    /// The types of the columns of the rows.
+    column_types: Vec<Option<StorageDataType>>,
+
+    /// The filters of the rows and the positions of their columns.
+    filters: Vec<(SelectorFilter, usize)>,
+
+    /// The primary keys that are not looked up yet.
+    keys: vec::IntoIter<StorageData>,
+}
+
+impl<'a, const NODE_SIZE: u8> IndexScan<'a, NODE_SIZE> {
+    /// Creates a new [`IndexScan`].
+    /// # Arguments
+    /// * `table` - The table to scan, it must have a primary key.
+    /// * `keys` - The primary keys of the rows, in the order the rows are
+    ///   returned. See [`controller::Table::get_row_by_key`].
+    /// * `filters` - The filters the returned rows pass.
+    /// # Returns
+    /// * `Result<Self, TableControllerError>` - The scan or
+    ///   [`TableControllerError::ColumnDoesNotExist`] if a filtered column
+    ///   doesn't exist.
+    pub fn new(
+        table: &'a controller::Table<NODE_SIZE>,
+        keys: Vec<StorageData>,
+        filters: Vec<SelectorFilter>,
+    ) -> Result<Self, TableControllerError> {
+        if table.get_primary_key().is_none() {
+            return Err(TableControllerError::PrimaryKeyDoesNotExist);
+        }
+
+        Ok(Self {
+            table,
+            column_names: table.get_column_names(),
+            column_types: column_types(table),
+            filters: filter_positions(table, filters)?,
+            keys: keys.into_iter(),
+        })
+    }
+
+    /// Qualifies the names of the columns by the name of the table, e.g.
+    /// `users.id`, as the inputs of a [`Join`] need.
+    ///
+    /// [`Join`]: crate::query::executor::Join
+    pub fn qualified(mut self) -> Self {
+        self.column_names = qualified_names(self.table);
+        self
+    }
+}
+
+impl<const NODE_SIZE: u8> Operator for IndexScan<'_, NODE_SIZE> {
+    fn column_names(&self) -> &[column::Name] {
+        &self.column_names
+    }
+
+    fn column_types(&self) -> &[Option<StorageDataType>] {
+        &self.column_types
+    }
+
+    fn next(&mut self) -> Result<Option<DataRow>, TableControllerError> {
+        for key in self.keys.by_ref() {
+            if let Some(row) = self.table.get_row_by_key(&key)? {
+                if passes(&self.filters, &row) {
+                    return Ok(Some(row));
+                }
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+/// Returns the filters with the positions of their columns in the table.
+fn filter_positions<const NODE_SIZE: u8>(
+    table: &controller::Table<NODE_SIZE>,
+    filters: Vec<SelectorFilter>,
+) -> Result<Vec<(SelectorFilter, usize)>, TableControllerError> {
+    let names = table.get_column_names();
+    filters
+        .into_iter()
+        .map(|filter| {
+            let position = names
+                .iter()
+                .position(|name| *name == filter.column_name)
+                .ok_or(TableControllerError::ColumnDoesNotExist)?;
+            Ok((filter, position))
+        })
+        .collect()
+}
+
+/// Returns the column types of the table, they are all known.
+fn column_types<const NODE_SIZE: u8>(
+    table: &controller::Table<NODE_SIZE>,
+) -> Vec<Option<StorageDataType>> {
+    table.get_column_types().into_iter().map(Some).collect()
+}
+
+/// Returns the column names of the table qualified by its name.
+fn qualified_names<const NODE_SIZE: u8>(
+    table: &controller::Table<NODE_SIZE>,
+) -> Vec<column::Name> {
+    table
+        .get_column_names()
+        .iter()
+        .map(|name| join::qualified_name(table.get_name(), name))
+        .collect()
+}
+
+/// Checks if the row passes all filters.
+fn passes(filters: &[(SelectorFilter, usize)], row: &DataRow) -> bool {
+    filters
+        .iter()
+        .all(|(filter, position)| filter.matches(&row.0[*position]))
+}
@@ -0,0 +1,91 @@
+use crate::{
+    controller::table::{
+        selector::OrderBy,
+        sort::{SortedRows, Sorter},
+        TableControllerError,
+    },
+    query::executor::{check_columns, rows, Operator},
+    schema::{
+        column,
+        r#type::{r#enum::StorageDataType, DataRow},
+    },
+};
+
+/// Sorts the rows of the input by `ORDER BY` items with a [`Sorter`]. All
+/// rows of the input are read when the first row is pulled, runs that
+/// don't fit into memory are spilled.
+pub struct Sort<'a> {
+    /// The input operator, `None` once its rows are read.
+    input: Option<Box<dyn Operator + 'a>>,
+
+    /// The names of the columns of the rows.
+    column_names: Vec<column::Name>,
+
+    /// The types of the columns of the rows.
+    column_types: Vec<Option<StorageDataType>>,
+
+    /// The items to sort by.
+    order_by: Vec<OrderBy>,
+
+    /// The number of first sorted rows that are needed, `None` means all.
+    limit: Option<usize>,
+
+    /// The sorted rows, `None` until the input is read.
+    sorted: Option<SortedRows>,
+}
+
+impl<'a> Sort<'a> {
+    /// Creates a new [`Sort`].
+    /// # Arguments
+    /// * `input` - The input operator.
+    /// * `order_by` - The items to sort by.
+    /// * `limit` - The number of first sorted rows that are needed, e.g.
+    ///   because of `LIMIT`, `None` means all rows. Only them are returned.
+    /// # Returns
+    /// * `Result<Self, TableControllerError>` - The sort or
+    ///   [`TableControllerError::ColumnDoesNotExist`] if an item uses a
+    ///   column the input doesn't have.
+    pub fn new(
+        input: Box<dyn Operator + 'a>,
+        order_by: Vec<OrderBy>,
+        limit: Option<usize>,
+    ) -> Result<Self, TableControllerError> {
+        check_columns(
+            input.column_names(),
+            order_by.iter().map(|order_by| &order_by.expression),
+        )?;
+
+        Ok(Self {
+            column_names: input.column_names().to_vec(),
+            column_types: input.column_types().to_vec(),
+            input: Some(input),
+            order_by,
+            limit,
+            sorted: None,
+        })
+    }
+}
+
+impl Operator for Sort<'_> {
+    fn column_names(&self) -> &[column::Name] {
+        &self.column_names
+    }
+
+    fn column_types(&self) -> &[Option<StorageDataType>] {
+        &self.column_types
+    }
+
+    fn next(&mut self) -> Result<Option<DataRow>, TableControllerError> {
+        if let Some(mut input) = self.input.take() {
+            let sorter = Sorter::new(
+                std::mem::take(&mut self.order_by),
+                self.column_names.clone(),
+            );
+            let input_rows = rows(&mut input).map(|row| row.map(|row| row.0));
+            self.sorted = Some(sorter.sort(input_rows, self.limit)?);
+        }
+
+        let sorted = self.sorted.as_mut().expect("input is sorted");
+        sorted.next().transpose().map(|row| row.map(DataRow))
+    }
+}
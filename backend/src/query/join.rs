@@ -0,0 +1,485 @@
+//! Joins of the rows of two tables with `INNER`, `LEFT`, `RIGHT`, `FULL`
+//! and `CROSS JOIN`.
+//!
+//! Columns of joined rows are qualified by the names of their tables, e.g.
+//! `users.id`, or by their aliases, e.g. `u.id` for `users AS u`, so that
+//! the `ON` condition can refer to columns of both tables with the same
+//! name, also of a table joined with itself. Rows are joined by one of the
+//! [`JoinAlgorithm`]s chosen by [`Join::algorithm`]: a hash join if the
+//! condition compares columns of both tables for equality, an index nested
+//! loop join if one of the compared columns is the primary key of the right
+//! table, or a nested loop join otherwise. The joins are executed by
+//! [`executor::Join`].
+//!
+//! [`executor::Join`]: crate::query::executor::Join
+
+use derive_more::Display;
+
+use crate::{
+    data::DataUnit,
+    expression::{self, BinaryOperator, EvaluationError, Expression},
+    query::{
+        executor::column_value,
+        key::{normalize, HashKey},
+    },
+    schema::{
+        column,
+        r#type::{r#enum::StorageDataType, DataRow},
+        table,
+    },
+};
+
+/// A type of a join.
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq)]
+pub enum JoinType {
+    /// Pairs of rows that match the condition.
+    #[display(fmt = "INNER JOIN")]
+    Inner,
+
+    /// Pairs of rows that match the condition and the left rows without a
+    /// match, with `NULL`s for the columns of the right table.
+    #[display(fmt = "LEFT JOIN")]
+    Left,
+
+    /// Pairs of rows that match the condition and the right rows without a
+    /// match, with `NULL`s for the columns of the left table.
+    #[display(fmt = "RIGHT JOIN")]
+    Right,
+
+    /// Pairs of rows that match the condition and the rows of both tables
+    /// without a match.
+    #[display(fmt = "FULL JOIN")]
+    Full,
+
+    /// All pairs of rows, it has no condition.
+    #[display(fmt = "CROSS JOIN")]
+    Cross,
+}
+
+impl JoinType {
+    /// Checks if the left rows without a match are returned.
+    pub(crate) fn keeps_left(&self) -> bool {
+        matches!(self, JoinType::Left | JoinType::Full)
+    }
+
+    /// Checks if the right rows without a match are returned.
+    pub(crate) fn keeps_right(&self) -> bool {
+        matches!(self, JoinType::Right | JoinType::Full)
+    }
+}
+
+/// An algorithm that joins rows.
+#[derive(Debug, Clone, PartialEq)]
+pub enum JoinAlgorithm {
+    /// Checks the condition for every pair of rows.
+    NestedLoop,
+
+    /// Puts the right rows into a hash table by the values compared for
+    /// equality and checks the condition only for the left and right rows
+    /// with the same values.
+    Hash,
+
+    /// Looks up the right row by the primary key for every left row, the key
+    /// is the value of the expression over the left row. It's used only for
+    /// `INNER` and `LEFT JOIN`, as the other types need all right rows.
+    IndexNestedLoop(Expression),
+}
+
+/// A table of a join, as `name` or `name AS alias`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JoinedTable {
+    /// The name of the table.
+    pub name: table::Name,
+
+    /// The alias the columns of the table are qualified by instead of its
+    /// name, e.g. `u.id` for `users AS u`, so that a table can be joined
+    /// with itself.
+    pub alias: Option<table::Name>,
+}
+
+impl JoinedTable {
+    /// Creates a table of a join with an alias.
+    /// # Arguments
+    /// * `name` - The name of the table.
+    /// * `alias` - The alias of the table, e.g. `u` for `users AS u`.
+    pub fn aliased(name: table::Name, alias: table::Name) -> Self {
+        Self {
+            name,
+            alias: Some(alias),
+        }
+    }
+
+    /// Returns the name the columns of the table are qualified by, the
+    /// alias if the table has one or its name otherwise.
+    pub fn qualifier(&self) -> &table::Name {
+        self.alias.as_ref().unwrap_or(&self.name)
+    }
+}
+
+impl From<table::Name> for JoinedTable {
+    fn from(name: table::Name) -> Self {
+        Self { name, alias: None }
+    }
+}
+
+impl From<&str> for JoinedTable {
+    fn from(name: &str) -> Self {
+        table::Name::from(name).into()
+    }
+}
+
+/// A join of two tables, as `left join_type right ON condition`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Join {
+    /// The type of the join.
+    pub join_type: JoinType,
+
+    /// The `ON` condition over the qualified columns of both tables, `None`
+    /// means all pairs of rows match.
+    pub condition: Option<Expression>,
+}
+
+impl Join {
+    /// Creates a join with the `ON` condition.
+    /// # Arguments
+    /// * `join_type` - The type of the join.
+    /// * `condition` - The condition, e.g. `users.id = orders.user_id`.
+    pub fn new(join_type: JoinType, condition: Expression) -> Self {
+        Self {
+            join_type,
+            condition: Some(condition),
+        }
+    }
+
+    /// Creates a `CROSS JOIN`.
+    pub fn cross() -> Self {
+        Self {
+            join_type: JoinType::Cross,
+            condition: None,
+        }
+    }
+
+    /// Returns the same join with the tables swapped, i.e. `LEFT` and
+    /// `RIGHT JOIN` swap, so that `b swapped a` returns the rows of
+    /// `a join b` with the columns of `b` first.
+    pub fn swapped(&self) -> Self {
+        let join_type = match self.join_type {
+            JoinType::Left => JoinType::Right,
+            JoinType::Right => JoinType::Left,
+            join_type => join_type,
+        };
+
+        Self {
+            join_type,
+            condition: self.condition.clone(),
+        }
+    }
+
+    /// Returns the pairs of expressions the condition compares for equality,
+    /// the first one over the left columns and the second one over the right
+    /// columns. Only comparisons that must be true for the whole condition
+    /// to be true are returned, i.e. the operands of top-level `AND`s.
+    /// # Arguments
+    /// * `left_names` - The names of the left columns.
+    /// * `right_names` - The names of the right columns.
+    pub fn equi_keys(
+        &self,
+        left_names: &[column::Name],
+        right_names: &[column::Name],
+    ) -> Vec<(Expression, Expression)> {
+        let mut conjuncts = Vec::new();
+        if let Some(condition) = &self.condition {
+            collect_conjuncts(condition, &mut conjuncts);
+        }
+
+        let is_over = |expression: &Expression, names: &[column::Name]| {
+            let columns = expression.columns();
+            !columns.is_empty()
+                && columns.iter().all(|column| names.contains(column))
+        };
+        conjuncts
+            .into_iter()
+            .filter_map(|conjunct| match conjunct {
+                Expression::Binary {
+                    left,
+                    operator: BinaryOperator::Equal,
+                    right,
+                } => {
+                    if is_over(left, left_names) && is_over(right, right_names)
+                    {
+                        Some((*left.clone(), *right.clone()))
+                    } else if is_over(right, left_names)
+                        && is_over(left, right_names)
+                    {
+                        Some((*right.clone(), *left.clone()))
+                    } else {
+                        None
+                    }
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Chooses the algorithm of the join.
+    /// # Arguments
+    /// * `left_names` - The names of the left columns.
+    /// * `right_names` - The names of the right columns.
+    /// * `right_key` - The qualified name of the primary key column of the
+    ///   right table, if it has one.
+    /// # Returns
+    /// * `JoinAlgorithm` - [`JoinAlgorithm::IndexNestedLoop`] if the right
+    ///   primary key column is compared for equality in an `INNER` or `LEFT
+    ///   JOIN`, [`JoinAlgorithm::Hash`] if other expressions are, or
+    ///   [`JoinAlgorithm::NestedLoop`].
+    pub fn algorithm(
+        &self,
+        left_names: &[column::Name],
+        right_names: &[column::Name],
+        right_key: Option<&column::Name>,
+    ) -> JoinAlgorithm {
+        let keys = self.equi_keys(left_names, right_names);
+        if keys.is_empty() {
+            return JoinAlgorithm::NestedLoop;
+        }
+
+        if matches!(self.join_type, JoinType::Inner | JoinType::Left) {
+            if let Some(right_key) = right_key {
+                let index_key = keys.iter().find(|(_, right)| match right {
+                    Expression::Column(name) => name == right_key,
+                    _ => false,
+                });
+                if let Some((left, _)) = index_key {
+                    return JoinAlgorithm::IndexNestedLoop(left.clone());
+                }
+            }
+        }
+
+        JoinAlgorithm::Hash
+    }
+
+    /// Checks that only `CROSS JOIN` has no condition.
+    pub(crate) fn check(&self) -> Result<(), JoinError> {
+        match (self.join_type, &self.condition) {
+            (JoinType::Cross, Some(_)) => Err(JoinError::CrossJoinCondition),
+            (JoinType::Cross, None) | (_, Some(_)) => Ok(()),
+            (join_type, None) => Err(JoinError::MissingCondition(join_type)),
+        }
+    }
+
+    /// Checks if the joined row matches the condition.
+    pub(crate) fn matches(
+        &self,
+        names: &[column::Name],
+        row: &DataRow,
+    ) -> Result<bool, JoinError> {
+        match &self.condition {
+            Some(condition) => {
+                Ok(condition
+                    .matches(|name| column_value(names, &row.0, name))?)
+            }
+            None => Ok(true),
+        }
+    }
+}
+
+/// Qualifies the column names of the rows by the name of their table, e.g.
+/// `id` of `users` becomes `users.id`.
+/// # Arguments
+/// * `table` - The name of the table.
+/// * `rows` - The rows of the table.
+/// # Returns
+/// * `DataUnit` - The same rows with qualified column names.
+pub fn qualify(table: &table::Name, rows: DataUnit) -> DataUnit {
+    let names = rows
+        .get_names()
+        .iter()
+        .map(|name| qualified_name(table, name))
+        .collect();
+    let types = rows.get_types().to_vec();
+    let mut data_unit = DataUnit::new(names).with_types(types);
+    for row in rows.get_values() {
+        data_unit.insert(row);
+    }
+
+    data_unit
+}
+
+/// Returns the name of the column qualified by the name of its table, e.g.
+/// `users.id`.
+pub fn qualified_name(
+    table: &table::Name,
+    name: &column::Name,
+) -> column::Name {
+    format!("{}.{}", table, name).as_str().into()
+}
+
+/// Adds the operands of the top-level `AND`s of the condition.
+fn collect_conjuncts<'a>(
+    condition: &'a Expression,
+    conjuncts: &mut Vec<&'a Expression>,
+) {
+    match condition {
+        Expression::Binary {
+            left,
+            operator: BinaryOperator::And,
+            right,
+        } => {
+            collect_conjuncts(left, conjuncts);
+            collect_conjuncts(right, conjuncts);
+        }
+        condition => conjuncts.push(condition),
+    }
+}
+
+/// Evaluates the expressions over the row into a key of the hash table,
+/// `None` if any value is `NULL`.
+pub(super) fn hash_key(
+    expressions: &[Expression],
+    names: &[column::Name],
+    row: &DataRow,
+) -> Result<Option<HashKey>, EvaluationError> {
+    let mut key = Vec::with_capacity(expressions.len());
+    for expression in expressions {
+        let value =
+            expression.evaluate(|name| column_value(names, &row.0, name))?;
+        let Some(data_type) = value.data_type() else {
+            return Ok(None);
+        };
+
+        let common_type = match data_type {
+            StorageDataType::Float
+            | StorageDataType::Double
+            | StorageDataType::Decimal(_, _) => Some(StorageDataType::Double),
+            data_type if data_type.is_integer() => {
+                Some(StorageDataType::Double)
+            }
+            StorageDataType::VarChar(_) => Some(StorageDataType::Text),
+            _ => None,
+        };
+        let value = match common_type {
+            Some(common_type) => {
+                expression::cast(value.clone(), &common_type).unwrap_or(value)
+            }
+            None => value,
+        };
+        key.push(normalize(value));
+    }
+
+    Ok(Some(HashKey(key)))
+}
+
+/// Errors that can occur during a [`Join`].
+#[derive(Debug, Display, Clone, PartialEq)]
+pub enum JoinError {
+    /// A join other than `CROSS JOIN` has no `ON` condition.
+    #[display(fmt = "`{}` needs an `ON` condition", _0)]
+    MissingCondition(JoinType),
+
+    /// A `CROSS JOIN` has an `ON` condition.
+    #[display(fmt = "`CROSS JOIN` can't have an `ON` condition")]
+    CrossJoinCondition,
+
+    /// The algorithm can't be used for the join type.
+    #[display(fmt = "`{}` can't be executed by a primary key lookup", _0)]
+    UnsupportedJoinType(JoinType),
+
+    /// An expression can't be evaluated.
+    #[display(fmt = "{}", _0)]
+    EvaluationError(EvaluationError),
+}
+
+impl From<EvaluationError> for JoinError {
+    fn from(error: EvaluationError) -> Self {
+        JoinError::EvaluationError(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        data::DataUnit,
+        expression::{BinaryOperator, Expression},
+        query::join::{qualify, Join, JoinAlgorithm, JoinType},
+        schema::r#type::{data_types::Text, r#enum::StorageData, DataRow},
+    };
+
+    fn column(name: &str) -> Expression {
+        Expression::Column(name.into())
+    }
+
+    fn text(value: &str) -> StorageData {
+        Text(value.into()).into()
+    }
+
+    fn equal(left: &str, right: &str) -> Expression {
+        Expression::binary(column(left), BinaryOperator::Equal, column(right))
+    }
+
+    /// Users 1 `ann`, 2 `bob` and 3 `carol`.
+    fn users() -> DataUnit {
+        let mut data = DataUnit::new(vec!["id".into(), "name".into()]);
+        for (id, name) in [(1, "ann"), (2, "bob"), (3, "carol")] {
+            data.insert(DataRow(vec![StorageData::from(id), text(name)]));
+        }
+        qualify(&"users".into(), data)
+    }
+
+    /// Orders 10 and 11 of user 1, 12 of user 4 and 13 without a user, the
+    /// user ids are `i16`s.
+    fn orders() -> DataUnit {
+        let mut data = DataUnit::new(vec!["id".into(), "user_id".into()]);
+        for (id, user_id) in [(10, Some(1i16)), (11, Some(1)), (12, Some(4))]
+            .into_iter()
+            .chain([(13, None)])
+        {
+            let user_id = user_id.map_or(StorageData::Null, StorageData::from);
+            data.insert(DataRow(vec![StorageData::from(id), user_id]));
+        }
+        qualify(&"orders".into(), data)
+    }
+
+    #[test]
+    fn test_join_algorithm() {
+        let users = users();
+        let orders = orders();
+        let key = "users.id".into();
+        let algorithm = |join: Join| {
+            join.algorithm(orders.get_names(), users.get_names(), Some(&key))
+        };
+
+        assert_eq!(
+            algorithm(Join::new(
+                JoinType::Left,
+                equal("users.id", "orders.user_id")
+            )),
+            JoinAlgorithm::IndexNestedLoop(column("orders.user_id"))
+        );
+        assert_eq!(
+            algorithm(Join::new(
+                JoinType::Full,
+                equal("users.id", "orders.user_id")
+            )),
+            JoinAlgorithm::Hash
+        );
+        assert_eq!(
+            algorithm(Join::new(
+                JoinType::Inner,
+                equal("users.name", "orders.id")
+            )),
+            JoinAlgorithm::Hash
+        );
+        assert_eq!(
+            algorithm(Join::new(
+                JoinType::Inner,
+                Expression::binary(
+                    column("users.id"),
+                    BinaryOperator::LessThan,
+                    column("orders.user_id")
+                )
+            )),
+            JoinAlgorithm::NestedLoop
+        );
+        assert_eq!(algorithm(Join::cross()), JoinAlgorithm::NestedLoop);
+    }
+}
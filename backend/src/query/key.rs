@@ -0,0 +1,64 @@
+//! Keys of the hash tables used by query operators.
+
+use common::structs::hash_table::hash::custom_hashable::CustomHash;
+
+use crate::schema::r#type::r#enum::StorageData;
+
+/// Values used as a key of a hash table, e.g. the values of the `GROUP BY`
+/// expressions of a group or of the columns of a `UNIQUE` constraint.
+/// Values are hashed by their textual form, so they should be
+/// [`normalize`]d first. Decimals are hashed without their trailing zeros,
+/// as `1.5` equals `1.50`.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct HashKey(pub(crate) Vec<StorageData>);
+
+impl CustomHash for HashKey {
+    fn hash(&self, hash: fn(&[u8]) -> u64) -> u64 {
+        let mut bytes = Vec::new();
+        for value in &self.0 {
+            let text = match value {
+                StorageData::Decimal(value) => value.trimmed().to_string(),
+                value => value.to_string(),
+            };
+            bytes.extend_from_slice(text.as_bytes());
+            bytes.push(0);
+        }
+
+        hash(&bytes)
+    }
+}
+
+/// Replaces negative zeros with zeros, as they are equal but are formatted
+/// differently and so would get different hashes.
+pub(crate) fn normalize(value: StorageData) -> StorageData {
+    match value {
+        StorageData::Float(value) if value.0 == 0.0 => 0.0f32.into(),
+        StorageData::Double(value) if value.0 == 0.0 => 0.0f64.into(),
+        value => value,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::structs::hash_table::hash::{
+        self, custom_hashable::CustomHash,
+    };
+
+    use crate::{
+        query::key::HashKey,
+        schema::r#type::{data_types::Decimal, r#enum::StorageData},
+    };
+
+    #[test]
+    fn test_hash_key_decimal() {
+        let key = |value: &str| {
+            HashKey(vec![StorageData::Decimal(
+                value.parse::<Decimal>().unwrap(),
+            )])
+        };
+
+        assert_eq!(key("1.5"), key("1.50"));
+        assert_eq!(key("1.5").hash(hash::hash), key("1.50").hash(hash::hash));
+        assert_ne!(key("1.5").hash(hash::hash), key("15").hash(hash::hash));
+    }
+}
@@ -0,0 +1,16 @@
+//! Query processing over rows selected from tables, e.g. aggregation of
+//! rows returned by [`controller::Table::get_data`] or joins of the rows of
+//! two tables. Queries are executed by the operators of [`executor`].
+//!
+//! [`controller::Table::get_data`]: crate::controller::Table::get_data
+
+pub mod aggregate;
+pub mod cost;
+pub mod executor;
+pub mod join;
+pub(crate) mod key;
+
+pub use aggregate::{
+    Aggregate, AggregateFunction, Aggregation, AggregationError,
+};
+pub use join::{Join, JoinAlgorithm, JoinError, JoinType, JoinedTable};
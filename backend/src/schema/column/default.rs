@@ -0,0 +1,71 @@
+//! Functions that compute a column's default value for every inserted row.
+
+use derive_more::Display;
+use std::str::FromStr;
+
+use crate::schema::r#type::{
+    data_types::Uuid,
+    r#enum::{StorageData, StorageDataType},
+};
+
+/// Function whose result is used as a column's default value. Unlike a static
+/// default, it is called for every inserted row that does not provide the
+/// column.
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq)]
+pub enum DefaultFunction {
+    /// Generates a random (version 4) UUID.
+    #[display(fmt = "gen_random_uuid()")]
+    GenRandomUuid,
+}
+
+impl DefaultFunction {
+    /// Calls the function.
+    /// # Returns
+    /// * `StorageData` - The computed default value.
+    pub fn call(&self) -> StorageData {
+        match self {
+            DefaultFunction::GenRandomUuid => Uuid::new_v4().into(),
+        }
+    }
+
+    /// Returns the data type of the function's results.
+    /// # Returns
+    /// * `StorageDataType` - The data type of the computed values.
+    pub fn data_type(&self) -> StorageDataType {
+        match self {
+            DefaultFunction::GenRandomUuid => StorageDataType::Uuid,
+        }
+    }
+}
+
+impl FromStr for DefaultFunction {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "gen_random_uuid()" => Ok(DefaultFunction::GenRandomUuid),
+            _ => Err(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::schema::{
+        column::default::DefaultFunction, r#type::r#enum::StorageDataType,
+    };
+
+    #[test]
+    fn test_gen_random_uuid() {
+        let function = "GEN_RANDOM_UUID()".parse::<DefaultFunction>().unwrap();
+        assert_eq!(function, DefaultFunction::GenRandomUuid);
+        assert_eq!(function.to_string(), "gen_random_uuid()");
+
+        let value = function.call();
+        assert_eq!(value.data_type(), Some(function.data_type()));
+        assert_eq!(function.data_type(), StorageDataType::Uuid);
+        assert_ne!(value, function.call());
+
+        assert_eq!("gen_random_uuid".parse::<DefaultFunction>(), Err(()));
+    }
+}
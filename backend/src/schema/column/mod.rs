@@ -1,8 +1,12 @@
+pub mod default;
 pub mod primary_key;
 
 use crate::{
     gen_name,
-    schema::r#type::r#enum::{StorageData, StorageDataType},
+    schema::{
+        column::default::DefaultFunction,
+        r#type::r#enum::{StorageData, StorageDataType},
+    },
 };
 
 /// Represents database column.
@@ -12,6 +16,10 @@ pub struct Column {
     not_null: bool,
     /// Default value for column.
     default: Option<StorageData>,
+    /// Function that computes the default value for every row.
+    default_function: Option<DefaultFunction>,
+    /// Marks column as filled from the table's sequence.
+    auto_increment: bool,
     /// The type of the column.
     type_: StorageDataType,
 }
@@ -28,6 +36,8 @@ impl Column {
         Column {
             not_null: false,
             default: None,
+            default_function: None,
+            auto_increment: false,
             type_,
         }
     }
@@ -39,25 +49,83 @@ impl Column {
         self.not_null = not_null;
     }
 
-    /// Sets the default value for the column.
+    /// Sets the default value for the column. Replaces the default function,
+    /// if it was set.
     /// # Arguments
     /// * `default` - The default value.
     pub fn set_default(&mut self, default: Option<StorageData>) {
+        if default.is_some() {
+            self.default_function = None;
+        }
         self.default = default;
     }
 
+    /// Sets the function that computes the default value of the column.
+    /// Replaces the default value, if it was set.
+    /// # Arguments
+    /// * `function` - The default function.
+    pub fn set_default_function(&mut self, function: Option<DefaultFunction>) {
+        if function.is_some() {
+            self.default = None;
+        }
+        self.default_function = function;
+    }
+
+    /// Marks the column as `AUTO_INCREMENT`, so values that are not provided
+    /// are generated by a sequence of the table.
+    /// # Arguments
+    /// * `auto_increment` - The auto increment state.
+    /// # Returns
+    /// * `bool` - False if the column isn't of an integer type, in which
+    ///   case the state is not changed.
+    pub fn set_auto_increment(&mut self, auto_increment: bool) -> bool {
+        if auto_increment && !self.type_.is_integer() {
+            return false;
+        }
+        self.auto_increment = auto_increment;
+        true
+    }
+
     /// Returns the type of the column.
     /// # Returns
     /// * `StorageDataType` - The type of the column.
     pub fn get_type(&self) -> StorageDataType {
         self.type_
     }
+
+    /// Returns the column not null state.
+    /// # Returns
+    /// * `bool` - The not null state.
+    pub fn is_not_null(&self) -> bool {
+        self.not_null
+    }
+
+    /// Returns the default value for the column.
+    /// # Returns
+    /// * `Option<&StorageData>` - The default value.
+    pub fn get_default(&self) -> Option<&StorageData> {
+        self.default.as_ref()
+    }
+
+    /// Returns the column auto increment state.
+    /// # Returns
+    /// * `bool` - The auto increment state.
+    pub fn is_auto_increment(&self) -> bool {
+        self.auto_increment
+    }
+
+    /// Returns the function that computes the default value for the column.
+    /// # Returns
+    /// * `Option<DefaultFunction>` - The default function.
+    pub fn get_default_function(&self) -> Option<DefaultFunction> {
+        self.default_function
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::schema::{
-        column::Column,
+        column::{default::DefaultFunction, Column},
         r#type::r#enum::{Integer, StorageData, StorageDataType},
     };
 
@@ -75,6 +143,33 @@ mod tests {
         assert_eq!(column.default, Some(StorageData::Integer(Integer(1))));
     }
 
+    #[test]
+    fn test_set_default_function() {
+        let mut column = Column::new(StorageDataType::Uuid);
+        column.set_default(Some(StorageData::Integer(Integer(1))));
+        column.set_default_function(Some(DefaultFunction::GenRandomUuid));
+
+        assert_eq!(column.default, None);
+        assert_eq!(
+            column.get_default_function(),
+            Some(DefaultFunction::GenRandomUuid)
+        );
+
+        column.set_default(Some(StorageData::Integer(Integer(1))));
+        assert_eq!(column.get_default_function(), None);
+    }
+
+    #[test]
+    fn test_set_auto_increment() {
+        let mut column = Column::new(StorageDataType::Long);
+        assert!(column.set_auto_increment(true));
+        assert!(column.is_auto_increment());
+
+        let mut column = Column::new(StorageDataType::Uuid);
+        assert!(!column.set_auto_increment(true));
+        assert!(!column.is_auto_increment());
+    }
+
     #[test]
     fn test_get_type() {
         let column = Column::new(StorageDataType::Integer);
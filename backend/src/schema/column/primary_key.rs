@@ -1,9 +1,14 @@
+use common::structs::hash_table::hash::custom_hashable::CustomHash;
+
 use crate::{
     gen_name,
     schema::{
         column,
         r#type::{
-            data_types::{Integer, Long, UInteger, ULong, UShort},
+            data_types::{
+                Decimal, Integer, Long, Short, UInteger, ULong, UShort, Uuid,
+                VarChar,
+            },
             r#enum::{Byte, StorageData, StorageDataType},
         },
         Column,
@@ -58,13 +63,21 @@ impl PrimaryKey {
             | StorageDataType::Long
             | StorageDataType::UShort
             | StorageDataType::UInteger
-            | StorageDataType::ULong => true,
-            StorageDataType::Bool
+            | StorageDataType::ULong
+            | StorageDataType::Decimal(_, _)
+            | StorageDataType::Uuid
             | StorageDataType::Byte
             | StorageDataType::Short
+            | StorageDataType::VarChar(_) => true,
+            StorageDataType::Bool
             | StorageDataType::Float
             | StorageDataType::Double
-            | StorageDataType::VarChar(_) => false,
+            | StorageDataType::Text
+            | StorageDataType::Blob
+            | StorageDataType::Date
+            | StorageDataType::Time
+            | StorageDataType::Timestamp
+            | StorageDataType::Interval => false,
         }
     }
 }
@@ -72,33 +85,76 @@ impl PrimaryKey {
 /// Enum that represents the primary key type.
 #[derive(Debug, Clone, PartialEq)]
 pub enum Type {
+    Byte,
+    Short,
     Integer,
     Long,
     UShort,
     UInteger,
     ULong,
+    Decimal(u8, u8),
+    Uuid,
+    VarChar(usize),
 }
 
 impl From<Type> for StorageDataType {
     fn from(pk_type: Type) -> Self {
         match pk_type {
+            Type::Byte => StorageDataType::Byte,
+            Type::Short => StorageDataType::Short,
             Type::Integer => StorageDataType::Integer,
             Type::Long => StorageDataType::Long,
             Type::UShort => StorageDataType::UShort,
             Type::UInteger => StorageDataType::UInteger,
             Type::ULong => StorageDataType::ULong,
+            Type::Decimal(precision, scale) => {
+                StorageDataType::Decimal(precision, scale)
+            }
+            Type::Uuid => StorageDataType::Uuid,
+            Type::VarChar(len) => StorageDataType::VarChar(len),
         }
     }
 }
 
 /// Enum that represents the primary key data.
+///
+/// Keys of the same type are ordered by value. `VarChar` keys are ordered
+/// byte-wise by their UTF-8 representation, which is the intended
+/// collation: it is case-sensitive and locale-independent, e.g. `"Z"` comes
+/// before `"a"`, and it's the order of Rust's `str`. Keys are compared in
+/// full, so strings that share a prefix are never equal.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub enum Data {
+    Byte(Byte),
+    Short(Short),
     Integer(Integer),
     Long(Long),
     UShort(UShort),
     UInteger(UInteger),
     ULong(ULong),
+    Decimal(Decimal),
+    Uuid(Uuid),
+    VarChar(VarChar<255>),
+}
+
+impl CustomHash for Data {
+    fn hash(&self, hash: fn(&[u8]) -> u64) -> u64 {
+        match self {
+            Data::VarChar(data) => hash(data.value.as_bytes()),
+            Data::Uuid(data) => hash(&data.0),
+            // Decimals are equal by value, so trailing zeros are stripped to
+            // give `1.5` and `1.50` the same hash.
+            Data::Decimal(data) => {
+                let data = data.trimmed();
+                let mut bytes = data.value.to_be_bytes().to_vec();
+                bytes.push(data.scale);
+                hash(&bytes)
+            }
+            data => {
+                hash(StorageData::from(data.clone()).to_string().as_bytes())
+            }
+        }
+    }
 }
 
 impl TryFrom<StorageData> for Data {
@@ -106,11 +162,16 @@ impl TryFrom<StorageData> for Data {
 
     fn try_from(data: StorageData) -> Result<Self, Self::Error> {
         match data {
+            StorageData::Byte(data) => Ok(Data::Byte(data)),
+            StorageData::Short(data) => Ok(Data::Short(data)),
             StorageData::Integer(data) => Ok(Data::Integer(data)),
             StorageData::Long(data) => Ok(Data::Long(data)),
             StorageData::UShort(data) => Ok(Data::UShort(data)),
             StorageData::UInteger(data) => Ok(Data::UInteger(data)),
             StorageData::ULong(data) => Ok(Data::ULong(data)),
+            StorageData::Decimal(data) => Ok(Data::Decimal(data)),
+            StorageData::Uuid(data) => Ok(Data::Uuid(data)),
+            StorageData::VarChar(data) => Ok(Data::VarChar(data)),
             _ => Err("Invalid data type for primary key"),
         }
     }
@@ -119,20 +180,34 @@ impl TryFrom<StorageData> for Data {
 impl From<Data> for StorageData {
     fn from(pk_data: Data) -> Self {
         match pk_data {
+            Data::Byte(data) => StorageData::Byte(data),
+            Data::Short(data) => StorageData::Short(data),
             Data::Integer(data) => StorageData::Integer(data),
             Data::Long(data) => StorageData::Long(data),
             Data::UShort(data) => StorageData::UShort(data),
             Data::UInteger(data) => StorageData::UInteger(data),
             Data::ULong(data) => StorageData::ULong(data),
+            Data::Decimal(data) => StorageData::Decimal(data),
+            Data::Uuid(data) => StorageData::Uuid(data),
+            Data::VarChar(data) => StorageData::VarChar(data),
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use common::structs::hash_table::hash::{
+        self, custom_hashable::CustomHash,
+    };
+
     use crate::schema::{
         column,
-        column::primary_key::{Name, PrimaryKey},
+        column::primary_key::{Data, Name, PrimaryKey},
+        r#type::{
+            data_types::VarChar,
+            r#enum::{StorageData, StorageDataType},
+        },
+        Column,
     };
 
     #[test]
@@ -162,4 +237,50 @@ mod tests {
 
         assert_eq!(pk.get_column(), &column);
     }
+
+    #[test]
+    fn test_check_type() {
+        for type_ in [
+            StorageDataType::Byte,
+            StorageDataType::Short,
+            StorageDataType::VarChar(16),
+            StorageDataType::Integer,
+        ] {
+            assert!(PrimaryKey::check_type(Column::new(type_)));
+        }
+        assert!(!PrimaryKey::check_type(Column::new(
+            StorageDataType::Double
+        )));
+        assert!(!PrimaryKey::check_type(Column::new(StorageDataType::Text)));
+    }
+
+    #[test]
+    fn test_varchar_data_order() {
+        let key = |value: &str| -> Data {
+            StorageData::VarChar(VarChar::new(value.to_string()).unwrap())
+                .try_into()
+                .unwrap()
+        };
+
+        assert!(key("abc") < key("abd"));
+        assert!(key("ab") < key("abc"));
+        assert!(key("Z") < key("a"));
+        assert!(key("z") < key("\u{e9}"));
+        assert_ne!(key("user"), key("user "));
+        assert_eq!(key("sku-1"), key("sku-1"));
+    }
+
+    #[test]
+    fn test_data_hash() {
+        let hash = |data: &Data| data.hash(hash::hash);
+        let decimal = |value: &str| Data::Decimal(value.parse().unwrap());
+
+        assert_eq!(decimal("1.5"), decimal("1.50"));
+        assert_eq!(hash(&decimal("1.5")), hash(&decimal("1.50")));
+        assert_eq!(hash(&decimal("10")), hash(&decimal("10.00")));
+        assert_ne!(hash(&decimal("1.5")), hash(&decimal("15")));
+
+        let key = Data::VarChar(VarChar::new("sku-1".to_string()).unwrap());
+        assert_eq!(hash(&key), hash(&key.clone()));
+    }
 }
@@ -1,6 +1,7 @@
 pub mod column;
 pub mod database;
 pub mod name;
+pub mod sequence;
 pub mod table;
 pub mod r#type;
 
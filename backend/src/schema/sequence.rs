@@ -0,0 +1,5 @@
+//! Defines the name of standalone sequences, created with `CREATE SEQUENCE`.
+
+use crate::gen_name;
+
+gen_name!();
@@ -0,0 +1,408 @@
+//! Defines the `UNIQUE`, `CHECK` and `FOREIGN KEY` constraints of a
+//! [`Table`].
+//!
+//! [`Table`]: super::Table
+
+use std::fmt::Display;
+
+use derive_more::Display;
+
+use crate::{
+    expression::{EvaluationError, Expression},
+    gen_name,
+    schema::{column, r#type::r#enum::StorageData, table},
+};
+
+gen_name!();
+
+/// A constraint of a table, checked for every added row. Rows can't be
+/// updated, so they are never checked again.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Constraint {
+    /// A `UNIQUE` constraint.
+    Unique(Unique),
+
+    /// A `CHECK` constraint.
+    Check(Check),
+
+    /// A `FOREIGN KEY` constraint.
+    ForeignKey(ForeignKey),
+}
+
+impl Constraint {
+    /// Returns the name of the constraint.
+    /// # Returns
+    /// * `&Name` - The name of the constraint.
+    pub fn get_name(&self) -> &Name {
+        match self {
+            Constraint::Unique(unique) => unique.get_name(),
+            Constraint::Check(check) => check.get_name(),
+            Constraint::ForeignKey(foreign_key) => foreign_key.get_name(),
+        }
+    }
+
+    /// Returns the names of the columns the constraint refers to.
+    /// # Returns
+    /// * `Vec<column::Name>` - The names of the columns.
+    pub fn get_columns(&self) -> Vec<column::Name> {
+        match self {
+            Constraint::Unique(unique) => unique.get_columns().clone(),
+            Constraint::Check(check) => check.get_expression().columns(),
+            Constraint::ForeignKey(foreign_key) => {
+                vec![foreign_key.get_column().clone()]
+            }
+        }
+    }
+}
+
+impl Display for Constraint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Constraint::Unique(unique) => write!(f, "{}", unique),
+            Constraint::Check(check) => write!(f, "{}", check),
+            Constraint::ForeignKey(foreign_key) => write!(f, "{}", foreign_key),
+        }
+    }
+}
+
+/// A `UNIQUE` constraint. Rows can't share the values of its columns, except
+/// for rows with `NULL` in any of them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Unique {
+    /// The name of the constraint.
+    name: Name,
+
+    /// The names of the columns whose values must be unique together.
+    columns: Vec<column::Name>,
+}
+
+impl Unique {
+    /// Creates a new [`Unique`] with the given parameters.
+    /// # Arguments
+    /// * `name` - The name of the constraint.
+    /// * `columns` - The names of the columns.
+    /// # Returns
+    /// A new [`Unique`] with the given parameters.
+    pub fn new(name: Name, columns: Vec<column::Name>) -> Self {
+        Unique { name, columns }
+    }
+
+    /// Returns the name of the constraint.
+    /// # Returns
+    /// * `&Name` - The name of the constraint.
+    pub fn get_name(&self) -> &Name {
+        &self.name
+    }
+
+    /// Returns the names of the columns whose values must be unique.
+    /// # Returns
+    /// * `&Vec<column::Name>` - The names of the columns.
+    pub fn get_columns(&self) -> &Vec<column::Name> {
+        &self.columns
+    }
+}
+
+impl Display for Unique {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let columns = self
+            .columns
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", ");
+        write!(f, "CONSTRAINT {} UNIQUE ({})", self.name, columns)
+    }
+}
+
+/// A `CHECK` constraint. Rows must satisfy its condition, a condition that
+/// is `NULL` is satisfied as its result is unknown.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Check {
+    /// The name of the constraint.
+    name: Name,
+
+    /// The condition the rows must satisfy.
+    expression: Expression,
+}
+
+impl Check {
+    /// Creates a new [`Check`] with the given parameters.
+    /// # Arguments
+    /// * `name` - The name of the constraint.
+    /// * `expression` - The condition the rows must satisfy.
+    /// # Returns
+    /// A new [`Check`] with the given parameters.
+    pub fn new(name: Name, expression: Expression) -> Self {
+        Check { name, expression }
+    }
+
+    /// Returns the name of the constraint.
+    /// # Returns
+    /// * `&Name` - The name of the constraint.
+    pub fn get_name(&self) -> &Name {
+        &self.name
+    }
+
+    /// Returns the condition the rows must satisfy.
+    /// # Returns
+    /// * `&Expression` - The condition.
+    pub fn get_expression(&self) -> &Expression {
+        &self.expression
+    }
+
+    /// Checks if a row passes the constraint.
+    /// # Arguments
+    /// * `value` - Returns the row's value of the column with the given name.
+    /// # Returns
+    /// * `Ok(bool)` - True if the condition is `TRUE` or `NULL`, false if it
+    ///   is `FALSE`.
+    /// * `Err(EvaluationError)` - If the evaluation fails or the condition
+    ///   isn't a `bool`.
+    pub fn is_satisfied<'a, F>(&self, value: F) -> Result<bool, EvaluationError>
+    where
+        F: Fn(&column::Name) -> Option<&'a StorageData>,
+    {
+        match self.expression.evaluate(value)? {
+            StorageData::Bool(value) => Ok(value.0),
+            StorageData::Null => Ok(true),
+            value => Err(EvaluationError::NotBoolean(
+                value.data_type().expect("isn't null"),
+            )),
+        }
+    }
+}
+
+impl Display for Check {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "CONSTRAINT {} CHECK ({})", self.name, self.expression)
+    }
+}
+
+/// A `FOREIGN KEY` constraint. Values of its column, except for `NULL`, must
+/// be in the referenced column of the referenced table.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ForeignKey {
+    /// The name of the constraint.
+    name: Name,
+
+    /// The name of the referencing column.
+    column: column::Name,
+
+    /// The name of the referenced table.
+    table: table::Name,
+
+    /// The name of the referenced column, the primary key or a column with
+    /// a `UNIQUE` constraint.
+    referenced_column: column::Name,
+
+    /// What happens to the referencing rows when a referenced row is
+    /// deleted.
+    on_delete: OnDelete,
+}
+
+impl ForeignKey {
+    /// Creates a new [`ForeignKey`] with the given parameters.
+    /// # Arguments
+    /// * `name` - The name of the constraint.
+    /// * `column` - The name of the referencing column.
+    /// * `table` - The name of the referenced table.
+    /// * `referenced_column` - The name of the referenced column.
+    /// * `on_delete` - The action on deletion of a referenced row.
+    /// # Returns
+    /// A new [`ForeignKey`] with the given parameters.
+    pub fn new(
+        name: Name,
+        column: column::Name,
+        table: table::Name,
+        referenced_column: column::Name,
+        on_delete: OnDelete,
+    ) -> Self {
+        ForeignKey {
+            name,
+            column,
+            table,
+            referenced_column,
+            on_delete,
+        }
+    }
+
+    /// Returns the name of the constraint.
+    /// # Returns
+    /// * `&Name` - The name of the constraint.
+    pub fn get_name(&self) -> &Name {
+        &self.name
+    }
+
+    /// Returns the name of the referencing column.
+    /// # Returns
+    /// * `&column::Name` - The name of the column.
+    pub fn get_column(&self) -> &column::Name {
+        &self.column
+    }
+
+    /// Returns the name of the referenced table.
+    /// # Returns
+    /// * `&table::Name` - The name of the table.
+    pub fn get_table(&self) -> &table::Name {
+        &self.table
+    }
+
+    /// Returns the name of the referenced column.
+    /// # Returns
+    /// * `&column::Name` - The name of the column.
+    pub fn get_referenced_column(&self) -> &column::Name {
+        &self.referenced_column
+    }
+
+    /// Returns the action on deletion of a referenced row.
+    /// # Returns
+    /// * `OnDelete` - The action.
+    pub fn get_on_delete(&self) -> OnDelete {
+        self.on_delete
+    }
+}
+
+impl Display for ForeignKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "CONSTRAINT {} FOREIGN KEY ({}) REFERENCES {} ({}) ON DELETE {}",
+            self.name,
+            self.column,
+            self.table,
+            self.referenced_column,
+            self.on_delete
+        )
+    }
+}
+
+/// Action on the referencing rows of a [`ForeignKey`] when a referenced row
+/// is deleted.
+#[derive(Debug, Display, Default, Clone, Copy, PartialEq)]
+pub enum OnDelete {
+    /// The deletion is refused.
+    #[default]
+    #[display(fmt = "RESTRICT")]
+    Restrict,
+
+    /// The referencing rows are deleted as well.
+    #[display(fmt = "CASCADE")]
+    Cascade,
+
+    /// The referencing column is set to `NULL`.
+    #[display(fmt = "SET NULL")]
+    SetNull,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        expression::{BinaryOperator, EvaluationError, Expression},
+        schema::{
+            column,
+            r#type::{
+                data_types::VarChar,
+                r#enum::{StorageData, StorageDataType},
+            },
+            table::constraint::{
+                Check, Constraint, ForeignKey, OnDelete, Unique,
+            },
+        },
+    };
+
+    fn compare<T: Into<StorageData>>(
+        column: &str,
+        operator: BinaryOperator,
+        value: T,
+    ) -> Expression {
+        Expression::binary(
+            Expression::Column(column.into()),
+            operator,
+            Expression::Literal(value.into()),
+        )
+    }
+
+    fn age_check() -> Check {
+        Check::new(
+            "age_check".into(),
+            Expression::binary(
+                compare("age", BinaryOperator::GreaterThanOrEqual, 18u8),
+                BinaryOperator::And,
+                compare("age", BinaryOperator::LessThan, 150u8),
+            ),
+        )
+    }
+
+    #[test]
+    fn test_check_is_satisfied() {
+        let check = age_check();
+        for (age, expected) in [
+            (StorageData::Byte(18.into()), true),
+            (StorageData::Byte(17.into()), false),
+            (StorageData::Byte(150.into()), false),
+            (StorageData::Null, true),
+        ] {
+            let satisfied = check.is_satisfied(|name: &column::Name| {
+                (name == &"age".into()).then_some(&age)
+            });
+            assert_eq!(satisfied, Ok(expected));
+        }
+        assert_eq!(
+            check.is_satisfied(|_| None),
+            Err(EvaluationError::ColumnDoesNotExist("age".into()))
+        );
+
+        let check =
+            Check::new("age_check".into(), Expression::Column("age".into()));
+        let age = StorageData::Byte(1.into());
+        assert_eq!(
+            check.is_satisfied(|_| Some(&age)),
+            Err(EvaluationError::NotBoolean(StorageDataType::Byte))
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let unique = Constraint::Unique(Unique::new(
+            "users_email_key".into(),
+            vec!["email".into(), "domain".into()],
+        ));
+        assert_eq!(
+            unique.to_string(),
+            "CONSTRAINT users_email_key UNIQUE (email, domain)"
+        );
+        assert_eq!(unique.get_columns().len(), 2);
+
+        assert_eq!(
+            Constraint::Check(age_check()).to_string(),
+            "CONSTRAINT age_check CHECK (age >= 18 AND age < 150)"
+        );
+
+        let check = Check::new(
+            "name_check".into(),
+            compare(
+                "name",
+                BinaryOperator::NotEqual,
+                VarChar::new("root".to_string()).unwrap(),
+            ),
+        );
+        assert_eq!(
+            check.to_string(),
+            "CONSTRAINT name_check CHECK (name <> 'root')"
+        );
+
+        let foreign_key = Constraint::ForeignKey(ForeignKey::new(
+            "orders_user_id_fkey".into(),
+            "user_id".into(),
+            "users".into(),
+            "id".into(),
+            OnDelete::SetNull,
+        ));
+        assert_eq!(
+            foreign_key.to_string(),
+            "CONSTRAINT orders_user_id_fkey FOREIGN KEY (user_id) \
+             REFERENCES users (id) ON DELETE SET NULL"
+        );
+        assert_eq!(foreign_key.get_columns(), vec!["user_id".into()]);
+    }
+}
@@ -1,12 +1,16 @@
-use common::structs::hash_table::{
-    scalable::ScalableHashTable, HashTable, VecFunctions,
-};
+pub mod constraint;
+pub mod statistics;
+
+use common::structs::hash_table::{scalable::ScalableHashTable, HashTable};
 
 use crate::{
+    data::id::Sequence,
     gen_name,
     schema::{
         column,
         column::{primary_key::PrimaryKey, Column},
+        r#type::r#enum::StorageDataType,
+        table::{constraint::Constraint, statistics::TableStatistics},
     },
 };
 
@@ -21,8 +25,23 @@ pub struct Table {
     /// The columns of the table.
     columns: ScalableHashTable<column::Name, Column>,
 
+    /// The column names in the order they were added.
+    column_names: Vec<column::Name>,
+
     /// The primary key of the table.
     primary_key: Option<PrimaryKey>,
+
+    /// The `UNIQUE`, `CHECK` and `FOREIGN KEY` constraints of the table in
+    /// the order they were added.
+    constraints: Vec<Constraint>,
+
+    /// The statistics of the rows collected by `ANALYZE`, `None` if the
+    /// table wasn't analyzed.
+    statistics: Option<TableStatistics>,
+
+    /// The sequences of the `AUTO_INCREMENT` columns. They are kept with the
+    /// schema, so that values generated once are never generated again.
+    sequences: Vec<(column::Name, Sequence)>,
 }
 
 impl Table {
@@ -37,7 +56,11 @@ impl Table {
         Table {
             name,
             columns: ScalableHashTable::default(),
+            column_names: Vec::new(),
             primary_key: None,
+            constraints: Vec::new(),
+            statistics: None,
+            sequences: Vec::new(),
         }
     }
 
@@ -48,11 +71,22 @@ impl Table {
         &self.name
     }
 
-    /// Adds a column to the table.
+    /// Adds a column to the table. A sequence is created for the column if
+    /// it is `AUTO_INCREMENT`, it's exhausted after the largest value of the
+    /// column's type.
     /// # Arguments
     /// * `name` - The name of the column.
     /// * `column` - The column to add.
     pub fn add_column(&mut self, name: column::Name, column: Column) {
+        if !self.column_names.contains(&name) {
+            self.column_names.push(name.clone());
+        }
+        self.sequences.retain(|(column, _)| column != &name);
+        if column.is_auto_increment() {
+            let max = column.get_type().integer_max().unwrap_or(i128::MAX);
+            let sequence = Sequence::default().with_max(max);
+            self.sequences.push((name.clone(), sequence));
+        }
         self.columns.insert(name, column);
     }
 
@@ -104,11 +138,90 @@ impl Table {
         self.primary_key = Some(primary_key);
     }
 
-    /// Returns the column names of the table.
+    /// Adds a constraint to the table.
+    /// # Arguments
+    /// * `constraint` - The constraint to add.
+    pub fn add_constraint(&mut self, constraint: Constraint) {
+        self.constraints.push(constraint);
+    }
+
+    /// Removes the constraint added last, e.g. if the table's rows don't
+    /// satisfy it.
+    pub fn remove_last_constraint(&mut self) {
+        self.constraints.pop();
+    }
+
+    /// Removes the constraint with the given name.
+    /// # Arguments
+    /// * `name` - The name of the constraint.
+    /// # Returns
+    /// * `Option<Constraint>` - The removed constraint.
+    pub fn remove_constraint(
+        &mut self,
+        name: &constraint::Name,
+    ) -> Option<Constraint> {
+        let position = self
+            .constraints
+            .iter()
+            .position(|constraint| constraint.get_name() == name)?;
+        Some(self.constraints.remove(position))
+    }
+
+    /// Returns the constraints of the table in the order they were added.
+    /// # Returns
+    /// * `&Vec<Constraint>` - The constraints of the table.
+    pub fn get_constraints(&self) -> &Vec<Constraint> {
+        &self.constraints
+    }
+
+    /// Returns the column names of the table in the order they were added.
     /// # Returns
     /// * `Vec<column::Name>` - The column names of the table.
-    pub fn get_column_names(&mut self) -> Vec<column::Name> {
-        self.columns.get_keys()
+    pub fn get_column_names(&self) -> Vec<column::Name> {
+        self.column_names.clone()
+    }
+
+    /// Returns the column types of the table in the order the columns were
+    /// added.
+    /// # Returns
+    /// * `Vec<StorageDataType>` - The column types of the table.
+    pub fn get_column_types(&self) -> Vec<StorageDataType> {
+        self.column_names
+            .iter()
+            .filter_map(|name| self.columns.get(name))
+            .map(|column| column.get_type())
+            .collect()
+    }
+
+    /// Returns the statistics of the table's rows.
+    /// # Returns
+    /// * `Option<&TableStatistics>` - The statistics collected last or
+    ///   `None` if the table wasn't analyzed.
+    pub fn get_statistics(&self) -> Option<&TableStatistics> {
+        self.statistics.as_ref()
+    }
+
+    /// Returns the sequence of an `AUTO_INCREMENT` column.
+    /// # Arguments
+    /// * `name` - The name of the column.
+    /// # Returns
+    /// * `Option<&mut Sequence>` - The sequence of the column, `None` if the
+    ///   column isn't `AUTO_INCREMENT`.
+    pub fn get_mut_sequence(
+        &mut self,
+        name: &column::Name,
+    ) -> Option<&mut Sequence> {
+        self.sequences
+            .iter_mut()
+            .find(|(column, _)| column == name)
+            .map(|(_, sequence)| sequence)
+    }
+
+    /// Sets the statistics of the table's rows.
+    /// # Arguments
+    /// * `statistics` - The statistics, they replace the previous ones.
+    pub fn set_statistics(&mut self, statistics: TableStatistics) {
+        self.statistics = Some(statistics);
     }
 }
 
@@ -125,7 +238,10 @@ mod tests {
     use crate::schema::{
         column::{primary_key::PrimaryKey, Column},
         r#type::r#enum::StorageDataType,
-        table::{Name, Table},
+        table::{
+            constraint::{Constraint, Unique},
+            Name, Table,
+        },
     };
 
     #[test]
@@ -176,4 +292,58 @@ mod tests {
         table.set_primary_key(primary_key.clone());
         assert_eq!(table.get_primary_key(), &Some(primary_key));
     }
+
+    #[test]
+    fn test_table_add_constraint() {
+        let mut table = Table::new("table".into());
+        assert!(table.get_constraints().is_empty());
+
+        let unique = Constraint::Unique(Unique::new(
+            "table_column_key".into(),
+            vec!["column".into()],
+        ));
+        table.add_constraint(unique.clone());
+        assert_eq!(table.get_constraints(), &vec![unique.clone()]);
+
+        assert_eq!(table.remove_constraint(&"missing".into()), None);
+        assert_eq!(
+            table.remove_constraint(&"table_column_key".into()),
+            Some(unique)
+        );
+        assert!(table.get_constraints().is_empty());
+    }
+
+    #[test]
+    fn test_table_auto_increment_sequence() {
+        let mut table = Table::new("table".into());
+        let mut column = Column::new(StorageDataType::Byte);
+        assert!(column.set_auto_increment(true));
+        table.add_column("id".into(), column);
+        table.add_column("value".into(), Column::new(StorageDataType::Byte));
+        assert!(table.get_mut_sequence(&"value".into()).is_none());
+
+        let sequence = table.get_mut_sequence(&"id".into()).unwrap();
+        sequence.observe(254);
+        assert_eq!(sequence.next_value(), Some(255));
+        assert_eq!(sequence.next_value(), None);
+
+        // The state of the sequence is a part of the schema.
+        let mut copy = table.clone();
+        let sequence = copy.get_mut_sequence(&"id".into()).unwrap();
+        assert_eq!(sequence.current_value(), Some(255));
+    }
+
+    #[test]
+    fn test_table_get_column_names_in_order() {
+        let mut table = Table::new("table".into());
+        for name in ["c", "a", "b", "a"] {
+            let column = Column::new(StorageDataType::Integer);
+            table.add_column(name.into(), column);
+        }
+
+        assert_eq!(
+            table.get_column_names(),
+            vec!["c".into(), "a".into(), "b".into()]
+        );
+    }
 }
@@ -0,0 +1,341 @@
+//! Statistics of the rows of a [`Table`] collected by `ANALYZE`. The
+//! planner estimates the number of rows of scans and joins by them, see
+//! [`query::cost`].
+//!
+//! [`Table`]: super::Table
+//! [`query::cost`]: crate::query::cost
+
+use std::cmp::Ordering;
+
+use crate::{
+    controller::table::selector::{FilterType, SelectorFilter},
+    expression::compare,
+    schema::{column, r#type::r#enum::StorageData},
+};
+
+/// The maximum number of buckets of a [`Histogram`].
+pub const HISTOGRAM_BUCKETS: usize = 16;
+
+/// The fraction of rows estimated to pass an equality filter on a column
+/// without statistics.
+pub const DEFAULT_EQUALITY_SELECTIVITY: f64 = 0.1;
+
+/// The fraction of rows estimated to pass any other filter on a column
+/// without statistics.
+pub const DEFAULT_SELECTIVITY: f64 = 1.0 / 3.0;
+
+/// Statistics of the rows of a table.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TableStatistics {
+    /// The number of rows.
+    row_count: usize,
+
+    /// The statistics of the columns in the order of the table's columns.
+    columns: Vec<(column::Name, ColumnStatistics)>,
+}
+
+impl TableStatistics {
+    /// Creates new [`TableStatistics`].
+    /// # Arguments
+    /// * `row_count` - The number of rows.
+    /// * `columns` - The statistics of the columns.
+    pub fn new(
+        row_count: usize,
+        columns: Vec<(column::Name, ColumnStatistics)>,
+    ) -> Self {
+        Self { row_count, columns }
+    }
+
+    /// Returns the number of rows when the statistics were collected.
+    pub fn get_row_count(&self) -> usize {
+        self.row_count
+    }
+
+    /// Returns the statistics of the columns in the order of the table's
+    /// columns.
+    pub fn get_columns(&self) -> &[(column::Name, ColumnStatistics)] {
+        &self.columns
+    }
+
+    /// Returns the statistics of the column with the given name.
+    /// # Arguments
+    /// * `name` - The name of the column.
+    /// # Returns
+    /// * `Option<&ColumnStatistics>` - The statistics or `None` if the
+    ///   column was added after the statistics were collected.
+    pub fn get_column(&self, name: &column::Name) -> Option<&ColumnStatistics> {
+        self.columns
+            .iter()
+            .find(|(column, _)| column == name)
+            .map(|(_, statistics)| statistics)
+    }
+
+    /// Estimates the fraction of rows that pass the filter.
+    /// # Arguments
+    /// * `filter` - The filter.
+    /// # Returns
+    /// * `f64` - The fraction between `0` and `1`, a default one if the
+    ///   filtered column has no statistics.
+    pub fn selectivity(&self, filter: &SelectorFilter) -> f64 {
+        match self.get_column(&filter.column_name) {
+            Some(column) => column.selectivity(
+                self.row_count,
+                &filter.filter_type,
+                &filter.value,
+            ),
+            None => default_selectivity(&filter.filter_type),
+        }
+    }
+}
+
+/// Statistics of the values of a column.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnStatistics {
+    /// The number of `NULL` values.
+    null_count: usize,
+
+    /// The number of distinct values other than `NULL`.
+    distinct_count: usize,
+
+    /// The histogram of the values other than `NULL`.
+    histogram: Histogram,
+}
+
+impl ColumnStatistics {
+    /// Collects the statistics of the values of a column.
+    /// # Arguments
+    /// * `values` - The values of the column in all rows.
+    pub fn collect(values: Vec<StorageData>) -> Self {
+        let row_count = values.len();
+        let mut values = values
+            .into_iter()
+            .filter(|value| *value != StorageData::Null)
+            .collect::<Vec<_>>();
+        values.sort_by(order);
+
+        let distinct_count = match values.first() {
+            Some(_) => {
+                1 + values
+                    .windows(2)
+                    .filter(|pair| order(&pair[0], &pair[1]).is_ne())
+                    .count()
+            }
+            None => 0,
+        };
+
+        Self {
+            null_count: row_count - values.len(),
+            distinct_count,
+            histogram: Histogram::new(&values),
+        }
+    }
+
+    /// Returns the number of `NULL` values.
+    pub fn get_null_count(&self) -> usize {
+        self.null_count
+    }
+
+    /// Returns the number of distinct values other than `NULL`.
+    pub fn get_distinct_count(&self) -> usize {
+        self.distinct_count
+    }
+
+    /// Returns the histogram of the values other than `NULL`.
+    pub fn get_histogram(&self) -> &Histogram {
+        &self.histogram
+    }
+
+    /// Estimates the fraction of rows whose value passes the filter. Values
+    /// are assumed to be spread evenly over the distinct values and the
+    /// buckets of the histogram.
+    fn selectivity(
+        &self,
+        row_count: usize,
+        filter_type: &FilterType,
+        value: &StorageData,
+    ) -> f64 {
+        if row_count == 0 {
+            return 0.0;
+        }
+
+        let not_null = 1.0 - self.null_count as f64 / row_count as f64;
+        let equal = if self.distinct_count == 0 || !self.histogram.covers(value)
+        {
+            0.0
+        } else {
+            not_null / self.distinct_count as f64
+        };
+        let below = not_null * self.histogram.fraction_below(value);
+
+        let selectivity = match filter_type {
+            FilterType::Equal => equal,
+            FilterType::NotEqual => not_null - equal,
+            FilterType::LessThan => below,
+            FilterType::LessThanOrEqual => below + equal,
+            FilterType::GreaterThan => not_null - below - equal,
+            FilterType::GreaterThanOrEqual => not_null - below,
+        };
+        selectivity.clamp(0.0, 1.0)
+    }
+}
+
+/// An equi-depth histogram of the values of a column: the bounds split the
+/// sorted values into buckets with the same number of values.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Histogram {
+    /// The smallest value, the bounds between the buckets and the largest
+    /// value.
+    bounds: Vec<StorageData>,
+}
+
+impl Histogram {
+    /// Builds the histogram of at most [`HISTOGRAM_BUCKETS`] buckets.
+    /// # Arguments
+    /// * `values` - The sorted values, none of them is `NULL`.
+    fn new(values: &[StorageData]) -> Self {
+        let Some(last) = values.len().checked_sub(1) else {
+            return Self::default();
+        };
+
+        let buckets = HISTOGRAM_BUCKETS.min(last.max(1));
+        let bounds = (0..=buckets)
+            .map(|bucket| values[bucket * last / buckets].clone())
+            .collect();
+        Self { bounds }
+    }
+
+    /// Returns the smallest value, the bounds between the buckets and the
+    /// largest value.
+    pub fn get_bounds(&self) -> &[StorageData] {
+        &self.bounds
+    }
+
+    /// Estimates the fraction of the values that are less than the value,
+    /// half of the values of the bucket it falls into are counted.
+    /// # Arguments
+    /// * `value` - The value.
+    /// # Returns
+    /// * `f64` - The fraction between `0` and `1`.
+    pub fn fraction_below(&self, value: &StorageData) -> f64 {
+        let below = self
+            .bounds
+            .iter()
+            .take_while(|bound| compare(bound, value) == Some(Ordering::Less))
+            .count();
+
+        match below {
+            0 => 0.0,
+            below if below == self.bounds.len() => 1.0,
+            below => (below as f64 - 0.5) / (self.bounds.len() - 1) as f64,
+        }
+    }
+
+    /// Checks if the value is between the smallest and the largest value.
+    fn covers(&self, value: &StorageData) -> bool {
+        match (self.bounds.first(), self.bounds.last()) {
+            (Some(first), Some(last)) => {
+                compare(first, value).is_some_and(Ordering::is_le)
+                    && compare(value, last).is_some_and(Ordering::is_le)
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Returns the fraction of rows estimated to pass a filter on a column
+/// without statistics.
+pub fn default_selectivity(filter_type: &FilterType) -> f64 {
+    match filter_type {
+        FilterType::Equal => DEFAULT_EQUALITY_SELECTIVITY,
+        FilterType::NotEqual => 1.0 - DEFAULT_EQUALITY_SELECTIVITY,
+        _ => DEFAULT_SELECTIVITY,
+    }
+}
+
+/// Orders values of a column, values that can't be compared are equal.
+fn order(left: &StorageData, right: &StorageData) -> Ordering {
+    compare(left, right).unwrap_or(Ordering::Equal)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        controller::table::selector::{FilterType, SelectorFilter},
+        schema::{
+            r#type::r#enum::StorageData,
+            table::statistics::{
+                ColumnStatistics, TableStatistics, DEFAULT_SELECTIVITY,
+            },
+        },
+    };
+
+    fn filter(filter_type: FilterType, value: i32) -> SelectorFilter {
+        SelectorFilter {
+            column_name: "age".into(),
+            value: StorageData::from(value),
+            filter_type,
+        }
+    }
+
+    /// Ages 1 to 100 and 20 `NULL`s.
+    fn statistics() -> TableStatistics {
+        let values = (1..=100)
+            .map(StorageData::from)
+            .chain(std::iter::repeat_n(StorageData::Null, 20))
+            .collect::<Vec<_>>();
+
+        TableStatistics::new(
+            120,
+            vec![("age".into(), ColumnStatistics::collect(values))],
+        )
+    }
+
+    #[test]
+    fn test_column_statistics() {
+        let values = [3, 1, 2, 3, 1]
+            .map(StorageData::from)
+            .into_iter()
+            .chain([StorageData::Null])
+            .collect();
+        let statistics = ColumnStatistics::collect(values);
+
+        assert_eq!(statistics.get_null_count(), 1);
+        assert_eq!(statistics.get_distinct_count(), 3);
+        assert_eq!(
+            statistics.get_histogram().get_bounds(),
+            &[1, 1, 2, 3, 3].map(StorageData::from)
+        );
+
+        let statistics = ColumnStatistics::collect(vec![StorageData::Null]);
+        assert_eq!(statistics.get_distinct_count(), 0);
+        assert!(statistics.get_histogram().get_bounds().is_empty());
+    }
+
+    #[test]
+    fn test_selectivity() {
+        let statistics = statistics();
+        let selectivity = |filter_type, value| {
+            statistics.selectivity(&filter(filter_type, value))
+        };
+        let close = |estimate: f64, expected: f64| {
+            assert!(
+                (estimate - expected).abs() < 0.05,
+                "{} isn't close to {}",
+                estimate,
+                expected
+            )
+        };
+
+        close(selectivity(FilterType::Equal, 50), 0.0083);
+        close(selectivity(FilterType::Equal, 500), 0.0);
+        close(selectivity(FilterType::NotEqual, 50), 0.825);
+        close(selectivity(FilterType::LessThan, 25), 0.2);
+        close(selectivity(FilterType::GreaterThanOrEqual, 25), 0.633);
+        close(selectivity(FilterType::GreaterThan, 0), 0.833);
+        close(selectivity(FilterType::LessThan, 0), 0.0);
+
+        let mut other = filter(FilterType::LessThan, 1);
+        other.column_name = "name".into();
+        assert_eq!(statistics.selectivity(&other), DEFAULT_SELECTIVITY);
+    }
+}
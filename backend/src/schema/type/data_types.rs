@@ -82,8 +82,92 @@ impl<const N: u16> TryFrom<String> for VarChar<N> {
     }
 }
 
+/// Represents a text data type of any length.
+#[derive(AsRef, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, From)]
+pub struct Text(pub String);
+
+/// Represents a binary data type of any length.
+#[derive(AsRef, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, From)]
+pub struct Blob(pub Vec<u8>);
+
+/// Represents a date as a number of days since 1970-01-01.
+#[derive(
+    AsRef, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, From,
+)]
+pub struct Date(pub i32);
+
+/// Represents a time of day as a number of microseconds since midnight.
+#[derive(
+    AsRef, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, From,
+)]
+pub struct Time(pub i64);
+
+/// Represents a timestamp as a number of microseconds since
+/// 1970-01-01 00:00:00 UTC.
+#[derive(
+    AsRef, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, From,
+)]
+pub struct Timestamp(pub i64);
+
+/// Represents an interval data type. Months and days are kept apart from
+/// microseconds, as their length depends on the date they are added to.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Interval {
+    /// Number of months.
+    pub months: i32,
+
+    /// Number of days.
+    pub days: i32,
+
+    /// Number of microseconds.
+    pub micros: i64,
+}
+
+/// Represents a UUID as its 16 bytes, in the order of its text form.
+#[derive(
+    AsRef, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, From,
+)]
+pub struct Uuid(pub [u8; 16]);
+
+/// Represents a fixed-precision decimal as an unscaled value and the number
+/// of digits after the decimal point, e.g. `123.45` is `12345` with scale
+/// `2`. Decimals are compared by their values, so `1.5` equals `1.50`.
+#[derive(Debug, Clone, Copy)]
+pub struct Decimal {
+    /// The unscaled value.
+    pub(crate) value: i128,
+
+    /// The number of digits after the decimal point.
+    pub(crate) scale: u8,
+}
+
+impl Blob {
+    /// Returns the data as lowercase hex digits, two per byte.
+    pub fn to_hex(&self) -> String {
+        self.0.iter().map(|byte| format!("{:02x}", byte)).collect()
+    }
+
+    /// Parses the data from hex digits, two per byte.
+    /// # Returns
+    /// * `Some(Blob)` - The parsed data.
+    /// * `None` - If the number of digits is odd or a char isn't a hex digit.
+    pub fn from_hex(hex: &str) -> Option<Self> {
+        let is_hex = hex.chars().all(|ch| ch.is_ascii_hexdigit());
+        if !is_hex || !hex.len().is_multiple_of(2) {
+            return None;
+        }
+
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).ok())
+            .collect::<Option<Vec<_>>>()
+            .map(Blob)
+    }
+}
+
+#[cfg(test)]
 mod tests {
-    use crate::schema::r#type::data_types::VarChar;
+    use crate::schema::r#type::data_types::{Blob, VarChar};
 
     #[test]
     fn test_varchar() {
@@ -96,4 +180,16 @@ mod tests {
         let varchar = VarChar::<10>::new("Hello World".to_string());
         assert!(varchar.is_err());
     }
+
+    #[test]
+    fn test_blob_hex() {
+        let blob = Blob::from_hex("DEADbeef00").unwrap();
+        assert_eq!(blob, Blob(vec![0xde, 0xad, 0xbe, 0xef, 0]));
+        assert_eq!(blob.to_hex(), "deadbeef00");
+
+        assert_eq!(Blob::from_hex(""), Some(Blob(vec![])));
+        assert_eq!(Blob::from_hex("abc"), None);
+        assert_eq!(Blob::from_hex("+f"), None);
+        assert_eq!(Blob::from_hex("zz"), None);
+    }
 }
@@ -0,0 +1,415 @@
+//! Arithmetic, rounding, comparison and parsing of [`Decimal`]s. Values are
+//! parsed from and formatted as plain numbers like `-123.45`.
+
+use std::{cmp::Ordering, fmt::Display, str::FromStr};
+
+use crate::schema::r#type::data_types::Decimal;
+
+/// Maximum number of digits of a decimal, as many as always fit into `i128`.
+pub const MAX_PRECISION: u8 = 38;
+
+/// Rule to round a decimal to fewer digits after the decimal point.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Rounding {
+    /// Rounds half away from zero, e.g. `2.5` to `3` and `-2.5` to `-3`.
+    #[default]
+    HalfUp,
+
+    /// Rounds half to the even neighbour, e.g. `2.5` to `2` and `3.5` to `4`.
+    HalfEven,
+
+    /// Drops the digits, e.g. `2.9` to `2` and `-2.9` to `-2`.
+    Down,
+}
+
+/// Returns `10` to the power of `exp`, or `None` if it doesn't fit.
+fn pow10(exp: u8) -> Option<i128> {
+    10i128.checked_pow(u32::from(exp))
+}
+
+/// Returns the number of digits of the value, `0` for zero.
+fn count_digits(value: i128) -> u8 {
+    let mut value = value.unsigned_abs();
+    let mut digits = 0;
+    while value != 0 {
+        value /= 10;
+        digits += 1;
+    }
+
+    digits
+}
+
+/// Divides the values and rounds the quotient to an integer.
+/// # Returns
+/// * `Some(i128)` - The rounded quotient.
+/// * `None` - If the divisor is zero or the quotient overflows.
+fn div_round(
+    dividend: i128,
+    divisor: i128,
+    rounding: Rounding,
+) -> Option<i128> {
+    let quotient = dividend.checked_div(divisor)?;
+    let remainder = dividend.checked_rem(divisor)?.unsigned_abs();
+    if remainder == 0 {
+        return Some(quotient);
+    }
+
+    let rest = divisor.unsigned_abs() - remainder;
+    let round_away = match rounding {
+        Rounding::HalfUp => remainder >= rest,
+        Rounding::HalfEven => {
+            remainder > rest || (remainder == rest && quotient % 2 != 0)
+        }
+        Rounding::Down => false,
+    };
+
+    match (round_away, (dividend < 0) != (divisor < 0)) {
+        (false, _) => Some(quotient),
+        (true, false) => quotient.checked_add(1),
+        (true, true) => quotient.checked_sub(1),
+    }
+}
+
+impl Decimal {
+    /// Creates a decimal from the unscaled value and the scale.
+    /// # Arguments
+    /// * `value` - The unscaled value, e.g. `12345` for `123.45`.
+    /// * `scale` - The number of digits after the decimal point.
+    /// # Returns
+    /// * `Some(Decimal)` - The decimal.
+    /// * `None` - If the value or the scale exceeds [`MAX_PRECISION`].
+    pub fn new(value: i128, scale: u8) -> Option<Self> {
+        (scale <= MAX_PRECISION && count_digits(value) <= MAX_PRECISION)
+            .then_some(Decimal { value, scale })
+    }
+
+    /// Returns zero with the scale, which is capped at [`MAX_PRECISION`].
+    pub fn zero(scale: u8) -> Self {
+        Decimal {
+            value: 0,
+            scale: scale.min(MAX_PRECISION),
+        }
+    }
+
+    /// Returns the unscaled value.
+    pub fn value(&self) -> i128 {
+        self.value
+    }
+
+    /// Returns the number of digits after the decimal point.
+    pub fn scale(&self) -> u8 {
+        self.scale
+    }
+
+    /// Returns the number of digits the decimal needs, which is at least its
+    /// scale, e.g. `3` for `1.23` and `2` for `0.05`.
+    pub fn precision(&self) -> u8 {
+        count_digits(self.value).max(self.scale).max(1)
+    }
+
+    /// Changes the number of digits after the decimal point.
+    /// # Arguments
+    /// * `scale` - The new scale.
+    /// * `rounding` - The rule to round dropped digits with.
+    /// # Returns
+    /// * `Some(Decimal)` - The decimal with the new scale.
+    /// * `None` - If the result exceeds [`MAX_PRECISION`].
+    pub fn rescale(self, scale: u8, rounding: Rounding) -> Option<Self> {
+        let value = match scale.cmp(&self.scale) {
+            Ordering::Equal => self.value,
+            Ordering::Greater => {
+                self.value.checked_mul(pow10(scale - self.scale)?)?
+            }
+            Ordering::Less => {
+                div_round(self.value, pow10(self.scale - scale)?, rounding)?
+            }
+        };
+
+        Self::new(value, scale)
+    }
+
+    /// Rounds the decimal half up to the scale of a `DECIMAL(precision,
+    /// scale)` column.
+    /// # Returns
+    /// * `Some(Decimal)` - The rounded decimal.
+    /// * `None` - If the digits before the decimal point don't fit.
+    pub fn fit(self, precision: u8, scale: u8) -> Option<Self> {
+        let value = self.rescale(scale, Rounding::default())?;
+        (value.precision() <= precision).then_some(value)
+    }
+
+    /// Drops the zeros at the end of the digits after the decimal point,
+    /// e.g. `1.50` becomes `1.5`, so that equal decimals have the same
+    /// digits, e.g. to hash them.
+    pub fn trimmed(self) -> Self {
+        let (mut value, mut scale) = (self.value, self.scale);
+        while scale > 0 && value % 10 == 0 {
+            value /= 10;
+            scale -= 1;
+        }
+
+        Self { value, scale }
+    }
+
+    /// Adds the decimals exactly, the sum has the larger scale of both.
+    pub fn checked_add(self, other: Decimal) -> Option<Decimal> {
+        let scale = self.scale.max(other.scale);
+        let lhs = self.rescale(scale, Rounding::Down)?;
+        let rhs = other.rescale(scale, Rounding::Down)?;
+
+        Self::new(lhs.value.checked_add(rhs.value)?, scale)
+    }
+
+    /// Subtracts the decimals exactly, the difference has the larger scale of
+    /// both.
+    pub fn checked_sub(self, other: Decimal) -> Option<Decimal> {
+        self.checked_add(other.checked_neg()?)
+    }
+
+    /// Multiplies the decimals. The product has the sum of both scales, and
+    /// is rounded half up if it exceeds [`MAX_PRECISION`].
+    pub fn checked_mul(self, other: Decimal) -> Option<Decimal> {
+        let value = self.value.checked_mul(other.value)?;
+        let scale = self.scale + other.scale;
+        if scale <= MAX_PRECISION {
+            return Self::new(value, scale);
+        }
+
+        let divisor = pow10(scale - MAX_PRECISION)?;
+        Self::new(
+            div_round(value, divisor, Rounding::default())?,
+            MAX_PRECISION,
+        )
+    }
+
+    /// Divides the decimals and rounds the quotient to the scale.
+    /// # Arguments
+    /// * `other` - The divisor.
+    /// * `scale` - The scale of the quotient.
+    /// * `rounding` - The rule to round the quotient with.
+    /// # Returns
+    /// * `Some(Decimal)` - The quotient.
+    /// * `None` - If the divisor is zero or the quotient overflows.
+    pub fn checked_div(
+        self,
+        other: Decimal,
+        scale: u8,
+        rounding: Rounding,
+    ) -> Option<Decimal> {
+        // a / 10^sa / (b / 10^sb) * 10^scale = a * 10^(scale + sb - sa) / b
+        let shift = scale.checked_add(other.scale)?;
+        let value = if shift >= self.scale {
+            let dividend =
+                self.value.checked_mul(pow10(shift - self.scale)?)?;
+            div_round(dividend, other.value, rounding)?
+        } else {
+            let divisor =
+                other.value.checked_mul(pow10(self.scale - shift)?)?;
+            div_round(self.value, divisor, rounding)?
+        };
+
+        Self::new(value, scale)
+    }
+
+    /// Negates the decimal.
+    pub fn checked_neg(self) -> Option<Decimal> {
+        Self::new(self.value.checked_neg()?, self.scale)
+    }
+
+    /// Splits the decimal into the integer part, rounded down, and the
+    /// non-negative fraction scaled to the scale.
+    fn split(&self, scale: u8) -> (i128, i128) {
+        let unit = pow10(self.scale).expect("scale is at most MAX_PRECISION");
+        let shift =
+            pow10(scale - self.scale).expect("scale is at most MAX_PRECISION");
+
+        (
+            self.value.div_euclid(unit),
+            self.value.rem_euclid(unit) * shift,
+        )
+    }
+}
+
+impl PartialEq for Decimal {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Decimal {}
+
+impl PartialOrd for Decimal {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Decimal {
+    /// Compares the values of the decimals. Integer parts and fractions are
+    /// compared separately, so that rescaling can't overflow.
+    fn cmp(&self, other: &Self) -> Ordering {
+        let scale = self.scale.max(other.scale);
+        self.split(scale).cmp(&other.split(scale))
+    }
+}
+
+impl FromStr for Decimal {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, unsigned) = match s.strip_prefix('-') {
+            Some(unsigned) => (true, unsigned),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        let (integer, fraction) =
+            unsigned.split_once('.').unwrap_or((unsigned, ""));
+
+        let is_digits = integer
+            .chars()
+            .chain(fraction.chars())
+            .all(|ch| ch.is_ascii_digit());
+        if !is_digits || (integer.is_empty() && fraction.is_empty()) {
+            return Err(());
+        }
+
+        let scale = u8::try_from(fraction.len()).map_err(|_| ())?;
+        let digits = format!("{}{}", integer, fraction);
+        let value = digits.parse::<i128>().map_err(|_| ())?;
+
+        Decimal::new(if negative { -value } else { value }, scale).ok_or(())
+    }
+}
+
+impl Display for Decimal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let scale = usize::from(self.scale);
+        let digits = format!(
+            "{:0>width$}",
+            self.value.unsigned_abs(),
+            width = scale + 1
+        );
+        let (integer, fraction) = digits.split_at(digits.len() - scale);
+        let sign = if self.value < 0 { "-" } else { "" };
+
+        if fraction.is_empty() {
+            write!(f, "{}{}", sign, integer)
+        } else {
+            write!(f, "{}{}.{}", sign, integer, fraction)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::schema::r#type::{
+        data_types::Decimal,
+        decimal::{Rounding, MAX_PRECISION},
+    };
+
+    fn decimal(s: &str) -> Decimal {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse_and_display() {
+        let value = decimal("-123.450");
+        assert_eq!((value.value(), value.scale()), (-123_450, 3));
+        assert_eq!(value.to_string(), "-123.450");
+
+        assert_eq!(decimal("0.05").to_string(), "0.05");
+        assert_eq!(decimal("+.5").to_string(), "0.5");
+        assert_eq!(decimal("7.").to_string(), "7");
+        assert_eq!(decimal("-0.001").to_string(), "-0.001");
+
+        assert_eq!("".parse::<Decimal>(), Err(()));
+        assert_eq!("-.".parse::<Decimal>(), Err(()));
+        assert_eq!("1e3".parse::<Decimal>(), Err(()));
+        assert_eq!("1.2.3".parse::<Decimal>(), Err(()));
+        assert_eq!("1".repeat(39).parse::<Decimal>(), Err(()));
+    }
+
+    #[test]
+    fn test_precision() {
+        assert_eq!(decimal("1.23").precision(), 3);
+        assert_eq!(decimal("0.05").precision(), 2);
+        assert_eq!(decimal("0").precision(), 1);
+        assert_eq!(Decimal::new(1, MAX_PRECISION + 1), None);
+    }
+
+    #[test]
+    fn test_rescale() {
+        let value = decimal("2.5");
+        assert_eq!(value.rescale(0, Rounding::HalfUp), Some(decimal("3")));
+        assert_eq!(value.rescale(0, Rounding::HalfEven), Some(decimal("2")));
+        assert_eq!(value.rescale(0, Rounding::Down), Some(decimal("2")));
+
+        let value = decimal("-3.5");
+        assert_eq!(value.rescale(0, Rounding::HalfUp), Some(decimal("-4")));
+        assert_eq!(value.rescale(0, Rounding::HalfEven), Some(decimal("-4")));
+        assert_eq!(value.rescale(0, Rounding::Down), Some(decimal("-3")));
+
+        let value = decimal("1.005").rescale(5, Rounding::Down).unwrap();
+        assert_eq!((value.value(), value.scale()), (100_500, 5));
+    }
+
+    #[test]
+    fn test_trimmed() {
+        let value = decimal("1.500").trimmed();
+        assert_eq!((value.value(), value.scale()), (15, 1));
+        let value = decimal("-20.00").trimmed();
+        assert_eq!((value.value(), value.scale()), (-20, 0));
+        let value = Decimal::zero(3).trimmed();
+        assert_eq!((value.value(), value.scale()), (0, 0));
+    }
+
+    #[test]
+    fn test_fit() {
+        assert_eq!(decimal("123.456").fit(5, 2), Some(decimal("123.46")));
+        assert_eq!(decimal("-0.005").fit(2, 2), Some(decimal("-0.01")));
+        assert_eq!(decimal("999.995").fit(5, 2), None);
+        assert_eq!(decimal("1234").fit(5, 2), None);
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let lhs = decimal("10.25");
+        let rhs = decimal("0.1");
+
+        assert_eq!(lhs.checked_add(rhs), Some(decimal("10.35")));
+        assert_eq!(lhs.checked_sub(rhs).unwrap().to_string(), "10.15");
+        assert_eq!(lhs.checked_mul(rhs).unwrap().to_string(), "1.025");
+        assert_eq!(
+            lhs.checked_div(rhs, 2, Rounding::HalfUp),
+            Some(decimal("102.5"))
+        );
+        assert_eq!(
+            decimal("1").checked_div(decimal("3"), 4, Rounding::HalfUp),
+            Some(decimal("0.3333"))
+        );
+        assert_eq!(
+            decimal("-2").checked_div(decimal("3"), 2, Rounding::HalfUp),
+            Some(decimal("-0.67"))
+        );
+        assert_eq!(
+            decimal("1000").checked_div(decimal("0.001"), 0, Rounding::Down),
+            Some(decimal("1000000"))
+        );
+        assert_eq!(lhs.checked_div(decimal("0.00"), 2, Rounding::Down), None);
+
+        let max = Decimal::new(10i128.pow(38) - 1, 0).unwrap();
+        assert_eq!(max.checked_add(decimal("1")), None);
+    }
+
+    #[test]
+    fn test_ordering() {
+        assert_eq!(decimal("1.5"), decimal("1.50"));
+        assert!(decimal("-1.5") < decimal("-1.25"));
+        assert!(decimal("0.001") < decimal("0.01"));
+        assert!(decimal("-0.5") < decimal("0"));
+
+        let large = Decimal::new(10i128.pow(37), 0).unwrap();
+        let small = Decimal::new(1, MAX_PRECISION).unwrap();
+        assert!(small < large);
+        assert!(large.checked_neg().unwrap() < small);
+    }
+}
@@ -17,12 +17,23 @@ pub enum StorageData<const N: u16 = 255> {
     Float(Float),
     Double(Double),
     VarChar(VarChar<N>),
+    Text(Text),
+    Blob(Blob),
+    Date(Date),
+    Time(Time),
+    Timestamp(Timestamp),
+    Interval(Interval),
+    Decimal(Decimal),
+    Uuid(Uuid),
+    /// Represents a missing value of a nullable column.
+    Null,
 }
 
 impl<const N: u16> StorageData<N> {
-    /// Returns the data type of the storage data.
-    pub fn data_type(&self) -> StorageDataType {
-        match self {
+    /// Returns the data type of the storage data, or `None` for
+    /// [`StorageData::Null`].
+    pub fn data_type(&self) -> Option<StorageDataType> {
+        let data_type = match self {
             StorageData::Bool(_) => StorageDataType::Bool,
             StorageData::Byte(_) => StorageDataType::Byte,
             StorageData::Short(_) => StorageDataType::Short,
@@ -36,10 +47,102 @@ impl<const N: u16> StorageData<N> {
             StorageData::VarChar(value) => {
                 StorageDataType::VarChar(value.value.len())
             }
+            StorageData::Text(_) => StorageDataType::Text,
+            StorageData::Blob(_) => StorageDataType::Blob,
+            StorageData::Date(_) => StorageDataType::Date,
+            StorageData::Time(_) => StorageDataType::Time,
+            StorageData::Timestamp(_) => StorageDataType::Timestamp,
+            StorageData::Interval(_) => StorageDataType::Interval,
+            StorageData::Decimal(value) => {
+                StorageDataType::Decimal(value.precision(), value.scale())
+            }
+            StorageData::Uuid(_) => StorageDataType::Uuid,
+            StorageData::Null => return None,
+        };
+
+        Some(data_type)
+    }
+
+    /// Returns the value of integer data as `i128`.
+    /// # Returns
+    /// * `Option<i128>` - The value, or `None` if the data isn't an integer
+    ///   or doesn't fit into `i128`.
+    pub fn as_integer(&self) -> Option<i128> {
+        match self {
+            StorageData::Byte(value) => Some(value.0.into()),
+            StorageData::Short(value) => Some(value.0.into()),
+            StorageData::Integer(value) => Some(value.0.into()),
+            StorageData::Long(value) => Some(value.0),
+            StorageData::UShort(value) => Some(value.0.into()),
+            StorageData::UInteger(value) => Some(value.0.into()),
+            StorageData::ULong(value) => value.0.try_into().ok(),
+            _ => None,
         }
     }
 }
 
+impl<const N: u16> Display for StorageData<N> {
+    /// Formats the data as an SQL literal, e.g. `'text'` or
+    /// `DATE '2024-01-31'`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StorageData::Bool(value) => {
+                write!(f, "{}", if value.0 { "TRUE" } else { "FALSE" })
+            }
+            StorageData::Byte(value) => write!(f, "{}", value.0),
+            StorageData::Short(value) => write!(f, "{}", value.0),
+            StorageData::Integer(value) => write!(f, "{}", value.0),
+            StorageData::Long(value) => write!(f, "{}", value.0),
+            StorageData::UShort(value) => write!(f, "{}", value.0),
+            StorageData::UInteger(value) => write!(f, "{}", value.0),
+            StorageData::ULong(value) => write!(f, "{}", value.0),
+            StorageData::Float(value) => write!(f, "{}", value.0),
+            StorageData::Double(value) => write!(f, "{}", value.0),
+            StorageData::VarChar(value) => {
+                write!(f, "'{}'", value.value.replace('\'', "''"))
+            }
+            StorageData::Text(value) => {
+                write!(f, "'{}'", value.0.replace('\'', "''"))
+            }
+            StorageData::Blob(value) => write!(f, "x'{}'", value.to_hex()),
+            StorageData::Date(value) => write!(f, "DATE '{}'", value),
+            StorageData::Time(value) => write!(f, "TIME '{}'", value),
+            StorageData::Timestamp(value) => {
+                write!(f, "TIMESTAMP '{}'", value)
+            }
+            StorageData::Interval(value) => write!(f, "INTERVAL '{}'", value),
+            StorageData::Decimal(value) => write!(f, "{}", value),
+            StorageData::Uuid(value) => write!(f, "UUID '{}'", value),
+            StorageData::Null => write!(f, "NULL"),
+        }
+    }
+}
+
+macro_rules! impl_from_primitive {
+    ($($primitive:ty => $variant:ident),* $(,)?) => {
+        $(
+            impl<const N: u16> From<$primitive> for StorageData<N> {
+                fn from(value: $primitive) -> Self {
+                    StorageData::$variant($variant(value))
+                }
+            }
+        )*
+    };
+}
+
+impl_from_primitive!(
+    bool => Bool,
+    u8 => Byte,
+    i16 => Short,
+    i32 => Integer,
+    i128 => Long,
+    u16 => UShort,
+    u32 => UInteger,
+    u128 => ULong,
+    f32 => Float,
+    f64 => Double,
+);
+
 /// Represents a storage data type.
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd, From)]
 pub enum StorageDataType {
@@ -54,23 +157,141 @@ pub enum StorageDataType {
     Float,
     Double,
     VarChar(usize),
+    /// Text of any length, stored with its length.
+    Text,
+    /// Binary data of any length, stored with its length.
+    Blob,
+    /// Date, stored as a number of days since 1970-01-01.
+    Date,
+    /// Time of day, stored as a number of microseconds since midnight.
+    Time,
+    /// Timestamp, stored as a number of microseconds since 1970-01-01.
+    Timestamp,
+    /// Interval of months, days and microseconds.
+    Interval,
+    /// Decimal with the total number of digits and the number of digits
+    /// after the decimal point, stored as its unscaled value.
+    Decimal(u8, u8),
+    /// UUID, stored as its 16 bytes.
+    Uuid,
 }
 
 impl StorageDataType {
-    /// Returns the size of the data type.
+    /// Checks if the data type is an integer type.
+    /// # Returns
+    /// * `bool` - True for signed and unsigned integers, false otherwise.
+    pub fn is_integer(&self) -> bool {
+        matches!(
+            self,
+            StorageDataType::Byte
+                | StorageDataType::Short
+                | StorageDataType::Integer
+                | StorageDataType::Long
+                | StorageDataType::UShort
+                | StorageDataType::UInteger
+                | StorageDataType::ULong
+        )
+    }
+
+    /// Returns the largest value of the integer type that fits into an
+    /// `i128`.
+    /// # Returns
+    /// * `Option<i128>` - The largest value, or `None` if the data type
+    ///   isn't an integer type.
+    pub fn integer_max(&self) -> Option<i128> {
+        let max = match self {
+            StorageDataType::Byte => u8::MAX.into(),
+            StorageDataType::Short => i16::MAX.into(),
+            StorageDataType::Integer => i32::MAX.into(),
+            StorageDataType::UShort => u16::MAX.into(),
+            StorageDataType::UInteger => u32::MAX.into(),
+            StorageDataType::Long | StorageDataType::ULong => i128::MAX,
+            _ => return None,
+        };
+
+        Some(max)
+    }
+
+    /// Converts an integer into data of the integer type.
+    /// # Arguments
+    /// * `value` - The value to convert.
+    /// # Returns
+    /// * `Option<StorageData<N>>` - The data, or `None` if the data type
+    ///   isn't an integer type or the value is out of its range.
+    pub fn integer_value<const N: u16>(
+        &self,
+        value: i128,
+    ) -> Option<StorageData<N>> {
+        let data = match self {
+            StorageDataType::Byte => Byte(value.try_into().ok()?).into(),
+            StorageDataType::Short => Short(value.try_into().ok()?).into(),
+            StorageDataType::Integer => Integer(value.try_into().ok()?).into(),
+            StorageDataType::Long => Long(value).into(),
+            StorageDataType::UShort => UShort(value.try_into().ok()?).into(),
+            StorageDataType::UInteger => {
+                UInteger(value.try_into().ok()?).into()
+            }
+            StorageDataType::ULong => ULong(value.try_into().ok()?).into(),
+            _ => return None,
+        };
+
+        Some(data)
+    }
+
+    /// Returns the size of the data type. Sizes of [`StorageDataType::Text`]
+    /// and [`StorageDataType::Blob`] depend on the value, so the size of an
+    /// empty value is returned for them.
     pub fn size(&self) -> usize {
         match self {
             StorageDataType::Bool => std::mem::size_of::<bool>(),
             StorageDataType::Byte => std::mem::size_of::<u8>(),
             StorageDataType::Short => std::mem::size_of::<i16>(),
             StorageDataType::Integer => std::mem::size_of::<i32>(),
-            StorageDataType::Long => std::mem::size_of::<i64>(),
+            StorageDataType::Long => std::mem::size_of::<i128>(),
             StorageDataType::UShort => std::mem::size_of::<u16>(),
             StorageDataType::UInteger => std::mem::size_of::<u32>(),
-            StorageDataType::ULong => std::mem::size_of::<u64>(),
+            StorageDataType::ULong => std::mem::size_of::<u128>(),
             StorageDataType::Float => std::mem::size_of::<f32>(),
             StorageDataType::Double => std::mem::size_of::<f64>(),
             StorageDataType::VarChar(size) => *size,
+            StorageDataType::Text | StorageDataType::Blob => 1,
+            StorageDataType::Date => std::mem::size_of::<i32>(),
+            StorageDataType::Time | StorageDataType::Timestamp => {
+                std::mem::size_of::<i64>()
+            }
+            StorageDataType::Interval => {
+                2 * std::mem::size_of::<i32>() + std::mem::size_of::<i64>()
+            }
+            StorageDataType::Decimal(_, _) => std::mem::size_of::<i128>(),
+            StorageDataType::Uuid => std::mem::size_of::<Uuid>(),
+        }
+    }
+
+    /// Returns the zero value of the data type, e.g. `0` or an empty string.
+    pub fn zero_value<const N: u16>(&self) -> StorageData<N> {
+        match self {
+            StorageDataType::Bool => Bool(false).into(),
+            StorageDataType::Byte => Byte(0).into(),
+            StorageDataType::Short => Short(0).into(),
+            StorageDataType::Integer => Integer(0).into(),
+            StorageDataType::Long => Long(0).into(),
+            StorageDataType::UShort => UShort(0).into(),
+            StorageDataType::UInteger => UInteger(0).into(),
+            StorageDataType::ULong => ULong(0).into(),
+            StorageDataType::Float => Float(0.0).into(),
+            StorageDataType::Double => Double(0.0).into(),
+            StorageDataType::VarChar(_) => VarChar {
+                value: String::new(),
+            }
+            .into(),
+            StorageDataType::Text => Text(String::new()).into(),
+            StorageDataType::Blob => Blob(Vec::new()).into(),
+            StorageDataType::Date => Date(0).into(),
+            StorageDataType::Time => Time(0).into(),
+            StorageDataType::Timestamp => Timestamp(0).into(),
+            StorageDataType::Interval => Interval::default().into(),
+            StorageDataType::Decimal(_, scale) => Decimal::zero(*scale).into(),
+            StorageDataType::Uuid => Uuid::NIL.into(),
         }
     }
 }
@@ -78,7 +299,7 @@ impl StorageDataType {
 #[cfg(test)]
 mod tests {
     use crate::schema::r#type::{
-        data_types::VarChar,
+        data_types::{Decimal, VarChar},
         r#enum::{StorageData, StorageDataType},
     };
 
@@ -87,7 +308,71 @@ mod tests {
         let value = VarChar::<5>::new("hello".to_string()).unwrap();
         let storage_data = StorageData::VarChar(value);
 
-        assert_eq!(storage_data.data_type(), StorageDataType::VarChar(5));
+        assert_eq!(storage_data.data_type(), Some(StorageDataType::VarChar(5)));
+        assert_eq!(StorageData::<5>::Null.data_type(), None);
+    }
+
+    #[test]
+    fn test_integer_value() {
+        let data: StorageData =
+            StorageDataType::Short.integer_value(7).unwrap();
+        assert_eq!(data, StorageData::Short(7.into()));
+        assert_eq!(data.as_integer(), Some(7));
+
+        assert_eq!(StorageDataType::Byte.integer_value::<255>(256), None);
+        assert_eq!(StorageDataType::ULong.integer_value::<255>(-1), None);
+        assert_eq!(StorageDataType::Text.integer_value::<255>(1), None);
+        assert_eq!(StorageDataType::Byte.integer_max(), Some(255));
+        assert_eq!(StorageDataType::ULong.integer_max(), Some(i128::MAX));
+        assert_eq!(StorageDataType::Double.integer_max(), None);
+        assert!(!StorageDataType::Double.is_integer());
+
+        let data: StorageData = StorageData::ULong(u128::MAX.into());
+        assert_eq!(data.as_integer(), None);
+    }
+
+    #[test]
+    fn test_display() {
+        let value = VarChar::<8>::new("it's".to_string()).unwrap();
+        assert_eq!(StorageData::VarChar(value).to_string(), "'it''s'");
+        assert_eq!(StorageData::<8>::from(-5).to_string(), "-5");
+        assert_eq!(StorageData::<8>::from(true).to_string(), "TRUE");
+        assert_eq!(StorageData::<8>::Null.to_string(), "NULL");
+        assert_eq!(
+            StorageData::<8>::Decimal(Decimal::new(-1_050, 3).unwrap())
+                .to_string(),
+            "-1.050"
+        );
+    }
+
+    #[test]
+    fn test_data_type_decimal() {
+        let value = Decimal::new(-1_050, 3).unwrap();
+        let storage_data = StorageData::<5>::Decimal(value);
+
+        assert_eq!(
+            storage_data.data_type(),
+            Some(StorageDataType::Decimal(4, 3))
+        );
+        assert_eq!(StorageDataType::Decimal(10, 2).to_string(), "decimal_10_2");
+        assert_eq!("decimal_10_2".parse(), Ok(StorageDataType::Decimal(10, 2)));
+        assert_eq!(
+            StorageDataType::Decimal(10, 2).zero_value::<5>(),
+            StorageData::Decimal(Decimal::zero(2))
+        );
+    }
+
+    #[test]
+    fn test_from_primitive() {
+        assert_eq!(StorageData::<255>::from(5), StorageData::Integer(5.into()));
+        assert_eq!(
+            StorageData::<255>::from(true),
+            StorageData::Bool(true.into())
+        );
+        assert_eq!(
+            StorageData::<255>::from(1u128),
+            StorageData::ULong(1.into())
+        );
     }
 }
 
@@ -105,6 +390,16 @@ impl Display for StorageDataType {
             StorageDataType::Float => "f32".to_string(),
             StorageDataType::Double => "f64".to_string(),
             StorageDataType::VarChar(size) => format!("array_char_{}", size),
+            StorageDataType::Text => "text".to_string(),
+            StorageDataType::Blob => "blob".to_string(),
+            StorageDataType::Date => "date".to_string(),
+            StorageDataType::Time => "time".to_string(),
+            StorageDataType::Timestamp => "timestamp".to_string(),
+            StorageDataType::Interval => "interval".to_string(),
+            StorageDataType::Decimal(precision, scale) => {
+                format!("decimal_{}_{}", precision, scale)
+            }
+            StorageDataType::Uuid => "uuid".to_string(),
         };
         write!(f, "{}", str)
     }
@@ -2,7 +2,7 @@ use crate::schema::r#type::r#enum::{StorageData, StorageDataType};
 use derive_more::From;
 use serde_storage::{
     de::{
-        decoder::{single_item::SingleItemDecoder, Storable},
+        decoder::{single_item::SingleItemDecoder, Storable, StorageDecoder},
         Error,
     },
     ser::encoder::{output_descriptor::DescriptorBytes, OutputDescriptor},
@@ -10,8 +10,11 @@ use serde_storage::{
 use std::str::FromStr;
 
 pub mod data_types;
+pub mod decimal;
 pub mod r#enum;
 mod storage;
+pub mod temporal;
+pub mod uuid;
 
 impl FromStr for StorageDataType {
     type Err = ();
@@ -28,7 +31,21 @@ impl FromStr for StorageDataType {
             "u128" => Ok(StorageDataType::ULong),
             "f32" => Ok(StorageDataType::Float),
             "f64" => Ok(StorageDataType::Double),
+            "text" => Ok(StorageDataType::Text),
+            "blob" => Ok(StorageDataType::Blob),
+            "date" => Ok(StorageDataType::Date),
+            "time" => Ok(StorageDataType::Time),
+            "timestamp" => Ok(StorageDataType::Timestamp),
+            "interval" => Ok(StorageDataType::Interval),
+            "uuid" => Ok(StorageDataType::Uuid),
             _ => {
+                if let Some(params) = input.strip_prefix("decimal_") {
+                    let (precision, scale) = params.split_once('_').ok_or(())?;
+                    return Ok(StorageDataType::Decimal(
+                        precision.parse().map_err(|_| ())?,
+                        scale.parse().map_err(|_| ())?,
+                    ));
+                }
                 if input.starts_with("array_char_") {
                     let size = input
                         .trim_start_matches("array_char_")
@@ -120,16 +137,15 @@ impl Storable<Self> for DataRow {
             .try_into()
             .map_err(|_| Error::NotDeserializable)?;
 
-        let mut data_vec = Vec::new();
-        let mut i = 0;
-
-        for (bytes, type_) in descriptor.get_descriptors() {
-            let type_ = StorageDataType::from_str(type_.as_str())
-                .map_err(|_| Error::NotDeserializable)?;
-            let part_len = type_.size();
-            let part = value[i..i + part_len].to_vec();
-            i += part_len;
+        let descriptions = descriptor
+            .get_descriptors()
+            .into_iter()
+            .map(|(bytes, _)| bytes)
+            .collect();
+        let parts = StorageDecoder::split_value(value, descriptions)?;
 
+        let mut data_vec = Vec::new();
+        for (part, bytes) in parts {
             let decoder = SingleItemDecoder {
                 decoder: decoder.decoder,
             };
@@ -184,4 +200,29 @@ mod test_decode_data_row {
             )
         );
     }
+
+    #[test]
+    fn test_compact_data_row() {
+        let value: Vec<Box<dyn Storable>> =
+            vec![Box::new(1u32), Box::new(-5i128), Box::new("Hi".to_string())];
+
+        let mut encoder = StorageEncoder::compact();
+        encoder.emit_struct(value).unwrap();
+        let bytes = encoder.output.get_bytes();
+        let descriptor = encoder.descriptor.get_descriptor_bytes();
+        assert_eq!(bytes.len(), 4);
+
+        let mut decoder = StorageDecoder;
+        let data = decoder
+            .emit_with_descriptor::<DataRow>(bytes, descriptor)
+            .unwrap()
+            .0;
+
+        assert_eq!(data[0], StorageData::UInteger(1.into()));
+        assert_eq!(data[1], StorageData::Long((-5).into()));
+        assert_eq!(
+            data[2],
+            StorageData::VarChar(VarChar::new("Hi".to_string()).unwrap())
+        );
+    }
 }
@@ -3,7 +3,9 @@ use serde_storage::{
         self,
         decoder::{self, single_item::SingleItemDecoder},
     },
-    descriptor::backwards::get_type_by_description_bytes,
+    descriptor::backwards::{
+        get_name_by_description_bytes, get_type_by_description_bytes,
+    },
     ser::{
         self,
         encoder::{self, single_item::SingleItemEncoder},
@@ -35,6 +37,54 @@ impl<const N: u16> encoder::Storable for VarChar<N> {
     }
 }
 
+impl encoder::Storable for Text {
+    fn encode(&self, encoder: SingleItemEncoder) -> Result<(), ser::Error> {
+        encoder.emit_text(&self.0)
+    }
+}
+
+impl encoder::Storable for Blob {
+    fn encode(&self, encoder: SingleItemEncoder) -> Result<(), ser::Error> {
+        encoder.emit_blob(&self.0)
+    }
+}
+
+impl encoder::Storable for Date {
+    fn encode(&self, encoder: SingleItemEncoder) -> Result<(), ser::Error> {
+        encoder.emit_date(self.0)
+    }
+}
+
+impl encoder::Storable for Time {
+    fn encode(&self, encoder: SingleItemEncoder) -> Result<(), ser::Error> {
+        encoder.emit_time(self.0)
+    }
+}
+
+impl encoder::Storable for Timestamp {
+    fn encode(&self, encoder: SingleItemEncoder) -> Result<(), ser::Error> {
+        encoder.emit_timestamp(self.0)
+    }
+}
+
+impl encoder::Storable for Interval {
+    fn encode(&self, encoder: SingleItemEncoder) -> Result<(), ser::Error> {
+        encoder.emit_interval(self.months, self.days, self.micros)
+    }
+}
+
+impl encoder::Storable for Decimal {
+    fn encode(&self, encoder: SingleItemEncoder) -> Result<(), ser::Error> {
+        encoder.emit_decimal(self.value, self.precision(), self.scale)
+    }
+}
+
+impl encoder::Storable for Uuid {
+    fn encode(&self, encoder: SingleItemEncoder) -> Result<(), ser::Error> {
+        encoder.emit_uuid(self.0)
+    }
+}
+
 impl decoder::Storable<Self> for Byte {
     fn decode(
         decoder: SingleItemDecoder,
@@ -267,3 +317,180 @@ impl<const N: u16> decoder::Storable<Self> for VarChar<N> {
         VarChar::try_from(str).map_err(|_| de::Error::InvalidLength)
     }
 }
+
+impl decoder::Storable<Self> for Text {
+    fn decode(
+        decoder: SingleItemDecoder,
+        value: Vec<u8>,
+    ) -> Result<Self, de::Error> {
+        decoder.emit_text(value).map(Text)
+    }
+
+    fn decode_with_descriptor(
+        decoder: SingleItemDecoder,
+        value: Vec<u8>,
+        descriptor: Vec<u8>,
+    ) -> Result<Self, de::Error> {
+        if get_type_by_description_bytes(descriptor.as_slice()) != "text" {
+            return Err(de::Error::InvalidType);
+        }
+
+        decoder.emit_text(value).map(Text)
+    }
+}
+
+impl decoder::Storable<Self> for Blob {
+    fn decode(
+        decoder: SingleItemDecoder,
+        value: Vec<u8>,
+    ) -> Result<Self, de::Error> {
+        decoder.emit_blob(value).map(Blob)
+    }
+
+    fn decode_with_descriptor(
+        decoder: SingleItemDecoder,
+        value: Vec<u8>,
+        descriptor: Vec<u8>,
+    ) -> Result<Self, de::Error> {
+        if get_type_by_description_bytes(descriptor.as_slice()) != "blob" {
+            return Err(de::Error::InvalidType);
+        }
+
+        decoder.emit_blob(value).map(Blob)
+    }
+}
+
+impl decoder::Storable<Self> for Date {
+    fn decode(
+        decoder: SingleItemDecoder,
+        value: Vec<u8>,
+    ) -> Result<Self, de::Error> {
+        decoder.emit_date(value).map(Date)
+    }
+
+    fn decode_with_descriptor(
+        decoder: SingleItemDecoder,
+        value: Vec<u8>,
+        descriptor: Vec<u8>,
+    ) -> Result<Self, de::Error> {
+        if get_type_by_description_bytes(descriptor.as_slice()) != "date" {
+            return Err(de::Error::InvalidType);
+        }
+
+        decoder.emit_date(value).map(Date)
+    }
+}
+
+impl decoder::Storable<Self> for Time {
+    fn decode(
+        decoder: SingleItemDecoder,
+        value: Vec<u8>,
+    ) -> Result<Self, de::Error> {
+        decoder.emit_time(value).map(Time)
+    }
+
+    fn decode_with_descriptor(
+        decoder: SingleItemDecoder,
+        value: Vec<u8>,
+        descriptor: Vec<u8>,
+    ) -> Result<Self, de::Error> {
+        if get_type_by_description_bytes(descriptor.as_slice()) != "time" {
+            return Err(de::Error::InvalidType);
+        }
+
+        decoder.emit_time(value).map(Time)
+    }
+}
+
+impl decoder::Storable<Self> for Timestamp {
+    fn decode(
+        decoder: SingleItemDecoder,
+        value: Vec<u8>,
+    ) -> Result<Self, de::Error> {
+        decoder.emit_timestamp(value).map(Timestamp)
+    }
+
+    fn decode_with_descriptor(
+        decoder: SingleItemDecoder,
+        value: Vec<u8>,
+        descriptor: Vec<u8>,
+    ) -> Result<Self, de::Error> {
+        if get_type_by_description_bytes(descriptor.as_slice()) != "timestamp" {
+            return Err(de::Error::InvalidType);
+        }
+
+        decoder.emit_timestamp(value).map(Timestamp)
+    }
+}
+
+impl decoder::Storable<Self> for Interval {
+    fn decode(
+        decoder: SingleItemDecoder,
+        value: Vec<u8>,
+    ) -> Result<Self, de::Error> {
+        let (months, days, micros) = decoder.emit_interval(value)?;
+        Ok(Interval {
+            months,
+            days,
+            micros,
+        })
+    }
+
+    fn decode_with_descriptor(
+        decoder: SingleItemDecoder,
+        value: Vec<u8>,
+        descriptor: Vec<u8>,
+    ) -> Result<Self, de::Error> {
+        if get_type_by_description_bytes(descriptor.as_slice()) != "interval" {
+            return Err(de::Error::InvalidType);
+        }
+
+        Self::decode(decoder, value)
+    }
+}
+
+impl decoder::Storable<Self> for Uuid {
+    fn decode(
+        decoder: SingleItemDecoder,
+        value: Vec<u8>,
+    ) -> Result<Self, de::Error> {
+        decoder.emit_uuid(value).map(Uuid)
+    }
+
+    fn decode_with_descriptor(
+        decoder: SingleItemDecoder,
+        value: Vec<u8>,
+        descriptor: Vec<u8>,
+    ) -> Result<Self, de::Error> {
+        if get_type_by_description_bytes(descriptor.as_slice()) != "uuid" {
+            return Err(de::Error::InvalidType);
+        }
+
+        Self::decode(decoder, value)
+    }
+}
+
+impl decoder::Storable<Self> for Decimal {
+    /// Decimals can only be decoded with their descriptor, that holds their
+    /// scale.
+    fn decode(_: SingleItemDecoder, _: Vec<u8>) -> Result<Self, de::Error> {
+        Err(de::Error::NotDeserializable)
+    }
+
+    fn decode_with_descriptor(
+        decoder: SingleItemDecoder,
+        value: Vec<u8>,
+        descriptor: Vec<u8>,
+    ) -> Result<Self, de::Error> {
+        let name = get_name_by_description_bytes(descriptor.as_slice())
+            .ok_or(de::Error::InvalidType)?;
+        let scale = name
+            .strip_prefix("decimal_")
+            .and_then(|params| params.split_once('_'))
+            .and_then(|(_, scale)| scale.parse().ok())
+            .ok_or(de::Error::InvalidType)?;
+
+        Decimal::new(decoder.emit_decimal(value)?, scale)
+            .ok_or(de::Error::InvalidValue)
+    }
+}
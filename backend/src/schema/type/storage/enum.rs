@@ -2,8 +2,9 @@ use crate::{
     data::row_type::RowType,
     schema::r#type::{
         data_types::{
-            Bool, Byte, Double, Float, Integer, Long, Short, UInteger, ULong,
-            UShort, VarChar,
+            Blob, Bool, Byte, Date, Decimal, Double, Float, Integer, Interval,
+            Long, Short, Text, Time, Timestamp, UInteger, ULong, UShort, Uuid,
+            VarChar,
         },
         r#enum::{StorageData, StorageDataType},
     },
@@ -11,6 +12,7 @@ use crate::{
 
 use serde_storage::{
     de::{decoder, decoder::single_item::SingleItemDecoder, Error},
+    descriptor::backwards::is_varint_by_description_bytes,
     ser::{
         encoder,
         encoder::{
@@ -37,6 +39,15 @@ impl<const N: u16> encoder::Storable for StorageData<N> {
             StorageData::Float(value) => encoder.emit(value.0),
             StorageData::Double(value) => encoder.emit(value.0),
             StorageData::VarChar(value) => encoder.emit_str(&value.value),
+            StorageData::Text(value) => encoder.emit_text(&value.0),
+            StorageData::Blob(value) => encoder.emit_blob(&value.0),
+            StorageData::Date(value) => encoder.emit(*value),
+            StorageData::Time(value) => encoder.emit(*value),
+            StorageData::Timestamp(value) => encoder.emit(*value),
+            StorageData::Interval(value) => encoder.emit(*value),
+            StorageData::Decimal(value) => encoder.emit(*value),
+            StorageData::Uuid(value) => encoder.emit(*value),
+            StorageData::Null => Err(serde_storage::ser::Error::NoValueWritten),
         }
     }
 }
@@ -51,6 +62,7 @@ impl<const N: u16> decoder::Storable<Self> for StorageData<N> {
         value: Vec<u8>,
         descriptor: Vec<u8>,
     ) -> Result<Self, Error> {
+        let is_varint = is_varint_by_description_bytes(&descriptor);
         let descriptor_bytes = DescriptorBytes(descriptor);
         let descriptor: OutputDescriptor = descriptor_bytes
             .try_into()
@@ -65,6 +77,10 @@ impl<const N: u16> decoder::Storable<Self> for StorageData<N> {
         }
 
         let data_type = types.first().expect("Existing data type");
+        if is_varint {
+            return decode_varint(decoder, value, data_type);
+        }
+
         match data_type {
             StorageDataType::Bool => Ok(decoder.emit::<Bool>(value)?.into()),
             StorageDataType::Byte => Ok(decoder.emit::<Byte>(value)?.into()),
@@ -87,6 +103,51 @@ impl<const N: u16> decoder::Storable<Self> for StorageData<N> {
             StorageDataType::VarChar(_) => {
                 Ok(decoder.emit::<VarChar<N>>(value)?.into())
             }
+            StorageDataType::Text => Ok(decoder.emit::<Text>(value)?.into()),
+            StorageDataType::Blob => Ok(decoder.emit::<Blob>(value)?.into()),
+            StorageDataType::Date => Ok(decoder.emit::<Date>(value)?.into()),
+            StorageDataType::Time => Ok(decoder.emit::<Time>(value)?.into()),
+            StorageDataType::Timestamp => {
+                Ok(decoder.emit::<Timestamp>(value)?.into())
+            }
+            StorageDataType::Interval => {
+                Ok(decoder.emit::<Interval>(value)?.into())
+            }
+            StorageDataType::Uuid => Ok(decoder.emit::<Uuid>(value)?.into()),
+            StorageDataType::Decimal(_, scale) => {
+                let value = decoder.emit_decimal(value)?;
+                Decimal::new(value, *scale)
+                    .map(StorageData::Decimal)
+                    .ok_or(Error::InvalidValue)
+            }
+        }
+    }
+}
+
+/// Decodes an integer of a row encoded in compact mode, where integers wider
+/// than a byte are encoded as varints.
+fn decode_varint<const N: u16>(
+    decoder: SingleItemDecoder,
+    value: Vec<u8>,
+    data_type: &StorageDataType,
+) -> Result<StorageData<N>, Error> {
+    match data_type {
+        StorageDataType::Short => {
+            Ok(Short(decoder.emit_signed_varint(value)?).into())
+        }
+        StorageDataType::Integer => {
+            Ok(Integer(decoder.emit_signed_varint(value)?).into())
+        }
+        StorageDataType::Long => {
+            Ok(Long(decoder.emit_signed_varint(value)?).into())
+        }
+        StorageDataType::UShort => {
+            Ok(UShort(decoder.emit_varint(value)?).into())
+        }
+        StorageDataType::UInteger => {
+            Ok(UInteger(decoder.emit_varint(value)?).into())
         }
+        StorageDataType::ULong => Ok(ULong(decoder.emit_varint(value)?).into()),
+        _ => Err(Error::InvalidType),
     }
 }
@@ -220,4 +220,23 @@ mod tests {
         assert!(res.is_ok());
         assert_eq!(res.unwrap(), value);
     }
+
+    #[test]
+    fn test_uuid() {
+        let value = Uuid([7; 16]);
+
+        let mut encoder = StorageEncoder::new();
+
+        let res = encoder.emit(value);
+        assert!(res.is_ok());
+
+        let bytes = encoder.output.get_bytes();
+        assert_eq!(bytes, vec![7; 16]);
+
+        let mut decoder = StorageDecoder;
+
+        let res = decoder.emit::<Uuid>(bytes);
+        assert!(res.is_ok());
+        assert_eq!(res.unwrap(), value);
+    }
 }
@@ -0,0 +1,548 @@
+//! Parsing, formatting and arithmetic of the temporal data types. Values are
+//! parsed from and formatted as ISO-8601, intervals as durations like
+//! `P1Y2M3DT4H5M6.5S`.
+
+use std::{fmt::Display, str::FromStr};
+
+use chrono::{
+    DateTime, Datelike as _, Months, NaiveDate, NaiveDateTime, NaiveTime,
+    TimeDelta, Timelike as _, Utc,
+};
+
+use crate::schema::r#type::data_types::{Date, Interval, Time, Timestamp};
+
+/// Number of microseconds in a second.
+const MICROS_PER_SECOND: i64 = 1_000_000;
+
+/// Number of microseconds in a minute.
+const MICROS_PER_MINUTE: i64 = 60 * MICROS_PER_SECOND;
+
+/// Number of microseconds in an hour.
+const MICROS_PER_HOUR: i64 = 60 * MICROS_PER_MINUTE;
+
+/// Number of microseconds in a day.
+pub const MICROS_PER_DAY: i64 = 24 * MICROS_PER_HOUR;
+
+/// Number of days from 0001-01-01 to 1970-01-01.
+const UNIX_EPOCH_DAYS_FROM_CE: i32 = 719_163;
+
+/// Format of a date.
+const DATE_FORMAT: &str = "%Y-%m-%d";
+
+/// Format of a time of day, fractions of a second are optional.
+const TIME_FORMAT: &str = "%H:%M:%S%.f";
+
+impl Date {
+    /// Returns the current date in UTC.
+    pub fn today() -> Self {
+        Self::from_naive(Utc::now().date_naive())
+    }
+
+    /// Adds the interval to the midnight of the date.
+    /// # Returns
+    /// * `Some(Timestamp)` - The resulting timestamp.
+    /// * `None` - If the result is out of range.
+    pub fn checked_add(self, interval: Interval) -> Option<Timestamp> {
+        Timestamp::try_from(self).ok()?.checked_add(interval)
+    }
+
+    /// Subtracts the interval from the midnight of the date.
+    /// # Returns
+    /// * `Some(Timestamp)` - The resulting timestamp.
+    /// * `None` - If the result is out of range.
+    pub fn checked_sub(self, interval: Interval) -> Option<Timestamp> {
+        self.checked_add(interval.checked_neg()?)
+    }
+
+    /// Returns the interval of days from the other date to this one.
+    pub fn since(self, other: Date) -> Option<Interval> {
+        Some(Interval {
+            days: self.0.checked_sub(other.0)?,
+            ..Default::default()
+        })
+    }
+
+    fn from_naive(date: NaiveDate) -> Self {
+        Date(date.num_days_from_ce() - UNIX_EPOCH_DAYS_FROM_CE)
+    }
+
+    fn to_naive(self) -> Option<NaiveDate> {
+        let days = self.0.checked_add(UNIX_EPOCH_DAYS_FROM_CE)?;
+        NaiveDate::from_num_days_from_ce_opt(days)
+    }
+}
+
+impl FromStr for Date {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        NaiveDate::parse_from_str(s, DATE_FORMAT)
+            .map(Self::from_naive)
+            .map_err(|_| ())
+    }
+}
+
+impl Display for Date {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.to_naive() {
+            Some(date) => write!(f, "{}", date.format(DATE_FORMAT)),
+            None => write!(f, "invalid date ({} days)", self.0),
+        }
+    }
+}
+
+impl Time {
+    /// Returns the current time of day in UTC.
+    pub fn now() -> Self {
+        Self::from_naive(Utc::now().time())
+    }
+
+    /// Adds the time part of the interval to the time, wrapping around
+    /// midnight. Months and days of the interval are ignored.
+    pub fn wrapping_add(self, interval: Interval) -> Self {
+        let micros = self.0.rem_euclid(MICROS_PER_DAY)
+            + interval.micros % MICROS_PER_DAY;
+        Time(micros.rem_euclid(MICROS_PER_DAY))
+    }
+
+    /// Subtracts the time part of the interval from the time, wrapping around
+    /// midnight. Months and days of the interval are ignored.
+    pub fn wrapping_sub(self, interval: Interval) -> Self {
+        let micros = self.0.rem_euclid(MICROS_PER_DAY)
+            - interval.micros % MICROS_PER_DAY;
+        Time(micros.rem_euclid(MICROS_PER_DAY))
+    }
+
+    /// Returns the interval from the other time to this one.
+    pub fn since(self, other: Time) -> Option<Interval> {
+        Some(Interval {
+            micros: self.0.checked_sub(other.0)?,
+            ..Default::default()
+        })
+    }
+
+    fn from_naive(time: NaiveTime) -> Self {
+        // Leap seconds are represented by nanoseconds over a second.
+        let micros = i64::from(time.nanosecond() / 1_000).min(999_999);
+        Time(
+            i64::from(time.num_seconds_from_midnight()) * MICROS_PER_SECOND
+                + micros,
+        )
+    }
+
+    fn to_naive(self) -> Option<NaiveTime> {
+        if !(0..MICROS_PER_DAY).contains(&self.0) {
+            return None;
+        }
+
+        let seconds = (self.0 / MICROS_PER_SECOND) as u32;
+        let nanos = (self.0 % MICROS_PER_SECOND) as u32 * 1_000;
+        NaiveTime::from_num_seconds_from_midnight_opt(seconds, nanos)
+    }
+}
+
+impl FromStr for Time {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        NaiveTime::parse_from_str(s, TIME_FORMAT)
+            .map(Self::from_naive)
+            .map_err(|_| ())
+    }
+}
+
+impl Display for Time {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.to_naive() {
+            Some(time) => write!(f, "{}", time.format(TIME_FORMAT)),
+            None => write!(f, "invalid time ({} us)", self.0),
+        }
+    }
+}
+
+impl Timestamp {
+    /// Returns the current timestamp.
+    pub fn now() -> Self {
+        Timestamp(Utc::now().timestamp_micros())
+    }
+
+    /// Adds the interval to the timestamp. Months are added first, keeping
+    /// the day of the month if it exists, then days and microseconds.
+    /// # Returns
+    /// * `Some(Timestamp)` - The resulting timestamp.
+    /// * `None` - If the result is out of range.
+    pub fn checked_add(self, interval: Interval) -> Option<Timestamp> {
+        let months = Months::new(interval.months.unsigned_abs());
+        let date_time = if interval.months >= 0 {
+            self.to_naive()?.checked_add_months(months)?
+        } else {
+            self.to_naive()?.checked_sub_months(months)?
+        };
+        let date_time = date_time
+            .checked_add_signed(TimeDelta::days(i64::from(interval.days)))?;
+
+        let micros = date_time.and_utc().timestamp_micros();
+        micros.checked_add(interval.micros).map(Timestamp)
+    }
+
+    /// Subtracts the interval from the timestamp.
+    /// # Returns
+    /// * `Some(Timestamp)` - The resulting timestamp.
+    /// * `None` - If the result is out of range.
+    pub fn checked_sub(self, interval: Interval) -> Option<Timestamp> {
+        self.checked_add(interval.checked_neg()?)
+    }
+
+    /// Returns the interval from the other timestamp to this one, in days
+    /// and microseconds.
+    pub fn since(self, other: Timestamp) -> Option<Interval> {
+        let micros = self.0.checked_sub(other.0)?;
+        Some(Interval {
+            months: 0,
+            days: i32::try_from(micros / MICROS_PER_DAY).ok()?,
+            micros: micros % MICROS_PER_DAY,
+        })
+    }
+
+    fn to_naive(self) -> Option<NaiveDateTime> {
+        DateTime::from_timestamp_micros(self.0).map(|value| value.naive_utc())
+    }
+}
+
+impl TryFrom<Date> for Timestamp {
+    type Error = ();
+
+    fn try_from(date: Date) -> Result<Self, Self::Error> {
+        i64::from(date.0)
+            .checked_mul(MICROS_PER_DAY)
+            .map(Timestamp)
+            .ok_or(())
+    }
+}
+
+impl FromStr for Timestamp {
+    type Err = ();
+
+    /// Parses a timestamp like `2024-01-31T10:00:00.5Z`. The date and the
+    /// time may be separated by a space, the time may be omitted.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.strip_suffix(['Z', 'z']).unwrap_or(s);
+        let Some((date, time)) = s.split_once(['T', 't', ' ']) else {
+            return Date::from_str(s).and_then(Timestamp::try_from);
+        };
+
+        let date = NaiveDate::parse_from_str(date, DATE_FORMAT);
+        let time = NaiveTime::parse_from_str(time, TIME_FORMAT);
+        match (date, time) {
+            (Ok(date), Ok(time)) => {
+                Ok(Timestamp(date.and_time(time).and_utc().timestamp_micros()))
+            }
+            _ => Err(()),
+        }
+    }
+}
+
+impl Display for Timestamp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let format = format!("{} {}", DATE_FORMAT, TIME_FORMAT);
+        match self.to_naive() {
+            Some(value) => write!(f, "{}", value.format(&format)),
+            None => write!(f, "invalid timestamp ({} us)", self.0),
+        }
+    }
+}
+
+impl Interval {
+    /// Adds the intervals field by field.
+    pub fn checked_add(self, other: Interval) -> Option<Interval> {
+        Some(Interval {
+            months: self.months.checked_add(other.months)?,
+            days: self.days.checked_add(other.days)?,
+            micros: self.micros.checked_add(other.micros)?,
+        })
+    }
+
+    /// Subtracts the intervals field by field.
+    pub fn checked_sub(self, other: Interval) -> Option<Interval> {
+        self.checked_add(other.checked_neg()?)
+    }
+
+    /// Negates every field of the interval.
+    pub fn checked_neg(self) -> Option<Interval> {
+        Some(Interval {
+            months: self.months.checked_neg()?,
+            days: self.days.checked_neg()?,
+            micros: self.micros.checked_neg()?,
+        })
+    }
+}
+
+/// Splits a part of an ISO-8601 duration into numbers and their units, e.g.
+/// `1Y-2M` into `("1", 'Y')` and `("-2", 'M')`.
+fn split_duration(part: &str) -> Option<Vec<(&str, char)>> {
+    let mut components = Vec::new();
+    let mut start = 0;
+    for (i, ch) in part.char_indices() {
+        if ch.is_ascii_alphabetic() {
+            if i == start {
+                return None;
+            }
+            components.push((&part[start..i], ch.to_ascii_uppercase()));
+            start = i + 1;
+        }
+    }
+
+    (start == part.len()).then_some(components)
+}
+
+/// Parses seconds with an optional fraction into microseconds.
+fn parse_seconds(value: &str) -> Option<i64> {
+    let (seconds, fraction) = value.split_once('.').unwrap_or((value, ""));
+    if fraction.len() > 6 || !fraction.chars().all(|ch| ch.is_ascii_digit()) {
+        return None;
+    }
+
+    let micros = format!("{:0<6}", fraction).parse::<i64>().ok()?;
+    let seconds = seconds.parse::<i64>().ok()?;
+    let micros = seconds
+        .unsigned_abs()
+        .checked_mul(MICROS_PER_SECOND as u64)?
+        .checked_add(micros as u64)?;
+    let micros = i64::try_from(micros).ok()?;
+
+    Some(if value.starts_with('-') {
+        -micros
+    } else {
+        micros
+    })
+}
+
+impl FromStr for Interval {
+    type Err = ();
+
+    /// Parses an ISO-8601 duration like `P1Y2M3DT4H5M6.5S` or `-P1W`.
+    /// Components may be negative, as in PostgreSQL.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, s) = match s.strip_prefix('-') {
+            Some(s) => (true, s),
+            None => (false, s),
+        };
+        let s = s.strip_prefix(['P', 'p']).ok_or(())?;
+        let (date, time) = match s.split_once(['T', 't']) {
+            Some((date, time)) if !time.is_empty() => (date, time),
+            Some(_) => return Err(()),
+            None if !s.is_empty() => (s, ""),
+            None => return Err(()),
+        };
+
+        let mut months = 0i64;
+        let mut days = 0i64;
+        let mut micros = 0i64;
+        for (value, unit) in split_duration(date).ok_or(())? {
+            let value = value.parse::<i64>().map_err(|_| ())?;
+            let (total, factor) = match unit {
+                'Y' => (&mut months, 12),
+                'M' => (&mut months, 1),
+                'W' => (&mut days, 7),
+                'D' => (&mut days, 1),
+                _ => return Err(()),
+            };
+            *total = value
+                .checked_mul(factor)
+                .and_then(|value| total.checked_add(value))
+                .ok_or(())?;
+        }
+        for (value, unit) in split_duration(time).ok_or(())? {
+            let value = match unit {
+                'H' => value
+                    .parse::<i64>()
+                    .ok()
+                    .and_then(|value| value.checked_mul(MICROS_PER_HOUR)),
+                'M' => value
+                    .parse::<i64>()
+                    .ok()
+                    .and_then(|value| value.checked_mul(MICROS_PER_MINUTE)),
+                'S' => parse_seconds(value),
+                _ => None,
+            };
+            micros = value
+                .and_then(|value| micros.checked_add(value))
+                .ok_or(())?;
+        }
+
+        let interval = Interval {
+            months: i32::try_from(months).map_err(|_| ())?,
+            days: i32::try_from(days).map_err(|_| ())?,
+            micros,
+        };
+        if negative {
+            interval.checked_neg().ok_or(())
+        } else {
+            Ok(interval)
+        }
+    }
+}
+
+impl Display for Interval {
+    /// Formats the interval as an ISO-8601 duration, e.g. `P1Y2M3DT4H5M6.5S`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if *self == Interval::default() {
+            return write!(f, "PT0S");
+        }
+
+        write!(f, "P")?;
+        let date = [
+            (i64::from(self.months / 12), 'Y'),
+            (i64::from(self.months % 12), 'M'),
+            (i64::from(self.days), 'D'),
+        ];
+        for (value, unit) in date {
+            if value != 0 {
+                write!(f, "{}{}", value, unit)?;
+            }
+        }
+
+        if self.micros == 0 {
+            return Ok(());
+        }
+        write!(f, "T")?;
+        let time = [
+            (self.micros / MICROS_PER_HOUR, 'H'),
+            (self.micros % MICROS_PER_HOUR / MICROS_PER_MINUTE, 'M'),
+        ];
+        for (value, unit) in time {
+            if value != 0 {
+                write!(f, "{}{}", value, unit)?;
+            }
+        }
+
+        let seconds = self.micros % MICROS_PER_MINUTE;
+        if seconds == 0 {
+            return Ok(());
+        }
+        let sign = if seconds < 0 { "-" } else { "" };
+        let fraction = seconds.abs() % MICROS_PER_SECOND;
+        write!(f, "{}{}", sign, seconds.abs() / MICROS_PER_SECOND)?;
+        if fraction != 0 {
+            let fraction = format!("{:06}", fraction);
+            write!(f, ".{}", fraction.trim_end_matches('0'))?;
+        }
+        write!(f, "S")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::schema::r#type::{
+        data_types::{Date, Interval, Time, Timestamp},
+        temporal::MICROS_PER_DAY,
+    };
+
+    fn interval(months: i32, days: i32, micros: i64) -> Interval {
+        Interval {
+            months,
+            days,
+            micros,
+        }
+    }
+
+    #[test]
+    fn test_date() {
+        let date: Date = "2024-02-29".parse().unwrap();
+
+        assert_eq!(date, Date(19_782));
+        assert_eq!(date.to_string(), "2024-02-29");
+        assert_eq!("1969-12-31".parse(), Ok(Date(-1)));
+        assert_eq!("2023-02-29".parse::<Date>(), Err(()));
+        assert_eq!("2024-02-29T10:00".parse::<Date>(), Err(()));
+    }
+
+    #[test]
+    fn test_time() {
+        let time: Time = "10:30:00.25".parse().unwrap();
+
+        assert_eq!(time, Time(37_800_250_000));
+        assert_eq!(time.to_string(), "10:30:00.250");
+        assert_eq!("00:00:01".parse(), Ok(Time(1_000_000)));
+        assert_eq!(Time(1_000_000).to_string(), "00:00:01");
+        assert_eq!("24:00:00".parse::<Time>(), Err(()));
+        assert_eq!(
+            Time(MICROS_PER_DAY).to_string(),
+            "invalid time (86400000000 us)"
+        );
+    }
+
+    #[test]
+    fn test_timestamp() {
+        let expected = Timestamp(1_706_695_200_500_000);
+
+        assert_eq!("2024-01-31T10:00:00.5Z".parse(), Ok(expected));
+        assert_eq!("2024-01-31 10:00:00.5".parse(), Ok(expected));
+        assert_eq!(expected.to_string(), "2024-01-31 10:00:00.500");
+        assert_eq!("2024-01-31".parse(), Ok(Timestamp(1_706_659_200_000_000)));
+        assert_eq!("2024-01-31T25:00:00".parse::<Timestamp>(), Err(()));
+    }
+
+    #[test]
+    fn test_interval() {
+        let value: Interval = "P1Y2M3DT4H5M6.5S".parse().unwrap();
+
+        assert_eq!(value, interval(14, 3, 14_706_500_000));
+        assert_eq!(value.to_string(), "P1Y2M3DT4H5M6.5S");
+        assert_eq!("P2W".parse(), Ok(interval(0, 14, 0)));
+        assert_eq!("-P1DT1H".parse(), Ok(interval(0, -1, -3_600_000_000)));
+        assert_eq!("PT-0.5S".parse(), Ok(interval(0, 0, -500_000)));
+        assert_eq!(interval(0, 0, -500_000).to_string(), "PT-0.5S");
+        assert_eq!(Interval::default().to_string(), "PT0S");
+
+        assert_eq!("P".parse::<Interval>(), Err(()));
+        assert_eq!("P1DT".parse::<Interval>(), Err(()));
+        assert_eq!("P1H".parse::<Interval>(), Err(()));
+        assert_eq!("PT1.5M".parse::<Interval>(), Err(()));
+        assert_eq!("1D".parse::<Interval>(), Err(()));
+    }
+
+    #[test]
+    fn test_timestamp_arithmetic() {
+        let start: Timestamp = "2024-01-31T10:00:00".parse().unwrap();
+
+        let end = start.checked_add(interval(1, 1, 3_600_000_000)).unwrap();
+        assert_eq!(end.to_string(), "2024-03-01 11:00:00");
+        assert_eq!(
+            end.checked_sub(interval(0, 1, 0)).unwrap().to_string(),
+            "2024-02-29 11:00:00"
+        );
+        assert_eq!(end.since(start), Some(interval(0, 30, 3_600_000_000)));
+        assert_eq!(Timestamp(i64::MAX).checked_add(interval(0, 0, 1)), None);
+    }
+
+    #[test]
+    fn test_date_and_time_arithmetic() {
+        let date: Date = "2024-02-29".parse().unwrap();
+
+        assert_eq!(
+            date.checked_add(interval(12, 0, 0)).unwrap().to_string(),
+            "2025-02-28 00:00:00"
+        );
+        assert_eq!(
+            date.checked_sub(interval(0, 0, 1)).unwrap().to_string(),
+            "2024-02-28 23:59:59.999999"
+        );
+        assert_eq!(date.since(Date(0)), Some(interval(0, 19_782, 0)));
+
+        let time: Time = "23:00:00".parse().unwrap();
+        let hours = |hours: i64| interval(1, 1, hours * 3_600_000_000);
+        assert_eq!(time.wrapping_add(hours(2)).to_string(), "01:00:00");
+        assert_eq!(time.wrapping_sub(hours(25)).to_string(), "22:00:00");
+        assert_eq!(Time(0).since(time), Some(interval(0, 0, -82_800_000_000)));
+    }
+
+    #[test]
+    fn test_temporal_ordering() {
+        let earlier: Timestamp = "2024-01-01T00:00:00".parse().unwrap();
+        let later: Timestamp = "2024-01-01T00:00:01".parse().unwrap();
+
+        assert!(earlier < later);
+        assert!("1999-12-31".parse::<Date>() < "2000-01-01".parse::<Date>());
+    }
+}
@@ -0,0 +1,80 @@
+//! Generation, parsing and formatting of [`Uuid`]s. Values are parsed from
+//! and formatted as the canonical text form, e.g.
+//! `67e55044-10b1-426f-9247-bb680e5fe0c8`.
+
+use std::{fmt::Display, str::FromStr};
+
+use crate::schema::r#type::data_types::Uuid;
+
+/// Length of the canonical text form of a UUID.
+const TEXT_LEN: usize = 36;
+
+impl Uuid {
+    /// The nil UUID, whose bits are all zero.
+    pub const NIL: Uuid = Uuid([0; 16]);
+
+    /// Generates a random (version 4) UUID, the same as `gen_random_uuid()`.
+    pub fn new_v4() -> Self {
+        Uuid(::uuid::Uuid::new_v4().into_bytes())
+    }
+}
+
+impl FromStr for Uuid {
+    type Err = ();
+
+    /// Parses the canonical text form, hex digits may be of any case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.len() != TEXT_LEN {
+            return Err(());
+        }
+
+        ::uuid::Uuid::try_parse(s)
+            .map(|uuid| Uuid(uuid.into_bytes()))
+            .map_err(|_| ())
+    }
+}
+
+impl Display for Uuid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", ::uuid::Uuid::from_bytes(self.0).hyphenated())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::schema::r#type::data_types::Uuid;
+
+    #[test]
+    fn test_parse_and_display() {
+        let text = "67e55044-10b1-426f-9247-bb680e5fe0c8";
+        let uuid = text.parse::<Uuid>().unwrap();
+        assert_eq!(uuid.0[..4], [0x67, 0xe5, 0x50, 0x44]);
+        assert_eq!(uuid.to_string(), text);
+
+        let uuid = "67E55044-10B1-426F-9247-BB680E5FE0C8".parse::<Uuid>();
+        assert_eq!(uuid.unwrap().to_string(), text);
+        assert_eq!(
+            Uuid::NIL.to_string(),
+            "00000000-0000-0000-0000-000000000000"
+        );
+
+        assert_eq!("67e5504410b1426f9247bb680e5fe0c8".parse::<Uuid>(), Err(()));
+        assert_eq!(
+            "{67e55044-10b1-426f-9247-bb680e5fe0c8}".parse::<Uuid>(),
+            Err(())
+        );
+        assert_eq!(
+            "67e55044-10b1-426f-9247-bb680e5fe0cz".parse::<Uuid>(),
+            Err(())
+        );
+    }
+
+    #[test]
+    fn test_new_v4() {
+        let uuid = Uuid::new_v4();
+
+        assert_ne!(uuid, Uuid::new_v4());
+        assert_eq!(uuid.0[6] >> 4, 4);
+        assert_eq!(uuid.0[8] >> 6, 0b10);
+    }
+}
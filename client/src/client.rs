@@ -0,0 +1,333 @@
+use std::{
+    io::{BufReader, BufWriter, Write},
+    mem,
+    net::{TcpStream, ToSocketAddrs},
+    sync::Arc,
+};
+
+use server::pg::message::{BackendMessage, FrontendMessage, StartupMessage};
+
+use crate::{
+    error::Error,
+    row::{QueryResult, Row},
+};
+
+/// Parameters of a connection.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Config {
+    /// Name of the user.
+    pub user: String,
+
+    /// Database to use after the connection is established.
+    pub database: Option<String>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            user: "nosqlite".to_string(),
+            database: None,
+        }
+    }
+}
+
+impl Config {
+    /// Sets the user of the connection.
+    pub fn with_user<T: Into<String>>(mut self, user: T) -> Self {
+        self.user = user.into();
+        self
+    }
+
+    /// Sets the database to use after the connection is established.
+    pub fn with_database<T: Into<String>>(mut self, database: T) -> Self {
+        self.database = Some(database.into());
+        self
+    }
+}
+
+/// Blocking client of the nosqlite server.
+#[derive(Debug)]
+pub struct Client {
+    /// Reader of the server stream.
+    reader: BufReader<TcpStream>,
+
+    /// Writer of the server stream.
+    writer: BufWriter<TcpStream>,
+
+    /// Run-time parameters reported by the server.
+    parameters: Vec<(String, String)>,
+
+    /// Whether the connection failed and can't be used anymore.
+    broken: bool,
+
+    /// Whether `USE DATABASE` or `USE SCHEMA` changed the context the
+    /// connection was opened with.
+    context_changed: bool,
+}
+
+impl Client {
+    /// Connects to the server with the default [`Config`].
+    /// # Arguments
+    /// * `addr` - Address of the server.
+    pub fn connect<A: ToSocketAddrs>(addr: A) -> Result<Self, Error> {
+        Self::connect_with(addr, &Config::default())
+    }
+
+    /// Connects to the server with the given [`Config`].
+    /// # Arguments
+    /// * `addr` - Address of the server.
+    /// * `config` - Parameters of the connection.
+    pub fn connect_with<A: ToSocketAddrs>(
+        addr: A,
+        config: &Config,
+    ) -> Result<Self, Error> {
+        let stream = TcpStream::connect(addr)?;
+        stream.set_nodelay(true)?;
+        let mut client = Self {
+            reader: BufReader::new(stream.try_clone()?),
+            writer: BufWriter::new(stream),
+            parameters: Vec::new(),
+            broken: false,
+            context_changed: false,
+        };
+
+        let mut params = vec![("user".to_string(), config.user.clone())];
+        if let Some(database) = &config.database {
+            params.push(("database".to_string(), database.clone()));
+        }
+        client
+            .writer
+            .write_all(&StartupMessage::Startup(params).encode())?;
+        client.writer.flush()?;
+
+        loop {
+            match client.read_message()? {
+                BackendMessage::AuthenticationOk
+                | BackendMessage::BackendKeyData { .. } => {}
+                BackendMessage::ParameterStatus(name, value) => {
+                    client.parameters.push((name, value));
+                }
+                BackendMessage::ReadyForQuery => return Ok(client),
+                BackendMessage::ErrorResponse(fields) => {
+                    return Err(Error::Server {
+                        code: fields.code,
+                        message: fields.message,
+                    })
+                }
+                message => {
+                    return Err(Error::UnexpectedMessage(format!(
+                        "{:?}",
+                        message
+                    )))
+                }
+            }
+        }
+    }
+
+    /// Returns a run-time parameter reported by the server.
+    /// # Arguments
+    /// * `name` - Name of the parameter, e.g. `server_version`.
+    pub fn parameter(&self, name: &str) -> Option<&str> {
+        self.parameters
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Returns `true` if the connection failed and can't be used anymore.
+    pub fn is_broken(&self) -> bool {
+        self.broken
+    }
+
+    /// Returns `true` if the current database or schema was changed after
+    /// the connection was established.
+    pub(crate) fn is_context_changed(&self) -> bool {
+        self.context_changed
+    }
+
+    /// Executes SQL, that may contain several commands.
+    /// # Arguments
+    /// * `sql` - SQL to execute.
+    /// # Returns
+    /// * `Ok(Vec<QueryResult>)` - Result of each executed command.
+    /// * `Err(Error)` - If any command failed. Commands after the failed
+    ///   one are not executed.
+    pub fn execute(&mut self, sql: &str) -> Result<Vec<QueryResult>, Error> {
+        self.write_message(&FrontendMessage::Query(sql.to_string()))?;
+
+        let mut results = Vec::new();
+        let mut columns = Arc::new(Vec::new());
+        let mut rows = Vec::new();
+        let mut error = None;
+        loop {
+            match self.read_message()? {
+                BackendMessage::RowDescription(fields) => {
+                    columns = Arc::new(fields);
+                }
+                BackendMessage::DataRow(values) => {
+                    rows.push(Row::new(columns.clone(), values));
+                }
+                BackendMessage::CommandComplete(tag) => {
+                    // `USE DATABASE` and `USE SCHEMA` complete as `SET`.
+                    if tag == "SET" {
+                        self.context_changed = true;
+                    }
+                    results.push(QueryResult {
+                        tag,
+                        columns: mem::take(&mut columns),
+                        rows: mem::take(&mut rows),
+                    });
+                }
+                BackendMessage::EmptyQueryResponse
+                | BackendMessage::ParameterStatus(..) => {}
+                BackendMessage::ErrorResponse(fields) => {
+                    error = Some(Error::Server {
+                        code: fields.code,
+                        message: fields.message,
+                    });
+                }
+                BackendMessage::ReadyForQuery => break,
+                message => {
+                    self.broken = true;
+                    return Err(Error::UnexpectedMessage(format!(
+                        "{:?}",
+                        message
+                    )));
+                }
+            }
+        }
+
+        match error {
+            Some(error) => Err(error),
+            None => Ok(results),
+        }
+    }
+
+    /// Executes SQL and returns the rows of the last command.
+    /// # Arguments
+    /// * `sql` - SQL to execute.
+    pub fn query(&mut self, sql: &str) -> Result<Vec<Row>, Error> {
+        let results = self.execute(sql)?;
+
+        Ok(results
+            .into_iter()
+            .last()
+            .map(|result| result.rows)
+            .unwrap_or_default())
+    }
+
+    /// Writes a message to the server.
+    fn write_message(
+        &mut self,
+        message: &FrontendMessage,
+    ) -> Result<(), Error> {
+        let result = message
+            .write(&mut self.writer)
+            .and_then(|()| self.writer.flush());
+        if result.is_err() {
+            self.broken = true;
+        }
+
+        Ok(result?)
+    }
+
+    /// Reads the next known message from the server.
+    fn read_message(&mut self) -> Result<BackendMessage, Error> {
+        loop {
+            match BackendMessage::read(&mut self.reader) {
+                Ok(Some(message)) => return Ok(message),
+                Ok(None) => continue,
+                Err(error) => {
+                    self.broken = true;
+                    return Err(error.into());
+                }
+            }
+        }
+    }
+}
+
+impl Drop for Client {
+    fn drop(&mut self) {
+        if !self.broken {
+            // The connection is closed anyway, so errors are ignored.
+            let _ = FrontendMessage::Terminate.write(&mut self.writer);
+            let _ = self.writer.flush();
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::{net::SocketAddr, thread};
+
+    use backend::schema::r#type::{data_types::Text, r#enum::StorageData};
+    use server::pg::Server;
+
+    use crate::{client::Config, error::Error, Client};
+
+    /// Starts the server in-process on an ephemeral port.
+    pub fn start_server() -> SocketAddr {
+        let server = Server::<4>::bind("127.0.0.1:0").unwrap();
+        let addr = server.local_addr().unwrap();
+        thread::spawn(move || server.run());
+
+        addr
+    }
+
+    #[test]
+    fn test_connect() {
+        let addr = start_server();
+        let client = Client::connect(addr).unwrap();
+
+        assert_eq!(client.parameter("client_encoding"), Some("UTF8"));
+        assert!(!client.is_broken());
+    }
+
+    #[test]
+    fn test_execute_and_iterate_rows() {
+        let addr = start_server();
+        let mut client = Client::connect(addr).unwrap();
+
+        let results = client
+            .execute("CREATE DATABASE first; CREATE DATABASE second;")
+            .unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].tag, "CREATE DATABASE");
+        assert!(results[0].rows.is_empty());
+
+        let rows = client.query("SHOW DATABASES;").unwrap();
+        assert_eq!(rows.len(), 1);
+        let output: String = rows[0].get("result").unwrap();
+        assert!(output.contains("first"));
+        assert!(output.contains("second"));
+    }
+
+    #[test]
+    fn test_decode_storage_data() {
+        let addr = start_server();
+        let config = Config::default().with_database("test");
+        let mut client = Client::connect_with(addr, &config).unwrap();
+        client.execute("CREATE DATABASE test;").unwrap();
+
+        let mut client = Client::connect_with(addr, &config).unwrap();
+        let rows = client.query("\\gc").unwrap();
+        let database: StorageData = rows[0].get("database").unwrap();
+        let schema: Option<StorageData> = rows[0].get("schema").unwrap();
+
+        assert_eq!(database, StorageData::Text(Text("test".to_string())));
+        assert_eq!(schema, None);
+    }
+
+    #[test]
+    fn test_server_error() {
+        let addr = start_server();
+        let mut client = Client::connect(addr).unwrap();
+
+        let result = client.execute("USE DATABASE missing;");
+        assert!(matches!(result, Err(Error::Server { .. })));
+
+        // Connection is still usable after an error.
+        assert!(!client.is_broken());
+        assert!(client.execute("CREATE DATABASE test;").is_ok());
+    }
+}
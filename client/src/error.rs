@@ -0,0 +1,53 @@
+use std::io;
+
+use derive_more::{Display, From};
+
+/// Errors that can occur while talking to the server.
+#[derive(Debug, Display, From)]
+pub enum Error {
+    /// Connection to the server failed.
+    #[display(fmt = "{}", _0)]
+    Io(io::Error),
+
+    /// Server returned an error.
+    #[from(ignore)]
+    #[display(fmt = "{} (SQLSTATE {})", message, code)]
+    Server {
+        /// `SQLSTATE` code of the error.
+        code: String,
+
+        /// Message of the error.
+        message: String,
+    },
+
+    /// Server sent a message that is not expected at this point.
+    #[from(ignore)]
+    #[display(fmt = "Unexpected message from the server: {}", _0)]
+    UnexpectedMessage(String),
+
+    /// Column with the given name doesn't exist in the row.
+    #[from(ignore)]
+    #[display(fmt = "Column `{}` not found", _0)]
+    ColumnNotFound(String),
+
+    /// Column with the given index doesn't exist in the row.
+    #[from(ignore)]
+    #[display(fmt = "Column index {} is out of range", _0)]
+    IndexOutOfRange(usize),
+
+    /// Value is `NULL`, but the target type is not nullable.
+    #[from(ignore)]
+    #[display(fmt = "Column `{}` is NULL", _0)]
+    UnexpectedNull(String),
+
+    /// Value can't be decoded into the target type.
+    #[from(ignore)]
+    #[display(fmt = "Can't decode `{}` as `{}`", value, target)]
+    Decode {
+        /// Text value received from the server.
+        value: String,
+
+        /// Name of the target type.
+        target: &'static str,
+    },
+}
@@ -0,0 +1,16 @@
+//! Blocking client of the nosqlite server.
+//!
+//! The server speaks the PostgreSQL protocol, so [`Client`] uses the message
+//! types of [`server::pg::message`].
+
+mod client;
+pub mod error;
+mod pool;
+pub mod row;
+pub mod value;
+
+pub use client::{Client, Config};
+pub use error::Error;
+pub use pool::{Pool, PooledClient};
+pub use row::{QueryResult, Row};
+pub use value::FromValue;
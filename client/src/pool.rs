@@ -0,0 +1,257 @@
+use std::{
+    io,
+    net::{SocketAddr, ToSocketAddrs},
+    ops::{Deref, DerefMut},
+    sync::{Arc, Condvar, Mutex},
+};
+
+use crate::{
+    client::{Client, Config},
+    error::Error,
+};
+
+/// Pool of [`Client`]s that reuses connections.
+///
+/// Cloning the [`Pool`] returns a handle to the same set of connections.
+#[derive(Debug, Clone)]
+pub struct Pool {
+    /// State shared by all handles and pooled clients.
+    inner: Arc<Inner>,
+}
+
+/// Shared state of a [`Pool`].
+#[derive(Debug)]
+struct Inner {
+    /// Addresses of the server.
+    addrs: Vec<SocketAddr>,
+
+    /// Parameters of new connections.
+    config: Config,
+
+    /// Maximum number of open connections.
+    max_size: usize,
+
+    /// Idle connections and the number of open ones.
+    state: Mutex<State>,
+
+    /// Notified when a connection is returned to the pool.
+    returned: Condvar,
+}
+
+/// Mutable state of a [`Pool`].
+#[derive(Debug, Default)]
+struct State {
+    /// Connections that are not in use.
+    idle: Vec<Client>,
+
+    /// Number of open connections, both idle and in use.
+    open: usize,
+}
+
+impl Pool {
+    /// Creates a new [`Pool`]. Connections are opened lazily.
+    /// # Arguments
+    /// * `addr` - Address of the server.
+    /// * `config` - Parameters of the connections.
+    /// * `max_size` - Maximum number of open connections.
+    pub fn new<A: ToSocketAddrs>(
+        addr: A,
+        config: Config,
+        max_size: usize,
+    ) -> Result<Self, Error> {
+        if max_size == 0 {
+            return Err(Error::Io(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "pool size must be positive",
+            )));
+        }
+
+        Ok(Self {
+            inner: Arc::new(Inner {
+                addrs: addr.to_socket_addrs()?.collect(),
+                config,
+                max_size,
+                state: Mutex::new(State::default()),
+                returned: Condvar::new(),
+            }),
+        })
+    }
+
+    /// Takes a [`Client`] from the pool, blocking until one is available.
+    ///
+    /// The [`Client`] returns to the pool when the [`PooledClient`] is
+    /// dropped. A connection whose database or schema was changed with
+    /// `USE` is closed instead, so every [`Client`] taken from the pool
+    /// starts in the context of the pool's [`Config`].
+    pub fn get(&self) -> Result<PooledClient, Error> {
+        let mut state = self.inner.state.lock().expect("not poisoned");
+        loop {
+            if let Some(client) = state.idle.pop() {
+                return Ok(self.pooled(client));
+            }
+
+            if state.open < self.inner.max_size {
+                state.open += 1;
+                drop(state);
+
+                return match Client::connect_with(
+                    self.inner.addrs.as_slice(),
+                    &self.inner.config,
+                ) {
+                    Ok(client) => Ok(self.pooled(client)),
+                    Err(error) => {
+                        self.inner.release();
+                        Err(error)
+                    }
+                };
+            }
+
+            state = self.inner.returned.wait(state).expect("not poisoned");
+        }
+    }
+
+    /// Returns the number of open connections.
+    pub fn open_connections(&self) -> usize {
+        self.inner.state.lock().expect("not poisoned").open
+    }
+
+    /// Wraps the [`Client`] into a [`PooledClient`].
+    fn pooled(&self, client: Client) -> PooledClient {
+        PooledClient {
+            client: Some(client),
+            pool: self.inner.clone(),
+        }
+    }
+}
+
+impl Inner {
+    /// Forgets a connection that was closed.
+    fn release(&self) {
+        let mut state = self.state.lock().expect("not poisoned");
+        state.open -= 1;
+        self.returned.notify_one();
+    }
+}
+
+/// [`Client`] taken from a [`Pool`].
+#[derive(Debug)]
+pub struct PooledClient {
+    /// The client, `None` only while dropping.
+    client: Option<Client>,
+
+    /// Pool to return the client to.
+    pool: Arc<Inner>,
+}
+
+impl Deref for PooledClient {
+    type Target = Client;
+
+    fn deref(&self) -> &Self::Target {
+        self.client.as_ref().expect("exists until dropped")
+    }
+}
+
+impl DerefMut for PooledClient {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.client.as_mut().expect("exists until dropped")
+    }
+}
+
+impl Drop for PooledClient {
+    fn drop(&mut self) {
+        let Some(client) = self.client.take() else {
+            return;
+        };
+
+        if client.is_broken() || client.is_context_changed() {
+            drop(client);
+            self.pool.release();
+        } else {
+            let mut state = self.pool.state.lock().expect("not poisoned");
+            state.idle.push(client);
+            self.pool.returned.notify_one();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use crate::{client::tests::start_server, Config, Pool};
+
+    #[test]
+    fn test_reuses_connections() {
+        let addr = start_server();
+        let pool = Pool::new(addr, Config::default(), 2).unwrap();
+
+        {
+            let mut client = pool.get().unwrap();
+            client.execute("CREATE DATABASE test;").unwrap();
+        }
+        {
+            let mut client = pool.get().unwrap();
+            client.execute("SHOW DATABASES;").unwrap();
+        }
+
+        assert_eq!(pool.open_connections(), 1);
+    }
+
+    #[test]
+    fn test_closes_connections_with_changed_context() {
+        let addr = start_server();
+        let pool = Pool::new(addr, Config::default(), 1).unwrap();
+
+        {
+            let mut client = pool.get().unwrap();
+            client.execute("CREATE DATABASE test;").unwrap();
+            assert!(client.execute("USE DATABASE missing;").is_err());
+        }
+        assert_eq!(pool.open_connections(), 1);
+
+        {
+            let mut client = pool.get().unwrap();
+            client.execute("USE DATABASE test;").unwrap();
+        }
+        assert_eq!(pool.open_connections(), 0);
+
+        let rows = pool.get().unwrap().query("\\gc").unwrap();
+        let database: Option<String> = rows[0].get("database").unwrap();
+        assert_eq!(database, None);
+    }
+
+    #[test]
+    fn test_blocks_when_exhausted() {
+        let addr = start_server();
+        let pool = Pool::new(addr, Config::default(), 2).unwrap();
+
+        let handles = (0..8)
+            .map(|i| {
+                let pool = pool.clone();
+                thread::spawn(move || {
+                    let mut client = pool.get().unwrap();
+                    client
+                        .execute(&format!("CREATE DATABASE db{};", i))
+                        .unwrap();
+                })
+            })
+            .collect::<Vec<_>>();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        assert!(pool.open_connections() <= 2);
+        let rows = pool.get().unwrap().query("SHOW DATABASES;").unwrap();
+        let output: String = rows[0].get(0).unwrap();
+        for i in 0..8 {
+            assert!(output.contains(&format!("db{}", i)));
+        }
+    }
+
+    #[test]
+    fn test_zero_size() {
+        let addr = start_server();
+
+        assert!(Pool::new(addr, Config::default(), 0).is_err());
+    }
+}
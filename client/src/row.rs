@@ -0,0 +1,161 @@
+use std::sync::Arc;
+
+use server::pg::message::FieldDescription;
+
+use crate::{error::Error, value::FromValue};
+
+/// Index of a column in a [`Row`].
+pub trait RowIndex {
+    /// Returns the position of the column.
+    /// # Arguments
+    /// * `columns` - Columns of the row.
+    fn position(&self, columns: &[FieldDescription]) -> Result<usize, Error>;
+}
+
+impl RowIndex for usize {
+    fn position(&self, columns: &[FieldDescription]) -> Result<usize, Error> {
+        if *self < columns.len() {
+            Ok(*self)
+        } else {
+            Err(Error::IndexOutOfRange(*self))
+        }
+    }
+}
+
+impl RowIndex for &str {
+    fn position(&self, columns: &[FieldDescription]) -> Result<usize, Error> {
+        columns
+            .iter()
+            .position(|column| column.name == *self)
+            .ok_or_else(|| Error::ColumnNotFound(self.to_string()))
+    }
+}
+
+/// Single row returned by the server.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Row {
+    /// Columns of the row, shared by all rows of a result.
+    columns: Arc<Vec<FieldDescription>>,
+
+    /// Values in the text format, `None` is `NULL`.
+    values: Vec<Option<String>>,
+}
+
+impl Row {
+    /// Creates a new [`Row`].
+    pub(crate) fn new(
+        columns: Arc<Vec<FieldDescription>>,
+        values: Vec<Option<String>>,
+    ) -> Self {
+        Self { columns, values }
+    }
+
+    /// Returns the columns of the row.
+    pub fn columns(&self) -> &[FieldDescription] {
+        self.columns.as_slice()
+    }
+
+    /// Returns the number of values in the row.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns `true` if the row has no values.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Decodes a value of the row.
+    /// # Arguments
+    /// * `index` - Position or name of the column.
+    /// # Returns
+    /// * `Ok(T)` - Decoded value.
+    /// * `Err(Error)` - If the column doesn't exist or the value can't be
+    ///   decoded into `T`.
+    pub fn get<I: RowIndex, T: FromValue>(&self, index: I) -> Result<T, Error> {
+        let position = index.position(self.columns())?;
+        let column = &self.columns[position];
+        let value = self
+            .values
+            .get(position)
+            .ok_or(Error::IndexOutOfRange(position))?;
+
+        match value {
+            Some(value) => {
+                T::from_text(value, column.pg_type).ok_or_else(|| {
+                    Error::Decode {
+                        value: value.clone(),
+                        target: std::any::type_name::<T>(),
+                    }
+                })
+            }
+            None => T::null()
+                .ok_or_else(|| Error::UnexpectedNull(column.name.clone())),
+        }
+    }
+}
+
+/// Result of a single command of a query.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueryResult {
+    /// Tag of the completed command, e.g. `CREATE TABLE`.
+    pub tag: String,
+
+    /// Columns of the result, empty if the command returns no rows.
+    pub columns: Arc<Vec<FieldDescription>>,
+
+    /// Rows of the result.
+    pub rows: Vec<Row>,
+}
+
+impl IntoIterator for QueryResult {
+    type Item = Row;
+    type IntoIter = std::vec::IntoIter<Row>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.rows.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use server::pg::r#type::PgType;
+
+    use super::*;
+
+    fn row() -> Row {
+        let columns = vec![
+            FieldDescription::new("id", PgType::TEXT),
+            FieldDescription::new("name", PgType::TEXT),
+        ];
+        Row::new(Arc::new(columns), vec![Some("1".to_string()), None])
+    }
+
+    #[test]
+    fn test_get_by_index_and_name() {
+        let row = row();
+
+        assert_eq!(row.get::<_, i32>(0).unwrap(), 1);
+        assert_eq!(row.get::<_, i32>("id").unwrap(), 1);
+        assert_eq!(row.get::<_, Option<String>>("name").unwrap(), None);
+    }
+
+    #[test]
+    fn test_get_errors() {
+        let row = row();
+
+        assert!(matches!(
+            row.get::<_, String>("name"),
+            Err(Error::UnexpectedNull(_))
+        ));
+        assert!(matches!(
+            row.get::<_, i32>("missing"),
+            Err(Error::ColumnNotFound(_))
+        ));
+        assert!(matches!(
+            row.get::<_, i32>(2),
+            Err(Error::IndexOutOfRange(2))
+        ));
+        assert!(matches!(row.get::<_, bool>(0), Err(Error::Decode { .. })));
+    }
+}
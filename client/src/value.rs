@@ -0,0 +1,214 @@
+use backend::schema::r#type::{
+    data_types::{
+        Blob, Bool, Date, Decimal, Double, Float, Integer, Interval, Long,
+        Short, Text, Time, Timestamp, ULong, Uuid, VarChar,
+    },
+    r#enum::StorageData,
+};
+use server::pg::r#type::{
+    PgType, BOOL_OID, BYTEA_OID, DATE_OID, FLOAT4_OID, FLOAT8_OID, INT2_OID,
+    INT4_OID, INT8_OID, INTERVAL_OID, NUMERIC_OID, TEXT_OID, TIMESTAMP_OID,
+    TIME_OID, UUID_OID, VARCHAR_OID,
+};
+
+/// Type that can be decoded from a value of the text format.
+pub trait FromValue: Sized {
+    /// Decodes a non-`NULL` value.
+    /// # Arguments
+    /// * `value` - Text representation of the value.
+    /// * `pg_type` - Type of the column the value belongs to.
+    /// # Returns
+    /// Decoded value or `None` if the value can't be decoded.
+    fn from_text(value: &str, pg_type: PgType) -> Option<Self>;
+
+    /// Returns the value that represents `NULL`, or `None` if the type is
+    /// not nullable.
+    fn null() -> Option<Self> {
+        None
+    }
+}
+
+impl<T: FromValue> FromValue for Option<T> {
+    fn from_text(value: &str, pg_type: PgType) -> Option<Self> {
+        T::from_text(value, pg_type).map(Some)
+    }
+
+    fn null() -> Option<Self> {
+        Some(None)
+    }
+}
+
+macro_rules! impl_from_value_parse {
+    ($($t:ty),*) => {
+        $(
+            impl FromValue for $t {
+                fn from_text(value: &str, _: PgType) -> Option<Self> {
+                    value.parse().ok()
+                }
+            }
+        )*
+    }
+}
+
+impl_from_value_parse!(
+    u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64, String, Date,
+    Time, Timestamp, Interval, Decimal, Uuid
+);
+
+impl FromValue for Vec<u8> {
+    /// Decodes binary data from the `bytea` hex format, e.g. `\xdeadbeef`.
+    fn from_text(value: &str, _: PgType) -> Option<Self> {
+        Blob::from_hex(value.strip_prefix("\\x")?).map(|blob| blob.0)
+    }
+}
+
+impl FromValue for bool {
+    fn from_text(value: &str, _: PgType) -> Option<Self> {
+        match value {
+            "t" | "true" => Some(true),
+            "f" | "false" => Some(false),
+            _ => None,
+        }
+    }
+}
+
+impl FromValue for StorageData {
+    /// Decodes the value into [`StorageData`] according to the column type.
+    ///
+    /// As the server widens unsigned types, the decoded variant is the one
+    /// of the PostgreSQL type, e.g. `int8` is decoded as [`Long`]. `numeric`
+    /// columns with a type modifier are decoded as [`Decimal`], the ones
+    /// without as integers.
+    fn from_text(value: &str, pg_type: PgType) -> Option<Self> {
+        let data = match pg_type.oid {
+            BOOL_OID => Bool(bool::from_text(value, pg_type)?).into(),
+            INT2_OID => Short(value.parse().ok()?).into(),
+            INT4_OID => Integer(value.parse().ok()?).into(),
+            INT8_OID => Long(value.parse().ok()?).into(),
+            NUMERIC_OID if pg_type.modifier != -1 => {
+                Decimal::from_text(value, pg_type)?.into()
+            }
+            NUMERIC_OID => match value.parse() {
+                Ok(value) => Long(value).into(),
+                Err(_) => ULong(value.parse().ok()?).into(),
+            },
+            FLOAT4_OID => Float(value.parse().ok()?).into(),
+            FLOAT8_OID => Double(value.parse().ok()?).into(),
+            VARCHAR_OID => VarChar::new(value.to_string()).ok()?.into(),
+            TEXT_OID => Text(value.to_string()).into(),
+            BYTEA_OID => Blob::from_hex(value.strip_prefix("\\x")?)?.into(),
+            DATE_OID => Date::from_text(value, pg_type)?.into(),
+            TIME_OID => Time::from_text(value, pg_type)?.into(),
+            TIMESTAMP_OID => Timestamp::from_text(value, pg_type)?.into(),
+            INTERVAL_OID => Interval::from_text(value, pg_type)?.into(),
+            UUID_OID => Uuid::from_text(value, pg_type)?.into(),
+            _ => return None,
+        };
+
+        Some(data)
+    }
+
+    fn null() -> Option<Self> {
+        Some(StorageData::Null)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use backend::schema::r#type::r#enum::StorageDataType;
+
+    use super::*;
+
+    #[test]
+    fn test_primitives() {
+        assert_eq!(i32::from_text("-1", PgType::TEXT), Some(-1));
+        assert_eq!(u8::from_text("256", PgType::TEXT), None);
+        assert_eq!(bool::from_text("t", PgType::TEXT), Some(true));
+        assert_eq!(
+            String::from_text("test", PgType::TEXT),
+            Some("test".to_string())
+        );
+        assert_eq!(
+            <Vec<u8>>::from_text("\\x0aff", PgType::TEXT),
+            Some(vec![10, 255])
+        );
+    }
+
+    #[test]
+    fn test_option() {
+        assert_eq!(<Option<i32>>::null(), Some(None));
+        assert_eq!(i32::null(), None);
+        assert_eq!(<Option<i32>>::from_text("1", PgType::TEXT), Some(Some(1)));
+    }
+
+    #[test]
+    fn test_storage_data() {
+        let pg_type = PgType::from(StorageDataType::Integer);
+        assert_eq!(
+            StorageData::from_text("5", pg_type),
+            Some(StorageData::Integer(Integer(5)))
+        );
+
+        let pg_type = PgType::from(StorageDataType::ULong);
+        assert_eq!(
+            StorageData::from_text(&u128::MAX.to_string(), pg_type),
+            Some(StorageData::ULong(ULong(u128::MAX)))
+        );
+
+        let pg_type = PgType::from(StorageDataType::VarChar(4));
+        assert_eq!(
+            StorageData::from_text("test", pg_type),
+            Some(StorageData::VarChar(VarChar::new("test".into()).unwrap()))
+        );
+
+        let pg_type = PgType::from(StorageDataType::Text);
+        assert_eq!(
+            StorageData::from_text(&"a".repeat(300), pg_type),
+            Some(StorageData::Text(Text("a".repeat(300))))
+        );
+
+        let pg_type = PgType::from(StorageDataType::Blob);
+        assert_eq!(
+            StorageData::from_text("\\xdead", pg_type),
+            Some(StorageData::Blob(Blob(vec![0xde, 0xad])))
+        );
+        assert_eq!(StorageData::from_text("dead", pg_type), None);
+
+        let pg_type = PgType::from(StorageDataType::Decimal(10, 2));
+        assert_eq!(
+            StorageData::from_text("-1.50", pg_type),
+            Some(StorageData::Decimal(Decimal::new(-150, 2).unwrap()))
+        );
+
+        let pg_type = PgType::from(StorageDataType::Timestamp);
+        assert_eq!(
+            StorageData::from_text("1970-01-01 00:00:01.500", pg_type),
+            Some(StorageData::Timestamp(Timestamp(1_500_000)))
+        );
+
+        let pg_type = PgType::from(StorageDataType::Interval);
+        assert_eq!(
+            StorageData::from_text("P1D", pg_type),
+            Some(StorageData::Interval(Interval {
+                months: 0,
+                days: 1,
+                micros: 0,
+            }))
+        );
+        assert_eq!(StorageData::from_text("1 day", pg_type), None);
+
+        let pg_type = PgType::from(StorageDataType::Uuid);
+        assert_eq!(
+            StorageData::from_text(
+                "abababab-abab-abab-abab-abababababab",
+                pg_type
+            ),
+            Some(StorageData::Uuid(Uuid([0xab; 16])))
+        );
+        assert_eq!(StorageData::from_text("abababab", pg_type), None);
+
+        let pg_type = PgType::from(StorageDataType::Bool);
+        assert_eq!(StorageData::from_text("maybe", pg_type), None);
+        assert_eq!(StorageData::null(), Some(StorageData::Null));
+    }
+}
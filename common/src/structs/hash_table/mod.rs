@@ -119,3 +119,14 @@ pub trait MutHashTable<K, V> {
     ///   hash table.
     fn get_mut_value(&mut self, key: &K) -> Option<&mut V>;
 }
+
+/// Trait for [`HashTable`] to get references to values by key.
+pub trait RefHashTable<K, V> {
+    /// Gets a reference to a value from the hash table without cloning it
+    /// # Arguments
+    /// * `key` - key of the value
+    /// # Returns
+    /// * `Option<&V>` - The reference to the value. None if the key is not
+    ///   in the hash table.
+    fn get_value(&self, key: &K) -> Option<&V>;
+}
@@ -7,12 +7,15 @@ use crate::{
     structs::hash_table::{
         hash::{custom_hashable::CustomHash, hash},
         vectors::{
-            hash_vec::{HashVec, Indexes, InternalStatistics, MutableHashVec},
+            hash_vec::{
+                HashVec, Indexes, InternalFunctions, InternalStatistics,
+                MutableHashVec,
+            },
             hash_vec_iterator::HashVecIterator,
             key_value::KeyValue,
             static_hash_vec::StaticHashVec,
         },
-        ExtendedFunctions, HashTable, MutHashTable, VecFunctions,
+        ExtendedFunctions, HashTable, MutHashTable, RefHashTable, VecFunctions,
     },
 };
 
@@ -281,13 +284,32 @@ where
     }
 }
 
+impl<K, V, H, const MAX_BUCKET_LEN: usize> RefHashTable<K, V>
+    for ScalableHashTable<K, V, H, MAX_BUCKET_LEN>
+where
+    H: HashVec<K, V> + InternalFunctions<K, V>,
+    K: Clone + CustomHash + PartialEq,
+    V: Clone,
+{
+    fn get_value(&self, key: &K) -> Option<&V> {
+        let hash = key.hash(self.hash);
+        let index = hash.to_usize() & (self.table.size() - 1);
+
+        self.table
+            .get_vec(index)?
+            .iter()
+            .find(|item| item.key == *key)
+            .map(|item| &item.value)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::structs::hash_table::{
         scalable::ScalableHashTable,
         vectors::{key_value::KeyValue, static_hash_vec::StaticHashVec},
         ExtendedFunctions as _, HashTable as _, MutHashTable as _,
-        VecFunctions as _,
+        RefHashTable as _, VecFunctions as _,
     };
 
     #[test]
@@ -503,4 +525,21 @@ mod tests {
         assert!(value.is_some());
         assert_eq!(value.unwrap(), &0);
     }
+
+    #[test]
+    fn test_scalable_hash_table_get_value() {
+        let mut hash_table: ScalableHashTable<
+            u64,
+            u64,
+            StaticHashVec<u64, u64>,
+        > = ScalableHashTable::new(8);
+
+        for i in 0..32 {
+            hash_table.insert(i, i * 2);
+        }
+
+        assert_eq!(hash_table.get_value(&0), Some(&0));
+        assert_eq!(hash_table.get_value(&31), Some(&62));
+        assert_eq!(hash_table.get_value(&32), None);
+    }
 }
@@ -5,12 +5,15 @@ use crate::{
     structs::hash_table::{
         hash::{custom_hashable::CustomHash, hash},
         vectors::{
-            hash_vec::{HashVec, Indexes, InternalStatistics, MutableHashVec},
+            hash_vec::{
+                HashVec, Indexes, InternalFunctions, InternalStatistics,
+                MutableHashVec,
+            },
             hash_vec_iterator::HashVecIterator,
             key_value::KeyValue,
             static_hash_vec::StaticHashVec,
         },
-        ExtendedFunctions, HashTable, MutHashTable, VecFunctions,
+        ExtendedFunctions, HashTable, MutHashTable, RefHashTable, VecFunctions,
     },
 };
 use std::marker::PhantomData;
@@ -189,12 +192,30 @@ where
     }
 }
 
+impl<K, V, H> RefHashTable<K, V> for StaticHashTable<K, V, H>
+where
+    H: HashVec<K, V> + InternalFunctions<K, V>,
+    K: Clone + CustomHash + PartialEq,
+    V: Clone,
+{
+    fn get_value(&self, key: &K) -> Option<&V> {
+        let hash = key.hash(self.hash);
+        let index = hash.to_usize() & (self.table.size() - 1);
+
+        self.table
+            .get_vec(index)?
+            .iter()
+            .find(|item| item.key == *key)
+            .map(|item| &item.value)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::structs::hash_table::{
         r#static::StaticHashTable,
         vectors::{key_value::KeyValue, static_hash_vec::StaticHashVec},
-        ExtendedFunctions, HashTable, MutHashTable, VecFunctions,
+        ExtendedFunctions, HashTable, MutHashTable, RefHashTable, VecFunctions,
     };
 
     #[test]
@@ -393,6 +414,21 @@ mod tests {
         assert_eq!(value, None);
     }
 
+    #[test]
+    fn test_static_hash_table_get_value() {
+        let mut hash_table: StaticHashTable<
+            usize,
+            usize,
+            StaticHashVec<usize, usize>,
+        > = StaticHashTable::new(8);
+        hash_table.insert(0, 0);
+        hash_table.insert(8, 8);
+
+        assert_eq!(hash_table.get_value(&0), Some(&0));
+        assert_eq!(hash_table.get_value(&8), Some(&8));
+        assert_eq!(hash_table.get_value(&1), None);
+    }
+
     // #[test]
     // fn test_hash() {
     //  let mut hash_table: StaticHashTable<u32, u32, StaticHashVec<u32, u32>> =
@@ -3,8 +3,6 @@ mod functions;
 
 use std::cmp::Ordering;
 
-use queues::{queue, IsQueue, Queue};
-
 use crate::structs::tree::{
     object::{
         balanced_tree::functions::{balance, find_min, remove_min},
@@ -182,16 +180,17 @@ impl<
     }
 
     fn push(&mut self, value: T) -> usize {
-        if self.nodes.len() == 0 {
-            self.root = Some(self.nodes.push(value));
-            self.len += 1;
-            self.root.unwrap()
-        } else {
-            let balanced =
-                self.add_from_root(value, self.root.unwrap()).unwrap();
+        // The vector keeps the slots of removed nodes, so only the root
+        // tells if the tree is empty.
+        if let Some(root) = self.root {
+            let balanced = self.add_from_root(value, root).unwrap();
             self.root = Some(balanced.0);
             self.len += 1;
             balanced.1
+        } else {
+            self.root = Some(self.nodes.push(value));
+            self.len += 1;
+            self.root.unwrap()
         }
     }
 
@@ -220,22 +219,11 @@ impl<
     }
 
     fn remove_by_value(&mut self, value: &T) -> Option<T> {
-        if self.nodes.len() == 0 {
-            return None;
-        } else if self.nodes.len() == 1 {
-            let item = self.nodes.remove(0);
-            self.len -= 1;
-            return match item {
-                Some(item) => {
-                    self.root = None;
-                    Some(item.value)
-                }
-                None => None,
-            };
-        }
+        let root = self.root?;
+        let removed = self.nodes.get(self.find(value)?)?.value;
         self.len -= 1;
-        self.root = self.remove_from_root(value, self.root.unwrap());
-        Some(value.clone())
+        self.root = self.remove_from_root(value, root);
+        Some(removed)
     }
 
     fn pop(&self) -> Option<T> {
@@ -243,7 +231,7 @@ impl<
     }
 
     fn is_empty(&self) -> bool {
-        self.nodes.len() == 0
+        self.root.is_none()
     }
 
     fn len(&self) -> usize {
@@ -287,175 +275,60 @@ impl<T: Default + PartialOrd + Clone, M: TreeVec<T> + Indexes<T> + Sized>
     FindFunctions<T> for BalancedTree<T, M>
 {
     fn find_greater_equal(&mut self, value: &T) -> Option<(usize, T)> {
-        let mut queue: Queue<(Option<usize>, String)> = queue![];
-        let mut current_index = self.root;
-        let mut last = (None, String::new());
-        let mut ind = false;
-        let mut turn_count = 0;
-
-        while !ind && current_index.is_some() {
-            if let Some(node_value) =
-                self.nodes.get_value_mut(current_index.unwrap())
-            {
-                if (self.compare)(value, node_value) == Ordering::Less {
-                    if last.1 == "right" {
-                        turn_count += 1;
-                    }
-
-                    last = (current_index, "left".to_string());
-
-                    if turn_count > 1 {
-                        while queue.peek().unwrap().1 != "right" {
-                            let _ = queue.remove();
-                        }
-                    }
-
-                    let _ = queue.add(last.clone());
-                    current_index = self
-                        .nodes
-                        .get_index_mut(current_index.unwrap())
-                        .left_index;
-                } else if (self.compare)(value, node_value) == Ordering::Greater
-                {
-                    if last.1 == "left" {
-                        turn_count += 1;
-                    }
-
-                    last = (current_index, "right".to_string());
-
-                    if turn_count > 1 {
-                        while queue.peek().unwrap().1 != "left" {
-                            let _ = queue.remove();
-                        }
-                    }
-
-                    let _ = queue.add(last.clone());
-                    current_index = self
-                        .nodes
-                        .get_index_mut(current_index.unwrap())
-                        .right_index;
-                } else {
-                    ind = true;
-                }
-            } else {
-                current_index = None;
-            }
-        }
-
-        return if ind {
-            Some((
-                self.nodes
-                    .get_index_mut(current_index.unwrap())
-                    .index
-                    .unwrap(),
-                self.nodes[current_index.unwrap()].clone(),
-            ))
-        } else if last.1 == "right" {
-            if queue.peek().unwrap().1 == "right" {
-                None
-            } else {
-                let mut turn = queue.remove().unwrap();
-                while queue.peek().unwrap().1 != "right" {
-                    turn = queue.remove().unwrap();
-                }
-
-                Some((
-                    self.nodes.get_index_mut(turn.0.unwrap()).index.unwrap(),
-                    self.nodes[turn.0.unwrap()].clone(),
-                ))
-            }
-        } else {
-            Some((
-                self.nodes.get_index_mut(last.0.unwrap()).index.unwrap(),
-                self.nodes[last.0.unwrap()].clone(),
-            ))
-        };
+        self.find_closest(value, Ordering::Less)
     }
 
     fn find_less_equal(&mut self, value: &T) -> Option<(usize, T)> {
-        let mut queue: Queue<(Option<usize>, String)> = queue![];
-        let mut current_index = self.root;
-        let mut last = (None, String::new());
-        let mut ind = false;
-        let mut turn_count = 0;
-
-        while !ind && current_index.is_some() {
-            if let Some(node_value) =
-                self.nodes.get_value_mut(current_index.unwrap())
-            {
-                if (self.compare)(value, node_value) == Ordering::Less {
-                    if last.1 == "right" {
-                        turn_count += 1;
-                    }
-
-                    last = (current_index, "left".to_string());
-
-                    if turn_count > 1 {
-                        while queue.peek().unwrap().1 != "right" {
-                            let _ = queue.remove();
-                        }
-                    }
-
-                    let _ = queue.add(last.clone());
-                    current_index = self
-                        .nodes
-                        .get_index_mut(current_index.unwrap())
-                        .left_index;
-                } else if (self.compare)(value, node_value) == Ordering::Greater
-                {
-                    if last.1 == "left" {
-                        turn_count += 1;
-                    }
-
-                    last = (current_index, "right".to_string());
+        self.find_closest(value, Ordering::Greater)
+    }
+}
 
-                    if turn_count > 1 {
-                        while queue.peek().unwrap().1 != "left" {
-                            let _ = queue.remove();
-                        }
-                    }
+impl<T: Default + PartialOrd + Clone, M: TreeVec<T> + Indexes<T> + Sized>
+    BalancedTree<T, M>
+{
+    /// Finds the node equal to the value or, if there is none, the closest
+    /// node on one side of it.
+    /// # Arguments
+    /// * `value` - The value to search for.
+    /// * `side` - [`Ordering::Less`] to find the smallest greater node,
+    ///   [`Ordering::Greater`] to find the greatest less node.
+    /// # Returns
+    /// * `Option<(usize, T)>` - The index and the value of the node.
+    fn find_closest(
+        &mut self,
+        value: &T,
+        side: Ordering,
+    ) -> Option<(usize, T)> {
+        let mut current_index = self.root;
+        let mut found = None;
 
-                    let _ = queue.add(last.clone());
-                    current_index = self
-                        .nodes
-                        .get_index_mut(current_index.unwrap())
-                        .right_index;
-                } else {
-                    ind = true;
-                }
-            } else {
-                current_index = None;
+        while let Some(index) = current_index {
+            let Some(node_value) = self.nodes.get_value_mut(index) else {
+                break;
+            };
+            let ordering = (self.compare)(value, node_value);
+            if ordering == Ordering::Equal {
+                found = Some(index);
+                break;
+            }
+            if ordering == side {
+                found = Some(index);
             }
-        }
 
-        return if ind {
-            Some((
-                self.nodes
-                    .get_index_mut(current_index.unwrap())
-                    .index
-                    .unwrap(),
-                self.nodes[current_index.unwrap()].clone(),
-            ))
-        } else if last.1 == "left" {
-            if queue.peek().unwrap().1 == "left" {
-                None
+            let node = self.nodes.get_index_mut(index);
+            current_index = if ordering == Ordering::Less {
+                node.left_index
             } else {
-                let mut turn = queue.remove().unwrap();
-                while queue.peek().unwrap().1 != "left" {
-                    turn = queue.remove().unwrap();
-                }
+                node.right_index
+            };
+        }
 
-                Some((
-                    self.nodes.get_index_mut(turn.0.unwrap()).index.unwrap(),
-                    self.nodes[turn.0.unwrap()].clone(),
-                ))
-            }
-        } else {
-            Some((
-                self.nodes.get_index_mut(last.0.unwrap()).index.unwrap(),
-                self.nodes[last.0.unwrap()].clone(),
-            ))
-        };
+        found.map(|index| {
+            (
+                self.nodes.get_index_mut(index).index.unwrap(),
+                self.nodes[index].clone(),
+            )
+        })
     }
 }
 
@@ -544,6 +417,25 @@ mod tests {
         assert_eq!(balanced.1, 1);
     }
 
+    #[test]
+    fn test_add_after_removing_all() {
+        let mut tree = BalancedTree::<u64, DefaultTreeVec<u64>>::default();
+
+        tree.push(1);
+        tree.push(2);
+        tree.remove_by_value(&1);
+        tree.remove_by_value(&2);
+
+        assert!(tree.is_empty());
+        assert_eq!(tree.len(), 0);
+        assert_eq!(tree.find_greater_equal(&1), None);
+
+        tree.push(3);
+
+        assert!(!tree.is_empty());
+        assert_eq!(tree.find_greater_equal(&1).map(|(_, v)| v), Some(3));
+    }
+
     #[test]
     fn test_add_left() {
         let mut tree = BalancedTree::<u64, DefaultTreeVec<u64>>::default();
@@ -713,6 +605,38 @@ mod tests {
         assert_eq!(tree.len(), 0);
     }
 
+    #[test]
+    fn test_remove_missing_value() {
+        let mut tree = BalancedTree::<u64, DefaultTreeVec<u64>>::default();
+        assert_eq!(tree.remove_by_value(&1), None);
+
+        tree.push(2);
+        tree.push(4);
+        tree.push(6);
+
+        assert_eq!(tree.remove_by_value(&1), None);
+        assert_eq!(tree.remove_by_value(&5), None);
+        assert_eq!(tree.remove_by_value(&7), None);
+        assert_eq!(tree.len(), 3);
+        assert!(tree.find(&4).is_some());
+
+        assert_eq!(tree.remove_by_value(&4), Some(4));
+        assert_eq!(tree.remove_by_value(&4), None);
+        assert_eq!(tree.len(), 2);
+    }
+
+    #[test]
+    fn test_remove_returns_stored_value() {
+        type Pairs = DefaultTreeVec<(u64, u64)>;
+        let mut tree =
+            BalancedTree::<_, Pairs>::new_with_compare(|a, b| a.0.cmp(&b.0));
+        tree.push((1, 10));
+        tree.push((2, 20));
+
+        assert_eq!(tree.remove_by_value(&(2, 0)), Some((2, 20)));
+        assert_eq!(tree.len(), 1);
+    }
+
     #[test]
     fn test_remove_from_long() {
         let mut tree = BalancedTree::<u64, DefaultTreeVec<u64>>::default();
@@ -756,6 +680,28 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_find_closest_matches_linear_scan() {
+        let mut tree = BalancedTree::<u64, DefaultTreeVec<u64>>::default();
+        let values = (0..41).map(|i| (i * 17 % 41) * 10).collect::<Vec<_>>();
+        for value in &values {
+            tree.push(*value);
+        }
+
+        for probe in 0..420 {
+            let greater = values.iter().filter(|v| **v >= probe).min();
+            let less = values.iter().filter(|v| **v <= probe).max();
+            assert_eq!(
+                tree.find_greater_equal(&probe).map(|(_, v)| v).as_ref(),
+                greater
+            );
+            assert_eq!(
+                tree.find_less_equal(&probe).map(|(_, v)| v).as_ref(),
+                less
+            );
+        }
+    }
+
     #[test]
     fn test_find_more_equal() {
         let mut tree = BalancedTree::<u64, DefaultTreeVec<u64>>::default();
@@ -52,7 +52,10 @@ impl<T: Default + Clone> TreeVec<T> for DefaultTreeVec<T> {
             self.length += 1;
             self.data.len()
         } else {
-            self.empty.pop().unwrap()
+            let index = self.empty.pop().unwrap();
+            // The slot may be past the length if the last one was removed.
+            self.length = self.length.max(index + 1);
+            index
         };
 
         let indexes = TreeIndex::new_with_index(index);
@@ -277,6 +280,20 @@ mod tests {
         assert_eq!(vec.len(), 2);
     }
 
+    #[test]
+    fn test_default_vec_push_after_remove_last() {
+        let mut vec = DefaultTreeVec::<i32>::new();
+
+        vec.push(1);
+        let index = vec.push(2);
+        vec.remove(index);
+        assert_eq!(vec.len(), 1);
+
+        assert_eq!(vec.push(3), index);
+        assert_eq!(vec.len(), 2);
+        assert_eq!(vec.get_value_mut(index), Some(&mut 3));
+    }
+
     #[test]
     fn test_default_vec_get_out_of_bounds() {
         let mut vec = DefaultTreeVec::<i32>::new();
@@ -6,6 +6,7 @@ use std::{io, io::Write};
 
 use backend_api::api::command::{r#enum::BackendCommand, Gateway};
 use frontend::planner::adapter::PlannerCommand;
+use server::pg::Server;
 
 use crate::{api::Api, command::execute_frontend_command, r#static::welcome};
 
@@ -58,6 +59,26 @@ fn repl<const NODE_SIZE: u8>(mut api: Api<NODE_SIZE>) -> io::Result<()> {
                                     println!("{}", error);
                                 }
                             }
+                            BackendCommand::Data(command) => {
+                                match api.backend_api.send(command) {
+                                    Ok(res) => {
+                                        println!("{:?}", res);
+                                    }
+                                    Err(error) => {
+                                        println!("{}", error);
+                                    }
+                                }
+                            }
+                            BackendCommand::Join(command) => {
+                                match api.backend_api.send(*command) {
+                                    Ok(res) => {
+                                        println!("{:?}", res);
+                                    }
+                                    Err(error) => {
+                                        println!("{}", error);
+                                    }
+                                }
+                            }
                         },
                         PlannerCommand::Frontend(command) => {
                             if let Err(error) =
@@ -83,6 +104,17 @@ fn repl<const NODE_SIZE: u8>(mut api: Api<NODE_SIZE>) -> io::Result<()> {
 }
 
 fn main() -> io::Result<()> {
+    // `--listen <addr>` starts the PostgreSQL protocol server instead of
+    // the REPL.
+    let mut args = std::env::args().skip(1);
+    if let Some("--listen") = args.next().as_deref() {
+        let addr = args.next().unwrap_or("127.0.0.1:5432".to_string());
+        let server = Server::<128>::bind(addr)?;
+        println!("Listening on {}", server.local_addr()?);
+
+        return server.run();
+    }
+
     let api = Api::<128>::default();
 
     clear_screen();
@@ -1,4 +1,6 @@
-use crate::lexer::token::Token;
+use derive_more::Display;
+
+use crate::lexer::token::{Literal, Operator, Token};
 
 pub mod token;
 
@@ -13,6 +15,26 @@ pub struct Lexer {
 
     /// The read position of the lexer.
     read_position: usize,
+
+    /// The error the lexer stopped on, if any.
+    error: Option<LexError>,
+}
+
+/// Represents a lexer error, the lexer stops producing tokens on it.
+#[derive(Debug, Display, PartialEq, Clone)]
+pub enum LexError {
+    /// The quoted value of a literal is malformed, e.g. `DATE '2024-13-45'`.
+    #[display(fmt = "Invalid literal {}", _0)]
+    InvalidLiteral(String),
+
+    /// The string literal isn't closed, e.g. `'abc`.
+    #[display(fmt = "Unterminated string {}", _0)]
+    UnterminatedString(String),
+
+    /// The parameters of a data type are out of range, e.g.
+    /// `DECIMAL(50, 60)`.
+    #[display(fmt = "Invalid data type {}", _0)]
+    InvalidDataType(String),
 }
 
 impl Lexer {
@@ -29,55 +51,129 @@ impl Lexer {
             input: input.as_ref().trim().to_string(),
             current_position: 0,
             read_position: 0,
+            error: None,
         }
     }
 
+    /// Takes the error the lexer stopped on.
+    /// # Returns
+    /// * `Option<LexError>` - The error, or `None` if the lexer didn't fail.
+    pub fn take_error(&mut self) -> Option<LexError> {
+        self.error.take()
+    }
+
+    /// Stops the lexer on the given error, no tokens are produced after it.
+    fn fail(&mut self, error: LexError) -> Option<Token> {
+        self.current_position = self.input.len();
+        self.error = Some(error);
+        None
+    }
+
     /// Skips the whitespace characters in the input source code.
     fn skip_whitespace(&mut self) {
-        if self.current_position >= self.input.len() {
+        let Some(rest) = self.input.get(self.current_position..) else {
             return;
+        };
+        self.current_position += rest.len() - rest.trim_start().len();
+    }
+
+    /// Skips the alphanumeric characters in the input source code.
+    fn read_alphanumeric(&mut self) {
+        let Some(rest) = self.input.get(self.read_position..) else {
+            return;
+        };
+        self.read_position += rest
+            .find(|ch: char| !(ch.is_alphanumeric() || ch == '_' || ch == '.'))
+            .unwrap_or(rest.len());
+    }
+
+    /// Reads the quoted part of a literal up to the closing quote, e.g. of
+    /// `x'deadbeef'` or `DATE '2024-01-31'`, if the read alphanumeric prefix
+    /// is followed by a quote. Whitespace between the prefix and the quote
+    /// is skipped.
+    /// # Returns
+    /// * `bool` - Whether the quoted part is read.
+    fn read_quoted(&mut self) -> bool {
+        let Some(rest) = self.input.get(self.read_position..) else {
+            return false;
+        };
+        let quoted = rest.trim_start();
+        let Some(value) = quoted.strip_prefix('\'') else {
+            return false;
+        };
+
+        let Some(len) = value.find('\'') else {
+            return false;
+        };
+        self.read_position += rest.len() - quoted.len() + len + 2;
+        true
+    }
+
+    /// Reads the parameters of a data type, e.g. of `DECIMAL(10, 2)`, if the
+    /// read alphanumeric prefix is followed by them.
+    /// # Returns
+    /// * `bool` - Whether the parameters are read.
+    fn read_type_params(&mut self) -> bool {
+        let Some(rest) = self.input.get(self.read_position..) else {
+            return false;
+        };
+        if !rest.starts_with('(') {
+            return false;
         }
 
-        let mut ch = self
-            .input
+        let Some(len) = rest.find(')') else {
+            return false;
+        };
+        let is_params = rest[1..len]
             .chars()
-            .nth(self.current_position)
-            .expect("exists because of the check");
-        while ch.is_whitespace() {
-            self.current_position += 1;
-            if self.current_position >= self.input.len() {
-                return;
-            }
-            ch = self
-                .input
-                .chars()
-                .nth(self.current_position)
-                .expect("exists because of the check");
+            .all(|ch| ch.is_ascii_digit() || ch == ',' || ch == ' ');
+        if is_params {
+            self.read_position += len + 1;
         }
+        is_params
     }
 
-    /// Skips the alphanumeric characters in the input source code.
-    fn read_alphanumeric(&mut self) {
-        if self.read_position >= self.input.len() {
-            return;
+    /// Reads the empty argument list of a function literal, e.g. of `NOW()`.
+    fn read_empty_call(&mut self) {
+        let rest = self.input.get(self.read_position..);
+        if rest.is_some_and(|rest| rest.starts_with("()")) {
+            self.read_position += 2;
         }
+    }
 
-        let mut ch = self
+    /// Reads a string literal in single quotes, quotes in it are doubled,
+    /// e.g. `'it''s'`.
+    /// # Returns
+    /// * `Option<String>` - The string, or `None` if the next char isn't a
+    ///   quote or the string isn't closed.
+    fn read_string(&mut self) -> Option<String> {
+        let rest = self
             .input
-            .chars()
-            .nth(self.read_position)
-            .expect("exists because of the check");
-        while ch.is_alphanumeric() || ch == '_' || ch == '.' {
-            self.read_position += 1;
-            if self.read_position >= self.input.len() {
-                return;
+            .get(self.current_position..)?
+            .strip_prefix('\'')?;
+
+        let mut value = String::new();
+        let mut chars = rest.char_indices().peekable();
+        while let Some((i, ch)) = chars.next() {
+            if ch == '\'' && chars.next_if(|(_, ch)| *ch == '\'').is_none() {
+                self.current_position += i + 2;
+                return Some(value);
             }
-            ch = self
-                .input
-                .chars()
-                .nth(self.read_position)
-                .expect("exists because of the check");
+            value.push(ch);
         }
+
+        None
+    }
+
+    /// Reads an operator, two chars long ones are preferred, e.g. `<=` over
+    /// `<`.
+    fn read_operator(&mut self) -> Option<Operator> {
+        let rest = self.input.get(self.current_position..)?;
+        [2, 1].into_iter().find_map(|len| {
+            let operator = rest.get(..len)?.parse().ok()?;
+            self.current_position += len;
+            Some(operator)
+        })
     }
 
     /// Reads the next token from the lexer.
@@ -87,7 +183,11 @@ impl Lexer {
             return None;
         }
 
-        self.read_position = self.current_position + 1;
+        let ch = self.input[self.current_position..]
+            .chars()
+            .next()
+            .expect("exists because of the check");
+        self.read_position = self.current_position + ch.len_utf8();
         {
             let substr = self
                 .input
@@ -101,12 +201,50 @@ impl Lexer {
             }
         }
 
+        if let Some(value) = self.read_string() {
+            return Some(Token::Literal(Literal::String(value)));
+        }
+        if ch == '\'' {
+            let rest = self.input[self.current_position..].to_string();
+            return self.fail(LexError::UnterminatedString(rest));
+        }
+
+        if let Some(operator) = self.read_operator() {
+            return Some(Token::Operator(operator));
+        }
+
         self.read_alphanumeric();
+        let prefix = self
+            .input
+            .get(self.current_position..self.read_position)
+            .map(str::to_lowercase);
+        let mut is_quoted = false;
+        let mut has_params = false;
+        match prefix.as_deref() {
+            Some("x" | "date" | "time" | "timestamp" | "interval" | "uuid") => {
+                is_quoted = self.read_quoted()
+            }
+            Some("now" | "gen_random_uuid") => self.read_empty_call(),
+            Some("decimal" | "numeric" | "varchar") => {
+                has_params = self.read_type_params()
+            }
+            _ => {}
+        }
         let substr = self
             .input
             .get(self.current_position..self.read_position)
             .expect("exists because of the check");
 
+        let literal = substr.parse::<token::Literal>();
+        if let Ok(literal) = literal {
+            self.current_position = self.read_position;
+            return Some(Token::Literal(literal));
+        }
+        if is_quoted {
+            let literal = substr.to_string();
+            return self.fail(LexError::InvalidLiteral(literal));
+        }
+
         let shortcut = substr.parse::<token::Shortcut>();
         if let Ok(shortcut) = shortcut {
             self.current_position = self.read_position;
@@ -136,6 +274,10 @@ impl Lexer {
             self.current_position = self.read_position;
             return Some(Token::DataType(data_type));
         }
+        if has_params {
+            let data_type = substr.to_string();
+            return self.fail(LexError::InvalidDataType(data_type));
+        }
 
         self.current_position = self.read_position;
 
@@ -154,10 +296,15 @@ impl Iterator for Lexer {
 
 #[cfg(test)]
 mod lexer_tests {
+    use backend::schema::r#type::data_types::{Blob, Date, Interval, Uuid};
+
     use crate::lexer::{
         token,
-        token::{Preposition, Token},
-        Lexer,
+        token::{
+            Clause, Conditional, Keyword, Literal, Logical, Operator,
+            Preposition, Token,
+        },
+        LexError, Lexer,
     };
 
     #[test]
@@ -332,4 +479,235 @@ mod lexer_tests {
 
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn test_lexer_blob_literal() {
+        let lexer = Lexer::new("data BYTEA, (x'deadbeef',X'00') x");
+        let expected = vec![
+            Token::Identifier(token::Identifier("data".to_string())),
+            Token::DataType(token::DataType::Blob),
+            Token::Delimiter(token::Delimiter::Comma),
+            Token::Delimiter(token::Delimiter::LeftParenthesis),
+            Token::Literal(token::Literal::Blob(Blob(vec![
+                0xde, 0xad, 0xbe, 0xef,
+            ]))),
+            Token::Delimiter(token::Delimiter::Comma),
+            Token::Literal(token::Literal::Blob(Blob(vec![0]))),
+            Token::Delimiter(token::Delimiter::RightParenthesis),
+            Token::Identifier(token::Identifier("x".to_string())),
+        ];
+
+        let actual: Vec<Token> = lexer.collect();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_lexer_decimal_data_type() {
+        let lexer = Lexer::new(
+            "(price DECIMAL(10, 2), total numeric, name VARCHAR(20))",
+        );
+        let expected = vec![
+            Token::Delimiter(token::Delimiter::LeftParenthesis),
+            Token::Identifier(token::Identifier("price".to_string())),
+            Token::DataType(token::DataType::Decimal(10, 2)),
+            Token::Delimiter(token::Delimiter::Comma),
+            Token::Identifier(token::Identifier("total".to_string())),
+            Token::DataType(token::DataType::Decimal(38, 0)),
+            Token::Delimiter(token::Delimiter::Comma),
+            Token::Identifier(token::Identifier("name".to_string())),
+            Token::DataType(token::DataType::VarChar(20)),
+            Token::Delimiter(token::Delimiter::RightParenthesis),
+        ];
+
+        let actual: Vec<Token> = lexer.collect();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_lexer_invalid_data_type() {
+        for input in ["DECIMAL(50, 60)", "numeric(0, 0)", "VARCHAR(256)"] {
+            let mut lexer = Lexer::new(format!("(price {input})"));
+            let actual: Vec<Token> = lexer.by_ref().collect();
+
+            assert_eq!(
+                actual,
+                vec![
+                    Token::Delimiter(token::Delimiter::LeftParenthesis),
+                    Token::Identifier(token::Identifier("price".to_string())),
+                ]
+            );
+            assert_eq!(
+                lexer.take_error(),
+                Some(LexError::InvalidDataType(input.to_string()))
+            );
+        }
+    }
+
+    #[test]
+    fn test_lexer_temporal_literal() {
+        let lexer = Lexer::new(
+            "created DATE, (DATE '1970-01-02', interval'P1D', NOW(), \
+             current_date) time",
+        );
+        let expected = vec![
+            Token::Identifier(token::Identifier("created".to_string())),
+            Token::DataType(token::DataType::Date),
+            Token::Delimiter(token::Delimiter::Comma),
+            Token::Delimiter(token::Delimiter::LeftParenthesis),
+            Token::Literal(token::Literal::Date(Date(1))),
+            Token::Delimiter(token::Delimiter::Comma),
+            Token::Literal(token::Literal::Interval(Interval {
+                days: 1,
+                ..Default::default()
+            })),
+            Token::Delimiter(token::Delimiter::Comma),
+            Token::Literal(token::Literal::CurrentTimestamp),
+            Token::Delimiter(token::Delimiter::Comma),
+            Token::Literal(token::Literal::CurrentDate),
+            Token::Delimiter(token::Delimiter::RightParenthesis),
+            Token::DataType(token::DataType::Time),
+        ];
+
+        let actual: Vec<Token> = lexer.collect();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_lexer_uuid_literal() {
+        let lexer = Lexer::new(
+            "id UUID, (UUID '67e55044-10b1-426f-9247-bb680e5fe0c8', \
+             gen_random_uuid())",
+        );
+        let uuid = "67e55044-10b1-426f-9247-bb680e5fe0c8"
+            .parse::<Uuid>()
+            .unwrap();
+        let expected = vec![
+            Token::Identifier(token::Identifier("id".to_string())),
+            Token::DataType(token::DataType::Uuid),
+            Token::Delimiter(token::Delimiter::Comma),
+            Token::Delimiter(token::Delimiter::LeftParenthesis),
+            Token::Literal(token::Literal::Uuid(uuid)),
+            Token::Delimiter(token::Delimiter::Comma),
+            Token::Literal(token::Literal::RandomUuid),
+            Token::Delimiter(token::Delimiter::RightParenthesis),
+        ];
+
+        let actual: Vec<Token> = lexer.collect();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_lexer_expression() {
+        let lexer =
+            Lexer::new("price*2>=10.5 AND name||'it''s'!=-x OR NOT ok IS NULL");
+        let expected = vec![
+            Token::Identifier(token::Identifier("price".to_string())),
+            Token::Operator(Operator::Asterisk),
+            Token::Literal(Literal::Integer(2)),
+            Token::Operator(Operator::GreaterThanOrEqual),
+            Token::Literal(Literal::Decimal("10.5".parse().unwrap())),
+            Token::Keyword(Keyword::Logical(Logical::And)),
+            Token::Identifier(token::Identifier("name".to_string())),
+            Token::Operator(Operator::Concat),
+            Token::Literal(Literal::String("it's".to_string())),
+            Token::Operator(Operator::NotEqual),
+            Token::Operator(Operator::Minus),
+            Token::Identifier(token::Identifier("x".to_string())),
+            Token::Keyword(Keyword::Logical(Logical::Or)),
+            Token::Keyword(Keyword::Logical(Logical::Not)),
+            Token::Identifier(token::Identifier("ok".to_string())),
+            Token::Keyword(Keyword::Logical(Logical::Is)),
+            Token::Literal(Literal::Null),
+        ];
+
+        let actual: Vec<Token> = lexer.collect();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_lexer_non_ascii() {
+        let lexer = Lexer::new("WHERE name = 'é' AND café\u{a0}= 7");
+        let expected = vec![
+            Token::Keyword(Keyword::Clause(Clause::Where)),
+            Token::Identifier(token::Identifier("name".to_string())),
+            Token::Operator(Operator::Equal),
+            Token::Literal(Literal::String("é".to_string())),
+            Token::Keyword(Keyword::Logical(Logical::And)),
+            Token::Identifier(token::Identifier("café".to_string())),
+            Token::Operator(Operator::Equal),
+            Token::Literal(Literal::Integer(7)),
+        ];
+
+        let actual: Vec<Token> = lexer.collect();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_lexer_case_and_cast() {
+        let lexer =
+            Lexer::new("CASE WHEN TRUE THEN '' ELSE CAST(x AS LONG) END");
+        let expected = vec![
+            Token::Keyword(Keyword::Conditional(Conditional::Case)),
+            Token::Keyword(Keyword::Conditional(Conditional::When)),
+            Token::Literal(Literal::Bool(true)),
+            Token::Keyword(Keyword::Conditional(Conditional::Then)),
+            Token::Literal(Literal::String(String::new())),
+            Token::Keyword(Keyword::Conditional(Conditional::Else)),
+            Token::Keyword(Keyword::Cast),
+            Token::Delimiter(token::Delimiter::LeftParenthesis),
+            Token::Identifier(token::Identifier("x".to_string())),
+            Token::Keyword(Keyword::Preposition(Preposition::As)),
+            Token::DataType(token::DataType::Long),
+            Token::Delimiter(token::Delimiter::RightParenthesis),
+            Token::Keyword(Keyword::Conditional(Conditional::End)),
+        ];
+
+        let actual: Vec<Token> = lexer.collect();
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_lexer_invalid_literal() {
+        let cases = [
+            ("x'zz'", LexError::InvalidLiteral("x'zz'".to_string())),
+            (
+                "DATE '2024-13-45'",
+                LexError::InvalidLiteral("DATE '2024-13-45'".to_string()),
+            ),
+            (
+                "TIME '25:00:00'",
+                LexError::InvalidLiteral("TIME '25:00:00'".to_string()),
+            ),
+            (
+                "UUID '67e55044-bad'",
+                LexError::InvalidLiteral("UUID '67e55044-bad'".to_string()),
+            ),
+            (
+                "'open",
+                LexError::UnterminatedString("'open AND ok".to_string()),
+            ),
+        ];
+
+        for (input, error) in cases {
+            let mut lexer = Lexer::new(format!("id = {input} AND ok"));
+            let actual: Vec<Token> = lexer.by_ref().collect();
+
+            assert_eq!(
+                actual,
+                vec![
+                    Token::Identifier(token::Identifier("id".to_string())),
+                    Token::Operator(Operator::Equal),
+                ]
+            );
+            assert_eq!(lexer.take_error(), Some(error));
+            assert_eq!(lexer.next(), None);
+        }
+    }
 }
@@ -1,3 +1,4 @@
+use backend::schema::r#type::decimal::MAX_PRECISION;
 use derive_more::Display;
 use std::str::FromStr;
 
@@ -37,6 +38,38 @@ pub enum DataType {
 
     /// Represents a variable character.
     VarChar(usize),
+
+    /// Represents a text of any length.
+    Text,
+
+    /// Represents binary data of any length.
+    Blob,
+
+    /// Represents a date.
+    Date,
+
+    /// Represents a time of day.
+    Time,
+
+    /// Represents a timestamp.
+    Timestamp,
+
+    /// Represents an interval.
+    Interval,
+
+    /// Represents a UUID.
+    Uuid,
+
+    /// Represents an auto-incremented integer.
+    Serial,
+
+    /// Represents an auto-incremented long.
+    BigSerial,
+
+    /// Represents a decimal with the total number of digits and the number
+    /// of digits after the decimal point.
+    #[display(fmt = "Decimal({}, {})", _0, _1)]
+    Decimal(u8, u8),
 }
 
 impl FromStr for DataType {
@@ -54,21 +87,68 @@ impl FromStr for DataType {
             "ulong" | "uint16" => Ok(DataType::ULong),
             "float" => Ok(DataType::Float),
             "double" => Ok(DataType::Double),
-            _ => {
-                if s.to_lowercase().starts_with("varchar") {
-                    let size = s[7..s.len()].parse().map_err(|_| ())?;
-                    if size == 0 || size > 255 {
-                        return Err(());
-                    }
-                    Ok(DataType::VarChar(size))
-                } else {
-                    Err(())
+            "text" => Ok(DataType::Text),
+            "blob" | "bytea" => Ok(DataType::Blob),
+            "date" => Ok(DataType::Date),
+            "time" => Ok(DataType::Time),
+            "timestamp" => Ok(DataType::Timestamp),
+            "interval" => Ok(DataType::Interval),
+            "uuid" => Ok(DataType::Uuid),
+            "serial" => Ok(DataType::Serial),
+            "bigserial" => Ok(DataType::BigSerial),
+            lowercase => {
+                if let Some(params) = lowercase
+                    .strip_prefix("decimal")
+                    .or_else(|| lowercase.strip_prefix("numeric"))
+                {
+                    return parse_decimal_params(params);
+                }
+                if let Some(params) = lowercase.strip_prefix("varchar") {
+                    return parse_varchar_params(params);
                 }
+                Err(())
             }
         }
     }
 }
 
+/// Parses the size of a varchar type, either in parentheses, e.g.
+/// `varchar(20)`, or right after the name, e.g. `varchar20`. The size must
+/// be in `1..=255`.
+fn parse_varchar_params(params: &str) -> Result<DataType, ()> {
+    let size = params
+        .strip_prefix('(')
+        .and_then(|params| params.strip_suffix(')'))
+        .unwrap_or(params);
+    let size = size.trim().parse::<usize>().map_err(|_| ())?;
+    if size == 0 || size > 255 {
+        return Err(());
+    }
+
+    Ok(DataType::VarChar(size))
+}
+
+/// Parses the `(precision, scale)` of a decimal type, both are optional.
+/// Precision defaults to [`MAX_PRECISION`] and scale to `0`.
+fn parse_decimal_params(params: &str) -> Result<DataType, ()> {
+    if params.is_empty() {
+        return Ok(DataType::Decimal(MAX_PRECISION, 0));
+    }
+
+    let params = params
+        .strip_prefix('(')
+        .and_then(|params| params.strip_suffix(')'))
+        .ok_or(())?;
+    let (precision, scale) = params.split_once(',').unwrap_or((params, "0"));
+    let precision = precision.trim().parse::<u8>().map_err(|_| ())?;
+    let scale = scale.trim().parse::<u8>().map_err(|_| ())?;
+    if precision == 0 || precision > MAX_PRECISION || scale > precision {
+        return Err(());
+    }
+
+    Ok(DataType::Decimal(precision, scale))
+}
+
 #[cfg(test)]
 mod data_type_tests {
     use super::*;
@@ -103,12 +183,37 @@ mod data_type_tests {
         assert_eq!("double".parse(), Ok(DataType::Double));
 
         assert_eq!("varchar10".parse(), Ok(DataType::VarChar(10)));
+        assert_eq!("VARCHAR(20)".parse(), Ok(DataType::VarChar(20)));
+
+        assert_eq!("text".parse(), Ok(DataType::Text));
+        assert_eq!("blob".parse(), Ok(DataType::Blob));
+        assert_eq!("bytea".parse(), Ok(DataType::Blob));
+
+        assert_eq!("date".parse(), Ok(DataType::Date));
+        assert_eq!("time".parse(), Ok(DataType::Time));
+        assert_eq!("timestamp".parse(), Ok(DataType::Timestamp));
+        assert_eq!("interval".parse(), Ok(DataType::Interval));
+        assert_eq!("UUID".parse(), Ok(DataType::Uuid));
+        assert_eq!("serial".parse(), Ok(DataType::Serial));
+        assert_eq!("BIGSERIAL".parse(), Ok(DataType::BigSerial));
+
+        assert_eq!("decimal(10, 2)".parse(), Ok(DataType::Decimal(10, 2)));
+        assert_eq!("NUMERIC(5)".parse(), Ok(DataType::Decimal(5, 0)));
+        assert_eq!("decimal".parse(), Ok(DataType::Decimal(38, 0)));
 
         assert_eq!("".parse::<DataType>(), Err(()));
+        assert_eq!("decimal(39)".parse::<DataType>(), Err(()));
+        assert_eq!("decimal(2, 3)".parse::<DataType>(), Err(()));
+        assert_eq!("decimal(0)".parse::<DataType>(), Err(()));
+        assert_eq!("decimal(1,".parse::<DataType>(), Err(()));
         assert_eq!("invalid".parse::<DataType>(), Err(()));
         assert_eq!("varchar".parse::<DataType>(), Err(()));
         assert_eq!("varchar0".parse::<DataType>(), Err(()));
         assert_eq!("varchar256".parse::<DataType>(), Err(()));
+        assert_eq!("varchar(0)".parse::<DataType>(), Err(()));
+        assert_eq!("varchar()".parse::<DataType>(), Err(()));
+        assert_eq!("decimal(50, 60)".parse::<DataType>(), Err(()));
+        assert_eq!("decimal(0, 0)".parse::<DataType>(), Err(()));
     }
 
     #[test]
@@ -132,5 +237,7 @@ mod data_type_tests {
         assert_eq!("fLoAt".parse(), Ok(DataType::Float));
         assert_eq!("dOuBlE".parse(), Ok(DataType::Double));
         assert_eq!("vArChAr10".parse(), Ok(DataType::VarChar(10)));
+        assert_eq!("tExT".parse(), Ok(DataType::Text));
+        assert_eq!("bYtEa".parse(), Ok(DataType::Blob));
     }
 }
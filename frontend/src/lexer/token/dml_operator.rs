@@ -30,6 +30,18 @@ pub enum DMLOperator {
     /// Token for `SHOW` statement.
     #[display(fmt = "SHOW")]
     Show,
+
+    /// Token for `VERIFY` statement.
+    #[display(fmt = "VERIFY")]
+    Verify,
+
+    /// Token for `DESCRIBE` statement.
+    #[display(fmt = "DESCRIBE")]
+    Describe,
+
+    /// Token for `ANALYZE` statement.
+    #[display(fmt = "ANALYZE")]
+    Analyze,
 }
 
 impl FromStr for DMLOperator {
@@ -43,6 +55,9 @@ impl FromStr for DMLOperator {
             "drop" => Ok(DMLOperator::Drop),
             "use" => Ok(DMLOperator::Use),
             "show" => Ok(DMLOperator::Show),
+            "verify" => Ok(DMLOperator::Verify),
+            "describe" => Ok(DMLOperator::Describe),
+            "analyze" => Ok(DMLOperator::Analyze),
             _ => Err(()),
         }
     }
@@ -60,6 +75,9 @@ mod dml_operator_tests {
         assert_eq!("DROP".parse(), Ok(DMLOperator::Drop));
         assert_eq!("USE".parse(), Ok(DMLOperator::Use));
         assert_eq!("SHOW".parse(), Ok(DMLOperator::Show));
+        assert_eq!("VERIFY".parse(), Ok(DMLOperator::Verify));
+        assert_eq!("DESCRIBE".parse(), Ok(DMLOperator::Describe));
+        assert_eq!("ANALYZE".parse(), Ok(DMLOperator::Analyze));
 
         assert_eq!("".parse::<DMLOperator>(), Err(()));
         assert_eq!("invalid".parse::<DMLOperator>(), Err(()));
@@ -73,5 +91,7 @@ mod dml_operator_tests {
         assert_eq!("dRoP".parse(), Ok(DMLOperator::Drop));
         assert_eq!("uSe".parse(), Ok(DMLOperator::Use));
         assert_eq!("sHoW".parse(), Ok(DMLOperator::Show));
+        assert_eq!("vErIfY".parse(), Ok(DMLOperator::Verify));
+        assert_eq!("aNaLyZe".parse(), Ok(DMLOperator::Analyze));
     }
 }
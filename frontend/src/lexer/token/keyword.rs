@@ -18,6 +18,40 @@ pub enum Keyword {
 
     /// Token for [`Key`].
     Key(Key),
+
+    /// Token for [`Attribute`].
+    Attribute(Attribute),
+
+    /// Token for [`Action`].
+    Action(Action),
+
+    /// Token for [`Logical`].
+    Logical(Logical),
+
+    /// Token for [`Conditional`].
+    Conditional(Conditional),
+
+    /// Token for [`Clause`].
+    Clause(Clause),
+
+    /// Token for [`Sort`].
+    Sort(Sort),
+
+    /// Token for [`Join`].
+    Join(Join),
+
+    /// Token for `CAST`.
+    #[display(fmt = "CAST")]
+    Cast,
+
+    /// Token for `DISTINCT` of aggregates, e.g. `COUNT(DISTINCT city)`.
+    #[display(fmt = "DISTINCT")]
+    Distinct,
+
+    /// Token for `CONSTRAINT` naming a constraint, e.g.
+    /// `CONSTRAINT adult CHECK (age >= 18)`.
+    #[display(fmt = "CONSTRAINT")]
+    Constraint,
 }
 
 impl FromStr for Keyword {
@@ -40,6 +74,46 @@ impl FromStr for Keyword {
             return Ok(Keyword::Key(key));
         }
 
+        if let Ok(attribute) = s.parse::<Attribute>() {
+            return Ok(Keyword::Attribute(attribute));
+        }
+
+        if let Ok(action) = s.parse::<Action>() {
+            return Ok(Keyword::Action(action));
+        }
+
+        if let Ok(logical) = s.parse::<Logical>() {
+            return Ok(Keyword::Logical(logical));
+        }
+
+        if let Ok(conditional) = s.parse::<Conditional>() {
+            return Ok(Keyword::Conditional(conditional));
+        }
+
+        if let Ok(clause) = s.parse::<Clause>() {
+            return Ok(Keyword::Clause(clause));
+        }
+
+        if let Ok(sort) = s.parse::<Sort>() {
+            return Ok(Keyword::Sort(sort));
+        }
+
+        if let Ok(join) = s.parse::<Join>() {
+            return Ok(Keyword::Join(join));
+        }
+
+        if s.eq_ignore_ascii_case("cast") {
+            return Ok(Keyword::Cast);
+        }
+
+        if s.eq_ignore_ascii_case("distinct") {
+            return Ok(Keyword::Distinct);
+        }
+
+        if s.eq_ignore_ascii_case("constraint") {
+            return Ok(Keyword::Constraint);
+        }
+
         Err(())
     }
 }
@@ -91,6 +165,10 @@ pub enum DBObject {
     /// Token for `COLUMN` object.
     #[display(fmt = "COLUMN")]
     Column,
+
+    /// Token for `SEQUENCE` object.
+    #[display(fmt = "SEQUENCE")]
+    Sequence,
 }
 
 impl FromStr for DBObject {
@@ -102,6 +180,7 @@ impl FromStr for DBObject {
             "schema" => Ok(DBObject::Schema),
             "table" => Ok(DBObject::Table),
             "column" => Ok(DBObject::Column),
+            "sequence" => Ok(DBObject::Sequence),
             _ => Err(()),
         }
     }
@@ -117,6 +196,7 @@ mod dbobject_tests {
         assert_eq!("schema".parse(), Ok(DBObject::Schema));
         assert_eq!("table".parse(), Ok(DBObject::Table));
         assert_eq!("column".parse(), Ok(DBObject::Column));
+        assert_eq!("sequence".parse(), Ok(DBObject::Sequence));
         assert_eq!("".parse::<DBObject>(), Err(()));
         assert_eq!("invalid".parse::<DBObject>(), Err(()));
     }
@@ -201,6 +281,14 @@ pub enum Preposition {
     /// Token for `FROM` preposition.
     #[display(fmt = "FROM")]
     From,
+
+    /// Token for `ON` preposition.
+    #[display(fmt = "ON")]
+    On,
+
+    /// Token for `AS` preposition.
+    #[display(fmt = "AS")]
+    As,
 }
 
 impl FromStr for Preposition {
@@ -211,6 +299,8 @@ impl FromStr for Preposition {
             "in" => Ok(Preposition::In),
             "to" => Ok(Preposition::To),
             "from" => Ok(Preposition::From),
+            "on" => Ok(Preposition::On),
+            "as" => Ok(Preposition::As),
             _ => Err(()),
         }
     }
@@ -225,6 +315,8 @@ mod preposition_tests {
         assert_eq!("in".parse(), Ok(Preposition::In));
         assert_eq!("to".parse(), Ok(Preposition::To));
         assert_eq!("from".parse(), Ok(Preposition::From));
+        assert_eq!("on".parse(), Ok(Preposition::On));
+        assert_eq!("as".parse(), Ok(Preposition::As));
 
         assert_eq!("".parse::<Preposition>(), Err(()));
         assert_eq!("invalid".parse::<Preposition>(), Err(()));
@@ -252,6 +344,10 @@ pub enum Key {
     /// Token for `Key`.
     #[display(fmt = "KEY")]
     Key,
+
+    /// Token for `REFERENCES` of a foreign key.
+    #[display(fmt = "REFERENCES")]
+    References,
 }
 
 impl FromStr for Key {
@@ -262,6 +358,7 @@ impl FromStr for Key {
             "primary" => Ok(Key::Primary),
             "foreign" => Ok(Key::Foreign),
             "key" => Ok(Key::Key),
+            "references" => Ok(Key::References),
             _ => Err(()),
         }
     }
@@ -276,6 +373,7 @@ mod key_tests {
         assert_eq!("primary".parse(), Ok(Key::Primary));
         assert_eq!("foreign".parse(), Ok(Key::Foreign));
         assert_eq!("key".parse(), Ok(Key::Key));
+        assert_eq!("references".parse(), Ok(Key::References));
 
         assert_eq!("".parse::<Key>(), Err(()));
         assert_eq!("invalid".parse::<Key>(), Err(()));
@@ -288,3 +386,393 @@ mod key_tests {
         assert_eq!("kEy".parse(), Ok(Key::Key));
     }
 }
+
+/// Represents a keyword in the SQL language for the column attributes.
+#[derive(Debug, Display, PartialEq, Clone, Copy)]
+pub enum Attribute {
+    /// Token for `AUTO_INCREMENT` attribute.
+    #[display(fmt = "AUTO_INCREMENT")]
+    AutoIncrement,
+
+    /// Token for `UNIQUE` attribute.
+    #[display(fmt = "UNIQUE")]
+    Unique,
+
+    /// Token for `CHECK` attribute.
+    #[display(fmt = "CHECK")]
+    Check,
+
+    /// Token for `DEFAULT` attribute.
+    #[display(fmt = "DEFAULT")]
+    Default,
+}
+
+impl FromStr for Attribute {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "auto_increment" => Ok(Attribute::AutoIncrement),
+            "unique" => Ok(Attribute::Unique),
+            "check" => Ok(Attribute::Check),
+            "default" => Ok(Attribute::Default),
+            _ => Err(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod attribute_tests {
+    use crate::lexer::token::keyword::Attribute;
+
+    #[test]
+    fn test_attribute_from_str() {
+        assert_eq!("auto_increment".parse(), Ok(Attribute::AutoIncrement));
+        assert_eq!("AUTO_Increment".parse(), Ok(Attribute::AutoIncrement));
+        assert_eq!("unique".parse(), Ok(Attribute::Unique));
+        assert_eq!("Check".parse(), Ok(Attribute::Check));
+        assert_eq!("DEFAULT".parse(), Ok(Attribute::Default));
+
+        assert_eq!("".parse::<Attribute>(), Err(()));
+        assert_eq!("autoincrement".parse::<Attribute>(), Err(()));
+    }
+}
+
+/// Represents a keyword in the SQL language for the referential actions,
+/// e.g. of `ON DELETE CASCADE`.
+#[derive(Debug, Display, PartialEq, Clone, Copy)]
+pub enum Action {
+    /// Token for `CASCADE` action.
+    #[display(fmt = "CASCADE")]
+    Cascade,
+
+    /// Token for `RESTRICT` action.
+    #[display(fmt = "RESTRICT")]
+    Restrict,
+
+    /// Token for `SET` of the `SET NULL` action.
+    #[display(fmt = "SET")]
+    Set,
+}
+
+impl FromStr for Action {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "cascade" => Ok(Action::Cascade),
+            "restrict" => Ok(Action::Restrict),
+            "set" => Ok(Action::Set),
+            _ => Err(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod action_tests {
+    use crate::lexer::token::keyword::Action;
+
+    #[test]
+    fn test_action_from_str() {
+        assert_eq!("cascade".parse(), Ok(Action::Cascade));
+        assert_eq!("RESTRICT".parse(), Ok(Action::Restrict));
+        assert_eq!("Set".parse(), Ok(Action::Set));
+
+        assert_eq!("".parse::<Action>(), Err(()));
+        assert_eq!("null".parse::<Action>(), Err(()));
+    }
+}
+
+/// Represents a keyword in the SQL language for the logical operators and
+/// `IS` of `IS [NOT] NULL`.
+#[derive(Debug, Display, PartialEq, Clone, Copy)]
+pub enum Logical {
+    /// Token for `AND` operator.
+    #[display(fmt = "AND")]
+    And,
+
+    /// Token for `OR` operator.
+    #[display(fmt = "OR")]
+    Or,
+
+    /// Token for `NOT` operator.
+    #[display(fmt = "NOT")]
+    Not,
+
+    /// Token for `IS` of `IS [NOT] NULL`.
+    #[display(fmt = "IS")]
+    Is,
+}
+
+impl FromStr for Logical {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "and" => Ok(Logical::And),
+            "or" => Ok(Logical::Or),
+            "not" => Ok(Logical::Not),
+            "is" => Ok(Logical::Is),
+            _ => Err(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod logical_tests {
+    use crate::lexer::token::keyword::{Keyword, Logical};
+
+    #[test]
+    fn test_logical_from_str() {
+        assert_eq!("and".parse(), Ok(Logical::And));
+        assert_eq!("OR".parse(), Ok(Logical::Or));
+        assert_eq!("Not".parse(), Ok(Logical::Not));
+        assert_eq!("is".parse(), Ok(Keyword::Logical(Logical::Is)));
+
+        assert_eq!("".parse::<Logical>(), Err(()));
+        assert_eq!("xor".parse::<Logical>(), Err(()));
+    }
+}
+
+/// Represents a keyword in the SQL language for the parts of `CASE`.
+#[derive(Debug, Display, PartialEq, Clone, Copy)]
+pub enum Conditional {
+    /// Token for `CASE`.
+    #[display(fmt = "CASE")]
+    Case,
+
+    /// Token for `WHEN`.
+    #[display(fmt = "WHEN")]
+    When,
+
+    /// Token for `THEN`.
+    #[display(fmt = "THEN")]
+    Then,
+
+    /// Token for `ELSE`.
+    #[display(fmt = "ELSE")]
+    Else,
+
+    /// Token for `END`.
+    #[display(fmt = "END")]
+    End,
+}
+
+impl FromStr for Conditional {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "case" => Ok(Conditional::Case),
+            "when" => Ok(Conditional::When),
+            "then" => Ok(Conditional::Then),
+            "else" => Ok(Conditional::Else),
+            "end" => Ok(Conditional::End),
+            _ => Err(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod conditional_tests {
+    use crate::lexer::token::keyword::{Conditional, Keyword};
+
+    #[test]
+    fn test_conditional_from_str() {
+        assert_eq!("case".parse(), Ok(Conditional::Case));
+        assert_eq!("WHEN".parse(), Ok(Conditional::When));
+        assert_eq!("Then".parse(), Ok(Conditional::Then));
+        assert_eq!("else".parse(), Ok(Conditional::Else));
+        assert_eq!("end".parse(), Ok(Keyword::Conditional(Conditional::End)));
+        assert_eq!("cast".parse(), Ok(Keyword::Cast));
+        assert_eq!("Distinct".parse(), Ok(Keyword::Distinct));
+        assert_eq!("constraint".parse(), Ok(Keyword::Constraint));
+
+        assert_eq!("".parse::<Conditional>(), Err(()));
+        assert_eq!("if".parse::<Conditional>(), Err(()));
+    }
+}
+
+/// Represents a keyword in the SQL language for the clauses of a query.
+#[derive(Debug, Display, PartialEq, Clone, Copy)]
+pub enum Clause {
+    /// Token for `WHERE`.
+    #[display(fmt = "WHERE")]
+    Where,
+
+    /// Token for `ORDER` of `ORDER BY`.
+    #[display(fmt = "ORDER")]
+    Order,
+
+    /// Token for `BY` of `ORDER BY`.
+    #[display(fmt = "BY")]
+    By,
+
+    /// Token for `GROUP` of `GROUP BY`.
+    #[display(fmt = "GROUP")]
+    Group,
+
+    /// Token for `HAVING`.
+    #[display(fmt = "HAVING")]
+    Having,
+
+    /// Token for `LIMIT`.
+    #[display(fmt = "LIMIT")]
+    Limit,
+
+    /// Token for `OFFSET`.
+    #[display(fmt = "OFFSET")]
+    Offset,
+}
+
+impl FromStr for Clause {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "where" => Ok(Clause::Where),
+            "order" => Ok(Clause::Order),
+            "by" => Ok(Clause::By),
+            "group" => Ok(Clause::Group),
+            "having" => Ok(Clause::Having),
+            "limit" => Ok(Clause::Limit),
+            "offset" => Ok(Clause::Offset),
+            _ => Err(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod clause_tests {
+    use crate::lexer::token::keyword::{Clause, Keyword};
+
+    #[test]
+    fn test_clause_from_str() {
+        assert_eq!("where".parse(), Ok(Clause::Where));
+        assert_eq!("WHERE".parse(), Ok(Keyword::Clause(Clause::Where)));
+        assert_eq!("Order".parse(), Ok(Clause::Order));
+        assert_eq!("by".parse(), Ok(Clause::By));
+        assert_eq!("GROUP".parse(), Ok(Clause::Group));
+        assert_eq!("having".parse(), Ok(Clause::Having));
+        assert_eq!("LIMIT".parse(), Ok(Clause::Limit));
+        assert_eq!("offset".parse(), Ok(Clause::Offset));
+
+        assert_eq!("".parse::<Clause>(), Err(()));
+        assert_eq!("wherever".parse::<Clause>(), Err(()));
+    }
+}
+
+/// Represents a keyword in the SQL language for the sort order of the items
+/// of `ORDER BY`. `FIRST` and `LAST` of `NULLS FIRST|LAST` are not keywords,
+/// so they can still be used as names.
+#[derive(Debug, Display, PartialEq, Clone, Copy)]
+pub enum Sort {
+    /// Token for `ASC`.
+    #[display(fmt = "ASC")]
+    Asc,
+
+    /// Token for `DESC`.
+    #[display(fmt = "DESC")]
+    Desc,
+
+    /// Token for `NULLS` of `NULLS FIRST|LAST`.
+    #[display(fmt = "NULLS")]
+    Nulls,
+}
+
+impl FromStr for Sort {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "asc" => Ok(Sort::Asc),
+            "desc" => Ok(Sort::Desc),
+            "nulls" => Ok(Sort::Nulls),
+            _ => Err(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod sort_tests {
+    use crate::lexer::token::keyword::{Keyword, Sort};
+
+    #[test]
+    fn test_sort_from_str() {
+        assert_eq!("asc".parse(), Ok(Sort::Asc));
+        assert_eq!("DESC".parse(), Ok(Keyword::Sort(Sort::Desc)));
+        assert_eq!("Nulls".parse(), Ok(Sort::Nulls));
+
+        assert_eq!("".parse::<Sort>(), Err(()));
+        assert_eq!("first".parse::<Keyword>(), Err(()));
+    }
+}
+
+/// Represents a keyword in the SQL language for the joins of `FROM`, e.g.
+/// `LEFT OUTER JOIN`.
+#[derive(Debug, Display, PartialEq, Clone, Copy)]
+pub enum Join {
+    /// Token for `JOIN`.
+    #[display(fmt = "JOIN")]
+    Join,
+
+    /// Token for `INNER` of `INNER JOIN`.
+    #[display(fmt = "INNER")]
+    Inner,
+
+    /// Token for `LEFT` of `LEFT JOIN`.
+    #[display(fmt = "LEFT")]
+    Left,
+
+    /// Token for `RIGHT` of `RIGHT JOIN`.
+    #[display(fmt = "RIGHT")]
+    Right,
+
+    /// Token for `FULL` of `FULL JOIN`.
+    #[display(fmt = "FULL")]
+    Full,
+
+    /// Token for `OUTER` of `LEFT|RIGHT|FULL OUTER JOIN`.
+    #[display(fmt = "OUTER")]
+    Outer,
+
+    /// Token for `CROSS` of `CROSS JOIN`.
+    #[display(fmt = "CROSS")]
+    Cross,
+}
+
+impl FromStr for Join {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "join" => Ok(Join::Join),
+            "inner" => Ok(Join::Inner),
+            "left" => Ok(Join::Left),
+            "right" => Ok(Join::Right),
+            "full" => Ok(Join::Full),
+            "outer" => Ok(Join::Outer),
+            "cross" => Ok(Join::Cross),
+            _ => Err(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod join_tests {
+    use crate::lexer::token::keyword::{Join, Keyword};
+
+    #[test]
+    fn test_join_from_str() {
+        assert_eq!("join".parse(), Ok(Join::Join));
+        assert_eq!("INNER".parse(), Ok(Keyword::Join(Join::Inner)));
+        assert_eq!("Left".parse(), Ok(Join::Left));
+        assert_eq!("right".parse(), Ok(Join::Right));
+        assert_eq!("FULL".parse(), Ok(Join::Full));
+        assert_eq!("outer".parse(), Ok(Join::Outer));
+        assert_eq!("cross".parse(), Ok(Join::Cross));
+
+        assert_eq!("".parse::<Join>(), Err(()));
+        assert_eq!("joined".parse::<Join>(), Err(()));
+    }
+}
@@ -0,0 +1,276 @@
+//! Literal token module of the lexer.
+
+use backend::schema::r#type::{
+    data_types::{
+        Blob, Date, Decimal, Integer, Interval, Long, Text, Time, Timestamp,
+        Uuid,
+    },
+    r#enum::StorageData,
+};
+use derive_more::Display;
+use std::str::FromStr;
+
+/// Literal is a token that represents a value written in the source code.
+#[derive(Debug, Display, PartialEq, Clone)]
+pub enum Literal {
+    /// Token for an integer, e.g. `42`.
+    #[display(fmt = "{}", _0)]
+    Integer(i128),
+
+    /// Token for a number with a decimal point, e.g. `1.50`.
+    #[display(fmt = "{}", _0)]
+    Decimal(Decimal),
+
+    /// Token for a string in single quotes, e.g. `'it''s'`.
+    #[display(fmt = "'{}'", "_0.replace('\\'', \"''\")")]
+    String(String),
+
+    /// Token for `TRUE` or `FALSE`.
+    #[display(fmt = "{}", "if *_0 { \"TRUE\" } else { \"FALSE\" }")]
+    Bool(bool),
+
+    /// Token for binary data written as hex digits, e.g. `x'deadbeef'`.
+    #[display(fmt = "x'{}'", "_0.to_hex()")]
+    Blob(Blob),
+
+    /// Token for an ISO-8601 date, e.g. `DATE '2024-01-31'`.
+    #[display(fmt = "DATE '{}'", _0)]
+    Date(Date),
+
+    /// Token for an ISO-8601 time of day, e.g. `TIME '10:00:00'`.
+    #[display(fmt = "TIME '{}'", _0)]
+    Time(Time),
+
+    /// Token for an ISO-8601 timestamp, e.g.
+    /// `TIMESTAMP '2024-01-31T10:00:00'`.
+    #[display(fmt = "TIMESTAMP '{}'", _0)]
+    Timestamp(Timestamp),
+
+    /// Token for an ISO-8601 duration, e.g. `INTERVAL 'P1DT2H'`.
+    #[display(fmt = "INTERVAL '{}'", _0)]
+    Interval(Interval),
+
+    /// Token for a UUID in its canonical text form, e.g.
+    /// `UUID '67e55044-10b1-426f-9247-bb680e5fe0c8'`.
+    #[display(fmt = "UUID '{}'", _0)]
+    Uuid(Uuid),
+
+    /// Token for the current date.
+    #[display(fmt = "CURRENT_DATE")]
+    CurrentDate,
+
+    /// Token for the current time of day.
+    #[display(fmt = "CURRENT_TIME")]
+    CurrentTime,
+
+    /// Token for the current timestamp.
+    #[display(fmt = "NOW()")]
+    CurrentTimestamp,
+
+    /// Token for a random UUID.
+    #[display(fmt = "gen_random_uuid()")]
+    RandomUuid,
+
+    /// Token for the missing value.
+    #[display(fmt = "NULL")]
+    Null,
+}
+
+impl Literal {
+    /// Returns the value of the literal. Integers are `i32` if they fit into
+    /// it and `i128` otherwise, strings are `TEXT`. Values of the current
+    /// date and time, and random UUIDs, are taken when this is called, not
+    /// when the literal is read.
+    pub fn value(&self) -> StorageData {
+        match self {
+            Literal::Integer(value) => match i32::try_from(*value) {
+                Ok(value) => Integer(value).into(),
+                Err(_) => Long(*value).into(),
+            },
+            Literal::Decimal(value) => (*value).into(),
+            Literal::String(value) => Text(value.clone()).into(),
+            Literal::Bool(value) => (*value).into(),
+            Literal::Blob(value) => value.clone().into(),
+            Literal::Date(value) => (*value).into(),
+            Literal::Time(value) => (*value).into(),
+            Literal::Timestamp(value) => (*value).into(),
+            Literal::Interval(value) => (*value).into(),
+            Literal::Uuid(value) => (*value).into(),
+            Literal::CurrentDate => Date::today().into(),
+            Literal::CurrentTime => Time::now().into(),
+            Literal::CurrentTimestamp => Timestamp::now().into(),
+            Literal::RandomUuid => Uuid::new_v4().into(),
+            Literal::Null => StorageData::Null,
+        }
+    }
+}
+
+impl FromStr for Literal {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "current_date" => return Ok(Literal::CurrentDate),
+            "current_time" => return Ok(Literal::CurrentTime),
+            "current_timestamp" | "now()" => {
+                return Ok(Literal::CurrentTimestamp)
+            }
+            "gen_random_uuid()" => return Ok(Literal::RandomUuid),
+            "null" => return Ok(Literal::Null),
+            "true" => return Ok(Literal::Bool(true)),
+            "false" => return Ok(Literal::Bool(false)),
+            _ => {}
+        }
+
+        if s.starts_with(|ch: char| ch.is_ascii_digit() || ch == '.') {
+            if let Ok(value) = s.parse() {
+                return Ok(Literal::Integer(value));
+            }
+            return s.parse().map(Literal::Decimal);
+        }
+
+        let (prefix, value) = s.split_once('\'').ok_or(())?;
+        let value = value.strip_suffix('\'').ok_or(())?;
+        match prefix.trim_end().to_lowercase().as_str() {
+            "x" => Blob::from_hex(value).map(Literal::Blob).ok_or(()),
+            "date" => value.parse().map(Literal::Date),
+            "time" => value.parse().map(Literal::Time),
+            "timestamp" => value.parse().map(Literal::Timestamp),
+            "interval" => value.parse().map(Literal::Interval),
+            "uuid" => value.parse().map(Literal::Uuid),
+            _ => Err(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod literal_tests {
+    use backend::schema::r#type::{
+        data_types::{Blob, Date, Decimal, Interval, Time, Timestamp, Uuid},
+        r#enum::StorageData,
+    };
+
+    use crate::lexer::token::literal::Literal;
+
+    #[test]
+    fn test_literal_from_str() {
+        assert_eq!(
+            "x'deadbeef'".parse(),
+            Ok(Literal::Blob(Blob(vec![0xde, 0xad, 0xbe, 0xef])))
+        );
+        assert_eq!("X'0A'".parse(), Ok(Literal::Blob(Blob(vec![10]))));
+        assert_eq!("x''".parse(), Ok(Literal::Blob(Blob(vec![]))));
+
+        assert_eq!("x'abc'".parse::<Literal>(), Err(()));
+        assert_eq!("x'zz'".parse::<Literal>(), Err(()));
+        assert_eq!("x'ab".parse::<Literal>(), Err(()));
+        assert_eq!("'ab'".parse::<Literal>(), Err(()));
+    }
+
+    #[test]
+    fn test_temporal_literal_from_str() {
+        assert_eq!("DATE '1970-01-02'".parse(), Ok(Literal::Date(Date(1))));
+        assert_eq!(
+            "time'00:00:01'".parse(),
+            Ok(Literal::Time(Time(1_000_000)))
+        );
+        assert_eq!(
+            "TIMESTAMP '1970-01-01T00:00:00.5Z'".parse(),
+            Ok(Literal::Timestamp(Timestamp(500_000)))
+        );
+        assert_eq!(
+            "INTERVAL 'P1M'".parse(),
+            Ok(Literal::Interval(Interval {
+                months: 1,
+                ..Default::default()
+            }))
+        );
+        assert_eq!("Now()".parse(), Ok(Literal::CurrentTimestamp));
+        assert_eq!("CURRENT_DATE".parse(), Ok(Literal::CurrentDate));
+
+        assert_eq!("DATE '2024-13-01'".parse::<Literal>(), Err(()));
+        assert_eq!("INTERVAL '1 day'".parse::<Literal>(), Err(()));
+        assert_eq!("now".parse::<Literal>(), Err(()));
+    }
+
+    #[test]
+    fn test_uuid_literal_from_str() {
+        let text = "67e55044-10b1-426f-9247-bb680e5fe0c8";
+        assert_eq!(
+            format!("uuid '{}'", text).parse(),
+            Ok(Literal::Uuid(text.parse::<Uuid>().unwrap()))
+        );
+        assert_eq!("GEN_RANDOM_UUID()".parse(), Ok(Literal::RandomUuid));
+
+        assert_eq!("UUID '67e55044'".parse::<Literal>(), Err(()));
+        assert_eq!("gen_random_uuid".parse::<Literal>(), Err(()));
+    }
+
+    #[test]
+    fn test_number_and_bool_literal_from_str() {
+        assert_eq!("42".parse(), Ok(Literal::Integer(42)));
+        assert_eq!(
+            "1.50".parse(),
+            Ok(Literal::Decimal(Decimal::new(150, 2).unwrap()))
+        );
+        assert_eq!(
+            ".5".parse(),
+            Ok(Literal::Decimal(Decimal::new(5, 1).unwrap()))
+        );
+        assert_eq!("TRUE".parse(), Ok(Literal::Bool(true)));
+        assert_eq!("false".parse(), Ok(Literal::Bool(false)));
+
+        assert_eq!("1a".parse::<Literal>(), Err(()));
+        assert_eq!("1.2.3".parse::<Literal>(), Err(()));
+        assert_eq!("a1".parse::<Literal>(), Err(()));
+    }
+
+    #[test]
+    fn test_number_string_literal_value() {
+        assert_eq!(Literal::Integer(7).value(), StorageData::Integer(7.into()));
+        assert_eq!(
+            Literal::Integer(1 << 40).value(),
+            StorageData::Long((1 << 40).into())
+        );
+        assert_eq!(
+            Literal::String("it's".to_string()).value(),
+            StorageData::Text("it's".to_string().into())
+        );
+        assert_eq!(Literal::String("it's".to_string()).to_string(), "'it''s'");
+        assert_eq!(Literal::Bool(true).to_string(), "TRUE");
+    }
+
+    #[test]
+    fn test_null_literal() {
+        assert_eq!("NULL".parse(), Ok(Literal::Null));
+        assert_eq!(Literal::Null.value(), StorageData::Null);
+        assert_eq!(Literal::Null.to_string(), "NULL");
+    }
+
+    #[test]
+    fn test_literal_value() {
+        let literal = Literal::Date(Date(1));
+        assert_eq!(literal.value(), StorageData::Date(Date(1)));
+
+        let before = Timestamp::now();
+        let StorageData::Timestamp(now) = Literal::CurrentTimestamp.value()
+        else {
+            panic!("NOW() is a timestamp");
+        };
+        assert!(now >= before);
+
+        let StorageData::Uuid(uuid) = Literal::RandomUuid.value() else {
+            panic!("gen_random_uuid() is a UUID");
+        };
+        assert_ne!(StorageData::Uuid(uuid), Literal::RandomUuid.value());
+    }
+
+    #[test]
+    fn test_literal_display() {
+        let literal = Literal::Blob(Blob(vec![0xde, 0xad]));
+        assert_eq!(literal.to_string(), "x'dead'");
+
+        let literal = Literal::Date(Date(1));
+        assert_eq!(literal.to_string(), "DATE '1970-01-02'");
+    }
+}
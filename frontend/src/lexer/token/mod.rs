@@ -3,6 +3,8 @@ mod ddl_operator;
 mod delimiter;
 mod dml_operator;
 mod keyword;
+mod literal;
+mod operator;
 mod shortcut;
 
 use derive_more::{Display, From};
@@ -11,7 +13,12 @@ pub use data_type::DataType;
 pub use ddl_operator::DDLOperator;
 pub use delimiter::Delimiter;
 pub use dml_operator::DMLOperator;
-pub use keyword::{DBObject, DBObjectMany, Key, Keyword, Preposition};
+pub use keyword::{
+    Action, Attribute, Clause, Conditional, DBObject, DBObjectMany, Join, Key,
+    Keyword, Logical, Preposition, Sort,
+};
+pub use literal::Literal;
+pub use operator::Operator;
 pub use shortcut::Shortcut;
 
 /// Identifier is a token that represents an identifier in the source code.
@@ -47,4 +54,10 @@ pub enum Token {
 
     /// Token for [`DataType`].
     DataType(DataType),
+
+    /// Token for [`Literal`].
+    Literal(Literal),
+
+    /// Token for [`Operator`].
+    Operator(Operator),
 }
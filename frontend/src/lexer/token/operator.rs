@@ -0,0 +1,96 @@
+//! Operator token module of the lexer.
+
+use derive_more::Display;
+use std::str::FromStr;
+
+/// Operator is a token that represents an arithmetic, comparison or string
+/// operator written with symbols.
+#[derive(Debug, Display, PartialEq, Clone, Copy)]
+pub enum Operator {
+    /// Token for `+` operator.
+    #[display(fmt = "+")]
+    Plus,
+
+    /// Token for `-` operator.
+    #[display(fmt = "-")]
+    Minus,
+
+    /// Token for `*` operator.
+    #[display(fmt = "*")]
+    Asterisk,
+
+    /// Token for `/` operator.
+    #[display(fmt = "/")]
+    Slash,
+
+    /// Token for `%` operator.
+    #[display(fmt = "%")]
+    Percent,
+
+    /// Token for `||` operator.
+    #[display(fmt = "||")]
+    Concat,
+
+    /// Token for `=` operator.
+    #[display(fmt = "=")]
+    Equal,
+
+    /// Token for `<>` operator, `!=` is read as it too.
+    #[display(fmt = "<>")]
+    NotEqual,
+
+    /// Token for `<` operator.
+    #[display(fmt = "<")]
+    LessThan,
+
+    /// Token for `<=` operator.
+    #[display(fmt = "<=")]
+    LessThanOrEqual,
+
+    /// Token for `>` operator.
+    #[display(fmt = ">")]
+    GreaterThan,
+
+    /// Token for `>=` operator.
+    #[display(fmt = ">=")]
+    GreaterThanOrEqual,
+}
+
+impl FromStr for Operator {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "+" => Ok(Operator::Plus),
+            "-" => Ok(Operator::Minus),
+            "*" => Ok(Operator::Asterisk),
+            "/" => Ok(Operator::Slash),
+            "%" => Ok(Operator::Percent),
+            "||" => Ok(Operator::Concat),
+            "=" => Ok(Operator::Equal),
+            "<>" | "!=" => Ok(Operator::NotEqual),
+            "<" => Ok(Operator::LessThan),
+            "<=" => Ok(Operator::LessThanOrEqual),
+            ">" => Ok(Operator::GreaterThan),
+            ">=" => Ok(Operator::GreaterThanOrEqual),
+            _ => Err(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod operator_tests {
+    use crate::lexer::token::operator::Operator;
+
+    #[test]
+    fn test_operator_from_str() {
+        assert_eq!("+".parse(), Ok(Operator::Plus));
+        assert_eq!("||".parse(), Ok(Operator::Concat));
+        assert_eq!("<>".parse(), Ok(Operator::NotEqual));
+        assert_eq!("!=".parse(), Ok(Operator::NotEqual));
+        assert_eq!(">=".parse(), Ok(Operator::GreaterThanOrEqual));
+        assert_eq!("".parse::<Operator>(), Err(()));
+        assert_eq!("|".parse::<Operator>(), Err(()));
+        assert_eq!("==".parse::<Operator>(), Err(()));
+    }
+}
@@ -2,16 +2,18 @@ pub mod ast;
 mod parsers;
 pub mod statement;
 
+pub use parsers::{ExpressionParseError, ExpressionParser};
 pub use statement::Statement;
 
 use crate::{
     lexer::{
         token::{Delimiter, Token},
-        Lexer,
+        LexError, Lexer,
     },
     parser::parsers::{
-        DmlParseError, DmlParser, ParenthesisParseError, ParenthesisParser,
-        ShortcutParseError, ShortcutParser,
+        DdlParseError, DdlParser, DmlParseError, DmlParser,
+        ParenthesisParseError, ParenthesisParser, ShortcutParseError,
+        ShortcutParser,
     },
 };
 
@@ -55,7 +57,18 @@ impl Parser {
         }
     }
 
+    /// Parses the next statement, an error of the lexer met on the way
+    /// takes precedence over the result of the statement parser.
     pub fn parse_statement(&mut self) -> Option<Result<Statement, ParseError>> {
+        let statement = self.parse_tokens();
+        match self.lexer.take_error() {
+            Some(error) => Some(Err(ParseError::LexError(error))),
+            None => statement,
+        }
+    }
+
+    /// Parses the next statement from the tokens of the lexer.
+    fn parse_tokens(&mut self) -> Option<Result<Statement, ParseError>> {
         let token = if self.peek_token.is_some() {
             self.peek_token.take()
         } else {
@@ -119,6 +132,16 @@ impl Parser {
                     self.state.clear();
                     Some(statement)
                 }
+                Token::DDL(_) => {
+                    self.state.push(token);
+                    let mut ddl_parser =
+                        DdlParser::new(&mut self.lexer, &mut self.state);
+                    let statement =
+                        ddl_parser.parse().map_err(ParseError::DdlParseError);
+
+                    self.state.clear();
+                    Some(statement)
+                }
                 Token::Shortcut(_) => {
                     self.state.push(token);
                     let mut shortcut_parser =
@@ -157,9 +180,15 @@ pub enum ParseError {
     /// Represents a wrong token provided.
     UnexpectedToken(Token),
 
+    /// Represents a lexer fails.
+    LexError(LexError),
+
     /// Represents a DML parser fails.
     DmlParseError(DmlParseError),
 
+    /// Represents a DDL parser fails.
+    DdlParseError(DdlParseError),
+
     /// Represents a Shortcut parser fails.
     ShortcutParseError(ShortcutParseError),
 
@@ -170,7 +199,10 @@ pub enum ParseError {
 #[cfg(test)]
 mod test {
     use crate::{
-        lexer::token::{DataType, Token},
+        lexer::{
+            token::{DataType, Token},
+            LexError,
+        },
         parser::statement::{
             common::{Column, RenameTo},
             dml::{
@@ -397,6 +429,11 @@ mod test {
                 identifier: "id".to_string().into(),
                 data_type: DataType::Integer,
                 is_primary_key: true,
+                is_auto_increment: false,
+                is_unique: false,
+                references: None,
+                checks: vec![],
+                default: None,
             })
         );
 
@@ -434,6 +471,11 @@ mod test {
                 identifier: "id".to_string().into(),
                 data_type: DataType::Integer,
                 is_primary_key: true,
+                is_auto_increment: false,
+                is_unique: false,
+                references: None,
+                checks: vec![],
+                default: None,
             })
         );
 
@@ -505,6 +547,11 @@ mod test {
                 identifier: "id".to_string().into(),
                 data_type: DataType::Integer,
                 is_primary_key: true,
+                is_auto_increment: false,
+                is_unique: false,
+                references: None,
+                checks: vec![],
+                default: None,
             })
         );
 
@@ -521,6 +568,11 @@ mod test {
                 identifier: "name".to_string().into(),
                 data_type: DataType::VarChar(10),
                 is_primary_key: false,
+                is_auto_increment: false,
+                is_unique: false,
+                references: None,
+                checks: vec![],
+                default: None,
             })
         );
 
@@ -558,10 +610,31 @@ mod test {
                 identifier: "id".to_string().into(),
                 data_type: DataType::Integer,
                 is_primary_key: true,
+                is_auto_increment: false,
+                is_unique: false,
+                references: None,
+                checks: vec![],
+                default: None,
             })
         );
 
         let statement = parser.next();
         assert!(statement.is_none());
     }
+
+    #[test]
+    fn parse_statement_invalid_literal() {
+        let input = "SELECT * FROM t WHERE d = DATE '2024-13-45';";
+
+        let mut parser = Parser::new(input);
+        let statement = parser.next();
+
+        assert_eq!(
+            statement,
+            Some(Err(ParseError::LexError(LexError::InvalidLiteral(
+                "DATE '2024-13-45'".to_string()
+            ))))
+        );
+        assert!(parser.next().is_none());
+    }
 }
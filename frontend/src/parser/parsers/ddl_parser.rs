@@ -0,0 +1,808 @@
+use backend::{
+    controller::table::selector::OrderBy,
+    expression::Expression,
+    query::{Aggregate, JoinType},
+};
+
+use crate::{
+    lexer::{
+        token::{
+            Clause, DDLOperator, Delimiter, Identifier, Join, Keyword, Literal,
+            Operator, Preposition, Sort, Token,
+        },
+        Lexer,
+    },
+    parser::{
+        parsers::{ExpressionParseError, ExpressionParser},
+        statement::ddl::{Select, SelectItem, SelectJoin},
+        Statement,
+    },
+};
+
+/// Represents a DDL parser.
+#[derive(Debug, PartialEq)]
+pub struct DdlParser<'a> {
+    /// Represents the lexer.
+    lexer: &'a mut Lexer,
+
+    /// Represents the state of the parser.
+    state: &'a mut Vec<Token>,
+}
+
+impl<'a> DdlParser<'a> {
+    /// Creates a new DDL parser.
+    pub fn new(lexer: &'a mut Lexer, state: &'a mut Vec<Token>) -> Self {
+        Self { lexer, state }
+    }
+
+    /// Parses a DDL operation.
+    pub fn parse(&mut self) -> Result<Statement, ParseError> {
+        let token = self
+            .state
+            .last()
+            .expect("exist because passed from `Parser`");
+        match token {
+            Token::DDL(DDLOperator::Select) => self.parse_select_statement(),
+            Token::DDL(_) => Err(ParseError::WrongTokenProvided {
+                got: token.clone(),
+                expected: "SELECT".to_string(),
+            }),
+            _ => panic!("Wrong token provided to the DDL parser"),
+        }
+    }
+
+    /// Reads the next token if it is the expected one.
+    /// # Returns
+    /// `true` if the token was read, otherwise `false`.
+    fn next_if(&mut self, expected: Token) -> bool {
+        if self.lexer.clone().next() == Some(expected) {
+            self.lexer.next();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Reads the next token, it must be the expected one.
+    /// # Arguments
+    /// * `expected` - The expected token.
+    fn expect(&mut self, expected: Token) -> Result<(), ParseError> {
+        match self.lexer.next() {
+            Some(token) if token == expected => Ok(()),
+            Some(token) => Err(ParseError::WrongTokenProvided {
+                got: token,
+                expected: expected.to_string(),
+            }),
+            None => Err(ParseError::NotEnoughTokens),
+        }
+    }
+
+    /// Checks that the statement ends, the next token must be `;` if any.
+    fn expect_end(&self) -> Result<(), ParseError> {
+        match self.lexer.clone().next() {
+            None | Some(Token::Delimiter(Delimiter::Semicolon)) => Ok(()),
+            Some(token) => Err(ParseError::WrongTokenProvided {
+                got: token,
+                expected: Token::Delimiter(Delimiter::Semicolon).to_string(),
+            }),
+        }
+    }
+
+    /// Parse `IDENTIFIER` token.
+    fn parse_identifier(&mut self) -> Result<Identifier, ParseError> {
+        match self.lexer.next() {
+            Some(Token::Identifier(identifier)) => Ok(identifier),
+            Some(token) => Err(ParseError::ExpectedIdentifier(token)),
+            None => Err(ParseError::NotEnoughTokens),
+        }
+    }
+
+    /// Parse an expression.
+    fn parse_expression(&mut self) -> Result<Expression, ParseError> {
+        ExpressionParser::new(self.lexer)
+            .parse()
+            .map_err(ParseError::ExpressionParseError)
+    }
+
+    /// Parse an expression that may use aggregates.
+    /// # Arguments
+    /// * `aggregates` - The list the aggregates are added to.
+    fn parse_aggregate_expression(
+        &mut self,
+        aggregates: &mut Vec<Aggregate>,
+    ) -> Result<Expression, ParseError> {
+        ExpressionParser::with_aggregates(self.lexer, aggregates)
+            .parse()
+            .map_err(ParseError::ExpressionParseError)
+    }
+
+    /// Parse a non-negative integer, e.g. of `LIMIT`.
+    fn parse_count(&mut self) -> Result<usize, ParseError> {
+        match self.lexer.next() {
+            Some(Token::Literal(Literal::Integer(value))) => {
+                usize::try_from(value).map_err(|_| {
+                    ParseError::WrongTokenProvided {
+                        got: Token::Literal(Literal::Integer(value)),
+                        expected: "non-negative integer".to_string(),
+                    }
+                })
+            }
+            Some(token) => Err(ParseError::WrongTokenProvided {
+                got: token,
+                expected: "non-negative integer".to_string(),
+            }),
+            None => Err(ParseError::NotEnoughTokens),
+        }
+    }
+
+    /// Parse the alias of a table, as `AS alias` or just `alias`.
+    fn parse_alias(&mut self) -> Result<Option<Identifier>, ParseError> {
+        if self.next_if(Token::Keyword(Keyword::Preposition(Preposition::As))) {
+            return self.parse_identifier().map(Some);
+        }
+
+        match self.lexer.clone().next() {
+            Some(Token::Identifier(_)) => self.parse_identifier().map(Some),
+            _ => Ok(None),
+        }
+    }
+
+    /// Parse `[INNER | LEFT [OUTER] | RIGHT [OUTER] | FULL [OUTER]] JOIN
+    /// ... ON ...` or `CROSS JOIN ...` of `FROM`, if any.
+    fn parse_join(&mut self) -> Result<Option<SelectJoin>, ParseError> {
+        let keyword = match self.lexer.clone().next() {
+            Some(Token::Keyword(Keyword::Join(keyword))) => keyword,
+            _ => return Ok(None),
+        };
+        self.lexer.next();
+
+        let join_type = match keyword {
+            Join::Join | Join::Inner => JoinType::Inner,
+            Join::Left => JoinType::Left,
+            Join::Right => JoinType::Right,
+            Join::Full => JoinType::Full,
+            Join::Cross => JoinType::Cross,
+            Join::Outer => {
+                return Err(ParseError::WrongTokenProvided {
+                    got: join(Join::Outer),
+                    expected: "INNER|LEFT|RIGHT|FULL|CROSS|JOIN".to_string(),
+                })
+            }
+        };
+        if matches!(
+            join_type,
+            JoinType::Left | JoinType::Right | JoinType::Full
+        ) {
+            self.next_if(join(Join::Outer));
+        }
+        if keyword != Join::Join {
+            self.expect(join(Join::Join))?;
+        }
+
+        let identifier = self.parse_identifier()?;
+        let alias = self.parse_alias()?;
+        let condition = if join_type == JoinType::Cross {
+            None
+        } else {
+            self.expect(Token::Keyword(Keyword::Preposition(Preposition::On)))?;
+            Some(self.parse_expression()?)
+        };
+
+        Ok(Some(SelectJoin {
+            join_type,
+            identifier,
+            alias,
+            condition,
+        }))
+    }
+
+    /// Parse `SELECT ... FROM ... [[AS] ...] [... JOIN ...] [WHERE ...]
+    /// [GROUP BY ...] [HAVING ...] [ORDER BY ...] [LIMIT ...] [OFFSET ...]`
+    /// statement.
+    fn parse_select_statement(&mut self) -> Result<Statement, ParseError> {
+        let mut aggregates = vec![];
+        let items = if self.next_if(Token::Operator(Operator::Asterisk)) {
+            None
+        } else {
+            Some(self.parse_select_items(&mut aggregates)?)
+        };
+
+        self.expect(Token::Keyword(Keyword::Preposition(Preposition::From)))?;
+        let identifier = self.parse_identifier()?;
+        let alias = self.parse_alias()?;
+        let join = self.parse_join()?;
+
+        let condition = if self.next_if(clause(Clause::Where)) {
+            Some(self.parse_expression()?)
+        } else {
+            None
+        };
+
+        let mut group_by = vec![];
+        if self.next_if(clause(Clause::Group)) {
+            self.expect(clause(Clause::By))?;
+            loop {
+                group_by.push(self.parse_expression()?);
+                if !self.next_if(Token::Delimiter(Delimiter::Comma)) {
+                    break;
+                }
+            }
+        }
+
+        let having = if self.next_if(clause(Clause::Having)) {
+            Some(self.parse_aggregate_expression(&mut aggregates)?)
+        } else {
+            None
+        };
+
+        let order_by = if self.next_if(clause(Clause::Order)) {
+            self.expect(clause(Clause::By))?;
+            self.parse_order_by_items(&mut aggregates)?
+        } else {
+            vec![]
+        };
+
+        let limit = if self.next_if(clause(Clause::Limit)) {
+            Some(self.parse_count()?)
+        } else {
+            None
+        };
+        let offset = if self.next_if(clause(Clause::Offset)) {
+            Some(self.parse_count()?)
+        } else {
+            None
+        };
+        self.expect_end()?;
+
+        Ok(Select::new_statement(Select {
+            items,
+            identifier,
+            alias,
+            join,
+            condition,
+            group_by,
+            having,
+            aggregates,
+            order_by,
+            limit,
+            offset,
+        }))
+    }
+
+    /// Parse the items of `ORDER BY`, e.g. `age DESC NULLS LAST, id`.
+    /// # Arguments
+    /// * `aggregates` - The list the aggregates of the items are added to.
+    fn parse_order_by_items(
+        &mut self,
+        aggregates: &mut Vec<Aggregate>,
+    ) -> Result<Vec<OrderBy>, ParseError> {
+        let mut items = vec![];
+        loop {
+            let expression = self.parse_aggregate_expression(aggregates)?;
+            let mut item = if self.next_if(sort(Sort::Desc)) {
+                OrderBy::desc(expression)
+            } else {
+                self.next_if(sort(Sort::Asc));
+                OrderBy::asc(expression)
+            };
+
+            if self.next_if(sort(Sort::Nulls)) {
+                // `FIRST` and `LAST` are not keywords.
+                item = match self.lexer.next() {
+                    Some(Token::Identifier(identifier))
+                        if identifier.0.eq_ignore_ascii_case("first") =>
+                    {
+                        item.nulls_first()
+                    }
+                    Some(Token::Identifier(identifier))
+                        if identifier.0.eq_ignore_ascii_case("last") =>
+                    {
+                        item.nulls_last()
+                    }
+                    Some(token) => {
+                        return Err(ParseError::WrongTokenProvided {
+                            got: token,
+                            expected: "FIRST|LAST".to_string(),
+                        })
+                    }
+                    None => return Err(ParseError::NotEnoughTokens),
+                };
+            }
+            items.push(item);
+
+            if !self.next_if(Token::Delimiter(Delimiter::Comma)) {
+                return Ok(items);
+            }
+        }
+    }
+
+    /// Parse the select list, e.g. `id, price * 2 AS twice`.
+    /// # Arguments
+    /// * `aggregates` - The list the aggregates of the items are added to.
+    fn parse_select_items(
+        &mut self,
+        aggregates: &mut Vec<Aggregate>,
+    ) -> Result<Vec<SelectItem>, ParseError> {
+        let mut items = vec![];
+        loop {
+            let expression = self.parse_aggregate_expression(aggregates)?;
+            let alias = if self
+                .next_if(Token::Keyword(Keyword::Preposition(Preposition::As)))
+            {
+                Some(self.parse_identifier()?)
+            } else {
+                None
+            };
+            items.push(SelectItem { expression, alias });
+
+            if !self.next_if(Token::Delimiter(Delimiter::Comma)) {
+                return Ok(items);
+            }
+        }
+    }
+}
+
+/// Returns the token of the clause keyword.
+fn clause(clause: Clause) -> Token {
+    Token::Keyword(Keyword::Clause(clause))
+}
+
+/// Returns the token of the join keyword.
+fn join(join: Join) -> Token {
+    Token::Keyword(Keyword::Join(join))
+}
+
+/// Returns the token of the sort keyword.
+fn sort(sort: Sort) -> Token {
+    Token::Keyword(Keyword::Sort(sort))
+}
+
+/// Error of [`DdlParser`] execution.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseError {
+    /// Wrong token provided to the [`DdlParser`].
+    WrongTokenProvided { got: Token, expected: String },
+
+    /// Not enough tokens got from the [`Lexer`].
+    NotEnoughTokens,
+
+    /// Expected identifier token but got something else.
+    ExpectedIdentifier(Token),
+
+    /// The [`ExpressionParser`] fails.
+    ExpressionParseError(ExpressionParseError),
+}
+
+#[cfg(test)]
+mod test {
+    use backend::{
+        controller::table::selector::OrderBy,
+        expression::{BinaryOperator, Expression},
+        query::{Aggregate, AggregateFunction, JoinType},
+        schema::r#type::r#enum::StorageData,
+    };
+
+    use crate::{
+        lexer::{
+            token::{DDLOperator, Delimiter, Join, Keyword, Operator, Token},
+            Lexer,
+        },
+        parser::{
+            parsers::ExpressionParseError,
+            statement::ddl::{Select, SelectItem, SelectJoin},
+        },
+    };
+
+    use super::{DdlParser, ParseError};
+
+    fn column(name: &str) -> Expression {
+        Expression::Column(name.into())
+    }
+
+    #[test]
+    fn test_select_all_statement() {
+        let mut lexer = Lexer::new("SELECT * FROM test");
+        let mut state = vec![lexer.next().unwrap()];
+        let mut parser = DdlParser::new(&mut lexer, &mut state);
+
+        assert_eq!(
+            parser.parse(),
+            Ok(Select::new_statement(Select {
+                identifier: "test".to_string().into(),
+                ..Default::default()
+            }))
+        );
+        assert_eq!(lexer.next(), None);
+    }
+
+    #[test]
+    fn test_select_join_statement() {
+        let mut lexer = Lexer::new(
+            "SELECT * FROM users AS u LEFT OUTER JOIN orders o \
+             ON u.id = o.user_id;",
+        );
+        let mut state = vec![lexer.next().unwrap()];
+        let mut parser = DdlParser::new(&mut lexer, &mut state);
+
+        assert_eq!(
+            parser.parse(),
+            Ok(Select::new_statement(Select {
+                identifier: "users".to_string().into(),
+                alias: Some("u".to_string().into()),
+                join: Some(SelectJoin {
+                    join_type: JoinType::Left,
+                    identifier: "orders".to_string().into(),
+                    alias: Some("o".to_string().into()),
+                    condition: Some(Expression::binary(
+                        column("u.id"),
+                        BinaryOperator::Equal,
+                        column("o.user_id"),
+                    )),
+                }),
+                ..Default::default()
+            }))
+        );
+
+        for (sql, join_type) in [
+            ("JOIN", JoinType::Inner),
+            ("INNER JOIN", JoinType::Inner),
+            ("RIGHT JOIN", JoinType::Right),
+            ("FULL OUTER JOIN", JoinType::Full),
+        ] {
+            let sql = format!("SELECT * FROM a {} b ON a.id = b.id", sql);
+            let mut lexer = Lexer::new(&sql);
+            let mut state = vec![lexer.next().unwrap()];
+            let mut parser = DdlParser::new(&mut lexer, &mut state);
+
+            assert_eq!(
+                parser.parse(),
+                Ok(Select::new_statement(Select {
+                    identifier: "a".to_string().into(),
+                    join: Some(SelectJoin {
+                        join_type,
+                        identifier: "b".to_string().into(),
+                        alias: None,
+                        condition: Some(Expression::binary(
+                            column("a.id"),
+                            BinaryOperator::Equal,
+                            column("b.id"),
+                        )),
+                    }),
+                    ..Default::default()
+                }))
+            );
+        }
+
+        let mut lexer = Lexer::new("SELECT * FROM a CROSS JOIN b;");
+        let mut state = vec![lexer.next().unwrap()];
+        let mut parser = DdlParser::new(&mut lexer, &mut state);
+        assert_eq!(
+            parser.parse(),
+            Ok(Select::new_statement(Select {
+                identifier: "a".to_string().into(),
+                join: Some(SelectJoin {
+                    join_type: JoinType::Cross,
+                    identifier: "b".to_string().into(),
+                    alias: None,
+                    condition: None,
+                }),
+                ..Default::default()
+            }))
+        );
+    }
+
+    #[test]
+    fn test_select_join_wrong_tokens() {
+        let mut lexer = Lexer::new("SELECT * FROM a JOIN b;");
+        let mut state = vec![lexer.next().unwrap()];
+        let mut parser = DdlParser::new(&mut lexer, &mut state);
+        assert_eq!(
+            parser.parse(),
+            Err(ParseError::WrongTokenProvided {
+                got: Token::Delimiter(Delimiter::Semicolon),
+                expected: "ON".to_string(),
+            })
+        );
+
+        let mut lexer = Lexer::new("SELECT * FROM a LEFT b ON a.id = b.id");
+        let mut state = vec![lexer.next().unwrap()];
+        let mut parser = DdlParser::new(&mut lexer, &mut state);
+        assert_eq!(
+            parser.parse(),
+            Err(ParseError::WrongTokenProvided {
+                got: Token::Identifier("b".to_string().into()),
+                expected: "JOIN".to_string(),
+            })
+        );
+
+        let mut lexer = Lexer::new("SELECT * FROM a OUTER JOIN b");
+        let mut state = vec![lexer.next().unwrap()];
+        let mut parser = DdlParser::new(&mut lexer, &mut state);
+        assert_eq!(
+            parser.parse(),
+            Err(ParseError::WrongTokenProvided {
+                got: Token::Keyword(Keyword::Join(Join::Outer)),
+                expected: "INNER|LEFT|RIGHT|FULL|CROSS|JOIN".to_string(),
+            })
+        );
+
+        let mut lexer =
+            Lexer::new("SELECT * FROM a JOIN b ON a.id = b.id JOIN c ON 1 = 1");
+        let mut state = vec![lexer.next().unwrap()];
+        let mut parser = DdlParser::new(&mut lexer, &mut state);
+        assert_eq!(
+            parser.parse(),
+            Err(ParseError::WrongTokenProvided {
+                got: Token::Keyword(Keyword::Join(Join::Join)),
+                expected: ";".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_select_where_statement() {
+        let mut lexer = Lexer::new(
+            "SELECT id, price * 2 AS twice FROM db.test WHERE id >= 10;",
+        );
+        let mut state = vec![lexer.next().unwrap()];
+        let mut parser = DdlParser::new(&mut lexer, &mut state);
+
+        let twice = Expression::binary(
+            column("price"),
+            BinaryOperator::Multiply,
+            Expression::Literal(StorageData::from(2)),
+        );
+        let condition = Expression::binary(
+            column("id"),
+            BinaryOperator::GreaterThanOrEqual,
+            Expression::Literal(StorageData::from(10)),
+        );
+        assert_eq!(
+            parser.parse(),
+            Ok(Select::new_statement(Select {
+                items: Some(vec![
+                    SelectItem {
+                        expression: column("id"),
+                        alias: None,
+                    },
+                    SelectItem {
+                        expression: twice,
+                        alias: Some("twice".to_string().into()),
+                    },
+                ]),
+                identifier: "db.test".to_string().into(),
+                condition: Some(condition),
+                ..Default::default()
+            }))
+        );
+        assert_eq!(lexer.next(), Some(Token::Delimiter(Delimiter::Semicolon)));
+    }
+
+    #[test]
+    fn test_select_order_by_statement() {
+        let mut lexer = Lexer::new(
+            "SELECT * FROM test ORDER BY age DESC NULLS LAST, \
+             name NULLS first, id ASC LIMIT 10 OFFSET 20",
+        );
+        let mut state = vec![lexer.next().unwrap()];
+        let mut parser = DdlParser::new(&mut lexer, &mut state);
+
+        assert_eq!(
+            parser.parse(),
+            Ok(Select::new_statement(Select {
+                identifier: "test".to_string().into(),
+                order_by: vec![
+                    OrderBy::desc(column("age")).nulls_last(),
+                    OrderBy::asc(column("name")).nulls_first(),
+                    OrderBy::asc(column("id")),
+                ],
+                limit: Some(10),
+                offset: Some(20),
+                ..Default::default()
+            }))
+        );
+
+        let mut lexer = Lexer::new("SELECT * FROM test OFFSET 5");
+        let mut state = vec![lexer.next().unwrap()];
+        let mut parser = DdlParser::new(&mut lexer, &mut state);
+
+        assert_eq!(
+            parser.parse(),
+            Ok(Select::new_statement(Select {
+                identifier: "test".to_string().into(),
+                offset: Some(5),
+                ..Default::default()
+            }))
+        );
+    }
+
+    #[test]
+    fn test_select_group_by_statement() {
+        let mut lexer = Lexer::new(
+            "SELECT city, COUNT(*) AS n, SUM(DISTINCT price) FROM test \
+             WHERE price > 0 GROUP BY city, price > 10 \
+             HAVING COUNT(*) > 1 ORDER BY MAX(price) DESC",
+        );
+        let mut state = vec![lexer.next().unwrap()];
+        let mut parser = DdlParser::new(&mut lexer, &mut state);
+
+        let positive = Expression::binary(
+            column("price"),
+            BinaryOperator::GreaterThan,
+            Expression::Literal(StorageData::from(0)),
+        );
+        let expensive = Expression::binary(
+            column("price"),
+            BinaryOperator::GreaterThan,
+            Expression::Literal(StorageData::from(10)),
+        );
+        let having = Expression::binary(
+            column("COUNT(*)"),
+            BinaryOperator::GreaterThan,
+            Expression::Literal(StorageData::from(1)),
+        );
+        assert_eq!(
+            parser.parse(),
+            Ok(Select::new_statement(Select {
+                items: Some(vec![
+                    SelectItem {
+                        expression: column("city"),
+                        alias: None,
+                    },
+                    SelectItem {
+                        expression: column("COUNT(*)"),
+                        alias: Some("n".to_string().into()),
+                    },
+                    SelectItem {
+                        expression: column("SUM(DISTINCT price)"),
+                        alias: None,
+                    },
+                ]),
+                identifier: "test".to_string().into(),
+                condition: Some(positive),
+                group_by: vec![column("city"), expensive],
+                having: Some(having),
+                aggregates: vec![
+                    Aggregate::count_all(),
+                    Aggregate::new(AggregateFunction::Sum, column("price"))
+                        .distinct(),
+                    Aggregate::new(AggregateFunction::Max, column("price")),
+                ],
+                order_by: vec![OrderBy::desc(column("MAX(price)"))],
+                ..Default::default()
+            }))
+        );
+    }
+
+    #[test]
+    fn test_select_group_by_wrong_tokens() {
+        let mut lexer = Lexer::new("SELECT * FROM test GROUP id");
+        let mut state = vec![lexer.next().unwrap()];
+        let mut parser = DdlParser::new(&mut lexer, &mut state);
+        assert_eq!(
+            parser.parse(),
+            Err(ParseError::WrongTokenProvided {
+                got: Token::Identifier("id".to_string().into()),
+                expected: "BY".to_string(),
+            })
+        );
+
+        let mut lexer = Lexer::new("SELECT * FROM test WHERE COUNT(*) > 1");
+        let mut state = vec![lexer.next().unwrap()];
+        let mut parser = DdlParser::new(&mut lexer, &mut state);
+        assert_eq!(
+            parser.parse(),
+            Err(ParseError::ExpressionParseError(
+                ExpressionParseError::AggregateNotAllowed(
+                    AggregateFunction::Count
+                )
+            ))
+        );
+
+        let mut lexer = Lexer::new("SELECT * FROM test GROUP BY SUM(id)");
+        let mut state = vec![lexer.next().unwrap()];
+        let mut parser = DdlParser::new(&mut lexer, &mut state);
+        assert_eq!(
+            parser.parse(),
+            Err(ParseError::ExpressionParseError(
+                ExpressionParseError::AggregateNotAllowed(
+                    AggregateFunction::Sum
+                )
+            ))
+        );
+    }
+
+    #[test]
+    fn test_select_order_by_wrong_tokens() {
+        let mut lexer = Lexer::new("SELECT * FROM test ORDER id");
+        let mut state = vec![lexer.next().unwrap()];
+        let mut parser = DdlParser::new(&mut lexer, &mut state);
+        assert_eq!(
+            parser.parse(),
+            Err(ParseError::WrongTokenProvided {
+                got: Token::Identifier("id".to_string().into()),
+                expected: "BY".to_string(),
+            })
+        );
+
+        let mut lexer = Lexer::new("SELECT * FROM test ORDER BY id NULLS id");
+        let mut state = vec![lexer.next().unwrap()];
+        let mut parser = DdlParser::new(&mut lexer, &mut state);
+        assert_eq!(
+            parser.parse(),
+            Err(ParseError::WrongTokenProvided {
+                got: Token::Identifier("id".to_string().into()),
+                expected: "FIRST|LAST".to_string(),
+            })
+        );
+
+        let mut lexer = Lexer::new("SELECT * FROM test LIMIT -1");
+        let mut state = vec![lexer.next().unwrap()];
+        let mut parser = DdlParser::new(&mut lexer, &mut state);
+        assert_eq!(
+            parser.parse(),
+            Err(ParseError::WrongTokenProvided {
+                got: Token::Operator(Operator::Minus),
+                expected: "non-negative integer".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_select_wrong_tokens() {
+        let mut lexer = Lexer::new("SELECT id test");
+        let mut state = vec![lexer.next().unwrap()];
+        let mut parser = DdlParser::new(&mut lexer, &mut state);
+        assert_eq!(
+            parser.parse(),
+            Err(ParseError::WrongTokenProvided {
+                got: Token::Identifier("test".to_string().into()),
+                expected: "FROM".to_string(),
+            })
+        );
+
+        let mut lexer = Lexer::new("SELECT * FROM test WHERE");
+        let mut state = vec![lexer.next().unwrap()];
+        let mut parser = DdlParser::new(&mut lexer, &mut state);
+        assert_eq!(
+            parser.parse(),
+            Err(ParseError::ExpressionParseError(
+                ExpressionParseError::NotEnoughTokens
+            ))
+        );
+
+        let mut lexer = Lexer::new("SELECT * FROM t, u;");
+        let mut state = vec![lexer.next().unwrap()];
+        let mut parser = DdlParser::new(&mut lexer, &mut state);
+        assert_eq!(
+            parser.parse(),
+            Err(ParseError::WrongTokenProvided {
+                got: Token::Delimiter(Delimiter::Comma),
+                expected: ";".to_string(),
+            })
+        );
+
+        let mut lexer = Lexer::new("SELECT COUNT(COUNT(a)) FROM t;");
+        let mut state = vec![lexer.next().unwrap()];
+        let mut parser = DdlParser::new(&mut lexer, &mut state);
+        assert_eq!(
+            parser.parse(),
+            Err(ParseError::ExpressionParseError(
+                ExpressionParseError::AggregateNotAllowed(
+                    AggregateFunction::Count
+                )
+            ))
+        );
+
+        let mut lexer = Lexer::new("INSERT INTO test");
+        let mut state = vec![lexer.next().unwrap()];
+        let mut parser = DdlParser::new(&mut lexer, &mut state);
+        assert_eq!(
+            parser.parse(),
+            Err(ParseError::WrongTokenProvided {
+                got: Token::DDL(DDLOperator::Insert),
+                expected: "SELECT".to_string(),
+            })
+        );
+    }
+}
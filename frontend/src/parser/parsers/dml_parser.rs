@@ -1,11 +1,12 @@
 use crate::{
-    alter_schema_statement_variant, create_database_statement_variant,
-    create_schema_statement_variant, create_table_statement_variant,
-    drop_database_statement_variant, drop_schema_statement_variant,
-    drop_table_statement_variant,
+    alter_schema_statement_variant, analyze_table_statement_variant,
+    create_database_statement_variant, create_schema_statement_variant,
+    create_sequence_statement_variant, create_table_statement_variant,
+    describe_table_statement_variant, drop_database_statement_variant,
+    drop_schema_statement_variant, drop_table_statement_variant,
     lexer::{
         token::{
-            DBObject, DBObjectMany, DMLOperator, Identifier, Keyword,
+            Action, DBObject, DBObjectMany, DMLOperator, Identifier, Keyword,
             Preposition, Token,
         },
         Lexer,
@@ -13,7 +14,7 @@ use crate::{
     parser::Statement,
     rename_to_statement_variant, show_databases_statement_variant,
     show_schemas_statement_variant, use_database_statement_variant,
-    use_schema_statement_variant,
+    use_schema_statement_variant, verify_database_statement_variant,
 };
 
 /// Represents a DML parser.
@@ -46,6 +47,9 @@ impl<'a> DmlParser<'a> {
                 DMLOperator::Drop => self.parse_drop_statement(),
                 DMLOperator::Use => self.parse_use_statement(),
                 DMLOperator::Show => self.parse_show_statement(),
+                DMLOperator::Verify => self.parse_verify_statement(),
+                DMLOperator::Describe => self.parse_describe_statement(),
+                DMLOperator::Analyze => self.parse_analyze_statement(),
             }
         } else {
             panic!("Wrong token provided to the DML parser")
@@ -104,15 +108,25 @@ impl<'a> DmlParser<'a> {
                             .try_into()
                             .expect("valid tokens")))
                     }
+                    DBObject::Sequence => {
+                        self.state.push(which_object);
+                        self.state.push(identifier?.into());
+
+                        Ok(create_sequence_statement_variant!(self
+                            .state
+                            .as_slice()
+                            .try_into()
+                            .expect("valid tokens")))
+                    }
                     DBObject::Column => Err(ParseError::WrongTokenProvided {
                         got: which_object,
-                        expected: "DATABASE|SCHEMA|TABLE".to_string(),
+                        expected: "DATABASE|SCHEMA|TABLE|SEQUENCE".to_string(),
                     }),
                 }
             } else {
                 Err(ParseError::WrongTokenProvided {
                     got: which_object,
-                    expected: "DATABASE|SCHEMA|TABLE".to_string(),
+                    expected: "DATABASE|SCHEMA|TABLE|SEQUENCE".to_string(),
                 })
             }
         } else {
@@ -152,16 +166,28 @@ impl<'a> DmlParser<'a> {
                         self.state.push(which_object);
                         self.state.push(identifier?.into());
 
+                        let mut lookahead = self.lexer.clone();
+                        if let Some(Token::Keyword(Keyword::Action(
+                            Action::Cascade,
+                        ))) = lookahead.next()
+                        {
+                            *self.lexer = lookahead;
+                            self.state
+                                .push(Keyword::Action(Action::Cascade).into());
+                        }
+
                         Ok(drop_table_statement_variant!(self
                             .state
                             .as_slice()
                             .try_into()
                             .expect("valid tokens")))
                     }
-                    DBObject::Column => Err(ParseError::WrongTokenProvided {
-                        got: which_object,
-                        expected: "DATABASE|SCHEMA|TABLE".to_string(),
-                    }),
+                    DBObject::Column | DBObject::Sequence => {
+                        Err(ParseError::WrongTokenProvided {
+                            got: which_object,
+                            expected: "DATABASE|SCHEMA|TABLE".to_string(),
+                        })
+                    }
                 }
             } else {
                 Err(ParseError::WrongTokenProvided {
@@ -202,10 +228,12 @@ impl<'a> DmlParser<'a> {
 
                         todo!("Add alter table support")
                     }
-                    DBObject::Column => Err(ParseError::WrongTokenProvided {
-                        got: which_object,
-                        expected: "SCHEMA|TABLE".to_string(),
-                    }),
+                    DBObject::Column | DBObject::Sequence => {
+                        Err(ParseError::WrongTokenProvided {
+                            got: which_object,
+                            expected: "SCHEMA|TABLE".to_string(),
+                        })
+                    }
                 }
             } else {
                 Err(ParseError::WrongTokenProvided {
@@ -275,10 +303,12 @@ impl<'a> DmlParser<'a> {
                         got: which_object,
                         expected: "DATABASE|SCHEMA".to_string(),
                     }),
-                    DBObject::Column => Err(ParseError::WrongTokenProvided {
-                        got: which_object,
-                        expected: "DATABASE|SCHEMA".to_string(),
-                    }),
+                    DBObject::Column | DBObject::Sequence => {
+                        Err(ParseError::WrongTokenProvided {
+                            got: which_object,
+                            expected: "DATABASE|SCHEMA".to_string(),
+                        })
+                    }
                 }
             } else {
                 Err(ParseError::WrongTokenProvided {
@@ -352,6 +382,88 @@ impl<'a> DmlParser<'a> {
             Err(ParseError::NotEnoughTokens)
         }
     }
+
+    /// Parse `VERIFY DATABASE ...` statement.
+    fn parse_verify_statement(&mut self) -> Result<Statement, ParseError> {
+        let which_object = self.lexer.next();
+        let identifier = self.parse_identifier();
+
+        if let Some(which_object) = which_object {
+            if let Token::Keyword(Keyword::DbObject(DBObject::Database)) =
+                which_object
+            {
+                self.state.push(which_object);
+                self.state.push(identifier?.into());
+
+                Ok(verify_database_statement_variant!(self
+                    .state
+                    .as_slice()
+                    .try_into()
+                    .expect("valid tokens")))
+            } else {
+                Err(ParseError::WrongTokenProvided {
+                    got: which_object,
+                    expected: "DATABASE".to_string(),
+                })
+            }
+        } else {
+            Err(ParseError::NotEnoughTokens)
+        }
+    }
+}
+
+impl DmlParser<'_> {
+    /// Parse `DESCRIBE [TABLE] ...` statement.
+    fn parse_describe_statement(&mut self) -> Result<Statement, ParseError> {
+        let which_object =
+            self.lexer.next().ok_or(ParseError::NotEnoughTokens)?;
+        match which_object {
+            Token::Keyword(Keyword::DbObject(DBObject::Table)) => {
+                self.state.push(which_object);
+                let identifier = self.parse_identifier()?;
+                self.state.push(identifier.into());
+            }
+            Token::Identifier(_) => self.state.push(which_object),
+            _ => {
+                return Err(ParseError::WrongTokenProvided {
+                    got: which_object,
+                    expected: "TABLE|IDENTIFIER".to_string(),
+                })
+            }
+        }
+
+        Ok(describe_table_statement_variant!(self
+            .state
+            .as_slice()
+            .try_into()
+            .expect("valid tokens")))
+    }
+
+    /// Parse `ANALYZE [TABLE] ...` statement.
+    fn parse_analyze_statement(&mut self) -> Result<Statement, ParseError> {
+        let which_object =
+            self.lexer.next().ok_or(ParseError::NotEnoughTokens)?;
+        match which_object {
+            Token::Keyword(Keyword::DbObject(DBObject::Table)) => {
+                self.state.push(which_object);
+                let identifier = self.parse_identifier()?;
+                self.state.push(identifier.into());
+            }
+            Token::Identifier(_) => self.state.push(which_object),
+            _ => {
+                return Err(ParseError::WrongTokenProvided {
+                    got: which_object,
+                    expected: "TABLE|IDENTIFIER".to_string(),
+                })
+            }
+        }
+
+        Ok(analyze_table_statement_variant!(self
+            .state
+            .as_slice()
+            .try_into()
+            .expect("valid tokens")))
+    }
 }
 
 /// Error of [`DmlParser`] execution.
@@ -371,12 +483,15 @@ pub enum ParseError {
 mod test {
     use crate::{
         lexer::{
-            token::{Identifier, Keyword, Preposition, Token},
+            token::{
+                DBObject, Delimiter, Identifier, Keyword, Preposition, Token,
+            },
             Lexer,
         },
         parser::statement::dml::{
-            AlterSchema, CreateDatabase, CreateSchema, CreateTable,
-            DropDatabase, DropSchema, DropTable, ShowDatabases, ShowSchemas,
+            AlterSchema, CreateDatabase, CreateSchema, CreateSequence,
+            CreateTable, DropDatabase, DropSchema, DropTable, ShowDatabases,
+            ShowSchemas, VerifyDatabase,
         },
     };
 
@@ -404,7 +519,7 @@ mod test {
             statement,
             Err(ParseError::WrongTokenProvided {
                 got: Token::Keyword(Keyword::Preposition(Preposition::To)),
-                expected: "DATABASE|SCHEMA|TABLE".to_string()
+                expected: "DATABASE|SCHEMA|TABLE|SEQUENCE".to_string()
             })
         );
     }
@@ -439,6 +554,20 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_create_sequence_statement() {
+        let mut lexer = Lexer::new("CREATE SEQUENCE ids");
+        let mut state = vec![lexer.next().unwrap()];
+        let mut parser = DmlParser::new(&mut lexer, &mut state);
+
+        let statement = parser.parse();
+
+        assert_eq!(
+            statement,
+            Ok(CreateSequence::new_statement(Identifier("ids".to_string())))
+        );
+    }
+
     #[test]
     fn test_create_schema_statement() {
         let mut lexer = Lexer::new("CREATE SCHEMA test");
@@ -467,6 +596,39 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_verify_database_statement() {
+        let mut lexer = Lexer::new("VERIFY DATABASE test");
+        let mut state = vec![lexer.next().unwrap()];
+        let mut parser = DmlParser::new(&mut lexer, &mut state);
+
+        let statement = parser.parse();
+
+        assert_eq!(
+            statement,
+            Ok(VerifyDatabase::new_statement(Identifier(
+                "test".to_string()
+            )))
+        );
+    }
+
+    #[test]
+    fn test_verify_schema_statement() {
+        let mut lexer = Lexer::new("VERIFY SCHEMA test");
+        let mut state = vec![lexer.next().unwrap()];
+        let mut parser = DmlParser::new(&mut lexer, &mut state);
+
+        let statement = parser.parse();
+
+        assert_eq!(
+            statement,
+            Err(ParseError::WrongTokenProvided {
+                got: Token::Keyword(Keyword::DbObject(DBObject::Schema)),
+                expected: "DATABASE".to_string()
+            })
+        );
+    }
+
     #[test]
     fn test_drop_schema_statement() {
         let mut lexer = Lexer::new("DROP SCHEMA test");
@@ -491,8 +653,26 @@ mod test {
 
         assert_eq!(
             statement,
-            Ok(DropTable::new_statement(Identifier("test".to_string())))
+            Ok(DropTable::new_statement(
+                Identifier("test".to_string()),
+                false
+            ))
+        );
+
+        let mut lexer = Lexer::new("DROP TABLE test CASCADE;");
+        let mut state = vec![lexer.next().unwrap()];
+        let mut parser = DmlParser::new(&mut lexer, &mut state);
+
+        let statement = parser.parse();
+
+        assert_eq!(
+            statement,
+            Ok(DropTable::new_statement(
+                Identifier("test".to_string()),
+                true
+            ))
         );
+        assert_eq!(lexer.next(), Some(Token::Delimiter(Delimiter::Semicolon)));
     }
 
     #[test]
@@ -0,0 +1,664 @@
+use backend::{
+    expression::{
+        BinaryOperator, Expression, Function, UnaryOperator, IS_PRECEDENCE,
+    },
+    query::{Aggregate, AggregateFunction},
+};
+
+use crate::lexer::{
+    token::{
+        Conditional, Delimiter, Keyword, Literal, Logical, Operator,
+        Preposition, Token,
+    },
+    Lexer,
+};
+
+/// Represents a parser of scalar expressions, e.g. of `WHERE` conditions.
+/// It uses precedence climbing: an operand is parsed with the lowest
+/// precedence of the operators it may contain, so that tighter operators
+/// are parsed first.
+#[derive(Debug, PartialEq)]
+pub struct ExpressionParser<'a> {
+    /// Represents the lexer.
+    lexer: &'a mut Lexer,
+
+    /// The aggregates of the parsed expressions, `None` if aggregates are
+    /// not allowed.
+    aggregates: Option<&'a mut Vec<Aggregate>>,
+}
+
+impl<'a> ExpressionParser<'a> {
+    /// Creates a new Expression parser.
+    pub fn new(lexer: &'a mut Lexer) -> Self {
+        Self {
+            lexer,
+            aggregates: None,
+        }
+    }
+
+    /// Creates a new Expression parser that allows aggregates, e.g.
+    /// `COUNT(*)`. An aggregate is added to the list, unless it's already
+    /// there, and parsed as the column of its result, which is named as the
+    /// aggregate is written in SQL.
+    /// # Arguments
+    /// * `lexer` - The lexer.
+    /// * `aggregates` - The list of aggregates.
+    pub fn with_aggregates(
+        lexer: &'a mut Lexer,
+        aggregates: &'a mut Vec<Aggregate>,
+    ) -> Self {
+        Self {
+            lexer,
+            aggregates: Some(aggregates),
+        }
+    }
+
+    /// Parses an expression. Parsing stops before the first token that
+    /// can't continue the expression, e.g. `,` or `FROM`, so it is left in
+    /// the lexer.
+    pub fn parse(&mut self) -> Result<Expression, ParseError> {
+        self.parse_expression(0)
+    }
+
+    /// Returns the next token without reading it.
+    fn peek(&self) -> Option<Token> {
+        self.lexer.clone().next()
+    }
+
+    /// Reads the next token.
+    fn next(&mut self) -> Result<Token, ParseError> {
+        self.lexer.next().ok_or(ParseError::NotEnoughTokens)
+    }
+
+    /// Reads the next token if it is the expected one.
+    fn expect(&mut self, expected: Token) -> Result<(), ParseError> {
+        let token = self.next()?;
+        if token == expected {
+            Ok(())
+        } else {
+            Err(ParseError::WrongTokenProvided {
+                got: token,
+                expected: format!("`{}`", expected),
+            })
+        }
+    }
+
+    /// Parses an expression of operators binding at least as tight as the
+    /// given precedence.
+    fn parse_expression(
+        &mut self,
+        min_precedence: u8,
+    ) -> Result<Expression, ParseError> {
+        let mut left = self.parse_operand()?;
+
+        while let Some(token) = self.peek() {
+            if token == Token::Keyword(Keyword::Logical(Logical::Is)) {
+                if IS_PRECEDENCE < min_precedence {
+                    break;
+                }
+                self.next()?;
+                left = self.parse_is_null(left)?;
+                continue;
+            }
+
+            let Some(operator) = binary_operator(&token) else {
+                break;
+            };
+            if operator.precedence() < min_precedence {
+                break;
+            }
+            self.next()?;
+
+            // Operators are left-associative, so the right operand may only
+            // contain tighter ones.
+            let right = self.parse_expression(operator.precedence() + 1)?;
+            left = Expression::binary(left, operator, right);
+        }
+
+        Ok(left)
+    }
+
+    /// Parses an operand of a binary operator, e.g. a literal, a column, an
+    /// expression in parentheses or one with a unary operator.
+    fn parse_operand(&mut self) -> Result<Expression, ParseError> {
+        let token = self.next()?;
+        match token {
+            Token::Literal(literal) => Ok(Expression::Literal(literal.value())),
+            Token::Identifier(identifier) => {
+                if self.peek()
+                    == Some(Token::Delimiter(Delimiter::LeftParenthesis))
+                {
+                    if let Ok(function) =
+                        identifier.0.parse::<AggregateFunction>()
+                    {
+                        return self.parse_aggregate(function);
+                    }
+                    let function = identifier
+                        .0
+                        .parse::<Function>()
+                        .map_err(|_| ParseError::UnknownFunction(identifier))?;
+                    self.parse_call(function)
+                } else {
+                    Ok(Expression::Column(identifier.0.into()))
+                }
+            }
+            Token::Delimiter(Delimiter::LeftParenthesis) => {
+                let expression = self.parse_expression(0)?;
+                self.expect(Token::Delimiter(Delimiter::RightParenthesis))?;
+                Ok(expression)
+            }
+            Token::Operator(Operator::Minus) => {
+                let operand =
+                    self.parse_expression(UnaryOperator::Minus.precedence())?;
+                Ok(negate(operand))
+            }
+            Token::Operator(Operator::Plus) => {
+                self.parse_expression(UnaryOperator::Minus.precedence())
+            }
+            Token::Keyword(Keyword::Logical(Logical::Not)) => {
+                let operand =
+                    self.parse_expression(UnaryOperator::Not.precedence())?;
+                Ok(Expression::unary(UnaryOperator::Not, operand))
+            }
+            Token::Keyword(Keyword::Conditional(Conditional::Case)) => {
+                self.parse_case()
+            }
+            Token::Keyword(Keyword::Cast) => self.parse_cast(),
+            token => Err(ParseError::UnexpectedToken(token)),
+        }
+    }
+
+    /// Parses the rest of `IS [NOT] NULL`, `IS` is already read.
+    fn parse_is_null(
+        &mut self,
+        operand: Expression,
+    ) -> Result<Expression, ParseError> {
+        let mut token = self.next()?;
+        let negated = token == Token::Keyword(Keyword::Logical(Logical::Not));
+        if negated {
+            token = self.next()?;
+        }
+        if token != Token::Literal(Literal::Null) {
+            return Err(ParseError::WrongTokenProvided {
+                got: token,
+                expected: "`NULL`".to_string(),
+            });
+        }
+
+        Ok(Expression::IsNull {
+            operand: Box::new(operand),
+            negated,
+        })
+    }
+
+    /// Parses the arguments of a function call in parentheses.
+    fn parse_call(
+        &mut self,
+        function: Function,
+    ) -> Result<Expression, ParseError> {
+        self.expect(Token::Delimiter(Delimiter::LeftParenthesis))?;
+
+        let mut arguments = Vec::new();
+        if self.peek() == Some(Token::Delimiter(Delimiter::RightParenthesis)) {
+            self.next()?;
+        } else {
+            loop {
+                arguments.push(self.parse_expression(0)?);
+                match self.next()? {
+                    Token::Delimiter(Delimiter::Comma) => {}
+                    Token::Delimiter(Delimiter::RightParenthesis) => break,
+                    token => {
+                        return Err(ParseError::WrongTokenProvided {
+                            got: token,
+                            expected: "`,` or `)`".to_string(),
+                        })
+                    }
+                }
+            }
+        }
+
+        if !function.accepts_arguments(arguments.len()) {
+            return Err(ParseError::WrongArgumentCount(
+                function,
+                arguments.len(),
+            ));
+        }
+
+        Ok(Expression::Function {
+            function,
+            arguments,
+        })
+    }
+
+    /// Parses an aggregate call in parentheses, e.g. `COUNT(DISTINCT a)`.
+    /// Aggregates can't be nested, so the argument is parsed without them.
+    fn parse_aggregate(
+        &mut self,
+        function: AggregateFunction,
+    ) -> Result<Expression, ParseError> {
+        let Some(aggregates) = self.aggregates.take() else {
+            return Err(ParseError::AggregateNotAllowed(function));
+        };
+        let column = self.parse_aggregate_call(function).map(|aggregate| {
+            let name = aggregate.name();
+            if !aggregates.contains(&aggregate) {
+                aggregates.push(aggregate);
+            }
+            Expression::Column(name)
+        });
+        self.aggregates = Some(aggregates);
+
+        column
+    }
+
+    /// Parses the arguments of an aggregate in parentheses.
+    fn parse_aggregate_call(
+        &mut self,
+        function: AggregateFunction,
+    ) -> Result<Aggregate, ParseError> {
+        self.expect(Token::Delimiter(Delimiter::LeftParenthesis))?;
+        let distinct = self.peek() == Some(Token::Keyword(Keyword::Distinct));
+        if distinct {
+            self.next()?;
+        }
+
+        let aggregate = if function == AggregateFunction::Count
+            && !distinct
+            && self.peek() == Some(Token::Operator(Operator::Asterisk))
+        {
+            self.next()?;
+            Aggregate::count_all()
+        } else {
+            Aggregate::new(function, self.parse_expression(0)?)
+        };
+        self.expect(Token::Delimiter(Delimiter::RightParenthesis))?;
+
+        Ok(if distinct {
+            aggregate.distinct()
+        } else {
+            aggregate
+        })
+    }
+
+    /// Parses the rest of `CASE [operand] WHEN .. THEN .. [ELSE ..] END`,
+    /// `CASE` is already read.
+    fn parse_case(&mut self) -> Result<Expression, ParseError> {
+        let when = Token::Keyword(Keyword::Conditional(Conditional::When));
+        let operand = if self.peek().as_ref() == Some(&when) {
+            None
+        } else {
+            Some(Box::new(self.parse_expression(0)?))
+        };
+
+        let mut branches = Vec::new();
+        let mut otherwise = None;
+        loop {
+            let token = self.next()?;
+            match token {
+                Token::Keyword(Keyword::Conditional(Conditional::When)) => {
+                    let condition = self.parse_expression(0)?;
+                    self.expect(Token::Keyword(Keyword::Conditional(
+                        Conditional::Then,
+                    )))?;
+                    branches.push((condition, self.parse_expression(0)?));
+                }
+                Token::Keyword(Keyword::Conditional(Conditional::Else))
+                    if !branches.is_empty() && otherwise.is_none() =>
+                {
+                    otherwise = Some(Box::new(self.parse_expression(0)?));
+                }
+                Token::Keyword(Keyword::Conditional(Conditional::End))
+                    if !branches.is_empty() =>
+                {
+                    break;
+                }
+                token => {
+                    let expected = if branches.is_empty() {
+                        "`WHEN`"
+                    } else if otherwise.is_none() {
+                        "`WHEN`, `ELSE` or `END`"
+                    } else {
+                        "`END`"
+                    };
+                    return Err(ParseError::WrongTokenProvided {
+                        got: token,
+                        expected: expected.to_string(),
+                    });
+                }
+            }
+        }
+
+        Ok(Expression::Case {
+            operand,
+            branches,
+            otherwise,
+        })
+    }
+
+    /// Parses the rest of `CAST(operand AS data_type)`, `CAST` is already
+    /// read.
+    fn parse_cast(&mut self) -> Result<Expression, ParseError> {
+        self.expect(Token::Delimiter(Delimiter::LeftParenthesis))?;
+        let operand = self.parse_expression(0)?;
+        self.expect(Token::Keyword(Keyword::Preposition(Preposition::As)))?;
+
+        let token = self.next()?;
+        let Token::DataType(data_type) = token else {
+            return Err(ParseError::WrongTokenProvided {
+                got: token,
+                expected: "data type".to_string(),
+            });
+        };
+        self.expect(Token::Delimiter(Delimiter::RightParenthesis))?;
+
+        Ok(Expression::Cast {
+            operand: Box::new(operand),
+            data_type: data_type.into(),
+        })
+    }
+}
+
+/// Returns the binary operator the token stands for.
+fn binary_operator(token: &Token) -> Option<BinaryOperator> {
+    let operator = match token {
+        Token::Operator(operator) => match operator {
+            Operator::Plus => BinaryOperator::Add,
+            Operator::Minus => BinaryOperator::Subtract,
+            Operator::Asterisk => BinaryOperator::Multiply,
+            Operator::Slash => BinaryOperator::Divide,
+            Operator::Percent => BinaryOperator::Modulo,
+            Operator::Concat => BinaryOperator::Concat,
+            Operator::Equal => BinaryOperator::Equal,
+            Operator::NotEqual => BinaryOperator::NotEqual,
+            Operator::LessThan => BinaryOperator::LessThan,
+            Operator::LessThanOrEqual => BinaryOperator::LessThanOrEqual,
+            Operator::GreaterThan => BinaryOperator::GreaterThan,
+            Operator::GreaterThanOrEqual => BinaryOperator::GreaterThanOrEqual,
+        },
+        Token::Keyword(Keyword::Logical(Logical::And)) => BinaryOperator::And,
+        Token::Keyword(Keyword::Logical(Logical::Or)) => BinaryOperator::Or,
+        _ => return None,
+    };
+
+    Some(operator)
+}
+
+/// Negates the operand, negative numbers are folded into literals.
+fn negate(operand: Expression) -> Expression {
+    let negation = Expression::unary(UnaryOperator::Minus, operand);
+    let Expression::Unary { operand, .. } = &negation else {
+        unreachable!("created as unary");
+    };
+    if !matches!(**operand, Expression::Literal(_)) {
+        return negation;
+    }
+
+    match negation.evaluate(|_| None) {
+        Ok(value) => Expression::Literal(value),
+        Err(_) => negation,
+    }
+}
+
+/// Error of [`ExpressionParser`] execution.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseError {
+    /// Wrong token provided to the [`ExpressionParser`].
+    WrongTokenProvided { got: Token, expected: String },
+
+    /// Not enough tokens got from the [`Lexer`].
+    NotEnoughTokens,
+
+    /// The token can't start an operand.
+    UnexpectedToken(Token),
+
+    /// The identifier followed by `(` isn't a known function.
+    UnknownFunction(crate::lexer::token::Identifier),
+
+    /// The function can't take the number of arguments.
+    WrongArgumentCount(Function, usize),
+
+    /// Aggregates are not allowed in the expression, e.g. in `WHERE` or in
+    /// the argument of another aggregate.
+    AggregateNotAllowed(AggregateFunction),
+}
+
+#[cfg(test)]
+mod test {
+    use backend::{
+        expression::{BinaryOperator, Expression, Function},
+        query::{Aggregate, AggregateFunction},
+        schema::r#type::{
+            data_types::Text,
+            r#enum::{StorageData, StorageDataType},
+        },
+    };
+
+    use crate::lexer::{
+        token::{Delimiter, Identifier, Keyword, Preposition, Token},
+        Lexer,
+    };
+
+    use super::{ExpressionParser, ParseError};
+
+    fn parse(input: &str) -> Result<Expression, ParseError> {
+        let mut lexer = Lexer::new(input);
+        ExpressionParser::new(&mut lexer).parse()
+    }
+
+    /// Parses the input and formats the expression back.
+    fn reformat(input: &str) -> String {
+        parse(input).unwrap().to_string()
+    }
+
+    #[test]
+    fn test_precedence() {
+        assert_eq!(reformat("1 + 2 * 3"), "1 + 2 * 3");
+        assert_eq!(reformat("(1 + 2) * 3"), "(1 + 2) * 3");
+        assert_eq!(reformat("1 - (2 - 3)"), "1 - (2 - 3)");
+        assert_eq!(reformat("((a))"), "a");
+        assert_eq!(
+            reformat("a = 1 OR b = 2 AND NOT c"),
+            "a = 1 OR b = 2 AND NOT c"
+        );
+        assert_eq!(
+            reformat("(a = 1 OR b = 2) AND c IS NOT NULL"),
+            "(a = 1 OR b = 2) AND c IS NOT NULL"
+        );
+        assert_eq!(reformat("name || 'x' = 'ax'"), "name || 'x' = 'ax'");
+
+        let expression = parse("1 - 2 - 3").unwrap();
+        let Expression::Binary { left, .. } = &expression else {
+            panic!("Expected binary expression, found {:?}", expression);
+        };
+        assert_eq!(left.to_string(), "1 - 2");
+        assert_eq!(expression.evaluate(|_| None), Ok(StorageData::from(-4)));
+    }
+
+    #[test]
+    fn test_unary() {
+        assert_eq!(parse("-5"), Ok(Expression::Literal((-5).into())));
+        assert_eq!(reformat("-a * 2"), "-a * 2");
+        assert_eq!(reformat("-(a + 1)"), "-(a + 1)");
+        assert_eq!(reformat("- -a"), "-(-a)");
+        assert_eq!(reformat("+a"), "a");
+        assert_eq!(reformat("NOT a AND b"), "NOT a AND b");
+        assert_eq!(reformat("NOT (a AND b)"), "NOT (a AND b)");
+        assert_eq!(reformat("NOT a = b"), "NOT a = b");
+    }
+
+    #[test]
+    fn test_function_case_cast() {
+        assert_eq!(
+            parse("coalesce(a, 'none')"),
+            Ok(Expression::Function {
+                function: Function::Coalesce,
+                arguments: vec![
+                    Expression::Column("a".into()),
+                    Expression::Literal(Text("none".to_string()).into()),
+                ],
+            })
+        );
+        assert_eq!(reformat("upper(lower(a))"), "UPPER(LOWER(a))");
+        assert_eq!(
+            parse("nextval('ids')"),
+            Ok(Expression::Function {
+                function: Function::NextVal,
+                arguments: vec![Expression::Literal(
+                    Text("ids".to_string()).into()
+                )],
+            })
+        );
+        assert_eq!(
+            reformat("CASE WHEN a > 1 THEN 'big' WHEN a IS NULL THEN NULL END"),
+            "CASE WHEN a > 1 THEN 'big' WHEN a IS NULL THEN NULL END"
+        );
+        assert_eq!(
+            reformat("case a when 1 then true else false end"),
+            "CASE a WHEN 1 THEN TRUE ELSE FALSE END"
+        );
+        assert_eq!(
+            parse("CAST(a AS INT2)"),
+            Ok(Expression::Cast {
+                operand: Box::new(Expression::Column("a".into())),
+                data_type: StorageDataType::Short,
+            })
+        );
+        assert_eq!(
+            parse("CAST('12' AS int4) + 1").unwrap().evaluate(|_| None),
+            Ok(StorageData::from(13))
+        );
+    }
+
+    #[test]
+    fn test_stops_before_other_tokens() {
+        let mut lexer = Lexer::new("a + 1, b) FROM t");
+        let expression = ExpressionParser::new(&mut lexer).parse().unwrap();
+        assert_eq!(
+            expression,
+            Expression::binary(
+                Expression::Column("a".into()),
+                BinaryOperator::Add,
+                Expression::Literal(1.into()),
+            )
+        );
+        assert_eq!(lexer.next(), Some(Token::Delimiter(Delimiter::Comma)));
+
+        let mut lexer = Lexer::new("b) FROM t");
+        ExpressionParser::new(&mut lexer).parse().unwrap();
+        assert_eq!(
+            lexer.next(),
+            Some(Token::Delimiter(Delimiter::RightParenthesis))
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(parse(""), Err(ParseError::NotEnoughTokens));
+        assert_eq!(parse("1 +"), Err(ParseError::NotEnoughTokens));
+        assert_eq!(parse("(1 + 2"), Err(ParseError::NotEnoughTokens));
+        assert_eq!(
+            parse(", 1"),
+            Err(ParseError::UnexpectedToken(Token::Delimiter(
+                Delimiter::Comma
+            )))
+        );
+        assert_eq!(
+            parse("missing(1)"),
+            Err(ParseError::UnknownFunction(Identifier(
+                "missing".to_string()
+            )))
+        );
+        assert_eq!(
+            parse("nullif(1)"),
+            Err(ParseError::WrongArgumentCount(Function::NullIf, 1))
+        );
+        assert_eq!(
+            parse("a IS 1"),
+            Err(ParseError::WrongTokenProvided {
+                got: Token::Literal(crate::lexer::token::Literal::Integer(1)),
+                expected: "`NULL`".to_string(),
+            })
+        );
+        assert_eq!(
+            parse("CASE a END"),
+            Err(ParseError::WrongTokenProvided {
+                got: Token::Keyword(Keyword::Conditional(
+                    crate::lexer::token::Conditional::End
+                )),
+                expected: "`WHEN`".to_string(),
+            })
+        );
+        assert_eq!(
+            parse("CAST(a TO int4)"),
+            Err(ParseError::WrongTokenProvided {
+                got: Token::Keyword(Keyword::Preposition(Preposition::To)),
+                expected: "`AS`".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_aggregates() {
+        let mut lexer =
+            Lexer::new("COUNT(*) + sum(DISTINCT price) * 2 - count(*), a");
+        let mut aggregates = vec![];
+        let expression =
+            ExpressionParser::with_aggregates(&mut lexer, &mut aggregates)
+                .parse()
+                .unwrap();
+
+        assert_eq!(
+            expression.to_string(),
+            "COUNT(*) + SUM(DISTINCT price) * 2 - COUNT(*)"
+        );
+        assert_eq!(
+            expression.columns(),
+            vec!["COUNT(*)".into(), "SUM(DISTINCT price)".into()]
+        );
+        assert_eq!(
+            aggregates,
+            vec![
+                Aggregate::count_all(),
+                Aggregate::new(
+                    AggregateFunction::Sum,
+                    Expression::Column("price".into())
+                )
+                .distinct(),
+            ]
+        );
+        assert_eq!(lexer.next(), Some(Token::Delimiter(Delimiter::Comma)));
+
+        assert_eq!(
+            parse("count(a) > 1"),
+            Err(ParseError::AggregateNotAllowed(AggregateFunction::Count))
+        );
+
+        let mut aggregates = vec![];
+        let mut parse_aggregates = |input: &str| {
+            let mut lexer = Lexer::new(input);
+            ExpressionParser::with_aggregates(&mut lexer, &mut aggregates)
+                .parse()
+        };
+        assert_eq!(
+            parse_aggregates("SUM(MAX(a))"),
+            Err(ParseError::AggregateNotAllowed(AggregateFunction::Max))
+        );
+        assert_eq!(
+            parse_aggregates("SUM(*)"),
+            Err(ParseError::UnexpectedToken(Token::Operator(
+                crate::lexer::token::Operator::Asterisk
+            )))
+        );
+        assert_eq!(
+            parse_aggregates("MIN(a) + 1").unwrap().to_string(),
+            "MIN(a) + 1"
+        );
+        assert_eq!(
+            aggregates,
+            vec![Aggregate::new(
+                AggregateFunction::Min,
+                Expression::Column("a".into())
+            )]
+        );
+    }
+}
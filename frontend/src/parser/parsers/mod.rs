@@ -1,8 +1,14 @@
+mod ddl_parser;
 mod dml_parser;
+mod expression_parser;
 mod parenthesis_parser;
 mod shortcut_parser;
 
+pub use ddl_parser::{DdlParser, ParseError as DdlParseError};
 pub use dml_parser::{DmlParser, ParseError as DmlParseError};
+pub use expression_parser::{
+    ExpressionParser, ParseError as ExpressionParseError,
+};
 pub use parenthesis_parser::{
     ParenthesisParser, ParseError as ParenthesisParseError,
 };
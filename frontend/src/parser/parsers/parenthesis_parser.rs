@@ -1,10 +1,19 @@
+use backend::schema::column::default::DefaultFunction;
+
 use crate::{
-    column_statement_variant,
     lexer::{
-        token::{Delimiter, Key, Keyword, Token},
+        token::{
+            Action, Attribute, DDLOperator, Delimiter, Identifier, Key,
+            Keyword, Literal, Preposition, Token,
+        },
         Lexer,
     },
-    parser::{ParenthesisState, Statement},
+    parser::{
+        parsers::{ExpressionParseError, ExpressionParser},
+        statement::common::{Check, Column, ColumnDefault, ForeignKey},
+        ParenthesisState, Statement,
+    },
+    unique_statement_variant,
 };
 use std::mem;
 
@@ -56,6 +65,28 @@ impl<'a> ParenthesisParser<'a> {
             Some(Token::Identifier(_)) => {
                 mem::swap(self.peek_token, &mut maybe_identifier_token);
             }
+            Some(Token::Keyword(Keyword::Attribute(Attribute::Unique))) => {
+                self.state.push(
+                    maybe_identifier_token.expect("exist because checked"),
+                );
+                return Some(self.parse_unique());
+            }
+            Some(Token::Keyword(Keyword::Attribute(Attribute::Check))) => {
+                return Some(self.parse_check(None).map(Check::new_statement));
+            }
+            Some(Token::Keyword(Keyword::Key(Key::Foreign))) => {
+                self.state.push(
+                    maybe_identifier_token.expect("exist because checked"),
+                );
+                return Some(self.parse_foreign_key());
+            }
+            Some(Token::Keyword(Keyword::Constraint)) => {
+                return Some(
+                    self.parse_constraint_name()
+                        .and_then(|name| self.parse_check(Some(name)))
+                        .map(Check::new_statement),
+                );
+            }
             Some(delimeter) => {
                 return match delimeter {
                     Token::Delimiter(Delimiter::RightParenthesis) => {
@@ -80,48 +111,335 @@ impl<'a> ParenthesisParser<'a> {
         Some(self.parse_column())
     }
 
-    /// Parses a column statement.
+    /// Parses a table-level `UNIQUE (...)` constraint, the `UNIQUE` token is
+    /// expected in the state.
+    fn parse_unique(&mut self) -> Result<Statement, ParseError> {
+        let token = self.lexer.next().ok_or(ParseError::NotEnoughTokens)?;
+        let Token::Delimiter(Delimiter::LeftParenthesis) = token else {
+            return Err(ParseError::WrongTokenProvided {
+                got: token,
+                expected: "`(`".to_string(),
+            });
+        };
+        self.state.push(token);
+
+        loop {
+            let token = self.lexer.next().ok_or(ParseError::NotEnoughTokens)?;
+            let Token::Identifier(_) = token else {
+                return Err(ParseError::ExpectedIdentifier(token));
+            };
+            self.state.push(token);
+
+            let token = self.lexer.next().ok_or(ParseError::NotEnoughTokens)?;
+            match token {
+                Token::Delimiter(Delimiter::Comma) => self.state.push(token),
+                Token::Delimiter(Delimiter::RightParenthesis) => {
+                    self.state.push(token);
+                    break;
+                }
+                _ => return Err(ParseError::UnexpectedToken(token)),
+            }
+        }
+
+        Ok(unique_statement_variant!(self
+            .state
+            .as_slice()
+            .try_into()
+            .expect("valid tokens")))
+    }
+
+    /// Parses a table-level
+    /// `FOREIGN KEY (column) REFERENCES table (column) [ON DELETE action]`
+    /// constraint, the `FOREIGN` token is expected in the state.
+    fn parse_foreign_key(&mut self) -> Result<Statement, ParseError> {
+        self.push_expected(
+            |token| matches!(token, Token::Keyword(Keyword::Key(Key::Key))),
+            "`key`",
+        )?;
+        self.push_expected(
+            |token| {
+                matches!(token, Token::Delimiter(Delimiter::LeftParenthesis))
+            },
+            "`(`",
+        )?;
+        self.push_expected(
+            |token| matches!(token, Token::Identifier(_)),
+            "`column_name`",
+        )?;
+        self.push_expected(
+            |token| {
+                matches!(token, Token::Delimiter(Delimiter::RightParenthesis))
+            },
+            "`)`",
+        )?;
+        self.push_expected(
+            |token| {
+                matches!(token, Token::Keyword(Keyword::Key(Key::References)))
+            },
+            "`references`",
+        )?;
+        self.parse_references()?;
+
+        let mut token = self.lexer.next();
+        if let Some(Token::Keyword(Keyword::Preposition(Preposition::On))) =
+            token
+        {
+            self.state.push(token.expect("exist because checked"));
+            self.parse_on_delete()?;
+        } else {
+            mem::swap(self.peek_token, &mut token);
+        }
+
+        Ok(ForeignKey::new_statement(
+            self.state.as_slice().try_into().expect("valid tokens"),
+        ))
+    }
+
+    /// Parses the name following the `CONSTRAINT` token and the `CHECK`
+    /// token of the named constraint.
+    fn parse_constraint_name(&mut self) -> Result<Identifier, ParseError> {
+        let token = self.lexer.next().ok_or(ParseError::NotEnoughTokens)?;
+        let Token::Identifier(name) = token else {
+            return Err(ParseError::ExpectedIdentifier(token));
+        };
+        let token = self.lexer.next().ok_or(ParseError::NotEnoughTokens)?;
+        let Token::Keyword(Keyword::Attribute(Attribute::Check)) = token else {
+            return Err(ParseError::WrongTokenProvided {
+                got: token,
+                expected: "`check`".to_string(),
+            });
+        };
+
+        Ok(name)
+    }
+
+    /// Parses the `(condition)` of a `CHECK` constraint, the `CHECK` token
+    /// is already read.
+    /// # Arguments
+    /// * `name` - The name of the constraint, if it's given.
+    fn parse_check(
+        &mut self,
+        name: Option<Identifier>,
+    ) -> Result<Check, ParseError> {
+        let token = self.lexer.next().ok_or(ParseError::NotEnoughTokens)?;
+        let Token::Delimiter(Delimiter::LeftParenthesis) = token else {
+            return Err(ParseError::WrongTokenProvided {
+                got: token,
+                expected: "`(`".to_string(),
+            });
+        };
+        let expression = ExpressionParser::new(self.lexer)
+            .parse()
+            .map_err(ParseError::ExpressionParseError)?;
+        let token = self.lexer.next().ok_or(ParseError::NotEnoughTokens)?;
+        let Token::Delimiter(Delimiter::RightParenthesis) = token else {
+            return Err(ParseError::WrongTokenProvided {
+                got: token,
+                expected: "`)`".to_string(),
+            });
+        };
+
+        Ok(Check { name, expression })
+    }
+
+    /// Reads the next token and pushes it to the state if it's the expected
+    /// one.
+    /// # Arguments
+    /// * `is_expected` - Checks if the token is the expected one.
+    /// * `expected` - Description of the expected token for the error.
+    fn push_expected<F>(
+        &mut self,
+        is_expected: F,
+        expected: &str,
+    ) -> Result<(), ParseError>
+    where
+        F: Fn(&Token) -> bool,
+    {
+        let token = self.lexer.next().ok_or(ParseError::NotEnoughTokens)?;
+        if !is_expected(&token) {
+            return Err(ParseError::WrongTokenProvided {
+                got: token,
+                expected: expected.to_string(),
+            });
+        }
+        self.state.push(token);
+
+        Ok(())
+    }
+
+    /// Parses `table (column)` following the `REFERENCES` token.
+    fn parse_references(&mut self) -> Result<(), ParseError> {
+        self.push_expected(
+            |token| matches!(token, Token::Identifier(_)),
+            "`table_name`",
+        )?;
+        self.push_expected(
+            |token| {
+                matches!(token, Token::Delimiter(Delimiter::LeftParenthesis))
+            },
+            "`(`",
+        )?;
+        self.push_expected(
+            |token| matches!(token, Token::Identifier(_)),
+            "`column_name`",
+        )?;
+        self.push_expected(
+            |token| {
+                matches!(token, Token::Delimiter(Delimiter::RightParenthesis))
+            },
+            "`)`",
+        )
+    }
+
+    /// Parses `DELETE CASCADE|RESTRICT|SET NULL` following the `ON` token.
+    fn parse_on_delete(&mut self) -> Result<(), ParseError> {
+        self.push_expected(
+            |token| matches!(token, Token::DDL(DDLOperator::Delete)),
+            "`delete`",
+        )?;
+        self.push_expected(
+            |token| matches!(token, Token::Keyword(Keyword::Action(_))),
+            "`cascade`|`restrict`|`set null`",
+        )?;
+        if let Some(Token::Keyword(Keyword::Action(Action::Set))) =
+            self.state.last()
+        {
+            self.push_expected(
+                |token| matches!(token, Token::Literal(Literal::Null)),
+                "`null`",
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Parses the expression following the `DEFAULT` token. A call of
+    /// `gen_random_uuid()` is kept as a function to be called for every
+    /// inserted row.
+    fn parse_default(&mut self) -> Result<ColumnDefault, ParseError> {
+        if let Some(Token::Literal(Literal::RandomUuid)) =
+            self.lexer.clone().next()
+        {
+            self.lexer.next();
+            return Ok(ColumnDefault::Function(DefaultFunction::GenRandomUuid));
+        }
+
+        ExpressionParser::new(self.lexer)
+            .parse()
+            .map(ColumnDefault::Value)
+            .map_err(ParseError::ExpressionParseError)
+    }
+
+    /// Parses a column statement. `PRIMARY KEY`, `AUTO_INCREMENT`, `UNIQUE`,
+    /// `DEFAULT expression`, `REFERENCES table (column) [ON DELETE action]`
+    /// and `[CONSTRAINT name] CHECK (condition)` may follow the data type in
+    /// any order.
     pub fn parse_column(&mut self) -> Result<Statement, ParseError> {
         let identifier_token = if self.peek_token.is_some() {
             self.peek_token.take().expect("exist because checked")
         } else {
             self.lexer.next().ok_or(ParseError::NotEnoughTokens)?
         };
+        let Token::Identifier(_) = identifier_token else {
+            return Err(ParseError::ExpectedIdentifier(identifier_token));
+        };
 
         let datatype_token =
             self.lexer.next().ok_or(ParseError::NotEnoughTokens)?;
+        let Token::DataType(_) = datatype_token else {
+            return Err(ParseError::WrongTokenProvided {
+                got: datatype_token,
+                expected: "data type".to_string(),
+            });
+        };
 
         self.state.push(identifier_token);
         self.state.push(datatype_token);
 
-        let mut primary_key_token = self.lexer.next();
-        match primary_key_token {
-            Some(Token::Keyword(Keyword::Key(Key::Primary))) => {
-                let key_token =
-                    self.lexer.next().ok_or(ParseError::NotEnoughTokens)?;
-                if let Token::Keyword(Keyword::Key(Key::Key)) = key_token {
-                    self.state.push(
-                        primary_key_token.expect("exist because checked"),
-                    );
-                    self.state.push(key_token);
-                } else {
-                    return Err(ParseError::WrongTokenProvided {
-                        got: key_token,
-                        expected: "`key`".to_string(),
-                    });
+        let mut is_primary_key = false;
+        let mut is_auto_increment = false;
+        let mut is_unique = false;
+        let mut is_references = false;
+        let mut is_on_delete = false;
+        let mut checks = vec![];
+        let mut default = None;
+        loop {
+            let mut attribute_token = self.lexer.next();
+            match attribute_token {
+                Some(Token::Keyword(Keyword::Key(Key::Primary)))
+                    if !is_primary_key =>
+                {
+                    let key_token =
+                        self.lexer.next().ok_or(ParseError::NotEnoughTokens)?;
+                    if let Token::Keyword(Keyword::Key(Key::Key)) = key_token {
+                        self.state.push(
+                            attribute_token.expect("exist because checked"),
+                        );
+                        self.state.push(key_token);
+                        is_primary_key = true;
+                    } else {
+                        return Err(ParseError::WrongTokenProvided {
+                            got: key_token,
+                            expected: "`key`".to_string(),
+                        });
+                    }
                 }
+                Some(Token::Keyword(Keyword::Attribute(
+                    Attribute::AutoIncrement,
+                ))) if !is_auto_increment => {
+                    self.state
+                        .push(attribute_token.expect("exist because checked"));
+                    is_auto_increment = true;
+                }
+                Some(Token::Keyword(Keyword::Attribute(Attribute::Unique)))
+                    if !is_unique =>
+                {
+                    self.state
+                        .push(attribute_token.expect("exist because checked"));
+                    is_unique = true;
+                }
+                Some(Token::Keyword(Keyword::Key(Key::References)))
+                    if !is_references =>
+                {
+                    self.state
+                        .push(attribute_token.expect("exist because checked"));
+                    self.parse_references()?;
+                    is_references = true;
+                }
+                Some(Token::Keyword(Keyword::Preposition(Preposition::On)))
+                    if is_references && !is_on_delete =>
+                {
+                    self.state
+                        .push(attribute_token.expect("exist because checked"));
+                    self.parse_on_delete()?;
+                    is_on_delete = true;
+                }
+                Some(Token::Keyword(Keyword::Attribute(Attribute::Check))) => {
+                    checks.push(self.parse_check(None)?);
+                }
+                Some(Token::Keyword(Keyword::Attribute(
+                    Attribute::Default,
+                ))) if default.is_none() => {
+                    default = Some(self.parse_default()?);
+                }
+                Some(Token::Keyword(Keyword::Constraint)) => {
+                    let name = self.parse_constraint_name()?;
+                    checks.push(self.parse_check(Some(name))?);
+                }
+                Some(_) => {
+                    mem::swap(self.peek_token, &mut attribute_token);
+                    break;
+                }
+                None => break,
             }
-            Some(_) => {
-                mem::swap(self.peek_token, &mut primary_key_token);
-            }
-            _ => {}
-        };
+        }
 
-        Ok(column_statement_variant!(self
-            .state
-            .as_slice()
-            .try_into()
-            .expect("valid tokens")))
+        let mut column = Column::try_from(self.state.as_slice())
+            .map_err(|()| ParseError::InvalidColumn)?;
+        column.checks = checks;
+        column.default = default;
+
+        Ok(Column::new_statement(column))
     }
 }
 
@@ -139,16 +457,38 @@ pub enum ParseError {
 
     /// Expected identifier token but got something else.
     ExpectedIdentifier(Token),
+
+    /// The tokens of a column definition don't form a column.
+    InvalidColumn,
+
+    /// The [`ExpressionParser`] fails.
+    ExpressionParseError(ExpressionParseError),
 }
 
 #[cfg(test)]
 mod test {
+    use backend::{
+        expression::{BinaryOperator, Expression},
+        schema::{
+            column::default::DefaultFunction, table::constraint::OnDelete,
+        },
+    };
+
     use crate::{
         lexer::{
-            token::{DataType, Delimiter, Identifier, Token},
+            token::{
+                Attribute, DataType, Delimiter, Identifier, Keyword, Operator,
+                Token,
+            },
             Lexer,
         },
-        parser::{statement::common::Column, ParenthesisState},
+        parser::{
+            parsers::ExpressionParseError,
+            statement::common::{
+                Check, Column, ColumnDefault, ForeignKey, References, Unique,
+            },
+            ParenthesisState,
+        },
     };
 
     use super::{ParenthesisParser, ParseError};
@@ -193,11 +533,48 @@ mod test {
                 identifier: Identifier("id".to_string()),
                 data_type: DataType::Integer,
                 is_primary_key: true,
+                is_auto_increment: false,
+                is_unique: false,
+                references: None,
+                checks: vec![],
+                default: None,
             })))
         );
         assert_eq!(parenthesis_state.opened.len(), 1);
     }
 
+    #[test]
+    fn test_column_auto_increment_statement() {
+        let mut lexer = Lexer::new("(id SERIAL AUTO_INCREMENT PRIMARY KEY,");
+        let mut state = vec![lexer.next().unwrap()];
+        let mut peek_token = None;
+        let mut parenthesis_state = ParenthesisState::default();
+
+        let mut parser = ParenthesisParser::new(
+            &mut lexer,
+            &mut state,
+            &mut peek_token,
+            &mut parenthesis_state,
+        );
+
+        let statement = parser.parse();
+
+        assert_eq!(
+            statement,
+            Some(Ok(Column::new_statement(Column {
+                identifier: Identifier("id".to_string()),
+                data_type: DataType::Serial,
+                is_primary_key: true,
+                is_auto_increment: true,
+                is_unique: false,
+                references: None,
+                checks: vec![],
+                default: None,
+            })))
+        );
+        assert_eq!(peek_token, Some(Token::Delimiter(Delimiter::Comma)))
+    }
+
     #[test]
     fn test_column_no_primary_key_statement() {
         let mut lexer = Lexer::new("(id INTEGER,");
@@ -220,6 +597,11 @@ mod test {
                 identifier: Identifier("id".to_string()),
                 data_type: DataType::Integer,
                 is_primary_key: false,
+                is_auto_increment: false,
+                is_unique: false,
+                references: None,
+                checks: vec![],
+                default: None,
             })))
         );
         assert_eq!(parenthesis_state.opened.len(), 1);
@@ -249,6 +631,11 @@ mod test {
                 identifier: Identifier("id".to_string()),
                 data_type: DataType::Integer,
                 is_primary_key: false,
+                is_auto_increment: false,
+                is_unique: false,
+                references: None,
+                checks: vec![],
+                default: None,
             })))
         );
         assert_eq!(parenthesis_state.opened.len(), 0);
@@ -256,6 +643,372 @@ mod test {
         assert_eq!(peek_token, Some(Token::Delimiter(Delimiter::Comma)))
     }
 
+    #[test]
+    fn test_column_unique_statement() {
+        let mut lexer = Lexer::new(",email TEXT UNIQUE,");
+        let mut state = vec![lexer.next().unwrap()];
+        let mut peek_token = None;
+        let mut parenthesis_state = ParenthesisState::default();
+
+        let mut parser = ParenthesisParser::new(
+            &mut lexer,
+            &mut state,
+            &mut peek_token,
+            &mut parenthesis_state,
+        );
+
+        let statement = parser.parse();
+
+        assert_eq!(
+            statement,
+            Some(Ok(Column::new_statement(Column {
+                identifier: Identifier("email".to_string()),
+                data_type: DataType::Text,
+                is_primary_key: false,
+                is_auto_increment: false,
+                is_unique: true,
+                references: None,
+                checks: vec![],
+                default: None,
+            })))
+        );
+        assert_eq!(peek_token, Some(Token::Delimiter(Delimiter::Comma)))
+    }
+
+    #[test]
+    fn test_column_references_statement() {
+        let mut lexer = Lexer::new(
+            ",user_id INTEGER REFERENCES users (id) ON DELETE SET NULL,",
+        );
+        let mut state = vec![lexer.next().unwrap()];
+        let mut peek_token = None;
+        let mut parenthesis_state = ParenthesisState::default();
+
+        let mut parser = ParenthesisParser::new(
+            &mut lexer,
+            &mut state,
+            &mut peek_token,
+            &mut parenthesis_state,
+        );
+
+        let statement = parser.parse();
+
+        assert_eq!(
+            statement,
+            Some(Ok(Column::new_statement(Column {
+                identifier: Identifier("user_id".to_string()),
+                data_type: DataType::Integer,
+                is_primary_key: false,
+                is_auto_increment: false,
+                is_unique: false,
+                references: Some(References {
+                    table: Identifier("users".to_string()),
+                    column: Identifier("id".to_string()),
+                    on_delete: OnDelete::SetNull,
+                }),
+                checks: vec![],
+                default: None,
+            })))
+        );
+        assert_eq!(peek_token, Some(Token::Delimiter(Delimiter::Comma)));
+
+        let mut lexer = Lexer::new(",user_id INTEGER REFERENCES users id");
+        let mut state = vec![lexer.next().unwrap()];
+        let mut peek_token = None;
+        let mut parser = ParenthesisParser::new(
+            &mut lexer,
+            &mut state,
+            &mut peek_token,
+            &mut parenthesis_state,
+        );
+        assert_eq!(
+            parser.parse(),
+            Some(Err(ParseError::WrongTokenProvided {
+                got: Token::Identifier(Identifier("id".to_string())),
+                expected: "`(`".to_string(),
+            }))
+        );
+    }
+
+    #[test]
+    fn test_column_default_statement() {
+        let mut lexer = Lexer::new(
+            ",id UUID DEFAULT gen_random_uuid() PRIMARY KEY,\
+             visits LONG DEFAULT 1 + 1 UNIQUE)",
+        );
+        let mut state = vec![lexer.next().unwrap()];
+        let mut peek_token = None;
+        let mut parenthesis_state = ParenthesisState::default();
+
+        let mut parser = ParenthesisParser::new(
+            &mut lexer,
+            &mut state,
+            &mut peek_token,
+            &mut parenthesis_state,
+        );
+
+        assert_eq!(
+            parser.parse(),
+            Some(Ok(Column::new_statement(Column {
+                identifier: Identifier("id".to_string()),
+                data_type: DataType::Uuid,
+                is_primary_key: true,
+                default: Some(ColumnDefault::Function(
+                    DefaultFunction::GenRandomUuid
+                )),
+                ..Column::default()
+            })))
+        );
+        assert_eq!(peek_token, Some(Token::Delimiter(Delimiter::Comma)));
+
+        let mut state = vec![peek_token.take().unwrap()];
+        let mut parser = ParenthesisParser::new(
+            &mut lexer,
+            &mut state,
+            &mut peek_token,
+            &mut parenthesis_state,
+        );
+        let statement = parser.parse();
+        assert_eq!(
+            statement,
+            Some(Ok(Column::new_statement(Column {
+                identifier: Identifier("visits".to_string()),
+                data_type: DataType::Long,
+                is_unique: true,
+                default: Some(ColumnDefault::Value(Expression::binary(
+                    Expression::Literal(1.into()),
+                    BinaryOperator::Add,
+                    Expression::Literal(1.into()),
+                ))),
+                ..Column::default()
+            })))
+        );
+        assert_eq!(
+            statement.unwrap().unwrap().to_string(),
+            "visits Long UNIQUE DEFAULT 1 + 1"
+        );
+
+        let mut lexer = Lexer::new(",id INTEGER DEFAULT)");
+        let mut state = vec![lexer.next().unwrap()];
+        let mut parser = ParenthesisParser::new(
+            &mut lexer,
+            &mut state,
+            &mut peek_token,
+            &mut parenthesis_state,
+        );
+        assert!(matches!(
+            parser.parse(),
+            Some(Err(ParseError::ExpressionParseError(_)))
+        ));
+    }
+
+    #[test]
+    fn test_check_statement() {
+        let adult = Expression::binary(
+            Expression::Column("age".into()),
+            BinaryOperator::GreaterThanOrEqual,
+            Expression::Literal(18.into()),
+        );
+        let mut parenthesis_state = ParenthesisState::default();
+        let mut parse = |input: &str| {
+            let mut lexer = Lexer::new(input);
+            let mut state = vec![lexer.next().unwrap()];
+            let mut peek_token = None;
+            ParenthesisParser::new(
+                &mut lexer,
+                &mut state,
+                &mut peek_token,
+                &mut parenthesis_state,
+            )
+            .parse()
+        };
+
+        assert_eq!(
+            parse(",age INTEGER CONSTRAINT adult CHECK (age >= 18) UNIQUE,"),
+            Some(Ok(Column::new_statement(Column {
+                identifier: Identifier("age".to_string()),
+                data_type: DataType::Integer,
+                is_unique: true,
+                checks: vec![Check {
+                    name: Some(Identifier("adult".to_string())),
+                    expression: adult.clone(),
+                }],
+                ..Column::default()
+            })))
+        );
+        assert_eq!(
+            parse(",CHECK (age >= 18))"),
+            Some(Ok(Check::new_statement(Check {
+                name: None,
+                expression: adult.clone(),
+            })))
+        );
+        assert_eq!(
+            parse(",constraint adult check (age >= 18))"),
+            Some(Ok(Check::new_statement(Check {
+                name: Some(Identifier("adult".to_string())),
+                expression: adult,
+            })))
+        );
+        assert_eq!(
+            parse(",CHECK age >= 18)"),
+            Some(Err(ParseError::WrongTokenProvided {
+                got: Token::Identifier(Identifier("age".to_string())),
+                expected: "`(`".to_string(),
+            }))
+        );
+        assert_eq!(
+            parse(",CHECK (age >= 18"),
+            Some(Err(ParseError::NotEnoughTokens))
+        );
+        assert_eq!(
+            parse(",CHECK (>= 18)"),
+            Some(Err(ParseError::ExpressionParseError(
+                ExpressionParseError::UnexpectedToken(Token::Operator(
+                    Operator::GreaterThanOrEqual
+                ))
+            )))
+        );
+        assert_eq!(
+            parse(",CONSTRAINT adult UNIQUE (age))"),
+            Some(Err(ParseError::WrongTokenProvided {
+                got: Token::Keyword(Keyword::Attribute(Attribute::Unique)),
+                expected: "`check`".to_string(),
+            }))
+        );
+    }
+
+    #[test]
+    fn test_column_without_data_type() {
+        let mut lexer = Lexer::new(",u;");
+        let mut state = vec![lexer.next().unwrap()];
+        let mut peek_token = None;
+        let mut parenthesis_state = ParenthesisState::default();
+
+        let mut parser = ParenthesisParser::new(
+            &mut lexer,
+            &mut state,
+            &mut peek_token,
+            &mut parenthesis_state,
+        );
+
+        assert_eq!(
+            parser.parse(),
+            Some(Err(ParseError::WrongTokenProvided {
+                got: Token::Delimiter(Delimiter::Semicolon),
+                expected: "data type".to_string(),
+            }))
+        );
+    }
+
+    #[test]
+    fn test_unique_statement() {
+        let mut lexer = Lexer::new(",UNIQUE (email, domain))");
+        let mut state = vec![lexer.next().unwrap()];
+        let mut peek_token = None;
+        let mut parenthesis_state = ParenthesisState::default();
+
+        let mut parser = ParenthesisParser::new(
+            &mut lexer,
+            &mut state,
+            &mut peek_token,
+            &mut parenthesis_state,
+        );
+
+        let statement = parser.parse();
+
+        assert_eq!(
+            statement,
+            Some(Ok(Unique::new_statement(vec![
+                Identifier("email".to_string()),
+                Identifier("domain".to_string()),
+            ])))
+        );
+        assert_eq!(peek_token, None);
+        assert_eq!(
+            lexer.next(),
+            Some(Token::Delimiter(Delimiter::RightParenthesis))
+        );
+
+        let mut lexer = Lexer::new(",UNIQUE (email");
+        let mut state = vec![lexer.next().unwrap()];
+        let mut parser = ParenthesisParser::new(
+            &mut lexer,
+            &mut state,
+            &mut peek_token,
+            &mut parenthesis_state,
+        );
+        assert_eq!(parser.parse(), Some(Err(ParseError::NotEnoughTokens)));
+    }
+
+    #[test]
+    fn test_foreign_key_statement() {
+        let mut lexer = Lexer::new(
+            ",FOREIGN KEY (tid) REFERENCES d.s.t(id) ON DELETE CASCADE)",
+        );
+        let mut state = vec![lexer.next().unwrap()];
+        let mut peek_token = None;
+        let mut parenthesis_state = ParenthesisState::default();
+
+        let mut parser = ParenthesisParser::new(
+            &mut lexer,
+            &mut state,
+            &mut peek_token,
+            &mut parenthesis_state,
+        );
+
+        assert_eq!(
+            parser.parse(),
+            Some(Ok(ForeignKey::new_statement(ForeignKey {
+                column: Identifier("tid".to_string()),
+                references: References {
+                    table: Identifier("d.s.t".to_string()),
+                    column: Identifier("id".to_string()),
+                    on_delete: OnDelete::Cascade,
+                },
+            })))
+        );
+        assert_eq!(peek_token, None);
+
+        let mut lexer = Lexer::new(",FOREIGN KEY (tid) REFERENCES t(id),");
+        let mut state = vec![lexer.next().unwrap()];
+        let mut parser = ParenthesisParser::new(
+            &mut lexer,
+            &mut state,
+            &mut peek_token,
+            &mut parenthesis_state,
+        );
+        assert_eq!(
+            parser.parse(),
+            Some(Ok(ForeignKey::new_statement(ForeignKey {
+                column: Identifier("tid".to_string()),
+                references: References {
+                    table: Identifier("t".to_string()),
+                    column: Identifier("id".to_string()),
+                    on_delete: OnDelete::Restrict,
+                },
+            })))
+        );
+        assert_eq!(peek_token, Some(Token::Delimiter(Delimiter::Comma)));
+
+        let mut lexer = Lexer::new(",FOREIGN (tid) REFERENCES t(id)");
+        let mut state = vec![lexer.next().unwrap()];
+        let mut peek_token = None;
+        let mut parser = ParenthesisParser::new(
+            &mut lexer,
+            &mut state,
+            &mut peek_token,
+            &mut parenthesis_state,
+        );
+        assert_eq!(
+            parser.parse(),
+            Some(Err(ParseError::WrongTokenProvided {
+                got: Token::Delimiter(Delimiter::LeftParenthesis),
+                expected: "`key`".to_string(),
+            }))
+        );
+    }
+
     #[test]
     fn test_empty_parenthesis() {
         let mut lexer = Lexer::new("()");
@@ -0,0 +1,131 @@
+use std::fmt::Display;
+
+use backend::expression::Expression;
+
+use crate::{
+    column_statement_variant, lexer::token, parser::Statement,
+    preprocessor::Node, unique_statement_variant,
+};
+
+/// Represents a `[CONSTRAINT name] CHECK (...)` constraint of a column or a
+/// table.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Check {
+    /// Name of the constraint, generated from the names of the table and
+    /// the columns if omitted.
+    pub name: Option<token::Identifier>,
+
+    /// The condition the rows must satisfy.
+    pub expression: Expression,
+}
+
+impl Display for Check {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(name) = &self.name {
+            write!(f, "CONSTRAINT {} ", name)?;
+        }
+        write!(f, "CHECK ({})", self.expression)
+    }
+}
+
+impl Check {
+    /// Returns a new instance of table-level `Check`.
+    /// # Arguments
+    /// * `check` - The constraint.
+    /// # Returns
+    /// * New instance of `Check` [`Statement`].
+    pub fn new_statement(check: Check) -> Statement {
+        use crate::check_statement_variant;
+
+        check_statement_variant!(check)
+    }
+}
+
+impl Node for Check {
+    fn can_be_followed(&self, other: &Statement) -> bool {
+        use crate::{check_statement_variant, foreign_key_statement_variant};
+
+        matches!(
+            other,
+            column_statement_variant!(_)
+                | unique_statement_variant!(_)
+                | check_statement_variant!(_)
+                | foreign_key_statement_variant!(_)
+                | Statement::Semicolon
+        )
+    }
+}
+
+/// Shortcut for [`Check`] variant of [`Statement`].
+#[macro_export]
+macro_rules! check_statement_variant {
+    ($($arg:tt)*) => {
+        $crate::parser::Statement::Common(
+            $crate::parser::statement::Common::Check(
+                $($arg)*,
+            ),
+        )
+    };
+}
+
+#[cfg(test)]
+mod check_tests {
+    use backend::expression::{BinaryOperator, Expression};
+
+    use crate::{
+        lexer::token,
+        parser::{
+            statement::{
+                common::{Column, Unique},
+                dml::CreateDatabase,
+            },
+            Statement,
+        },
+        preprocessor::Node,
+    };
+
+    use super::Check;
+
+    fn adult() -> Expression {
+        Expression::binary(
+            Expression::Column("age".into()),
+            BinaryOperator::GreaterThanOrEqual,
+            Expression::Literal(18.into()),
+        )
+    }
+
+    #[test]
+    fn test_check_display() {
+        let check = Check {
+            name: None,
+            expression: adult(),
+        };
+        assert_eq!(check.to_string(), "CHECK (age >= 18)");
+
+        let check = Check {
+            name: Some(token::Identifier("adult".to_string())),
+            expression: adult(),
+        };
+        assert_eq!(check.to_string(), "CONSTRAINT adult CHECK (age >= 18)");
+    }
+
+    #[test]
+    fn test_check_can_be_followed() {
+        let check = Check {
+            name: None,
+            expression: adult(),
+        };
+
+        assert!(check.can_be_followed(&Column::new_statement(Column {
+            identifier: token::Identifier("id".to_string()),
+            data_type: token::DataType::Integer,
+            ..Column::default()
+        })));
+        assert!(check.can_be_followed(&Unique::new_statement(vec![])));
+        assert!(check.can_be_followed(&Check::new_statement(check.clone())));
+        assert!(check.can_be_followed(&Statement::Semicolon));
+        assert!(!check.can_be_followed(&CreateDatabase::new_statement(
+            token::Identifier("test".to_string())
+        )));
+    }
+}
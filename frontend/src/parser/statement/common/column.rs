@@ -1,13 +1,21 @@
+use backend::{
+    expression::Expression,
+    schema::{column::default::DefaultFunction, table::constraint::OnDelete},
+};
+
 use crate::{
     lexer::{
         token,
-        token::{Key, Keyword, Token},
+        token::{
+            Action, Attribute, DDLOperator, Delimiter, Key, Keyword, Literal,
+            Preposition, Token,
+        },
     },
-    parser::Statement,
+    parser::{statement::common::Check, Statement},
     preprocessor::Node,
 };
 use derive_more::Display;
-use std::fmt::Display;
+use std::{fmt::Display, slice::Iter};
 
 /// Represents a column in a table.
 #[derive(Debug, Default, PartialEq, Clone)]
@@ -20,20 +28,88 @@ pub struct Column {
 
     /// Whether the column is a primary key.
     pub is_primary_key: bool,
+
+    /// Whether the column values are generated by a sequence.
+    pub is_auto_increment: bool,
+
+    /// Whether the column values must be unique.
+    pub is_unique: bool,
+
+    /// The column referenced by the column's values, if it's a foreign key.
+    pub references: Option<References>,
+
+    /// The `CHECK` constraints of the column.
+    pub checks: Vec<Check>,
+
+    /// The value used if an inserted row doesn't provide the column.
+    pub default: Option<ColumnDefault>,
+}
+
+/// Represents the `DEFAULT` clause of a column.
+#[derive(Debug, Display, PartialEq, Clone)]
+pub enum ColumnDefault {
+    /// A constant expression, e.g. `DEFAULT 0`.
+    Value(Expression),
+
+    /// A function called for every inserted row, e.g.
+    /// `DEFAULT gen_random_uuid()`.
+    Function(DefaultFunction),
+}
+
+/// Represents the `REFERENCES table (column) [ON DELETE action]` clause of a
+/// column.
+#[derive(Debug, PartialEq, Clone)]
+pub struct References {
+    /// Name of the referenced table.
+    pub table: token::Identifier,
+
+    /// Name of the referenced column.
+    pub column: token::Identifier,
+
+    /// The action on deletion of a referenced row, `RESTRICT` if omitted.
+    pub on_delete: OnDelete,
+}
+
+impl Display for References {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "REFERENCES {} ({}) ON DELETE {}",
+            self.table, self.column, self.on_delete
+        )
+    }
 }
 
 impl Display for Column {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{} {}{}",
+            "{} {}{}{}{}{}{}{}",
             self.identifier,
             self.data_type,
             if self.is_primary_key {
                 " PRIMARY KEY"
             } else {
                 ""
-            }
+            },
+            if self.is_auto_increment {
+                " AUTO_INCREMENT"
+            } else {
+                ""
+            },
+            if self.is_unique { " UNIQUE" } else { "" },
+            self.default
+                .as_ref()
+                .map(|default| format!(" DEFAULT {}", default))
+                .unwrap_or_default(),
+            self.references
+                .as_ref()
+                .map(|references| format!(" {}", references))
+                .unwrap_or_default(),
+            self.checks
+                .iter()
+                .map(|check| format!(" {}", check))
+                .collect::<String>()
         )
     }
 }
@@ -47,16 +123,22 @@ impl Column {
     pub fn new_statement(column: Column) -> Statement {
         use crate::column_statement_variant;
 
-        column_statement_variant!(column)
+        column_statement_variant!(Box::new(column))
     }
 }
 
 impl Node for Column {
     fn can_be_followed(&self, other: &Statement) -> bool {
-        use crate::column_statement_variant;
+        use crate::{
+            check_statement_variant, column_statement_variant,
+            foreign_key_statement_variant, unique_statement_variant,
+        };
 
         match other {
             column_statement_variant!(_) => true,
+            unique_statement_variant!(_) => true,
+            check_statement_variant!(_) => true,
+            foreign_key_statement_variant!(_) => true,
             Statement::Semicolon => true,
             _ => false,
         }
@@ -70,8 +152,6 @@ impl TryFrom<&[Token]> for Column {
         let mut tokens = tokens.iter();
         let identifier = tokens.next().ok_or(())?;
         let type_ = tokens.next().ok_or(())?;
-        let key_type = tokens.next();
-        let key = tokens.next();
 
         let identifier = match identifier {
             Token::Identifier(identifier) => identifier.clone(),
@@ -83,22 +163,118 @@ impl TryFrom<&[Token]> for Column {
             _ => return Err(()),
         };
 
-        let is_primary_key = matches!(
-            (key_type, key),
-            (
-                Some(Token::Keyword(Keyword::Key(Key::Primary))),
-                Some(Token::Keyword(Keyword::Key(Key::Key))),
-            )
-        );
+        let mut is_primary_key = false;
+        let mut is_auto_increment = false;
+        let mut is_unique = false;
+        let mut references = None;
+        let mut is_on_delete_set = false;
+        while let Some(token) = tokens.next() {
+            match token {
+                Token::Keyword(Keyword::Key(Key::Primary))
+                    if !is_primary_key =>
+                {
+                    match tokens.next() {
+                        Some(Token::Keyword(Keyword::Key(Key::Key))) => {
+                            is_primary_key = true;
+                        }
+                        _ => return Err(()),
+                    }
+                }
+                Token::Keyword(Keyword::Attribute(
+                    Attribute::AutoIncrement,
+                )) if !is_auto_increment => {
+                    is_auto_increment = true;
+                }
+                Token::Keyword(Keyword::Attribute(Attribute::Unique))
+                    if !is_unique =>
+                {
+                    is_unique = true;
+                }
+                Token::Keyword(Keyword::Key(Key::References))
+                    if references.is_none() =>
+                {
+                    references = Some(References::try_from(&mut tokens)?);
+                }
+                Token::Keyword(Keyword::Preposition(Preposition::On))
+                    if references.is_some() && !is_on_delete_set =>
+                {
+                    let on_delete = read_on_delete(&mut tokens)?;
+                    references.as_mut().expect("checked").on_delete = on_delete;
+                    is_on_delete_set = true;
+                }
+                _ => return Err(()),
+            }
+        }
 
         Ok(Self {
             identifier,
             data_type,
             is_primary_key,
+            is_auto_increment,
+            is_unique,
+            references,
+            checks: vec![],
+            default: None,
+        })
+    }
+}
+
+impl TryFrom<&mut Iter<'_, Token>> for References {
+    type Error = ();
+
+    /// Reads `table (column)` following the `REFERENCES` token.
+    fn try_from(tokens: &mut Iter<'_, Token>) -> Result<Self, Self::Error> {
+        let Some(Token::Identifier(table)) = tokens.next() else {
+            return Err(());
+        };
+        let Some(Token::Delimiter(Delimiter::LeftParenthesis)) = tokens.next()
+        else {
+            return Err(());
+        };
+        let Some(Token::Identifier(column)) = tokens.next() else {
+            return Err(());
+        };
+        let Some(Token::Delimiter(Delimiter::RightParenthesis)) = tokens.next()
+        else {
+            return Err(());
+        };
+
+        Ok(Self {
+            table: table.clone(),
+            column: column.clone(),
+            on_delete: OnDelete::default(),
         })
     }
 }
 
+/// Reads `DELETE CASCADE|RESTRICT|SET NULL` following the `ON` token.
+/// # Arguments
+/// * `tokens` - The tokens following the `ON` token.
+/// # Returns
+/// * The action on deletion of a referenced row.
+pub(super) fn read_on_delete(
+    tokens: &mut Iter<'_, Token>,
+) -> Result<OnDelete, ()> {
+    let Some(Token::DDL(DDLOperator::Delete)) = tokens.next() else {
+        return Err(());
+    };
+    match tokens.next() {
+        Some(Token::Keyword(Keyword::Action(Action::Cascade))) => {
+            Ok(OnDelete::Cascade)
+        }
+        Some(Token::Keyword(Keyword::Action(Action::Restrict))) => {
+            Ok(OnDelete::Restrict)
+        }
+        Some(Token::Keyword(Keyword::Action(Action::Set))) => {
+            let Some(Token::Literal(Literal::Null)) = tokens.next() else {
+                return Err(());
+            };
+            Ok(OnDelete::SetNull)
+        }
+        _ => Err(()),
+    }
+}
+
 /// Shortcut for [`Column`] variant of [`Statement`].
 #[macro_export]
 macro_rules! column_statement_variant {
@@ -133,11 +309,54 @@ mod rename_to_tests {
             identifier: token::Identifier("test".to_string()),
             data_type: token::DataType::Integer,
             is_primary_key: false,
+            is_auto_increment: false,
+            is_unique: false,
+            references: None,
+            checks: vec![],
+            default: None,
         });
 
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn test_column_try_from_token_vec_attributes() {
+        let tokens = vec![
+            Token::Identifier(token::Identifier("id".to_string())),
+            Token::DataType(token::DataType::Long),
+            Token::Keyword(token::Keyword::Attribute(
+                token::Attribute::AutoIncrement,
+            )),
+            Token::Keyword(token::Keyword::Key(token::Key::Primary)),
+            Token::Keyword(token::Keyword::Key(token::Key::Key)),
+        ];
+
+        let actual = Column::try_from(tokens.as_slice());
+        let expected = Ok(Column {
+            identifier: token::Identifier("id".to_string()),
+            data_type: token::DataType::Long,
+            is_primary_key: true,
+            is_auto_increment: true,
+            is_unique: false,
+            references: None,
+            checks: vec![],
+            default: None,
+        });
+
+        assert_eq!(actual, expected);
+        assert_eq!(
+            actual.unwrap().to_string(),
+            "id Long PRIMARY KEY AUTO_INCREMENT"
+        );
+
+        let tokens = vec![
+            Token::Identifier(token::Identifier("id".to_string())),
+            Token::DataType(token::DataType::Long),
+            Token::Keyword(token::Keyword::Key(token::Key::Primary)),
+        ];
+        assert_eq!(Column::try_from(tokens.as_slice()), Err(()));
+    }
+
     #[test]
     fn test_column_try_from_token_vec_invalid_tokens() {
         let tokens = vec![
@@ -171,12 +390,22 @@ mod rename_to_tests {
             identifier: token::Identifier("test".to_string()),
             data_type: token::DataType::Integer,
             is_primary_key: false,
+            is_auto_increment: false,
+            is_unique: false,
+            references: None,
+            checks: vec![],
+            default: None,
         };
 
         let another_column = Column {
             identifier: token::Identifier("test".to_string()),
             data_type: token::DataType::UInteger,
             is_primary_key: true,
+            is_auto_increment: false,
+            is_unique: false,
+            references: None,
+            checks: vec![],
+            default: None,
         };
 
         let identifier = token::Identifier("test".to_string());
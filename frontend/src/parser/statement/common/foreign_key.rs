@@ -0,0 +1,215 @@
+use std::fmt::Display;
+
+use crate::{
+    check_statement_variant, column_statement_variant,
+    lexer::{
+        token,
+        token::{Delimiter, Key, Keyword, Preposition, Token},
+    },
+    parser::{
+        statement::common::{column::read_on_delete, References},
+        Statement,
+    },
+    preprocessor::Node,
+    unique_statement_variant,
+};
+
+/// Represents a table-level
+/// `FOREIGN KEY (column) REFERENCES table (column) [ON DELETE action]`
+/// constraint.
+#[derive(Debug, PartialEq, Clone)]
+pub struct ForeignKey {
+    /// Name of the referencing column.
+    pub column: token::Identifier,
+
+    /// The column referenced by the column's values.
+    pub references: References,
+}
+
+impl Display for ForeignKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "FOREIGN KEY ({}) {}", self.column, self.references)
+    }
+}
+
+impl ForeignKey {
+    /// Returns a new instance of table-level `ForeignKey`.
+    /// # Arguments
+    /// * `foreign_key` - The constraint.
+    /// # Returns
+    /// * New instance of `ForeignKey` [`Statement`].
+    pub fn new_statement(foreign_key: ForeignKey) -> Statement {
+        use crate::foreign_key_statement_variant;
+
+        foreign_key_statement_variant!(foreign_key)
+    }
+}
+
+impl Node for ForeignKey {
+    fn can_be_followed(&self, other: &Statement) -> bool {
+        use crate::foreign_key_statement_variant;
+
+        matches!(
+            other,
+            column_statement_variant!(_)
+                | unique_statement_variant!(_)
+                | check_statement_variant!(_)
+                | foreign_key_statement_variant!(_)
+                | Statement::Semicolon
+        )
+    }
+}
+
+impl TryFrom<&[Token]> for ForeignKey {
+    type Error = ();
+
+    fn try_from(tokens: &[Token]) -> Result<Self, Self::Error> {
+        let mut tokens = tokens.iter();
+        let Some(Token::Keyword(Keyword::Key(Key::Foreign))) = tokens.next()
+        else {
+            return Err(());
+        };
+        let Some(Token::Keyword(Keyword::Key(Key::Key))) = tokens.next() else {
+            return Err(());
+        };
+        let Some(Token::Delimiter(Delimiter::LeftParenthesis)) = tokens.next()
+        else {
+            return Err(());
+        };
+        let Some(Token::Identifier(column)) = tokens.next() else {
+            return Err(());
+        };
+        let Some(Token::Delimiter(Delimiter::RightParenthesis)) = tokens.next()
+        else {
+            return Err(());
+        };
+        let Some(Token::Keyword(Keyword::Key(Key::References))) = tokens.next()
+        else {
+            return Err(());
+        };
+        let mut references = References::try_from(&mut tokens)?;
+        match tokens.next() {
+            Some(Token::Keyword(Keyword::Preposition(Preposition::On))) => {
+                references.on_delete = read_on_delete(&mut tokens)?;
+            }
+            None => {}
+            _ => return Err(()),
+        }
+        if tokens.next().is_some() {
+            return Err(());
+        }
+
+        Ok(Self {
+            column: column.clone(),
+            references,
+        })
+    }
+}
+
+/// Shortcut for [`ForeignKey`] variant of [`Statement`].
+#[macro_export]
+macro_rules! foreign_key_statement_variant {
+    ($($arg:tt)*) => {
+        $crate::parser::Statement::Common(
+            $crate::parser::statement::Common::ForeignKey(
+                $($arg)*,
+            ),
+        )
+    };
+}
+
+#[cfg(test)]
+mod foreign_key_tests {
+    use backend::schema::table::constraint::OnDelete;
+
+    use crate::{
+        lexer::{
+            token,
+            token::{
+                Action, DDLOperator, Delimiter, Key, Keyword, Preposition,
+                Token,
+            },
+        },
+        parser::{
+            statement::{
+                common::{References, Unique},
+                dml::CreateDatabase,
+            },
+            Statement,
+        },
+        preprocessor::Node,
+    };
+
+    use super::ForeignKey;
+
+    fn tokens() -> Vec<Token> {
+        vec![
+            Token::Keyword(Keyword::Key(Key::Foreign)),
+            Token::Keyword(Keyword::Key(Key::Key)),
+            Token::Delimiter(Delimiter::LeftParenthesis),
+            Token::Identifier(token::Identifier("tid".to_string())),
+            Token::Delimiter(Delimiter::RightParenthesis),
+            Token::Keyword(Keyword::Key(Key::References)),
+            Token::Identifier(token::Identifier("t".to_string())),
+            Token::Delimiter(Delimiter::LeftParenthesis),
+            Token::Identifier(token::Identifier("id".to_string())),
+            Token::Delimiter(Delimiter::RightParenthesis),
+        ]
+    }
+
+    #[test]
+    fn test_foreign_key_try_from_token_vec_basic() {
+        let mut tokens = tokens();
+        tokens.extend([
+            Token::Keyword(Keyword::Preposition(Preposition::On)),
+            Token::DDL(DDLOperator::Delete),
+            Token::Keyword(Keyword::Action(Action::Cascade)),
+        ]);
+
+        let actual = ForeignKey::try_from(tokens.as_slice());
+        let expected = Ok(ForeignKey {
+            column: token::Identifier("tid".to_string()),
+            references: References {
+                table: token::Identifier("t".to_string()),
+                column: token::Identifier("id".to_string()),
+                on_delete: OnDelete::Cascade,
+            },
+        });
+
+        assert_eq!(actual, expected);
+        assert_eq!(
+            actual.unwrap().to_string(),
+            "FOREIGN KEY (tid) REFERENCES t (id) ON DELETE CASCADE"
+        );
+        assert_eq!(
+            ForeignKey::try_from(self::tokens().as_slice())
+                .map(|foreign_key| foreign_key.references.on_delete),
+            Ok(OnDelete::Restrict)
+        );
+    }
+
+    #[test]
+    fn test_foreign_key_try_from_token_vec_invalid_tokens() {
+        let tokens = tokens();
+        assert_eq!(ForeignKey::try_from(&tokens[..8]), Err(()));
+        assert_eq!(ForeignKey::try_from(&tokens[1..]), Err(()));
+
+        let mut tokens = self::tokens();
+        tokens.push(Token::Keyword(Keyword::Preposition(Preposition::On)));
+        assert_eq!(ForeignKey::try_from(tokens.as_slice()), Err(()));
+    }
+
+    #[test]
+    fn test_foreign_key_can_be_followed() {
+        let foreign_key =
+            ForeignKey::try_from(tokens().as_slice()).expect("valid tokens");
+
+        assert!(foreign_key.can_be_followed(&Unique::new_statement(vec![])));
+        assert!(foreign_key
+            .can_be_followed(&ForeignKey::new_statement(foreign_key.clone())));
+        assert!(foreign_key.can_be_followed(&Statement::Semicolon));
+        assert!(!foreign_key.can_be_followed(&CreateDatabase::new_statement(
+            token::Identifier("test".to_string())
+        )));
+    }
+}
@@ -1,11 +1,17 @@
+mod check;
 mod column;
+mod foreign_key;
 mod rename_to;
+mod unique;
 
 use crate::{parser::Statement, preprocessor::Node};
 use derive_more::Display;
 
-pub use column::Column;
+pub use check::Check;
+pub use column::{Column, ColumnDefault, References};
+pub use foreign_key::ForeignKey;
 pub use rename_to::RenameTo;
+pub use unique::Unique;
 
 /// Represents an AST node for a common operation.
 #[derive(Debug, Display, PartialEq, Clone)]
@@ -14,7 +20,17 @@ pub enum Common {
     RenameTo(RenameTo),
 
     /// Represents a column statement.
-    Column(Column),
+    Column(Box<Column>),
+
+    /// Represents a table-level `UNIQUE (...)` constraint.
+    Unique(Unique),
+
+    /// Represents a table-level `CHECK (...)` constraint.
+    Check(Check),
+
+    /// Represents a table-level `FOREIGN KEY (...) REFERENCES ...`
+    /// constraint.
+    ForeignKey(ForeignKey),
 }
 
 impl Node for Common {
@@ -22,6 +38,9 @@ impl Node for Common {
         match self {
             Common::RenameTo(stmnt) => stmnt.can_be_followed(next),
             Common::Column(stmnt) => stmnt.can_be_followed(next),
+            Common::Unique(stmnt) => stmnt.can_be_followed(next),
+            Common::Check(stmnt) => stmnt.can_be_followed(next),
+            Common::ForeignKey(stmnt) => stmnt.can_be_followed(next),
         }
     }
 }
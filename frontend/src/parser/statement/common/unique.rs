@@ -0,0 +1,187 @@
+use std::fmt::Display;
+
+use crate::{
+    column_statement_variant,
+    lexer::{
+        token,
+        token::{Attribute, Delimiter, Keyword, Token},
+    },
+    parser::Statement,
+    preprocessor::Node,
+};
+
+/// Represents a table-level `UNIQUE (...)` constraint.
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Unique {
+    /// Names of the columns whose values must be unique together.
+    pub columns: Vec<token::Identifier>,
+}
+
+impl Display for Unique {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let columns = self
+            .columns
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", ");
+        write!(f, "UNIQUE ({})", columns)
+    }
+}
+
+impl Unique {
+    /// Returns a new instance of `Unique`.
+    /// # Arguments
+    /// * `columns` - Names of the columns.
+    /// # Returns
+    /// * New instance of `Unique` [`Statement`].
+    pub fn new_statement(columns: Vec<token::Identifier>) -> Statement {
+        use crate::unique_statement_variant;
+
+        unique_statement_variant!(Self { columns })
+    }
+}
+
+impl Node for Unique {
+    fn can_be_followed(&self, other: &Statement) -> bool {
+        use crate::{
+            check_statement_variant, foreign_key_statement_variant,
+            unique_statement_variant,
+        };
+
+        matches!(
+            other,
+            column_statement_variant!(_)
+                | unique_statement_variant!(_)
+                | check_statement_variant!(_)
+                | foreign_key_statement_variant!(_)
+                | Statement::Semicolon
+        )
+    }
+}
+
+impl TryFrom<&[Token]> for Unique {
+    type Error = ();
+
+    fn try_from(tokens: &[Token]) -> Result<Self, Self::Error> {
+        let mut tokens = tokens.iter();
+        let unique = tokens.next().ok_or(())?;
+        let left_parenthesis = tokens.next().ok_or(())?;
+
+        let Token::Keyword(Keyword::Attribute(Attribute::Unique)) = unique
+        else {
+            return Err(());
+        };
+        let Token::Delimiter(Delimiter::LeftParenthesis) = left_parenthesis
+        else {
+            return Err(());
+        };
+
+        let mut columns = Vec::new();
+        loop {
+            match tokens.next() {
+                Some(Token::Identifier(identifier)) => {
+                    columns.push(identifier.clone())
+                }
+                _ => return Err(()),
+            }
+            match tokens.next() {
+                Some(Token::Delimiter(Delimiter::Comma)) => {}
+                Some(Token::Delimiter(Delimiter::RightParenthesis)) => break,
+                _ => return Err(()),
+            }
+        }
+        if tokens.next().is_some() {
+            return Err(());
+        }
+
+        Ok(Self { columns })
+    }
+}
+
+/// Shortcut for [`Unique`] variant of [`Statement`].
+#[macro_export]
+macro_rules! unique_statement_variant {
+    ($($arg:tt)*) => {
+        $crate::parser::Statement::Common(
+            $crate::parser::statement::Common::Unique(
+                $($arg)*,
+            ),
+        )
+    };
+}
+
+#[cfg(test)]
+mod unique_tests {
+    use crate::{
+        lexer::{
+            token,
+            token::{Attribute, Delimiter, Keyword, Token},
+        },
+        parser::{
+            statement::{common::Column, dml::CreateDatabase},
+            Statement,
+        },
+        preprocessor::Node,
+    };
+
+    use super::Unique;
+
+    #[test]
+    fn test_unique_try_from_token_vec_basic() {
+        let tokens = vec![
+            Token::Keyword(Keyword::Attribute(Attribute::Unique)),
+            Token::Delimiter(Delimiter::LeftParenthesis),
+            Token::Identifier(token::Identifier("email".to_string())),
+            Token::Delimiter(Delimiter::Comma),
+            Token::Identifier(token::Identifier("domain".to_string())),
+            Token::Delimiter(Delimiter::RightParenthesis),
+        ];
+
+        let actual = Unique::try_from(tokens.as_slice());
+        let expected = Ok(Unique {
+            columns: vec![
+                token::Identifier("email".to_string()),
+                token::Identifier("domain".to_string()),
+            ],
+        });
+
+        assert_eq!(actual, expected);
+        assert_eq!(actual.unwrap().to_string(), "UNIQUE (email, domain)");
+    }
+
+    #[test]
+    fn test_unique_try_from_token_vec_invalid_tokens() {
+        let tokens = vec![
+            Token::Keyword(Keyword::Attribute(Attribute::Unique)),
+            Token::Delimiter(Delimiter::LeftParenthesis),
+            Token::Delimiter(Delimiter::RightParenthesis),
+        ];
+        assert_eq!(Unique::try_from(tokens.as_slice()), Err(()));
+
+        let tokens = vec![
+            Token::Keyword(Keyword::Attribute(Attribute::Unique)),
+            Token::Delimiter(Delimiter::LeftParenthesis),
+            Token::Identifier(token::Identifier("email".to_string())),
+        ];
+        assert_eq!(Unique::try_from(tokens.as_slice()), Err(()));
+    }
+
+    #[test]
+    fn test_unique_can_be_followed() {
+        let unique = Unique {
+            columns: vec![token::Identifier("email".to_string())],
+        };
+
+        assert!(unique.can_be_followed(&Column::new_statement(Column {
+            identifier: token::Identifier("id".to_string()),
+            data_type: token::DataType::Integer,
+            ..Column::default()
+        })));
+        assert!(unique.can_be_followed(&Unique::new_statement(vec![])));
+        assert!(unique.can_be_followed(&Statement::Semicolon));
+        assert!(!unique.can_be_followed(&CreateDatabase::new_statement(
+            token::Identifier("test".to_string())
+        )));
+    }
+}
@@ -0,0 +1,32 @@
+mod select;
+
+use derive_more::Display;
+
+use crate::{parser::Statement, preprocessor::Node};
+
+pub use select::{Select, SelectItem, SelectJoin};
+
+/// Represents an AST node for a DDL operation.
+#[derive(Debug, Display, PartialEq, Clone)]
+pub enum DDL {
+    /// Represents a `SELECT ...` statement.
+    Select(Box<Select>),
+}
+
+impl Node for DDL {
+    fn can_be_followed(&self, next: &Statement) -> bool {
+        match self {
+            DDL::Select(stmnt) => stmnt.can_be_followed(next),
+        }
+    }
+}
+
+/// Shortcut for a [`DDL`] variant of [`Statement`].
+#[macro_export]
+macro_rules! ddl_statement_variant {
+    ($($arg:tt)*) => {
+        $crate::parser::Statement::Ddl(
+            $($arg)*
+        )
+    };
+}
@@ -0,0 +1,277 @@
+use std::fmt::Display;
+
+use backend::{
+    controller::table::selector::OrderBy,
+    expression::Expression,
+    query::{Aggregate, JoinType},
+};
+
+use crate::{lexer::token, parser::Statement, preprocessor::LeafNode};
+
+/// Describes `SELECT ... FROM ... [[AS] ...] [... JOIN ...] [WHERE ...]
+/// [GROUP BY ...] [HAVING ...] [ORDER BY ...] [LIMIT ...] [OFFSET ...]`
+/// statement for AST.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Select {
+    /// Items of the select list, `None` for `*`.
+    pub items: Option<Vec<SelectItem>>,
+
+    /// Name of the table.
+    pub identifier: token::Identifier,
+
+    /// Alias of the table.
+    pub alias: Option<token::Identifier>,
+
+    /// The table joined with the table.
+    pub join: Option<SelectJoin>,
+
+    /// Condition of the `WHERE` clause.
+    pub condition: Option<Expression>,
+
+    /// Expressions of the `GROUP BY` clause.
+    pub group_by: Vec<Expression>,
+
+    /// Condition of the `HAVING` clause.
+    pub having: Option<Expression>,
+
+    /// Aggregates of the select list, `HAVING` and `ORDER BY`. These
+    /// expressions refer to an aggregate as a column named after it, e.g.
+    /// `COUNT(*)`.
+    pub aggregates: Vec<Aggregate>,
+
+    /// Items of the `ORDER BY` clause.
+    pub order_by: Vec<OrderBy>,
+
+    /// Number of rows of the `LIMIT` clause.
+    pub limit: Option<usize>,
+
+    /// Number of rows of the `OFFSET` clause.
+    pub offset: Option<usize>,
+}
+
+/// Describes an item of the select list, e.g. `price * 2 AS twice`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SelectItem {
+    /// Expression of the result column.
+    pub expression: Expression,
+
+    /// Name of the result column given by `AS`.
+    pub alias: Option<token::Identifier>,
+}
+
+/// Describes a join of the `FROM` clause, e.g.
+/// `LEFT JOIN orders AS o ON users.id = o.user_id`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SelectJoin {
+    /// Type of the join.
+    pub join_type: JoinType,
+
+    /// Name of the joined table.
+    pub identifier: token::Identifier,
+
+    /// Alias of the joined table.
+    pub alias: Option<token::Identifier>,
+
+    /// Condition of `ON`, `None` for `CROSS JOIN`.
+    pub condition: Option<Expression>,
+}
+
+impl Display for Select {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SELECT ")?;
+        match &self.items {
+            Some(items) => {
+                for (position, item) in items.iter().enumerate() {
+                    if position > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", item)?;
+                }
+            }
+            None => write!(f, "*")?,
+        }
+        write!(f, " FROM {}", self.identifier)?;
+        if let Some(alias) = &self.alias {
+            write!(f, " AS {}", alias)?;
+        }
+        if let Some(join) = &self.join {
+            write!(f, " {}", join)?;
+        }
+        if let Some(condition) = &self.condition {
+            write!(f, " WHERE {}", condition)?;
+        }
+        for (position, expression) in self.group_by.iter().enumerate() {
+            let prefix = if position == 0 { " GROUP BY" } else { "," };
+            write!(f, "{} {}", prefix, expression)?;
+        }
+        if let Some(having) = &self.having {
+            write!(f, " HAVING {}", having)?;
+        }
+        for (position, item) in self.order_by.iter().enumerate() {
+            let prefix = if position == 0 { " ORDER BY" } else { "," };
+            write!(f, "{} {}", prefix, item)?;
+        }
+        if let Some(limit) = self.limit {
+            write!(f, " LIMIT {}", limit)?;
+        }
+        if let Some(offset) = self.offset {
+            write!(f, " OFFSET {}", offset)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for SelectItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.expression)?;
+        if let Some(alias) = &self.alias {
+            write!(f, " AS {}", alias)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for SelectJoin {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.join_type, self.identifier)?;
+        if let Some(alias) = &self.alias {
+            write!(f, " AS {}", alias)?;
+        }
+        if let Some(condition) = &self.condition {
+            write!(f, " ON {}", condition)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Select {
+    /// Creates a new `Select` statement.
+    /// # Arguments
+    /// * `select` - The `Select` statement.
+    /// # Returns
+    /// * New instance of `Select` [`Statement`].
+    pub fn new_statement(select: Select) -> Statement {
+        use crate::select_statement_variant;
+
+        select_statement_variant!(Box::new(select))
+    }
+}
+
+impl LeafNode for Select {}
+
+/// Shortcut for [`Select`] variant of [`Statement`].
+#[macro_export]
+macro_rules! select_statement_variant {
+    ($($arg:tt)*) => {
+        $crate::parser::Statement::Ddl(
+            $crate::parser::statement::DDL::Select(
+                $($arg)*,
+            ),
+        )
+    };
+}
+
+#[cfg(test)]
+mod select_tests {
+    use backend::{
+        controller::table::selector::OrderBy,
+        expression::{BinaryOperator, Expression},
+        query::{Aggregate, JoinType},
+        schema::r#type::r#enum::StorageData,
+    };
+
+    use super::{Select, SelectItem, SelectJoin};
+
+    #[test]
+    fn test_select_display() {
+        let select = Select {
+            identifier: "users".to_string().into(),
+            ..Default::default()
+        };
+        assert_eq!(select.to_string(), "SELECT * FROM users");
+
+        let select = Select {
+            items: Some(vec![
+                SelectItem {
+                    expression: Expression::Column("id".into()),
+                    alias: None,
+                },
+                SelectItem {
+                    expression: Expression::Column("name".into()),
+                    alias: Some("user".to_string().into()),
+                },
+            ]),
+            identifier: "users".to_string().into(),
+            condition: Some(Expression::binary(
+                Expression::Column("id".into()),
+                BinaryOperator::GreaterThan,
+                Expression::Literal(StorageData::from(1)),
+            )),
+            ..Default::default()
+        };
+        assert_eq!(
+            select.to_string(),
+            "SELECT id, name AS user FROM users WHERE id > 1"
+        );
+
+        let select = Select {
+            identifier: "users".to_string().into(),
+            order_by: vec![
+                OrderBy::desc(Expression::Column("age".into())).nulls_last(),
+                OrderBy::asc(Expression::Column("id".into())),
+            ],
+            limit: Some(10),
+            offset: Some(20),
+            ..Default::default()
+        };
+        assert_eq!(
+            select.to_string(),
+            "SELECT * FROM users ORDER BY age DESC NULLS LAST, id ASC \
+             LIMIT 10 OFFSET 20"
+        );
+
+        let count = Expression::Column("COUNT(*)".into());
+        let select = Select {
+            items: Some(vec![SelectItem {
+                expression: count.clone(),
+                alias: None,
+            }]),
+            identifier: "users".to_string().into(),
+            group_by: vec![Expression::Column("age".into())],
+            having: Some(Expression::binary(
+                count,
+                BinaryOperator::GreaterThan,
+                Expression::Literal(StorageData::from(1)),
+            )),
+            aggregates: vec![Aggregate::count_all()],
+            ..Default::default()
+        };
+        assert_eq!(
+            select.to_string(),
+            "SELECT COUNT(*) FROM users GROUP BY age HAVING COUNT(*) > 1"
+        );
+
+        let select = Select {
+            identifier: "users".to_string().into(),
+            alias: Some("u".to_string().into()),
+            join: Some(SelectJoin {
+                join_type: JoinType::Left,
+                identifier: "orders".to_string().into(),
+                alias: None,
+                condition: Some(Expression::binary(
+                    Expression::Column("u.id".into()),
+                    BinaryOperator::Equal,
+                    Expression::Column("orders.user_id".into()),
+                )),
+            }),
+            ..Default::default()
+        };
+        assert_eq!(
+            select.to_string(),
+            "SELECT * FROM users AS u LEFT JOIN orders ON u.id = orders.user_id"
+        );
+    }
+}
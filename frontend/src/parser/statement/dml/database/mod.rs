@@ -2,6 +2,7 @@ mod create;
 mod drop;
 mod show;
 mod r#use;
+mod verify;
 
 use derive_more::Display;
 
@@ -11,6 +12,7 @@ pub use create::CreateDatabase;
 pub use drop::DropDatabase;
 pub use r#use::UseDatabase;
 pub use show::ShowDatabases;
+pub use verify::VerifyDatabase;
 
 /// Represents an AST node for a database operation.
 #[derive(Debug, Display, PartialEq, Clone)]
@@ -26,6 +28,9 @@ pub enum DatabaseNode {
 
     /// Represents a `SHOW DATABASES ...` statement.
     Show(ShowDatabases),
+
+    /// Represents a `VERIFY DATABASE ...` statement.
+    Verify(VerifyDatabase),
 }
 
 impl Node for DatabaseNode {
@@ -35,6 +40,7 @@ impl Node for DatabaseNode {
             DatabaseNode::Create(stmnt) => stmnt.can_be_followed(next),
             DatabaseNode::Use(stmnt) => stmnt.can_be_followed(next),
             DatabaseNode::Show(stmnt) => stmnt.can_be_followed(next),
+            DatabaseNode::Verify(stmnt) => stmnt.can_be_followed(next),
         }
     }
 }
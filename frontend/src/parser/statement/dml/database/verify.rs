@@ -0,0 +1,146 @@
+use crate::{
+    lexer::{
+        token,
+        token::{DBObject, Keyword, Token},
+    },
+    parser::Statement,
+    preprocessor::LeafNode,
+};
+use std::fmt::Display;
+
+/// Describes `VERIFY DATABASE ...` statement for AST.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VerifyDatabase {
+    /// Name of the database.
+    pub identifier: token::Identifier,
+}
+
+impl Display for VerifyDatabase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "VERIFY DATABASE {}", self.identifier)
+    }
+}
+
+impl VerifyDatabase {
+    /// Creates a new [`VerifyDatabase`] statement.
+    /// # Arguments
+    /// * `identifier` - Name of the database.
+    /// # Returns
+    /// * New instance of `VerifyDatabase` [`Statement`].
+    pub fn new_statement(identifier: token::Identifier) -> Statement {
+        use crate::verify_database_statement_variant;
+
+        verify_database_statement_variant!(Self { identifier })
+    }
+}
+
+impl LeafNode for VerifyDatabase {}
+
+impl TryFrom<&[Token]> for VerifyDatabase {
+    type Error = ();
+
+    fn try_from(tokens: &[Token]) -> Result<Self, Self::Error> {
+        let mut tokens = tokens.iter();
+        let verify = tokens.next().ok_or(())?;
+        let database = tokens.next().ok_or(())?;
+        let identifier = tokens.next().ok_or(())?;
+
+        let Token::DML(token::DMLOperator::Verify) = verify else {
+            return Err(());
+        };
+        let Token::Keyword(Keyword::DbObject(DBObject::Database)) = database
+        else {
+            return Err(());
+        };
+
+        match identifier {
+            Token::Identifier(identifier) => Ok(Self {
+                identifier: identifier.clone(),
+            }),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Shortcut for a [`VerifyDatabase`] variant of [`Statement`].
+#[macro_export]
+macro_rules! verify_database_statement_variant {
+    ($($arg:tt)*) => {
+        $crate::parser::Statement::Dml(
+            $crate::parser::statement::DML::Database(
+                $crate::parser::statement::dml::DatabaseNode::Verify(
+                    $($arg)*,
+                ),
+            ),
+        )
+    };
+}
+
+#[cfg(test)]
+mod verify_database_tests {
+    use crate::{
+        lexer::{token, token::Token},
+        parser::statement::dml::CreateDatabase,
+        preprocessor::Node,
+    };
+
+    use super::VerifyDatabase;
+
+    #[test]
+    fn test_verify_database_try_from_token_vec_basic() {
+        let tokens = vec![
+            Token::DML(token::DMLOperator::Verify),
+            Token::Keyword(token::Keyword::DbObject(token::DBObject::Database)),
+            Token::Identifier(token::Identifier("test".to_string())),
+        ];
+
+        let actual = VerifyDatabase::try_from(tokens.as_slice());
+        let expected = Ok(VerifyDatabase {
+            identifier: token::Identifier("test".to_string()),
+        });
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_verify_database_try_from_token_vec_invalid_tokens() {
+        let tokens = vec![
+            Token::DML(token::DMLOperator::Verify),
+            Token::Keyword(token::Keyword::DbObject(token::DBObject::Table)),
+            Token::Identifier(token::Identifier("test".to_string())),
+        ];
+
+        let actual = VerifyDatabase::try_from(tokens.as_slice());
+        let expected = Err(());
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_verify_database_try_from_token_vec_not_enough_tokens() {
+        let tokens = vec![
+            Token::DML(token::DMLOperator::Verify),
+            Token::Keyword(token::Keyword::DbObject(token::DBObject::Database)),
+        ];
+
+        let actual = VerifyDatabase::try_from(tokens.as_slice());
+        let expected = Err(());
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_verify_database_cant_be_followed_by_nothing() {
+        let verify_database = VerifyDatabase {
+            identifier: token::Identifier("test".to_string()),
+        };
+
+        let identifier = token::Identifier("test".to_string());
+
+        assert!(!verify_database.can_be_followed(
+            &CreateDatabase::new_statement(identifier.clone())
+        ));
+        assert!(!verify_database
+            .can_be_followed(&VerifyDatabase::new_statement(identifier)));
+    }
+}
@@ -0,0 +1,116 @@
+use std::fmt::Display;
+
+use crate::{
+    lexer::{
+        token,
+        token::{DBObject, Keyword, Token},
+    },
+    parser::Statement,
+    preprocessor::LeafNode,
+};
+
+/// Describes `ANALYZE [TABLE] ...` statement for AST.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnalyzeTable {
+    /// Name of the table.
+    pub identifier: token::Identifier,
+}
+
+impl Display for AnalyzeTable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ANALYZE TABLE {}", self.identifier)
+    }
+}
+
+impl AnalyzeTable {
+    /// Creates a new `AnalyzeTable` statement.
+    /// # Arguments
+    /// * `identifier` - Name of the table.
+    /// # Returns
+    /// * New instance of `AnalyzeTable` [`Statement`].
+    pub fn new_statement(identifier: token::Identifier) -> Statement {
+        use crate::analyze_table_statement_variant;
+
+        analyze_table_statement_variant!(Self { identifier })
+    }
+}
+
+impl LeafNode for AnalyzeTable {}
+
+impl TryFrom<&[Token]> for AnalyzeTable {
+    type Error = ();
+
+    fn try_from(tokens: &[Token]) -> Result<Self, Self::Error> {
+        let mut tokens = tokens.iter();
+        let analyze = tokens.next().ok_or(())?;
+        let mut identifier = tokens.next().ok_or(())?;
+
+        let Token::DML(token::DMLOperator::Analyze) = analyze else {
+            return Err(());
+        };
+        if let Token::Keyword(Keyword::DbObject(DBObject::Table)) = identifier {
+            identifier = tokens.next().ok_or(())?;
+        }
+
+        match identifier {
+            Token::Identifier(identifier) => Ok(Self {
+                identifier: identifier.clone(),
+            }),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Shortcut for [`AnalyzeTable`] variant of [`Statement`].
+#[macro_export]
+macro_rules! analyze_table_statement_variant {
+    ($($arg:tt)*) => {
+        $crate::parser::Statement::Dml(
+            $crate::parser::statement::DML::Table(
+                $crate::parser::statement::dml::TableNode::Analyze(
+                    $($arg)*,
+                ),
+            ),
+        )
+    };
+}
+
+#[cfg(test)]
+mod analyze_table_tests {
+    use crate::lexer::{token, token::Token};
+
+    use super::AnalyzeTable;
+
+    #[test]
+    fn test_analyze_table_try_from_token_vec_basic() {
+        let expected = Ok(AnalyzeTable {
+            identifier: token::Identifier("test".to_string()),
+        });
+
+        let tokens = vec![
+            Token::DML(token::DMLOperator::Analyze),
+            Token::Keyword(token::Keyword::DbObject(token::DBObject::Table)),
+            Token::Identifier(token::Identifier("test".to_string())),
+        ];
+        assert_eq!(AnalyzeTable::try_from(tokens.as_slice()), expected);
+
+        let tokens = vec![
+            Token::DML(token::DMLOperator::Analyze),
+            Token::Identifier(token::Identifier("test".to_string())),
+        ];
+        assert_eq!(AnalyzeTable::try_from(tokens.as_slice()), expected);
+    }
+
+    #[test]
+    fn test_analyze_table_try_from_token_vec_invalid_tokens() {
+        let tokens = vec![
+            Token::DML(token::DMLOperator::Analyze),
+            Token::Keyword(token::Keyword::DbObject(token::DBObject::Schema)),
+            Token::Identifier(token::Identifier("test".to_string())),
+        ];
+        assert_eq!(AnalyzeTable::try_from(tokens.as_slice()), Err(()));
+
+        let tokens = vec![Token::DML(token::DMLOperator::Analyze)];
+        assert_eq!(AnalyzeTable::try_from(tokens.as_slice()), Err(()));
+    }
+}
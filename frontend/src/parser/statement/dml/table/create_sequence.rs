@@ -0,0 +1,107 @@
+use std::fmt::Display;
+
+use crate::{
+    lexer::{
+        token,
+        token::{DBObject, Keyword, Token},
+    },
+    parser::Statement,
+    preprocessor::LeafNode,
+};
+
+/// Describes `CREATE SEQUENCE ...` statement for AST.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreateSequence {
+    /// Name of the sequence.
+    pub identifier: token::Identifier,
+}
+
+impl Display for CreateSequence {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "CREATE SEQUENCE {}", self.identifier)
+    }
+}
+
+impl CreateSequence {
+    /// Creates a new `CreateSequence` statement.
+    /// # Arguments
+    /// * `identifier` - Name of the sequence.
+    /// # Returns
+    /// * New instance of `CreateSequence` [`Statement`].
+    pub fn new_statement(identifier: token::Identifier) -> Statement {
+        use crate::create_sequence_statement_variant;
+
+        create_sequence_statement_variant!(Self { identifier })
+    }
+}
+
+impl LeafNode for CreateSequence {}
+
+impl TryFrom<&[Token]> for CreateSequence {
+    type Error = ();
+
+    fn try_from(tokens: &[Token]) -> Result<Self, Self::Error> {
+        let mut tokens = tokens.iter();
+        let create = tokens.next().ok_or(())?;
+        let sequence = tokens.next().ok_or(())?;
+        let identifier = tokens.next().ok_or(())?;
+
+        let Token::DML(token::DMLOperator::Create) = create else {
+            return Err(());
+        };
+        let Token::Keyword(Keyword::DbObject(DBObject::Sequence)) = sequence
+        else {
+            return Err(());
+        };
+
+        match identifier {
+            Token::Identifier(identifier) => Ok(Self {
+                identifier: identifier.clone(),
+            }),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Shortcut for [`CreateSequence`] variant of [`Statement`].
+#[macro_export]
+macro_rules! create_sequence_statement_variant {
+    ($($arg:tt)*) => {
+        $crate::parser::Statement::Dml(
+            $crate::parser::statement::DML::Table(
+                $crate::parser::statement::dml::TableNode::CreateSequence(
+                    $($arg)*,
+                ),
+            ),
+        )
+    };
+}
+
+#[cfg(test)]
+mod create_sequence_tests {
+    use crate::lexer::{token, token::Token};
+
+    use super::CreateSequence;
+
+    #[test]
+    fn test_create_sequence_try_from_token_vec() {
+        let tokens = vec![
+            Token::DML(token::DMLOperator::Create),
+            Token::Keyword(token::Keyword::DbObject(token::DBObject::Sequence)),
+            Token::Identifier(token::Identifier("ids".to_string())),
+        ];
+        assert_eq!(
+            CreateSequence::try_from(tokens.as_slice()),
+            Ok(CreateSequence {
+                identifier: token::Identifier("ids".to_string()),
+            })
+        );
+
+        let tokens = vec![
+            Token::DML(token::DMLOperator::Create),
+            Token::Keyword(token::Keyword::DbObject(token::DBObject::Table)),
+            Token::Identifier(token::Identifier("ids".to_string())),
+        ];
+        assert_eq!(CreateSequence::try_from(tokens.as_slice()), Err(()));
+    }
+}
@@ -0,0 +1,116 @@
+use std::fmt::Display;
+
+use crate::{
+    lexer::{
+        token,
+        token::{DBObject, Keyword, Token},
+    },
+    parser::Statement,
+    preprocessor::LeafNode,
+};
+
+/// Describes `DESCRIBE [TABLE] ...` statement for AST.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DescribeTable {
+    /// Name of the table.
+    pub identifier: token::Identifier,
+}
+
+impl Display for DescribeTable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "DESCRIBE TABLE {}", self.identifier)
+    }
+}
+
+impl DescribeTable {
+    /// Creates a new `DescribeTable` statement.
+    /// # Arguments
+    /// * `identifier` - Name of the table.
+    /// # Returns
+    /// * New instance of `DescribeTable` [`Statement`].
+    pub fn new_statement(identifier: token::Identifier) -> Statement {
+        use crate::describe_table_statement_variant;
+
+        describe_table_statement_variant!(Self { identifier })
+    }
+}
+
+impl LeafNode for DescribeTable {}
+
+impl TryFrom<&[Token]> for DescribeTable {
+    type Error = ();
+
+    fn try_from(tokens: &[Token]) -> Result<Self, Self::Error> {
+        let mut tokens = tokens.iter();
+        let describe = tokens.next().ok_or(())?;
+        let mut identifier = tokens.next().ok_or(())?;
+
+        let Token::DML(token::DMLOperator::Describe) = describe else {
+            return Err(());
+        };
+        if let Token::Keyword(Keyword::DbObject(DBObject::Table)) = identifier {
+            identifier = tokens.next().ok_or(())?;
+        }
+
+        match identifier {
+            Token::Identifier(identifier) => Ok(Self {
+                identifier: identifier.clone(),
+            }),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Shortcut for [`DescribeTable`] variant of [`Statement`].
+#[macro_export]
+macro_rules! describe_table_statement_variant {
+    ($($arg:tt)*) => {
+        $crate::parser::Statement::Dml(
+            $crate::parser::statement::DML::Table(
+                $crate::parser::statement::dml::TableNode::Describe(
+                    $($arg)*,
+                ),
+            ),
+        )
+    };
+}
+
+#[cfg(test)]
+mod describe_table_tests {
+    use crate::lexer::{token, token::Token};
+
+    use super::DescribeTable;
+
+    #[test]
+    fn test_describe_table_try_from_token_vec_basic() {
+        let expected = Ok(DescribeTable {
+            identifier: token::Identifier("test".to_string()),
+        });
+
+        let tokens = vec![
+            Token::DML(token::DMLOperator::Describe),
+            Token::Keyword(token::Keyword::DbObject(token::DBObject::Table)),
+            Token::Identifier(token::Identifier("test".to_string())),
+        ];
+        assert_eq!(DescribeTable::try_from(tokens.as_slice()), expected);
+
+        let tokens = vec![
+            Token::DML(token::DMLOperator::Describe),
+            Token::Identifier(token::Identifier("test".to_string())),
+        ];
+        assert_eq!(DescribeTable::try_from(tokens.as_slice()), expected);
+    }
+
+    #[test]
+    fn test_describe_table_try_from_token_vec_invalid_tokens() {
+        let tokens = vec![
+            Token::DML(token::DMLOperator::Describe),
+            Token::Keyword(token::Keyword::DbObject(token::DBObject::Schema)),
+            Token::Identifier(token::Identifier("test".to_string())),
+        ];
+        assert_eq!(DescribeTable::try_from(tokens.as_slice()), Err(()));
+
+        let tokens = vec![Token::DML(token::DMLOperator::Describe)];
+        assert_eq!(DescribeTable::try_from(tokens.as_slice()), Err(()));
+    }
+}
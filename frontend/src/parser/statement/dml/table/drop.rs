@@ -3,7 +3,7 @@ use std::fmt::Display;
 use crate::{
     lexer::{
         token,
-        token::{DBObject, Keyword, Token},
+        token::{Action, DBObject, Keyword, Token},
     },
     parser::Statement,
     preprocessor::LeafNode,
@@ -14,11 +14,19 @@ use crate::{
 pub struct DropTable {
     /// Name of the table.
     pub identifier: token::Identifier,
+
+    /// Whether the foreign keys referencing the table are dropped as well.
+    pub cascade: bool,
 }
 
 impl Display for DropTable {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "DROP TABLE {}", self.identifier)
+        write!(
+            f,
+            "DROP TABLE {}{}",
+            self.identifier,
+            if self.cascade { " CASCADE" } else { "" }
+        )
     }
 }
 
@@ -26,12 +34,20 @@ impl DropTable {
     /// Creates a new `DropTable` statement.
     /// # Arguments
     /// * `identifier` - Name of the schema.
+    /// * `cascade` - Whether the foreign keys referencing the table are
+    ///   dropped as well.
     /// # Returns
     /// * New instance of `DropTable` [`Statement`].
-    pub fn new_statement(identifier: token::Identifier) -> Statement {
+    pub fn new_statement(
+        identifier: token::Identifier,
+        cascade: bool,
+    ) -> Statement {
         use crate::drop_table_statement_variant;
 
-        drop_table_statement_variant!(Self { identifier })
+        drop_table_statement_variant!(Self {
+            identifier,
+            cascade
+        })
     }
 }
 
@@ -53,12 +69,19 @@ impl TryFrom<&[Token]> for DropTable {
             return Err(());
         };
 
-        match identifier {
-            Token::Identifier(identifier) => Ok(Self {
-                identifier: identifier.clone(),
-            }),
-            _ => Err(()),
-        }
+        let Token::Identifier(identifier) = identifier else {
+            return Err(());
+        };
+        let cascade = match tokens.next() {
+            Some(Token::Keyword(Keyword::Action(Action::Cascade))) => true,
+            Some(_) => return Err(()),
+            None => false,
+        };
+
+        Ok(Self {
+            identifier: identifier.clone(),
+            cascade,
+        })
     }
 }
 
@@ -97,11 +120,31 @@ mod drop_schema_tests {
         let actual = DropTable::try_from(tokens.as_slice());
         let expected = Ok(DropTable {
             identifier: token::Identifier("test".to_string()),
+            cascade: false,
         });
 
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn test_drop_table_try_from_token_vec_cascade() {
+        let tokens = vec![
+            Token::DML(token::DMLOperator::Drop),
+            Token::Keyword(token::Keyword::DbObject(token::DBObject::Table)),
+            Token::Identifier(token::Identifier("test".to_string())),
+            Token::Keyword(token::Keyword::Action(token::Action::Cascade)),
+        ];
+
+        let actual = DropTable::try_from(tokens.as_slice());
+        let expected = DropTable {
+            identifier: token::Identifier("test".to_string()),
+            cascade: true,
+        };
+
+        assert_eq!(actual, Ok(expected.clone()));
+        assert_eq!(expected.to_string(), "DROP TABLE test CASCADE");
+    }
+
     #[test]
     fn test_drop_table_try_from_token_vec_invalid_tokens() {
         let tokens = vec![
@@ -133,6 +176,7 @@ mod drop_schema_tests {
     fn test_drop_table_cant_be_followed_by_nothing() {
         let drop_schema = DropTable {
             identifier: token::Identifier("test".to_string()),
+            cascade: false,
         };
 
         let identifier = token::Identifier("test".to_string());
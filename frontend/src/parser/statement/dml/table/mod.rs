@@ -1,10 +1,16 @@
 use crate::{parser::Statement, preprocessor::Node};
 use derive_more::Display;
 
+mod analyze;
 mod create;
+mod create_sequence;
+mod describe;
 mod drop;
 
+pub use analyze::AnalyzeTable;
 pub use create::CreateTable;
+pub use create_sequence::CreateSequence;
+pub use describe::DescribeTable;
 pub use drop::DropTable;
 
 /// Represents an AST node for a table operation.
@@ -15,6 +21,15 @@ pub enum TableNode {
 
     /// Represents a `DROP TABLE ...` statement.
     Drop(DropTable),
+
+    /// Represents a `DESCRIBE [TABLE] ...` statement.
+    Describe(DescribeTable),
+
+    /// Represents an `ANALYZE [TABLE] ...` statement.
+    Analyze(AnalyzeTable),
+
+    /// Represents a `CREATE SEQUENCE ...` statement.
+    CreateSequence(CreateSequence),
 }
 
 impl Node for TableNode {
@@ -22,6 +37,9 @@ impl Node for TableNode {
         match self {
             TableNode::Create(stmnt) => stmnt.can_be_followed(next),
             TableNode::Drop(stmnt) => stmnt.can_be_followed(next),
+            TableNode::Describe(stmnt) => stmnt.can_be_followed(next),
+            TableNode::Analyze(stmnt) => stmnt.can_be_followed(next),
+            TableNode::CreateSequence(stmnt) => stmnt.can_be_followed(next),
         }
     }
 }
@@ -1,4 +1,5 @@
 pub mod common;
+pub mod ddl;
 pub mod dml;
 pub mod shortcut;
 
@@ -7,6 +8,7 @@ use derive_more::Display;
 use crate::preprocessor::Node;
 
 pub use common::Common;
+pub use ddl::DDL;
 pub use dml::DML;
 pub use shortcut::Shortcut;
 
@@ -16,6 +18,9 @@ pub enum Statement {
     /// Represents a DML operation.
     Dml(DML),
 
+    /// Represents a DDL operation.
+    Ddl(DDL),
+
     /// Represents a common operation.
     Common(Common),
 
@@ -31,6 +36,7 @@ impl Node for Statement {
     fn can_be_followed(&self, next: &Statement) -> bool {
         match self {
             Statement::Dml(stmnt) => stmnt.can_be_followed(next),
+            Statement::Ddl(stmnt) => stmnt.can_be_followed(next),
             Statement::Common(stmnt) => stmnt.can_be_followed(next),
             Statement::Shortcut(stmnt) => stmnt.can_be_followed(next),
             Statement::Semicolon => true,
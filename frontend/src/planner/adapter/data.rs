@@ -0,0 +1,205 @@
+use backend::{
+    controller::table::selector::DataSelector,
+    expression::Expression,
+    query::{Aggregation, Join, JoinedTable},
+    schema::{self, column, database, table},
+};
+use backend_api::api::command::{schema::JoinTables, table::Select};
+
+use crate::{
+    lexer::token,
+    parser::{
+        ast,
+        statement::ddl::{self, SelectItem},
+    },
+    planner::adapter::{
+        parse_identifier, same_name, ParseError, WrongIdentifierError,
+    },
+    select_statement_variant,
+};
+
+impl TryFrom<ast::Node> for Select {
+    type Error = ParseError;
+
+    fn try_from(node: ast::Node) -> Result<Self, Self::Error> {
+        if let select_statement_variant!(statement) = node.statement {
+            let statement = *statement;
+            if statement.join.is_some() {
+                return Err(ParseError::UnexpectedStatement(
+                    ddl::Select::new_statement(statement),
+                ));
+            }
+            let mut names =
+                parse_identifier(statement.identifier.clone()).into_iter();
+            let name = names
+                .next()
+                .ok_or(ParseError::WrongIdentifier(WrongIdentifierError {
+                    got: statement.identifier,
+                    expected_type: "`table_name`",
+                }))?
+                .into();
+            let schema_name = names.next().map(|name| name.into());
+            let db_name = names.next().map(|name| name.into());
+
+            let aggregated = !statement.aggregates.is_empty()
+                || !statement.group_by.is_empty()
+                || statement.having.is_some();
+            let group_by = statement.group_by;
+            let group_column = |expression| group_column(expression, &group_by);
+
+            let columns = statement.items.map(|items| {
+                items
+                    .into_iter()
+                    .map(|item| {
+                        result_column(SelectItem {
+                            expression: group_column(item.expression),
+                            alias: item.alias,
+                        })
+                    })
+                    .collect()
+            });
+            let order_by = statement
+                .order_by
+                .into_iter()
+                .map(|mut item| {
+                    item.expression = group_column(item.expression);
+                    item
+                })
+                .collect();
+            let having = statement.having.map(group_column);
+
+            let aggregation = aggregated.then_some(Aggregation {
+                group_by,
+                aggregates: statement.aggregates,
+                having,
+            });
+
+            Ok(Select {
+                database_name: db_name,
+                schema_name,
+                name,
+                selector: DataSelector {
+                    columns,
+                    condition: statement.condition,
+                    order_by,
+                    limit: statement.limit,
+                    offset: statement.offset.unwrap_or_default(),
+                    ..Default::default()
+                },
+                aggregation,
+            })
+        } else {
+            Err(ParseError::UnexpectedStatement(node.statement))
+        }
+    }
+}
+
+impl TryFrom<ast::Node> for JoinTables {
+    type Error = ParseError;
+
+    fn try_from(node: ast::Node) -> Result<Self, Self::Error> {
+        let select_statement_variant!(statement) = node.statement else {
+            return Err(ParseError::UnexpectedStatement(node.statement));
+        };
+        let statement = *statement;
+        let Some(join) = statement.join.clone() else {
+            return Err(ParseError::UnexpectedStatement(
+                ddl::Select::new_statement(statement),
+            ));
+        };
+        if let Some(clause) = unsupported_with_join(&statement) {
+            return Err(ParseError::UnsupportedWithJoin(clause));
+        }
+
+        let (left, left_schema, left_db) =
+            joined_table(statement.identifier, statement.alias)?;
+        let (right, right_schema, right_db) =
+            joined_table(join.identifier, join.alias)?;
+
+        Ok(JoinTables {
+            database_name: same_name(left_db, right_db)?,
+            schema_name: same_name(left_schema, right_schema)?,
+            left,
+            right,
+            join: Join {
+                join_type: join.join_type,
+                condition: join.condition,
+            },
+        })
+    }
+}
+
+/// Returns the first clause of the `SELECT` that can't be used with `JOIN`,
+/// the joined rows are returned as they are.
+fn unsupported_with_join(statement: &ddl::Select) -> Option<&'static str> {
+    if statement.items.is_some() {
+        Some("SELECT list")
+    } else if statement.condition.is_some() {
+        Some("WHERE")
+    } else if !statement.group_by.is_empty() {
+        Some("GROUP BY")
+    } else if statement.having.is_some() {
+        Some("HAVING")
+    } else if !statement.order_by.is_empty() {
+        Some("ORDER BY")
+    } else if statement.limit.is_some() {
+        Some("LIMIT")
+    } else if statement.offset.is_some() {
+        Some("OFFSET")
+    } else {
+        None
+    }
+}
+
+/// Converts a table of `FROM` into a [`JoinedTable`].
+/// # Returns
+/// * The table and the names of its schema and database, if given.
+fn joined_table(
+    identifier: token::Identifier,
+    alias: Option<token::Identifier>,
+) -> Result<
+    (JoinedTable, Option<schema::Name>, Option<database::Name>),
+    ParseError,
+> {
+    let mut names = parse_identifier(identifier.clone()).into_iter();
+    let name: table::Name = names
+        .next()
+        .ok_or(ParseError::WrongIdentifier(WrongIdentifierError {
+            got: identifier,
+            expected_type: "`table_name`",
+        }))?
+        .into();
+    let schema_name = names.next().map(|name| name.into());
+    let db_name = names.next().map(|name| name.into());
+    let table = match alias {
+        Some(alias) => JoinedTable::aliased(name, alias.0.into()),
+        None => name.into(),
+    };
+
+    Ok((table, schema_name, db_name))
+}
+
+/// Converts an item of the select list into a result column. Columns
+/// without an alias are named after the selected column or, for other
+/// expressions, after the expression itself, e.g. `price * 2`.
+fn result_column(item: SelectItem) -> (Expression, column::Name) {
+    let name = match (item.alias, &item.expression) {
+        (Some(alias), _) => alias.0.into(),
+        (None, Expression::Column(name)) => name.clone(),
+        (None, expression) => expression.to_string().into(),
+    };
+
+    (item.expression, name)
+}
+
+/// Replaces an expression equal to a `GROUP BY` expression with the column
+/// of the aggregated rows named after it, see
+/// [`Aggregation::column_names`]. Only the whole expression is compared, so
+/// `age + 1` with `GROUP BY age` stays as it is.
+fn group_column(expression: Expression, group_by: &[Expression]) -> Expression {
+    if group_by.contains(&expression) {
+        Expression::Column(expression.to_string().as_str().into())
+    } else {
+        expression
+    }
+}
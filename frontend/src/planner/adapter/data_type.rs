@@ -5,17 +5,31 @@ use crate::lexer::token;
 impl From<token::DataType> for StorageDataType {
     fn from(data_type: token::DataType) -> Self {
         match data_type {
-            token::DataType::Integer => StorageDataType::Integer,
+            token::DataType::Integer | token::DataType::Serial => {
+                StorageDataType::Integer
+            }
             token::DataType::UInteger => StorageDataType::UInteger,
             token::DataType::Bool => StorageDataType::Bool,
             token::DataType::Byte => StorageDataType::Byte,
             token::DataType::Short => StorageDataType::Short,
             token::DataType::UShort => StorageDataType::UShort,
-            token::DataType::Long => StorageDataType::Long,
+            token::DataType::Long | token::DataType::BigSerial => {
+                StorageDataType::Long
+            }
             token::DataType::ULong => StorageDataType::ULong,
             token::DataType::Float => StorageDataType::Float,
             token::DataType::Double => StorageDataType::Double,
             token::DataType::VarChar(size) => StorageDataType::VarChar(size),
+            token::DataType::Text => StorageDataType::Text,
+            token::DataType::Blob => StorageDataType::Blob,
+            token::DataType::Date => StorageDataType::Date,
+            token::DataType::Time => StorageDataType::Time,
+            token::DataType::Timestamp => StorageDataType::Timestamp,
+            token::DataType::Interval => StorageDataType::Interval,
+            token::DataType::Uuid => StorageDataType::Uuid,
+            token::DataType::Decimal(precision, scale) => {
+                StorageDataType::Decimal(precision, scale)
+            }
         }
     }
 }
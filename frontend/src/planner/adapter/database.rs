@@ -1,5 +1,6 @@
 use backend_api::api::command::backend_api::{
     CreateDatabase, DropDatabase, ShowDatabases, UseDatabase, UseSchema,
+    VerifyDatabase,
 };
 
 use crate::{
@@ -7,7 +8,7 @@ use crate::{
     parser::ast,
     planner::adapter::{parse_identifier, ParseError, WrongIdentifierError},
     show_databases_statement_variant, use_database_statement_variant,
-    use_schema_statement_variant,
+    use_schema_statement_variant, verify_database_statement_variant,
 };
 
 impl TryFrom<ast::Node> for CreateDatabase {
@@ -56,6 +57,29 @@ impl TryFrom<ast::Node> for DropDatabase {
     }
 }
 
+impl TryFrom<ast::Node> for VerifyDatabase {
+    type Error = ParseError;
+
+    fn try_from(node: ast::Node) -> Result<Self, Self::Error> {
+        if let verify_database_statement_variant!(statement) = node.statement {
+            let names = parse_identifier(statement.identifier.clone());
+            if names.len() != 1 {
+                return Err(ParseError::WrongIdentifier(
+                    WrongIdentifierError {
+                        got: statement.identifier,
+                        expected_type: "db_name",
+                    },
+                ));
+            }
+            let name = names.into_iter().next().expect("names is not empty");
+
+            Ok(VerifyDatabase { name: name.into() })
+        } else {
+            Err(ParseError::UnexpectedStatement(node.statement))
+        }
+    }
+}
+
 impl TryFrom<ast::Node> for UseDatabase {
     type Error = ParseError;
 
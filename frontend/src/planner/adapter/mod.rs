@@ -1,6 +1,6 @@
 use crate::{
     lexer::{token, token::Token},
-    parser::Statement,
+    parser::{statement::common::ColumnDefault, Statement},
     planner::command::FrontendCommand,
 };
 use backend::schema::database as db;
@@ -8,6 +8,7 @@ use backend_api::api::command::r#enum::BackendCommand;
 use derive_more::{Display, From};
 use std::fmt::Display;
 
+mod data;
 mod data_type;
 mod database;
 mod schema;
@@ -22,6 +23,28 @@ pub fn parse_identifier(identifier: token::Identifier) -> Vec<String> {
     identifier.0.split('.').map(String::from).rev().collect()
 }
 
+/// Returns the name of the schema or the database given for either of two
+/// tables which must be in the same one.
+/// # Arguments
+/// * `left` - The name given for the first table.
+/// * `right` - The name given for the second table.
+/// # Returns
+/// The given name, or an error if both are given and differ.
+fn same_name<T: PartialEq + Display>(
+    left: Option<T>,
+    right: Option<T>,
+) -> Result<Option<T>, ParseError> {
+    match (left, right) {
+        (Some(left), Some(right)) if left != right => {
+            Err(ParseError::IdentifierMismatch(IdentifierMismatchError {
+                got: right.to_string(),
+                expected: left.to_string(),
+            }))
+        }
+        (left, right) => Ok(left.or(right)),
+    }
+}
+
 /// Represents a planner command.
 #[derive(Debug, Clone, From, PartialEq)]
 pub enum PlannerCommand {
@@ -50,6 +73,14 @@ pub enum ParseError {
 
     /// Error of unexpected statement.
     UnexpectedStatement(Statement),
+
+    /// Error of a clause of `SELECT` that can't be used with `JOIN`.
+    #[from(ignore)]
+    UnsupportedWithJoin(&'static str),
+
+    /// Error of a `DEFAULT` clause that doesn't give a constant value of
+    /// the column's type.
+    InvalidDefault(ColumnDefault),
 }
 
 impl Display for ParseError {
@@ -70,6 +101,18 @@ impl Display for ParseError {
             ParseError::ExpectedStatement(statement) => {
                 write!(f, "Expected statement: `{}`", statement)
             }
+            ParseError::UnsupportedWithJoin(clause) => write!(
+                f,
+                "`{}` is not supported with `JOIN`, only \
+                 `SELECT * FROM ... JOIN ...` is",
+                clause
+            ),
+            ParseError::InvalidDefault(default) => write!(
+                f,
+                "Invalid default: `{}` is not a constant value of the \
+                 column's type",
+                default
+            ),
         }
     }
 }
@@ -1,12 +1,30 @@
-use backend::schema::{self, column, column::primary_key::PrimaryKey, table};
-use backend_api::api::command::schema::{CreateTable, DropTable};
+use backend::{
+    expression::Expression,
+    schema::{
+        self, column,
+        column::primary_key::PrimaryKey,
+        table,
+        table::constraint::{self, Check, Constraint, ForeignKey, Unique},
+    },
+};
+use backend_api::api::command::schema::{
+    AnalyzeTable, CreateSequence, CreateTable, DescribeTable, DropTable,
+};
 
 use crate::{
-    column_statement_variant, create_table_statement_variant,
-    drop_table_statement_variant,
-    lexer::token::{Key, Keyword, Token},
-    parser::{ast, statement, statement::common::Column},
-    planner::adapter::{parse_identifier, ParseError, WrongIdentifierError},
+    analyze_table_statement_variant, check_statement_variant,
+    column_statement_variant, create_sequence_statement_variant,
+    create_table_statement_variant, describe_table_statement_variant,
+    drop_table_statement_variant, foreign_key_statement_variant,
+    lexer::token::{self, Key, Keyword, Token},
+    parser::{
+        ast, statement,
+        statement::common::{Column, ColumnDefault, References},
+    },
+    planner::adapter::{
+        parse_identifier, same_name, ParseError, WrongIdentifierError,
+    },
+    unique_statement_variant,
 };
 
 impl TryFrom<ast::Node> for DropTable {
@@ -30,6 +48,92 @@ impl TryFrom<ast::Node> for DropTable {
                 database_name: db_name,
                 schema_name,
                 name,
+                cascade: statement.cascade,
+            })
+        } else {
+            Err(ParseError::UnexpectedStatement(node.statement))
+        }
+    }
+}
+
+impl TryFrom<ast::Node> for DescribeTable {
+    type Error = ParseError;
+
+    fn try_from(node: ast::Node) -> Result<Self, Self::Error> {
+        if let describe_table_statement_variant!(statement) = node.statement {
+            let mut names =
+                parse_identifier(statement.identifier.clone()).into_iter();
+            let name = names
+                .next()
+                .ok_or(ParseError::WrongIdentifier(WrongIdentifierError {
+                    got: statement.identifier,
+                    expected_type: "`table_name`",
+                }))?
+                .into();
+            let schema_name = names.next().map(|name| name.into());
+            let db_name = names.next().map(|name| name.into());
+
+            Ok(DescribeTable {
+                database_name: db_name,
+                schema_name,
+                name,
+            })
+        } else {
+            Err(ParseError::UnexpectedStatement(node.statement))
+        }
+    }
+}
+
+impl TryFrom<ast::Node> for AnalyzeTable {
+    type Error = ParseError;
+
+    fn try_from(node: ast::Node) -> Result<Self, Self::Error> {
+        if let analyze_table_statement_variant!(statement) = node.statement {
+            let mut names =
+                parse_identifier(statement.identifier.clone()).into_iter();
+            let name = names
+                .next()
+                .ok_or(ParseError::WrongIdentifier(WrongIdentifierError {
+                    got: statement.identifier,
+                    expected_type: "`table_name`",
+                }))?
+                .into();
+            let schema_name = names.next().map(|name| name.into());
+            let db_name = names.next().map(|name| name.into());
+
+            Ok(AnalyzeTable {
+                database_name: db_name,
+                schema_name,
+                name,
+            })
+        } else {
+            Err(ParseError::UnexpectedStatement(node.statement))
+        }
+    }
+}
+
+impl TryFrom<ast::Node> for CreateSequence {
+    type Error = ParseError;
+
+    fn try_from(node: ast::Node) -> Result<Self, Self::Error> {
+        if let create_sequence_statement_variant!(statement) = node.statement {
+            let mut names =
+                parse_identifier(statement.identifier.clone()).into_iter();
+            let name = names
+                .next()
+                .ok_or(ParseError::WrongIdentifier(WrongIdentifierError {
+                    got: statement.identifier,
+                    expected_type: "`sequence_name`",
+                }))?
+                .into();
+            let schema_name = names.next().map(|name| name.into());
+            let db_name = names.next().map(|name| name.into());
+
+            Ok(CreateSequence {
+                database_name: db_name,
+                schema_name,
+                name,
+                start: 1,
             })
         } else {
             Err(ParseError::UnexpectedStatement(node.statement))
@@ -42,7 +146,7 @@ impl TryFrom<ast::Node> for CreateTable {
 
     fn try_from(node: ast::Node) -> Result<Self, Self::Error> {
         #[rustfmt::skip]
-        let (name, schema_name, db_name)
+        let (name, mut schema_name, mut db_name)
             = if let create_table_statement_variant!(
             statement
         ) = node.statement
@@ -64,10 +168,13 @@ impl TryFrom<ast::Node> for CreateTable {
             return Err(ParseError::UnexpectedStatement(node.statement));
         };
 
-        let (columns, primary_key) = {
+        let (columns, primary_key, constraints) = {
             let mut next = node.next;
             let mut columns = vec![];
             let mut primary_key = None;
+            let mut unique_columns: Vec<Vec<column::Name>> = vec![];
+            let mut references: Vec<(column::Name, References)> = vec![];
+            let mut checks: Vec<statement::common::Check> = vec![];
 
             if next.is_none() {
                 return Err(ParseError::ExpectedStatement(
@@ -82,8 +189,30 @@ impl TryFrom<ast::Node> for CreateTable {
                 {
                     let column_name: column::Name =
                         statement.identifier.0.into();
-                    let data_type = statement.data_type.into();
-                    let column = schema::Column::new(data_type);
+                    let is_serial = matches!(
+                        statement.data_type,
+                        token::DataType::Serial | token::DataType::BigSerial
+                    );
+                    let mut column =
+                        schema::Column::new(statement.data_type.clone().into());
+                    if (is_serial || statement.is_auto_increment)
+                        && !column.set_auto_increment(true)
+                    {
+                        return Err(ParseError::UnexpectedStatement(
+                            node.statement,
+                        ));
+                    }
+
+                    if let Some(default) = statement.default.clone() {
+                        // Values of `AUTO_INCREMENT` columns are generated
+                        // by their sequences.
+                        if column.is_auto_increment() {
+                            return Err(ParseError::UnexpectedStatement(
+                                node.statement,
+                            ));
+                        }
+                        set_default(&mut column, default)?;
+                    }
 
                     if statement.is_primary_key {
                         if primary_key.is_some() {
@@ -93,8 +222,37 @@ impl TryFrom<ast::Node> for CreateTable {
                         }
                         primary_key = Some(column_name.clone());
                     }
+                    if statement.is_unique {
+                        unique_columns.push(vec![column_name.clone()]);
+                    }
+                    if let Some(column_references) = statement.references {
+                        references
+                            .push((column_name.clone(), column_references));
+                    }
+                    checks.extend(statement.checks);
 
                     columns.push((column_name, column));
+                } else if let unique_statement_variant!(statement) =
+                    node.statement.clone()
+                {
+                    unique_columns.push(
+                        statement
+                            .columns
+                            .into_iter()
+                            .map(|identifier| identifier.0.into())
+                            .collect(),
+                    );
+                } else if let check_statement_variant!(statement) =
+                    node.statement.clone()
+                {
+                    checks.push(statement);
+                } else if let foreign_key_statement_variant!(statement) =
+                    node.statement.clone()
+                {
+                    references.push((
+                        statement.column.0.into(),
+                        statement.references,
+                    ));
                 } else {
                     return Err(ParseError::UnexpectedStatement(
                         node.statement,
@@ -113,7 +271,69 @@ impl TryFrom<ast::Node> for CreateTable {
                 ]));
             };
 
-            (columns, primary_key)
+            let mut constraints = unique_columns
+                .into_iter()
+                .map(|columns| {
+                    let column_names = columns
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect::<Vec<_>>()
+                        .join("_");
+                    Constraint::Unique(Unique::new(
+                        format!("{}_{}_key", name, column_names).into(),
+                        columns,
+                    ))
+                })
+                .collect::<Vec<_>>();
+            for (column_name, references) in references {
+                // Only tables of the same schema can be referenced, so the
+                // names given for the referenced table must match the ones
+                // given for the created table, or determine them if omitted.
+                let mut names =
+                    parse_identifier(references.table.clone()).into_iter();
+                let wrong_identifier = || {
+                    ParseError::WrongIdentifier(WrongIdentifierError {
+                        got: references.table.clone(),
+                        expected_type: "`table_name`",
+                    })
+                };
+                let table_name = names.next().ok_or_else(wrong_identifier)?;
+                let reference_schema = names.next().map(|name| name.into());
+                let reference_db = names.next().map(|name| name.into());
+                if names.next().is_some() {
+                    return Err(wrong_identifier());
+                }
+                schema_name = same_name(schema_name, reference_schema)?;
+                db_name = same_name(db_name, reference_db)?;
+                constraints.push(Constraint::ForeignKey(ForeignKey::new(
+                    format!("{}_{}_fkey", name, column_name).into(),
+                    column_name,
+                    table_name.into(),
+                    references.column.0.into(),
+                    references.on_delete,
+                )));
+            }
+            for check in checks {
+                let check_name = match check.name {
+                    Some(check_name) => check_name.0.into(),
+                    None => {
+                        let check_name =
+                            match check.expression.columns().as_slice() {
+                                [column] => {
+                                    format!("{}_{}_check", name, column)
+                                }
+                                _ => format!("{}_check", name),
+                            };
+                        unique_constraint_name(&constraints, check_name)
+                    }
+                };
+                constraints.push(Constraint::Check(Check::new(
+                    check_name,
+                    check.expression,
+                )));
+            }
+
+            (columns, primary_key, constraints)
         };
 
         Ok(CreateTable {
@@ -122,6 +342,64 @@ impl TryFrom<ast::Node> for CreateTable {
             name,
             columns,
             primary_key,
+            constraints,
         })
     }
 }
+
+/// Sets the default of a column. A default value is evaluated and cast to
+/// the column's type once, when the table is created.
+/// # Arguments
+/// * `column` - The column.
+/// * `default` - The `DEFAULT` clause of the column.
+fn set_default(
+    column: &mut schema::Column,
+    default: ColumnDefault,
+) -> Result<(), ParseError> {
+    match &default {
+        ColumnDefault::Function(function)
+            if function.data_type() == column.get_type() =>
+        {
+            column.set_default_function(Some(*function));
+        }
+        ColumnDefault::Value(expression) => {
+            let value = Expression::Cast {
+                operand: Box::new(expression.clone()),
+                data_type: column.get_type(),
+            }
+            .evaluate(|_| None)
+            .map_err(|_| ParseError::InvalidDefault(default.clone()))?;
+            column.set_default(Some(value));
+        }
+        ColumnDefault::Function(_) => {
+            return Err(ParseError::InvalidDefault(default));
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the name, or the name with the smallest number appended if it's
+/// taken by another constraint.
+/// # Arguments
+/// * `constraints` - The constraints of the table.
+/// * `name` - The name of the constraint.
+fn unique_constraint_name(
+    constraints: &[Constraint],
+    name: String,
+) -> constraint::Name {
+    let is_taken = |name: &str| {
+        constraints
+            .iter()
+            .any(|constraint| constraint.get_name().0 == name)
+    };
+    if !is_taken(&name) {
+        return name.into();
+    }
+
+    (1..)
+        .map(|number| format!("{}{}", name, number))
+        .find(|name| !is_taken(name))
+        .expect("exists because the range is unbounded")
+        .into()
+}
@@ -3,7 +3,8 @@ pub mod command;
 mod planners;
 
 use crate::{
-    database_statement_variant, get_context_statement_variant,
+    database_statement_variant, ddl_statement_variant,
+    get_context_statement_variant,
     parser::Statement,
     planner::{
         adapter::PlannerCommand, command::FrontendCommand,
@@ -16,7 +17,7 @@ use crate::{
 
 use crate::planner::{
     adapter::ParseError,
-    planners::{SchemaPlanner, TablePlanner},
+    planners::{DataPlanner, SchemaPlanner, TablePlanner},
 };
 use derive_more::{Display, From};
 
@@ -61,6 +62,9 @@ impl Planner {
                 table_statement_variant!(_) => {
                     Some(TablePlanner::new(node).parse_command())
                 }
+                ddl_statement_variant!(_) => {
+                    Some(DataPlanner::new(node).parse_command())
+                }
                 quit_statement_variant!(_) => {
                     Some(Ok(FrontendCommand::Quit.into()))
                 }
@@ -99,24 +103,48 @@ pub enum PlannerError {
 
 #[cfg(test)]
 mod tests {
-    use backend::schema::{
-        column::primary_key::PrimaryKey, r#type::r#enum::StorageDataType,
-        Column,
+    use backend::{
+        controller::table::selector::{DataSelector, OrderBy},
+        expression::{BinaryOperator, Expression},
+        query::{
+            Aggregate, AggregateFunction, Aggregation, Join, JoinType,
+            JoinedTable,
+        },
+        schema::{
+            column::{default::DefaultFunction, primary_key::PrimaryKey},
+            r#type::r#enum::{StorageData, StorageDataType},
+            table::constraint::{
+                Check, Constraint, ForeignKey, OnDelete, Unique,
+            },
+            Column,
+        },
     };
     use backend_api::api::command::{
         backend_api::{
             CreateDatabase, DatabaseCommand, DropDatabase, ShowDatabases,
-            UseDatabase, UseSchema,
+            UseDatabase, UseSchema, VerifyDatabase,
         },
         database::{
             CreateSchema, DropSchema, RenameSchema, SchemaCommand, ShowSchemas,
         },
         r#enum::BackendCommand,
-        schema::{CreateTable, DropTable, TableCommand},
+        schema::{
+            AnalyzeTable, CreateSequence, CreateTable, DescribeTable,
+            DropTable, JoinTables, TableCommand,
+        },
+        table::{DataCommand, Select},
+    };
+
+    use crate::{
+        lexer::LexError,
+        parser::{self, statement::common::ColumnDefault},
+        preprocessor::PreprocessorError,
     };
 
     use crate::planner::{
-        adapter::PlannerCommand, command::FrontendCommand, Planner,
+        adapter::{IdentifierMismatchError, ParseError, PlannerCommand},
+        command::FrontendCommand,
+        Planner, PlannerError,
     };
 
     #[test]
@@ -163,6 +191,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_verify_database() {
+        let query = "VERIFY DATABASE test;";
+
+        let mut planner = Planner::new(query);
+        let command = planner.next_command();
+
+        assert!(command.is_some());
+        let command = command.unwrap();
+        assert!(command.is_ok());
+        let command = command.unwrap();
+
+        assert_eq!(
+            command,
+            PlannerCommand::Backend(BackendCommand::Database(
+                DatabaseCommand::VerifyDatabase(VerifyDatabase {
+                    name: "test".into()
+                })
+            ))
+        );
+    }
+
     #[test]
     fn test_use_database() {
         let query = "USE DATABASE test;";
@@ -418,7 +468,8 @@ mod tests {
                         "id".into(),
                         Column::new(StorageDataType::Long)
                     )],
-                    primary_key: PrimaryKey::new("pk".into(), "id".into())
+                    primary_key: PrimaryKey::new("pk".into(), "id".into()),
+                    constraints: Vec::new(),
                 })
             ))
         );
@@ -451,12 +502,659 @@ mod tests {
                             Column::new(StorageDataType::VarChar(10))
                         )
                     ],
-                    primary_key: PrimaryKey::new("pk".into(), "id".into())
+                    primary_key: PrimaryKey::new("pk".into(), "id".into()),
+                    constraints: Vec::new(),
                 })
             ))
         );
     }
 
+    #[test]
+    fn test_create_table_type_params() {
+        let query = "CREATE TABLE tbl (id LONG PRIMARY KEY,\
+                                       name VARCHAR(20), price DECIMAL(5));";
+
+        let mut planner = Planner::new(query);
+        assert_eq!(
+            planner.next_command(),
+            Some(Ok(PlannerCommand::Backend(BackendCommand::Table(
+                TableCommand::Create(CreateTable {
+                    database_name: None,
+                    schema_name: None,
+                    name: "tbl".into(),
+                    columns: vec![
+                        ("id".into(), Column::new(StorageDataType::Long)),
+                        (
+                            "name".into(),
+                            Column::new(StorageDataType::VarChar(20))
+                        ),
+                        (
+                            "price".into(),
+                            Column::new(StorageDataType::Decimal(5, 0))
+                        ),
+                    ],
+                    primary_key: PrimaryKey::new("pk".into(), "id".into()),
+                    constraints: Vec::new(),
+                })
+            ))))
+        );
+
+        let query = "CREATE TABLE tbl (id LONG PRIMARY KEY,\
+                                       price DECIMAL(50, 60));";
+
+        let mut planner = Planner::new(query);
+        assert_eq!(
+            planner.next_command(),
+            Some(Err(PlannerError::PreprocessorError(
+                PreprocessorError::ParseError(parser::ParseError::LexError(
+                    LexError::InvalidDataType("DECIMAL(50, 60)".to_string())
+                ))
+            )))
+        );
+    }
+
+    #[test]
+    fn test_create_table_auto_increment() {
+        let query = "CREATE TABLE tbl (id SERIAL PRIMARY KEY,\
+                                       n LONG AUTO_INCREMENT);";
+
+        let mut id = Column::new(StorageDataType::Integer);
+        id.set_auto_increment(true);
+        let mut n = Column::new(StorageDataType::Long);
+        n.set_auto_increment(true);
+
+        let mut planner = Planner::new(query);
+        assert_eq!(
+            planner.next_command(),
+            Some(Ok(PlannerCommand::Backend(BackendCommand::Table(
+                TableCommand::Create(CreateTable {
+                    database_name: None,
+                    schema_name: None,
+                    name: "tbl".into(),
+                    columns: vec![("id".into(), id), ("n".into(), n)],
+                    primary_key: PrimaryKey::new("pk".into(), "id".into()),
+                    constraints: Vec::new(),
+                })
+            ))))
+        );
+
+        let query = "CREATE TABLE tbl (id LONG PRIMARY KEY,\
+                                       name TEXT AUTO_INCREMENT);";
+        let mut planner = Planner::new(query);
+        assert!(matches!(planner.next_command(), Some(Err(_))));
+    }
+
+    #[test]
+    fn test_create_table_unique() {
+        let query = "CREATE TABLE users (id INTEGER PRIMARY KEY,\
+                                         email TEXT UNIQUE,\
+                                         first TEXT, last TEXT,\
+                                         UNIQUE (first, last));";
+
+        let mut planner = Planner::new(query);
+        assert_eq!(
+            planner.next_command(),
+            Some(Ok(PlannerCommand::Backend(BackendCommand::Table(
+                TableCommand::Create(CreateTable {
+                    database_name: None,
+                    schema_name: None,
+                    name: "users".into(),
+                    columns: vec![
+                        ("id".into(), Column::new(StorageDataType::Integer)),
+                        ("email".into(), Column::new(StorageDataType::Text)),
+                        ("first".into(), Column::new(StorageDataType::Text)),
+                        ("last".into(), Column::new(StorageDataType::Text)),
+                    ],
+                    primary_key: PrimaryKey::new("pk".into(), "id".into()),
+                    constraints: vec![
+                        Constraint::Unique(Unique::new(
+                            "users_email_key".into(),
+                            vec!["email".into()],
+                        )),
+                        Constraint::Unique(Unique::new(
+                            "users_first_last_key".into(),
+                            vec!["first".into(), "last".into()],
+                        )),
+                    ],
+                })
+            ))))
+        );
+    }
+
+    #[test]
+    fn test_create_table_check() {
+        let query = "CREATE TABLE users (id INTEGER PRIMARY KEY,\
+                     age INTEGER CHECK (age >= 18) CHECK (age < 150),\
+                     low INTEGER CONSTRAINT positive CHECK (low > 0),\
+                     high INTEGER,\
+                     CHECK (low <= high));";
+
+        let compare = |column: &str, operator, value: i32| {
+            Expression::binary(
+                Expression::Column(column.into()),
+                operator,
+                Expression::Literal(value.into()),
+            )
+        };
+        let mut planner = Planner::new(query);
+        assert_eq!(
+            planner.next_command(),
+            Some(Ok(PlannerCommand::Backend(BackendCommand::Table(
+                TableCommand::Create(CreateTable {
+                    database_name: None,
+                    schema_name: None,
+                    name: "users".into(),
+                    columns: vec![
+                        ("id".into(), Column::new(StorageDataType::Integer)),
+                        ("age".into(), Column::new(StorageDataType::Integer)),
+                        ("low".into(), Column::new(StorageDataType::Integer)),
+                        ("high".into(), Column::new(StorageDataType::Integer)),
+                    ],
+                    primary_key: PrimaryKey::new("pk".into(), "id".into()),
+                    constraints: vec![
+                        Constraint::Check(Check::new(
+                            "users_age_check".into(),
+                            compare(
+                                "age",
+                                BinaryOperator::GreaterThanOrEqual,
+                                18
+                            ),
+                        )),
+                        Constraint::Check(Check::new(
+                            "users_age_check1".into(),
+                            compare("age", BinaryOperator::LessThan, 150),
+                        )),
+                        Constraint::Check(Check::new(
+                            "positive".into(),
+                            compare("low", BinaryOperator::GreaterThan, 0),
+                        )),
+                        Constraint::Check(Check::new(
+                            "users_check".into(),
+                            Expression::binary(
+                                Expression::Column("low".into()),
+                                BinaryOperator::LessThanOrEqual,
+                                Expression::Column("high".into()),
+                            ),
+                        )),
+                    ],
+                })
+            ))))
+        );
+
+        let mut planner = Planner::new(
+            "CREATE TABLE users (id INTEGER PRIMARY KEY, CHECK (id >));",
+        );
+        assert!(matches!(planner.next_command(), Some(Err(_))));
+        let mut planner = Planner::new(
+            "CREATE TABLE users (id INTEGER PRIMARY KEY, CONSTRAINT c (id));",
+        );
+        assert!(matches!(planner.next_command(), Some(Err(_))));
+    }
+
+    #[test]
+    fn test_create_table_foreign_key() {
+        let query = "CREATE TABLE orders (id INTEGER PRIMARY KEY,\
+                     user_id INTEGER REFERENCES users (id) ON DELETE CASCADE,\
+                     parent_id INTEGER REFERENCES orders (id));\
+                     DROP TABLE users CASCADE;";
+
+        let mut planner = Planner::new(query);
+        assert_eq!(
+            planner.next_command(),
+            Some(Ok(PlannerCommand::Backend(BackendCommand::Table(
+                TableCommand::Create(CreateTable {
+                    database_name: None,
+                    schema_name: None,
+                    name: "orders".into(),
+                    columns: vec![
+                        ("id".into(), Column::new(StorageDataType::Integer)),
+                        (
+                            "user_id".into(),
+                            Column::new(StorageDataType::Integer)
+                        ),
+                        (
+                            "parent_id".into(),
+                            Column::new(StorageDataType::Integer)
+                        ),
+                    ],
+                    primary_key: PrimaryKey::new("pk".into(), "id".into()),
+                    constraints: vec![
+                        Constraint::ForeignKey(ForeignKey::new(
+                            "orders_user_id_fkey".into(),
+                            "user_id".into(),
+                            "users".into(),
+                            "id".into(),
+                            OnDelete::Cascade,
+                        )),
+                        Constraint::ForeignKey(ForeignKey::new(
+                            "orders_parent_id_fkey".into(),
+                            "parent_id".into(),
+                            "orders".into(),
+                            "id".into(),
+                            OnDelete::Restrict,
+                        )),
+                    ],
+                })
+            ))))
+        );
+        assert_eq!(
+            planner.next_command(),
+            Some(Ok(PlannerCommand::Backend(BackendCommand::Table(
+                TableCommand::Drop(DropTable {
+                    database_name: None,
+                    schema_name: None,
+                    name: "users".into(),
+                    cascade: true,
+                })
+            ))))
+        );
+    }
+
+    #[test]
+    fn test_create_table_qualified_foreign_key() {
+        let query = "CREATE TABLE d.s.orders (id INTEGER PRIMARY KEY,\
+                     tid INTEGER, FOREIGN KEY (tid) REFERENCES d.s.t(id)\
+                     ON DELETE CASCADE);\
+                     CREATE TABLE orders (id INTEGER PRIMARY KEY,\
+                     tid INTEGER REFERENCES s.t(id));\
+                     CREATE TABLE a.orders (id INTEGER PRIMARY KEY,\
+                     tid INTEGER REFERENCES b.t(id));";
+
+        let create_table = |database_name: Option<&str>,
+                            schema_name: Option<&str>,
+                            on_delete| {
+            Some(Ok(PlannerCommand::Backend(BackendCommand::Table(
+                TableCommand::Create(CreateTable {
+                    database_name: database_name.map(Into::into),
+                    schema_name: schema_name.map(Into::into),
+                    name: "orders".into(),
+                    columns: vec![
+                        ("id".into(), Column::new(StorageDataType::Integer)),
+                        ("tid".into(), Column::new(StorageDataType::Integer)),
+                    ],
+                    primary_key: PrimaryKey::new("pk".into(), "id".into()),
+                    constraints: vec![Constraint::ForeignKey(ForeignKey::new(
+                        "orders_tid_fkey".into(),
+                        "tid".into(),
+                        "t".into(),
+                        "id".into(),
+                        on_delete,
+                    ))],
+                }),
+            ))))
+        };
+
+        let mut planner = Planner::new(query);
+        assert_eq!(
+            planner.next_command(),
+            create_table(Some("d"), Some("s"), OnDelete::Cascade)
+        );
+        assert_eq!(
+            planner.next_command(),
+            create_table(None, Some("s"), OnDelete::Restrict)
+        );
+        assert_eq!(
+            planner.next_command(),
+            Some(Err(PlannerError::ParseError(
+                ParseError::IdentifierMismatch(IdentifierMismatchError {
+                    got: "b".to_string(),
+                    expected: "a".to_string(),
+                })
+            )))
+        );
+    }
+
+    #[test]
+    fn test_create_table_default() {
+        let query = "CREATE TABLE users (\
+                     id UUID PRIMARY KEY DEFAULT gen_random_uuid(),\
+                     visits LONG DEFAULT 1 + 1);\
+                     CREATE TABLE users (id INTEGER DEFAULT gen_random_uuid()\
+                     PRIMARY KEY);\
+                     CREATE TABLE users (id INTEGER PRIMARY KEY DEFAULT id);\
+                     CREATE TABLE users (id SERIAL PRIMARY KEY DEFAULT 1);";
+
+        let mut id = Column::new(StorageDataType::Uuid);
+        id.set_default_function(Some(DefaultFunction::GenRandomUuid));
+        let mut visits = Column::new(StorageDataType::Long);
+        visits.set_default(StorageDataType::Long.integer_value(2));
+
+        let mut planner = Planner::new(query);
+        assert_eq!(
+            planner.next_command(),
+            Some(Ok(PlannerCommand::Backend(BackendCommand::Table(
+                TableCommand::Create(CreateTable {
+                    database_name: None,
+                    schema_name: None,
+                    name: "users".into(),
+                    columns: vec![("id".into(), id), ("visits".into(), visits)],
+                    primary_key: PrimaryKey::new("pk".into(), "id".into()),
+                    constraints: vec![],
+                })
+            ))))
+        );
+        assert_eq!(
+            planner.next_command(),
+            Some(Err(PlannerError::ParseError(ParseError::InvalidDefault(
+                ColumnDefault::Function(DefaultFunction::GenRandomUuid)
+            ))))
+        );
+        assert_eq!(
+            planner.next_command(),
+            Some(Err(PlannerError::ParseError(ParseError::InvalidDefault(
+                ColumnDefault::Value(Expression::Column("id".into()))
+            ))))
+        );
+        assert!(matches!(
+            planner.next_command(),
+            Some(Err(PlannerError::ParseError(
+                ParseError::UnexpectedStatement(_)
+            )))
+        ));
+    }
+
+    #[test]
+    fn test_describe_table() {
+        let query = "DESCRIBE TABLE test.users; DESCRIBE users;";
+
+        let mut planner = Planner::new(query);
+        assert_eq!(
+            planner.next_command(),
+            Some(Ok(PlannerCommand::Backend(BackendCommand::Table(
+                TableCommand::Describe(DescribeTable {
+                    database_name: None,
+                    schema_name: Some("test".into()),
+                    name: "users".into(),
+                })
+            ))))
+        );
+        assert_eq!(
+            planner.next_command(),
+            Some(Ok(PlannerCommand::Backend(BackendCommand::Table(
+                TableCommand::Describe(DescribeTable {
+                    database_name: None,
+                    schema_name: None,
+                    name: "users".into(),
+                })
+            ))))
+        );
+    }
+
+    #[test]
+    fn test_analyze_table() {
+        let query = "ANALYZE TABLE test.users; ANALYZE users;";
+
+        let mut planner = Planner::new(query);
+        assert_eq!(
+            planner.next_command(),
+            Some(Ok(PlannerCommand::Backend(BackendCommand::Table(
+                TableCommand::Analyze(AnalyzeTable {
+                    database_name: None,
+                    schema_name: Some("test".into()),
+                    name: "users".into(),
+                })
+            ))))
+        );
+        assert_eq!(
+            planner.next_command(),
+            Some(Ok(PlannerCommand::Backend(BackendCommand::Table(
+                TableCommand::Analyze(AnalyzeTable {
+                    database_name: None,
+                    schema_name: None,
+                    name: "users".into(),
+                })
+            ))))
+        );
+    }
+
+    #[test]
+    fn test_create_sequence() {
+        let query = "CREATE SEQUENCE test.ids; CREATE SEQUENCE ids;";
+
+        let mut planner = Planner::new(query);
+        assert_eq!(
+            planner.next_command(),
+            Some(Ok(PlannerCommand::Backend(BackendCommand::Table(
+                TableCommand::CreateSequence(CreateSequence {
+                    database_name: None,
+                    schema_name: Some("test".into()),
+                    name: "ids".into(),
+                    start: 1,
+                })
+            ))))
+        );
+        assert_eq!(
+            planner.next_command(),
+            Some(Ok(PlannerCommand::Backend(BackendCommand::Table(
+                TableCommand::CreateSequence(CreateSequence {
+                    database_name: None,
+                    schema_name: None,
+                    name: "ids".into(),
+                    start: 1,
+                })
+            ))))
+        );
+    }
+
+    #[test]
+    fn test_select() {
+        let query = "SELECT * FROM test.users; \
+                     SELECT id, age + 1, name AS user FROM users \
+                     WHERE age > 18 AND name IS NOT NULL;";
+        let column = |name: &str| Expression::Column(name.into());
+
+        let mut planner = Planner::new(query);
+        assert_eq!(
+            planner.next_command(),
+            Some(Ok(PlannerCommand::Backend(BackendCommand::Data(
+                DataCommand::Select(Box::new(Select {
+                    database_name: None,
+                    schema_name: Some("test".into()),
+                    name: "users".into(),
+                    selector: DataSelector::default(),
+                    aggregation: None,
+                }))
+            ))))
+        );
+
+        let older = Expression::binary(
+            column("age"),
+            BinaryOperator::Add,
+            Expression::Literal(StorageData::from(1)),
+        );
+        let condition = Expression::binary(
+            Expression::binary(
+                column("age"),
+                BinaryOperator::GreaterThan,
+                Expression::Literal(StorageData::from(18)),
+            ),
+            BinaryOperator::And,
+            Expression::IsNull {
+                operand: Box::new(column("name")),
+                negated: true,
+            },
+        );
+        assert_eq!(
+            planner.next_command(),
+            Some(Ok(PlannerCommand::Backend(BackendCommand::Data(
+                DataCommand::Select(Box::new(Select {
+                    database_name: None,
+                    schema_name: None,
+                    name: "users".into(),
+                    selector: DataSelector {
+                        columns: Some(vec![
+                            (column("id"), "id".into()),
+                            (older, "age + 1".into()),
+                            (column("name"), "user".into()),
+                        ]),
+                        condition: Some(condition),
+                        ..Default::default()
+                    },
+                    aggregation: None,
+                }))
+            ))))
+        );
+        assert_eq!(planner.next_command(), None);
+    }
+
+    #[test]
+    fn test_select_order_by() {
+        let query = "SELECT name FROM users \
+                     ORDER BY age DESC NULLS LAST, id LIMIT 5 OFFSET 10;";
+        let column = |name: &str| Expression::Column(name.into());
+
+        let mut planner = Planner::new(query);
+        assert_eq!(
+            planner.next_command(),
+            Some(Ok(PlannerCommand::Backend(BackendCommand::Data(
+                DataCommand::Select(Box::new(Select {
+                    database_name: None,
+                    schema_name: None,
+                    name: "users".into(),
+                    selector: DataSelector {
+                        columns: Some(vec![(column("name"), "name".into())]),
+                        order_by: vec![
+                            OrderBy::desc(column("age")).nulls_last(),
+                            OrderBy::asc(column("id")),
+                        ],
+                        limit: Some(5),
+                        offset: 10,
+                        ..Default::default()
+                    },
+                    aggregation: None,
+                }))
+            ))))
+        );
+    }
+
+    #[test]
+    fn test_select_group_by() {
+        let query = "SELECT age + 1, COUNT(*) AS n FROM users \
+                     GROUP BY age + 1 HAVING COUNT(*) > 1 \
+                     ORDER BY age + 1; \
+                     SELECT SUM(age) FROM users;";
+        let column = |name: &str| Expression::Column(name.into());
+        let older = Expression::binary(
+            column("age"),
+            BinaryOperator::Add,
+            Expression::Literal(StorageData::from(1)),
+        );
+
+        let mut planner = Planner::new(query);
+        assert_eq!(
+            planner.next_command(),
+            Some(Ok(PlannerCommand::Backend(BackendCommand::Data(
+                DataCommand::Select(Box::new(Select {
+                    database_name: None,
+                    schema_name: None,
+                    name: "users".into(),
+                    selector: DataSelector {
+                        columns: Some(vec![
+                            (column("age + 1"), "age + 1".into()),
+                            (column("COUNT(*)"), "n".into()),
+                        ]),
+                        order_by: vec![OrderBy::asc(column("age + 1"))],
+                        ..Default::default()
+                    },
+                    aggregation: Some(Aggregation {
+                        group_by: vec![older],
+                        aggregates: vec![Aggregate::count_all()],
+                        having: Some(Expression::binary(
+                            column("COUNT(*)"),
+                            BinaryOperator::GreaterThan,
+                            Expression::Literal(StorageData::from(1)),
+                        )),
+                    }),
+                }))
+            ))))
+        );
+
+        let sum = Aggregate::new(AggregateFunction::Sum, column("age"));
+        assert_eq!(
+            planner.next_command(),
+            Some(Ok(PlannerCommand::Backend(BackendCommand::Data(
+                DataCommand::Select(Box::new(Select {
+                    database_name: None,
+                    schema_name: None,
+                    name: "users".into(),
+                    selector: DataSelector {
+                        columns: Some(vec![(
+                            column("SUM(age)"),
+                            "SUM(age)".into()
+                        )]),
+                        ..Default::default()
+                    },
+                    aggregation: Some(Aggregation {
+                        group_by: vec![],
+                        aggregates: vec![sum],
+                        having: None,
+                    }),
+                }))
+            ))))
+        );
+    }
+
+    #[test]
+    fn test_select_join() {
+        let query = "SELECT * FROM users AS u LEFT JOIN test.orders \
+                     ON u.id = orders.user_id; \
+                     SELECT * FROM db.test.a CROSS JOIN b;";
+        let column = |name: &str| Expression::Column(name.into());
+
+        let mut planner = Planner::new(query);
+        assert_eq!(
+            planner.next_command(),
+            Some(Ok(PlannerCommand::Backend(BackendCommand::Join(Box::new(
+                JoinTables {
+                    database_name: None,
+                    schema_name: Some("test".into()),
+                    left: JoinedTable::aliased("users".into(), "u".into()),
+                    right: JoinedTable::from("orders"),
+                    join: Join::new(
+                        JoinType::Left,
+                        Expression::binary(
+                            column("u.id"),
+                            BinaryOperator::Equal,
+                            column("orders.user_id"),
+                        ),
+                    ),
+                }
+            )))))
+        );
+        assert_eq!(
+            planner.next_command(),
+            Some(Ok(PlannerCommand::Backend(BackendCommand::Join(Box::new(
+                JoinTables {
+                    database_name: Some("db".into()),
+                    schema_name: Some("test".into()),
+                    left: JoinedTable::from("a"),
+                    right: JoinedTable::from("b"),
+                    join: Join::cross(),
+                }
+            )))))
+        );
+
+        let mut planner =
+            Planner::new("SELECT * FROM a CROSS JOIN b WHERE a.id = 1;");
+        assert_eq!(
+            planner.next_command(),
+            Some(Err(PlannerError::ParseError(
+                ParseError::UnsupportedWithJoin("WHERE")
+            )))
+        );
+        let mut planner =
+            Planner::new("SELECT * FROM s.a JOIN t.b ON a.id = b.id;");
+        assert_eq!(
+            planner.next_command(),
+            Some(Err(PlannerError::ParseError(
+                ParseError::IdentifierMismatch(IdentifierMismatchError {
+                    got: "t".to_string(),
+                    expected: "s".to_string(),
+                })
+            )))
+        );
+    }
+
     #[test]
     fn test_drop_table_with_db_from() {
         let query = "DROP TABLE xd.test.tbl;";
@@ -476,6 +1174,7 @@ mod tests {
                     database_name: Some("xd".into()),
                     schema_name: Some("test".into()),
                     name: "tbl".into(),
+                    cascade: false,
                 }
             )))
         );
@@ -0,0 +1,48 @@
+use backend_api::api::command::{r#enum::BackendCommand, table::DataCommand};
+
+use crate::{
+    parser::ast,
+    planner::{adapter::PlannerCommand, PlannerError},
+    select_statement_variant,
+};
+
+/// DataPlanner is a planner for operations on the data of tables.
+#[derive(Debug, PartialEq)]
+pub struct DataPlanner {
+    /// The current node.
+    node: ast::Node,
+}
+
+impl DataPlanner {
+    /// Creates a new data planner.
+    /// # Arguments
+    /// * `node` - The current node.
+    pub fn new(node: ast::Node) -> Self {
+        Self { node }
+    }
+
+    /// Parses the command.
+    /// # Errors
+    /// Returns an error if the statement is not a data statement.
+    pub fn parse_command(self) -> Result<PlannerCommand, PlannerError> {
+        let node = self.node;
+
+        match &node.statement {
+            select_statement_variant!(statement)
+                if statement.join.is_some() =>
+            {
+                Ok(BackendCommand::Join(Box::new(
+                    node.try_into().map_err(PlannerError::ParseError)?,
+                ))
+                .into())
+            }
+            select_statement_variant!(_) => {
+                Ok(BackendCommand::Data(DataCommand::Select(Box::new(
+                    node.try_into().map_err(PlannerError::ParseError)?,
+                )))
+                .into())
+            }
+            _ => Err(PlannerError::UnexpectedStatement(node.statement)),
+        }
+    }
+}
@@ -3,7 +3,7 @@ use crate::{
     parser::ast,
     planner::{adapter::PlannerCommand, PlannerError},
     show_databases_statement_variant, use_database_statement_variant,
-    use_schema_statement_variant,
+    use_schema_statement_variant, verify_database_statement_variant,
 };
 use backend_api::api::command::{
     backend_api::DatabaseCommand, database::SchemaCommand,
@@ -62,6 +62,12 @@ impl DatabasePlanner {
                 ))
                 .into())
             }
+            verify_database_statement_variant!(_) => {
+                Ok(BackendCommand::Database(DatabaseCommand::VerifyDatabase(
+                    node.try_into().map_err(PlannerError::ParseError)?,
+                ))
+                .into())
+            }
             _ => Err(PlannerError::UnexpectedStatement(node.statement)),
         }
     }
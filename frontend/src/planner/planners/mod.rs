@@ -1,7 +1,9 @@
+mod data;
 mod database;
 mod schema;
 mod table;
 
+pub use data::DataPlanner;
 pub use database::DatabasePlanner;
 pub use schema::SchemaPlanner;
 pub use table::TablePlanner;
@@ -1,5 +1,7 @@
 use crate::{
-    create_table_statement_variant, drop_table_statement_variant,
+    analyze_table_statement_variant, create_sequence_statement_variant,
+    create_table_statement_variant, describe_table_statement_variant,
+    drop_table_statement_variant,
     parser::ast,
     planner::{adapter::PlannerCommand, PlannerError},
 };
@@ -39,6 +41,24 @@ impl TablePlanner {
                 ))
                 .into())
             }
+            describe_table_statement_variant!(_) => {
+                Ok(BackendCommand::Table(TableCommand::Describe(
+                    node.try_into().map_err(PlannerError::ParseError)?,
+                ))
+                .into())
+            }
+            analyze_table_statement_variant!(_) => {
+                Ok(BackendCommand::Table(TableCommand::Analyze(
+                    node.try_into().map_err(PlannerError::ParseError)?,
+                ))
+                .into())
+            }
+            create_sequence_statement_variant!(_) => {
+                Ok(BackendCommand::Table(TableCommand::CreateSequence(
+                    node.try_into().map_err(PlannerError::ParseError)?,
+                ))
+                .into())
+            }
             _ => Err(PlannerError::UnexpectedStatement(node.statement)),
         }
     }
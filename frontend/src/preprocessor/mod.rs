@@ -321,6 +321,11 @@ mod tests {
                         identifier: "id".to_string().into(),
                         data_type: DataType::Integer,
                         is_primary_key: true,
+                        is_auto_increment: false,
+                        is_unique: false,
+                        references: None,
+                        checks: vec![],
+                        default: None,
                     }),
                     next: None,
                 }))
@@ -347,12 +352,22 @@ mod tests {
                         identifier: "id".to_string().into(),
                         data_type: DataType::Integer,
                         is_primary_key: true,
+                        is_auto_increment: false,
+                        is_unique: false,
+                        references: None,
+                        checks: vec![],
+                        default: None,
                     }),
                     next: Some(Box::new(ast::Node {
                         statement: Column::new_statement(Column {
                             identifier: "name".to_string().into(),
                             data_type: DataType::VarChar(10),
                             is_primary_key: false,
+                            is_auto_increment: false,
+                            is_unique: false,
+                            references: None,
+                            checks: vec![],
+                            default: None,
                         }),
                         next: None,
                     }))
@@ -0,0 +1,152 @@
+//! Derive that decodes structs and enums encoded by the `Storable` derive.
+
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::{parse_quote, spanned::Spanned, DataEnum, DeriveInput, Fields, Type};
+
+/// Implements `serde_storage::de::decoder::Storable`.
+pub fn impl_decode(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let (types, body) = match &input.data {
+        syn::Data::Struct(data) => match &data.fields {
+            Fields::Named(_) => {
+                (field_types(&data.fields)?, decode_struct(&data.fields))
+            }
+            _ => return Err(unsupported(input)),
+        },
+        syn::Data::Enum(data) if data.variants.is_empty() => {
+            return Err(syn::Error::new(
+                input.ident.span(),
+                "Enums without variants can't derive `Decode`",
+            ));
+        }
+        syn::Data::Enum(data) => {
+            let types = data
+                .variants
+                .iter()
+                .map(|variant| field_types(&variant.fields))
+                .collect::<syn::Result<Vec<_>>>()?
+                .concat();
+            (types, decode_enum(data))
+        }
+        _ => return Err(unsupported(input)),
+    };
+
+    let name = &input.ident;
+    let mut generics = input.generics.clone();
+    if generics.type_params().next().is_some() {
+        let where_clause = generics.make_where_clause();
+        for ty in types {
+            where_clause.predicates.push(parse_quote!(
+                #ty: ::serde_storage::de::decoder::Storable<#ty>
+            ));
+        }
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let de = quote!(::serde_storage::de);
+    Ok(quote!(
+        impl #impl_generics #de::decoder::Storable<Self>
+            for #name #ty_generics #where_clause
+        {
+            fn decode(
+                _: #de::decoder::single_item::SingleItemDecoder,
+                _: Vec<u8>,
+            ) -> Result<Self, #de::Error> {
+                Err(#de::Error::NotDeserializable)
+            }
+
+            fn decode_with_descriptor(
+                decoder: #de::decoder::single_item::SingleItemDecoder,
+                value: Vec<u8>,
+                descriptor: Vec<u8>,
+            ) -> Result<Self, #de::Error> {
+                #body
+            }
+        }
+    ))
+}
+
+/// Returns the body of `decode_with_descriptor` of a struct.
+fn decode_struct(fields: &Fields) -> TokenStream {
+    let constructor = construct(quote!(Self), fields);
+
+    quote!(
+        let mut __fields = decoder.emit_struct(value, descriptor)?;
+        let __value = #constructor;
+        __fields.end()?;
+
+        Ok(__value)
+    )
+}
+
+/// Returns the body of `decode_with_descriptor` of an enum, whose variants
+/// are encoded by their index.
+fn decode_enum(data: &DataEnum) -> TokenStream {
+    let variants = data.variants.iter().enumerate().map(|(index, variant)| {
+        let index = index as u32;
+        let ident = &variant.ident;
+        let constructor = construct(quote!(Self::#ident), &variant.fields);
+        quote!(#index => #constructor)
+    });
+
+    quote!(
+        let (__variant, mut __fields) =
+            decoder.emit_enum(value, descriptor)?;
+        let __value = match __variant {
+            #(#variants,)*
+            _ => return Err(::serde_storage::de::Error::InvalidValue),
+        };
+        __fields.end()?;
+
+        Ok(__value)
+    )
+}
+
+/// Returns the expression that builds the struct or variant from the fields
+/// decoded one by one.
+fn construct(path: TokenStream, fields: &Fields) -> TokenStream {
+    let values = fields.iter().map(|field| {
+        let ty = &field.ty;
+        let value = quote_spanned!(ty.span()=> __fields.emit_field::<#ty>()?);
+        match &field.ident {
+            Some(ident) => quote!(#ident: #value),
+            None => value,
+        }
+    });
+
+    match fields {
+        Fields::Named(_) => quote!(#path { #(#values),* }),
+        Fields::Unnamed(_) => quote!(#path(#(#values),*)),
+        Fields::Unit => path,
+    }
+}
+
+/// Returns the types of the fields, that must be decodable.
+fn field_types(fields: &Fields) -> syn::Result<Vec<&Type>> {
+    fields
+        .iter()
+        .map(|field| check_field_type(&field.ty).map(|_| &field.ty))
+        .collect()
+}
+
+/// Returns the error for types that can't derive `Decode`.
+fn unsupported(input: &DeriveInput) -> syn::Error {
+    syn::Error::new(
+        input.ident.span(),
+        "Only structs with named fields and enums can derive `Decode`",
+    )
+}
+
+/// Checks that the field type is an owned type, that can be decoded.
+fn check_field_type(ty: &Type) -> syn::Result<()> {
+    match ty {
+        Type::Path(_) => Ok(()),
+        Type::Group(group) => check_field_type(&group.elem),
+        Type::Paren(paren) => check_field_type(&paren.elem),
+        Type::Tuple(tuple) => tuple.elems.iter().try_for_each(check_field_type),
+        _ => Err(syn::Error::new_spanned(
+            ty,
+            "Field type can't be decoded, use an owned named type or a tuple",
+        )),
+    }
+}
@@ -1,6 +1,9 @@
+mod decode;
+mod row;
+
 use proc_macro::TokenStream;
-use quote::quote;
-use syn::{parse_macro_input, DeriveInput, Fields};
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, spanned::Spanned, DataEnum, DeriveInput, Fields};
 
 #[proc_macro_derive(Storable)]
 pub fn derive_storable(input: TokenStream) -> TokenStream {
@@ -9,12 +12,59 @@ pub fn derive_storable(input: TokenStream) -> TokenStream {
     impl_storable(&input)
 }
 
+/// Derives `serde_storage::de::decoder::Storable` for structs and enums
+/// encoded by the [`Storable`] derive.
+#[proc_macro_derive(Decode)]
+pub fn derive_decode(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    decode::impl_decode(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derives `backend_api::connection::IntoRow`.
+#[proc_macro_derive(IntoRow, attributes(row))]
+pub fn derive_into_row(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    row::impl_into_row(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derives `backend_api::connection::FromRow`.
+#[proc_macro_derive(FromRow, attributes(row))]
+pub fn derive_from_row(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    row::impl_from_row(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derives `backend_api::connection::TableDefinition`.
+#[proc_macro_derive(TableDefinition, attributes(row))]
+pub fn derive_table_definition(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    row::impl_table_definition(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
 fn impl_storable(input: &DeriveInput) -> TokenStream {
+    if let syn::Data::Enum(ref data) = input.data {
+        if !data.variants.is_empty() {
+            return impl_storable_enum(input, data);
+        }
+    }
+
     if let syn::Data::Struct(ref data) = input.data {
         if let Fields::Named(ref fields) = data.fields {
             let field_vals = fields.named.iter().map(|field| {
                 let name = &field.ident;
-                quote!(__storable_data.push(Box::new(self.#name));)
+                quote!(__storable_data.push(Box::new(Clone::clone(&self.#name)));)
             });
 
             let name = input.clone().ident;
@@ -37,8 +87,54 @@ fn impl_storable(input: &DeriveInput) -> TokenStream {
     TokenStream::from(
         syn::Error::new(
             input.ident.span(),
-            "Only structs with named fields can derive `Storable`",
+            "Only structs with named fields and enums can derive `Storable`",
         )
         .to_compile_error(),
     )
 }
+
+/// Implements `serde_storage::ser::Storable` for an enum, whose variants are
+/// encoded by their index.
+fn impl_storable_enum(input: &DeriveInput, data: &DataEnum) -> TokenStream {
+    let variants = data.variants.iter().enumerate().map(|(index, variant)| {
+        let index = index as u32;
+        let ident = &variant.ident;
+        let bindings = variant
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(i, field)| {
+                format_ident!("__field{}", i, span = field.span())
+            })
+            .collect::<Vec<_>>();
+        let pattern = match &variant.fields {
+            Fields::Named(fields) => {
+                let names = fields.named.iter().map(|field| &field.ident);
+                quote!(Self::#ident { #(#names: #bindings),* })
+            }
+            Fields::Unnamed(_) => quote!(Self::#ident(#(#bindings),*)),
+            Fields::Unit => quote!(Self::#ident),
+        };
+
+        quote!(
+            #pattern => encoder.emit_enum(
+                #index,
+                &[#(#bindings as &dyn serde_storage::ser::Storable),*],
+            )
+        )
+    });
+
+    let name = &input.ident;
+    TokenStream::from(quote!(
+        impl serde_storage::ser::Storable for #name {
+            fn encode(
+                &self,
+                encoder: serde_storage::ser::SingleItemEncoder,
+            ) -> Result<(), serde_storage::ser::Error> {
+                match self {
+                    #(#variants,)*
+                }
+            }
+        }
+    ))
+}
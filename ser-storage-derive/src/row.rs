@@ -0,0 +1,233 @@
+//! Derives that map structs to table rows of `backend_api::connection`.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{DeriveInput, Fields, Ident, LitInt, LitStr, Type};
+
+/// Field of a struct mapped to a column.
+struct RowField {
+    /// Name of the field.
+    ident: Ident,
+
+    /// Type of the field.
+    ty: Type,
+
+    /// Name of the column.
+    column: String,
+
+    /// Whether the column is the primary key.
+    primary_key: bool,
+
+    /// Length of the `VarChar` column.
+    length: Option<usize>,
+}
+
+/// Struct mapped to a table.
+struct RowStruct {
+    /// Name of the struct.
+    ident: Ident,
+
+    /// Name of the table.
+    table: String,
+
+    /// Fields in the order of the columns.
+    fields: Vec<RowField>,
+}
+
+impl RowStruct {
+    /// Parses the struct and its `#[row(...)]` attributes.
+    fn parse(input: &DeriveInput, derive: &str) -> syn::Result<Self> {
+        let fields = match &input.data {
+            syn::Data::Struct(data) => match &data.fields {
+                Fields::Named(fields) => &fields.named,
+                _ => return Err(unsupported(input, derive)),
+            },
+            _ => return Err(unsupported(input, derive)),
+        };
+
+        let mut table = to_snake_case(&input.ident.to_string());
+        for attr in input.attrs.iter().filter(|a| a.path().is_ident("row")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("table") {
+                    table = meta.value()?.parse::<LitStr>()?.value();
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported `row` attribute"))
+                }
+            })?;
+        }
+
+        let fields = fields
+            .iter()
+            .map(|field| {
+                let ident = field.ident.clone().expect("named field");
+                let mut row_field = RowField {
+                    column: ident.to_string(),
+                    ident,
+                    ty: field.ty.clone(),
+                    primary_key: false,
+                    length: None,
+                };
+
+                for attr in
+                    field.attrs.iter().filter(|a| a.path().is_ident("row"))
+                {
+                    attr.parse_nested_meta(|meta| {
+                        if meta.path.is_ident("rename") {
+                            row_field.column =
+                                meta.value()?.parse::<LitStr>()?.value();
+                        } else if meta.path.is_ident("primary_key") {
+                            row_field.primary_key = true;
+                        } else if meta.path.is_ident("length") {
+                            let length = meta.value()?.parse::<LitInt>()?;
+                            row_field.length = Some(length.base10_parse()?);
+                        } else {
+                            return Err(
+                                meta.error("unsupported `row` attribute")
+                            );
+                        }
+                        Ok(())
+                    })?;
+                }
+
+                Ok(row_field)
+            })
+            .collect::<syn::Result<Vec<_>>>()?;
+
+        Ok(Self {
+            ident: input.ident.clone(),
+            table,
+            fields,
+        })
+    }
+}
+
+/// Returns the error for types that can't derive the trait.
+fn unsupported(input: &DeriveInput, derive: &str) -> syn::Error {
+    syn::Error::new(
+        input.ident.span(),
+        format!("Only structs with named fields can derive `{}`", derive),
+    )
+}
+
+/// Converts `CamelCase` to `snake_case`.
+fn to_snake_case(name: &str) -> String {
+    let mut result = String::new();
+    for (i, c) in name.chars().enumerate() {
+        if c.is_uppercase() {
+            if i != 0 {
+                result.push('_');
+            }
+            result.extend(c.to_lowercase());
+        } else {
+            result.push(c);
+        }
+    }
+
+    result
+}
+
+/// Implements `backend_api::connection::IntoRow`.
+pub fn impl_into_row(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let row = RowStruct::parse(input, "IntoRow")?;
+    let name = &row.ident;
+    let columns = row.fields.iter().map(|field| &field.column);
+    let values = row.fields.iter().map(|field| {
+        let ident = &field.ident;
+        quote!(
+            ::backend_api::connection::ColumnValue::into_value(self.#ident)?
+        )
+    });
+
+    Ok(quote!(
+        impl ::backend_api::connection::IntoRow for #name {
+            fn column_names() -> Vec<::backend::schema::column::Name> {
+                vec![#(::backend::schema::column::Name::from(#columns)),*]
+            }
+
+            fn into_row(
+                self,
+            ) -> Result<
+                Vec<::backend::schema::r#type::r#enum::StorageData>,
+                ::backend_api::connection::RowError,
+            > {
+                Ok(vec![#(#values),*])
+            }
+        }
+    ))
+}
+
+/// Implements `backend_api::connection::FromRow`.
+pub fn impl_from_row(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let row = RowStruct::parse(input, "FromRow")?;
+    let name = &row.ident;
+    let fields = row.fields.iter().map(|field| {
+        let ident = &field.ident;
+        let column = &field.column;
+        quote!(#ident: row.decode(#column)?)
+    });
+
+    Ok(quote!(
+        impl ::backend_api::connection::FromRow for #name {
+            fn from_row(
+                row: ::backend_api::connection::Row<'_>,
+            ) -> Result<Self, ::backend_api::connection::RowError> {
+                Ok(Self { #(#fields),* })
+            }
+        }
+    ))
+}
+
+/// Implements `backend_api::connection::TableDefinition`.
+pub fn impl_table_definition(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let row = RowStruct::parse(input, "TableDefinition")?;
+    let name = &row.ident;
+    let table = &row.table;
+
+    let mut keys = row.fields.iter().filter(|field| field.primary_key);
+    let key = keys.next().ok_or_else(|| {
+        syn::Error::new(
+            name.span(),
+            "`TableDefinition` requires a field with `#[row(primary_key)]`",
+        )
+    })?;
+    if let Some(field) = keys.next() {
+        return Err(syn::Error::new(
+            field.ident.span(),
+            "Only one field can have `#[row(primary_key)]`",
+        ));
+    }
+    let key = &key.column;
+
+    let columns = row.fields.iter().map(|field| {
+        let column = &field.column;
+        let ty = &field.ty;
+        let length = match field.length {
+            Some(length) => quote!(Some(#length)),
+            None => quote!(None),
+        };
+        quote!((
+            ::backend::schema::column::Name::from(#column),
+            <#ty as ::backend_api::connection::ColumnValue>::column(#length),
+        ))
+    });
+
+    Ok(quote!(
+        impl ::backend_api::connection::TableDefinition for #name {
+            fn table_name() -> ::backend::schema::table::Name {
+                ::backend::schema::table::Name::from(#table)
+            }
+
+            fn column_definitions() -> Vec<(
+                ::backend::schema::column::Name,
+                ::backend::schema::Column,
+            )> {
+                vec![#(#columns),*]
+            }
+
+            fn primary_key() -> ::backend::schema::column::Name {
+                ::backend::schema::column::Name::from(#key)
+            }
+        }
+    ))
+}
@@ -1,6 +1,8 @@
-use ser_storage_derive::Storable;
+use ser_storage_derive::{Decode, Storable};
 use serde_storage::{
-    descriptor::backwards::get_type_by_description_bytes, ser::StorageEncoder,
+    de::{decoder::StorageDecoder, Error},
+    descriptor::backwards::get_type_by_description_bytes,
+    ser::StorageEncoder,
 };
 
 #[test]
@@ -34,3 +36,238 @@ fn test_storable() {
         "u32"
     );
 }
+
+#[test]
+fn test_decode() {
+    #[derive(Debug, PartialEq, Storable, Decode)]
+    struct Test {
+        id: u32,
+        name: String,
+        active: bool,
+        score: f64,
+    }
+
+    let test = Test {
+        id: 1,
+        name: "Hello, world!".to_string(),
+        active: true,
+        score: 0.5,
+    };
+
+    let mut encoder = StorageEncoder::new();
+
+    let res = encoder.emit(test);
+    assert!(res.is_ok());
+
+    let bytes = encoder.output.get_bytes();
+    let descriptor = encoder.descriptor.get_descriptor_bytes();
+
+    let mut decoder = StorageDecoder;
+
+    let res = decoder.emit_with_descriptor::<Test>(bytes.clone(), descriptor);
+    assert_eq!(
+        res.unwrap(),
+        Test {
+            id: 1,
+            name: "Hello, world!".to_string(),
+            active: true,
+            score: 0.5,
+        }
+    );
+
+    let res = decoder.emit::<Test>(bytes);
+    assert!(matches!(res, Err(Error::NotDeserializable)));
+}
+
+#[test]
+fn test_decode_mismatched_descriptor() {
+    #[derive(Storable)]
+    struct Encoded {
+        a: u32,
+        b: u16,
+    }
+
+    #[derive(Debug, Decode)]
+    #[allow(dead_code)]
+    struct Decoded {
+        a: u32,
+        b: u32,
+    }
+
+    let mut encoder = StorageEncoder::new();
+    encoder.emit(Encoded { a: 1, b: 2 }).unwrap();
+
+    let bytes = encoder.output.get_bytes();
+    let descriptor = encoder.descriptor.get_descriptor_bytes();
+
+    let mut decoder = StorageDecoder;
+
+    let res = decoder.emit_with_descriptor::<Decoded>(bytes, descriptor);
+    assert!(matches!(res, Err(Error::InvalidType)));
+}
+
+#[test]
+fn test_decode_compile_fail() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
+
+#[test]
+fn test_decode_generic() {
+    #[derive(Debug, PartialEq, Decode)]
+    struct Test<T> {
+        id: u32,
+        value: T,
+    }
+
+    let mut encoder = StorageEncoder::new();
+    encoder
+        .emit_struct(vec![Box::new(1u32), Box::new(2u16)])
+        .unwrap();
+
+    let bytes = encoder.output.get_bytes();
+    let descriptor = encoder.descriptor.get_descriptor_bytes();
+
+    let mut decoder = StorageDecoder;
+
+    let res = decoder.emit_with_descriptor::<Test<u16>>(bytes, descriptor);
+    assert_eq!(res.unwrap(), Test { id: 1, value: 2 });
+}
+
+#[test]
+fn test_decode_nested() {
+    #[derive(Debug, Clone, PartialEq, Storable, Decode)]
+    struct Tag {
+        id: u16,
+        name: String,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Storable, Decode)]
+    struct Post {
+        id: u32,
+        author: Tag,
+        tags: Vec<Tag>,
+        rating: Option<f32>,
+        range: (u8, u8),
+    }
+
+    let post = Post {
+        id: 1,
+        author: Tag {
+            id: 2,
+            name: "ann".to_string(),
+        },
+        tags: vec![Tag {
+            id: 3,
+            name: "rust".to_string(),
+        }],
+        rating: None,
+        range: (1, 5),
+    };
+
+    let mut encoder = StorageEncoder::new();
+    encoder.emit(post.clone()).unwrap();
+
+    let bytes = encoder.output.get_bytes();
+    let descriptor = encoder.descriptor.get_descriptors();
+    assert_eq!(descriptor.len(), 5);
+    assert_eq!(descriptor[1].1, "struct_2");
+    assert_eq!(descriptor[2].1, "vec_1");
+    assert_eq!(descriptor[3].1, "option_none");
+    assert_eq!(descriptor[4].1, "tuple_2");
+
+    let mut decoder = StorageDecoder;
+
+    let res = decoder.emit_with_descriptor::<Post>(
+        bytes,
+        encoder.descriptor.get_descriptor_bytes(),
+    );
+    assert_eq!(res.unwrap(), post);
+}
+
+#[test]
+fn test_decode_enum() {
+    #[derive(Debug, Clone, PartialEq, Storable, Decode)]
+    enum Status {
+        Active,
+        Suspended(u32, String),
+        Deleted { at: u64, reason: Option<String> },
+    }
+
+    let values = vec![
+        Status::Active,
+        Status::Suspended(30, "spam".to_string()),
+        Status::Deleted {
+            at: 1024,
+            reason: None,
+        },
+    ];
+
+    for (variant, value) in values.into_iter().enumerate() {
+        let mut encoder = StorageEncoder::new();
+        encoder.emit(value.clone()).unwrap();
+
+        let descriptor = encoder.descriptor.get_descriptors();
+        assert_eq!(descriptor.len(), 1);
+        assert_eq!(descriptor[0].1, format!("enum_{}", variant));
+
+        let bytes = encoder.output.get_bytes();
+        let mut decoder = StorageDecoder;
+
+        let res = decoder.emit_with_descriptor::<Status>(
+            bytes,
+            encoder.descriptor.get_descriptor_bytes(),
+        );
+        assert_eq!(res.unwrap(), value);
+    }
+
+    let mut decoder = StorageDecoder;
+
+    let res = decoder.emit_with_descriptor::<Status>(
+        vec![],
+        vec![28, 0, 0, 0, 3, 0, 0, 0, 0],
+    );
+    assert!(matches!(res, Err(Error::InvalidValue)));
+
+    let res = decoder.emit_with_descriptor::<Status>(
+        vec![],
+        vec![28, 0, 0, 0, 1, 0, 0, 0, 0],
+    );
+    assert!(matches!(res, Err(Error::InvalidLength)));
+}
+
+#[test]
+fn test_decode_enum_field() {
+    #[derive(Debug, Clone, Copy, PartialEq, Storable, Decode)]
+    enum Code {
+        Ok,
+        NotFound,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Storable, Decode)]
+    struct Response {
+        id: u32,
+        code: Code,
+        codes: Vec<Code>,
+    }
+
+    let response = Response {
+        id: 1,
+        code: Code::NotFound,
+        codes: vec![Code::Ok, Code::NotFound],
+    };
+
+    let mut encoder = StorageEncoder::new();
+    encoder.emit(response.clone()).unwrap();
+
+    let bytes = encoder.output.get_bytes();
+    assert_eq!(bytes, vec![0, 0, 0, 1]);
+
+    let mut decoder = StorageDecoder;
+
+    let res = decoder.emit_with_descriptor::<Response>(
+        bytes,
+        encoder.descriptor.get_descriptor_bytes(),
+    );
+    assert_eq!(res.unwrap(), response);
+}
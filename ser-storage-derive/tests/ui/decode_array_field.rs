@@ -0,0 +1,9 @@
+use ser_storage_derive::Decode;
+
+#[derive(Decode)]
+struct Test {
+    id: u32,
+    pair: (u32, [u32; 2]),
+}
+
+fn main() {}
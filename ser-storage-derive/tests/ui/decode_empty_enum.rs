@@ -0,0 +1,6 @@
+use ser_storage_derive::Decode;
+
+#[derive(Decode)]
+enum Test {}
+
+fn main() {}
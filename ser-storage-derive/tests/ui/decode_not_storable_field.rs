@@ -0,0 +1,9 @@
+use ser_storage_derive::Decode;
+
+#[derive(Decode)]
+struct Test {
+    id: u32,
+    letter: char,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use ser_storage_derive::Decode;
+
+#[derive(Decode)]
+struct Test<'a> {
+    id: u32,
+    name: &'a str,
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+use ser_storage_derive::Decode;
+
+#[derive(Decode)]
+struct Test(u32, u32);
+
+fn main() {}
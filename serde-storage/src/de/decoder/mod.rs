@@ -1,11 +1,25 @@
 pub mod single_item;
 pub mod storable;
-
-use crate::de::{decoder::single_item::SingleItemDecoder, error::Error};
+pub mod r#struct;
+
+use crate::{
+    de::{decoder::single_item::SingleItemDecoder, error::Error},
+    descriptor::backwards::{
+        get_encoded_size, get_item_descriptions, get_type_by_description_bytes,
+        get_variant_by_description_bytes, is_array_by_description_bytes,
+        is_length_prefixed_by_description_bytes,
+        is_varint_by_description_bytes,
+    },
+    varint,
+};
 use std::mem::size_of;
 
+pub use r#struct::StructDecoder;
 pub use storable::Storable;
 
+/// Bytes of a value and its description bytes.
+pub type DescribedValue = (Vec<u8>, Vec<u8>);
+
 /// StorageDecoder is a helper for decoding items.
 #[derive(Default, Debug)]
 pub struct StorageDecoder;
@@ -27,6 +41,81 @@ impl StorageDecoder {
         })
     }
 
+    /// Emit a value nested in an option, a struct, a vector or a tuple. The
+    /// description of a nested struct is replaced by the descriptions of its
+    /// fields, as structs are decoded from them.
+    pub fn emit_nested<T: Storable<T>>(
+        &mut self,
+        value: Vec<u8>,
+        descriptor: Vec<u8>,
+    ) -> Result<T, Error> {
+        let descriptor = if !is_array_by_description_bytes(&descriptor)
+            && get_type_by_description_bytes(&descriptor) == "struct"
+        {
+            get_item_descriptions(&descriptor)
+                .ok_or(Error::InvalidType)?
+                .concat()
+        } else {
+            descriptor
+        };
+
+        self.emit_with_descriptor(value, descriptor)
+    }
+
+    /// Emit a variant of an enum, whose fields are decoded with the
+    /// [`StructDecoder`].
+    /// # Returns
+    /// * `Ok((u32, StructDecoder))` - The variant and the decoder of its
+    ///   fields.
+    /// * `Err(Error)` - If the value isn't an enum.
+    pub fn emit_enum(
+        &mut self,
+        value: Vec<u8>,
+        descriptor: Vec<u8>,
+    ) -> Result<(u32, StructDecoder<'_>), Error> {
+        let variant = get_variant_by_description_bytes(&descriptor)
+            .ok_or(Error::InvalidType)?;
+        let descriptions =
+            get_item_descriptions(&descriptor).ok_or(Error::InvalidType)?;
+        let fields = Self::split_value(value, descriptions)?;
+
+        Ok((variant, StructDecoder::from_fields(self, fields)))
+    }
+
+    /// Split a value into the parts described by the item descriptions.
+    /// # Returns
+    /// * `Ok(Vec<DescribedValue>)` - The bytes and description of each item.
+    /// * `Err(Error)` - If the value doesn't match the descriptions.
+    pub fn split_value(
+        value: Vec<u8>,
+        descriptions: Vec<Vec<u8>>,
+    ) -> Result<Vec<DescribedValue>, Error> {
+        let mut items = Vec::new();
+        let mut i = 0;
+        for description in descriptions {
+            let rest = value.get(i..).ok_or(Error::InvalidLength)?;
+            let size = get_encoded_size(&description, rest).ok_or(
+                if is_varint_by_description_bytes(&description)
+                    || is_length_prefixed_by_description_bytes(&description)
+                {
+                    Error::InvalidLength
+                } else {
+                    Error::InvalidType
+                },
+            )?;
+            let part = value.get(i..i + size).ok_or(Error::InvalidLength)?;
+            i += size;
+
+            items.push((part.to_vec(), description));
+        }
+
+        if i != value.len() {
+            return Err(Error::InvalidLength);
+        }
+
+        Ok(items)
+    }
+
     /// Emit a value with a callback.
     pub fn emit_with<F, T>(&mut self, value_cb: F) -> Result<T, Error>
     where
@@ -86,6 +175,34 @@ impl StorageDecoder {
         Ok(u128::from_be_bytes(bytes))
     }
 
+    /// Emit an unsigned integer encoded as a varint.
+    pub fn emit_varint<T: TryFrom<u128>>(
+        &mut self,
+        value: Vec<u8>,
+    ) -> Result<T, Error> {
+        let (number, len) =
+            varint::decode_unsigned(&value).ok_or(Error::InvalidValue)?;
+        if len != value.len() {
+            return Err(Error::InvalidLength);
+        }
+
+        T::try_from(number).map_err(|_| Error::InvalidValue)
+    }
+
+    /// Emit a signed integer encoded as a varint.
+    pub fn emit_signed_varint<T: TryFrom<i128>>(
+        &mut self,
+        value: Vec<u8>,
+    ) -> Result<T, Error> {
+        let (number, len) =
+            varint::decode_signed(&value).ok_or(Error::InvalidValue)?;
+        if len != value.len() {
+            return Err(Error::InvalidLength);
+        }
+
+        T::try_from(number).map_err(|_| Error::InvalidValue)
+    }
+
     /// Emit a `bool`.
     pub fn emit_bool(&mut self, value: Vec<u8>) -> Result<bool, Error> {
         if value.len() != size_of::<bool>() {
@@ -155,6 +272,81 @@ impl StorageDecoder {
         Ok(string)
     }
 
+    /// Emit a text whose bytes start with its length as a varint.
+    pub fn emit_text(&mut self, value: Vec<u8>) -> Result<String, Error> {
+        let bytes = Self::strip_length_prefix(value)?;
+        String::from_utf8(bytes).map_err(|_| Error::InvalidUtf8)
+    }
+
+    /// Emit binary data whose bytes start with its length as a varint.
+    pub fn emit_blob(&mut self, value: Vec<u8>) -> Result<Vec<u8>, Error> {
+        Self::strip_length_prefix(value)
+    }
+
+    /// Strip the varint length from the start of the value.
+    /// # Returns
+    /// * `Ok(Vec<u8>)` - The bytes after the length.
+    /// * `Err(Error)` - If the length doesn't match the rest of the value.
+    fn strip_length_prefix(mut value: Vec<u8>) -> Result<Vec<u8>, Error> {
+        let (len, prefix_len) =
+            varint::decode_unsigned(&value).ok_or(Error::InvalidLength)?;
+        if prefix_len as u128 + len != value.len() as u128 {
+            return Err(Error::InvalidLength);
+        }
+
+        Ok(value.split_off(prefix_len))
+    }
+
+    /// Emit a date as a number of days.
+    pub fn emit_date(&mut self, value: Vec<u8>) -> Result<i32, Error> {
+        self.emit_i32(value)
+    }
+
+    /// Emit a time of day as a number of microseconds.
+    pub fn emit_time(&mut self, value: Vec<u8>) -> Result<i64, Error> {
+        self.emit_i64(value)
+    }
+
+    /// Emit a timestamp as a number of microseconds.
+    pub fn emit_timestamp(&mut self, value: Vec<u8>) -> Result<i64, Error> {
+        self.emit_i64(value)
+    }
+
+    /// Emit an interval.
+    /// # Returns
+    /// * `Ok((i32, i32, i64))` - Months, days and microseconds of the
+    ///   interval.
+    /// * `Err(Error)` - If the value isn't 16 bytes long.
+    pub fn emit_interval(
+        &mut self,
+        value: Vec<u8>,
+    ) -> Result<(i32, i32, i64), Error> {
+        if value.len() != 16 {
+            return Err(Error::InvalidLength);
+        }
+
+        let months = self.emit_i32(value[0..4].to_vec())?;
+        let days = self.emit_i32(value[4..8].to_vec())?;
+        let micros = self.emit_i64(value[8..16].to_vec())?;
+        Ok((months, days, micros))
+    }
+
+    /// Emit a decimal.
+    /// # Returns
+    /// * `Ok(i128)` - The unscaled value of the decimal.
+    /// * `Err(Error)` - If the value isn't 16 bytes long.
+    pub fn emit_decimal(&mut self, value: Vec<u8>) -> Result<i128, Error> {
+        Ok(self.emit_i128(value)? ^ i128::MIN)
+    }
+
+    /// Emit a UUID.
+    /// # Returns
+    /// * `Ok([u8; 16])` - The bytes of the UUID.
+    /// * `Err(Error)` - If the value isn't 16 bytes long.
+    pub fn emit_uuid(&mut self, value: Vec<u8>) -> Result<[u8; 16], Error> {
+        value.try_into().map_err(|_| Error::InvalidLength)
+    }
+
     /// Emit a f32.
     pub fn emit_f32(&mut self, value: Vec<u8>) -> Result<f32, Error> {
         if value.len() != size_of::<f32>() {
@@ -179,4 +371,13 @@ impl StorageDecoder {
     pub fn emit_bytes(&mut self, value: &[u8]) -> Result<Vec<u8>, Error> {
         Ok(value.to_vec())
     }
+
+    /// Emit a struct, whose fields are decoded with the [`StructDecoder`].
+    pub fn emit_struct(
+        &mut self,
+        value: Vec<u8>,
+        descriptor: Vec<u8>,
+    ) -> Result<StructDecoder<'_>, Error> {
+        StructDecoder::new(self, value, descriptor)
+    }
 }
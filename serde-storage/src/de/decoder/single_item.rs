@@ -1,5 +1,5 @@
 use crate::de::{
-    decoder::{storable::Storable, StorageDecoder},
+    decoder::{storable::Storable, StorageDecoder, StructDecoder},
     error::Error,
 };
 
@@ -49,6 +49,22 @@ impl<'d> SingleItemDecoder<'d> {
         self.decoder.emit_u128(value)
     }
 
+    /// Emit an unsigned integer encoded as a varint.
+    pub fn emit_varint<T: TryFrom<u128>>(
+        self,
+        value: Vec<u8>,
+    ) -> Result<T, Error> {
+        self.decoder.emit_varint(value)
+    }
+
+    /// Emit a signed integer encoded as a varint.
+    pub fn emit_signed_varint<T: TryFrom<i128>>(
+        self,
+        value: Vec<u8>,
+    ) -> Result<T, Error> {
+        self.decoder.emit_signed_varint(value)
+    }
+
     /// Emit a bool.
     pub fn emit_bool(self, value: Vec<u8>) -> Result<bool, Error> {
         self.decoder.emit_bool(value)
@@ -84,6 +100,49 @@ impl<'d> SingleItemDecoder<'d> {
         self.decoder.emit_str(value)
     }
 
+    /// Emit a text of any length.
+    pub fn emit_text(self, value: Vec<u8>) -> Result<String, Error> {
+        self.decoder.emit_text(value)
+    }
+
+    /// Emit binary data of any length.
+    pub fn emit_blob(self, value: Vec<u8>) -> Result<Vec<u8>, Error> {
+        self.decoder.emit_blob(value)
+    }
+
+    /// Emit a date.
+    pub fn emit_date(self, value: Vec<u8>) -> Result<i32, Error> {
+        self.decoder.emit_date(value)
+    }
+
+    /// Emit a time of day.
+    pub fn emit_time(self, value: Vec<u8>) -> Result<i64, Error> {
+        self.decoder.emit_time(value)
+    }
+
+    /// Emit a timestamp.
+    pub fn emit_timestamp(self, value: Vec<u8>) -> Result<i64, Error> {
+        self.decoder.emit_timestamp(value)
+    }
+
+    /// Emit an interval.
+    pub fn emit_interval(
+        self,
+        value: Vec<u8>,
+    ) -> Result<(i32, i32, i64), Error> {
+        self.decoder.emit_interval(value)
+    }
+
+    /// Emit a decimal.
+    pub fn emit_decimal(self, value: Vec<u8>) -> Result<i128, Error> {
+        self.decoder.emit_decimal(value)
+    }
+
+    /// Emit a UUID.
+    pub fn emit_uuid(self, value: Vec<u8>) -> Result<[u8; 16], Error> {
+        self.decoder.emit_uuid(value)
+    }
+
     /// Emit a f32.
     pub fn emit_f32(self, value: Vec<u8>) -> Result<f32, Error> {
         self.decoder.emit_f32(value)
@@ -98,4 +157,22 @@ impl<'d> SingleItemDecoder<'d> {
     pub fn emit_bytes(self, value: &[u8]) -> Result<Vec<u8>, Error> {
         self.decoder.emit_bytes(value)
     }
+
+    /// Emit a variant of an enum.
+    pub fn emit_enum(
+        self,
+        value: Vec<u8>,
+        descriptor: Vec<u8>,
+    ) -> Result<(u32, StructDecoder<'d>), Error> {
+        self.decoder.emit_enum(value, descriptor)
+    }
+
+    /// Emit a struct.
+    pub fn emit_struct(
+        self,
+        value: Vec<u8>,
+        descriptor: Vec<u8>,
+    ) -> Result<StructDecoder<'d>, Error> {
+        self.decoder.emit_struct(value, descriptor)
+    }
 }
@@ -1,6 +1,16 @@
 use crate::{
-    de::{decoder::single_item::SingleItemDecoder, error::Error},
-    descriptor::backwards::get_type_by_description_bytes,
+    de::{
+        decoder::{single_item::SingleItemDecoder, StorageDecoder},
+        error::Error,
+    },
+    descriptor::{
+        backwards::{
+            get_item_descriptions, get_length_by_description_bytes,
+            get_type_by_description_bytes, is_array_by_description_bytes,
+            is_varint_by_description_bytes,
+        },
+        get_type_name,
+    },
 };
 
 pub trait Storable<T> {
@@ -47,6 +57,9 @@ impl Storable<u16> for u16 {
         if get_type_by_description_bytes(descriptor.as_slice()) != "u16" {
             return Err(Error::InvalidType);
         }
+        if is_varint_by_description_bytes(&descriptor) {
+            return decoder.emit_varint(value);
+        }
 
         decoder.emit_u16(value)
     }
@@ -68,6 +81,9 @@ impl Storable<u32> for u32 {
         if get_type_by_description_bytes(descriptor.as_slice()) != "u32" {
             return Err(Error::InvalidType);
         }
+        if is_varint_by_description_bytes(&descriptor) {
+            return decoder.emit_varint(value);
+        }
 
         decoder.emit_u32(value)
     }
@@ -89,6 +105,9 @@ impl Storable<u64> for u64 {
         if get_type_by_description_bytes(descriptor.as_slice()) != "u64" {
             return Err(Error::InvalidType);
         }
+        if is_varint_by_description_bytes(&descriptor) {
+            return decoder.emit_varint(value);
+        }
 
         decoder.emit_u64(value)
     }
@@ -110,6 +129,9 @@ impl Storable<u128> for u128 {
         if get_type_by_description_bytes(descriptor.as_slice()) != "u128" {
             return Err(Error::InvalidType);
         }
+        if is_varint_by_description_bytes(&descriptor) {
+            return decoder.emit_varint(value);
+        }
 
         decoder.emit_u128(value)
     }
@@ -170,6 +192,9 @@ impl Storable<i16> for i16 {
         if get_type_by_description_bytes(descriptor.as_slice()) != "i16" {
             return Err(Error::InvalidType);
         }
+        if is_varint_by_description_bytes(&descriptor) {
+            return decoder.emit_signed_varint(value);
+        }
 
         decoder.emit_i16(value)
     }
@@ -191,6 +216,9 @@ impl Storable<i32> for i32 {
         if get_type_by_description_bytes(descriptor.as_slice()) != "i32" {
             return Err(Error::InvalidType);
         }
+        if is_varint_by_description_bytes(&descriptor) {
+            return decoder.emit_signed_varint(value);
+        }
 
         decoder.emit_i32(value)
     }
@@ -212,6 +240,9 @@ impl Storable<i64> for i64 {
         if get_type_by_description_bytes(descriptor.as_slice()) != "i64" {
             return Err(Error::InvalidType);
         }
+        if is_varint_by_description_bytes(&descriptor) {
+            return decoder.emit_signed_varint(value);
+        }
 
         decoder.emit_i64(value)
     }
@@ -233,6 +264,9 @@ impl Storable<i128> for i128 {
         if get_type_by_description_bytes(descriptor.as_slice()) != "i128" {
             return Err(Error::InvalidType);
         }
+        if is_varint_by_description_bytes(&descriptor) {
+            return decoder.emit_signed_varint(value);
+        }
 
         decoder.emit_i128(value)
     }
@@ -301,23 +335,123 @@ impl Storable<f64> for f64 {
     }
 }
 
-impl Storable<Vec<u8>> for Vec<u8> {
+impl<T: Storable<T>> Storable<Vec<T>> for Vec<T> {
     fn decode(
         decoder: SingleItemDecoder,
         value: Vec<u8>,
-    ) -> Result<Vec<u8>, Error> {
-        decoder.emit_bytes(value.as_slice())
+    ) -> Result<Vec<T>, Error> {
+        if get_type_name::<T>() != "u8" {
+            return Err(Error::NotDeserializable);
+        }
+
+        value
+            .into_iter()
+            .map(|byte| decoder.decoder.emit::<T>(vec![byte]))
+            .collect()
     }
 
     fn decode_with_descriptor(
         decoder: SingleItemDecoder,
         value: Vec<u8>,
         descriptor: Vec<u8>,
-    ) -> Result<Vec<u8>, Error> {
-        if get_type_by_description_bytes(descriptor.as_slice()) != "u8" {
+    ) -> Result<Vec<T>, Error> {
+        let descriptions = if is_array_by_description_bytes(&descriptor) {
+            let length = get_length_by_description_bytes(&descriptor)
+                .ok_or(Error::InvalidType)?;
+            vec![vec![descriptor[0] & 0b0111_1111]; length as usize]
+        } else if get_type_by_description_bytes(&descriptor) == "vec" {
+            get_item_descriptions(&descriptor).ok_or(Error::InvalidType)?
+        } else {
+            return Err(Error::InvalidType);
+        };
+
+        StorageDecoder::split_value(value, descriptions)?
+            .into_iter()
+            .map(|(item, description)| {
+                decoder.decoder.emit_nested::<T>(item, description)
+            })
+            .collect()
+    }
+}
+
+impl<T: Storable<T>> Storable<Option<T>> for Option<T> {
+    fn decode(_: SingleItemDecoder, _: Vec<u8>) -> Result<Option<T>, Error> {
+        Err(Error::NotDeserializable)
+    }
+
+    fn decode_with_descriptor(
+        decoder: SingleItemDecoder,
+        value: Vec<u8>,
+        descriptor: Vec<u8>,
+    ) -> Result<Option<T>, Error> {
+        if is_array_by_description_bytes(&descriptor)
+            || get_type_by_description_bytes(&descriptor) != "option"
+        {
             return Err(Error::InvalidType);
         }
 
-        decoder.emit_bytes(value.as_slice())
+        let descriptions =
+            get_item_descriptions(&descriptor).ok_or(Error::InvalidType)?;
+        match descriptions.into_iter().next() {
+            Some(description) => {
+                decoder.decoder.emit_nested(value, description).map(Some)
+            }
+            None if value.is_empty() => Ok(None),
+            None => Err(Error::InvalidLength),
+        }
+    }
+}
+
+#[rustfmt::skip]
+macro_rules! impl_tuple {
+    ($(($($t:ident),+)),*) => {
+        $(
+            impl<$($t: Storable<$t>),+> Storable<($($t,)+)> for ($($t,)+) {
+                fn decode(
+                    _: SingleItemDecoder,
+                    _: Vec<u8>,
+                ) -> Result<($($t,)+), Error> {
+                    Err(Error::NotDeserializable)
+                }
+
+                fn decode_with_descriptor(
+                    decoder: SingleItemDecoder,
+                    value: Vec<u8>,
+                    descriptor: Vec<u8>,
+                ) -> Result<($($t,)+), Error> {
+                    if is_array_by_description_bytes(&descriptor)
+                        || get_type_by_description_bytes(&descriptor) != "tuple"
+                    {
+                        return Err(Error::InvalidType);
+                    }
+
+                    let descriptions = get_item_descriptions(&descriptor)
+                        .ok_or(Error::InvalidType)?;
+                    let mut items =
+                        StorageDecoder::split_value(value, descriptions)?
+                            .into_iter();
+                    if items.len() != [$(stringify!($t)),+].len() {
+                        return Err(Error::InvalidLength);
+                    }
+
+                    Ok(($(
+                        {
+                            let (item, description) =
+                                items.next().expect("checked length");
+                            decoder.decoder.emit_nested::<$t>(item, description)?
+                        },
+                    )+))
+                }
+            }
+        )*
     }
 }
+
+impl_tuple!(
+    (A),
+    (A, B),
+    (A, B, C),
+    (A, B, C, D),
+    (A, B, C, D, E),
+    (A, B, C, D, E, F)
+);
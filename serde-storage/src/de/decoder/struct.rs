@@ -0,0 +1,123 @@
+use std::vec::IntoIter;
+
+use crate::{
+    de::{
+        decoder::{storable::Storable, DescribedValue, StorageDecoder},
+        error::Error,
+    },
+    ser::encoder::{output_descriptor::DescriptorBytes, OutputDescriptor},
+};
+
+/// StructDecoder is a helper for decoding fields of a struct one by one.
+pub struct StructDecoder<'d> {
+    /// Decoder to use.
+    decoder: &'d mut StorageDecoder,
+
+    /// Bytes and descriptor bytes of the fields that are not decoded yet.
+    fields: IntoIter<DescribedValue>,
+}
+
+impl<'d> StructDecoder<'d> {
+    /// Create a new [`StructDecoder`] by splitting the value into fields
+    /// described by the descriptor.
+    pub fn new(
+        decoder: &'d mut StorageDecoder,
+        value: Vec<u8>,
+        descriptor: Vec<u8>,
+    ) -> Result<Self, Error> {
+        let descriptor: OutputDescriptor = DescriptorBytes(descriptor)
+            .try_into()
+            .map_err(|_| Error::InvalidType)?;
+        let descriptions = descriptor
+            .get_descriptors()
+            .into_iter()
+            .map(|(bytes, _)| bytes)
+            .collect();
+        let fields = StorageDecoder::split_value(value, descriptions)?;
+
+        Ok(Self::from_fields(decoder, fields))
+    }
+
+    /// Create a new [`StructDecoder`] of already split fields.
+    pub(crate) fn from_fields(
+        decoder: &'d mut StorageDecoder,
+        fields: Vec<DescribedValue>,
+    ) -> Self {
+        Self {
+            decoder,
+            fields: fields.into_iter(),
+        }
+    }
+
+    /// Emit the next field.
+    pub fn emit_field<T: Storable<T>>(&mut self) -> Result<T, Error> {
+        let (value, descriptor) =
+            self.fields.next().ok_or(Error::InvalidLength)?;
+
+        self.decoder.emit_nested::<T>(value, descriptor)
+    }
+
+    /// Check that all fields were decoded.
+    pub fn end(self) -> Result<(), Error> {
+        if self.fields.len() != 0 {
+            return Err(Error::InvalidLength);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        de::{decoder::StorageDecoder, error::Error},
+        ser::{Storable, StorageEncoder},
+    };
+
+    fn encode(values: Vec<Box<dyn Storable>>) -> (Vec<u8>, Vec<u8>) {
+        let mut encoder = StorageEncoder::new();
+        encoder.emit_struct(values).unwrap();
+
+        let descriptor = encoder.descriptor.get_descriptor_bytes();
+        (encoder.output.get_bytes(), descriptor)
+    }
+
+    #[test]
+    fn test_struct_decoder() {
+        let (bytes, descriptor) = encode(vec![
+            Box::new(1u32),
+            Box::new("Hello, world!".to_string()),
+            Box::new(true),
+        ]);
+
+        let mut decoder = StorageDecoder;
+        let mut fields = decoder.emit_struct(bytes, descriptor).unwrap();
+
+        assert_eq!(fields.emit_field::<u32>().unwrap(), 1);
+        assert_eq!(fields.emit_field::<String>().unwrap(), "Hello, world!");
+        assert!(fields.emit_field::<bool>().unwrap());
+        assert!(fields.end().is_ok());
+    }
+
+    #[test]
+    fn test_struct_decoder_errors() {
+        let (bytes, descriptor) = encode(vec![Box::new(1u32), Box::new(2u16)]);
+
+        let mut decoder = StorageDecoder;
+
+        let res = decoder.emit_struct(bytes[1..].to_vec(), descriptor.clone());
+        assert!(matches!(res, Err(Error::InvalidLength)));
+
+        let mut fields = decoder
+            .emit_struct(bytes.clone(), descriptor.clone())
+            .unwrap();
+        assert!(matches!(
+            fields.emit_field::<u16>(),
+            Err(Error::InvalidType)
+        ));
+
+        let mut fields = decoder.emit_struct(bytes, descriptor).unwrap();
+        fields.emit_field::<u32>().unwrap();
+        assert!(matches!(fields.end(), Err(Error::InvalidLength)));
+    }
+}
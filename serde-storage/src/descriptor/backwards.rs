@@ -1,7 +1,9 @@
+use crate::varint::{self, VARINT_FLAG};
+
 /// Returns the type of the value by the description bytes. In case of array,
-/// it returns base array type.
+/// it returns base array type, in case of varint the type of the integer.
 pub fn get_type_by_description_bytes(value: &[u8]) -> &'static str {
-    match value[0] & 0b0111_1111 {
+    match value[0] & 0b0011_1111 {
         0 => "bool",
         1 => "u8",
         2 => "u16",
@@ -26,15 +28,205 @@ pub fn get_type_by_description_bytes(value: &[u8]) -> &'static str {
         21 => "char",
         22 => "f32",
         23 => "f64",
+        24 => "option",
+        25 => "struct",
+        26 => "vec",
+        27 => "tuple",
+        28 => "enum",
+        29 => "text",
+        30 => "blob",
+        31 => "date",
+        32 => "time",
+        33 => "timestamp",
+        34 => "interval",
+        35 => "decimal",
+        36 => "uuid",
         _ => "unknown",
     }
 }
 
+/// Returns true if the value is an option, a struct, a vector, a tuple or an
+/// enum by the description bytes.
+pub fn is_composite_by_description_bytes(value: &[u8]) -> bool {
+    !value.is_empty()
+        && !is_array_by_description_bytes(value)
+        && !is_varint_by_description_bytes(value)
+        && matches!(
+            get_type_by_description_bytes(value),
+            "option" | "struct" | "vec" | "tuple" | "enum"
+        )
+}
+
+/// Returns true if the value is a text or a blob by the description bytes,
+/// whose encoded bytes start with their length as a varint.
+pub fn is_length_prefixed_by_description_bytes(value: &[u8]) -> bool {
+    !value.is_empty()
+        && !is_array_by_description_bytes(value)
+        && !is_varint_by_description_bytes(value)
+        && matches!(get_type_by_description_bytes(value), "text" | "blob")
+}
+
+/// Returns the variant of the enum by the description bytes.
+pub fn get_variant_by_description_bytes(value: &[u8]) -> Option<u32> {
+    if !is_composite_by_description_bytes(value)
+        || get_type_by_description_bytes(value) != "enum"
+    {
+        return None;
+    }
+
+    get_length_by_description_bytes(value)
+}
+
+/// Returns the number of items and the offset of their descriptions in the
+/// description bytes of a composite value.
+fn get_items_header(value: &[u8]) -> Option<(u32, usize)> {
+    match get_type_by_description_bytes(value) {
+        "option" => Some((*value.get(1)? as u32, 2)),
+        "enum" => {
+            let mut bytes = [0u8; 4];
+            bytes.copy_from_slice(value.get(5..9)?);
+            Some((u32::from_be_bytes(bytes), 9))
+        }
+        _ => Some((get_length_by_description_bytes(value)?, 5)),
+    }
+}
+
+/// Returns the length of the first description in the description bytes.
+pub fn get_description_len(value: &[u8]) -> Option<usize> {
+    if value.is_empty() {
+        return None;
+    }
+    if is_array_by_description_bytes(value) {
+        return (value.len() >= 5).then_some(5);
+    }
+    if is_varint_by_description_bytes(value) {
+        return is_wide_integer(get_type_by_description_bytes(value))
+            .then_some(1);
+    }
+
+    match get_type_by_description_bytes(value) {
+        "unknown" => None,
+        "option" => match value.get(1)? {
+            0 => Some(2),
+            1 => Some(2 + get_description_len(value.get(2..)?)?),
+            _ => None,
+        },
+        "decimal" => (value.len() >= 3).then_some(3),
+        "struct" | "vec" | "tuple" | "enum" => {
+            let (count, mut len) = get_items_header(value)?;
+            for _ in 0..count {
+                len += get_description_len(value.get(len..)?)?;
+            }
+            Some(len)
+        }
+        _ => Some(1),
+    }
+}
+
+/// Returns the descriptions of the items of an option, a struct, a vector, a
+/// tuple or the fields of an enum variant by the description bytes.
+pub fn get_item_descriptions(value: &[u8]) -> Option<Vec<Vec<u8>>> {
+    if !is_composite_by_description_bytes(value) {
+        return None;
+    }
+
+    let (count, mut i) = get_items_header(value)?;
+
+    let mut items = Vec::new();
+    for _ in 0..count {
+        let len = get_description_len(value.get(i..)?)?;
+        items.push(value[i..i + len].to_vec());
+        i += len;
+    }
+
+    Some(items)
+}
+
+/// Returns the name of the type by the description bytes, the same as the
+/// name of its description.
+pub fn get_name_by_description_bytes(value: &[u8]) -> Option<String> {
+    get_description_len(value)?;
+
+    let type_ = get_type_by_description_bytes(value);
+    if is_array_by_description_bytes(value) {
+        let length = get_length_by_description_bytes(value)?;
+        return Some(format!("array_{}_{}", type_, length));
+    }
+
+    match type_ {
+        "option" => match get_item_descriptions(value)?.first() {
+            Some(item) => {
+                Some(format!("option_{}", get_name_by_description_bytes(item)?))
+            }
+            None => Some("option_none".to_string()),
+        },
+        "decimal" => Some(format!("decimal_{}_{}", value[1], value[2])),
+        "struct" | "vec" | "tuple" | "enum" => {
+            let length = get_length_by_description_bytes(value)?;
+            Some(format!("{}_{}", type_, length))
+        }
+        _ => Some(type_.to_string()),
+    }
+}
+
 /// Returns true if the value is an array by the description bytes.
 pub fn is_array_by_description_bytes(value: &[u8]) -> bool {
     value[0] & 0b1000_0000 != 0
 }
 
+/// Returns true if the value is an integer encoded as a varint by the
+/// description bytes.
+pub fn is_varint_by_description_bytes(value: &[u8]) -> bool {
+    !is_array_by_description_bytes(value) && value[0] & VARINT_FLAG != 0
+}
+
+/// Returns true if the type is an integer wider than a byte, that is encoded
+/// as a varint in compact mode.
+fn is_wide_integer(type_: &str) -> bool {
+    matches!(
+        type_,
+        "u16"
+            | "u32"
+            | "u64"
+            | "u128"
+            | "usize_u16"
+            | "usize_u32"
+            | "usize_u64"
+            | "usize_u128"
+            | "i16"
+            | "i32"
+            | "i64"
+            | "i128"
+            | "isize_i16"
+            | "isize_i32"
+            | "isize_i64"
+            | "isize_i128"
+    )
+}
+
+/// Returns the description bytes of the value encoded in compact mode, where
+/// integers wider than a byte, also nested ones, are encoded as varints.
+pub fn get_compact_description_bytes(value: &[u8]) -> Option<Vec<u8>> {
+    let len = get_description_len(value)?;
+    if is_composite_by_description_bytes(value) {
+        let (_, offset) = get_items_header(value)?;
+        let mut bytes = value[..offset].to_vec();
+        for item in get_item_descriptions(value)? {
+            bytes.extend(get_compact_description_bytes(&item)?);
+        }
+        return Some(bytes);
+    }
+
+    let mut bytes = value[..len].to_vec();
+    if !is_array_by_description_bytes(value)
+        && is_wide_integer(get_type_by_description_bytes(value))
+    {
+        bytes[0] |= VARINT_FLAG;
+    }
+
+    Some(bytes)
+}
+
 /// Returns the length of the array by the description bytes.
 pub fn get_length_by_description_bytes(value: &[u8]) -> Option<u32> {
     if value.len() < 5 {
@@ -46,9 +238,73 @@ pub fn get_length_by_description_bytes(value: &[u8]) -> Option<u32> {
     Some(u32::from_be_bytes(bytes))
 }
 
+/// Returns the size of the encoded value by the description bytes. In case of
+/// array, it returns the size of all its items. The size of varints, texts and
+/// blobs depends on the value, use [`get_encoded_size`] for them.
+pub fn get_value_size_by_description_bytes(value: &[u8]) -> Option<usize> {
+    if is_varint_by_description_bytes(value) {
+        return None;
+    }
+    if is_composite_by_description_bytes(value) {
+        return get_item_descriptions(value)?
+            .iter()
+            .map(|item| get_value_size_by_description_bytes(item))
+            .sum();
+    }
+
+    let item_size = match get_type_by_description_bytes(value) {
+        "bool" | "u8" | "usize_u8" | "i8" | "isize_i8" | "char" => 1,
+        "u16" | "usize_u16" | "i16" | "isize_i16" => 2,
+        "u32" | "usize_u32" | "i32" | "isize_i32" | "f32" | "date" => 4,
+        "u64" | "usize_u64" | "i64" | "isize_i64" | "f64" | "time"
+        | "timestamp" => 8,
+        "u128" | "usize_u128" | "i128" | "isize_i128" | "interval"
+        | "decimal" | "uuid" => 16,
+        _ => return None,
+    };
+
+    if is_array_by_description_bytes(value) {
+        let length = get_length_by_description_bytes(value)?;
+        Some(item_size * length as usize)
+    } else {
+        Some(item_size)
+    }
+}
+
+/// Returns the size of the value at the start of the encoded bytes by the
+/// description bytes. Unlike [`get_value_size_by_description_bytes`], it
+/// supports varints, texts and blobs, whose size is read from the encoded
+/// bytes.
+pub fn get_encoded_size(description: &[u8], bytes: &[u8]) -> Option<usize> {
+    if is_varint_by_description_bytes(description) {
+        return varint::get_len(bytes);
+    }
+    if is_length_prefixed_by_description_bytes(description) {
+        let (len, prefix_len) = varint::decode_unsigned(bytes)?;
+        return usize::try_from(len).ok()?.checked_add(prefix_len);
+    }
+    if is_composite_by_description_bytes(description) {
+        let mut size = 0;
+        for item in get_item_descriptions(description)? {
+            size += get_encoded_size(&item, bytes.get(size..)?)?;
+        }
+        return Some(size);
+    }
+
+    get_value_size_by_description_bytes(description)
+}
+
 /// Returns the descriptor bytes by the type.
 pub fn get_descriptor_bytes_by_type(type_: &str) -> Vec<u8> {
-    if type_.starts_with("array_") {
+    if let Some(params) = type_.strip_prefix("decimal_") {
+        let (precision, scale) = params.split_once('_').unwrap();
+        let descriptor_byte = get_descriptor_byte_by_base_type("decimal");
+        vec![
+            descriptor_byte.unwrap(),
+            precision.parse().unwrap(),
+            scale.parse().unwrap(),
+        ]
+    } else if type_.starts_with("array_") {
         let parts: Vec<&str> = type_.split('_').collect();
         let base_type = parts[1];
         let length = parts[2].parse::<u32>().unwrap();
@@ -92,13 +348,28 @@ fn get_descriptor_byte_by_base_type(type_: &str) -> Option<u8> {
         "char" => Some(21),
         "f32" => Some(22),
         "f64" => Some(23),
+        "text" => Some(29),
+        "blob" => Some(30),
+        "date" => Some(31),
+        "time" => Some(32),
+        "timestamp" => Some(33),
+        "interval" => Some(34),
+        "decimal" => Some(35),
+        "uuid" => Some(36),
         _ => None,
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::descriptor::backwards::get_descriptor_bytes_by_type;
+    use crate::descriptor::backwards::{
+        get_compact_description_bytes, get_description_len,
+        get_descriptor_bytes_by_type, get_encoded_size, get_item_descriptions,
+        get_name_by_description_bytes, get_type_by_description_bytes,
+        get_value_size_by_description_bytes, get_variant_by_description_bytes,
+        is_length_prefixed_by_description_bytes,
+        is_varint_by_description_bytes,
+    };
 
     #[test]
     fn test_get_descriptor_bytes_by_type() {
@@ -108,4 +379,167 @@ mod tests {
         let descriptor_bytes = get_descriptor_bytes_by_type("array_u8_3");
         assert_eq!(descriptor_bytes, vec![1 | 0b1000_0000, 0, 0, 0, 3]);
     }
+
+    #[test]
+    fn test_get_value_size_by_description_bytes() {
+        assert_eq!(get_value_size_by_description_bytes(&[3]), Some(4));
+        assert_eq!(get_value_size_by_description_bytes(&[23]), Some(8));
+
+        let descriptor_bytes = get_descriptor_bytes_by_type("array_char_13");
+        assert_eq!(
+            get_value_size_by_description_bytes(&descriptor_bytes),
+            Some(13)
+        );
+
+        let descriptor_bytes = get_descriptor_bytes_by_type("array_u16_3");
+        assert_eq!(
+            get_value_size_by_description_bytes(&descriptor_bytes),
+            Some(6)
+        );
+
+        assert_eq!(get_value_size_by_description_bytes(&[100]), None);
+    }
+
+    #[test]
+    fn test_composite_description_bytes() {
+        // Option of a tuple of `u32` and `array_char_2`.
+        let value = vec![24, 1, 27, 0, 0, 0, 2, 3, 0b1001_0101, 0, 0, 0, 2, 9];
+
+        assert_eq!(get_description_len(&value), Some(13));
+        assert_eq!(get_value_size_by_description_bytes(&value), Some(6));
+        assert_eq!(
+            get_name_by_description_bytes(&value),
+            Some("option_tuple_2".to_string())
+        );
+
+        let items = get_item_descriptions(&value).unwrap();
+        assert_eq!(items, vec![value[2..13].to_vec()]);
+        let items = get_item_descriptions(&items[0]).unwrap();
+        assert_eq!(items, vec![vec![3], vec![0b1001_0101, 0, 0, 0, 2]]);
+
+        assert_eq!(get_description_len(&[24, 0]), Some(2));
+        assert_eq!(get_value_size_by_description_bytes(&[24, 0]), Some(0));
+        assert_eq!(get_description_len(&[25, 0, 0, 0, 2, 3]), None);
+        assert_eq!(get_item_descriptions(&[3]), None);
+    }
+
+    #[test]
+    fn test_enum_description_bytes() {
+        // Variant 2 with `u16` and `option_none` fields.
+        let value = vec![28, 0, 0, 0, 2, 0, 0, 0, 2, 2, 24, 0];
+
+        assert_eq!(get_description_len(&value), Some(12));
+        assert_eq!(get_value_size_by_description_bytes(&value), Some(2));
+        assert_eq!(get_variant_by_description_bytes(&value), Some(2));
+        assert_eq!(
+            get_name_by_description_bytes(&value),
+            Some("enum_2".to_string())
+        );
+        assert_eq!(
+            get_item_descriptions(&value),
+            Some(vec![vec![2], vec![24, 0]])
+        );
+
+        assert_eq!(get_variant_by_description_bytes(&[25, 0, 0, 0, 0]), None);
+        assert_eq!(get_description_len(&[28, 0, 0, 0, 1, 0, 0, 0, 1]), None);
+    }
+
+    #[test]
+    fn test_varint_description_bytes() {
+        // `i32` encoded as a varint.
+        let value = vec![13 | 0b0100_0000];
+
+        assert!(is_varint_by_description_bytes(&value));
+        assert_eq!(get_type_by_description_bytes(&value), "i32");
+        assert_eq!(get_description_len(&value), Some(1));
+        assert_eq!(get_value_size_by_description_bytes(&value), None);
+        assert_eq!(get_encoded_size(&value, &[0b1000_0001, 1, 7]), Some(2));
+        assert_eq!(get_encoded_size(&value, &[0b1000_0001]), None);
+
+        // Varints of a byte and of composites don't exist.
+        assert_eq!(get_description_len(&[1 | 0b0100_0000]), None);
+        assert_eq!(get_description_len(&[25 | 0b0100_0000, 0, 0, 0, 0]), None);
+    }
+
+    #[test]
+    fn test_compact_description_bytes() {
+        // Tuple of `u8`, `u32` and `array_u16_2`.
+        let value = vec![27, 0, 0, 0, 3, 1, 3, 0b1000_0010, 0, 0, 0, 2];
+        let compact = get_compact_description_bytes(&value).unwrap();
+
+        assert_eq!(
+            compact,
+            vec![27, 0, 0, 0, 3, 1, 3 | 0b0100_0000, 0b1000_0010, 0, 0, 0, 2]
+        );
+        assert_eq!(
+            get_encoded_size(&compact, &[1, 0b1000_0000, 1, 0, 0, 0, 0]),
+            Some(7)
+        );
+        assert_eq!(
+            get_compact_description_bytes(&[15]),
+            Some(vec![15 | 0b0100_0000])
+        );
+        assert_eq!(get_compact_description_bytes(&[100]), None);
+    }
+
+    #[test]
+    fn test_temporal_description_bytes() {
+        let sizes = [
+            ("date", 4),
+            ("time", 8),
+            ("timestamp", 8),
+            ("interval", 16),
+            ("uuid", 16),
+        ];
+        for (type_, size) in sizes {
+            let value = get_descriptor_bytes_by_type(type_);
+
+            assert_eq!(get_type_by_description_bytes(&value), type_);
+            assert_eq!(get_value_size_by_description_bytes(&value), Some(size));
+            assert_eq!(get_compact_description_bytes(&value), Some(value));
+        }
+    }
+
+    #[test]
+    fn test_decimal_description_bytes() {
+        let value = get_descriptor_bytes_by_type("decimal_10_2");
+        assert_eq!(value, vec![35, 10, 2]);
+
+        assert_eq!(get_type_by_description_bytes(&value), "decimal");
+        assert_eq!(get_description_len(&value), Some(3));
+        assert_eq!(
+            get_name_by_description_bytes(&value),
+            Some("decimal_10_2".to_string())
+        );
+        assert_eq!(get_value_size_by_description_bytes(&value), Some(16));
+        assert_eq!(get_compact_description_bytes(&value), Some(value));
+
+        // The precision and the scale are missing.
+        assert_eq!(get_description_len(&[35, 10]), None);
+    }
+
+    #[test]
+    fn test_length_prefixed_description_bytes() {
+        let text = get_descriptor_bytes_by_type("text");
+        let blob = get_descriptor_bytes_by_type("blob");
+        assert_eq!(text, vec![29]);
+        assert_eq!(blob, vec![30]);
+
+        assert!(is_length_prefixed_by_description_bytes(&text));
+        assert!(is_length_prefixed_by_description_bytes(&blob));
+        assert!(!is_length_prefixed_by_description_bytes(&[21]));
+        assert_eq!(get_description_len(&text), Some(1));
+        assert_eq!(
+            get_name_by_description_bytes(&blob),
+            Some("blob".to_string())
+        );
+        assert_eq!(get_value_size_by_description_bytes(&text), None);
+
+        // Length of 200 takes two bytes of the varint.
+        let mut bytes = vec![0b1100_1000, 1];
+        bytes.extend(vec![7; 200]);
+        assert_eq!(get_encoded_size(&text, &bytes), Some(202));
+        assert_eq!(get_encoded_size(&blob, &[0, 1, 2]), Some(1));
+        assert_eq!(get_encoded_size(&blob, &[]), None);
+    }
 }
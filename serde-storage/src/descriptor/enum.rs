@@ -0,0 +1,41 @@
+use crate::{book_values, descriptor::Description};
+
+/// Description of an enum variant.
+pub struct EnumDescription {
+    /// Bytes of the description.
+    bytes: Vec<u8>,
+
+    /// Type name of the enum.
+    name: String,
+}
+
+const ENUM_NUMBER: u8 = 28;
+book_values!(ENUM_NUMBER);
+
+impl EnumDescription {
+    /// Create a new [`EnumDescription`] of the variant with the given field
+    /// descriptions.
+    pub(crate) fn new(variant: u32, fields: &[(Vec<u8>, String)]) -> Self {
+        let mut bytes = vec![ENUM_NUMBER];
+        bytes.extend_from_slice(&variant.to_be_bytes());
+        bytes.extend_from_slice(&(fields.len() as u32).to_be_bytes());
+        for (field_bytes, _) in fields {
+            bytes.extend_from_slice(field_bytes);
+        }
+
+        Self {
+            bytes,
+            name: format!("enum_{}", variant),
+        }
+    }
+}
+
+impl Description for EnumDescription {
+    fn get_bytes(&self) -> Vec<u8> {
+        self.bytes.clone()
+    }
+
+    fn get_name(&self) -> String {
+        self.name.clone()
+    }
+}
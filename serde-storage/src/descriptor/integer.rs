@@ -2,6 +2,7 @@ use crate::{
     book_values,
     descriptor::{get_type_name, Description, Descriptor},
     ser::encoder::StorableInteger,
+    varint::VARINT_FLAG,
     USIZE_SIZE,
 };
 
@@ -15,6 +16,14 @@ pub struct IntegerDescription {
     name: String,
 }
 
+impl IntegerDescription {
+    /// Mark the integer as encoded as a varint.
+    pub fn varint(mut self) -> Self {
+        self.bytes[0] |= VARINT_FLAG;
+        self
+    }
+}
+
 impl Description for IntegerDescription {
     fn get_bytes(&self) -> Vec<u8> {
         self.bytes.clone()
@@ -253,4 +262,12 @@ mod tests {
         );
         assert_eq!(isize_desc.get_name(), "isize");
     }
+
+    #[test]
+    fn test_varint_integer_descriptor() {
+        let desc = IntegerDescriptor::<i32>::describe().varint();
+
+        assert_eq!(desc.get_bytes(), vec![I32_NUMBER | VARINT_FLAG]);
+        assert_eq!(desc.get_name(), "i32");
+    }
 }
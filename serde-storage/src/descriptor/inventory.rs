@@ -19,6 +19,11 @@ macro_rules! book_values {
             $crate::descriptor::inventory::submit!(
                 $crate::descriptor::inventory::Value($t | 0b1000_0000)
             );
+            $crate::descriptor::inventory::submit!(
+                $crate::descriptor::inventory::Value(
+                    $t | $crate::varint::VARINT_FLAG
+                )
+            );
         )*
     }
 }
@@ -1,8 +1,11 @@
 pub mod array;
 pub mod backwards;
+pub mod r#enum;
 pub mod integer;
 pub mod inventory;
-mod r#struct;
+pub mod option;
+pub mod sequence;
+pub mod r#struct;
 pub mod r#type;
 
 /// [`Descriptor`] of a type for encoding.
@@ -31,3 +34,18 @@ pub trait DescribableArray<D: Description> {
 pub fn get_type_name<T>() -> &'static str {
     std::any::type_name::<T>()
 }
+
+/// Get the bytes of a composite description: the type number, the number of
+/// items and the descriptions of the items.
+pub(crate) fn get_composite_bytes(
+    number: u8,
+    items: &[(Vec<u8>, String)],
+) -> Vec<u8> {
+    let mut bytes = vec![number];
+    bytes.extend_from_slice(&(items.len() as u32).to_be_bytes());
+    for (item_bytes, _) in items {
+        bytes.extend_from_slice(item_bytes);
+    }
+
+    bytes
+}
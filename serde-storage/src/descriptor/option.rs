@@ -0,0 +1,47 @@
+use crate::{book_values, descriptor::Description};
+
+/// Description of an [`Option`].
+pub struct OptionDescription {
+    /// Bytes of the description.
+    bytes: Vec<u8>,
+
+    /// Type name of the option.
+    name: String,
+}
+
+const OPTION_NUMBER: u8 = 24;
+book_values!(OPTION_NUMBER);
+
+impl OptionDescription {
+    /// Create a new [`OptionDescription`] of `None`.
+    pub(crate) fn none() -> Self {
+        Self {
+            bytes: vec![OPTION_NUMBER, 0],
+            name: "option_none".to_string(),
+        }
+    }
+
+    /// Create a new [`OptionDescription`] of `Some` with the given value
+    /// description.
+    pub(crate) fn some(value: (Vec<u8>, String)) -> Self {
+        let (value_bytes, value_name) = value;
+
+        let mut bytes = vec![OPTION_NUMBER, 1];
+        bytes.extend(value_bytes);
+
+        Self {
+            bytes,
+            name: format!("option_{}", value_name),
+        }
+    }
+}
+
+impl Description for OptionDescription {
+    fn get_bytes(&self) -> Vec<u8> {
+        self.bytes.clone()
+    }
+
+    fn get_name(&self) -> String {
+        self.name.clone()
+    }
+}
@@ -0,0 +1,68 @@
+use crate::{
+    book_values,
+    descriptor::{get_composite_bytes, Description},
+};
+
+/// Description of a [`Vec`] of non-byte items.
+pub struct VecDescription {
+    /// Bytes of the description.
+    bytes: Vec<u8>,
+
+    /// Type name of the vector.
+    name: String,
+}
+
+const VEC_NUMBER: u8 = 26;
+book_values!(VEC_NUMBER);
+
+impl VecDescription {
+    /// Create a new [`VecDescription`] with the given item descriptions.
+    pub(crate) fn new(items: &[(Vec<u8>, String)]) -> Self {
+        Self {
+            bytes: get_composite_bytes(VEC_NUMBER, items),
+            name: format!("vec_{}", items.len()),
+        }
+    }
+}
+
+impl Description for VecDescription {
+    fn get_bytes(&self) -> Vec<u8> {
+        self.bytes.clone()
+    }
+
+    fn get_name(&self) -> String {
+        self.name.clone()
+    }
+}
+
+/// Description of a tuple.
+pub struct TupleDescription {
+    /// Bytes of the description.
+    bytes: Vec<u8>,
+
+    /// Type name of the tuple.
+    name: String,
+}
+
+const TUPLE_NUMBER: u8 = 27;
+book_values!(TUPLE_NUMBER);
+
+impl TupleDescription {
+    /// Create a new [`TupleDescription`] with the given item descriptions.
+    pub(crate) fn new(items: &[(Vec<u8>, String)]) -> Self {
+        Self {
+            bytes: get_composite_bytes(TUPLE_NUMBER, items),
+            name: format!("tuple_{}", items.len()),
+        }
+    }
+}
+
+impl Description for TupleDescription {
+    fn get_bytes(&self) -> Vec<u8> {
+        self.bytes.clone()
+    }
+
+    fn get_name(&self) -> String {
+        self.name.clone()
+    }
+}
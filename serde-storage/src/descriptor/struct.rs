@@ -1,17 +1,37 @@
-use crate::ser::encoder::StorageEncoder;
+use crate::{
+    book_values,
+    descriptor::{get_composite_bytes, Description},
+};
 
-/// Storage encoder for structs.
-pub struct StructEncoder<'a> {
-    /// Encoder for the storage.
-    encoder: &'a mut StorageEncoder,
-}
-
-impl<'a> StructEncoder<'a> {}
-
-pub struct StructDescriptor {
+/// Description of a nested struct.
+pub struct StructDescription {
     /// Bytes of the description.
     bytes: Vec<u8>,
 
-    /// Type name of the integer.
+    /// Type name of the struct.
     name: String,
 }
+
+const STRUCT_NUMBER: u8 = 25;
+book_values!(STRUCT_NUMBER);
+
+impl StructDescription {
+    /// Create a new [`StructDescription`] of a struct with the given field
+    /// descriptions.
+    pub(crate) fn new(fields: &[(Vec<u8>, String)]) -> Self {
+        Self {
+            bytes: get_composite_bytes(STRUCT_NUMBER, fields),
+            name: format!("struct_{}", fields.len()),
+        }
+    }
+}
+
+impl Description for StructDescription {
+    fn get_bytes(&self) -> Vec<u8> {
+        self.bytes.clone()
+    }
+
+    fn get_name(&self) -> String {
+        self.name.clone()
+    }
+}
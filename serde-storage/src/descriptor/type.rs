@@ -154,3 +154,268 @@ impl Describable<F64Description> for f64 {
         F64Description::default()
     }
 }
+
+/// Descriptor for a text of any length. The length is encoded as a varint
+/// before the bytes of the text, so it isn't a part of the description.
+pub struct TextDescription {
+    /// Bytes of the description.
+    bytes: Vec<u8>,
+
+    /// Type name of the text.
+    name: String,
+}
+
+const TEXT_NUMBER: u8 = 29;
+book_values!(TEXT_NUMBER);
+
+impl Default for TextDescription {
+    fn default() -> Self {
+        let name = "text".to_string();
+        Self {
+            bytes: vec![TEXT_NUMBER],
+            name,
+        }
+    }
+}
+
+impl Description for TextDescription {
+    fn get_bytes(&self) -> Vec<u8> {
+        self.bytes.clone()
+    }
+
+    fn get_name(&self) -> String {
+        self.name.clone()
+    }
+}
+
+/// Descriptor for binary data of any length. The length is encoded as a
+/// varint before the bytes, the same as for a text.
+pub struct BlobDescription {
+    /// Bytes of the description.
+    bytes: Vec<u8>,
+
+    /// Type name of the binary data.
+    name: String,
+}
+
+const BLOB_NUMBER: u8 = 30;
+book_values!(BLOB_NUMBER);
+
+impl Default for BlobDescription {
+    fn default() -> Self {
+        let name = "blob".to_string();
+        Self {
+            bytes: vec![BLOB_NUMBER],
+            name,
+        }
+    }
+}
+
+impl Description for BlobDescription {
+    fn get_bytes(&self) -> Vec<u8> {
+        self.bytes.clone()
+    }
+
+    fn get_name(&self) -> String {
+        self.name.clone()
+    }
+}
+
+/// Descriptor for a date, encoded as a `i32` number of days.
+pub struct DateDescription {
+    /// Bytes of the description.
+    bytes: Vec<u8>,
+
+    /// Type name of the date.
+    name: String,
+}
+
+const DATE_NUMBER: u8 = 31;
+book_values!(DATE_NUMBER);
+
+impl Default for DateDescription {
+    fn default() -> Self {
+        let name = "date".to_string();
+        Self {
+            bytes: vec![DATE_NUMBER],
+            name,
+        }
+    }
+}
+
+impl Description for DateDescription {
+    fn get_bytes(&self) -> Vec<u8> {
+        self.bytes.clone()
+    }
+
+    fn get_name(&self) -> String {
+        self.name.clone()
+    }
+}
+
+/// Descriptor for a time of day, encoded as a `i64` number of
+/// microseconds.
+pub struct TimeDescription {
+    /// Bytes of the description.
+    bytes: Vec<u8>,
+
+    /// Type name of the time.
+    name: String,
+}
+
+const TIME_NUMBER: u8 = 32;
+book_values!(TIME_NUMBER);
+
+impl Default for TimeDescription {
+    fn default() -> Self {
+        let name = "time".to_string();
+        Self {
+            bytes: vec![TIME_NUMBER],
+            name,
+        }
+    }
+}
+
+impl Description for TimeDescription {
+    fn get_bytes(&self) -> Vec<u8> {
+        self.bytes.clone()
+    }
+
+    fn get_name(&self) -> String {
+        self.name.clone()
+    }
+}
+
+/// Descriptor for a timestamp, encoded as a `i64` number of
+/// microseconds.
+pub struct TimestampDescription {
+    /// Bytes of the description.
+    bytes: Vec<u8>,
+
+    /// Type name of the timestamp.
+    name: String,
+}
+
+const TIMESTAMP_NUMBER: u8 = 33;
+book_values!(TIMESTAMP_NUMBER);
+
+impl Default for TimestampDescription {
+    fn default() -> Self {
+        let name = "timestamp".to_string();
+        Self {
+            bytes: vec![TIMESTAMP_NUMBER],
+            name,
+        }
+    }
+}
+
+impl Description for TimestampDescription {
+    fn get_bytes(&self) -> Vec<u8> {
+        self.bytes.clone()
+    }
+
+    fn get_name(&self) -> String {
+        self.name.clone()
+    }
+}
+
+/// Descriptor for an interval, encoded as `i32` months, `i32` days
+/// and `i64` microseconds.
+pub struct IntervalDescription {
+    /// Bytes of the description.
+    bytes: Vec<u8>,
+
+    /// Type name of the interval.
+    name: String,
+}
+
+const INTERVAL_NUMBER: u8 = 34;
+book_values!(INTERVAL_NUMBER);
+
+impl Default for IntervalDescription {
+    fn default() -> Self {
+        let name = "interval".to_string();
+        Self {
+            bytes: vec![INTERVAL_NUMBER],
+            name,
+        }
+    }
+}
+
+impl Description for IntervalDescription {
+    fn get_bytes(&self) -> Vec<u8> {
+        self.bytes.clone()
+    }
+
+    fn get_name(&self) -> String {
+        self.name.clone()
+    }
+}
+
+/// Descriptor for a fixed-precision decimal, encoded as a `i128` unscaled
+/// value with the sign bit flipped, so that the encoded bytes of decimals of
+/// the same scale are ordered the same way as their values. The precision and
+/// the scale follow the descriptor number.
+pub struct DecimalDescription {
+    /// Bytes of the description.
+    bytes: Vec<u8>,
+
+    /// Type name of the decimal.
+    name: String,
+}
+
+const DECIMAL_NUMBER: u8 = 35;
+book_values!(DECIMAL_NUMBER);
+
+impl DecimalDescription {
+    /// Creates a description of a decimal with the given total number of
+    /// digits and number of digits after the decimal point.
+    pub fn new(precision: u8, scale: u8) -> Self {
+        Self {
+            bytes: vec![DECIMAL_NUMBER, precision, scale],
+            name: format!("decimal_{}_{}", precision, scale),
+        }
+    }
+}
+
+impl Description for DecimalDescription {
+    fn get_bytes(&self) -> Vec<u8> {
+        self.bytes.clone()
+    }
+
+    fn get_name(&self) -> String {
+        self.name.clone()
+    }
+}
+
+/// Descriptor for a UUID, encoded as its 16 bytes.
+pub struct UuidDescription {
+    /// Bytes of the description.
+    bytes: Vec<u8>,
+
+    /// Type name of the UUID.
+    name: String,
+}
+
+const UUID_NUMBER: u8 = 36;
+book_values!(UUID_NUMBER);
+
+impl Default for UuidDescription {
+    fn default() -> Self {
+        let name = "uuid".to_string();
+        Self {
+            bytes: vec![UUID_NUMBER],
+            name,
+        }
+    }
+}
+
+impl Description for UuidDescription {
+    fn get_bytes(&self) -> Vec<u8> {
+        self.bytes.clone()
+    }
+
+    fn get_name(&self) -> String {
+        self.name.clone()
+    }
+}
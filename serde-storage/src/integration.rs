@@ -1,11 +1,24 @@
 #[cfg(test)]
 mod tests {
     use crate::{
-        de::decoder::StorageDecoder,
+        de::{
+            decoder::{
+                single_item::SingleItemDecoder, Storable as Decodable,
+                StorageDecoder,
+            },
+            Error,
+        },
         descriptor::backwards::{
             get_length_by_description_bytes, get_type_by_description_bytes,
         },
-        ser::encoder::StorageEncoder,
+        ser::{
+            encoder::{
+                output_descriptor::DescriptorBytes,
+                single_item::SingleItemEncoder, OutputDescriptor,
+                StorageEncoder,
+            },
+            Storable,
+        },
     };
 
     #[test]
@@ -537,4 +550,387 @@ mod tests {
         assert!(res.is_ok());
         assert_eq!(res.unwrap(), value);
     }
+
+    fn encode<T: Storable>(value: T) -> (Vec<u8>, Vec<(Vec<u8>, String)>) {
+        let mut encoder = StorageEncoder::new();
+
+        let res = encoder.emit(value);
+        assert!(res.is_ok());
+
+        let descriptor = encoder.descriptor.get_descriptors();
+        (encoder.output.get_bytes(), descriptor)
+    }
+
+    fn decode<T: Decodable<T>>(
+        bytes: Vec<u8>,
+        descriptor: &[(Vec<u8>, String)],
+    ) -> Result<T, Error> {
+        let descriptor = descriptor
+            .iter()
+            .flat_map(|(bytes, _)| bytes.clone())
+            .collect();
+
+        StorageDecoder.emit_with_descriptor::<T>(bytes, descriptor)
+    }
+
+    #[test]
+    fn test_encode_decode_option() {
+        let (bytes, descriptor) = encode(Some(1025u16));
+        assert_eq!(bytes, vec![4, 1]);
+        assert_eq!(descriptor, vec![(vec![24, 1, 2], "option_u16".into())]);
+        assert_eq!(
+            decode::<Option<u16>>(bytes, &descriptor).unwrap(),
+            Some(1025)
+        );
+
+        let (bytes, descriptor) = encode(None::<u16>);
+        assert!(bytes.is_empty());
+        assert_eq!(descriptor, vec![(vec![24, 0], "option_none".into())]);
+        assert_eq!(decode::<Option<u16>>(bytes, &descriptor).unwrap(), None);
+
+        let (bytes, descriptor) = encode(Some(1u8));
+        assert!(matches!(
+            decode::<Option<u16>>(bytes.clone(), &descriptor),
+            Err(Error::InvalidType)
+        ));
+        assert!(matches!(
+            decode::<u8>(bytes, &descriptor),
+            Err(Error::InvalidType)
+        ));
+    }
+
+    #[test]
+    fn test_encode_decode_vec() {
+        let value = vec!["ab".to_string(), "cde".to_string()];
+
+        let (bytes, descriptor) = encode(value.clone());
+        assert_eq!(bytes, b"abcde".to_vec());
+        assert_eq!(descriptor.len(), 1);
+        assert_eq!(descriptor[0].1, "vec_2");
+        assert_eq!(decode::<Vec<String>>(bytes, &descriptor).unwrap(), value);
+
+        let value = vec![Some(1u32), None, Some(3)];
+        let (bytes, descriptor) = encode(value.clone());
+        assert_eq!(bytes, vec![0, 0, 0, 1, 0, 0, 0, 3]);
+        assert_eq!(
+            decode::<Vec<Option<u32>>>(bytes, &descriptor).unwrap(),
+            value
+        );
+
+        let (bytes, descriptor) = encode(Vec::<u32>::new());
+        assert!(bytes.is_empty());
+        assert_eq!(descriptor[0].1, "vec_0");
+        assert!(decode::<Vec<u32>>(bytes, &descriptor).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_encode_decode_tuple() {
+        let value = (1u32, "ab".to_string(), (true, -1i8));
+
+        let (bytes, descriptor) = encode(value.clone());
+        assert_eq!(bytes, vec![0, 0, 0, 1, 97, 98, 1, 255]);
+        assert_eq!(descriptor.len(), 1);
+        assert_eq!(descriptor[0].1, "tuple_3");
+        assert_eq!(
+            decode::<(u32, String, (bool, i8))>(bytes.clone(), &descriptor)
+                .unwrap(),
+            value
+        );
+
+        assert!(matches!(
+            decode::<(u32, String)>(bytes, &descriptor),
+            Err(Error::InvalidLength)
+        ));
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    impl Storable for Point {
+        fn encode(
+            &self,
+            encoder: SingleItemEncoder,
+        ) -> Result<(), crate::ser::Error> {
+            encoder.emit_struct(vec![Box::new(self.x), Box::new(self.y)])
+        }
+    }
+
+    impl Decodable<Point> for Point {
+        fn decode(_: SingleItemDecoder, _: Vec<u8>) -> Result<Point, Error> {
+            Err(Error::NotDeserializable)
+        }
+
+        fn decode_with_descriptor(
+            decoder: SingleItemDecoder,
+            value: Vec<u8>,
+            descriptor: Vec<u8>,
+        ) -> Result<Point, Error> {
+            let mut fields = decoder.emit_struct(value, descriptor)?;
+            let point = Point {
+                x: fields.emit_field()?,
+                y: fields.emit_field()?,
+            };
+            fields.end()?;
+
+            Ok(point)
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Path {
+        id: u8,
+        points: Vec<Point>,
+        end: Option<Point>,
+    }
+
+    impl Storable for Path {
+        fn encode(
+            &self,
+            encoder: SingleItemEncoder,
+        ) -> Result<(), crate::ser::Error> {
+            encoder.emit_struct(vec![
+                Box::new(self.id),
+                Box::new(self.points.clone()),
+                Box::new(self.end.clone()),
+            ])
+        }
+    }
+
+    impl Decodable<Path> for Path {
+        fn decode(_: SingleItemDecoder, _: Vec<u8>) -> Result<Path, Error> {
+            Err(Error::NotDeserializable)
+        }
+
+        fn decode_with_descriptor(
+            decoder: SingleItemDecoder,
+            value: Vec<u8>,
+            descriptor: Vec<u8>,
+        ) -> Result<Path, Error> {
+            let mut fields = decoder.emit_struct(value, descriptor)?;
+            let path = Path {
+                id: fields.emit_field()?,
+                points: fields.emit_field()?,
+                end: fields.emit_field()?,
+            };
+            fields.end()?;
+
+            Ok(path)
+        }
+    }
+
+    #[test]
+    fn test_encode_decode_nested_struct() {
+        let point = Point { x: 1, y: -1 };
+
+        let (bytes, descriptor) = encode(point.clone());
+        assert_eq!(descriptor.len(), 2);
+        assert_eq!(descriptor[0].1, "i32");
+        assert_eq!(decode::<Point>(bytes, &descriptor).unwrap(), point);
+
+        let path = Path {
+            id: 7,
+            points: vec![point.clone(), Point { x: 2, y: 3 }],
+            end: Some(point),
+        };
+
+        let (bytes, descriptor) = encode(path.clone());
+        assert_eq!(bytes.len(), 1 + 16 + 8);
+        assert_eq!(descriptor.len(), 3);
+        assert_eq!(descriptor[0].1, "u8");
+        assert_eq!(descriptor[1].1, "vec_2");
+        assert_eq!(descriptor[2].1, "option_struct_2");
+        assert_eq!(
+            descriptor[1].0,
+            vec![
+                26, 0, 0, 0, 2, 25, 0, 0, 0, 2, 13, 13, 25, 0, 0, 0, 2, 13, 13
+            ]
+        );
+        assert_eq!(decode::<Path>(bytes, &descriptor).unwrap(), path);
+    }
+
+    #[test]
+    fn test_encode_enum() {
+        let mut encoder = StorageEncoder::new();
+
+        let res = encoder.emit_enum(2, &[&1u16, &None::<u8>]);
+        assert!(res.is_ok());
+
+        let descriptor = encoder.descriptor.get_descriptors();
+        assert_eq!(descriptor.len(), 1);
+        assert_eq!(descriptor[0].0, vec![28, 0, 0, 0, 2, 0, 0, 0, 2, 2, 24, 0]);
+        assert_eq!(descriptor[0].1, "enum_2");
+
+        let bytes = encoder.output.get_bytes();
+        assert_eq!(bytes, vec![0, 1]);
+
+        let mut decoder = StorageDecoder;
+        let (variant, mut fields) = decoder
+            .emit_enum(bytes, encoder.descriptor.get_descriptor_bytes())
+            .unwrap();
+        assert_eq!(variant, 2);
+        assert_eq!(fields.emit_field::<u16>().unwrap(), 1);
+        assert_eq!(fields.emit_field::<Option<u8>>().unwrap(), None);
+        assert!(fields.end().is_ok());
+    }
+
+    #[test]
+    fn test_encode_decode_compact() {
+        let values: Vec<Box<dyn Storable>> = vec![
+            Box::new(-2i128),
+            Box::new(vec![1u32, 300]),
+            Box::new(Some((7u64, -1i16))),
+            Box::new("ab".to_string()),
+        ];
+
+        let mut encoder = StorageEncoder::compact();
+        encoder.emit_struct(values).unwrap();
+
+        let bytes = encoder.output.get_bytes();
+        assert_eq!(bytes, vec![3, 1, 0b1010_1100, 2, 7, 1, b'a', b'b']);
+
+        let mut fixed = StorageEncoder::new();
+        fixed
+            .emit_struct(vec![
+                Box::new(-2i128),
+                Box::new(vec![1u32, 300]),
+                Box::new(Some((7u64, -1i16))),
+                Box::new("ab".to_string()),
+            ])
+            .unwrap();
+        assert_eq!(
+            fixed.descriptor.compact().get_descriptor_bytes(),
+            encoder.descriptor.get_descriptor_bytes()
+        );
+
+        let mut decoder = StorageDecoder;
+        let mut fields = decoder
+            .emit_struct(bytes, encoder.descriptor.get_descriptor_bytes())
+            .unwrap();
+        assert_eq!(fields.emit_field::<i128>().unwrap(), -2);
+        assert_eq!(fields.emit_field::<Vec<u32>>().unwrap(), vec![1, 300]);
+        assert_eq!(
+            fields.emit_field::<Option<(u64, i16)>>().unwrap(),
+            Some((7, -1))
+        );
+        assert_eq!(fields.emit_field::<String>().unwrap(), "ab");
+        assert!(fields.end().is_ok());
+    }
+
+    #[test]
+    fn test_decode_compact_errors() {
+        let mut encoder = StorageEncoder::compact();
+        encoder.emit_int(u32::MAX).unwrap();
+        let bytes = encoder.output.get_bytes();
+        let descriptor = encoder.descriptor.get_descriptor_bytes();
+
+        let mut decoder = StorageDecoder;
+        let res = decoder
+            .emit_with_descriptor::<u32>(bytes.clone(), descriptor.clone());
+        assert_eq!(res.unwrap(), u32::MAX);
+
+        let mut descriptor = descriptor;
+        descriptor[0] = 2 | 0b0100_0000;
+        let res =
+            decoder.emit_with_descriptor::<u16>(bytes.clone(), descriptor);
+        assert!(matches!(res, Err(Error::InvalidValue)));
+
+        let res = decoder.emit_varint::<u32>(bytes[..2].to_vec());
+        assert!(matches!(res, Err(Error::InvalidValue)));
+    }
+
+    #[test]
+    fn test_encode_decode_text_and_blob() {
+        let text = "a".repeat(300);
+
+        let mut encoder = StorageEncoder::new();
+        encoder.emit_text(&text).unwrap();
+        encoder.emit_int(7u8).unwrap();
+        encoder.emit_blob(&[0xde, 0xad, 0xbe, 0xef]).unwrap();
+
+        let bytes = encoder.output.get_bytes();
+        let descriptions = encoder
+            .descriptor
+            .get_descriptors()
+            .into_iter()
+            .map(|(bytes, _)| bytes)
+            .collect();
+        let parts = StorageDecoder::split_value(bytes, descriptions).unwrap();
+        assert_eq!(parts[0].0.len(), 302);
+        assert_eq!(parts[1].0, vec![7]);
+
+        let mut decoder = StorageDecoder;
+        assert_eq!(decoder.emit_text(parts[0].0.clone()).unwrap(), text);
+        assert_eq!(
+            decoder.emit_blob(parts[2].0.clone()).unwrap(),
+            vec![0xde, 0xad, 0xbe, 0xef]
+        );
+
+        let res = decoder.emit_blob(vec![3, 1, 2]);
+        assert!(matches!(res, Err(Error::InvalidLength)));
+        let res = decoder.emit_text(vec![1, 0xff]);
+        assert!(matches!(res, Err(Error::InvalidUtf8)));
+        let res = StorageDecoder::split_value(vec![5, 1], vec![vec![30]]);
+        assert!(matches!(res, Err(Error::InvalidLength)));
+    }
+
+    #[test]
+    fn test_encode_decode_temporal() {
+        let mut encoder = StorageEncoder::compact();
+        encoder.emit_date(-3).unwrap();
+        encoder.emit_time(1_000).unwrap();
+        encoder.emit_interval(-1, 2, i64::MAX).unwrap();
+
+        let bytes = encoder.output.get_bytes();
+        let descriptions = encoder
+            .descriptor
+            .get_descriptors()
+            .into_iter()
+            .map(|(bytes, _)| bytes)
+            .collect();
+        let parts = StorageDecoder::split_value(bytes, descriptions).unwrap();
+
+        let mut decoder = StorageDecoder;
+        assert_eq!(decoder.emit_date(parts[0].0.clone()).unwrap(), -3);
+        assert_eq!(decoder.emit_time(parts[1].0.clone()).unwrap(), 1_000);
+        assert_eq!(
+            decoder.emit_interval(parts[2].0.clone()).unwrap(),
+            (-1, 2, i64::MAX)
+        );
+
+        let res = decoder.emit_interval(vec![0; 15]);
+        assert!(matches!(res, Err(Error::InvalidLength)));
+
+        let res = decoder.emit_uuid(vec![0; 17]);
+        assert!(matches!(res, Err(Error::InvalidLength)));
+    }
+
+    #[test]
+    fn test_encode_decode_decimal() {
+        let mut encoder = StorageEncoder::new();
+        encoder.emit_decimal(-12_345, 38, 2).unwrap();
+        encoder.emit_decimal(i128::MAX, 38, 0).unwrap();
+        encoder.emit(1u8).unwrap();
+
+        let bytes = encoder.output.get_bytes();
+        let descriptor =
+            DescriptorBytes(encoder.descriptor.get_descriptor_bytes());
+        let descriptor = OutputDescriptor::try_from(descriptor).unwrap();
+        let descriptions = descriptor
+            .get_descriptors()
+            .into_iter()
+            .map(|(bytes, _)| bytes)
+            .collect();
+        let parts = StorageDecoder::split_value(bytes, descriptions).unwrap();
+
+        let mut decoder = StorageDecoder;
+        assert_eq!(decoder.emit_decimal(parts[0].0.clone()).unwrap(), -12_345);
+        assert_eq!(
+            decoder.emit_decimal(parts[1].0.clone()).unwrap(),
+            i128::MAX
+        );
+        assert_eq!(decoder.emit_u8(parts[2].0.clone()).unwrap(), 1);
+    }
 }
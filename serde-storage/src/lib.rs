@@ -5,5 +5,6 @@ pub mod de;
 pub mod descriptor;
 mod integration;
 pub mod ser;
+pub mod varint;
 
 pub const USIZE_SIZE: usize = size_of::<usize>();
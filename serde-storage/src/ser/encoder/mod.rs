@@ -8,13 +8,25 @@ use crate::{
         integer::IntegerDescriptor, r#type::BoolDescription, Descriptor as _,
     },
     ser::{encoder::single_item::SingleItemEncoder, error::Error},
+    varint,
 };
 
 use crate::descriptor::{
     array::ArrayDescription,
+    get_type_name,
     integer::IntegerDescription,
-    r#type::{CharDescription, F32Description, F64Description},
+    option::OptionDescription,
+    r#enum::EnumDescription,
+    r#struct::StructDescription,
+    r#type::{
+        BlobDescription, CharDescription, DateDescription, DecimalDescription,
+        F32Description, F64Description, IntervalDescription, TextDescription,
+        TimeDescription, TimestampDescription, UuidDescription,
+    },
+    sequence::{TupleDescription, VecDescription},
+    Description,
 };
+use std::mem::size_of;
 
 pub use output_descriptor::OutputDescriptor;
 pub use storable::Storable;
@@ -48,6 +60,11 @@ pub struct StorageEncoder {
     pub output: OutputBytes,
     /// [`OutputDescriptor`] is the descriptor.
     pub descriptor: OutputDescriptor,
+    /// Depth of the encoded value. Fields of the top level struct are
+    /// flattened into the descriptor, nested values are described as a whole.
+    depth: usize,
+    /// Whether integers wider than a byte are encoded as varints.
+    compact: bool,
 }
 
 impl StorageEncoder {
@@ -56,6 +73,20 @@ impl StorageEncoder {
         <Self as Default>::default()
     }
 
+    /// Create a new [`StorageEncoder`] in compact mode, where integers wider
+    /// than a byte are encoded as varints, so small values take less bytes.
+    pub fn compact() -> Self {
+        StorageEncoder {
+            compact: true,
+            ..Default::default()
+        }
+    }
+
+    /// Returns true if the encoder is in compact mode.
+    pub fn is_compact(&self) -> bool {
+        self.compact
+    }
+
     pub fn emit<T: Storable>(&mut self, value: T) -> Result<(), Error> {
         self.emit_with(|encoder| value.encode(encoder))
     }
@@ -79,17 +110,22 @@ impl StorageEncoder {
         Ok(())
     }
 
-    /// Encode an [`StorableInteger`] and append it to the output.
+    /// Encode an [`StorableInteger`] and append it to the output. In compact
+    /// mode integers wider than a byte are encoded as varints.
     pub fn emit_int<T: StorableInteger>(
         &mut self,
         value: T,
     ) -> Result<(), Error> {
-        self.output.append(value.get_storable());
-
         // Unwrap is safe because the value is always a valid integer.
         let description = IntegerDescriptor::<T>::describe();
 
-        self.descriptor.append(description);
+        if self.compact && size_of::<T>() > 1 {
+            self.output.append(value.get_varint());
+            self.descriptor.append(description.varint());
+        } else {
+            self.output.append(value.get_storable());
+            self.descriptor.append(description);
+        }
 
         Ok(())
     }
@@ -128,6 +164,97 @@ impl StorageEncoder {
         Ok(())
     }
 
+    /// Encode a text of any length and append it to the output. Unlike
+    /// [`StorageEncoder::emit_str`], the length is encoded with the value, so
+    /// values of the same description can have different lengths.
+    pub fn emit_text<S: AsRef<str>>(&mut self, value: S) -> Result<(), Error> {
+        self.append_length_prefixed(value.as_ref().as_bytes());
+        self.descriptor.append(TextDescription::default());
+
+        Ok(())
+    }
+
+    /// Encode binary data of any length and append it to the output. Unlike
+    /// [`StorageEncoder::emit_bytes`], the length is encoded with the value.
+    pub fn emit_blob(&mut self, value: &[u8]) -> Result<(), Error> {
+        self.append_length_prefixed(value);
+        self.descriptor.append(BlobDescription::default());
+
+        Ok(())
+    }
+
+    /// Encode a date as a number of days and append it to the output. Dates
+    /// are fixed-width, also in compact mode.
+    pub fn emit_date(&mut self, days: i32) -> Result<(), Error> {
+        self.descriptor.append(DateDescription::default());
+        self.output.append(days.to_be_bytes().to_vec());
+
+        Ok(())
+    }
+
+    /// Encode a time of day as a number of microseconds and append it to the
+    /// output.
+    pub fn emit_time(&mut self, micros: i64) -> Result<(), Error> {
+        self.descriptor.append(TimeDescription::default());
+        self.output.append(micros.to_be_bytes().to_vec());
+
+        Ok(())
+    }
+
+    /// Encode a timestamp as a number of microseconds and append it to the
+    /// output.
+    pub fn emit_timestamp(&mut self, micros: i64) -> Result<(), Error> {
+        self.descriptor.append(TimestampDescription::default());
+        self.output.append(micros.to_be_bytes().to_vec());
+
+        Ok(())
+    }
+
+    /// Encode an interval of months, days and microseconds and append it to
+    /// the output.
+    pub fn emit_interval(
+        &mut self,
+        months: i32,
+        days: i32,
+        micros: i64,
+    ) -> Result<(), Error> {
+        self.descriptor.append(IntervalDescription::default());
+        self.output.append(months.to_be_bytes().to_vec());
+        self.output.append(days.to_be_bytes().to_vec());
+        self.output.append(micros.to_be_bytes().to_vec());
+
+        Ok(())
+    }
+
+    /// Encode a decimal as its unscaled value and append it to the output.
+    /// The sign bit of the value is flipped, so that the encoded bytes of
+    /// decimals of the same scale are ordered the same way as their values.
+    /// # Arguments
+    /// * `value` - The unscaled value, e.g. `12345` for `123.45`.
+    /// * `precision` - The total number of digits.
+    /// * `scale` - The number of digits after the decimal point.
+    pub fn emit_decimal(
+        &mut self,
+        value: i128,
+        precision: u8,
+        scale: u8,
+    ) -> Result<(), Error> {
+        self.descriptor
+            .append(DecimalDescription::new(precision, scale));
+        self.output
+            .append((value ^ i128::MIN).to_be_bytes().to_vec());
+
+        Ok(())
+    }
+
+    /// Encode a UUID and append it to the output.
+    pub fn emit_uuid(&mut self, bytes: [u8; 16]) -> Result<(), Error> {
+        self.descriptor.append(UuidDescription::default());
+        self.output.append(bytes.to_vec());
+
+        Ok(())
+    }
+
     /// Encode a `f32` and append it to the output.
     pub fn emit_f32(&mut self, value: f32) -> Result<(), Error> {
         self.descriptor.append(F32Description::default());
@@ -144,10 +271,24 @@ impl StorageEncoder {
         Ok(())
     }
 
+    /// Encode a struct. Fields of the top level struct are appended one by
+    /// one, nested structs are appended as a single value.
     pub fn emit_struct(
         &mut self,
         values: Vec<Box<dyn Storable>>,
     ) -> Result<(), Error> {
+        if self.depth > 0 {
+            let mut nested = self.nested();
+            for value in values.iter() {
+                nested.emit_with(|encoder| value.encode(encoder))?;
+            }
+            let description =
+                StructDescription::new(&nested.descriptor.get_descriptors());
+
+            return self.append_nested(description, nested);
+        }
+
+        self.depth += 1;
         for value in values {
             let encoder = SingleItemEncoder {
                 encoder: self,
@@ -155,6 +296,102 @@ impl StorageEncoder {
             };
             value.encode(encoder)?;
         }
+        self.depth -= 1;
+        Ok(())
+    }
+
+    /// Encode an [`Option`] and append it to the output.
+    pub fn emit_option<T: Storable>(
+        &mut self,
+        value: Option<&T>,
+    ) -> Result<(), Error> {
+        let Some(value) = value else {
+            self.descriptor.append(OptionDescription::none());
+            return Ok(());
+        };
+
+        let mut nested = self.nested();
+        nested.emit_with(|encoder| value.encode(encoder))?;
+        let mut descriptors = nested.descriptor.get_descriptors();
+        let description = OptionDescription::some(descriptors.remove(0));
+
+        self.append_nested(description, nested)
+    }
+
+    /// Encode a slice of values and append it to the output. Bytes are
+    /// encoded as a byte array.
+    pub fn emit_vec<T: Storable>(&mut self, values: &[T]) -> Result<(), Error> {
+        let mut nested = self.nested();
+        for value in values {
+            nested.emit_with(|encoder| value.encode(encoder))?;
+        }
+
+        if get_type_name::<T>() == "u8" {
+            return self.emit_bytes(&nested.output.get_bytes());
+        }
+
+        let description =
+            VecDescription::new(&nested.descriptor.get_descriptors());
+        self.append_nested(description, nested)
+    }
+
+    /// Encode values of a tuple and append them to the output.
+    pub fn emit_tuple(
+        &mut self,
+        values: &[&dyn Storable],
+    ) -> Result<(), Error> {
+        let mut nested = self.nested();
+        for value in values {
+            nested.emit_with(|encoder| value.encode(encoder))?;
+        }
+
+        let description =
+            TupleDescription::new(&nested.descriptor.get_descriptors());
+        self.append_nested(description, nested)
+    }
+
+    /// Encode a variant of an enum with the values of its fields and append
+    /// it to the output.
+    pub fn emit_enum(
+        &mut self,
+        variant: u32,
+        values: &[&dyn Storable],
+    ) -> Result<(), Error> {
+        let mut nested = self.nested();
+        for value in values {
+            nested.emit_with(|encoder| value.encode(encoder))?;
+        }
+
+        let description =
+            EnumDescription::new(variant, &nested.descriptor.get_descriptors());
+        self.append_nested(description, nested)
+    }
+
+    /// Create an encoder for the items of a nested value.
+    fn nested(&self) -> StorageEncoder {
+        StorageEncoder {
+            depth: self.depth + 1,
+            compact: self.compact,
+            ..Default::default()
+        }
+    }
+
+    /// Append the bytes to the output after their length encoded as a varint.
+    fn append_length_prefixed(&mut self, bytes: &[u8]) {
+        self.output
+            .append(varint::encode_unsigned(bytes.len() as u128));
+        self.output.append(bytes.to_vec());
+    }
+
+    /// Append the output of the nested encoder as a single value.
+    fn append_nested<D: Description>(
+        &mut self,
+        description: D,
+        nested: StorageEncoder,
+    ) -> Result<(), Error> {
+        self.output.append(nested.output.get_bytes());
+        self.descriptor.append(description);
+
         Ok(())
     }
 }
@@ -181,6 +418,22 @@ mod tests_storage_encoder {
         assert_eq!(descriptor[0].1, "u32");
     }
 
+    #[test]
+    fn test_compact_integer() {
+        let mut encoder = StorageEncoder::compact();
+        assert!(encoder.is_compact());
+
+        encoder.emit_int(1i128).unwrap();
+        encoder.emit_int(300u32).unwrap();
+        encoder.emit_int(2u8).unwrap();
+
+        let bytes = encoder.output.get_bytes();
+        assert_eq!(bytes, vec![2, 0b1010_1100, 0b0000_0010, 2]);
+
+        let descriptor = encoder.descriptor.get_descriptor_bytes();
+        assert_eq!(descriptor, vec![15 | 0b0100_0000, 3 | 0b0100_0000, 1]);
+    }
+
     #[test]
     fn test_string() {
         let value = "Hello, world!";
@@ -223,6 +476,60 @@ mod tests_storage_encoder {
         assert_eq!(descriptor[0].1, "array_u8_4");
     }
 
+    #[test]
+    fn test_text_and_blob() {
+        let mut encoder = StorageEncoder::new();
+
+        encoder.emit_text("Hi").unwrap();
+        encoder.emit_blob(&[0xde, 0xad]).unwrap();
+
+        let bytes = encoder.output.get_bytes();
+        assert_eq!(bytes, vec![2, b'H', b'i', 2, 0xde, 0xad]);
+
+        let descriptor = encoder.descriptor.get_descriptors();
+        assert_eq!(descriptor.len(), 2);
+        assert_eq!(descriptor[0].1, "text");
+        assert_eq!(descriptor[1].1, "blob");
+    }
+
+    #[test]
+    fn test_temporal() {
+        let mut encoder = StorageEncoder::compact();
+
+        encoder.emit_date(1).unwrap();
+        encoder.emit_timestamp(-1).unwrap();
+        encoder.emit_interval(1, 2, 3).unwrap();
+        encoder.emit_uuid([7; 16]).unwrap();
+
+        let bytes = encoder.output.get_bytes();
+        assert_eq!(bytes.len(), 4 + 8 + 16 + 16);
+        assert_eq!(bytes[28..], [7; 16]);
+        assert_eq!(bytes[..4], [0, 0, 0, 1]);
+        assert_eq!(bytes[4..12], [255; 8]);
+
+        let descriptor = encoder.descriptor.get_descriptors();
+        assert_eq!(descriptor[0].1, "date");
+        assert_eq!(descriptor[1].1, "timestamp");
+        assert_eq!(descriptor[2].1, "interval");
+        assert_eq!(descriptor[3].1, "uuid");
+    }
+
+    #[test]
+    fn test_decimal() {
+        let mut encoder = StorageEncoder::compact();
+
+        encoder.emit_decimal(-1, 10, 2).unwrap();
+        encoder.emit_decimal(1, 10, 2).unwrap();
+
+        let bytes = encoder.output.get_bytes();
+        assert_eq!(bytes.len(), 2 * 16);
+        assert!(bytes[..16] < bytes[16..]);
+        assert_eq!(bytes[..16], [vec![127], vec![255; 15]].concat());
+
+        let descriptor = encoder.descriptor.get_descriptors();
+        assert_eq!(descriptor[0], (vec![35, 10, 2], "decimal_10_2".into()));
+    }
+
     #[test]
     fn test_f32() {
         let value = 1.1231234;
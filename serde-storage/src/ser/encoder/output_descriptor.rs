@@ -1,7 +1,9 @@
 use crate::descriptor::{
     backwards::{
+        get_compact_description_bytes, get_description_len,
         get_descriptor_bytes_by_type, get_length_by_description_bytes,
-        get_type_by_description_bytes, is_array_by_description_bytes,
+        get_name_by_description_bytes, get_type_by_description_bytes,
+        is_array_by_description_bytes, is_composite_by_description_bytes,
     },
     Description,
 };
@@ -39,6 +41,22 @@ impl OutputDescriptor {
             .push((description.get_bytes(), description.get_name()));
     }
 
+    /// Get the descriptor of the same values encoded in compact mode, where
+    /// integers wider than a byte are encoded as varints.
+    pub fn compact(&self) -> OutputDescriptor {
+        let descriptors = self
+            .descriptors
+            .iter()
+            .map(|(bytes, name)| {
+                let bytes = get_compact_description_bytes(bytes)
+                    .unwrap_or_else(|| bytes.clone());
+                (bytes, name.clone())
+            })
+            .collect();
+
+        OutputDescriptor { descriptors }
+    }
+
     /// Get the length of the descriptors.
     pub fn len(&self) -> usize {
         self.descriptors.len()
@@ -62,9 +80,17 @@ impl TryFrom<DescriptorBytes> for OutputDescriptor {
             if let Ok(res) = try_value_bytes(byte) {
                 descriptors.push(res);
                 i += 1;
-            } else if let Ok(res) = try_array_bytes(&bytes[i..i + 5]) {
+            } else if let Ok(res) =
+                try_array_bytes(&bytes[i..bytes.len().min(i + 5)])
+            {
                 descriptors.push(res);
                 i += 5;
+            } else if let Ok(res) = try_parameterized_bytes(&bytes[i..]) {
+                i += res.0.len();
+                descriptors.push(res);
+            } else if let Ok(res) = try_composite_bytes(&bytes[i..]) {
+                i += res.0.len();
+                descriptors.push(res);
             } else {
                 return Err(DescriptorError::InvalidDescriptor);
             }
@@ -77,11 +103,13 @@ impl TryFrom<DescriptorBytes> for OutputDescriptor {
 /// Try to get description from single value.
 fn try_value_bytes(value: u8) -> Result<(Vec<u8>, String), ()> {
     let value = vec![value];
-    if is_array_by_description_bytes(value.as_slice()) {
+    if is_array_by_description_bytes(value.as_slice())
+        || is_composite_by_description_bytes(value.as_slice())
+    {
         Err(())
     } else {
         let type_ = get_type_by_description_bytes(value.as_slice());
-        if type_ == "unknown" {
+        if type_ == "unknown" || get_description_len(&value).is_none() {
             return Err(());
         }
         Ok((value, type_.to_string()))
@@ -105,6 +133,32 @@ fn try_array_bytes(value: &[u8]) -> Result<(Vec<u8>, String), ()> {
     }
 }
 
+/// Try to get description from a value whose description has parameters
+/// after the descriptor number, e.g. the precision and the scale of a decimal.
+fn try_parameterized_bytes(value: &[u8]) -> Result<(Vec<u8>, String), ()> {
+    if is_array_by_description_bytes(value)
+        || is_composite_by_description_bytes(value)
+        || get_type_by_description_bytes(value) != "decimal"
+    {
+        return Err(());
+    }
+    let len = get_description_len(value).ok_or(())?;
+    let name = get_name_by_description_bytes(value).ok_or(())?;
+
+    Ok((value[..len].to_vec(), name))
+}
+
+/// Try to get description from option, struct, vector or tuple value.
+fn try_composite_bytes(value: &[u8]) -> Result<(Vec<u8>, String), ()> {
+    if !is_composite_by_description_bytes(value) {
+        return Err(());
+    }
+    let len = get_description_len(value).ok_or(())?;
+    let name = get_name_by_description_bytes(value).ok_or(())?;
+
+    Ok((value[..len].to_vec(), name))
+}
+
 /// Descriptor parse error.
 #[derive(Debug)]
 pub enum DescriptorError {
@@ -184,6 +238,69 @@ mod test_descriptor {
         assert_eq!(descriptors[1].1, "u16");
         assert_eq!(descriptors[2].1, "array_u32_4");
     }
+
+    #[test]
+    fn test_output_descriptor_try_from_composite() {
+        let value =
+            vec![1u8, 24, 1, 3, 25, 0, 0, 0, 2, 24, 0, 0, 26, 0, 0, 0, 0];
+        let descriptor_bytes = DescriptorBytes(value);
+        let res = OutputDescriptor::try_from(descriptor_bytes);
+        assert!(res.is_ok());
+
+        let descriptor = res.unwrap();
+        let descriptors = descriptor.get_descriptors();
+        assert_eq!(descriptors.len(), 4);
+        assert_eq!(descriptors[0].1, "u8");
+        assert_eq!(descriptors[1], (vec![24, 1, 3], "option_u32".to_string()));
+        assert_eq!(descriptors[2].1, "struct_2");
+        assert_eq!(descriptors[3].1, "vec_0");
+    }
+
+    #[test]
+    fn test_output_descriptor_try_from_decimal() {
+        let value = vec![1u8, 35, 10, 2, 3u8];
+        let descriptor_bytes = DescriptorBytes(value);
+        let descriptor = OutputDescriptor::try_from(descriptor_bytes).unwrap();
+
+        let descriptors = descriptor.get_descriptors();
+        assert_eq!(descriptors.len(), 3);
+        assert_eq!(descriptors[1], (vec![35, 10, 2], "decimal_10_2".into()));
+        assert_eq!(descriptors[2].1, "u32");
+
+        let res = OutputDescriptor::try_from(DescriptorBytes(vec![35, 10]));
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn test_output_descriptor_compact() {
+        let value = vec![1u8, 13u8, 3u8 | 0b1000_0000, 0, 0, 0, 4u8];
+        let descriptor =
+            OutputDescriptor::try_from(DescriptorBytes(value)).unwrap();
+        let compact = descriptor.compact();
+
+        assert_eq!(
+            compact.get_descriptor_bytes(),
+            vec![1u8, 13u8 | 0b0100_0000, 3u8 | 0b1000_0000, 0, 0, 0, 4u8]
+        );
+        assert_eq!(compact.get_descriptors()[1].1, "i32");
+
+        let res = OutputDescriptor::try_from(DescriptorBytes(
+            compact.get_descriptor_bytes(),
+        ));
+        assert_eq!(res.unwrap().len(), 3);
+
+        let res =
+            OutputDescriptor::try_from(DescriptorBytes(vec![1 | 0b0100_0000]));
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn test_output_descriptor_try_from_truncated_array() {
+        let value = vec![1u8, 3u8 | 0b1000_0000, 0, 0];
+        let descriptor_bytes = DescriptorBytes(value);
+        let res = OutputDescriptor::try_from(descriptor_bytes);
+        assert!(res.is_err());
+    }
 }
 
 /// Descriptor types.
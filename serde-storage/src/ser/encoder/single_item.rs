@@ -51,6 +51,64 @@ impl<'e> SingleItemEncoder<'e> {
         self.encoder.emit_bytes(value)
     }
 
+    /// Emit a text of any length.
+    pub fn emit_text(self, value: &str) -> Result<(), Error> {
+        *self.value_written = true;
+        self.encoder.emit_text(value)
+    }
+
+    /// Emit binary data of any length.
+    pub fn emit_blob(self, value: &[u8]) -> Result<(), Error> {
+        *self.value_written = true;
+        self.encoder.emit_blob(value)
+    }
+
+    /// Emit a date.
+    pub fn emit_date(self, days: i32) -> Result<(), Error> {
+        *self.value_written = true;
+        self.encoder.emit_date(days)
+    }
+
+    /// Emit a time of day.
+    pub fn emit_time(self, micros: i64) -> Result<(), Error> {
+        *self.value_written = true;
+        self.encoder.emit_time(micros)
+    }
+
+    /// Emit a timestamp.
+    pub fn emit_timestamp(self, micros: i64) -> Result<(), Error> {
+        *self.value_written = true;
+        self.encoder.emit_timestamp(micros)
+    }
+
+    /// Emit an interval.
+    pub fn emit_interval(
+        self,
+        months: i32,
+        days: i32,
+        micros: i64,
+    ) -> Result<(), Error> {
+        *self.value_written = true;
+        self.encoder.emit_interval(months, days, micros)
+    }
+
+    /// Emit a decimal.
+    pub fn emit_decimal(
+        self,
+        value: i128,
+        precision: u8,
+        scale: u8,
+    ) -> Result<(), Error> {
+        *self.value_written = true;
+        self.encoder.emit_decimal(value, precision, scale)
+    }
+
+    /// Emit a UUID.
+    pub fn emit_uuid(self, bytes: [u8; 16]) -> Result<(), Error> {
+        *self.value_written = true;
+        self.encoder.emit_uuid(bytes)
+    }
+
     /// Emit a f32.
     pub fn emit_f32(self, value: f32) -> Result<(), Error> {
         *self.value_written = true;
@@ -63,6 +121,7 @@ impl<'e> SingleItemEncoder<'e> {
         self.encoder.emit_f64(value)
     }
 
+    /// Emit a struct.
     pub fn emit_struct(
         self,
         values: Vec<Box<dyn Storable>>,
@@ -70,4 +129,35 @@ impl<'e> SingleItemEncoder<'e> {
         *self.value_written = true;
         self.encoder.emit_struct(values)
     }
+
+    /// Emit an option.
+    pub fn emit_option<T: Storable>(
+        self,
+        value: Option<&T>,
+    ) -> Result<(), Error> {
+        *self.value_written = true;
+        self.encoder.emit_option(value)
+    }
+
+    /// Emit a vector.
+    pub fn emit_vec<T: Storable>(self, values: &[T]) -> Result<(), Error> {
+        *self.value_written = true;
+        self.encoder.emit_vec(values)
+    }
+
+    /// Emit a variant of an enum.
+    pub fn emit_enum(
+        self,
+        variant: u32,
+        values: &[&dyn Storable],
+    ) -> Result<(), Error> {
+        *self.value_written = true;
+        self.encoder.emit_enum(variant, values)
+    }
+
+    /// Emit a tuple.
+    pub fn emit_tuple(self, values: &[&dyn Storable]) -> Result<(), Error> {
+        *self.value_written = true;
+        self.encoder.emit_tuple(values)
+    }
 }
@@ -31,9 +31,15 @@ impl Storable for String {
     }
 }
 
-impl Storable for Vec<u8> {
+impl<T: Storable> Storable for Vec<T> {
     fn encode(&self, encoder: SingleItemEncoder) -> Result<(), Error> {
-        encoder.emit_bytes(self)
+        encoder.emit_vec(self)
+    }
+}
+
+impl<T: Storable> Storable for Option<T> {
+    fn encode(&self, encoder: SingleItemEncoder) -> Result<(), Error> {
+        encoder.emit_option(self.as_ref())
     }
 }
 
@@ -54,3 +60,25 @@ impl Storable for &[u8] {
         encoder.emit_bytes(self)
     }
 }
+
+#[rustfmt::skip]
+macro_rules! impl_tuple {
+    ($(($($t:ident $i:tt),+)),*) => {
+        $(
+            impl<$($t: Storable),+> Storable for ($($t,)+) {
+                fn encode(&self, encoder: SingleItemEncoder) -> Result<(), Error> {
+                    encoder.emit_tuple(&[$(&self.$i),+])
+                }
+            }
+        )*
+    }
+}
+
+impl_tuple!(
+    (A 0),
+    (A 0, B 1),
+    (A 0, B 1, C 2),
+    (A 0, B 1, C 2, D 3),
+    (A 0, B 1, C 2, D 3, E 4),
+    (A 0, B 1, C 2, D 3, E 4, F 5)
+);
@@ -1,21 +1,31 @@
+use crate::varint::{encode_signed, encode_unsigned};
+
 /// An integer value, that can be interpreted as a byte array.
 pub trait StorableInteger {
     fn get_storable(&self) -> Vec<u8>;
+
+    /// Get the bytes of the integer encoded as a varint.
+    fn get_varint(&self) -> Vec<u8>;
 }
 
 macro_rules! impl_integer {
-    ($($t:ty),*) => {
+    ($encode:ident, $as:ty, $($t:ty),*) => {
         $(
             impl StorableInteger for $t {
                 fn get_storable(&self) -> Vec<u8> {
                     self.to_be_bytes().to_vec()
                 }
+
+                fn get_varint(&self) -> Vec<u8> {
+                    $encode(*self as $as)
+                }
             }
         )*
     }
 }
 
-impl_integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_integer!(encode_unsigned, u128, u8, u16, u32, u64, u128, usize);
+impl_integer!(encode_signed, i128, i8, i16, i32, i64, i128, isize);
 
 #[cfg(test)]
 mod tests {
@@ -59,4 +69,12 @@ mod tests {
 
         assert_eq!(isize_val.get_storable(), vec![0; USIZE_SIZE]);
     }
+
+    #[test]
+    fn test_varint_integer() {
+        assert_eq!(1u128.get_varint(), vec![1]);
+        assert_eq!(300u32.get_varint(), vec![0b1010_1100, 0b0000_0010]);
+        assert_eq!((-1i128).get_varint(), vec![1]);
+        assert_eq!(i16::MIN.get_varint(), vec![0xff, 0xff, 0b0000_0011]);
+    }
 }
@@ -0,0 +1,111 @@
+//! Variable-length integers of the compact encoding. Unsigned integers are
+//! encoded as LEB128, signed integers are zigzag encoded first, so that small
+//! negative values stay short.
+
+/// Flag of the description byte of an integer encoded as a varint.
+pub const VARINT_FLAG: u8 = 0b0100_0000;
+
+/// Maximum number of bytes of a varint, enough for a [`u128`].
+const MAX_LEN: usize = 19;
+
+/// Encode an unsigned integer as LEB128.
+pub fn encode_unsigned(mut value: u128) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    loop {
+        let byte = (value & 0b0111_1111) as u8;
+        value >>= 7;
+        if value == 0 {
+            bytes.push(byte);
+            return bytes;
+        }
+        bytes.push(byte | 0b1000_0000);
+    }
+}
+
+/// Encode a signed integer as a zigzag LEB128.
+pub fn encode_signed(value: i128) -> Vec<u8> {
+    encode_unsigned(((value << 1) ^ (value >> 127)) as u128)
+}
+
+/// Decode an unsigned LEB128 integer.
+/// # Returns
+/// * `Some((u128, usize))` - The value and the number of bytes it takes.
+/// * `None` - If the bytes end before the varint or it overflows a [`u128`].
+pub fn decode_unsigned(bytes: &[u8]) -> Option<(u128, usize)> {
+    let len = get_len(bytes)?;
+
+    let mut value = 0u128;
+    for (i, byte) in bytes[..len].iter().enumerate() {
+        let bits = (byte & 0b0111_1111) as u128;
+        let shift = 7 * i as u32;
+        let shifted = bits << shift;
+        if shifted >> shift != bits {
+            return None;
+        }
+        value |= shifted;
+    }
+
+    Some((value, len))
+}
+
+/// Decode a zigzag LEB128 integer.
+/// # Returns
+/// * `Some((i128, usize))` - The value and the number of bytes it takes.
+/// * `None` - If the bytes end before the varint or it overflows a [`i128`].
+pub fn decode_signed(bytes: &[u8]) -> Option<(i128, usize)> {
+    let (value, len) = decode_unsigned(bytes)?;
+    let value = (value >> 1) as i128 ^ -((value & 1) as i128);
+
+    Some((value, len))
+}
+
+/// Returns the number of bytes of the varint at the start of the bytes.
+pub fn get_len(bytes: &[u8]) -> Option<usize> {
+    let len = bytes.iter().position(|byte| byte & 0b1000_0000 == 0)? + 1;
+
+    (len <= MAX_LEN).then_some(len)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unsigned() {
+        assert_eq!(encode_unsigned(0), vec![0]);
+        assert_eq!(encode_unsigned(127), vec![127]);
+        assert_eq!(encode_unsigned(300), vec![0b1010_1100, 0b0000_0010]);
+        assert_eq!(encode_unsigned(u128::MAX).len(), MAX_LEN);
+
+        for value in [0, 1, 127, 128, 300, u64::MAX as u128, u128::MAX] {
+            let bytes = encode_unsigned(value);
+            assert_eq!(decode_unsigned(&bytes), Some((value, bytes.len())));
+        }
+    }
+
+    #[test]
+    fn test_signed() {
+        assert_eq!(encode_signed(0), vec![0]);
+        assert_eq!(encode_signed(-1), vec![1]);
+        assert_eq!(encode_signed(1), vec![2]);
+        assert_eq!(encode_signed(-64), vec![127]);
+
+        for value in [0, -1, 1, -300, 300, i128::MIN, i128::MAX] {
+            let bytes = encode_signed(value);
+            assert_eq!(decode_signed(&bytes), Some((value, bytes.len())));
+        }
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_eq!(decode_unsigned(&[]), None);
+        assert_eq!(decode_unsigned(&[0b1000_0000]), None);
+        assert_eq!(decode_unsigned(&[0xff; 20]), None);
+
+        let mut overflow = vec![0xff; MAX_LEN - 1];
+        overflow.push(0b0000_0100);
+        assert_eq!(decode_unsigned(&overflow), None);
+
+        assert_eq!(get_len(&[0b1000_0001, 1, 5]), Some(2));
+    }
+}
@@ -0,0 +1,676 @@
+use std::io::{self, Read, Write};
+
+use crate::pg::r#type::PgType;
+
+/// Version `3.0` of the protocol sent in the startup message.
+pub const PROTOCOL_VERSION: i32 = 196_608;
+
+/// Code sent instead of the protocol version to request an SSL connection.
+pub const SSL_REQUEST_CODE: i32 = 80_877_103;
+
+/// Code sent instead of the protocol version to request GSSAPI encryption.
+pub const GSSENC_REQUEST_CODE: i32 = 80_877_104;
+
+/// Code sent instead of the protocol version to cancel a running query.
+pub const CANCEL_REQUEST_CODE: i32 = 80_877_102;
+
+/// Maximum length of a message accepted from a client.
+const MAX_MESSAGE_LEN: usize = 1 << 24;
+
+/// Messages that a client sends before the session is established.
+#[derive(Debug, Clone, PartialEq)]
+pub enum StartupMessage {
+    /// Startup message with the connection parameters.
+    Startup(Vec<(String, String)>),
+
+    /// Request to use SSL for the connection.
+    SslRequest,
+
+    /// Request to use GSSAPI encryption for the connection.
+    GssEncRequest,
+
+    /// Request to cancel a query running in another session.
+    CancelRequest,
+}
+
+impl StartupMessage {
+    /// Reads a [`StartupMessage`] from the given reader.
+    /// # Arguments
+    /// * `reader` - The reader to read from.
+    /// # Returns
+    /// * `Ok(StartupMessage)` - The message read.
+    /// * `Err(io::Error)` - If the message is malformed or reading failed.
+    pub fn read<R: Read>(reader: &mut R) -> io::Result<Self> {
+        let len = read_len(reader)?;
+        if len < 4 {
+            return Err(invalid_data("startup message is too short"));
+        }
+
+        let code = read_i32(reader)?;
+        let mut body = vec![0; len - 4];
+        reader.read_exact(&mut body)?;
+
+        match code {
+            PROTOCOL_VERSION => {
+                let mut params = Vec::new();
+                let mut fields = body.split(|b| *b == 0);
+                while let Some(key) = fields.next() {
+                    if key.is_empty() {
+                        break;
+                    }
+                    let value = fields.next().unwrap_or_default();
+                    params.push((to_string(key)?, to_string(value)?));
+                }
+
+                Ok(StartupMessage::Startup(params))
+            }
+            SSL_REQUEST_CODE => Ok(StartupMessage::SslRequest),
+            GSSENC_REQUEST_CODE => Ok(StartupMessage::GssEncRequest),
+            CANCEL_REQUEST_CODE => Ok(StartupMessage::CancelRequest),
+            _ => Err(invalid_data("unsupported protocol version")),
+        }
+    }
+
+    /// Encodes the message into bytes.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut body = Vec::new();
+        let code = match self {
+            StartupMessage::Startup(params) => {
+                for (key, value) in params {
+                    put_str(&mut body, key);
+                    put_str(&mut body, value);
+                }
+                body.push(0);
+                PROTOCOL_VERSION
+            }
+            StartupMessage::SslRequest => SSL_REQUEST_CODE,
+            StartupMessage::GssEncRequest => GSSENC_REQUEST_CODE,
+            StartupMessage::CancelRequest => CANCEL_REQUEST_CODE,
+        };
+
+        let mut bytes = (body.len() as i32 + 8).to_be_bytes().to_vec();
+        bytes.extend_from_slice(&code.to_be_bytes());
+        bytes.append(&mut body);
+        bytes
+    }
+}
+
+/// Messages that a client sends once the session is established.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FrontendMessage {
+    /// Simple query with the SQL text.
+    Query(String),
+
+    /// End of an extended query.
+    Sync,
+
+    /// Client closes the connection.
+    Terminate,
+
+    /// Message that is not supported, identified by its type byte.
+    Unsupported(u8),
+}
+
+impl FrontendMessage {
+    /// Reads a [`FrontendMessage`] from the given reader.
+    /// # Arguments
+    /// * `reader` - The reader to read from.
+    /// # Returns
+    /// * `Ok(Some(FrontendMessage))` - The message read.
+    /// * `Ok(None)` - If the client closed the connection.
+    /// * `Err(io::Error)` - If the message is malformed or reading failed.
+    pub fn read<R: Read>(reader: &mut R) -> io::Result<Option<Self>> {
+        let mut tag = [0; 1];
+        if reader.read(&mut tag)? == 0 {
+            return Ok(None);
+        }
+
+        let len = read_len(reader)?;
+        let mut body = vec![0; len];
+        reader.read_exact(&mut body)?;
+
+        let message = match tag[0] {
+            b'Q' => {
+                let query = body.split(|b| *b == 0).next().unwrap_or_default();
+                FrontendMessage::Query(to_string(query)?)
+            }
+            b'S' => FrontendMessage::Sync,
+            b'X' => FrontendMessage::Terminate,
+            tag => FrontendMessage::Unsupported(tag),
+        };
+
+        Ok(Some(message))
+    }
+
+    /// Encodes the message into bytes.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut body = Vec::new();
+        let tag = match self {
+            FrontendMessage::Query(query) => {
+                put_str(&mut body, query);
+                b'Q'
+            }
+            FrontendMessage::Sync => b'S',
+            FrontendMessage::Terminate => b'X',
+            FrontendMessage::Unsupported(tag) => *tag,
+        };
+
+        frame(tag, body)
+    }
+
+    /// Writes the encoded message to the given writer.
+    /// # Arguments
+    /// * `writer` - The writer to write to.
+    pub fn write<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        writer.write_all(&self.encode())
+    }
+}
+
+/// Description of a single field of a `RowDescription` message.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldDescription {
+    /// Name of the field.
+    pub name: String,
+
+    /// Type of the field.
+    pub pg_type: PgType,
+}
+
+impl FieldDescription {
+    /// Creates a new [`FieldDescription`].
+    /// # Arguments
+    /// * `name` - Name of the field.
+    /// * `pg_type` - Type of the field.
+    pub fn new<T: Into<String>>(name: T, pg_type: PgType) -> Self {
+        Self {
+            name: name.into(),
+            pg_type,
+        }
+    }
+}
+
+/// Fields of an `ErrorResponse` message.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ErrorFields {
+    /// `SQLSTATE` code of the error.
+    pub code: String,
+
+    /// Human-readable error message.
+    pub message: String,
+}
+
+/// Messages that the server sends to a client.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BackendMessage {
+    /// Authentication was successful.
+    AuthenticationOk,
+
+    /// Run-time parameter of the server.
+    ParameterStatus(String, String),
+
+    /// Key data that a client can use to cancel queries.
+    BackendKeyData {
+        /// Identifier of the session.
+        process_id: i32,
+
+        /// Secret key of the session.
+        secret_key: i32,
+    },
+
+    /// Server is ready for a new query.
+    ReadyForQuery,
+
+    /// Description of the rows that follow.
+    RowDescription(Vec<FieldDescription>),
+
+    /// Single row with values in the text format, `None` is `NULL`.
+    DataRow(Vec<Option<String>>),
+
+    /// Command completed with the given tag.
+    CommandComplete(String),
+
+    /// Query string was empty.
+    EmptyQueryResponse,
+
+    /// Error occurred.
+    ErrorResponse(ErrorFields),
+}
+
+impl BackendMessage {
+    /// Encodes the message into bytes.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut body = Vec::new();
+        let tag = match self {
+            BackendMessage::AuthenticationOk => {
+                body.extend_from_slice(&0i32.to_be_bytes());
+                b'R'
+            }
+            BackendMessage::ParameterStatus(name, value) => {
+                put_str(&mut body, name);
+                put_str(&mut body, value);
+                b'S'
+            }
+            BackendMessage::BackendKeyData {
+                process_id,
+                secret_key,
+            } => {
+                body.extend_from_slice(&process_id.to_be_bytes());
+                body.extend_from_slice(&secret_key.to_be_bytes());
+                b'K'
+            }
+            BackendMessage::ReadyForQuery => {
+                // Transactions are not supported, so the session is always
+                // idle.
+                body.push(b'I');
+                b'Z'
+            }
+            BackendMessage::RowDescription(fields) => {
+                body.extend_from_slice(&(fields.len() as i16).to_be_bytes());
+                for field in fields {
+                    put_str(&mut body, &field.name);
+                    // Table OID and column attribute number.
+                    body.extend_from_slice(&0i32.to_be_bytes());
+                    body.extend_from_slice(&0i16.to_be_bytes());
+                    body.extend_from_slice(&field.pg_type.oid.to_be_bytes());
+                    body.extend_from_slice(&field.pg_type.size.to_be_bytes());
+                    body.extend_from_slice(
+                        &field.pg_type.modifier.to_be_bytes(),
+                    );
+                    // Text format code.
+                    body.extend_from_slice(&0i16.to_be_bytes());
+                }
+                b'T'
+            }
+            BackendMessage::DataRow(values) => {
+                body.extend_from_slice(&(values.len() as i16).to_be_bytes());
+                for value in values {
+                    match value {
+                        Some(value) => {
+                            body.extend_from_slice(
+                                &(value.len() as i32).to_be_bytes(),
+                            );
+                            body.extend_from_slice(value.as_bytes());
+                        }
+                        None => body.extend_from_slice(&(-1i32).to_be_bytes()),
+                    }
+                }
+                b'D'
+            }
+            BackendMessage::CommandComplete(tag) => {
+                put_str(&mut body, tag);
+                b'C'
+            }
+            BackendMessage::EmptyQueryResponse => b'I',
+            BackendMessage::ErrorResponse(fields) => {
+                for (field, value) in [
+                    (b'S', "ERROR"),
+                    (b'V', "ERROR"),
+                    (b'C', fields.code.as_str()),
+                    (b'M', fields.message.as_str()),
+                ] {
+                    body.push(field);
+                    put_str(&mut body, value);
+                }
+                body.push(0);
+                b'E'
+            }
+        };
+
+        frame(tag, body)
+    }
+
+    /// Reads a [`BackendMessage`] from the given reader.
+    /// # Arguments
+    /// * `reader` - The reader to read from.
+    /// # Returns
+    /// * `Ok(Some(BackendMessage))` - The message read.
+    /// * `Ok(None)` - If the message type is not known, the message is
+    ///   skipped.
+    /// * `Err(io::Error)` - If the message is malformed or reading failed.
+    pub fn read<R: Read>(reader: &mut R) -> io::Result<Option<Self>> {
+        let mut tag = [0; 1];
+        reader.read_exact(&mut tag)?;
+        let len = read_len(reader)?;
+        let mut bytes = vec![0; len];
+        reader.read_exact(&mut bytes)?;
+        let mut body = Body(bytes.as_slice());
+
+        let message = match tag[0] {
+            b'R' => match body.get_i32()? {
+                0 => BackendMessage::AuthenticationOk,
+                _ => {
+                    return Err(invalid_data(
+                        "authentication method is not supported",
+                    ))
+                }
+            },
+            b'S' => BackendMessage::ParameterStatus(
+                body.get_str()?,
+                body.get_str()?,
+            ),
+            b'K' => BackendMessage::BackendKeyData {
+                process_id: body.get_i32()?,
+                secret_key: body.get_i32()?,
+            },
+            b'Z' => BackendMessage::ReadyForQuery,
+            b'T' => {
+                let count = body.get_i16()?;
+                let mut fields = Vec::new();
+                for _ in 0..count {
+                    let name = body.get_str()?;
+                    // Table OID and column attribute number.
+                    body.get_bytes(6)?;
+                    let pg_type = PgType {
+                        oid: body.get_i32()? as u32,
+                        size: body.get_i16()?,
+                        modifier: body.get_i32()?,
+                    };
+                    // Format code.
+                    body.get_bytes(2)?;
+                    fields.push(FieldDescription { name, pg_type });
+                }
+                BackendMessage::RowDescription(fields)
+            }
+            b'D' => {
+                let count = body.get_i16()?;
+                let mut values = Vec::new();
+                for _ in 0..count {
+                    let value = match usize::try_from(body.get_i32()?) {
+                        Ok(len) => Some(to_string(body.get_bytes(len)?)?),
+                        Err(_) => None,
+                    };
+                    values.push(value);
+                }
+                BackendMessage::DataRow(values)
+            }
+            b'C' => BackendMessage::CommandComplete(body.get_str()?),
+            b'I' => BackendMessage::EmptyQueryResponse,
+            b'E' => {
+                let mut code = String::new();
+                let mut message = String::new();
+                loop {
+                    let field = body.get_bytes(1)?[0];
+                    if field == 0 {
+                        break;
+                    }
+                    let value = body.get_str()?;
+                    match field {
+                        b'C' => code = value,
+                        b'M' => message = value,
+                        _ => {}
+                    }
+                }
+                BackendMessage::ErrorResponse(ErrorFields { code, message })
+            }
+            _ => return Ok(None),
+        };
+
+        Ok(Some(message))
+    }
+
+    /// Writes the encoded message to the given writer.
+    /// # Arguments
+    /// * `writer` - The writer to write to.
+    pub fn write<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        writer.write_all(&self.encode())
+    }
+}
+
+/// Body of a message that is read field by field.
+struct Body<'a>(&'a [u8]);
+
+impl<'a> Body<'a> {
+    /// Takes the next `len` bytes.
+    fn get_bytes(&mut self, len: usize) -> io::Result<&'a [u8]> {
+        if self.0.len() < len {
+            return Err(invalid_data("message is too short"));
+        }
+        let (bytes, rest) = self.0.split_at(len);
+        self.0 = rest;
+
+        Ok(bytes)
+    }
+
+    /// Takes a big-endian `i16`.
+    fn get_i16(&mut self) -> io::Result<i16> {
+        let bytes = self.get_bytes(2)?;
+        Ok(i16::from_be_bytes([bytes[0], bytes[1]]))
+    }
+
+    /// Takes a big-endian `i32`.
+    fn get_i32(&mut self) -> io::Result<i32> {
+        let mut bytes = [0; 4];
+        bytes.copy_from_slice(self.get_bytes(4)?);
+        Ok(i32::from_be_bytes(bytes))
+    }
+
+    /// Takes a null-terminated string.
+    fn get_str(&mut self) -> io::Result<String> {
+        let len = self
+            .0
+            .iter()
+            .position(|b| *b == 0)
+            .ok_or_else(|| invalid_data("string is not terminated"))?;
+        let value = to_string(self.get_bytes(len)?)?;
+        self.get_bytes(1)?;
+
+        Ok(value)
+    }
+}
+
+/// Prepends the message type and length to the body.
+fn frame(tag: u8, mut body: Vec<u8>) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(body.len() + 5);
+    bytes.push(tag);
+    bytes.extend_from_slice(&(body.len() as i32 + 4).to_be_bytes());
+    bytes.append(&mut body);
+    bytes
+}
+
+/// Reads a big-endian `i32`.
+fn read_i32<R: Read>(reader: &mut R) -> io::Result<i32> {
+    let mut bytes = [0; 4];
+    reader.read_exact(&mut bytes)?;
+    Ok(i32::from_be_bytes(bytes))
+}
+
+/// Reads the length of a message and returns the length of its body.
+fn read_len<R: Read>(reader: &mut R) -> io::Result<usize> {
+    let len = read_i32(reader)?;
+    let len = usize::try_from(len)
+        .ok()
+        .and_then(|len| len.checked_sub(4))
+        .ok_or_else(|| invalid_data("invalid message length"))?;
+    if len > MAX_MESSAGE_LEN {
+        return Err(invalid_data("message is too long"));
+    }
+
+    Ok(len)
+}
+
+/// Appends a null-terminated string.
+fn put_str(buf: &mut Vec<u8>, value: &str) {
+    buf.extend_from_slice(value.as_bytes());
+    buf.push(0);
+}
+
+/// Converts bytes to a UTF-8 string.
+fn to_string(bytes: &[u8]) -> io::Result<String> {
+    String::from_utf8(bytes.to_vec())
+        .map_err(|_| invalid_data("string is not valid UTF-8"))
+}
+
+/// Creates an [`io::Error`] of the [`io::ErrorKind::InvalidData`] kind.
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pg::r#type::{PgType, INT4_OID};
+
+    use super::*;
+
+    fn startup_bytes(code: i32, body: &[u8]) -> Vec<u8> {
+        let mut bytes = (body.len() as i32 + 8).to_be_bytes().to_vec();
+        bytes.extend_from_slice(&code.to_be_bytes());
+        bytes.extend_from_slice(body);
+        bytes
+    }
+
+    #[test]
+    fn test_read_startup() {
+        let bytes = startup_bytes(
+            PROTOCOL_VERSION,
+            b"user\0postgres\0database\0test\0\0",
+        );
+        let message = StartupMessage::read(&mut bytes.as_slice()).unwrap();
+
+        assert_eq!(
+            message,
+            StartupMessage::Startup(vec![
+                ("user".to_string(), "postgres".to_string()),
+                ("database".to_string(), "test".to_string()),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_read_ssl_request() {
+        let bytes = startup_bytes(SSL_REQUEST_CODE, &[]);
+        let message = StartupMessage::read(&mut bytes.as_slice()).unwrap();
+
+        assert_eq!(message, StartupMessage::SslRequest);
+    }
+
+    #[test]
+    fn test_read_unknown_protocol() {
+        let bytes = startup_bytes(131_072, &[0]);
+        let message = StartupMessage::read(&mut bytes.as_slice());
+
+        assert!(message.is_err());
+    }
+
+    #[test]
+    fn test_read_query() {
+        let mut bytes = vec![b'Q'];
+        bytes.extend_from_slice(&13i32.to_be_bytes());
+        bytes.extend_from_slice(b"select 1\0");
+        let message = FrontendMessage::read(&mut bytes.as_slice()).unwrap();
+
+        assert_eq!(message, Some(FrontendMessage::Query("select 1".into())));
+    }
+
+    #[test]
+    fn test_read_eof() {
+        let message = FrontendMessage::read(&mut [].as_slice()).unwrap();
+
+        assert_eq!(message, None);
+    }
+
+    #[test]
+    fn test_read_invalid_length() {
+        let mut bytes = vec![b'Q'];
+        bytes.extend_from_slice(&2i32.to_be_bytes());
+        let message = FrontendMessage::read(&mut bytes.as_slice());
+
+        assert!(message.is_err());
+    }
+
+    #[test]
+    fn test_startup_roundtrip() {
+        let message = StartupMessage::Startup(vec![(
+            "user".to_string(),
+            "test".to_string(),
+        )]);
+        let bytes = message.encode();
+
+        assert_eq!(
+            StartupMessage::read(&mut bytes.as_slice()).unwrap(),
+            message
+        );
+    }
+
+    #[test]
+    fn test_query_roundtrip() {
+        let message = FrontendMessage::Query("SHOW DATABASES;".to_string());
+        let bytes = message.encode();
+
+        assert_eq!(
+            FrontendMessage::read(&mut bytes.as_slice()).unwrap(),
+            Some(message)
+        );
+    }
+
+    #[test]
+    fn test_backend_roundtrip() {
+        let messages = vec![
+            BackendMessage::AuthenticationOk,
+            BackendMessage::ParameterStatus("a".to_string(), "b".to_string()),
+            BackendMessage::RowDescription(vec![FieldDescription::new(
+                "id",
+                PgType::TEXT,
+            )]),
+            BackendMessage::DataRow(vec![Some("1".to_string()), None]),
+            BackendMessage::CommandComplete("SELECT 1".to_string()),
+            BackendMessage::ErrorResponse(ErrorFields {
+                code: "XX000".to_string(),
+                message: "error".to_string(),
+            }),
+            BackendMessage::ReadyForQuery,
+        ];
+
+        for message in messages {
+            let bytes = message.encode();
+            let read = BackendMessage::read(&mut bytes.as_slice()).unwrap();
+            assert_eq!(read, Some(message));
+        }
+    }
+
+    #[test]
+    fn test_read_unknown_backend_message() {
+        let bytes = frame(b'N', vec![0]);
+        let read = BackendMessage::read(&mut bytes.as_slice()).unwrap();
+
+        assert_eq!(read, None);
+    }
+
+    #[test]
+    fn test_encode_ready_for_query() {
+        let bytes = BackendMessage::ReadyForQuery.encode();
+
+        assert_eq!(bytes, vec![b'Z', 0, 0, 0, 5, b'I']);
+    }
+
+    #[test]
+    fn test_encode_row_description() {
+        let pg_type = PgType {
+            oid: INT4_OID,
+            size: 4,
+            modifier: -1,
+        };
+        let message =
+            BackendMessage::RowDescription(vec![FieldDescription::new(
+                "id", pg_type,
+            )]);
+        let bytes = message.encode();
+
+        assert_eq!(bytes[0], b'T');
+        assert_eq!(&bytes[1..5], &(bytes.len() as i32 - 1).to_be_bytes());
+        assert_eq!(&bytes[5..7], &1i16.to_be_bytes());
+        assert_eq!(&bytes[7..10], b"id\0");
+        assert_eq!(&bytes[16..20], &INT4_OID.to_be_bytes());
+    }
+
+    #[test]
+    fn test_encode_data_row_with_null() {
+        let message =
+            BackendMessage::DataRow(vec![Some("1".to_string()), None]);
+        let bytes = message.encode();
+
+        assert_eq!(
+            bytes,
+            vec![b'D', 0, 0, 0, 15, 0, 2, 0, 0, 0, 1, b'1', 255, 255, 255, 255]
+        );
+    }
+}
@@ -0,0 +1,370 @@
+pub mod message;
+pub mod session;
+pub mod r#type;
+
+use std::{
+    io,
+    net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs},
+    sync::{Arc, Mutex},
+    thread,
+};
+
+use backend_api::api::facade::BackendFacade;
+
+pub use session::Session;
+
+/// Server that speaks the PostgreSQL v3 frontend/backend protocol.
+///
+/// Only the simple query protocol is supported. Queries are parsed by the
+/// [`frontend::FrontendApi`] and executed on the shared [`BackendFacade`].
+pub struct Server<const NODE_SIZE: u8> {
+    /// Listener of the client connections.
+    listener: TcpListener,
+
+    /// [`BackendFacade`] shared by all sessions.
+    facade: Arc<Mutex<BackendFacade<NODE_SIZE>>>,
+}
+
+impl<const NODE_SIZE: u8> Server<NODE_SIZE> {
+    /// Creates a new [`Server`] with an empty [`BackendFacade`].
+    /// # Arguments
+    /// * `addr` - Address to listen on.
+    pub fn bind<A: ToSocketAddrs>(addr: A) -> io::Result<Self> {
+        let facade = Arc::new(Mutex::new(BackendFacade::default()));
+        Self::bind_with_facade(addr, facade)
+    }
+
+    /// Creates a new [`Server`] with the given [`BackendFacade`].
+    /// # Arguments
+    /// * `addr` - Address to listen on.
+    /// * `facade` - [`BackendFacade`] to execute commands on.
+    pub fn bind_with_facade<A: ToSocketAddrs>(
+        addr: A,
+        facade: Arc<Mutex<BackendFacade<NODE_SIZE>>>,
+    ) -> io::Result<Self> {
+        Ok(Self {
+            listener: TcpListener::bind(addr)?,
+            facade,
+        })
+    }
+
+    /// Returns the address the [`Server`] listens on.
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    /// Returns the [`BackendFacade`] shared by the sessions.
+    pub fn facade(&self) -> Arc<Mutex<BackendFacade<NODE_SIZE>>> {
+        self.facade.clone()
+    }
+
+    /// Accepts connections and serves each of them in a separate thread.
+    pub fn run(self) -> io::Result<()> {
+        for (id, stream) in (1..).zip(self.listener.incoming()) {
+            let stream = stream?;
+            let facade = self.facade.clone();
+            thread::spawn(move || {
+                // Errors of a single session must not stop the server.
+                let _ = serve(stream, facade, id);
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// Serves a single client connection.
+fn serve<const NODE_SIZE: u8>(
+    stream: TcpStream,
+    facade: Arc<Mutex<BackendFacade<NODE_SIZE>>>,
+    id: i32,
+) -> io::Result<()> {
+    stream.set_nodelay(true)?;
+    let reader = stream.try_clone()?;
+    Session::new(reader, stream, facade, id).run()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{Read, Write},
+        net::TcpStream,
+        thread,
+    };
+
+    use backend::{
+        data::DataUnit,
+        schema::r#type::{r#enum::StorageData, DataRow},
+    };
+    use backend_api::api::command::{table::Insert, Gateway};
+
+    use crate::pg::{
+        message::{PROTOCOL_VERSION, SSL_REQUEST_CODE},
+        r#type::{INT4_OID, TEXT_OID},
+        Server,
+    };
+
+    /// Message received from the server in tests.
+    struct Message {
+        tag: u8,
+        body: Vec<u8>,
+    }
+
+    fn start_server() -> TcpStream {
+        let server = Server::<4>::bind("127.0.0.1:0").unwrap();
+        let addr = server.local_addr().unwrap();
+        thread::spawn(move || server.run());
+
+        TcpStream::connect(addr).unwrap()
+    }
+
+    fn read_message(stream: &mut TcpStream) -> Message {
+        let mut tag = [0; 1];
+        stream.read_exact(&mut tag).unwrap();
+        let mut len = [0; 4];
+        stream.read_exact(&mut len).unwrap();
+        let mut body = vec![0; i32::from_be_bytes(len) as usize - 4];
+        stream.read_exact(&mut body).unwrap();
+
+        Message { tag: tag[0], body }
+    }
+
+    /// Reads messages until `ReadyForQuery`, returns their tags.
+    fn read_until_ready(stream: &mut TcpStream) -> Vec<Message> {
+        let mut messages = Vec::new();
+        loop {
+            let message = read_message(stream);
+            let tag = message.tag;
+            messages.push(message);
+            if tag == b'Z' {
+                return messages;
+            }
+        }
+    }
+
+    fn startup(stream: &mut TcpStream) -> Vec<Message> {
+        let body = b"user\0test\0\0";
+        let mut bytes = (body.len() as i32 + 8).to_be_bytes().to_vec();
+        bytes.extend_from_slice(&PROTOCOL_VERSION.to_be_bytes());
+        bytes.extend_from_slice(body);
+        stream.write_all(&bytes).unwrap();
+
+        read_until_ready(stream)
+    }
+
+    fn query(stream: &mut TcpStream, query: &str) -> Vec<Message> {
+        let mut bytes = vec![b'Q'];
+        bytes.extend_from_slice(&(query.len() as i32 + 5).to_be_bytes());
+        bytes.extend_from_slice(query.as_bytes());
+        bytes.push(0);
+        stream.write_all(&bytes).unwrap();
+
+        read_until_ready(stream)
+    }
+
+    fn tags(messages: &[Message]) -> Vec<u8> {
+        messages.iter().map(|m| m.tag).collect()
+    }
+
+    #[test]
+    fn test_startup() {
+        let mut stream = start_server();
+        let messages = startup(&mut stream);
+
+        assert_eq!(messages[0].tag, b'R');
+        assert_eq!(messages[0].body, vec![0, 0, 0, 0]);
+        assert_eq!(messages.last().unwrap().body, vec![b'I']);
+    }
+
+    #[test]
+    fn test_ssl_request_is_declined() {
+        let mut stream = start_server();
+        let mut bytes = 8i32.to_be_bytes().to_vec();
+        bytes.extend_from_slice(&SSL_REQUEST_CODE.to_be_bytes());
+        stream.write_all(&bytes).unwrap();
+
+        let mut answer = [0; 1];
+        stream.read_exact(&mut answer).unwrap();
+        assert_eq!(answer[0], b'N');
+
+        let messages = startup(&mut stream);
+        assert_eq!(messages[0].tag, b'R');
+    }
+
+    #[test]
+    fn test_create_database() {
+        let mut stream = start_server();
+        startup(&mut stream);
+
+        let messages = query(&mut stream, "CREATE DATABASE test;");
+        assert_eq!(tags(&messages), vec![b'C', b'Z']);
+        assert_eq!(messages[0].body, b"CREATE DATABASE\0");
+
+        let messages = query(&mut stream, "SHOW DATABASES;");
+        assert_eq!(tags(&messages), vec![b'T', b'D', b'C', b'Z']);
+        assert_eq!(&messages[0].body[2..9], b"result\0");
+        assert!(String::from_utf8_lossy(&messages[1].body).contains("test"));
+    }
+
+    #[test]
+    fn test_error_response() {
+        let mut stream = start_server();
+        startup(&mut stream);
+
+        let messages = query(&mut stream, "USE DATABASE missing;");
+        assert_eq!(tags(&messages), vec![b'E', b'Z']);
+        let body = String::from_utf8_lossy(&messages[0].body).to_string();
+        assert!(body.contains("XX000"));
+        assert!(body.contains("missing"));
+    }
+
+    #[test]
+    fn test_error_skips_rest_of_query() {
+        let mut stream = start_server();
+        startup(&mut stream);
+
+        let messages = query(
+            &mut stream,
+            "USE DATABASE missing; CREATE DATABASE skipped;",
+        );
+        assert_eq!(tags(&messages), vec![b'E', b'Z']);
+
+        let messages = query(&mut stream, "SHOW DATABASES;");
+        let body = String::from_utf8_lossy(&messages[1].body).to_string();
+        assert!(!body.contains("skipped"));
+    }
+
+    #[test]
+    fn test_empty_query() {
+        let mut stream = start_server();
+        startup(&mut stream);
+
+        let messages = query(&mut stream, "");
+        assert_eq!(tags(&messages), vec![b'I', b'Z']);
+    }
+
+    #[test]
+    fn test_sessions_have_own_context() {
+        let server = Server::<4>::bind("127.0.0.1:0").unwrap();
+        let addr = server.local_addr().unwrap();
+        thread::spawn(move || server.run());
+
+        let mut first = TcpStream::connect(addr).unwrap();
+        startup(&mut first);
+        query(&mut first, "CREATE DATABASE test;");
+        let messages = query(&mut first, "USE DATABASE test;");
+        assert_eq!(tags(&messages), vec![b'C', b'Z']);
+
+        let mut second = TcpStream::connect(addr).unwrap();
+        startup(&mut second);
+        let messages = query(&mut second, "\\gc");
+        assert_eq!(tags(&messages), vec![b'T', b'D', b'C', b'Z']);
+        assert_eq!(&messages[0].body[17..21], &TEXT_OID.to_be_bytes());
+        // Both values are `NULL`.
+        assert_eq!(
+            messages[1].body,
+            vec![0, 2, 255, 255, 255, 255, 255, 255, 255, 255]
+        );
+    }
+
+    #[test]
+    fn test_select() {
+        let server = Server::<4>::bind("127.0.0.1:0").unwrap();
+        let addr = server.local_addr().unwrap();
+        let facade = server.facade();
+        thread::spawn(move || server.run());
+
+        let mut stream = TcpStream::connect(addr).unwrap();
+        startup(&mut stream);
+        let messages = query(
+            &mut stream,
+            "CREATE DATABASE test; CREATE SCHEMA test.shop; \
+             CREATE TABLE test.shop.items (id INTEGER PRIMARY KEY);",
+        );
+        assert_eq!(tags(&messages), vec![b'C', b'C', b'C', b'Z']);
+
+        let mut data = DataUnit::new(vec!["id".into()]);
+        for id in 1..=3 {
+            data.insert(DataRow(vec![StorageData::from(id)]));
+        }
+        let insert = Insert {
+            database_name: Some("test".into()),
+            schema_name: Some("shop".into()),
+            name: "items".into(),
+            data,
+        };
+        assert!(facade.lock().unwrap().send(insert).is_ok());
+
+        let messages = query(
+            &mut stream,
+            "SELECT id * 10 AS tens FROM test.shop.items WHERE id > 1;",
+        );
+        assert_eq!(tags(&messages), vec![b'T', b'D', b'D', b'C', b'Z']);
+        assert_eq!(&messages[0].body[2..7], b"tens\0");
+        assert_eq!(&messages[0].body[13..17], &INT4_OID.to_be_bytes());
+        assert_eq!(messages[1].body, b"\0\x01\0\0\0\x0220");
+        assert_eq!(messages[2].body, b"\0\x01\0\0\0\x0230");
+        assert_eq!(messages[3].body, b"SELECT 2\0");
+
+        let messages = query(
+            &mut stream,
+            "SELECT * FROM test.shop.items ORDER BY id DESC LIMIT 1 OFFSET 1;",
+        );
+        assert_eq!(tags(&messages), vec![b'T', b'D', b'C', b'Z']);
+        assert_eq!(messages[1].body, b"\0\x01\0\0\0\x012");
+        assert_eq!(messages[2].body, b"SELECT 1\0");
+
+        let messages = query(
+            &mut stream,
+            "SELECT COUNT(*) AS n, SUM(id) FROM test.shop.items \
+             WHERE id > 1 HAVING COUNT(*) > 0;",
+        );
+        assert_eq!(tags(&messages), vec![b'T', b'D', b'C', b'Z']);
+        assert_eq!(&messages[0].body[2..4], b"n\0");
+        assert_eq!(messages[1].body, b"\0\x02\0\0\0\x012\0\0\0\x015");
+        assert_eq!(messages[2].body, b"SELECT 1\0");
+
+        let messages = query(
+            &mut stream,
+            "SELECT * FROM test.shop.items AS a JOIN test.shop.items AS b \
+             ON a.id = b.id + 1;",
+        );
+        assert_eq!(tags(&messages), vec![b'T', b'D', b'D', b'C', b'Z']);
+        assert_eq!(&messages[0].body[2..7], b"a.id\0");
+        assert_eq!(messages[3].body, b"SELECT 2\0");
+
+        let messages =
+            query(&mut stream, "SELECT id FROM test.shop.items WHERE id > 10;");
+        assert_eq!(tags(&messages), vec![b'T', b'C', b'Z']);
+        assert_eq!(&messages[0].body[11..15], &INT4_OID.to_be_bytes());
+        assert_eq!(messages[1].body, b"SELECT 0\0");
+
+        let messages =
+            query(&mut stream, "SELECT id / 0 FROM test.shop.items;");
+        assert_eq!(tags(&messages), vec![b'E', b'Z']);
+        let body = String::from_utf8_lossy(&messages[0].body).to_string();
+        assert!(body.contains("22012"));
+
+        let messages = query(&mut stream, "SELECT * FROM test.shop.missing;");
+        assert_eq!(tags(&messages), vec![b'E', b'Z']);
+    }
+
+    #[test]
+    fn test_poisoned_facade() {
+        let server = Server::<4>::bind("127.0.0.1:0").unwrap();
+        let addr = server.local_addr().unwrap();
+        let facade = server.facade();
+        thread::spawn(move || server.run());
+
+        let _ = thread::spawn(move || {
+            let _facade = facade.lock().unwrap();
+            panic!("poisons the facade");
+        })
+        .join();
+
+        let mut stream = TcpStream::connect(addr).unwrap();
+        startup(&mut stream);
+        let messages = query(&mut stream, "CREATE DATABASE test;");
+        assert_eq!(tags(&messages), vec![b'C', b'Z']);
+    }
+}
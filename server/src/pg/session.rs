@@ -0,0 +1,485 @@
+use std::{
+    io::{self, BufReader, BufWriter, Read, Write},
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+};
+
+use backend::{
+    controller::{table::TableControllerError, SchemaControllerError},
+    data::DataUnit,
+    expression::EvaluationError,
+    query::AggregationError,
+    schema::database,
+};
+use backend_api::{
+    api::{
+        command::{
+            backend_api::DatabaseCommand,
+            database::SchemaCommand,
+            r#enum::BackendCommand,
+            schema::{join_tables, TableCommand},
+            table::{self, select, DataCommand},
+            Gateway, GatewayError,
+        },
+        facade::BackendFacade,
+        CommandResultString,
+    },
+    Context,
+};
+use common::structs::hash_table::HashTable;
+use frontend::{
+    planner::{adapter::PlannerCommand, command::FrontendCommand},
+    FrontendApi,
+};
+
+use crate::pg::{
+    message::{
+        BackendMessage, ErrorFields, FieldDescription, FrontendMessage,
+        StartupMessage,
+    },
+    r#type::{encode_text, PgType},
+};
+
+/// `SQLSTATE` code for syntax errors.
+const SYNTAX_ERROR: &str = "42601";
+
+/// `SQLSTATE` code for errors returned by the backend.
+const INTERNAL_ERROR: &str = "XX000";
+
+/// `SQLSTATE` code for duplicate values of a primary key or a unique
+/// constraint.
+const UNIQUE_VIOLATION: &str = "23505";
+
+/// `SQLSTATE` code for rows that violate a check constraint.
+const CHECK_VIOLATION: &str = "23514";
+
+/// `SQLSTATE` code for rows that violate a foreign key constraint.
+const FOREIGN_KEY_VIOLATION: &str = "23503";
+
+/// `SQLSTATE` code for division by zero.
+const DIVISION_BY_ZERO: &str = "22012";
+
+/// `SQLSTATE` code for features that are not supported.
+const FEATURE_NOT_SUPPORTED: &str = "0A000";
+
+/// Version reported to clients in the `server_version` parameter.
+const SERVER_VERSION: &str = "14.0 (nosqlite)";
+
+/// Single client session of the PostgreSQL protocol.
+pub struct Session<S: Read + Write, const NODE_SIZE: u8> {
+    /// Reader of the client stream.
+    reader: BufReader<S>,
+
+    /// Writer of the client stream.
+    writer: BufWriter<S>,
+
+    /// Shared [`BackendFacade`].
+    facade: Arc<Mutex<BackendFacade<NODE_SIZE>>>,
+
+    /// [`Context`] of the session. It is swapped into the [`BackendFacade`]
+    /// for the time of a command execution, so sessions don't see each
+    /// other's current database and schema.
+    context: Context,
+
+    /// Identifier of the session sent in `BackendKeyData`.
+    id: i32,
+}
+
+impl<S, const NODE_SIZE: u8> Session<S, NODE_SIZE>
+where
+    S: Read + Write,
+{
+    /// Creates a new [`Session`].
+    /// # Arguments
+    /// * `reader` - Stream to read client messages from.
+    /// * `writer` - Stream to write server messages to.
+    /// * `facade` - Shared [`BackendFacade`] to execute commands on.
+    /// * `id` - Identifier of the session.
+    pub fn new(
+        reader: S,
+        writer: S,
+        facade: Arc<Mutex<BackendFacade<NODE_SIZE>>>,
+        id: i32,
+    ) -> Self {
+        Self {
+            reader: BufReader::new(reader),
+            writer: BufWriter::new(writer),
+            facade,
+            context: Context::default(),
+            id,
+        }
+    }
+
+    /// Runs the session until the client disconnects.
+    pub fn run(mut self) -> io::Result<()> {
+        if !self.startup()? {
+            return Ok(());
+        }
+
+        // After an error in an extended query all messages are skipped until
+        // `Sync`.
+        let mut skip_until_sync = false;
+        while let Some(message) = FrontendMessage::read(&mut self.reader)? {
+            match message {
+                FrontendMessage::Query(query) => {
+                    let quit = self.simple_query(&query)?;
+                    if quit {
+                        break;
+                    }
+                }
+                FrontendMessage::Sync => {
+                    skip_until_sync = false;
+                    self.send(BackendMessage::ReadyForQuery)?;
+                }
+                FrontendMessage::Terminate => break,
+                FrontendMessage::Unsupported(tag) => {
+                    if !skip_until_sync {
+                        skip_until_sync = true;
+                        self.send(error(
+                            FEATURE_NOT_SUPPORTED,
+                            format!(
+                                "message `{}` is not supported, only simple \
+                                 queries are",
+                                tag as char
+                            ),
+                        ))?;
+                    }
+                }
+            }
+            self.writer.flush()?;
+        }
+
+        Ok(())
+    }
+
+    /// Handles the startup phase of the session.
+    /// # Returns
+    /// * `Ok(true)` - If the session was established.
+    /// * `Ok(false)` - If the client doesn't need a session.
+    fn startup(&mut self) -> io::Result<bool> {
+        let params = loop {
+            match StartupMessage::read(&mut self.reader)? {
+                StartupMessage::Startup(params) => break params,
+                StartupMessage::SslRequest | StartupMessage::GssEncRequest => {
+                    // Encryption is not supported, client continues with an
+                    // unencrypted startup message.
+                    self.writer.write_all(b"N")?;
+                    self.writer.flush()?;
+                }
+                StartupMessage::CancelRequest => return Ok(false),
+            }
+        };
+
+        if let Some((_, name)) = params.iter().find(|(k, _)| k == "database") {
+            let name = database::Name::from(name.as_str());
+            let mut facade = lock(&self.facade);
+            if facade.database_controllers.contains_key(&name) {
+                self.context.set_current_db(name);
+            }
+        }
+
+        self.send(BackendMessage::AuthenticationOk)?;
+        for (name, value) in [
+            ("server_version", SERVER_VERSION),
+            ("server_encoding", "UTF8"),
+            ("client_encoding", "UTF8"),
+            ("DateStyle", "ISO, MDY"),
+            ("integer_datetimes", "on"),
+            ("standard_conforming_strings", "on"),
+        ] {
+            self.send(BackendMessage::ParameterStatus(
+                name.to_string(),
+                value.to_string(),
+            ))?;
+        }
+        self.send(BackendMessage::BackendKeyData {
+            process_id: self.id,
+            secret_key: 0,
+        })?;
+        self.send(BackendMessage::ReadyForQuery)?;
+        self.writer.flush()?;
+
+        Ok(true)
+    }
+
+    /// Executes a simple query, that may contain several commands.
+    /// # Returns
+    /// `true` if the client asked to quit.
+    fn simple_query(&mut self, query: &str) -> io::Result<bool> {
+        if query.trim().trim_matches(';').trim().is_empty() {
+            self.send(BackendMessage::EmptyQueryResponse)?;
+            self.send(BackendMessage::ReadyForQuery)?;
+            return Ok(false);
+        }
+
+        let mut quit = false;
+        for command in FrontendApi::new(query) {
+            let command = match command {
+                Ok(command) => command,
+                Err(err) => {
+                    self.send(error(SYNTAX_ERROR, err.to_string()))?;
+                    break;
+                }
+            };
+
+            let result = match command {
+                PlannerCommand::Backend(command) => {
+                    self.execute_backend(command)
+                }
+                PlannerCommand::Frontend(command) => {
+                    self.execute_frontend(command, &mut quit)
+                }
+            };
+
+            match result {
+                Ok(messages) => {
+                    for message in messages {
+                        self.send(message)?;
+                    }
+                }
+                Err(err) => {
+                    // Like PostgreSQL, the rest of the query is skipped
+                    // after an error.
+                    self.send(err)?;
+                    break;
+                }
+            }
+
+            if quit {
+                return Ok(true);
+            }
+        }
+        self.send(BackendMessage::ReadyForQuery)?;
+
+        Ok(false)
+    }
+
+    /// Executes a [`BackendCommand`] on the shared [`BackendFacade`].
+    /// # Returns
+    /// * `Ok(Vec<BackendMessage>)` - Messages with the command result.
+    /// * `Err(BackendMessage)` - `ErrorResponse` message.
+    fn execute_backend(
+        &mut self,
+        command: BackendCommand,
+    ) -> Result<Vec<BackendMessage>, BackendMessage> {
+        let tag = command_tag(&command);
+
+        let mut facade = lock(&self.facade);
+        std::mem::swap(&mut facade.context, &mut self.context);
+        let result = match command {
+            BackendCommand::Database(command) => facade
+                .send(command)
+                .map(|result| result_messages(tag, result))
+                .map_err(|e| error(INTERNAL_ERROR, e.to_string())),
+            BackendCommand::Schema(command) => facade
+                .send(command)
+                .map(|result| result_messages(tag, result))
+                .map_err(|e| error(INTERNAL_ERROR, e.to_string())),
+            BackendCommand::Table(command) => facade
+                .send(command)
+                .map(|result| result_messages(tag, result))
+                .map_err(|e| error(INTERNAL_ERROR, e.to_string())),
+            BackendCommand::Data(command) => facade
+                .send(command)
+                .map(|data| data_messages(tag, data))
+                .map_err(|e| {
+                    let code = match &e {
+                        GatewayError::CommandError(
+                            table::ExecutionError::Select(
+                                select::ExecutionError::TableControllerError(e),
+                            ),
+                        ) => table_error_code(e),
+                        _ => INTERNAL_ERROR,
+                    };
+                    error(code, e.to_string())
+                }),
+            BackendCommand::Join(command) => facade
+                .send(*command)
+                .map(|data| data_messages(tag, data))
+                .map_err(|e| {
+                    let code = match &e {
+                        GatewayError::CommandError(
+                            join_tables::ExecutionError::SchemaControllerError(
+                                e,
+                            ),
+                        ) => schema_error_code(e),
+                        _ => INTERNAL_ERROR,
+                    };
+                    error(code, e.to_string())
+                }),
+        };
+        std::mem::swap(&mut facade.context, &mut self.context);
+
+        result
+    }
+
+    /// Executes a [`FrontendCommand`] in the session.
+    /// # Returns
+    /// * `Ok(Vec<BackendMessage>)` - Messages with the command result.
+    /// * `Err(BackendMessage)` - `ErrorResponse` message.
+    fn execute_frontend(
+        &mut self,
+        command: FrontendCommand,
+        quit: &mut bool,
+    ) -> Result<Vec<BackendMessage>, BackendMessage> {
+        match command {
+            FrontendCommand::Quit => {
+                *quit = true;
+                Ok(vec![])
+            }
+            FrontendCommand::GetContext => {
+                let db = self.context.current_db().map(|db| db.0.clone());
+                let schema = self
+                    .context
+                    .current_schema()
+                    .map(|schema| schema.0.clone());
+
+                Ok(vec![
+                    BackendMessage::RowDescription(vec![
+                        FieldDescription::new("database", PgType::TEXT),
+                        FieldDescription::new("schema", PgType::TEXT),
+                    ]),
+                    BackendMessage::DataRow(vec![db, schema]),
+                    BackendMessage::CommandComplete("SELECT 1".to_string()),
+                ])
+            }
+            FrontendCommand::Help | FrontendCommand::Clear => Err(error(
+                FEATURE_NOT_SUPPORTED,
+                format!("{:?} is a console command", command),
+            )),
+        }
+    }
+
+    /// Writes a message to the client.
+    fn send(&mut self, message: BackendMessage) -> io::Result<()> {
+        message.write(&mut self.writer)
+    }
+}
+
+/// Locks the shared [`BackendFacade`]. A session that panicked while holding
+/// the lock poisons it, the facade is still used by the other sessions then.
+fn lock<const NODE_SIZE: u8>(
+    facade: &Mutex<BackendFacade<NODE_SIZE>>,
+) -> MutexGuard<'_, BackendFacade<NODE_SIZE>> {
+    facade.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Returns the `CommandComplete` tag of the [`BackendCommand`].
+fn command_tag(command: &BackendCommand) -> &'static str {
+    match command {
+        BackendCommand::Database(command) => match command {
+            DatabaseCommand::Create(_) => "CREATE DATABASE",
+            DatabaseCommand::Drop(_) => "DROP DATABASE",
+            DatabaseCommand::Use(_) | DatabaseCommand::UseSchema(_) => "SET",
+            DatabaseCommand::ShowDatabases(_) => "SHOW",
+            DatabaseCommand::VerifyDatabase(_) => "VERIFY DATABASE",
+        },
+        BackendCommand::Schema(command) => match command {
+            SchemaCommand::Create(_) => "CREATE SCHEMA",
+            SchemaCommand::Drop(_) => "DROP SCHEMA",
+            SchemaCommand::Rename(_) => "ALTER SCHEMA",
+            SchemaCommand::Show(_) => "SHOW",
+        },
+        BackendCommand::Table(command) => match command {
+            TableCommand::Create(_) => "CREATE TABLE",
+            TableCommand::Drop(_) => "DROP TABLE",
+            TableCommand::CreateSequence(_) => "CREATE SEQUENCE",
+            TableCommand::Describe(_) => "DESCRIBE",
+            TableCommand::Analyze(_) => "ANALYZE",
+        },
+        BackendCommand::Data(command) => match command {
+            DataCommand::Select(_) => "SELECT",
+        },
+        BackendCommand::Join(_) => "SELECT",
+    }
+}
+
+/// Converts a [`CommandResultString`] into protocol messages.
+///
+/// `SHOW`, `VERIFY DATABASE` and `DESCRIBE` commands return their output as
+/// a single `text` value, other commands only report completion.
+fn result_messages(
+    tag: &str,
+    result: CommandResultString,
+) -> Vec<BackendMessage> {
+    let mut messages = Vec::new();
+    if matches!(tag, "SHOW" | "VERIFY DATABASE" | "DESCRIBE") {
+        messages.push(BackendMessage::RowDescription(vec![
+            FieldDescription::new("result", PgType::TEXT),
+        ]));
+        messages.push(BackendMessage::DataRow(vec![Some(result.result)]));
+    }
+    messages.push(BackendMessage::CommandComplete(tag.to_string()));
+
+    messages
+}
+
+/// Converts the rows of a [`DataUnit`] into protocol messages. The type of a
+/// column is the column type of the [`DataUnit`]. If it's unknown, e.g. of a
+/// `NULL` literal, it's the type of the first non-`NULL` value, `text` if
+/// all values are `NULL`.
+fn data_messages(tag: &str, data: DataUnit) -> Vec<BackendMessage> {
+    let names = data.get_names().to_vec();
+    let types = data.get_types().to_vec();
+    let rows = data.get_values();
+    let fields = names
+        .into_iter()
+        .zip(types)
+        .enumerate()
+        .map(|(position, (name, data_type))| {
+            let pg_type = data_type
+                .or_else(|| {
+                    rows.iter().find_map(|row| row.0[position].data_type())
+                })
+                .map_or(PgType::TEXT, PgType::from);
+            FieldDescription::new(name.0, pg_type)
+        })
+        .collect();
+    let count = rows.len();
+
+    let mut messages = vec![BackendMessage::RowDescription(fields)];
+    messages.extend(rows.into_iter().map(|row| {
+        BackendMessage::DataRow(row.0.iter().map(encode_text).collect())
+    }));
+    messages.push(BackendMessage::CommandComplete(format!(
+        "{} {}",
+        tag, count
+    )));
+
+    messages
+}
+
+/// Returns the `SQLSTATE` code of a [`TableControllerError`], constraint
+/// violations and division by zero have their own codes.
+fn table_error_code(error: &TableControllerError) -> &'static str {
+    match error {
+        TableControllerError::DuplicatePrimaryKey
+        | TableControllerError::UniqueViolation(_) => UNIQUE_VIOLATION,
+        TableControllerError::CheckViolation(_) => CHECK_VIOLATION,
+        TableControllerError::ForeignKeyViolation(_) => FOREIGN_KEY_VIOLATION,
+        TableControllerError::EvaluationError(
+            EvaluationError::DivisionByZero,
+        )
+        | TableControllerError::AggregationError(
+            AggregationError::EvaluationError(EvaluationError::DivisionByZero),
+        ) => DIVISION_BY_ZERO,
+        _ => INTERNAL_ERROR,
+    }
+}
+
+/// Returns the `SQLSTATE` code of a [`SchemaControllerError`], see
+/// [`table_error_code`].
+fn schema_error_code(error: &SchemaControllerError) -> &'static str {
+    match error {
+        SchemaControllerError::RestrictViolation(_) => FOREIGN_KEY_VIOLATION,
+        SchemaControllerError::Table(error) => table_error_code(error),
+        _ => INTERNAL_ERROR,
+    }
+}
+
+/// Creates an `ErrorResponse` message.
+fn error(code: &'static str, message: String) -> BackendMessage {
+    BackendMessage::ErrorResponse(ErrorFields {
+        code: code.to_string(),
+        message,
+    })
+}
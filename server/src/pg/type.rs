@@ -0,0 +1,272 @@
+use backend::schema::r#type::r#enum::{StorageData, StorageDataType};
+
+/// Object identifier of a PostgreSQL type.
+pub type Oid = u32;
+
+/// `bool` type OID.
+pub const BOOL_OID: Oid = 16;
+
+/// `bytea` type OID.
+pub const BYTEA_OID: Oid = 17;
+
+/// `int8` type OID.
+pub const INT8_OID: Oid = 20;
+
+/// `int2` type OID.
+pub const INT2_OID: Oid = 21;
+
+/// `int4` type OID.
+pub const INT4_OID: Oid = 23;
+
+/// `text` type OID.
+pub const TEXT_OID: Oid = 25;
+
+/// `float4` type OID.
+pub const FLOAT4_OID: Oid = 700;
+
+/// `float8` type OID.
+pub const FLOAT8_OID: Oid = 701;
+
+/// `varchar` type OID.
+pub const VARCHAR_OID: Oid = 1043;
+
+/// `date` type OID.
+pub const DATE_OID: Oid = 1082;
+
+/// `time` type OID.
+pub const TIME_OID: Oid = 1083;
+
+/// `timestamp` type OID.
+pub const TIMESTAMP_OID: Oid = 1114;
+
+/// `interval` type OID.
+pub const INTERVAL_OID: Oid = 1186;
+
+/// `numeric` type OID.
+pub const NUMERIC_OID: Oid = 1700;
+
+/// `uuid` type OID.
+pub const UUID_OID: Oid = 2950;
+
+/// Size of the `varlena` header that PostgreSQL adds to the type modifier of
+/// `varchar(n)` and `numeric(p, s)` columns.
+const VARHDRSZ: i32 = 4;
+
+/// Description of a PostgreSQL type as it is sent in a `RowDescription`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PgType {
+    /// OID of the type.
+    pub oid: Oid,
+
+    /// Size of the type in bytes, `-1` for variable length types.
+    pub size: i16,
+
+    /// Type modifier, `-1` if the type has no modifier.
+    pub modifier: i32,
+}
+
+impl PgType {
+    /// `text` type, used for values that have no [`StorageDataType`].
+    pub const TEXT: PgType = PgType {
+        oid: TEXT_OID,
+        size: -1,
+        modifier: -1,
+    };
+}
+
+impl From<StorageDataType> for PgType {
+    /// Maps [`StorageDataType`] to the closest PostgreSQL type.
+    ///
+    /// Unsigned types are widened to the next signed type, because PostgreSQL
+    /// has no unsigned integers. 128-bit integers don't fit into any integer
+    /// type, so they are sent as `numeric`.
+    fn from(value: StorageDataType) -> Self {
+        let (oid, size) = match value {
+            StorageDataType::Bool => (BOOL_OID, 1),
+            StorageDataType::Byte => (INT2_OID, 2),
+            StorageDataType::Short => (INT2_OID, 2),
+            StorageDataType::Integer => (INT4_OID, 4),
+            StorageDataType::Long => (NUMERIC_OID, -1),
+            StorageDataType::UShort => (INT4_OID, 4),
+            StorageDataType::UInteger => (INT8_OID, 8),
+            StorageDataType::ULong => (NUMERIC_OID, -1),
+            StorageDataType::Float => (FLOAT4_OID, 4),
+            StorageDataType::Double => (FLOAT8_OID, 8),
+            StorageDataType::Text => (TEXT_OID, -1),
+            StorageDataType::Blob => (BYTEA_OID, -1),
+            StorageDataType::Date => (DATE_OID, 4),
+            StorageDataType::Time => (TIME_OID, 8),
+            StorageDataType::Timestamp => (TIMESTAMP_OID, 8),
+            StorageDataType::Interval => (INTERVAL_OID, 16),
+            StorageDataType::Uuid => (UUID_OID, 16),
+            StorageDataType::VarChar(len) => {
+                return PgType {
+                    oid: VARCHAR_OID,
+                    size: -1,
+                    modifier: i32::try_from(len)
+                        .map_or(-1, |len| len + VARHDRSZ),
+                }
+            }
+            StorageDataType::Decimal(precision, scale) => {
+                return PgType {
+                    oid: NUMERIC_OID,
+                    size: -1,
+                    modifier: (i32::from(precision) << 16 | i32::from(scale))
+                        + VARHDRSZ,
+                }
+            }
+        };
+
+        PgType {
+            oid,
+            size,
+            modifier: -1,
+        }
+    }
+}
+
+/// Encodes [`StorageData`] in the PostgreSQL text format. Binary data is
+/// encoded in the `bytea` hex format, e.g. `\xdeadbeef`, and intervals as
+/// ISO-8601 durations, e.g. `P1DT2H`.
+/// # Arguments
+/// * `value` - The value to encode.
+/// # Returns
+/// Text representation of the value, or `None` for `NULL`.
+pub fn encode_text<const N: u16>(value: &StorageData<N>) -> Option<String> {
+    let text = match value {
+        StorageData::Bool(value) => if value.0 { "t" } else { "f" }.to_string(),
+        StorageData::Byte(value) => value.0.to_string(),
+        StorageData::Short(value) => value.0.to_string(),
+        StorageData::Integer(value) => value.0.to_string(),
+        StorageData::Long(value) => value.0.to_string(),
+        StorageData::UShort(value) => value.0.to_string(),
+        StorageData::UInteger(value) => value.0.to_string(),
+        StorageData::ULong(value) => value.0.to_string(),
+        StorageData::Float(value) => value.0.to_string(),
+        StorageData::Double(value) => value.0.to_string(),
+        StorageData::VarChar(value) => value.value.clone(),
+        StorageData::Text(value) => value.0.clone(),
+        StorageData::Blob(value) => format!("\\x{}", value.to_hex()),
+        StorageData::Date(value) => value.to_string(),
+        StorageData::Time(value) => value.to_string(),
+        StorageData::Timestamp(value) => value.to_string(),
+        StorageData::Interval(value) => value.to_string(),
+        StorageData::Decimal(value) => value.to_string(),
+        StorageData::Uuid(value) => value.to_string(),
+        StorageData::Null => return None,
+    };
+
+    Some(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use backend::schema::r#type::{
+        data_types::{
+            Blob, Bool, Date, Decimal, Integer, Interval, Long, Text,
+            Timestamp, Uuid, VarChar,
+        },
+        r#enum::{StorageData, StorageDataType},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_integer_types() {
+        assert_eq!(PgType::from(StorageDataType::Short).oid, INT2_OID);
+        assert_eq!(PgType::from(StorageDataType::Integer).oid, INT4_OID);
+        assert_eq!(PgType::from(StorageDataType::UShort).oid, INT4_OID);
+        assert_eq!(PgType::from(StorageDataType::UInteger).oid, INT8_OID);
+        assert_eq!(PgType::from(StorageDataType::Long).oid, NUMERIC_OID);
+        assert_eq!(PgType::from(StorageDataType::ULong).oid, NUMERIC_OID);
+    }
+
+    #[test]
+    fn test_varchar_modifier() {
+        let pg_type = PgType::from(StorageDataType::VarChar(10));
+
+        assert_eq!(pg_type.oid, VARCHAR_OID);
+        assert_eq!(pg_type.size, -1);
+        assert_eq!(pg_type.modifier, 14);
+    }
+
+    #[test]
+    fn test_text_and_blob_types() {
+        assert_eq!(PgType::from(StorageDataType::Text), PgType::TEXT);
+        assert_eq!(PgType::from(StorageDataType::Blob).oid, BYTEA_OID);
+    }
+
+    #[test]
+    fn test_decimal_modifier() {
+        let pg_type = PgType::from(StorageDataType::Decimal(10, 2));
+
+        assert_eq!(pg_type.oid, NUMERIC_OID);
+        assert_eq!(pg_type.modifier, (10 << 16 | 2) + 4);
+        assert_eq!(PgType::from(StorageDataType::Long).modifier, -1);
+    }
+
+    #[test]
+    fn test_temporal_types() {
+        assert_eq!(PgType::from(StorageDataType::Date).oid, DATE_OID);
+        assert_eq!(PgType::from(StorageDataType::Time).oid, TIME_OID);
+        assert_eq!(PgType::from(StorageDataType::Timestamp).size, 8);
+        assert_eq!(PgType::from(StorageDataType::Interval).oid, INTERVAL_OID);
+    }
+
+    #[test]
+    fn test_uuid_type() {
+        let pg_type = PgType::from(StorageDataType::Uuid);
+
+        assert_eq!(pg_type.oid, UUID_OID);
+        assert_eq!(pg_type.size, 16);
+        assert_eq!(pg_type.modifier, -1);
+    }
+
+    #[test]
+    fn test_encode_text() {
+        let value: StorageData = StorageData::Bool(Bool(true));
+        assert_eq!(encode_text(&value).unwrap(), "t");
+
+        let value: StorageData = StorageData::Integer(Integer(-5));
+        assert_eq!(encode_text(&value).unwrap(), "-5");
+
+        let value: StorageData = StorageData::Long(Long(i128::MAX));
+        assert_eq!(encode_text(&value).unwrap(), i128::MAX.to_string());
+
+        let value: StorageData =
+            StorageData::VarChar(VarChar::new("test".to_string()).unwrap());
+        assert_eq!(encode_text(&value).unwrap(), "test");
+
+        let value: StorageData = StorageData::Text(Text("a".repeat(300)));
+        assert_eq!(encode_text(&value).unwrap(), "a".repeat(300));
+
+        let value: StorageData = StorageData::Blob(Blob(vec![0xde, 0xad]));
+        assert_eq!(encode_text(&value).unwrap(), "\\xdead");
+
+        let value: StorageData = StorageData::Date(Date(1));
+        assert_eq!(encode_text(&value).unwrap(), "1970-01-02");
+
+        let value: StorageData = StorageData::Timestamp(Timestamp(1_500_000));
+        assert_eq!(encode_text(&value).unwrap(), "1970-01-01 00:00:01.500");
+
+        let value: StorageData = StorageData::Interval(Interval {
+            months: 0,
+            days: 1,
+            micros: 0,
+        });
+        assert_eq!(encode_text(&value).unwrap(), "P1D");
+
+        let value: StorageData =
+            StorageData::Decimal(Decimal::new(-5, 2).unwrap());
+        assert_eq!(encode_text(&value).unwrap(), "-0.05");
+
+        let value: StorageData = StorageData::Uuid(Uuid([0xab; 16]));
+        assert_eq!(
+            encode_text(&value).unwrap(),
+            "abababab-abab-abab-abab-abababababab"
+        );
+
+        let value: StorageData = StorageData::Null;
+        assert_eq!(encode_text(&value), None);
+    }
+}